@@ -1,11 +1,12 @@
 use std::time::Duration;
 
-use aggchain_proof_contracts::config::AggchainProofContractsConfig;
-use prover_config::ProverType;
+use aggchain_proof_contracts::config::{AggchainProofContractsConfig, LocalExitRootCheckConfig};
+use prover_config::{CostAccountingConfig, GoldenCaptureConfig, ProgramCheckConfig, ProverType};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// The Aggchain proof builder configuration
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug)]
 #[serde(rename_all = "kebab-case")]
 pub struct AggchainProofBuilderConfig {
     /// ID of the network for which the proof is generated (rollup id).
@@ -25,6 +26,56 @@ pub struct AggchainProofBuilderConfig {
     /// Contract configuration
     #[serde(default)]
     pub contracts: AggchainProofContractsConfig,
+
+    /// Expected identity of the aggchain proof program.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub program_check: ProgramCheckConfig,
+
+    /// Capture of the proven requests as golden vectors, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub golden_capture: Option<GoldenCaptureConfig>,
+
+    /// Accounting of the proving cost, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_accounting: Option<CostAccountingConfig>,
+
+    /// Serving of the previous program once a new one is loaded.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub program_upgrade: ProgramUpgradeConfig,
+
+    /// Dual run of the aggchain proof program, whose logic is also executed
+    /// natively on the witness of every request, the public values of the
+    /// proof being compared with the native ones. Catches the witness
+    /// assembly bugs in the CI and canary environments, typically along with
+    /// the mock prover.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub differential_verification: bool,
+
+    /// Indexing of the bridge events of the l2, from which the bridge exits
+    /// witness of every request is built. The new local exit root recomputed
+    /// from them is compared with the root of the l2 bridge at the end block,
+    /// which catches the bugs of the event indexing before proving.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub local_exit_root_check: LocalExitRootCheckConfig,
+}
+
+/// Upgrade of the aggchain proof program while serving the requests.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProgramUpgradeConfig {
+    /// How long the previous program keeps proving the requests pinning it
+    /// once a new program is loaded.
+    #[serde(default = "default_transition_window")]
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub transition_window: Duration,
+}
+
+impl Default for ProgramUpgradeConfig {
+    fn default() -> Self {
+        Self {
+            transition_window: default_transition_window(),
+        }
+    }
 }
 
 impl Default for AggchainProofBuilderConfig {
@@ -35,10 +86,24 @@ impl Default for AggchainProofBuilderConfig {
             primary_prover: ProverType::NetworkProver(prover_config::NetworkProverConfig::default()),
             fallback_prover: None,
             contracts: AggchainProofContractsConfig::default(),
+            program_check: ProgramCheckConfig::default(),
+            golden_capture: None,
+            cost_accounting: None,
+            program_upgrade: ProgramUpgradeConfig::default(),
+            differential_verification: false,
+            local_exit_root_check: LocalExitRootCheckConfig::default(),
         }
     }
 }
 
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
 fn default_aggchain_prover_timeout() -> Duration {
     Duration::from_secs(3600)
 }
+
+fn default_transition_window() -> Duration {
+    Duration::from_secs(24 * 3600)
+}
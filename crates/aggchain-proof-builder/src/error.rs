@@ -1,8 +1,9 @@
 use aggchain_proof_core::full_execution_proof::AggregationProofPublicValues;
 use aggkit_prover_types::vkey_hash::VKeyHash;
 use agglayer_interop::types::bincode;
+use unified_bridge::AggchainProofPublicValues;
 
-use crate::WitnessGeneration;
+use crate::{programs::UnknownProgram, WitnessGeneration};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -12,6 +13,9 @@ pub enum Error {
     #[error("Failed to retrieve l1 chain data")]
     L1ChainDataRetrievalError(#[source] aggchain_proof_contracts::Error),
 
+    #[error("Aggchain proof program is not the expected one")]
+    ProgramCompatibility(#[source] prover_executor::CompatibilityError),
+
     #[error("Prover executor returned an error")]
     ProverExecutorError(#[source] prover_executor::Error),
 
@@ -49,6 +53,40 @@ pub enum Error {
         expected_by_contract: Box<AggregationProofPublicValues>,
         expected_by_verifier: Box<AggregationProofPublicValues>,
     },
+    #[error("Native execution of the aggchain proof program failed")]
+    NativeExecutionFailed(#[source] aggchain_proof_core::error::ProofError),
+
+    /// Divergence between the public values of the proof and the ones of the
+    /// native execution of the program, on the same witness.
+    #[error(
+        "Mismatch on the aggchain proof public values. native: {native:?}, proven: {proven:?}"
+    )]
+    MismatchAggchainProofPublicValues {
+        native: Box<AggchainProofPublicValues>,
+        proven: Box<AggchainProofPublicValues>,
+    },
+
+    #[error("Invalid FEP inputs")]
+    InvalidFepInputs(#[source] aggchain_proof_core::error::FepInputsError),
+
+    /// Bridge witness which fails the bridge constraints of the program,
+    /// checked before proving.
+    #[error("Invalid bridge witness")]
+    InvalidBridgeWitness(#[source] aggchain_proof_core::bridge::BridgeConstraintsError),
+
+    /// Local exit root of the l2 bridge which differs from the one recomputed
+    /// from its bridge events, or whose bridge events could not be indexed.
+    #[error("Local exit root check failed")]
+    LocalExitRootCheck(
+        #[source] aggchain_proof_contracts::local_exit_root::LocalExitRootCheckError,
+    ),
+
     #[error("Unable to fetch trusted sequencer address")]
     UnableToFetchTrustedSequencerAddress(#[source] aggchain_proof_contracts::Error),
+
+    #[error(transparent)]
+    UnknownProgram(#[from] UnknownProgram),
+
+    #[error("The current aggchain proof program {0:?} can't be retired")]
+    CurrentProgramRetired(VKeyHash),
 }
@@ -1,5 +1,6 @@
 pub mod config;
 mod error;
+pub mod programs;
 
 #[cfg(test)]
 mod tests;
@@ -7,24 +8,34 @@ mod tests;
 use std::{
     sync::Arc,
     task::{Context, Poll},
+    time::Instant,
 };
 
 use aggchain_proof_contracts::{
     contracts::{
-        GetTrustedSequencerAddress, L1RollupConfigHashFetcher, L2EvmStateSketchFetcher,
-        L2LocalExitRootFetcher, L2OutputAtBlockFetcher,
+        GetTrustedSequencerAddress, L1RollupConfigHashFetcher, L2BridgeExitsFetcher,
+        L2EvmStateSketchFetcher, L2LocalExitRootFetcher, L2OutputAtBlockFetcher,
     },
+    local_exit_root::LocalExitRootCheck,
     AggchainContractsClient,
 };
 use aggchain_proof_core::{
-    bridge::{inserted_ger::InsertedGER, BridgeWitness},
+    bridge::{
+        inserted_ger::{self, InsertedGER},
+        BridgeWitness,
+    },
+    fep_inputs_builder::FepInputsBuilder,
     full_execution_proof::{
-        AggchainParamsValues, AggregationProofPublicValues, BabyBearDigest, ClaimRoot, FepInputs,
+        AggchainParamsValues, AggregationProofPublicValues, BabyBearDigest, ClaimRoot,
     },
     proof::{AggchainProofWitness, IMPORTED_BRIDGE_EXIT_COMMITMENT_VERSION},
 };
 use aggchain_proof_types::AggchainProofInputs;
-use aggkit_prover_types::vkey_hash::VKeyHash;
+use aggkit_prover_types::{
+    envelope::ProofMode,
+    stage_timings::{Stage, StageTimings},
+    vkey_hash::VKeyHash,
+};
 use agglayer_interop::types::{
     bincode, GlobalIndexWithLeafHash, ImportedBridgeExitCommitmentValues,
 };
@@ -32,19 +43,22 @@ use agglayer_primitives::{Address, Digest};
 use alloy::eips::BlockNumberOrTag;
 pub use error::Error;
 use futures::{future::BoxFuture, FutureExt};
-use prover_executor::{Executor, ProofType};
+use prover_executor::{cost::CostLedger, ProofType, StdinBuilder};
 use serde::{Deserialize, Serialize};
 use sp1_sdk::{HashableKey, SP1Stdin, SP1VerifyingKey};
 use tower::{buffer::Buffer, util::BoxService, ServiceExt as _};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, info_span, Instrument as _};
 use unified_bridge::AggchainProofPublicValues;
 
-use crate::config::AggchainProofBuilderConfig;
+use crate::{config::AggchainProofBuilderConfig, programs::ProgramRegistry};
 
 const MAX_CONCURRENT_REQUESTS: usize = 100;
 
 pub const AGGCHAIN_PROOF_ELF: &[u8] = agglayer_elf_build::elf_bytes!();
 
+/// Name of the program in the golden vectors.
+pub const AGGCHAIN_PROGRAM_NAME: &str = "aggchain";
+
 /// Hardcoded hash of the "aggregation vkey".
 /// NOTE: Format being `hash_u32()` of the `SP1StarkVerifyingKey`.
 pub const AGGREGATION_VKEY_HASH: VKeyHash = proposer_elfs::aggregation::VKEY_HASH;
@@ -63,6 +77,10 @@ pub(crate) type ProverService = Buffer<
 pub struct AggchainProverInputs {
     pub output_root: ClaimRoot,
     pub stdin: SP1Stdin,
+    /// Public values of the native execution of the program, with the
+    /// differential verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_public_values: Option<AggchainProofPublicValues>,
 }
 
 pub enum FepVerification {
@@ -100,6 +118,12 @@ pub struct AggchainProofBuilderResponse {
     /// Verification key for the aggchain proof.
     pub vkey: Vec<u8>,
 
+    /// Hash of the verification key for the aggchain proof.
+    pub vkey_hash: VKeyHash,
+
+    /// SP1 proof mode of the aggchain proof.
+    pub proof_mode: ProofMode,
+
     /// Aggchain params.
     pub aggchain_params: Digest,
 
@@ -117,6 +141,9 @@ pub struct AggchainProofBuilderResponse {
 
     /// The public inputs that were provided to the proof
     pub public_values: AggchainProofPublicValues,
+
+    /// Time spent retrieving the chain data, proving and verifying.
+    pub stage_timings: StageTimings,
 }
 
 /// This service is responsible for building an Aggchain proof.
@@ -130,17 +157,25 @@ pub struct AggchainProofBuilder<ContractsClient> {
     /// Network id of the l2 chain for which the proof is generated.
     network_id: u32,
 
-    /// Prover client service.
-    prover: ProverService,
+    /// Served aggchain proof programs, with their prover.
+    programs: Arc<ProgramRegistry>,
 
     /// Verification key for the aggregated fep proof.
     aggregation_vkey: Arc<SP1VerifyingKey>,
 
-    /// Verification key for the aggchain proof.
-    aggchain_vkey: Arc<SP1VerifyingKey>,
-
     /// Static call caller address.
     static_call_caller_address: Address,
+
+    /// Running totals of the proving cost, when accounted for.
+    cost_ledger: Option<Arc<CostLedger>>,
+
+    /// Whether the program is also executed natively, its public values
+    /// being compared with the proven ones.
+    differential_verification: bool,
+
+    /// Index of the bridge events, from which the new local exit root is
+    /// checked and the bridge exits witness is built.
+    local_exit_root_check: Arc<LocalExitRootCheck>,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -154,16 +189,15 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         config: &AggchainProofBuilderConfig,
         contracts_client: Arc<ContractsClient>,
     ) -> Result<Self, Error> {
-        let executor = Executor::new(
-            &config.primary_prover,
-            &config.fallback_prover,
+        let cost_ledger = config
+            .cost_accounting
+            .as_ref()
+            .map(|cost_accounting| Arc::new(CostLedger::new(cost_accounting)));
+        let programs = Arc::new(ProgramRegistry::new(
+            config,
+            cost_ledger.clone(),
             AGGCHAIN_PROOF_ELF,
-        );
-
-        let aggchain_vkey = executor.get_vkey().clone();
-        let executor = tower::ServiceBuilder::new().service(executor).boxed();
-
-        let prover = Buffer::new(executor, MAX_CONCURRENT_REQUESTS);
+        )?);
 
         // Retrieve the entire aggregation vkey from the ELF
         let aggregation_vkey = proposer_elfs::aggregation::VKEY.vkey().clone();
@@ -182,15 +216,30 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         }
 
         Ok(AggchainProofBuilder {
-            aggchain_vkey,
             contracts_client,
-            prover,
+            programs,
             network_id: config.network_id,
             aggregation_vkey: Arc::new(aggregation_vkey),
             static_call_caller_address: config.contracts.static_call_caller_address,
+            cost_ledger,
+            differential_verification: config.differential_verification,
+            local_exit_root_check: Arc::new(
+                LocalExitRootCheck::new(config.local_exit_root_check.clone())
+                    .map_err(Error::LocalExitRootCheck)?,
+            ),
         })
     }
 
+    /// Running totals of the proving cost, when accounted for.
+    pub fn cost_ledger(&self) -> Option<&Arc<CostLedger>> {
+        self.cost_ledger.as_ref()
+    }
+
+    /// Served aggchain proof programs.
+    pub fn programs(&self) -> &Arc<ProgramRegistry> {
+        &self.programs
+    }
+
     /// Retrieve l1 and l2 public data needed for aggchain proof generation.
     /// Combine with the rest of the inputs to form an `AggchainProverInputs`.
     pub(crate) async fn retrieve_chain_data(
@@ -199,13 +248,17 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         network_id: u32,
         aggregation_vkey: Arc<SP1VerifyingKey>,
         static_call_caller_address: Address,
+        differential_verification: bool,
+        local_exit_root_check: Arc<LocalExitRootCheck>,
     ) -> Result<AggchainProverInputs, Error>
     where
         ContractsClient: L2LocalExitRootFetcher
+            + L2BridgeExitsFetcher
             + L2OutputAtBlockFetcher
             + L2EvmStateSketchFetcher
             + GetTrustedSequencerAddress
-            + L1RollupConfigHashFetcher,
+            + L1RollupConfigHashFetcher
+            + Sync,
     {
         info!(last_proven_block=%request.aggchain_proof_inputs.last_proven_block,
             end_block=%request.end_block,
@@ -224,6 +277,28 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
             .await
             .map_err(Error::L2ChainDataRetrievalError)?;
 
+        if local_exit_root_check.enabled() {
+            local_exit_root_check
+                .check(&*contracts_client, request.end_block, new_local_exit_root)
+                .instrument(info_span!("local_exit_root_check"))
+                .await
+                .map_err(Error::LocalExitRootCheck)?;
+        }
+
+        let bridge_exits = local_exit_root_check
+            .bridge_exits_witness(
+                &*contracts_client,
+                request.aggchain_proof_inputs.last_proven_block,
+                prev_local_exit_root,
+                request.end_block,
+            )
+            .instrument(info_span!("bridge_exits_witness"))
+            .await
+            .map_err(Error::LocalExitRootCheck)?;
+        bridge_exits
+            .verify(prev_local_exit_root, new_local_exit_root)
+            .map_err(Error::InvalidBridgeWitness)?;
+
         let l2_pre_root_output_at_block = contracts_client
             .get_l2_output_at_block(request.aggchain_proof_inputs.last_proven_block)
             .await
@@ -260,6 +335,11 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
         let inserted_gers: Vec<InsertedGER> = request
             .aggchain_proof_inputs
             .sorted_inserted_gers(&new_blocks_range);
+        inserted_ger::verify_inclusion_proofs(
+            &inserted_gers,
+            request.aggchain_proof_inputs.l1_info_tree_root_hash,
+        )
+        .map_err(Error::InvalidBridgeWitness)?;
 
         // NOTE: Corresponds to all of them because we do not have removed GERs yet.
         let inserted_gers_hash_chain = inserted_gers
@@ -279,24 +359,32 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
             })
             .collect();
 
-        let l1_info_tree_leaf = request.aggchain_proof_inputs.l1_info_tree_leaf;
-        let mut fep_inputs = FepInputs {
-            l1_head: l1_info_tree_leaf.inner.block_hash,
-            claim_block_num: request.end_block as u32,
-            rollup_config_hash,
-            prev_state_root: l2_pre_root_output_at_block.state_root,
-            prev_withdrawal_storage_root: l2_pre_root_output_at_block.withdrawal_storage_root,
-            prev_block_hash: l2_pre_root_output_at_block.latest_block_hash,
-            new_state_root: claim_root_output_at_block.state_root,
-            new_withdrawal_storage_root: claim_root_output_at_block.withdrawal_storage_root,
-            new_block_hash: claim_root_output_at_block.latest_block_hash,
-            trusted_sequencer,
-            signature_optimistic_mode: None, // NOTE: disabled for now
-            l1_info_tree_leaf,
-            l1_head_inclusion_proof: request.aggchain_proof_inputs.l1_info_tree_merkle_proof,
-            aggregation_vkey_hash: BabyBearDigest(aggregation_vkey.hash_babybear()),
-            range_vkey_commitment: RANGE_VKEY_COMMITMENT,
-        };
+        let fep_inputs_builder = FepInputsBuilder::default()
+            .prev_block_num(request.aggchain_proof_inputs.last_proven_block)
+            .claim_block_num(request.end_block)
+            .rollup_config_hash(rollup_config_hash)
+            .prev_output(
+                l2_pre_root_output_at_block.state_root,
+                l2_pre_root_output_at_block.withdrawal_storage_root,
+                l2_pre_root_output_at_block.latest_block_hash,
+            )
+            .new_output(
+                claim_root_output_at_block.state_root,
+                claim_root_output_at_block.withdrawal_storage_root,
+                claim_root_output_at_block.latest_block_hash,
+            )
+            .trusted_sequencer(trusted_sequencer)
+            .l1_info_tree_leaf(request.aggchain_proof_inputs.l1_info_tree_leaf)
+            .l1_head_inclusion_proof(request.aggchain_proof_inputs.l1_info_tree_merkle_proof)
+            .aggregation_vkey_hash(BabyBearDigest(aggregation_vkey.hash_babybear()))
+            .range_vkey_commitment(RANGE_VKEY_COMMITMENT);
+
+        let fep_inputs = match request.fep_verification {
+            FepVerification::Proof { .. } => fep_inputs_builder.sp1_proof(),
+            FepVerification::Optimistic { signature } => fep_inputs_builder.optimistic(signature),
+        }
+        .build()
+        .map_err(Error::InvalidFepInputs)?;
 
         {
             match request.fep_verification {
@@ -319,9 +407,7 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
                         });
                     }
                 }
-                FepVerification::Optimistic { signature } => {
-                    fep_inputs.signature_optimistic_mode = Some(signature);
-                }
+                FepVerification::Optimistic { .. } => {}
             }
 
             info!(
@@ -349,22 +435,35 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
                     prev_l2_block_sketch,
                     new_l2_block_sketch,
                     caller_address: static_call_caller_address,
+                    bridge_exits,
                 },
             };
 
             let output_root = prover_witness.fep.compute_claim_root();
 
+            // Outside of the zkVM, the aggregation proof is not verified.
+            let native_public_values = differential_verification
+                .then(|| {
+                    info_span!("native_execution")
+                        .in_scope(|| prover_witness.verify_aggchain_inputs())
+                        .map_err(Error::NativeExecutionFailed)
+                })
+                .transpose()?;
+
             let sp1_stdin = {
-                let mut stdin = SP1Stdin::new();
-                stdin.write(&prover_witness);
+                let mut stdin = StdinBuilder::new().write("aggchain_witness", &prover_witness);
 
                 if let FepVerification::Proof {
                     aggregation_proof, ..
                 } = request.fep_verification
                 {
-                    stdin.write_proof(*aggregation_proof, aggregation_vkey.vk.clone());
+                    stdin = stdin.write_proof(
+                        "aggregation_proof",
+                        *aggregation_proof,
+                        &aggregation_vkey,
+                    );
                 }
-                stdin
+                stdin.build()
             };
 
             info!(last_proven_block=%request.aggchain_proof_inputs.last_proven_block,
@@ -374,6 +473,7 @@ impl<ContractsClient> AggchainProofBuilder<ContractsClient> {
             Ok(AggchainProverInputs {
                 output_root,
                 stdin: sp1_stdin,
+                native_public_values,
             })
         }
     }
@@ -390,50 +490,74 @@ where
 
     type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
-    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        self.prover.poll_ready(cx).map_err(|e| {
-            if let Some(error) = e.downcast_ref::<prover_executor::Error>() {
-                Error::ProverExecutorError(error.clone())
-            } else {
-                Error::ProverServiceError(e.to_string())
-            }
-        })
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The readiness of the prover of the requested program is awaited in
+        // the call.
+        Poll::Ready(Ok(()))
     }
 
     fn call(&mut self, req: AggchainProofBuilderRequest) -> Self::Future {
         let contracts_client = self.contracts_client.clone();
-        let mut prover = self.prover.clone();
+        let program = self
+            .programs
+            .get(req.aggchain_proof_inputs.requested_vkey_hash);
         let network_id = self.network_id;
         let aggregation_vkey = self.aggregation_vkey.clone();
-        let aggchain_vkey = self.aggchain_vkey.clone();
         let static_call_caller_address = self.static_call_caller_address;
+        let differential_verification = self.differential_verification;
+        let local_exit_root_check = self.local_exit_root_check.clone();
 
         async move {
+            let programs::Program {
+                vkey: aggchain_vkey,
+                prover: mut prover,
+            } = program?;
             let last_proven_block = req.aggchain_proof_inputs.last_proven_block;
             let end_block = req.end_block;
             info!(%last_proven_block, %end_block, "Starting generation of the aggchain proof");
+            let mut stage_timings = StageTimings::default();
+
             // Retrieve all the necessary public inputs. Combine with
             // the data provided by the agg-sender in the request.
+            let started = Instant::now();
             let aggchain_prover_inputs = Self::retrieve_chain_data(
                 contracts_client,
                 req,
                 network_id,
                 aggregation_vkey,
                 static_call_caller_address,
+                differential_verification,
+                local_exit_root_check,
             )
+            .instrument(info_span!("witness"))
             .await?;
+            stage_timings.record(Stage::Witness, started.elapsed());
 
             let output_root = aggchain_prover_inputs.output_root;
-            let prover_executor::Response { proof } = prover
-                .ready()
-                .await
-                .map_err(Error::ProverServiceReadyError)?
-                .call(prover_executor::Request {
-                    stdin: aggchain_prover_inputs.stdin,
-                    proof_type: ProofType::Stark,
-                })
-                .await
-                .map_err(|error| Error::ProverFailedToExecute(anyhow::Error::from_boxed(error)))?;
+            let native_public_values = aggchain_prover_inputs.native_public_values;
+            let started = Instant::now();
+            let prover_executor::Response {
+                proof,
+                verification,
+            } = async {
+                prover
+                    .ready()
+                    .await
+                    .map_err(Error::ProverServiceReadyError)?
+                    .call(prover_executor::Request {
+                        stdin: aggchain_prover_inputs.stdin,
+                        proof_type: ProofType::Compressed,
+                    })
+                    .await
+                    .map_err(|error| Error::ProverFailedToExecute(anyhow::Error::from_boxed(error)))
+            }
+            .instrument(info_span!("prove"))
+            .await?;
+            // The executor verifies the proofs it generates, the verification
+            // being timed on its own.
+            let verification = verification.unwrap_or_default();
+            stage_timings.record(Stage::Prove, started.elapsed().saturating_sub(verification));
+            stage_timings.record(Stage::Verify, verification);
 
             let public_input: AggchainProofPublicValues = bincode::sp1v4()
                 .deserialize(proof.public_values.as_slice())
@@ -456,23 +580,48 @@ where
                 public_input.commit_imported_bridge_exits
             );
 
+            if let Some(native) = native_public_values {
+                check_public_values(native, &public_input).inspect_err(|error| {
+                    error!(%last_proven_block, %end_block, ?error, "Differential verification failed")
+                })?;
+            }
+
             info!(%last_proven_block, %end_block, "Aggchain proof generated");
 
             Ok(AggchainProofBuilderResponse {
                 vkey: bincode::default()
                     .serialize(&aggchain_vkey)
                     .map_err(Error::UnableToSerializeVkey)?,
+                vkey_hash: VKeyHash::from_vkey(&aggchain_vkey),
                 proof: bincode::default()
                     .serialize(&stark)
                     .map_err(Error::UnableToSerializeProof)?,
+                proof_mode: ProofMode::Compressed,
                 aggchain_params: public_input.aggchain_params,
                 last_proven_block,
                 end_block,
                 output_root,
                 new_local_exit_root: public_input.new_local_exit_root,
                 public_values: public_input,
+                stage_timings,
             })
         }
         .boxed()
     }
 }
+
+/// Compares the public values of the proof with the ones of the native
+/// execution of the program on the same witness.
+pub(crate) fn check_public_values(
+    native: AggchainProofPublicValues,
+    proven: &AggchainProofPublicValues,
+) -> Result<(), Error> {
+    if native == *proven {
+        return Ok(());
+    }
+
+    Err(Error::MismatchAggchainProofPublicValues {
+        native: Box::new(native),
+        proven: Box::new(proven.clone()),
+    })
+}
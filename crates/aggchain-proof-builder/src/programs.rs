@@ -0,0 +1,370 @@
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant},
+};
+
+use aggkit_prover_types::vkey_hash::VKeyHash;
+use agglayer_primitives::Digest;
+use prover_config::{GoldenCaptureConfig, ProgramCheckConfig, ProverType};
+use prover_executor::{
+    cost::{CostAccountingLayer, CostLedger},
+    golden::GoldenCaptureLayer,
+    Executor,
+};
+use serde::{Deserialize, Serialize};
+use sp1_sdk::SP1VerifyingKey;
+use tower::{buffer::Buffer, Layer as _, ServiceExt as _};
+use tracing::info;
+
+use crate::{
+    config::AggchainProofBuilderConfig, Error, ProverService, AGGCHAIN_PROGRAM_NAME,
+    MAX_CONCURRENT_REQUESTS,
+};
+
+/// The requested aggchain proof program is not served.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[error("The aggchain proof program {vkey_hash:?} is not served")]
+pub struct UnknownProgram {
+    pub vkey_hash: VKeyHash,
+}
+
+/// State of a served aggchain proof program, as listed to the operators.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProgramState {
+    pub vkey_hash: VKeyHash,
+
+    /// Whether the program proves the requests pinning no program.
+    pub current: bool,
+
+    /// Time left until the program is no longer served, zero for the current
+    /// program.
+    #[serde(
+        with = "prover_utils::with::HumanDuration",
+        skip_serializing_if = "Duration::is_zero"
+    )]
+    pub retires_in: Duration,
+}
+
+#[derive(Clone)]
+pub(crate) struct Program {
+    pub(crate) vkey: Arc<SP1VerifyingKey>,
+    pub(crate) prover: ProverService,
+}
+
+struct ServedProgram {
+    program: Program,
+    retires_at: Option<Instant>,
+}
+
+struct Programs {
+    current: VKeyHash,
+    served: BTreeMap<VKeyHash, ServedProgram>,
+}
+
+impl Programs {
+    fn prune(&mut self, now: Instant) {
+        self.served
+            .retain(|_, served| served.retires_at.is_none_or(|retires_at| retires_at > now));
+    }
+}
+
+/// Builds the prover of the aggchain proof programs.
+struct ProgramFactory {
+    primary_prover: ProverType,
+    fallback_prover: Option<ProverType>,
+    golden_capture: Option<GoldenCaptureConfig>,
+    cost_ledger: Option<Arc<CostLedger>>,
+    network_id: u32,
+}
+
+impl ProgramFactory {
+    fn build(&self, elf: &[u8], program_check: &ProgramCheckConfig) -> Result<Program, Error> {
+        let executor = Executor::new(&self.primary_prover, &self.fallback_prover, elf);
+
+        executor
+            .check_compatibility(program_check)
+            .map_err(Error::ProgramCompatibility)?;
+
+        let vkey = executor.get_vkey().clone();
+        let executor = match &self.golden_capture {
+            Some(golden_capture) => {
+                GoldenCaptureLayer::new(AGGCHAIN_PROGRAM_NAME, vkey.clone(), golden_capture)
+                    .layer(executor)
+                    .boxed()
+            }
+            None => executor.boxed(),
+        };
+
+        let executor = match &self.cost_ledger {
+            Some(cost_ledger) => {
+                CostAccountingLayer::new(cost_ledger.clone(), self.network_id, elf)
+                    .layer(executor)
+                    .boxed()
+            }
+            None => executor,
+        };
+
+        Ok(Program {
+            vkey,
+            prover: Buffer::new(executor, MAX_CONCURRENT_REQUESTS),
+        })
+    }
+}
+
+/// Aggchain proof programs served by the builder, by hash of their verifying
+/// key.
+///
+/// The requests pinning no program are proven by the current program. When a
+/// new program is loaded, it becomes the current one and the previous one keeps
+/// proving the requests pinning it until the end of the transition window.
+pub struct ProgramRegistry {
+    factory: ProgramFactory,
+    transition_window: Duration,
+    programs: RwLock<Programs>,
+}
+
+impl ProgramRegistry {
+    pub(crate) fn new(
+        config: &AggchainProofBuilderConfig,
+        cost_ledger: Option<Arc<CostLedger>>,
+        elf: &[u8],
+    ) -> Result<Self, Error> {
+        let factory = ProgramFactory {
+            primary_prover: config.primary_prover.clone(),
+            fallback_prover: config.fallback_prover.clone(),
+            golden_capture: config.golden_capture.clone(),
+            cost_ledger,
+            network_id: config.network_id,
+        };
+        let program = factory.build(elf, &config.program_check)?;
+
+        Ok(Self::with_program(
+            factory,
+            config.program_upgrade.transition_window,
+            VKeyHash::from_vkey(program.vkey.as_ref()),
+            program,
+        ))
+    }
+
+    fn with_program(
+        factory: ProgramFactory,
+        transition_window: Duration,
+        vkey_hash: VKeyHash,
+        program: Program,
+    ) -> Self {
+        let served = ServedProgram {
+            program,
+            retires_at: None,
+        };
+
+        Self {
+            factory,
+            transition_window,
+            programs: RwLock::new(Programs {
+                current: vkey_hash,
+                served: BTreeMap::from([(vkey_hash, served)]),
+            }),
+        }
+    }
+
+    /// Hash of the verifying key of the current program.
+    pub fn current(&self) -> VKeyHash {
+        self.programs
+            .read()
+            .expect("program registry lock poisoned")
+            .current
+    }
+
+    /// Checks that the requested program is served, the current program being
+    /// requested when none is.
+    pub fn check(&self, requested: Option<Digest>) -> Result<(), UnknownProgram> {
+        self.get(requested).map(|_| ())
+    }
+
+    pub(crate) fn get(&self, requested: Option<Digest>) -> Result<Program, UnknownProgram> {
+        let programs = self
+            .programs
+            .read()
+            .expect("program registry lock poisoned");
+        let vkey_hash = requested
+            .map(|digest| VKeyHash::from(digest.0))
+            .unwrap_or(programs.current);
+
+        programs
+            .served
+            .get(&vkey_hash)
+            .filter(|served| {
+                served
+                    .retires_at
+                    .is_none_or(|retires_at| retires_at > Instant::now())
+            })
+            .map(|served| served.program.clone())
+            .ok_or(UnknownProgram { vkey_hash })
+    }
+
+    /// Loads the given ELF as the current program, the previous one being
+    /// served until the end of the transition window.
+    ///
+    /// Blocks while building the provers of the program, to call from a
+    /// blocking task.
+    pub fn load(&self, elf: &[u8], program_check: &ProgramCheckConfig) -> Result<VKeyHash, Error> {
+        let program = self.factory.build(elf, program_check)?;
+        let vkey_hash = VKeyHash::from_vkey(program.vkey.as_ref());
+        self.install(vkey_hash, program);
+
+        Ok(vkey_hash)
+    }
+
+    fn install(&self, vkey_hash: VKeyHash, program: Program) {
+        let now = Instant::now();
+        let mut programs = self
+            .programs
+            .write()
+            .expect("program registry lock poisoned");
+        programs.prune(now);
+
+        let previous = programs.current;
+        if previous != vkey_hash {
+            if let Some(served) = programs.served.get_mut(&previous) {
+                served.retires_at = Some(now + self.transition_window);
+            }
+        }
+
+        programs.current = vkey_hash;
+        programs.served.insert(
+            vkey_hash,
+            ServedProgram {
+                program,
+                retires_at: None,
+            },
+        );
+        info!(?vkey_hash, ?previous, "Aggchain proof program loaded");
+    }
+
+    /// Stops serving the given program before the end of its transition
+    /// window, returning whether it was served.
+    pub fn retire(&self, vkey_hash: VKeyHash) -> Result<bool, Error> {
+        let mut programs = self
+            .programs
+            .write()
+            .expect("program registry lock poisoned");
+        programs.prune(Instant::now());
+
+        if programs.current == vkey_hash {
+            return Err(Error::CurrentProgramRetired(vkey_hash));
+        }
+
+        let retired = programs.served.remove(&vkey_hash).is_some();
+        if retired {
+            info!(?vkey_hash, "Aggchain proof program retired");
+        }
+
+        Ok(retired)
+    }
+
+    /// Lists the served programs.
+    pub fn states(&self) -> Vec<ProgramState> {
+        let now = Instant::now();
+        let mut programs = self
+            .programs
+            .write()
+            .expect("program registry lock poisoned");
+        programs.prune(now);
+
+        programs
+            .served
+            .iter()
+            .map(|(vkey_hash, served)| ProgramState {
+                vkey_hash: *vkey_hash,
+                current: *vkey_hash == programs.current,
+                retires_in: served
+                    .retires_at
+                    .map(|retires_at| retires_at.saturating_duration_since(now))
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::b256;
+    use tower::{service_fn, util::BoxService};
+
+    use super::*;
+
+    const OLD: VKeyHash = VKeyHash::from_bytes(b256!(
+        "0000000100000002000000030000000400000005000000060000000700000008"
+    ));
+    const NEW: VKeyHash = VKeyHash::from_bytes(b256!(
+        "0000000900000010000000110000001200000013000000140000001500000016"
+    ));
+
+    fn program() -> Program {
+        let prover = BoxService::new(service_fn(|_: prover_executor::Request| async {
+            Err::<prover_executor::Response, _>(prover_executor::Error::UnableToExecuteProver)
+        }));
+
+        Program {
+            vkey: Arc::new(proposer_elfs::aggregation::VKEY.vkey().clone()),
+            prover: Buffer::new(prover, 1),
+        }
+    }
+
+    fn registry(transition_window: Duration) -> ProgramRegistry {
+        let factory = ProgramFactory {
+            primary_prover: ProverType::default(),
+            fallback_prover: None,
+            golden_capture: None,
+            cost_ledger: None,
+            network_id: 1,
+        };
+
+        ProgramRegistry::with_program(factory, transition_window, OLD, program())
+    }
+
+    fn digest(vkey_hash: VKeyHash) -> Digest {
+        Digest(vkey_hash.to_byte_array())
+    }
+
+    #[tokio::test]
+    async fn previous_program_is_served_during_the_transition_window() {
+        let registry = registry(Duration::from_secs(3600));
+        registry.install(NEW, program());
+
+        assert_eq!(registry.current(), NEW);
+        assert!(registry.check(None).is_ok());
+        assert!(registry.check(Some(digest(OLD))).is_ok());
+        assert!(registry.check(Some(digest(NEW))).is_ok());
+
+        let states = registry.states();
+        assert_eq!(states.len(), 2);
+        assert!(!states[0].current && !states[0].retires_in.is_zero());
+        assert!(states[1].current && states[1].retires_in.is_zero());
+
+        assert!(matches!(
+            registry.retire(NEW),
+            Err(Error::CurrentProgramRetired(_))
+        ));
+        assert!(registry.retire(OLD).unwrap());
+        assert!(!registry.retire(OLD).unwrap());
+        assert_eq!(
+            registry.check(Some(digest(OLD))),
+            Err(UnknownProgram { vkey_hash: OLD })
+        );
+    }
+
+    #[tokio::test]
+    async fn previous_program_is_no_longer_served_after_the_transition_window() {
+        let registry = registry(Duration::ZERO);
+        registry.install(NEW, program());
+
+        assert_eq!(
+            registry.check(Some(digest(OLD))),
+            Err(UnknownProgram { vkey_hash: OLD })
+        );
+        assert_eq!(registry.states().len(), 1);
+    }
+}
@@ -40,6 +40,7 @@ mod aggchain_proof_builder {
                 proving_timeout: Duration::from_secs(3600),
                 proving_request_timeout: Some(Duration::from_secs(600)),
                 sp1_cluster_endpoint: "https://rpc.production.succinct.xyz/".parse()?,
+                private_key: None,
             }),
             &None,
             crate::AGGCHAIN_PROOF_ELF,
@@ -58,13 +59,13 @@ mod aggchain_proof_builder {
             "src/tests/data/aggchain_prover_inputs_001_lpb_1_eb_4.json",
         )?;
 
-        let prover_executor::Response { proof } = prover
+        let prover_executor::Response { proof, .. } = prover
             .ready()
             .await
             .map_err(Error::ProverServiceReadyError)?
             .call(prover_executor::Request {
                 stdin: aggchain_prover_inputs.stdin,
-                proof_type: prover_executor::ProofType::Stark,
+                proof_type: prover_executor::ProofType::Compressed,
             })
             .await
             .map_err(|error| Error::ProverFailedToExecute(anyhow::Error::from_boxed(error)))?;
@@ -74,3 +75,143 @@ mod aggchain_proof_builder {
         Ok(())
     }
 }
+
+mod differential_verification {
+    use agglayer_primitives::Digest;
+    use unified_bridge::AggchainProofPublicValues;
+
+    use crate::{check_public_values, Error};
+
+    #[test]
+    fn diverging_public_values_are_rejected() {
+        let native = AggchainProofPublicValues {
+            prev_local_exit_root: Digest::default(),
+            new_local_exit_root: Digest([1; 32]),
+            l1_info_root: Digest([2; 32]),
+            origin_network: 1_u32.into(),
+            commit_imported_bridge_exits: Digest([3; 32]),
+            aggchain_params: Digest([4; 32]),
+        };
+        assert!(check_public_values(native.clone(), &native).is_ok());
+
+        let proven = AggchainProofPublicValues {
+            new_local_exit_root: Digest([5; 32]),
+            ..native.clone()
+        };
+        assert!(matches!(
+            check_public_values(native, &proven),
+            Err(Error::MismatchAggchainProofPublicValues { .. })
+        ));
+    }
+}
+
+/// Cycles of the keccak256 hashes and of the merkle proofs of the program,
+/// against their budget.
+///
+/// The program is to be built with the `cycle-tracker` feature, its ELF being
+/// read from `AGGCHAIN_PROOF_CYCLE_TRACKER_ELF`. The prover inputs are read
+/// from `AGGCHAIN_PROOF_CYCLE_TRACKER_INPUTS`, as dumped by
+/// [`dump_aggchain_prover_inputs_json`] from the same sources: the inputs of
+/// `src/tests/data` predate the current layout of the witness.
+///
+/// ```sh
+/// (cd crates/aggchain-proof-program && cargo prove build --features cycle-tracker)
+/// AGGCHAIN_PROOF_CYCLE_TRACKER_ELF=<elf> AGGCHAIN_PROOF_CYCLE_TRACKER_INPUTS=<json> \
+///     cargo test -p aggchain-proof-builder --features cycle-budgets cycle_budgets \
+///     -- --nocapture
+/// ```
+///
+/// The measured cycles are printed, to set the budgets from, the ones of the
+/// keccak256 hashes along with the ones of sample hashes computed without the
+/// keccak precompile.
+#[cfg(feature = "cycle-budgets")]
+mod cycle_budgets {
+    use aggchain_proof_core::{cycles::MerkleProofKind, keccak};
+    use prover_executor::CycleProfile;
+    use sp1_sdk::{Prover as _, ProverClient};
+
+    use crate::tests::load_aggchain_prover_inputs_json;
+
+    const ELF_ENV: &str = "AGGCHAIN_PROOF_CYCLE_TRACKER_ELF";
+    const INPUTS_ENV: &str = "AGGCHAIN_PROOF_CYCLE_TRACKER_INPUTS";
+
+    fn env_path(name: &str) -> String {
+        std::env::var(name).unwrap_or_else(|_| panic!("{name} is not set"))
+    }
+
+    /// Profile of the execution of the cycle tracker ELF on the dumped inputs.
+    fn profile() -> CycleProfile {
+        let elf = std::fs::read(env_path(ELF_ENV)).unwrap();
+        let inputs = load_aggchain_prover_inputs_json(&env_path(INPUTS_ENV)).unwrap();
+
+        let (_, report) = ProverClient::builder()
+            .cpu()
+            .build()
+            .execute(&elf, &inputs.stdin)
+            .run()
+            .unwrap();
+        CycleProfile::from_report(&report)
+    }
+
+    #[test]
+    fn keccak256_hashes_go_through_the_precompile() {
+        let profile = profile();
+        let region = profile.regions.get(keccak::CYCLE_TRACKER_LABEL).expect(
+            "No keccak256 hash tracked, the program is not built with the cycle-tracker feature",
+        );
+
+        let software = profile
+            .regions
+            .get(keccak::SOFTWARE_CYCLE_TRACKER_LABEL)
+            .expect("No keccak256 hash tracked without the precompile");
+
+        let cycles = region.cycles / region.invocations.max(1);
+        let software_cycles = software.cycles / software.invocations.max(1);
+        println!(
+            "keccak256: {} hashes, {cycles} cycles per hash, {software_cycles} cycles per hash \
+             without the precompile",
+            region.invocations
+        );
+        assert!(
+            cycles <= keccak::CYCLE_BUDGET,
+            "keccak256 takes {cycles} cycles per hash, over its budget of {}",
+            keccak::CYCLE_BUDGET
+        );
+        assert!(
+            software_cycles > keccak::CYCLE_BUDGET,
+            "keccak256 takes {software_cycles} cycles per hash without the precompile, within \
+             the budget of {} which does not tell both paths apart",
+            keccak::CYCLE_BUDGET
+        );
+    }
+
+    #[test]
+    fn merkle_proofs_fit_their_cycle_budget() {
+        let profile = profile();
+
+        let mut tracked = 0;
+        for kind in MerkleProofKind::ALL {
+            let Some(region) = profile.regions.get(kind.label()) else {
+                continue;
+            };
+            tracked += 1;
+
+            // The executor only reports the total of the region.
+            let cycles = region.cycles / region.invocations.max(1);
+            println!(
+                "{}: {} verifications, {cycles} cycles per verification",
+                kind.label(),
+                region.invocations
+            );
+            assert!(
+                cycles <= kind.cycle_budget(),
+                "{kind:?} takes {cycles} cycles per verification, over its budget of {}",
+                kind.cycle_budget()
+            );
+        }
+        assert!(
+            tracked > 0,
+            "No merkle proof tracked, the program is not built with the cycle-tracker feature"
+        );
+    }
+}
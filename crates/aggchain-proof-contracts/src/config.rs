@@ -1,6 +1,9 @@
+use std::path::PathBuf;
+
 use agglayer_primitives::{address, Address};
-use prover_alloy::L1RpcEndpoint;
+use prover_alloy::{BlockFinality, L1RpcEndpoint};
 use prover_utils::from_env_or_default;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use sp1_cc_client_executor::Genesis;
 use tracing::info;
@@ -18,30 +21,47 @@ const POLYGON_ROLLUP_MANAGER: Address = address!("0xB7f8BC63BbcaD18155201308C8f3
 /// Default random address just for the static call caller address.
 const STATIC_CALL_CALLER_ADDRESS: Address = address!("0x39027D57969aD59161365e0bbd53D2F63eE5AAA6");
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct AggchainProofContractsConfig {
     /// JSON-RPC endpoint of the l1 node.
     #[serde(default)]
     pub l1_rpc_endpoint: L1RpcEndpoint,
 
+    /// Additional JSON-RPC endpoints of l1 nodes, the requests being routed
+    /// to the healthiest of all the l1 endpoints.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub l1_rpc_fallback_endpoints: Vec<Url>,
+
+    /// Finality of the l1 blocks the witness data is fetched at.
+    #[serde(default)]
+    pub l1_block_finality: BlockFinality,
+
     /// JSON-RPC endpoint of the l2 execution node.
     #[serde(default = "prover_alloy::default_l2_execution_layer_url")]
     pub l2_execution_layer_rpc_endpoint: Url,
 
+    /// Additional JSON-RPC endpoints of l2 execution nodes, the requests
+    /// being routed to the healthiest of all the l2 execution endpoints.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub l2_execution_layer_rpc_fallback_endpoints: Vec<Url>,
+
     /// JSON-RPC endpoint of the l2 rollup node.
     #[serde(default = "prover_alloy::default_l2_consensus_layer_url")]
     pub l2_consensus_layer_rpc_endpoint: Url,
 
     /// Address of the L1 PolygonRollupManager.sol contract
     #[serde(default = "default_polygon_rollup_manager")]
+    #[schemars(with = "String")]
     pub polygon_rollup_manager: Address,
     /// Address of the L2 GlobalExitRootManagerL2SovereignChain.sol contract
     #[serde(default = "default_global_exit_root_manager_v2_sovereign_chain")]
+    #[schemars(with = "String")]
     pub global_exit_root_manager_v2_sovereign_chain: Address,
 
     /// Caller address for the static calls
     #[serde(default = "default_static_call_caller_address")]
+    #[schemars(with = "String")]
     pub static_call_caller_address: Address,
 
     // EVM sketch genesis configuration
@@ -55,7 +75,10 @@ impl Default for AggchainProofContractsConfig {
     fn default() -> Self {
         Self {
             l1_rpc_endpoint: L1RpcEndpoint::default(),
+            l1_rpc_fallback_endpoints: Vec::new(),
+            l1_block_finality: BlockFinality::default(),
             l2_execution_layer_rpc_endpoint: prover_alloy::default_l2_execution_layer_url(),
+            l2_execution_layer_rpc_fallback_endpoints: Vec::new(),
             l2_consensus_layer_rpc_endpoint: prover_alloy::default_l2_consensus_layer_url(),
             polygon_rollup_manager: default_polygon_rollup_manager(),
             global_exit_root_manager_v2_sovereign_chain:
@@ -66,6 +89,68 @@ impl Default for AggchainProofContractsConfig {
     }
 }
 
+/// Recomputation of the local exit root from the bridge events of the l2,
+/// checked against the root of the l2 bridge contract.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct LocalExitRootCheckConfig {
+    /// Whether the new local exit root of the requests is checked before
+    /// proving. The bridge events are indexed for the bridge exits of the
+    /// requests nonetheless.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// L2 block the bridge events are read from, at most the block of the
+    /// first deposit of the bridge.
+    #[serde(default)]
+    pub from_block: u64,
+
+    /// Largest block range of the bridge events read at once.
+    #[serde(default = "default_max_block_range")]
+    pub max_block_range: u64,
+
+    /// Largest number of block ranges whose bridge events are read
+    /// concurrently, the ranges being appended in order nonetheless.
+    #[serde(default = "default_max_concurrent_reads")]
+    pub max_concurrent_reads: usize,
+
+    /// Number of blocks whose bridge events are kept before the last indexed
+    /// one, at least the block range of the requests. The bridge events of the
+    /// older blocks are folded into the local exit tree checkpoint.
+    #[serde(default = "default_retained_blocks")]
+    pub retained_blocks: u64,
+
+    /// File the local exit tree checkpoint is saved to, from which the
+    /// indexing resumes after a restart instead of `from_block`.
+    #[serde(default)]
+    pub checkpoint_path: Option<PathBuf>,
+}
+
+impl Default for LocalExitRootCheckConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            from_block: 0,
+            max_block_range: default_max_block_range(),
+            max_concurrent_reads: default_max_concurrent_reads(),
+            retained_blocks: default_retained_blocks(),
+            checkpoint_path: None,
+        }
+    }
+}
+
+const fn default_max_block_range() -> u64 {
+    10_000
+}
+
+const fn default_max_concurrent_reads() -> usize {
+    4
+}
+
+const fn default_retained_blocks() -> u64 {
+    100_000
+}
+
 pub(crate) fn default_output_at_block_endpoint() -> String {
     from_env_or_default(
         "L2_OUTPUT_AT_BLOCK_ENDPOINT",
@@ -1,7 +1,9 @@
 use agglayer_interop::types::Digest;
 use agglayer_primitives::Address;
 use alloy::{eips::BlockNumberOrTag, network::Ethereum, sol};
+use prover_alloy::BlockFinality;
 use sp1_cc_client_executor::io::EvmSketchInput;
+use unified_bridge::{BridgeExit, L1InfoTreeLeaf};
 
 use crate::Error;
 
@@ -37,6 +39,23 @@ sol!(
     "contracts/AggchainFEP.json"
 );
 
+sol!(
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface GlobalExitRootManagerL1 {
+        function globalExitRootMap(bytes32 globalExitRoot) external view returns (uint256 timestamp);
+
+        event UpdateL1InfoTree(bytes32 indexed mainnetExitRoot, bytes32 indexed rollupExitRoot);
+
+        event UpdateL1InfoTreeV2(
+            bytes32 currentL1InfoRoot,
+            uint32 indexed leafCount,
+            uint256 blockhash,
+            uint64 minTimestamp
+        );
+    }
+);
+
 pub(crate) type ZkevmBridgeRpcClient<RpcProvider> =
     PolygonZkevmBridgeV2::PolygonZkevmBridgeV2Instance<RpcProvider, Ethereum>;
 
@@ -46,6 +65,9 @@ pub(crate) type PolygonRollupManagerRpcClient<RpcProvider> =
 pub(crate) type AggchainFepRpcClient<RpcProvider> =
     AggchainFep::AggchainFepInstance<RpcProvider, Ethereum>;
 
+pub(crate) type GlobalExitRootManagerL1RpcClient<RpcProvider> =
+    GlobalExitRootManagerL1::GlobalExitRootManagerL1Instance<RpcProvider, Ethereum>;
+
 pub(crate) type GlobalExitRootManagerL2SovereignChainRpcClient<RpcProvider> =
     GlobalExitRootManagerL2SovereignChain::GlobalExitRootManagerL2SovereignChainInstance<
         RpcProvider,
@@ -57,6 +79,17 @@ pub trait L2LocalExitRootFetcher {
     async fn get_l2_local_exit_root(&self, block_number: u64) -> Result<Digest, Error>;
 }
 
+#[async_trait::async_trait]
+pub trait L2BridgeExitsFetcher {
+    /// Bridge exits emitted by the l2 bridge from `from_block` to `to_block`
+    /// included, in insertion order.
+    async fn get_l2_bridge_exits(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedBridgeExit>, Error>;
+}
+
 #[async_trait::async_trait]
 pub trait L2OutputAtBlockFetcher {
     async fn get_l2_output_at_block(&self, block_number: u64) -> Result<L2OutputAtBlock, Error>;
@@ -67,11 +100,43 @@ pub trait L1RollupConfigHashFetcher {
     async fn get_rollup_config_hash(&self) -> Result<Digest, Error>;
 }
 
+#[async_trait::async_trait]
+pub trait L1GlobalExitRootFetcher {
+    /// Timestamp of the l1 block the GER was added in, zero if it never was.
+    async fn get_l1_global_exit_root_timestamp(&self, ger: Digest) -> Result<u64, Error>;
+}
+
+#[async_trait::async_trait]
+pub trait L1InfoTreeLeavesFetcher {
+    /// Number of the latest l1 block which reached the configured finality.
+    async fn get_l1_head(&self) -> Result<u64, Error>;
+
+    /// Leaves added to the l1 info tree within the block range, in order.
+    async fn get_l1_info_tree_leaves(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedL1InfoTreeLeaf>, Error>;
+}
+
+#[async_trait::async_trait]
+pub trait L1SettledBlockFetcher {
+    /// Latest l2 block settled on l1, as recorded by the aggchain FEP
+    /// contract.
+    async fn get_l1_settled_block(&self) -> Result<u64, Error>;
+}
+
 #[async_trait::async_trait]
 pub trait GetTrustedSequencerAddress {
     async fn get_trusted_sequencer_address(&self) -> Result<Address, Error>;
 }
 
+#[async_trait::async_trait]
+pub trait L2HeadFetcher {
+    /// Number of the latest l2 block which reached the finality.
+    async fn get_l2_head(&self, finality: BlockFinality) -> Result<u64, Error>;
+}
+
 #[async_trait::async_trait]
 pub trait L2EvmStateSketchFetcher {
     async fn get_prev_l2_block_sketch(
@@ -85,6 +150,27 @@ pub trait L2EvmStateSketchFetcher {
     ) -> Result<EvmSketchInput, Error>;
 }
 
+/// Bridge exit as emitted by the `BridgeEvent` of the l2 bridge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedBridgeExit {
+    /// L2 block the bridge exit was emitted in.
+    pub block_number: u64,
+    /// Index of the leaf of the bridge exit in the local exit tree.
+    pub deposit_count: u32,
+    pub bridge_exit: BridgeExit,
+}
+
+/// Leaf of the l1 info tree as emitted by the `UpdateL1InfoTree` and
+/// `UpdateL1InfoTreeV2` events of the l1 global exit root manager.
+#[derive(Debug, Clone)]
+pub struct IndexedL1InfoTreeLeaf {
+    /// L1 block the leaf was added in.
+    pub block_number: u64,
+    pub leaf: L1InfoTreeLeaf,
+    /// Root of the l1 info tree once the leaf added.
+    pub root: Digest,
+}
+
 /// L2 output at block data structure.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct L2OutputAtBlock {
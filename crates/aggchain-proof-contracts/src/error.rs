@@ -1,4 +1,5 @@
 use aggchain_proof_core::bridge::static_call::StaticCallStage;
+use agglayer_interop::types::Digest;
 use sp1_cc_host_executor::HostError;
 
 #[derive(thiserror::Error, Debug)]
@@ -24,12 +25,26 @@ pub enum Error {
     #[error("Error retrieving local exit root")]
     LocalExitRootError(#[source] alloy::contract::Error),
 
+    #[error("Error retrieving the bridge events from block {from_block} to block {to_block}")]
+    BridgeEventsRetrievalError {
+        from_block: u64,
+        to_block: u64,
+        #[source]
+        source: alloy::contract::Error,
+    },
+
+    #[error("Invalid bridge event, {0}")]
+    InvalidBridgeEvent(String),
+
     #[error("Unable to setup async engine")]
     AsyncEngineSetupError(#[source] std::io::Error),
 
     #[error("Unable to create HTTP RPC rollup node client")]
     RollupNodeInitError(#[source] jsonrpsee::core::ClientError),
 
+    #[error("Error retrieving the l2 head from the node")]
+    L2HeadRetrievalError(#[source] anyhow::Error),
+
     #[error("Error retrieving l2 output at block from the node")]
     L2OutputAtBlockRetrievalError(#[source] jsonrpsee::core::ClientError),
 
@@ -42,9 +57,38 @@ pub enum Error {
     #[error("Error performing rollup manager rollup id to rollup data call")]
     InvalidRollupIdToRollupData(#[source] alloy::contract::Error),
 
+    #[error("Unable to retrieve the l1 global exit root manager address")]
+    GlobalExitRootManagerAddressError(#[source] alloy::contract::Error),
+
+    #[error("Error retrieving the GER {ger} from the l1 global exit root manager")]
+    GlobalExitRootError {
+        ger: Digest,
+        #[source]
+        source: alloy::contract::Error,
+    },
+
+    #[error(
+        "Error retrieving the l1 info tree events from block {from_block} to block {to_block}"
+    )]
+    L1InfoTreeEventsRetrievalError {
+        from_block: u64,
+        to_block: u64,
+        #[source]
+        source: alloy::contract::Error,
+    },
+
+    #[error("Invalid l1 info tree event, {0}")]
+    InvalidL1InfoTreeEvent(String),
+
+    #[error("Error retrieving the l1 head from the node")]
+    L1HeadRetrievalError(#[source] anyhow::Error),
+
     #[error("Error retrieving rollup config hash")]
     RollupConfigHashError(#[source] alloy::contract::Error),
 
+    #[error("Error retrieving the latest settled l2 block")]
+    SettledBlockError(#[source] alloy::contract::Error),
+
     #[error("Error retrieving aggchain vkey")]
     AggchainVKeyRetrievalError(#[source] alloy::contract::Error),
 
@@ -1,11 +1,13 @@
 pub mod config;
 pub mod contracts;
 mod error;
+pub mod local_exit_root;
+pub mod prefetch;
 
 #[cfg(test)]
 mod tests;
 
-use std::{str::FromStr, sync::Arc};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use aggchain_proof_core::bridge::{
     static_call::{HashChainType, StaticCallStage},
@@ -14,29 +16,39 @@ use aggchain_proof_core::bridge::{
 use agglayer_interop::types::Digest;
 use agglayer_primitives::Address;
 use alloy::{
-    eips::BlockNumberOrTag, network::AnyNetwork, primitives::B256, providers::Provider,
+    eips::BlockNumberOrTag,
+    network::AnyNetwork,
+    primitives::{keccak256, B256},
+    providers::Provider,
     sol_types::SolCall,
 };
 use contracts::{
     GetTrustedSequencerAddress, GlobalExitRootManagerL2SovereignChainRpcClient,
-    L2EvmStateSketchFetcher,
+    L2EvmStateSketchFetcher, L2HeadFetcher,
 };
 use jsonrpsee::{core::client::ClientT, http_client::HttpClient, rpc_params};
-use prover_alloy::{build_alloy_fill_provider, AlloyFillProvider};
+use prover_alloy::{connect_fallback_alloy_fill_provider, AlloyFillProvider, BlockFinality};
 use sp1_cc_client_executor::{
     io::{EvmSketchInput, Primitives},
     ContractInput, Genesis,
 };
 use sp1_cc_host_executor::EvmSketch;
+use tokio::sync::OnceCell;
 use tracing::{debug, info};
+use unified_bridge::{
+    BridgeExit, L1InfoTreeLeaf, L1InfoTreeLeafInner, LeafType, NetworkId, TokenInfo,
+};
 use url::Url;
 
 pub use crate::error::Error;
 use crate::{
     config::AggchainProofContractsConfig,
     contracts::{
-        AggchainFep, AggchainFepRpcClient, GlobalExitRootManagerL2SovereignChain,
-        L1RollupConfigHashFetcher, L2LocalExitRootFetcher, L2OutputAtBlock, L2OutputAtBlockFetcher,
+        AggchainFep, AggchainFepRpcClient, GlobalExitRootManagerL1,
+        GlobalExitRootManagerL1RpcClient, GlobalExitRootManagerL2SovereignChain, IndexedBridgeExit,
+        IndexedL1InfoTreeLeaf, L1GlobalExitRootFetcher, L1InfoTreeLeavesFetcher,
+        L1RollupConfigHashFetcher, L1SettledBlockFetcher, L2BridgeExitsFetcher,
+        L2LocalExitRootFetcher, L2OutputAtBlock, L2OutputAtBlockFetcher,
         PolygonRollupManagerRpcClient, PolygonZkevmBridgeV2, ZkevmBridgeRpcClient,
     },
 };
@@ -45,6 +57,7 @@ use crate::{
 /// contracts relevant for the aggchain prover.
 pub trait AggchainContractsClient:
     L2LocalExitRootFetcher
+    + L2BridgeExitsFetcher
     + L2OutputAtBlockFetcher
     + L1RollupConfigHashFetcher
     + L2EvmStateSketchFetcher
@@ -70,6 +83,16 @@ pub struct AggchainContractsRpcClient<RpcProvider> {
     /// Aggchain FEP contract on the l1 network.
     aggchain_fep: AggchainFepRpcClient<RpcProvider>,
 
+    /// Polygon rollup manager contract on the l1 network.
+    polygon_rollup_manager: PolygonRollupManagerRpcClient<RpcProvider>,
+
+    /// GER contract on the l1 network, resolved from the rollup manager on
+    /// first use.
+    global_exit_root_manager_l1: Arc<OnceCell<GlobalExitRootManagerL1RpcClient<RpcProvider>>>,
+
+    /// Finality of the l1 blocks the data is fetched at.
+    l1_block_finality: BlockFinality,
+
     /// Trusted sequencer address.
     trusted_sequencer_addr: agglayer_primitives::Address,
 
@@ -100,6 +123,69 @@ where
     }
 }
 
+#[async_trait::async_trait]
+impl<RpcProvider> L2BridgeExitsFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Send + Sync,
+{
+    async fn get_l2_bridge_exits(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedBridgeExit>, Error> {
+        let events = self
+            .polygon_zkevm_bridge_v2
+            .BridgeEvent_filter()
+            .from_block(from_block)
+            .to_block(to_block)
+            .query()
+            .await
+            .map_err(|source| Error::BridgeEventsRetrievalError {
+                from_block,
+                to_block,
+                source,
+            })?;
+
+        events
+            .into_iter()
+            .map(|(event, log)| {
+                let leaf_type = match event.leafType {
+                    0 => LeafType::Transfer,
+                    1 => LeafType::Message,
+                    leaf_type => {
+                        return Err(Error::InvalidBridgeEvent(format!(
+                            "unknown leaf type {leaf_type}"
+                        )))
+                    }
+                };
+                let block_number = log.block_number.ok_or_else(|| {
+                    Error::InvalidBridgeEvent(format!(
+                        "missing block number of the deposit {}",
+                        event.depositCount
+                    ))
+                })?;
+
+                Ok(IndexedBridgeExit {
+                    block_number,
+                    deposit_count: event.depositCount,
+                    bridge_exit: BridgeExit {
+                        leaf_type,
+                        token_info: TokenInfo {
+                            origin_network: NetworkId::new(event.originNetwork),
+                            origin_token_address: event.originAddress.into(),
+                        },
+                        dest_network: NetworkId::new(event.destinationNetwork),
+                        dest_address: event.destinationAddress.into(),
+                        amount: event.amount,
+                        // The leaves commit to the hash of the metadata.
+                        metadata: Some(keccak256(&event.metadata).into()),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
 #[async_trait::async_trait]
 impl<RpcProvider> L2OutputAtBlockFetcher for AggchainContractsRpcClient<RpcProvider>
 where
@@ -123,14 +209,132 @@ where
     RpcProvider: alloy::providers::Provider + Send + Sync,
 {
     async fn get_rollup_config_hash(&self) -> Result<Digest, Error> {
-        let response = self
+        self.get_rollup_config_hash_at(None).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<RpcProvider> L1GlobalExitRootFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Clone + Send + Sync,
+{
+    async fn get_l1_global_exit_root_timestamp(&self, ger: Digest) -> Result<u64, Error> {
+        let timestamp = self
+            .global_exit_root_manager_l1()
+            .await?
+            .globalExitRootMap(ger.0.into())
+            .call()
+            .block(self.l1_block_finality.into())
+            .await
+            .map_err(|source| Error::GlobalExitRootError { ger, source })?;
+
+        Ok(timestamp.saturating_to())
+    }
+}
+
+#[async_trait::async_trait]
+impl<RpcProvider> L1InfoTreeLeavesFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Clone + Send + Sync,
+{
+    async fn get_l1_head(&self) -> Result<u64, Error> {
+        prover_alloy::get_block_number_at(
+            self.polygon_rollup_manager.provider(),
+            self.l1_block_finality,
+        )
+        .await
+        .map_err(Error::L1HeadRetrievalError)
+    }
+
+    async fn get_l1_info_tree_leaves(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedL1InfoTreeLeaf>, Error> {
+        let global_exit_root_manager = self.global_exit_root_manager_l1().await?;
+        let retrieval_error =
+            |source: alloy::contract::Error| Error::L1InfoTreeEventsRetrievalError {
+                from_block,
+                to_block,
+                source,
+            };
+
+        let exit_roots = global_exit_root_manager
+            .UpdateL1InfoTree_filter()
+            .from_block(from_block)
+            .to_block(to_block)
+            .query()
+            .await
+            .map_err(retrieval_error)?;
+        let leaves = global_exit_root_manager
+            .UpdateL1InfoTreeV2_filter()
+            .from_block(from_block)
+            .to_block(to_block)
+            .query()
+            .await
+            .map_err(retrieval_error)?;
+
+        // Both events are emitted one after the other when a leaf is added,
+        // the exit roots first.
+        let exit_roots = exit_roots
+            .into_iter()
+            .filter_map(|(event, log)| Some(((log.transaction_hash?, log.log_index?), event)))
+            .collect::<HashMap<_, _>>();
+
+        leaves
+            .into_iter()
+            .map(|(event, log)| {
+                let missing = |field: &str| {
+                    Error::InvalidL1InfoTreeEvent(format!(
+                        "missing {field} of the leaf count {}",
+                        event.leafCount
+                    ))
+                };
+                let block_number = log.block_number.ok_or_else(|| missing("block number"))?;
+                let exit_roots = log
+                    .transaction_hash
+                    .zip(log.log_index.and_then(|index| index.checked_sub(1)))
+                    .and_then(|position| exit_roots.get(&position))
+                    .ok_or_else(|| missing("exit roots"))?;
+                let l1_info_tree_index = event.leafCount.checked_sub(1).ok_or_else(|| {
+                    Error::InvalidL1InfoTreeEvent("leaf count of zero".to_string())
+                })?;
+                let (mer, rer) = (exit_roots.mainnetExitRoot, exit_roots.rollupExitRoot);
+
+                Ok(IndexedL1InfoTreeLeaf {
+                    block_number,
+                    leaf: L1InfoTreeLeaf {
+                        l1_info_tree_index,
+                        rer: rer.0.into(),
+                        mer: mer.0.into(),
+                        inner: L1InfoTreeLeafInner {
+                            global_exit_root: keccak256([mer.0, rer.0].concat()).0.into(),
+                            block_hash: Digest(event.blockhash.to_be_bytes()),
+                            timestamp: event.minTimestamp,
+                        },
+                    },
+                    root: event.currentL1InfoRoot.0.into(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl<RpcProvider> L1SettledBlockFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Send + Sync,
+{
+    async fn get_l1_settled_block(&self) -> Result<u64, Error> {
+        let settled_block = self
             .aggchain_fep
-            .rollupConfigHash()
+            .latestBlockNumber()
             .call()
+            .block(self.l1_block_finality.into())
             .await
-            .map_err(Error::RollupConfigHashError)?;
+            .map_err(Error::SettledBlockError)?;
 
-        Ok((response.0).into())
+        Ok(settled_block.saturating_to())
     }
 }
 
@@ -144,6 +348,18 @@ where
     }
 }
 
+#[async_trait::async_trait]
+impl<RpcProvider> L2HeadFetcher for AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Send + Sync,
+{
+    async fn get_l2_head(&self, finality: BlockFinality) -> Result<u64, Error> {
+        prover_alloy::get_block_number_at(self.polygon_zkevm_bridge_v2.provider(), finality)
+            .await
+            .map_err(Error::L2HeadRetrievalError)
+    }
+}
+
 #[async_trait::async_trait]
 impl<RpcProvider> L2EvmStateSketchFetcher for AggchainContractsRpcClient<RpcProvider>
 where
@@ -321,6 +537,55 @@ async fn host_execute<C: SolCall, P: Provider<AnyNetwork> + Clone, PT: Primitive
     Ok(())
 }
 
+impl<RpcProvider> AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Send + Sync,
+{
+    /// Retrieves the rollup config hash at the configured l1 block finality,
+    /// or at `finality` if given.
+    pub async fn get_rollup_config_hash_at(
+        &self,
+        finality: Option<BlockFinality>,
+    ) -> Result<Digest, Error> {
+        let response = self
+            .aggchain_fep
+            .rollupConfigHash()
+            .call()
+            .block(self.l1_block_finality.or_override(finality).into())
+            .await
+            .map_err(Error::RollupConfigHashError)?;
+
+        Ok((response.0).into())
+    }
+}
+
+impl<RpcProvider> AggchainContractsRpcClient<RpcProvider>
+where
+    RpcProvider: alloy::providers::Provider + Clone + Send + Sync,
+{
+    async fn global_exit_root_manager_l1(
+        &self,
+    ) -> Result<&GlobalExitRootManagerL1RpcClient<RpcProvider>, Error> {
+        self.global_exit_root_manager_l1
+            .get_or_try_init(|| async {
+                let address = self
+                    .polygon_rollup_manager
+                    .globalExitRootManager()
+                    .call()
+                    .await
+                    .map_err(Error::GlobalExitRootManagerAddressError)?;
+                info!(global_exit_root_manager_l1=%address,
+                    "Resolved the l1 global exit root manager");
+
+                Ok::<_, Error>(GlobalExitRootManagerL1::new(
+                    address,
+                    self.polygon_rollup_manager.provider().clone(),
+                ))
+            })
+            .await
+    }
+}
+
 impl<RpcProvider> AggchainContractsRpcClient<RpcProvider> {
     fn parse_l2_output_root(json: serde_json::Value) -> Result<L2OutputAtBlock, Error> {
         fn parse_hash(json: &serde_json::Value, field: &str) -> Result<Digest, Error> {
@@ -354,18 +619,32 @@ impl AggchainContractsRpcClient<AlloyFillProvider> {
         network_id: u32,
         config: &AggchainProofContractsConfig,
     ) -> Result<Self, crate::Error> {
-        let l1_client = build_alloy_fill_provider(
-            &config.l1_rpc_endpoint.url,
+        let l1_rpc_urls: Vec<Url> = std::iter::once(config.l1_rpc_endpoint.url.clone())
+            .chain(config.l1_rpc_fallback_endpoints.iter().cloned())
+            .collect();
+        let l1_client = connect_fallback_alloy_fill_provider(
+            &l1_rpc_urls,
             prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
             prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
         )
+        .await
         .map_err(Error::ProviderInitializationError)?;
 
-        let l2_el_client = build_alloy_fill_provider(
-            &config.l2_execution_layer_rpc_endpoint,
+        let l2_el_rpc_urls: Vec<Url> =
+            std::iter::once(config.l2_execution_layer_rpc_endpoint.clone())
+                .chain(
+                    config
+                        .l2_execution_layer_rpc_fallback_endpoints
+                        .iter()
+                        .cloned(),
+                )
+                .collect();
+        let l2_el_client = connect_fallback_alloy_fill_provider(
+            &l2_el_rpc_urls,
             prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
             prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
         )
+        .await
         .map_err(Error::ProviderInitializationError)?;
 
         let l2_cl_client = Arc::new(
@@ -426,8 +705,11 @@ impl AggchainContractsRpcClient<AlloyFillProvider> {
             l2_cl_client,
             polygon_zkevm_bridge_v2,
             aggchain_fep,
+            polygon_rollup_manager,
+            global_exit_root_manager_l1: Arc::new(OnceCell::new()),
             l2_root_provider_endpoint: config.l2_execution_layer_rpc_endpoint.clone(),
             global_exit_root_manager_l2,
+            l1_block_finality: config.l1_block_finality,
             trusted_sequencer_addr,
             static_call_caller_address: config.static_call_caller_address,
             evm_sketch_genesis: config::parse_evm_sketch_genesis(&config.evm_sketch_genesis)?,
@@ -0,0 +1,951 @@
+//! Recomputation of the local exit root of the l2 from the bridge events,
+//! checked against the root of the l2 bridge contract before proving, so that
+//! the bugs of the event indexing are caught before a proof is generated.
+//!
+//! The bridge events are indexed incrementally across the requests, the block
+//! ranges being read concurrently and appended in order. The local exit tree
+//! only keeps its frontier, along with its root after the last leaf of each
+//! block, such that a mismatch is narrowed down to the first diverging leaf
+//! with a binary search over the roots of the bridge.
+//!
+//! Only the bridge events of the last [`retained_blocks`] are kept, the older
+//! ones being folded into a checkpoint of the local exit tree, saved to the
+//! [`checkpoint_path`] if any for the indexing to resume from it after a
+//! restart. The blocks from the first diverging one on are dropped from the
+//! index, e.g. upon a reorg of the l2, and read again by the next request.
+//!
+//! The same index provides the [`BridgeExitsWitness`] of the requests, from
+//! which the aggchain program recomputes the new local exit root in-guest.
+//!
+//! [`retained_blocks`]: LocalExitRootCheckConfig::retained_blocks
+//! [`checkpoint_path`]: LocalExitRootCheckConfig::checkpoint_path
+use std::{
+    collections::BTreeMap,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use aggchain_proof_core::bridge::bridge_exits::BridgeExitsWitness;
+use agglayer_interop::types::Digest;
+use futures::{StreamExt as _, TryFutureExt as _, TryStreamExt as _};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+use unified_bridge::{LocalExitTree, LocalExitTreeError};
+
+use crate::{
+    config::LocalExitRootCheckConfig,
+    contracts::{L2BridgeExitsFetcher, L2LocalExitRootFetcher},
+    Error,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum LocalExitRootCheckError {
+    #[error(transparent)]
+    Fetch(#[from] Error),
+
+    /// Gap in the bridge events, or bridge events read from a block after the
+    /// first deposit of the bridge.
+    #[error(
+        "Bridge event of the deposit {deposit_count} at block {block_number}, expected the \
+         deposit {expected}"
+    )]
+    NonContiguousDeposit {
+        block_number: u64,
+        expected: u32,
+        deposit_count: u32,
+    },
+
+    /// Bridge events read again for a witness which differ from the indexed
+    /// ones, e.g. upon a reorg of the l2.
+    #[error("Bridge events from block {from_block} to {to_block} differ from the indexed ones")]
+    InconsistentBridgeEvents { from_block: u64, to_block: u64 },
+
+    /// Block older than the bridge events kept by the index.
+    #[error(
+        "Bridge events of the block {block_number} are folded into the checkpoint of the block \
+         {checkpoint_block}"
+    )]
+    Pruned {
+        block_number: u64,
+        checkpoint_block: u64,
+    },
+
+    #[error("Unable to read or write the local exit tree checkpoint {path:?}")]
+    Checkpoint {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unable to append the bridge exit to the local exit tree")]
+    LocalExitTree(#[source] LocalExitTreeError),
+
+    #[error(
+        "Local exit root {computed} recomputed from the bridge events differs from the root \
+         {on_chain} of the l2 bridge at block {block_number}, diverging from the leaf \
+         {diverging_leaf_index}"
+    )]
+    Mismatch {
+        block_number: u64,
+        computed: Digest,
+        on_chain: Digest,
+        diverging_leaf_index: u32,
+    },
+}
+
+/// Check of the local exit roots of the l2 bridge against the ones recomputed
+/// from its bridge events.
+pub struct LocalExitRootCheck {
+    config: LocalExitRootCheckConfig,
+    index: Mutex<Index>,
+}
+
+/// Local exit tree as of a block, all the bridge events up to it included.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+struct Checkpoint {
+    block_number: u64,
+    local_exit_tree: LocalExitTree,
+}
+
+/// Bridge events indexed so far.
+struct Index {
+    /// Bridge events folded into a checkpoint, none before the first
+    /// [`LocalExitRootCheckConfig::retained_blocks`] are indexed.
+    checkpoint: Option<Checkpoint>,
+    /// Last block whose bridge events are appended to the tree.
+    indexed_up_to: Option<u64>,
+    tree: LocalExitTree,
+    /// Hashes of the leaves appended after the checkpoint, from which the tree
+    /// is rebuilt as of the previous block of the witnesses.
+    leaves: Vec<Digest>,
+    /// Leaf count and root of the tree after the last leaf of each block after
+    /// the checkpoint.
+    roots: BTreeMap<u64, (u32, Digest)>,
+}
+
+impl LocalExitRootCheck {
+    /// Resumes the indexing from the checkpoint saved to the checkpoint file,
+    /// if any.
+    pub fn new(config: LocalExitRootCheckConfig) -> Result<Self, LocalExitRootCheckError> {
+        let checkpoint = config
+            .checkpoint_path
+            .as_deref()
+            .map(Checkpoint::load)
+            .transpose()?
+            .flatten();
+        if let Some(checkpoint) = &checkpoint {
+            info!(
+                block_number = checkpoint.block_number,
+                leaf_count = checkpoint.local_exit_tree.leaf_count,
+                "Resuming the indexing of the bridge events from the checkpoint"
+            );
+        }
+
+        Ok(Self {
+            config,
+            index: Mutex::new(Index::new(checkpoint)),
+        })
+    }
+
+    /// Whether the local exit roots are checked before proving.
+    pub fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Checks the local exit root of the l2 bridge at `block_number`, the
+    /// bridge events up to it being indexed beforehand.
+    pub async fn check<ContractsClient>(
+        &self,
+        contracts_client: &ContractsClient,
+        block_number: u64,
+        on_chain: Digest,
+    ) -> Result<(), LocalExitRootCheckError>
+    where
+        ContractsClient: L2BridgeExitsFetcher + L2LocalExitRootFetcher + Sync,
+    {
+        let mut index = self.index.lock().await;
+        index
+            .catch_up(contracts_client, &self.config, block_number)
+            .await?;
+
+        index
+            .verify_root(contracts_client, block_number, on_chain)
+            .await
+    }
+
+    /// Returns the local exit tree as of `prev_block` along with the bridge
+    /// exits emitted after it up to `new_block`, the bridge events up to
+    /// `new_block` being indexed beforehand. The local exit tree as of
+    /// `prev_block` is checked against `prev_local_exit_root`.
+    ///
+    /// Only the hashes of the indexed bridge exits are kept, hence the ones of
+    /// the block range are read again and matched against them.
+    pub async fn bridge_exits_witness<ContractsClient>(
+        &self,
+        contracts_client: &ContractsClient,
+        prev_block: u64,
+        prev_local_exit_root: Digest,
+        new_block: u64,
+    ) -> Result<BridgeExitsWitness, LocalExitRootCheckError>
+    where
+        ContractsClient: L2BridgeExitsFetcher + L2LocalExitRootFetcher + Sync,
+    {
+        let (prev_local_exit_tree, new_leaves) = {
+            let mut index = self.index.lock().await;
+            index
+                .catch_up(contracts_client, &self.config, new_block)
+                .await?;
+            index
+                .verify_root(contracts_client, prev_block, prev_local_exit_root)
+                .await?;
+
+            let prev_local_exit_tree = index.tree_at(prev_block)?;
+            let (new_leaf_count, _) = index.root_at(new_block)?;
+            let checkpoint_leaf_count = index.checkpoint_tree().leaf_count;
+            let start = (prev_local_exit_tree.leaf_count - checkpoint_leaf_count) as usize;
+            let end = (new_leaf_count - checkpoint_leaf_count) as usize;
+            let new_leaves = index.leaves[start..end].to_vec();
+            (prev_local_exit_tree, new_leaves)
+        };
+
+        let bridge_exits = futures::stream::iter(block_ranges(
+            prev_block + 1,
+            new_block,
+            self.config.max_block_range,
+        ))
+        .map(move |(from_block, range_end)| {
+            contracts_client.get_l2_bridge_exits(from_block, range_end)
+        })
+        .buffered(self.config.max_concurrent_reads.max(1))
+        .try_concat()
+        .await?;
+
+        let consistent = bridge_exits.len() == new_leaves.len()
+            && bridge_exits
+                .iter()
+                .zip(&new_leaves)
+                .zip(prev_local_exit_tree.leaf_count..)
+                .all(|((indexed, leaf), deposit_count)| {
+                    indexed.deposit_count == deposit_count && indexed.bridge_exit.hash() == *leaf
+                });
+        if !consistent {
+            self.index.lock().await.drop_from(prev_block + 1)?;
+            return Err(LocalExitRootCheckError::InconsistentBridgeEvents {
+                from_block: prev_block + 1,
+                to_block: new_block,
+            });
+        }
+
+        Ok(BridgeExitsWitness::new(
+            prev_local_exit_tree,
+            bridge_exits
+                .into_iter()
+                .map(|indexed| indexed.bridge_exit)
+                .collect(),
+        ))
+    }
+}
+
+/// Splits the blocks from `from_block` to `to_block` included in ranges of at
+/// most `max_block_range` blocks.
+fn block_ranges(mut from_block: u64, to_block: u64, max_block_range: u64) -> Vec<(u64, u64)> {
+    let max_block_range = max_block_range.max(1);
+
+    let mut ranges = Vec::new();
+    while from_block <= to_block {
+        let range_end = to_block.min(from_block.saturating_add(max_block_range - 1));
+        ranges.push((from_block, range_end));
+        from_block = range_end + 1;
+    }
+    ranges
+}
+
+impl Checkpoint {
+    /// Reads the checkpoint saved to `path`, none being saved yet if the file
+    /// does not exist.
+    fn load(path: &Path) -> Result<Option<Self>, LocalExitRootCheckError> {
+        let error = |source| LocalExitRootCheckError::Checkpoint {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        match std::fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map(Some)
+                .map_err(|source| error(source.into())),
+            Err(source) if source.kind() == ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(error(source)),
+        }
+    }
+
+    /// Writes the checkpoint to a temporary file renamed over `path`, for a
+    /// partially written checkpoint not to be read.
+    fn save(&self, path: &Path) -> Result<(), LocalExitRootCheckError> {
+        let error = |source| LocalExitRootCheckError::Checkpoint {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        let partial = path.with_extension("partial");
+        let bytes = serde_json::to_vec(self).expect("the checkpoint is serializable");
+        std::fs::write(&partial, bytes).map_err(error)?;
+        std::fs::rename(&partial, path).map_err(error)
+    }
+}
+
+impl Index {
+    fn new(checkpoint: Option<Checkpoint>) -> Self {
+        Self {
+            indexed_up_to: checkpoint
+                .as_ref()
+                .map(|checkpoint| checkpoint.block_number),
+            tree: checkpoint
+                .as_ref()
+                .map_or_else(LocalExitTree::new, |checkpoint| {
+                    checkpoint.local_exit_tree.clone()
+                }),
+            checkpoint,
+            leaves: Vec::new(),
+            roots: BTreeMap::new(),
+        }
+    }
+
+    async fn catch_up<ContractsClient>(
+        &mut self,
+        contracts_client: &ContractsClient,
+        config: &LocalExitRootCheckConfig,
+        to_block: u64,
+    ) -> Result<(), LocalExitRootCheckError>
+    where
+        ContractsClient: L2BridgeExitsFetcher + Sync,
+    {
+        let from_block = self
+            .indexed_up_to
+            .map_or(config.from_block, |indexed_up_to| indexed_up_to + 1);
+        let ranges = block_ranges(from_block, to_block, config.max_block_range);
+
+        // Yielded in the order of the ranges, whatever the order of the reads.
+        let mut reads = futures::stream::iter(ranges)
+            .map(move |(from_block, range_end)| {
+                contracts_client
+                    .get_l2_bridge_exits(from_block, range_end)
+                    .map_ok(move |bridge_exits| (range_end, bridge_exits))
+            })
+            .buffered(config.max_concurrent_reads.max(1));
+
+        while let Some(read) = reads.next().await {
+            let (range_end, bridge_exits) = read?;
+
+            // Appended to a copy, the index being left as is on failure.
+            let mut tree = self.tree.clone();
+            let mut leaves = Vec::new();
+            let mut roots = Vec::new();
+            for indexed in bridge_exits {
+                if indexed.deposit_count != tree.leaf_count {
+                    return Err(LocalExitRootCheckError::NonContiguousDeposit {
+                        block_number: indexed.block_number,
+                        expected: tree.leaf_count,
+                        deposit_count: indexed.deposit_count,
+                    });
+                }
+                let leaf = indexed.bridge_exit.hash();
+                tree.add_leaf(leaf)
+                    .map_err(LocalExitRootCheckError::LocalExitTree)?;
+                leaves.push(leaf);
+                roots.push((indexed.block_number, (tree.leaf_count, tree.get_root())));
+            }
+
+            self.tree = tree;
+            self.leaves.extend(leaves);
+            self.roots.extend(roots);
+            self.indexed_up_to = Some(range_end);
+            self.prune(config)?;
+        }
+
+        Ok(())
+    }
+
+    /// Folds the bridge events of the blocks before the retained ones into the
+    /// checkpoint, saved to the checkpoint file if any.
+    fn prune(&mut self, config: &LocalExitRootCheckConfig) -> Result<(), LocalExitRootCheckError> {
+        let Some(block_number) = self
+            .indexed_up_to
+            .and_then(|indexed_up_to| indexed_up_to.checked_sub(config.retained_blocks))
+        else {
+            return Ok(());
+        };
+        if self
+            .checkpoint
+            .as_ref()
+            .is_some_and(|checkpoint| checkpoint.block_number >= block_number)
+        {
+            return Ok(());
+        }
+
+        let local_exit_tree = self.tree_at(block_number)?;
+        let folded = local_exit_tree.leaf_count - self.checkpoint_tree().leaf_count;
+        let checkpoint = Checkpoint {
+            block_number,
+            local_exit_tree,
+        };
+        if let Some(path) = &config.checkpoint_path {
+            checkpoint.save(path)?;
+        }
+
+        self.leaves.drain(..folded as usize);
+        self.roots = self.roots.split_off(&(block_number + 1));
+        self.checkpoint = Some(checkpoint);
+
+        Ok(())
+    }
+
+    /// Drops the bridge events indexed from `from_block` on, down to the
+    /// checkpoint at most, for them to be read again.
+    fn drop_from(&mut self, from_block: u64) -> Result<(), LocalExitRootCheckError> {
+        let from_block = self.checkpoint.as_ref().map_or(from_block, |checkpoint| {
+            from_block.max(checkpoint.block_number + 1)
+        });
+        if !self
+            .indexed_up_to
+            .is_some_and(|indexed_up_to| indexed_up_to >= from_block)
+        {
+            return Ok(());
+        }
+
+        let dropped = self.roots.split_off(&from_block);
+        self.tree = match from_block.checked_sub(1) {
+            Some(block_number) => self.tree_at(block_number)?,
+            None => LocalExitTree::new(),
+        };
+        self.leaves
+            .truncate((self.tree.leaf_count - self.checkpoint_tree().leaf_count) as usize);
+        self.indexed_up_to = from_block.checked_sub(1);
+        warn!(
+            from_block,
+            dropped_blocks = dropped.len(),
+            "Dropped the indexed bridge events"
+        );
+
+        Ok(())
+    }
+
+    /// Local exit tree as of the checkpoint.
+    fn checkpoint_tree(&self) -> LocalExitTree {
+        self.checkpoint
+            .as_ref()
+            .map_or_else(LocalExitTree::new, |checkpoint| {
+                checkpoint.local_exit_tree.clone()
+            })
+    }
+
+    /// Leaf count and root of the tree as of `block_number`.
+    fn root_at(&self, block_number: u64) -> Result<(u32, Digest), LocalExitRootCheckError> {
+        if let Some(checkpoint) = &self.checkpoint {
+            if block_number < checkpoint.block_number {
+                return Err(LocalExitRootCheckError::Pruned {
+                    block_number,
+                    checkpoint_block: checkpoint.block_number,
+                });
+            }
+        }
+
+        Ok(self
+            .roots
+            .range(..=block_number)
+            .next_back()
+            .map(|(_, root)| *root)
+            .unwrap_or_else(|| {
+                let tree = self.checkpoint_tree();
+                (tree.leaf_count, tree.get_root())
+            }))
+    }
+
+    /// Local exit tree as of `block_number`.
+    fn tree_at(&self, block_number: u64) -> Result<LocalExitTree, LocalExitRootCheckError> {
+        let (leaf_count, _) = self.root_at(block_number)?;
+        let mut tree = self.checkpoint_tree();
+        for leaf in &self.leaves[..(leaf_count - tree.leaf_count) as usize] {
+            tree.add_leaf(*leaf)
+                .map_err(LocalExitRootCheckError::LocalExitTree)?;
+        }
+        Ok(tree)
+    }
+
+    /// Checks the root of the tree as of `block_number` against the one of the
+    /// bridge, the blocks from the first diverging one on being dropped from
+    /// the index on mismatch.
+    async fn verify_root<ContractsClient>(
+        &mut self,
+        contracts_client: &ContractsClient,
+        block_number: u64,
+        on_chain: Digest,
+    ) -> Result<(), LocalExitRootCheckError>
+    where
+        ContractsClient: L2LocalExitRootFetcher + Sync,
+    {
+        let (leaf_count, computed) = self.root_at(block_number)?;
+        if computed == on_chain {
+            debug!(block_number, leaf_count, "Local exit root checked");
+            return Ok(());
+        }
+
+        let (first_diverging_block, diverging_leaf_index) = self
+            .first_divergence(contracts_client, block_number)
+            .await?;
+        warn!(
+            block_number,
+            %computed,
+            %on_chain,
+            diverging_leaf_index,
+            "Local exit root differs from the bridge events"
+        );
+        if let Some(first_diverging_block) = first_diverging_block {
+            self.drop_from(first_diverging_block)?;
+        }
+
+        Err(LocalExitRootCheckError::Mismatch {
+            block_number,
+            computed,
+            on_chain,
+            diverging_leaf_index,
+        })
+    }
+
+    /// First block and first leaf whose root differs from the one of the
+    /// bridge, the roots matching up to a missing, extra or altered bridge
+    /// event and differing from it on.
+    async fn first_divergence<ContractsClient>(
+        &self,
+        contracts_client: &ContractsClient,
+        block_number: u64,
+    ) -> Result<(Option<u64>, u32), Error>
+    where
+        ContractsClient: L2LocalExitRootFetcher + Sync,
+    {
+        let roots = self
+            .roots
+            .range(..=block_number)
+            .map(|(block_number, root)| (*block_number, *root))
+            .collect::<Vec<_>>();
+
+        // First block whose root differs.
+        let (mut low, mut high) = (0, roots.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let (block_number, (_, root)) = roots[middle];
+            let on_chain = contracts_client
+                .get_l2_local_exit_root(block_number)
+                .await?;
+            if on_chain == root {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        // The first diverging leaf follows the ones of the last matching block.
+        let diverging_leaf_index =
+            low.checked_sub(1)
+                .map_or(self.checkpoint_tree().leaf_count, |matching| {
+                    let (_, (leaf_count, _)) = roots[matching];
+                    leaf_count
+                });
+        Ok((
+            roots.get(low).map(|(block_number, _)| *block_number),
+            diverging_leaf_index,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use agglayer_primitives::U256;
+    use unified_bridge::{BridgeExit, LeafType, NetworkId, TokenInfo};
+
+    use super::*;
+    use crate::contracts::IndexedBridgeExit;
+
+    fn indexed(block_number: u64, deposit_count: u32, amount: u64) -> IndexedBridgeExit {
+        IndexedBridgeExit {
+            block_number,
+            deposit_count,
+            bridge_exit: BridgeExit {
+                leaf_type: LeafType::Transfer,
+                token_info: TokenInfo {
+                    origin_network: NetworkId::new(0),
+                    origin_token_address: alloy::primitives::Address::ZERO.into(),
+                },
+                dest_network: NetworkId::new(1),
+                dest_address: alloy::primitives::Address::ZERO.into(),
+                amount: U256::from(amount),
+                metadata: None,
+            },
+        }
+    }
+
+    /// L2 bridge whose events, as read by the indexing, may differ from its
+    /// actual deposits.
+    struct FakeBridge {
+        deposits: Vec<IndexedBridgeExit>,
+        events: std::sync::Mutex<Vec<IndexedBridgeExit>>,
+        queried_ranges: std::sync::Mutex<Vec<(u64, u64)>>,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    impl FakeBridge {
+        fn new(deposits: Vec<IndexedBridgeExit>) -> Self {
+            Self {
+                events: std::sync::Mutex::new(deposits.clone()),
+                deposits,
+                queried_ranges: Default::default(),
+                in_flight: Default::default(),
+                max_in_flight: Default::default(),
+            }
+        }
+
+        fn root_at(&self, block_number: u64) -> Digest {
+            let mut tree = LocalExitTree::new();
+            for deposit in &self.deposits {
+                if deposit.block_number <= block_number {
+                    tree.add_leaf(deposit.bridge_exit.hash()).unwrap();
+                }
+            }
+            tree.get_root()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl L2BridgeExitsFetcher for FakeBridge {
+        async fn get_l2_bridge_exits(
+            &self,
+            from_block: u64,
+            to_block: u64,
+        ) -> Result<Vec<IndexedBridgeExit>, Error> {
+            self.queried_ranges
+                .lock()
+                .expect("fake bridge lock poisoned")
+                .push((from_block, to_block));
+
+            // Left pending once, the reads of the next ranges being issued
+            // meanwhile.
+            let in_flight = self.in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::Relaxed);
+            tokio::task::yield_now().await;
+            self.in_flight.fetch_sub(1, Ordering::Relaxed);
+
+            Ok(self
+                .events
+                .lock()
+                .expect("fake bridge lock poisoned")
+                .iter()
+                .filter(|event| (from_block..=to_block).contains(&event.block_number))
+                .cloned()
+                .collect())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl L2LocalExitRootFetcher for FakeBridge {
+        async fn get_l2_local_exit_root(&self, block_number: u64) -> Result<Digest, Error> {
+            Ok(self.root_at(block_number))
+        }
+    }
+
+    fn check(max_block_range: u64) -> LocalExitRootCheck {
+        LocalExitRootCheck::new(LocalExitRootCheckConfig {
+            from_block: 0,
+            max_block_range,
+            max_concurrent_reads: 1,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn bridge_events_are_indexed_incrementally() {
+        let bridge = FakeBridge::new(vec![
+            indexed(1, 0, 10),
+            indexed(1, 1, 11),
+            indexed(5, 2, 12),
+            indexed(12, 3, 13),
+        ]);
+        let check = check(4);
+
+        check.check(&bridge, 5, bridge.root_at(5)).await.unwrap();
+        check.check(&bridge, 12, bridge.root_at(12)).await.unwrap();
+        // The earlier blocks are served from the index.
+        check.check(&bridge, 3, bridge.root_at(3)).await.unwrap();
+        check.check(&bridge, 0, bridge.root_at(0)).await.unwrap();
+
+        assert_eq!(
+            *bridge
+                .queried_ranges
+                .lock()
+                .expect("fake bridge lock poisoned"),
+            [(0, 3), (4, 5), (6, 9), (10, 12)]
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_exits_witnesses_lead_to_the_new_roots() {
+        let bridge = FakeBridge::new(vec![
+            indexed(1, 0, 10),
+            indexed(1, 1, 11),
+            indexed(5, 2, 12),
+            indexed(12, 3, 13),
+        ]);
+        let check = check(4);
+
+        let witness = check
+            .bridge_exits_witness(&bridge, 1, bridge.root_at(1), 12)
+            .await
+            .unwrap();
+        assert_eq!(witness.prev_local_exit_tree.leaf_count, 2);
+        assert_eq!(witness.bridge_exits.len(), 2);
+        witness
+            .verify(bridge.root_at(1), bridge.root_at(12))
+            .unwrap();
+
+        // The tree is rebuilt as of a block before the last indexed one.
+        let witness = check
+            .bridge_exits_witness(&bridge, 0, bridge.root_at(0), 5)
+            .await
+            .unwrap();
+        assert_eq!(witness.prev_local_exit_tree.leaf_count, 0);
+        assert_eq!(witness.bridge_exits.len(), 3);
+        witness
+            .verify(bridge.root_at(0), bridge.root_at(5))
+            .unwrap();
+
+        // The bridge events read again differ from the indexed ones.
+        bridge.events.lock().expect("fake bridge lock poisoned")[2] = indexed(5, 2, 1);
+        let error = check
+            .bridge_exits_witness(&bridge, 1, bridge.root_at(1), 12)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                LocalExitRootCheckError::InconsistentBridgeEvents {
+                    from_block: 2,
+                    to_block: 12,
+                }
+            ),
+            "unexpected error {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn inconsistent_blocks_are_read_again() {
+        let bridge = FakeBridge::new(vec![
+            indexed(1, 0, 10),
+            indexed(1, 1, 11),
+            indexed(5, 2, 12),
+            indexed(12, 3, 13),
+        ]);
+        let check = check(100);
+        check.check(&bridge, 12, bridge.root_at(12)).await.unwrap();
+
+        // The bridge events of the block 5 change, e.g. upon a reorg.
+        let reorged = indexed(5, 2, 1);
+        bridge.events.lock().expect("fake bridge lock poisoned")[2] = reorged.clone();
+        check
+            .bridge_exits_witness(&bridge, 1, bridge.root_at(1), 12)
+            .await
+            .unwrap_err();
+
+        // The blocks after the previous one of the witness are indexed again.
+        let witness = check
+            .bridge_exits_witness(&bridge, 1, bridge.root_at(1), 12)
+            .await
+            .unwrap();
+        assert_eq!(witness.bridge_exits[0].hash(), reorged.bridge_exit.hash());
+        assert_eq!(
+            *bridge
+                .queried_ranges
+                .lock()
+                .expect("fake bridge lock poisoned"),
+            [(0, 12), (2, 12), (2, 12), (2, 12)]
+        );
+    }
+
+    #[tokio::test]
+    async fn older_blocks_are_folded_into_the_checkpoint() {
+        let checkpoint_path = std::env::temp_dir().join(format!(
+            "local-exit-tree-checkpoint-{}.json",
+            std::process::id()
+        ));
+        let config = LocalExitRootCheckConfig {
+            enabled: true,
+            from_block: 0,
+            max_block_range: 4,
+            max_concurrent_reads: 1,
+            retained_blocks: 5,
+            checkpoint_path: Some(checkpoint_path.clone()),
+        };
+        let bridge = FakeBridge::new(vec![
+            indexed(1, 0, 10),
+            indexed(1, 1, 11),
+            indexed(5, 2, 12),
+            indexed(12, 3, 13),
+        ]);
+
+        let check = LocalExitRootCheck::new(config.clone()).unwrap();
+        check.check(&bridge, 12, bridge.root_at(12)).await.unwrap();
+        {
+            let index = check.index.lock().await;
+            assert_eq!(index.leaves.len(), 1);
+            assert_eq!(index.roots.len(), 1);
+        }
+
+        let error = check
+            .bridge_exits_witness(&bridge, 1, bridge.root_at(1), 12)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                LocalExitRootCheckError::Pruned {
+                    block_number: 1,
+                    checkpoint_block: 7,
+                }
+            ),
+            "unexpected error {error:?}"
+        );
+        check
+            .bridge_exits_witness(&bridge, 7, bridge.root_at(7), 12)
+            .await
+            .unwrap()
+            .verify(bridge.root_at(7), bridge.root_at(12))
+            .unwrap();
+
+        // Resumed from the checkpoint after a restart.
+        bridge
+            .queried_ranges
+            .lock()
+            .expect("fake bridge lock poisoned")
+            .clear();
+        let check = LocalExitRootCheck::new(config).unwrap();
+        check.check(&bridge, 12, bridge.root_at(12)).await.unwrap();
+        assert_eq!(
+            *bridge
+                .queried_ranges
+                .lock()
+                .expect("fake bridge lock poisoned"),
+            [(8, 11), (12, 12)]
+        );
+
+        std::fs::remove_file(checkpoint_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn block_ranges_are_read_concurrently() {
+        let deposits = (0..20)
+            .map(|deposit_count| indexed(deposit_count as u64 * 3, deposit_count, 100))
+            .collect::<Vec<_>>();
+        let bridge = FakeBridge::new(deposits);
+        let check = LocalExitRootCheck::new(LocalExitRootCheckConfig {
+            from_block: 0,
+            max_block_range: 5,
+            max_concurrent_reads: 3,
+            ..Default::default()
+        })
+        .unwrap();
+
+        // Appended out of order, the deposits would not be contiguous.
+        check.check(&bridge, 59, bridge.root_at(59)).await.unwrap();
+
+        assert_eq!(
+            bridge
+                .queried_ranges
+                .lock()
+                .expect("fake bridge lock poisoned")
+                .len(),
+            12
+        );
+        assert_eq!(bridge.max_in_flight.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn mismatches_point_at_the_diverging_leaf() {
+        let deposits = (0..8)
+            .map(|deposit_count| indexed(deposit_count as u64 + 1, deposit_count, 100))
+            .collect::<Vec<_>>();
+        let bridge = FakeBridge::new(deposits);
+        // The amount of the fourth deposit is misread.
+        bridge.events.lock().expect("fake bridge lock poisoned")[3] = indexed(4, 3, 1);
+
+        let check = check(100);
+
+        let error = check
+            .check(&bridge, 8, bridge.root_at(8))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                LocalExitRootCheckError::Mismatch {
+                    block_number: 8,
+                    diverging_leaf_index: 3,
+                    ..
+                }
+            ),
+            "unexpected error {error:?}"
+        );
+
+        // The diverging blocks are read again.
+        bridge.events.lock().expect("fake bridge lock poisoned")[3] = indexed(4, 3, 100);
+        check.check(&bridge, 8, bridge.root_at(8)).await.unwrap();
+        assert_eq!(
+            *bridge
+                .queried_ranges
+                .lock()
+                .expect("fake bridge lock poisoned"),
+            [(0, 8), (4, 8)]
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_bridge_events_are_reported() {
+        let bridge = FakeBridge::new(vec![
+            indexed(1, 0, 10),
+            indexed(2, 1, 11),
+            indexed(3, 2, 12),
+        ]);
+        let missing = bridge
+            .events
+            .lock()
+            .expect("fake bridge lock poisoned")
+            .remove(1);
+        let check = check(100);
+
+        let error = check
+            .check(&bridge, 3, bridge.root_at(3))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                LocalExitRootCheckError::NonContiguousDeposit {
+                    block_number: 3,
+                    expected: 1,
+                    deposit_count: 2,
+                }
+            ),
+            "unexpected error {error:?}"
+        );
+
+        // Nothing is indexed from the faulty range, which is read again.
+        bridge
+            .events
+            .lock()
+            .expect("fake bridge lock poisoned")
+            .insert(1, missing);
+        check.check(&bridge, 3, bridge.root_at(3)).await.unwrap();
+    }
+}
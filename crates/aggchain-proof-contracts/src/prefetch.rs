@@ -0,0 +1,398 @@
+//! Contracts client serving the chain data fetched ahead of the requests, such
+//! that the data of an expected block range is ready when its aggchain proof
+//! is requested.
+//!
+//! Each prefetched value is served once, the other calls being forwarded to
+//! the inner client.
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+use agglayer_interop::types::Digest;
+use agglayer_primitives::Address;
+use alloy::eips::BlockNumberOrTag;
+use prover_alloy::BlockFinality;
+use sp1_cc_client_executor::io::EvmSketchInput;
+use tracing::debug;
+
+use crate::{
+    contracts::{
+        GetTrustedSequencerAddress, IndexedBridgeExit, IndexedL1InfoTreeLeaf,
+        L1GlobalExitRootFetcher, L1InfoTreeLeavesFetcher, L1RollupConfigHashFetcher,
+        L1SettledBlockFetcher, L2BridgeExitsFetcher, L2EvmStateSketchFetcher, L2HeadFetcher,
+        L2LocalExitRootFetcher, L2OutputAtBlock, L2OutputAtBlockFetcher,
+    },
+    AggchainContractsClient, Error,
+};
+
+/// Chain data prefetched, keyed by l2 block number.
+#[derive(Default)]
+struct Prefetched {
+    local_exit_roots: BTreeMap<u64, Digest>,
+    outputs: BTreeMap<u64, L2OutputAtBlock>,
+    prev_sketches: BTreeMap<u64, EvmSketchInput>,
+    new_sketches: BTreeMap<u64, EvmSketchInput>,
+}
+
+impl Prefetched {
+    fn prune(&mut self, before: u64) {
+        self.local_exit_roots = self.local_exit_roots.split_off(&before);
+        self.outputs = self.outputs.split_off(&before);
+        self.prev_sketches = self.prev_sketches.split_off(&before);
+        self.new_sketches = self.new_sketches.split_off(&before);
+    }
+}
+
+pub struct PrefetchingClient<ContractsClient> {
+    inner: Arc<ContractsClient>,
+    prefetched: Mutex<Prefetched>,
+}
+
+impl<ContractsClient> PrefetchingClient<ContractsClient> {
+    pub fn new(inner: Arc<ContractsClient>) -> Self {
+        Self {
+            inner,
+            prefetched: Mutex::default(),
+        }
+    }
+
+    pub fn inner(&self) -> &Arc<ContractsClient> {
+        &self.inner
+    }
+
+    /// Drops the data prefetched for the blocks before `before`, which are
+    /// no longer expected to be requested.
+    pub fn prune(&self, before: u64) {
+        self.prefetched
+            .lock()
+            .expect("prefetch lock poisoned")
+            .prune(before);
+    }
+
+    /// Keeps the data fetched successfully, returning the first failure.
+    fn store(
+        &self,
+        block_number: u64,
+        local_exit_root: Result<Digest, Error>,
+        output: Result<L2OutputAtBlock, Error>,
+        sketch: Result<EvmSketchInput, Error>,
+        sketches: impl FnOnce(&mut Prefetched) -> &mut BTreeMap<u64, EvmSketchInput>,
+    ) -> Result<(), Error> {
+        let mut prefetched = self.prefetched.lock().expect("prefetch lock poisoned");
+        let local_exit_root =
+            local_exit_root.map(|value| prefetched.local_exit_roots.insert(block_number, value));
+        let output = output.map(|value| prefetched.outputs.insert(block_number, value));
+        let sketch = sketch.map(|value| sketches(&mut *prefetched).insert(block_number, value));
+
+        local_exit_root.and(output).and(sketch).map(|_| ())
+    }
+}
+
+impl<ContractsClient> PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L2LocalExitRootFetcher + L2OutputAtBlockFetcher + L2EvmStateSketchFetcher,
+{
+    /// Prefetches the data of the last proven block of a range, keeping the
+    /// data fetched successfully if any of it fails.
+    pub async fn prefetch_range_start(&self, block_number: u64) -> Result<(), Error> {
+        debug!(
+            block_number,
+            "Prefetching the chain data of the range start"
+        );
+        let (local_exit_root, output, sketch) = tokio::join!(
+            self.inner.get_l2_local_exit_root(block_number),
+            self.inner.get_l2_output_at_block(block_number),
+            self.inner
+                .get_prev_l2_block_sketch(BlockNumberOrTag::Number(block_number)),
+        );
+
+        self.store(
+            block_number,
+            local_exit_root,
+            output,
+            sketch,
+            |prefetched| &mut prefetched.prev_sketches,
+        )
+    }
+
+    /// Prefetches the data of the end block of a range, keeping the data
+    /// fetched successfully if any of it fails.
+    pub async fn prefetch_range_end(&self, block_number: u64) -> Result<(), Error> {
+        debug!(block_number, "Prefetching the chain data of the range end");
+        let (local_exit_root, output, sketch) = tokio::join!(
+            self.inner.get_l2_local_exit_root(block_number),
+            self.inner.get_l2_output_at_block(block_number),
+            self.inner
+                .get_new_l2_block_sketch(BlockNumberOrTag::Number(block_number)),
+        );
+
+        self.store(
+            block_number,
+            local_exit_root,
+            output,
+            sketch,
+            |prefetched| &mut prefetched.new_sketches,
+        )
+    }
+}
+
+impl<ContractsClient> AggchainContractsClient for PrefetchingClient<ContractsClient> where
+    ContractsClient: AggchainContractsClient + Send + Sync
+{
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L2LocalExitRootFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L2LocalExitRootFetcher + Send + Sync,
+{
+    async fn get_l2_local_exit_root(&self, block_number: u64) -> Result<Digest, Error> {
+        let prefetched = self
+            .prefetched
+            .lock()
+            .expect("prefetch lock poisoned")
+            .local_exit_roots
+            .remove(&block_number);
+        match prefetched {
+            Some(local_exit_root) => {
+                debug!(block_number, "Serving the prefetched local exit root");
+                Ok(local_exit_root)
+            }
+            None => self.inner.get_l2_local_exit_root(block_number).await,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L2BridgeExitsFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L2BridgeExitsFetcher + Send + Sync,
+{
+    async fn get_l2_bridge_exits(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedBridgeExit>, Error> {
+        self.inner.get_l2_bridge_exits(from_block, to_block).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L2OutputAtBlockFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L2OutputAtBlockFetcher + Send + Sync,
+{
+    async fn get_l2_output_at_block(&self, block_number: u64) -> Result<L2OutputAtBlock, Error> {
+        let prefetched = self
+            .prefetched
+            .lock()
+            .expect("prefetch lock poisoned")
+            .outputs
+            .remove(&block_number);
+        match prefetched {
+            Some(output) => {
+                debug!(block_number, "Serving the prefetched l2 output");
+                Ok(output)
+            }
+            None => self.inner.get_l2_output_at_block(block_number).await,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L2EvmStateSketchFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L2EvmStateSketchFetcher + Send + Sync,
+{
+    async fn get_prev_l2_block_sketch(
+        &self,
+        prev_l2_block: BlockNumberOrTag,
+    ) -> Result<EvmSketchInput, Error> {
+        let prefetched = prev_l2_block.as_number().and_then(|block_number| {
+            self.prefetched
+                .lock()
+                .expect("prefetch lock poisoned")
+                .prev_sketches
+                .remove(&block_number)
+        });
+        match prefetched {
+            Some(sketch) => {
+                debug!(%prev_l2_block, "Serving the prefetched prev l2 block sketch");
+                Ok(sketch)
+            }
+            None => self.inner.get_prev_l2_block_sketch(prev_l2_block).await,
+        }
+    }
+
+    async fn get_new_l2_block_sketch(
+        &self,
+        new_l2_block: BlockNumberOrTag,
+    ) -> Result<EvmSketchInput, Error> {
+        let prefetched = new_l2_block.as_number().and_then(|block_number| {
+            self.prefetched
+                .lock()
+                .expect("prefetch lock poisoned")
+                .new_sketches
+                .remove(&block_number)
+        });
+        match prefetched {
+            Some(sketch) => {
+                debug!(%new_l2_block, "Serving the prefetched new l2 block sketch");
+                Ok(sketch)
+            }
+            None => self.inner.get_new_l2_block_sketch(new_l2_block).await,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L1RollupConfigHashFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L1RollupConfigHashFetcher + Send + Sync,
+{
+    async fn get_rollup_config_hash(&self) -> Result<Digest, Error> {
+        self.inner.get_rollup_config_hash().await
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L1GlobalExitRootFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L1GlobalExitRootFetcher + Send + Sync,
+{
+    async fn get_l1_global_exit_root_timestamp(&self, ger: Digest) -> Result<u64, Error> {
+        self.inner.get_l1_global_exit_root_timestamp(ger).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L1InfoTreeLeavesFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L1InfoTreeLeavesFetcher + Send + Sync,
+{
+    async fn get_l1_head(&self) -> Result<u64, Error> {
+        self.inner.get_l1_head().await
+    }
+
+    async fn get_l1_info_tree_leaves(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedL1InfoTreeLeaf>, Error> {
+        self.inner
+            .get_l1_info_tree_leaves(from_block, to_block)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L1SettledBlockFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L1SettledBlockFetcher + Send + Sync,
+{
+    async fn get_l1_settled_block(&self) -> Result<u64, Error> {
+        self.inner.get_l1_settled_block().await
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> GetTrustedSequencerAddress for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: GetTrustedSequencerAddress + Send + Sync,
+{
+    async fn get_trusted_sequencer_address(&self) -> Result<Address, Error> {
+        self.inner.get_trusted_sequencer_address().await
+    }
+}
+
+#[async_trait::async_trait]
+impl<ContractsClient> L2HeadFetcher for PrefetchingClient<ContractsClient>
+where
+    ContractsClient: L2HeadFetcher + Send + Sync,
+{
+    async fn get_l2_head(&self, finality: BlockFinality) -> Result<u64, Error> {
+        self.inner.get_l2_head(finality).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Client counting the local exit roots fetched, the other data being
+    /// unavailable.
+    #[derive(Default)]
+    struct CountingClient {
+        local_exit_roots: AtomicUsize,
+    }
+
+    fn unavailable() -> Error {
+        Error::InvalidEvmSketchGenesisInput("unavailable".to_string())
+    }
+
+    #[async_trait::async_trait]
+    impl L2LocalExitRootFetcher for CountingClient {
+        async fn get_l2_local_exit_root(&self, block_number: u64) -> Result<Digest, Error> {
+            self.local_exit_roots.fetch_add(1, Ordering::SeqCst);
+            Ok(Digest([block_number as u8; 32]))
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl L2OutputAtBlockFetcher for CountingClient {
+        async fn get_l2_output_at_block(&self, _: u64) -> Result<L2OutputAtBlock, Error> {
+            Err(unavailable())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl L2EvmStateSketchFetcher for CountingClient {
+        async fn get_prev_l2_block_sketch(
+            &self,
+            _: BlockNumberOrTag,
+        ) -> Result<EvmSketchInput, Error> {
+            Err(unavailable())
+        }
+
+        async fn get_new_l2_block_sketch(
+            &self,
+            _: BlockNumberOrTag,
+        ) -> Result<EvmSketchInput, Error> {
+            Err(unavailable())
+        }
+    }
+
+    #[tokio::test]
+    async fn prefetched_data_is_served_once() {
+        let client = PrefetchingClient::new(Arc::new(CountingClient::default()));
+
+        // The data fetched successfully is kept despite the failures.
+        assert!(client.prefetch_range_end(10).await.is_err());
+        assert_eq!(client.inner().local_exit_roots.load(Ordering::SeqCst), 1);
+
+        assert_eq!(
+            client.get_l2_local_exit_root(10).await.unwrap(),
+            Digest([10; 32])
+        );
+        assert_eq!(client.inner().local_exit_roots.load(Ordering::SeqCst), 1);
+
+        client.get_l2_local_exit_root(10).await.unwrap();
+        assert_eq!(client.inner().local_exit_roots.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn data_before_the_range_is_pruned() {
+        let client = PrefetchingClient::new(Arc::new(CountingClient::default()));
+        assert!(client.prefetch_range_start(10).await.is_err());
+        assert!(client.prefetch_range_end(20).await.is_err());
+
+        client.prune(20);
+        client.get_l2_local_exit_root(10).await.unwrap();
+        client.get_l2_local_exit_root(20).await.unwrap();
+
+        assert_eq!(client.inner().local_exit_roots.load(Ordering::SeqCst), 3);
+    }
+}
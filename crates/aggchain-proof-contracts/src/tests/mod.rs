@@ -5,19 +5,24 @@ mod aggchain_contracts_rpc_client {
     use agglayer_primitives::{address, Address};
     use alloy::{
         hex::{self, FromHex},
-        primitives::B256,
-        sol_types::{SolCall, SolValue},
+        primitives::{keccak256, LogData, B256, U256},
+        sol_types::{SolCall, SolEvent, SolValue},
     };
     use mockito::ServerGuard;
-    use prover_alloy::{AlloyFillProvider, L1RpcEndpoint};
+    use prover_alloy::{AlloyFillProvider, BlockFinality, L1RpcEndpoint};
     use serde_json::json;
     use url::Url;
 
     use crate::{
         config::AggchainProofContractsConfig,
         contracts::{
-            AggchainFep::trustedSequencerCall, L1RollupConfigHashFetcher, L2LocalExitRootFetcher,
-            L2OutputAtBlockFetcher,
+            AggchainFep::trustedSequencerCall,
+            GlobalExitRootManagerL1::{
+                globalExitRootMapCall, UpdateL1InfoTree, UpdateL1InfoTreeV2,
+            },
+            IndexedL1InfoTreeLeaf, L1GlobalExitRootFetcher, L1InfoTreeLeavesFetcher,
+            L1RollupConfigHashFetcher, L2LocalExitRootFetcher, L2OutputAtBlockFetcher,
+            PolygonRollupManager::globalExitRootManagerCall,
         },
         AggchainContractsRpcClient,
     };
@@ -106,7 +111,10 @@ mod aggchain_contracts_rpc_client {
         let mock_server_l2_cl_url = Url::parse(&server_l2_cl.url()).unwrap();
         let config = AggchainProofContractsConfig {
             l1_rpc_endpoint: mock_server_l1_url,
+            l1_rpc_fallback_endpoints: Vec::new(),
+            l1_block_finality: BlockFinality::Finalized,
             l2_execution_layer_rpc_endpoint: mock_server_l2_el_url,
+            l2_execution_layer_rpc_fallback_endpoints: Vec::new(),
             l2_consensus_layer_rpc_endpoint: mock_server_l2_cl_url,
             polygon_rollup_manager: address!("0x9a676e781a523b5d0c0e43731313a708cb607508"),
             global_exit_root_manager_v2_sovereign_chain: address!(
@@ -131,7 +139,7 @@ mod aggchain_contracts_rpc_client {
         ))
     }
 
-    fn mock_get_rollup_config_hash(server_l1: &mut ServerGuard) -> mockito::Mock {
+    fn mock_get_rollup_config_hash(server_l1: &mut ServerGuard, block_tag: &str) -> mockito::Mock {
         let rollup_config_hash_expected_body = serde_json::json!(
         {
             "method":"eth_call",
@@ -140,7 +148,7 @@ mod aggchain_contracts_rpc_client {
                     "to": "0x8e80ffe6dc044f4a766afd6e5a8732fe0977a493",
                     "input": format!("0x{}", hex::encode(crate::contracts::AggchainFep::rollupConfigHashCall{}.abi_encode())),
                 },
-                "latest"
+                block_tag
             ],
             "id": 2,
             "jsonrpc":"2.0",
@@ -193,6 +201,35 @@ mod aggchain_contracts_rpc_client {
             .create()
     }
 
+    /// The global exit root manager of the l1 is resolved once, from the
+    /// rollup manager.
+    fn mock_global_exit_root_manager(server_l1: &mut ServerGuard) -> mockito::Mock {
+        server_l1
+            .mock("POST", "/")
+            .with_status(200)
+            .with_header("content-type", "text/javascript")
+            .match_body(mockito::Matcher::Json(json!({
+                "method": "eth_call",
+                "params": [{
+                    "to": "0x9a676e781a523b5d0c0e43731313a708cb607508",
+                    "input": format!("0x{}", hex::encode(globalExitRootManagerCall {}.abi_encode())),
+                },
+                "latest"],
+                "id": 2,
+                "jsonrpc": "2.0",
+            })))
+            .with_body(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 2,
+                    "result": "0x000000000000000000000000580bda1e7a0cfae92fa7f6c20a3794f169ce3cfb"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create()
+    }
+
     #[test]
     fn parsing_l2_output_root() -> Result<(), Box<dyn std::error::Error>> {
         let json_l2_output_root_str = include_str!("parsing_l2_output_root.json");
@@ -257,7 +294,10 @@ mod aggchain_contracts_rpc_client {
         let mock_server_l2_url = Url::parse(&server_l2.url())?;
         let config = AggchainProofContractsConfig {
             l1_rpc_endpoint: L1RpcEndpoint::from(dummy_url()),
+            l1_rpc_fallback_endpoints: Vec::new(),
+            l1_block_finality: BlockFinality::Finalized,
             l2_execution_layer_rpc_endpoint: mock_server_l2_url.clone(),
+            l2_execution_layer_rpc_fallback_endpoints: Vec::new(),
             l2_consensus_layer_rpc_endpoint: dummy_url(),
             polygon_rollup_manager: dummy_address(),
             global_exit_root_manager_v2_sovereign_chain: dummy_address(),
@@ -376,7 +416,7 @@ mod aggchain_contracts_rpc_client {
         let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
         let mut server_l1 = test_servers.server_l1;
 
-        let mock_l1 = mock_get_rollup_config_hash(&mut server_l1);
+        let mock_l1 = mock_get_rollup_config_hash(&mut server_l1, "finalized");
         let result = contracts_client.get_rollup_config_hash().await;
 
         mock_l1.assert_async().await;
@@ -395,6 +435,168 @@ mod aggchain_contracts_rpc_client {
         Ok(())
     }
 
+    #[test_log::test(tokio::test)]
+    async fn get_rollup_config_hash_finality_override() -> Result<(), Box<dyn std::error::Error>> {
+        let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
+        let mut server_l1 = test_servers.server_l1;
+
+        let mock_l1 = mock_get_rollup_config_hash(&mut server_l1, "latest");
+        let result = contracts_client
+            .get_rollup_config_hash_at(Some(BlockFinality::Latest))
+            .await;
+
+        mock_l1.assert_async().await;
+        assert!(result.is_ok());
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn get_l1_global_exit_root_timestamp() -> Result<(), Box<dyn std::error::Error>> {
+        let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
+        let mut server_l1 = test_servers.server_l1;
+        let (found, missing) = (Digest([0xab; 32]), Digest([0xcd; 32]));
+
+        let mock_manager = mock_global_exit_root_manager(&mut server_l1);
+
+        let mut mock_ger = |id: u64, ger: Digest, timestamp: u64| {
+            let input = globalExitRootMapCall {
+                globalExitRoot: ger.0.into(),
+            }
+            .abi_encode();
+            server_l1
+                .mock("POST", "/")
+                .with_status(200)
+                .with_header("content-type", "text/javascript")
+                .match_body(mockito::Matcher::Json(json!({
+                    "method": "eth_call",
+                    "params": [{
+                        "to": "0x580bda1e7a0cfae92fa7f6c20a3794f169ce3cfb",
+                        "input": format!("0x{}", hex::encode(input)),
+                    },
+                    "finalized"],
+                    "id": id,
+                    "jsonrpc": "2.0",
+                })))
+                .with_body(
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "result": format!("0x{timestamp:064x}"),
+                    })
+                    .to_string(),
+                )
+                .create()
+        };
+        let mock_found = mock_ger(3, found, 1_700_000_000);
+        let mock_missing = mock_ger(4, missing, 0);
+
+        assert_eq!(
+            contracts_client
+                .get_l1_global_exit_root_timestamp(found)
+                .await?,
+            1_700_000_000
+        );
+        assert_eq!(
+            contracts_client
+                .get_l1_global_exit_root_timestamp(missing)
+                .await?,
+            0
+        );
+
+        mock_manager.assert_async().await;
+        mock_found.assert_async().await;
+        mock_missing.assert_async().await;
+
+        Ok(())
+    }
+
+    #[test_log::test(tokio::test)]
+    async fn get_l1_info_tree_leaves() -> Result<(), Box<dyn std::error::Error>> {
+        let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
+        let mut server_l1 = test_servers.server_l1;
+        let (mer, rer, root) = (
+            B256::repeat_byte(1),
+            B256::repeat_byte(2),
+            B256::repeat_byte(3),
+        );
+        let block_hash = U256::from(0xabcd);
+
+        let mock_manager = mock_global_exit_root_manager(&mut server_l1);
+        let mut mock_logs = |id: u64, log_index: u64, log_data: LogData| {
+            let signature = log_data.topics()[0];
+            server_l1
+                .mock("POST", "/")
+                .with_status(200)
+                .with_header("content-type", "text/javascript")
+                .match_body(mockito::Matcher::Regex(format!("eth_getLogs.*{signature}")))
+                .with_body(
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "result": [{
+                            "address": "0x580bda1e7a0cfae92fa7f6c20a3794f169ce3cfb",
+                            "topics": log_data.topics(),
+                            "data": log_data.data,
+                            "blockNumber": "0x64",
+                            "blockHash": B256::repeat_byte(4),
+                            "transactionHash": B256::repeat_byte(5),
+                            "transactionIndex": "0x0",
+                            "logIndex": format!("0x{log_index:x}"),
+                            "removed": false,
+                        }],
+                    })
+                    .to_string(),
+                )
+                .create()
+        };
+        let mock_exit_roots = mock_logs(
+            3,
+            7,
+            UpdateL1InfoTree {
+                mainnetExitRoot: mer,
+                rollupExitRoot: rer,
+            }
+            .encode_log_data(),
+        );
+        let mock_leaves = mock_logs(
+            4,
+            8,
+            UpdateL1InfoTreeV2 {
+                currentL1InfoRoot: root,
+                leafCount: 5,
+                blockhash: block_hash,
+                minTimestamp: 1_700_000_000,
+            }
+            .encode_log_data(),
+        );
+
+        let leaves = contracts_client.get_l1_info_tree_leaves(90, 110).await?;
+
+        assert_eq!(leaves.len(), 1);
+        let IndexedL1InfoTreeLeaf {
+            block_number,
+            leaf,
+            root: leaf_root,
+        } = &leaves[0];
+        assert_eq!(*block_number, 100);
+        assert_eq!(*leaf_root, Digest(root.0));
+        assert_eq!(leaf.l1_info_tree_index, 4);
+        assert_eq!((leaf.mer, leaf.rer), (Digest(mer.0), Digest(rer.0)));
+        assert_eq!(
+            leaf.inner.global_exit_root,
+            Digest(keccak256([mer.0, rer.0].concat()).0)
+        );
+        assert_eq!(leaf.inner.block_hash, Digest(block_hash.to_be_bytes()));
+        assert_eq!(leaf.inner.timestamp, 1_700_000_000);
+
+        mock_manager.assert_async().await;
+        mock_exit_roots.assert_async().await;
+        mock_leaves.assert_async().await;
+
+        Ok(())
+    }
+
     #[test_log::test(tokio::test)]
     async fn get_l2_output_at_block() -> Result<(), Box<dyn std::error::Error>> {
         let (contracts_client, test_servers) = aggchain_contracts_rpc_client().await?;
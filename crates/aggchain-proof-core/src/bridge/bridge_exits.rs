@@ -0,0 +1,154 @@
+use std::borrow::Borrow;
+
+use agglayer_primitives::Digest;
+use serde::{Deserialize, Serialize};
+use unified_bridge::{BridgeExit, LocalExitTree, LocalExitTreeError};
+
+use super::BridgeConstraintsError;
+use crate::cycles::{self, MerkleProofKind};
+
+/// Data to verify the bridge exits emitted within the block range against the
+/// previous and new local exit roots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BridgeExitsWitness {
+    /// Local exit tree as of the previous L2 block.
+    pub prev_local_exit_tree: LocalExitTree,
+    /// Bridge exits emitted within the block range, in insertion order.
+    pub bridge_exits: Vec<BridgeExit>,
+}
+
+impl BridgeExitsWitness {
+    /// Builds the witness from the local exit tree as of the previous L2 block
+    /// and the bridge exits emitted within the block range.
+    pub fn new(prev_local_exit_tree: LocalExitTree, bridge_exits: Vec<BridgeExit>) -> Self {
+        Self {
+            prev_local_exit_tree,
+            bridge_exits,
+        }
+    }
+
+    /// Returns the local exit root resulting from appending the bridge exits
+    /// to the previous local exit tree.
+    pub fn compute_new_local_exit_root(&self) -> Result<Digest, BridgeConstraintsError> {
+        let mut local_exit_tree = self.prev_local_exit_tree.clone();
+        append_bridge_exits(&mut local_exit_tree, &self.bridge_exits)
+            .map_err(BridgeConstraintsError::LocalExitTree)?;
+
+        Ok(local_exit_tree.get_root())
+    }
+
+    /// Verify that the previous local exit tree matches the previous local
+    /// exit root, and that appending the bridge exits leads to the new local
+    /// exit root.
+    pub fn verify(
+        &self,
+        prev_local_exit_root: Digest,
+        new_local_exit_root: Digest,
+    ) -> Result<(), BridgeConstraintsError> {
+        let prev_root = self.prev_local_exit_tree.get_root();
+        if prev_root != prev_local_exit_root {
+            return Err(BridgeConstraintsError::MismatchPrevLocalExitTree {
+                computed: prev_root,
+                input: prev_local_exit_root,
+            });
+        }
+
+        let computed = self.compute_new_local_exit_root()?;
+        if computed != new_local_exit_root {
+            return Err(BridgeConstraintsError::MismatchBridgeExits {
+                computed,
+                input: new_local_exit_root,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends the bridge exits to the local exit tree as they come, e.g. read one
+/// by one from the event log of the bridge over a large range.
+///
+/// The tree only keeps its frontier, hence the memory used does not depend on
+/// the number of bridge exits.
+pub fn append_bridge_exits<B: Borrow<BridgeExit>>(
+    local_exit_tree: &mut LocalExitTree,
+    bridge_exits: impl IntoIterator<Item = B>,
+) -> Result<(), LocalExitTreeError> {
+    for bridge_exit in bridge_exits {
+        cycles::track(MerkleProofKind::BridgeExit, || {
+            local_exit_tree.add_leaf(bridge_exit.borrow().hash())
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_primitives::U256;
+    use unified_bridge::{LeafType, NetworkId, TokenInfo};
+
+    use super::*;
+
+    fn bridge_exit(amount: u64) -> BridgeExit {
+        BridgeExit {
+            leaf_type: LeafType::Transfer,
+            token_info: TokenInfo {
+                origin_network: NetworkId::new(0),
+                origin_token_address: alloy_primitives::Address::ZERO.into(),
+            },
+            dest_network: NetworkId::new(1),
+            dest_address: alloy_primitives::Address::ZERO.into(),
+            amount: U256::from(amount),
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn bridge_exits_lead_to_new_local_exit_root() {
+        let mut prev_local_exit_tree = LocalExitTree::new();
+        prev_local_exit_tree.add_leaf(bridge_exit(1).hash()).unwrap();
+        let prev_local_exit_root = prev_local_exit_tree.get_root();
+
+        let mut new_local_exit_tree = prev_local_exit_tree.clone();
+        new_local_exit_tree.add_leaf(bridge_exit(2).hash()).unwrap();
+        new_local_exit_tree.add_leaf(bridge_exit(3).hash()).unwrap();
+        let new_local_exit_root = new_local_exit_tree.get_root();
+
+        let witness =
+            BridgeExitsWitness::new(prev_local_exit_tree, vec![bridge_exit(2), bridge_exit(3)]);
+
+        witness
+            .verify(prev_local_exit_root, new_local_exit_root)
+            .unwrap();
+
+        // Wrong order of the bridge exits
+        let reordered = BridgeExitsWitness {
+            bridge_exits: vec![bridge_exit(3), bridge_exit(2)],
+            ..witness.clone()
+        };
+        assert!(matches!(
+            reordered.verify(prev_local_exit_root, new_local_exit_root),
+            Err(BridgeConstraintsError::MismatchBridgeExits { .. })
+        ));
+
+        // Wrong previous local exit root
+        assert!(matches!(
+            witness.verify(new_local_exit_root, new_local_exit_root),
+            Err(BridgeConstraintsError::MismatchPrevLocalExitTree { .. })
+        ));
+    }
+
+    #[test]
+    fn bridge_exits_are_appended_as_they_come() {
+        let mut expected = LocalExitTree::new();
+        for amount in 0..100 {
+            expected.add_leaf(bridge_exit(amount).hash()).unwrap();
+        }
+
+        let mut local_exit_tree = LocalExitTree::new();
+        append_bridge_exits(&mut local_exit_tree, (0..100).map(bridge_exit)).unwrap();
+
+        assert_eq!(local_exit_tree.get_root(), expected.get_root());
+    }
+}
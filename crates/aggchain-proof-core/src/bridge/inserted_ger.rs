@@ -2,6 +2,9 @@ use agglayer_primitives::Digest;
 use serde::{Deserialize, Serialize};
 use unified_bridge::{L1InfoTreeLeaf, MerkleProof};
 
+use super::BridgeConstraintsError;
+use crate::cycles::{self, MerkleProofKind};
+
 /// Data to verify the legitimacy of one inserted GER.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InsertedGER {
@@ -23,10 +26,12 @@ impl InsertedGER {
             return false;
         }
 
-        self.proof.verify(
-            self.l1_info_tree_leaf.hash(),
-            self.l1_info_tree_leaf.l1_info_tree_index,
-        )
+        cycles::track(MerkleProofKind::InsertedGer, || {
+            self.proof.verify(
+                self.l1_info_tree_leaf.hash(),
+                self.l1_info_tree_leaf.l1_info_tree_index,
+            )
+        })
     }
 
     /// Returns the inserted GER.
@@ -34,3 +39,19 @@ impl InsertedGER {
         self.l1_info_tree_leaf.ger()
     }
 }
+
+/// Verify the inclusion proofs of the inserted GERs against one L1 info root,
+/// both in-guest and on the host before proving.
+pub fn verify_inclusion_proofs(
+    inserted_gers: &[InsertedGER],
+    l1_info_root: Digest,
+) -> Result<(), BridgeConstraintsError> {
+    match inserted_gers.iter().find(|ger| !ger.verify(l1_info_root)) {
+        Some(wrong_ger) => Err(BridgeConstraintsError::InvalidMerklePathGERToL1Root {
+            inserted_ger: wrong_ger.ger(),
+            l1_info_leaf_index: wrong_ger.l1_info_tree_leaf.l1_info_tree_index,
+            l1_info_root,
+        }),
+        None => Ok(()),
+    }
+}
@@ -1,17 +1,21 @@
 //! A program that verifies the bridge integrity
 use std::{collections::HashMap, hash::Hash};
 
-use agglayer_primitives::{address, keccak::keccak256_combine, Address, Digest, U256};
+use agglayer_primitives::{address, Address, Digest, U256};
 use alloy_sol_macro::sol;
 use alloy_sol_types::SolCall;
+use bridge_exits::BridgeExitsWitness;
 use inserted_ger::InsertedGER;
 use serde::{Deserialize, Serialize};
 use sp1_cc_client_executor::io::EvmSketchInput;
 use static_call::{HashChainType, StaticCallError, StaticCallStage, StaticCallWithContext};
-use unified_bridge::{GlobalIndexWithLeafHash, ImportedBridgeExitCommitmentValues};
+use unified_bridge::{
+    GlobalIndexWithLeafHash, ImportedBridgeExitCommitmentValues, LocalExitTreeError,
+};
 
-use crate::proof::IMPORTED_BRIDGE_EXIT_COMMITMENT_VERSION;
+use crate::{keccak::keccak256_combine, proof::IMPORTED_BRIDGE_EXIT_COMMITMENT_VERSION};
 
+pub mod bridge_exits;
 pub mod inserted_ger;
 pub mod static_call;
 
@@ -75,6 +79,20 @@ pub enum BridgeConstraintsError {
     #[error("Mismatch on the new LER. retrieved: {retrieved}, input: {input}")]
     MismatchNewLocalExitRoot { retrieved: Digest, input: Digest },
 
+    /// The provided previous local exit tree does not correspond with the
+    /// previous LER.
+    #[error("Mismatch on the prev local exit tree. computed: {computed}, input: {input}")]
+    MismatchPrevLocalExitTree { computed: Digest, input: Digest },
+
+    /// The bridge exits appended to the previous local exit tree do not lead
+    /// to the new LER.
+    #[error("Mismatch on the bridge exits. computed: {computed}, input: {input}")]
+    MismatchBridgeExits { computed: Digest, input: Digest },
+
+    /// The bridge exits could not be appended to the local exit tree.
+    #[error("Invalid local exit tree.")]
+    LocalExitTree(#[source] LocalExitTreeError),
+
     /// The provided constrained global indices do not correspond with the
     /// computed ones.
     #[error(
@@ -133,6 +151,9 @@ pub struct BridgeWitness {
     pub new_l2_block_sketch: EvmSketchInput,
     /// Caller address for the static call.
     pub caller_address: Address,
+    /// Bridge exits emitted within the block range along with the previous
+    /// local exit tree.
+    pub bridge_exits: BridgeExitsWitness,
 }
 
 /// Bridge data required to verify the bridge smart contract integrity.
@@ -141,6 +162,7 @@ pub struct BridgeConstraintsInput {
     pub ger_addr: Address,
     pub prev_l2_block_hash: Digest,
     pub new_l2_block_hash: Digest,
+    pub prev_local_exit_root: Digest,
     pub new_local_exit_root: Digest,
     pub l1_info_root: Digest,
     pub commit_imported_bridge_exits: Digest,
@@ -308,6 +330,14 @@ impl BridgeConstraintsInput {
         Ok(())
     }
 
+    /// Verify the bridge exits emitted within the block range against the
+    /// previous and new local exit roots.
+    fn verify_bridge_exits(&self) -> Result<(), BridgeConstraintsError> {
+        self.bridge_witness
+            .bridge_exits
+            .verify(self.prev_local_exit_root, self.new_local_exit_root)
+    }
+
     /// Fetch the bridge address through a static call.
     fn fetch_bridge_address(&self) -> Result<Address, BridgeConstraintsError> {
         // Get the bridge address from the GER smart contract.
@@ -380,25 +410,11 @@ impl BridgeConstraintsInput {
         }
 
         // Check that the inserted gers are correctly inserted in the L1InfoRoot.
-        let maybe_wrong_inserted_ger = self
-            .bridge_witness
-            .inserted_gers
-            .iter()
-            .find(|ger| !ger.verify(self.l1_info_root));
-
-        if let Some(wrong_ger) = maybe_wrong_inserted_ger {
-            return Err(BridgeConstraintsError::InvalidMerklePathGERToL1Root {
-                inserted_ger: wrong_ger.ger(),
-                l1_info_leaf_index: wrong_ger.l1_info_tree_leaf.l1_info_tree_index,
-                l1_info_root: self.l1_info_root,
-            });
-        }
-
-        Ok(())
+        inserted_ger::verify_inclusion_proofs(&self.bridge_witness.inserted_gers, self.l1_info_root)
     }
 
-    /// Verify the bridge state.
-    pub fn verify(&self) -> Result<(), BridgeConstraintsError> {
+    /// Verify the state of the l2 contracts against the witness.
+    fn verify_l2_contracts(&self) -> Result<(), BridgeConstraintsError> {
         self.verify_ger_hash_chains()?;
         let bridge_address = self.fetch_bridge_address()?;
         self.verify_claims_hash_chains(bridge_address)?;
@@ -407,6 +423,12 @@ impl BridgeConstraintsInput {
         self.verify_inserted_gers()
     }
 
+    /// Verify the bridge state.
+    pub fn verify(&self) -> Result<(), BridgeConstraintsError> {
+        self.verify_l2_contracts()?;
+        self.verify_bridge_exits()
+    }
+
     /// Validate that the rebuilt hash chain is equal to the new hash chain.
     fn validate_hash_chain(
         &self,
@@ -482,7 +504,7 @@ mod tests {
     use serde_json::Value;
     use sp1_cc_client_executor::Genesis;
     use sp1_cc_host_executor::EvmSketch;
-    use unified_bridge::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+    use unified_bridge::{L1InfoTreeLeaf, L1InfoTreeLeafInner, LocalExitTree, MerkleProof};
     use url::Url;
 
     use super::*;
@@ -859,6 +881,7 @@ mod tests {
             ger_addr: ger_address.into(),
             prev_l2_block_hash: prev_l2_block_sketch.anchor.header().hash_slow().0.into(),
             new_l2_block_hash: new_l2_block_sketch.anchor.header().hash_slow().0.into(),
+            prev_local_exit_root: empty_bridge_exits().prev_local_exit_tree.get_root(),
             new_local_exit_root: expected_new_ler,
             l1_info_root,
             commit_imported_bridge_exits: ImportedBridgeExitCommitmentValues {
@@ -874,6 +897,8 @@ mod tests {
                 prev_l2_block_sketch,
                 new_l2_block_sketch,
                 caller_address: address!("0x39027D57969aD59161365e0bbd53D2F63eE5AAA6"),
+                // NOTE: The bridge events of the l2 are not read by this test.
+                bridge_exits: empty_bridge_exits(),
             },
         };
 
@@ -893,8 +918,20 @@ mod tests {
         Ok(())
     }
 
+    /// Witness of a range without bridge exits over an empty local exit tree.
+    fn empty_bridge_exits() -> BridgeExitsWitness {
+        BridgeExitsWitness::new(LocalExitTree::new(), Vec::new())
+    }
+
     fn assert_bridge_data(bridge_data_input: BridgeConstraintsInput) {
-        bridge_data_input.verify().unwrap();
+        bridge_data_input.verify_l2_contracts().unwrap();
+
+        // The recorded input has no witness of its bridge exits, which does not
+        // lead to its new local exit root.
+        assert!(matches!(
+            bridge_data_input.verify(),
+            Err(BridgeConstraintsError::MismatchBridgeExits { .. })
+        ));
 
         // Invalid l1 info root
         {
@@ -939,7 +976,22 @@ mod tests {
             .join("src/test_input/bridge_constraints_input.json");
         let file = File::open(path).unwrap();
         let reader = BufReader::new(file);
-        let bridge_data_input: BridgeConstraintsInput = serde_json::from_reader(reader).unwrap();
+        let mut bridge_data_input: Value = serde_json::from_reader(reader).unwrap();
+        // The file may predate the witness of the bridge exits.
+        bridge_data_input
+            .as_object_mut()
+            .unwrap()
+            .entry("prev_local_exit_root")
+            .or_insert_with(|| {
+                serde_json::to_value(empty_bridge_exits().prev_local_exit_tree.get_root()).unwrap()
+            });
+        bridge_data_input["bridge_witness"]
+            .as_object_mut()
+            .unwrap()
+            .entry("bridge_exits")
+            .or_insert_with(|| serde_json::to_value(empty_bridge_exits()).unwrap());
+        let bridge_data_input: BridgeConstraintsInput =
+            serde_json::from_value(bridge_data_input).unwrap();
         // If the alloy version changes, this can lead to the file no longer parsing
         // correctly, and thus this test failing.
         // In that case, you should update the file.
@@ -0,0 +1,82 @@
+//! In-guest cost of the merkle proofs verified by the aggchain program.
+//!
+//! Each verification is delimited as a region of the cycle tracker when the
+//! program is built with the `cycle-tracker` feature, labelled after its
+//! [`MerkleProofKind`]. The budgets bound the cycles of one verification:
+//! the `cycle-budgets` harness of the aggchain proof builder asserts them on
+//! the execution reports, averaged over the verifications of a region.
+
+/// Merkle proof verified in the guest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MerkleProofKind {
+    /// Inclusion proof of an inserted GER in the l1 info tree.
+    InsertedGer,
+    /// Inclusion proof of the l1 head of the FEP in the l1 info tree.
+    L1Head,
+    /// Append of one bridge exit to the local exit tree.
+    BridgeExit,
+}
+
+impl MerkleProofKind {
+    pub const ALL: [Self; 3] = [Self::InsertedGer, Self::L1Head, Self::BridgeExit];
+
+    /// Label of the region of the cycle tracker.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::InsertedGer => "inserted-ger-proof",
+            Self::L1Head => "l1-head-proof",
+            Self::BridgeExit => "bridge-exit-append",
+        }
+    }
+
+    /// Cycles of one verification at most, with the keccak precompile.
+    ///
+    /// The inclusion proofs hash the leaf then the 32 levels of the tree. An
+    /// append hashes the bridge exit, then at most the 32 levels of the
+    /// frontier. The budgets are estimated from these hashes and not measured
+    /// yet, the `cycle-budgets` harness printing the cycles to set them from.
+    pub const fn cycle_budget(self) -> u64 {
+        match self {
+            Self::InsertedGer | Self::L1Head => 25_000,
+            Self::BridgeExit => 20_000,
+        }
+    }
+}
+
+/// Runs `verify` within the region of the cycle tracker of `kind`.
+pub(crate) fn track<T>(kind: MerkleProofKind, verify: impl FnOnce() -> T) -> T {
+    track_region(kind.label(), verify)
+}
+
+/// Runs `run` within the region of the cycle tracker labelled `label`.
+pub(crate) fn track_region<T>(label: &str, run: impl FnOnce() -> T) -> T {
+    #[cfg(all(target_os = "zkvm", feature = "cycle-tracker"))]
+    println!("cycle-tracker-report-start: {label}");
+    #[cfg(not(all(target_os = "zkvm", feature = "cycle-tracker")))]
+    let _ = label;
+
+    let output = run();
+
+    #[cfg(all(target_os = "zkvm", feature = "cycle-tracker"))]
+    println!("cycle-tracker-report-end: {label}");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn regions_are_tracked_apart() {
+        let labels = MerkleProofKind::ALL
+            .iter()
+            .map(|kind| kind.label())
+            .collect::<BTreeSet<_>>();
+        assert_eq!(labels.len(), MerkleProofKind::ALL.len());
+
+        assert!(track(MerkleProofKind::InsertedGer, || true));
+    }
+}
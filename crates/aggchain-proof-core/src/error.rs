@@ -39,9 +39,42 @@ pub enum ProofError {
 
     /// The signer recovered from the signature differs from the one declared as
     /// witness.
-    #[error("Invalid signer. declared: {declared}, recovered: {recovered}")]
+    #[error(
+        "Invalid signer. declared: {declared}, recovered: {recovered}, signed_commitment: \
+         {signed_commitment}, y_parity: {y_parity}"
+    )]
     InvalidSigner {
         declared: Address,
         recovered: Address,
+        /// Prehashed message the signature is checked against.
+        signed_commitment: Digest,
+        /// Recovery id of the signature.
+        y_parity: bool,
+    },
+}
+
+/// Represents the errors upon building the FEP inputs.
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum FepInputsError {
+    /// A required field has not been set.
+    #[error("Missing field: {0}")]
+    MissingField(&'static str),
+
+    /// A field which must be non-zero is zero.
+    #[error("Zero value for the field: {0}")]
+    ZeroValue(&'static str),
+
+    /// The claim block is not after the previous proven block.
+    #[error(
+        "The claim block number ({claim_block_num}) must be greater than the previous block \
+         number ({prev_block_num})"
+    )]
+    ClaimBlockNotAfterPrevBlock {
+        claim_block_num: u32,
+        prev_block_num: u32,
     },
+
+    /// An L2 block number does not fit the 32 bits of the FEP inputs.
+    #[error("The {field} ({block_num}) does not fit in 32 bits")]
+    BlockNumberOverflow { field: &'static str, block_num: u64 },
 }
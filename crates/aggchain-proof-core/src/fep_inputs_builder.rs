@@ -0,0 +1,353 @@
+//! Builder for the [`FepInputs`] validating their invariants on the host,
+//! before they reach the guest.
+use agglayer_primitives::{Address, Digest, Signature};
+use p3_baby_bear::BabyBear;
+use p3_field::AbstractField;
+use unified_bridge::{L1InfoTreeLeaf, MerkleProof};
+
+use crate::{
+    error::FepInputsError,
+    full_execution_proof::{BabyBearDigest, FepInputs},
+};
+
+/// Way the FEP gets verified in the aggchain proof.
+#[derive(Clone, Debug)]
+enum FepMode {
+    /// Verification of the aggregation proof.
+    Sp1Proof,
+    /// Verification of the trusted sequencer signature.
+    Optimistic(Signature),
+}
+
+/// Builder for the [`FepInputs`].
+#[derive(Clone, Debug, Default)]
+pub struct FepInputsBuilder {
+    prev_block_num: Option<u64>,
+    claim_block_num: Option<u64>,
+    rollup_config_hash: Option<Digest>,
+    prev_state_root: Option<Digest>,
+    prev_withdrawal_storage_root: Option<Digest>,
+    prev_block_hash: Option<Digest>,
+    new_state_root: Option<Digest>,
+    new_withdrawal_storage_root: Option<Digest>,
+    new_block_hash: Option<Digest>,
+    aggregation_vkey_hash: Option<BabyBearDigest>,
+    range_vkey_commitment: Option<[u8; 32]>,
+    trusted_sequencer: Option<Address>,
+    mode: Option<FepMode>,
+    l1_info_tree_leaf: Option<L1InfoTreeLeaf>,
+    l1_head_inclusion_proof: Option<MerkleProof>,
+}
+
+impl FepInputsBuilder {
+    /// Last proven L2 block number, which the claim block must be above.
+    pub fn prev_block_num(mut self, prev_block_num: u64) -> Self {
+        self.prev_block_num = Some(prev_block_num);
+        self
+    }
+
+    pub fn claim_block_num(mut self, claim_block_num: u64) -> Self {
+        self.claim_block_num = Some(claim_block_num);
+        self
+    }
+
+    pub fn rollup_config_hash(mut self, rollup_config_hash: Digest) -> Self {
+        self.rollup_config_hash = Some(rollup_config_hash);
+        self
+    }
+
+    /// Pre root values.
+    pub fn prev_output(
+        mut self,
+        state_root: Digest,
+        withdrawal_storage_root: Digest,
+        block_hash: Digest,
+    ) -> Self {
+        self.prev_state_root = Some(state_root);
+        self.prev_withdrawal_storage_root = Some(withdrawal_storage_root);
+        self.prev_block_hash = Some(block_hash);
+        self
+    }
+
+    /// Claim root values.
+    pub fn new_output(
+        mut self,
+        state_root: Digest,
+        withdrawal_storage_root: Digest,
+        block_hash: Digest,
+    ) -> Self {
+        self.new_state_root = Some(state_root);
+        self.new_withdrawal_storage_root = Some(withdrawal_storage_root);
+        self.new_block_hash = Some(block_hash);
+        self
+    }
+
+    pub fn aggregation_vkey_hash(mut self, aggregation_vkey_hash: BabyBearDigest) -> Self {
+        self.aggregation_vkey_hash = Some(aggregation_vkey_hash);
+        self
+    }
+
+    pub fn range_vkey_commitment(mut self, range_vkey_commitment: [u8; 32]) -> Self {
+        self.range_vkey_commitment = Some(range_vkey_commitment);
+        self
+    }
+
+    pub fn trusted_sequencer(mut self, trusted_sequencer: Address) -> Self {
+        self.trusted_sequencer = Some(trusted_sequencer);
+        self
+    }
+
+    /// The FEP is verified through the aggregation proof.
+    pub fn sp1_proof(mut self) -> Self {
+        self.mode = Some(FepMode::Sp1Proof);
+        self
+    }
+
+    /// The FEP is verified through the signature of the trusted sequencer.
+    pub fn optimistic(mut self, signature: Signature) -> Self {
+        self.mode = Some(FepMode::Optimistic(signature));
+        self
+    }
+
+    /// L1 info tree leaf containing the `l1Head` as block hash.
+    pub fn l1_info_tree_leaf(mut self, l1_info_tree_leaf: L1InfoTreeLeaf) -> Self {
+        self.l1_info_tree_leaf = Some(l1_info_tree_leaf);
+        self
+    }
+
+    pub fn l1_head_inclusion_proof(mut self, l1_head_inclusion_proof: MerkleProof) -> Self {
+        self.l1_head_inclusion_proof = Some(l1_head_inclusion_proof);
+        self
+    }
+
+    /// Validate the invariants and build the [`FepInputs`].
+    pub fn build(self) -> Result<FepInputs, FepInputsError> {
+        let l1_info_tree_leaf = required(self.l1_info_tree_leaf, "l1_info_tree_leaf")?;
+        let claim_block_num = block_num(
+            required(self.claim_block_num, "claim_block_num")?,
+            "claim_block_num",
+        )?;
+        let prev_block_num = self
+            .prev_block_num
+            .map(|prev_block_num| block_num(prev_block_num, "prev_block_num"))
+            .transpose()?;
+
+        if let Some(prev_block_num) = prev_block_num {
+            if claim_block_num <= prev_block_num {
+                return Err(FepInputsError::ClaimBlockNotAfterPrevBlock {
+                    claim_block_num,
+                    prev_block_num,
+                });
+            }
+        }
+
+        let fep_inputs = FepInputs {
+            l1_head: non_zero(l1_info_tree_leaf.inner.block_hash, "l1_head")?,
+            claim_block_num,
+            rollup_config_hash: non_zero(
+                required(self.rollup_config_hash, "rollup_config_hash")?,
+                "rollup_config_hash",
+            )?,
+            prev_state_root: non_zero(
+                required(self.prev_state_root, "prev_state_root")?,
+                "prev_state_root",
+            )?,
+            prev_withdrawal_storage_root: required(
+                self.prev_withdrawal_storage_root,
+                "prev_withdrawal_storage_root",
+            )?,
+            prev_block_hash: non_zero(
+                required(self.prev_block_hash, "prev_block_hash")?,
+                "prev_block_hash",
+            )?,
+            new_state_root: non_zero(
+                required(self.new_state_root, "new_state_root")?,
+                "new_state_root",
+            )?,
+            new_withdrawal_storage_root: required(
+                self.new_withdrawal_storage_root,
+                "new_withdrawal_storage_root",
+            )?,
+            new_block_hash: non_zero(
+                required(self.new_block_hash, "new_block_hash")?,
+                "new_block_hash",
+            )?,
+            aggregation_vkey_hash: required(self.aggregation_vkey_hash, "aggregation_vkey_hash")?,
+            range_vkey_commitment: required(self.range_vkey_commitment, "range_vkey_commitment")?,
+            trusted_sequencer: required(self.trusted_sequencer, "trusted_sequencer")?,
+            signature_optimistic_mode: None,
+            l1_info_tree_leaf,
+            l1_head_inclusion_proof: required(
+                self.l1_head_inclusion_proof,
+                "l1_head_inclusion_proof",
+            )?,
+        };
+
+        match required(self.mode, "mode")? {
+            FepMode::Sp1Proof => {
+                if fep_inputs.aggregation_vkey_hash.0 == [BabyBear::zero(); 8] {
+                    return Err(FepInputsError::ZeroValue("aggregation_vkey_hash"));
+                }
+
+                if fep_inputs.range_vkey_commitment == [0u8; 32] {
+                    return Err(FepInputsError::ZeroValue("range_vkey_commitment"));
+                }
+
+                Ok(fep_inputs)
+            }
+            FepMode::Optimistic(signature) => {
+                if alloy_primitives::Address::from(fep_inputs.trusted_sequencer).is_zero() {
+                    return Err(FepInputsError::ZeroValue("trusted_sequencer"));
+                }
+
+                Ok(FepInputs {
+                    signature_optimistic_mode: Some(signature),
+                    ..fep_inputs
+                })
+            }
+        }
+    }
+}
+
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, FepInputsError> {
+    value.ok_or(FepInputsError::MissingField(field))
+}
+
+fn block_num(block_num: u64, field: &'static str) -> Result<u32, FepInputsError> {
+    u32::try_from(block_num).map_err(|_| FepInputsError::BlockNumberOverflow { field, block_num })
+}
+
+fn non_zero(value: Digest, field: &'static str) -> Result<Digest, FepInputsError> {
+    if value == Digest::ZERO {
+        return Err(FepInputsError::ZeroValue(field));
+    }
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+    use p3_baby_bear::BabyBear;
+    use unified_bridge::L1InfoTreeLeafInner;
+
+    use super::*;
+
+    fn digest(byte: u8) -> Digest {
+        Digest([byte; 32])
+    }
+
+    fn builder() -> FepInputsBuilder {
+        FepInputsBuilder::default()
+            .prev_block_num(10)
+            .claim_block_num(20)
+            .rollup_config_hash(digest(1))
+            .prev_output(digest(2), digest(3), digest(4))
+            .new_output(digest(5), digest(6), digest(7))
+            .aggregation_vkey_hash(BabyBearDigest([BabyBear::one(); 8]))
+            .range_vkey_commitment([8u8; 32])
+            .trusted_sequencer(alloy_primitives::Address::repeat_byte(9).into())
+            .l1_info_tree_leaf(L1InfoTreeLeaf {
+                l1_info_tree_index: 0,
+                rer: Digest::ZERO,
+                mer: Digest::ZERO,
+                inner: L1InfoTreeLeafInner {
+                    global_exit_root: Digest::ZERO,
+                    block_hash: digest(10),
+                    timestamp: 0,
+                },
+            })
+            .l1_head_inclusion_proof(MerkleProof::new(Digest::ZERO, [Digest::ZERO; 32]))
+    }
+
+    #[test]
+    fn valid_inputs() {
+        let fep_inputs = builder().sp1_proof().build().unwrap();
+        assert!(fep_inputs.signature_optimistic_mode.is_none());
+        assert_eq!(fep_inputs.l1_head, digest(10));
+
+        let signature = Signature::new(U256::ZERO, U256::ZERO, false);
+        let fep_inputs = builder().optimistic(signature).build().unwrap();
+        assert!(fep_inputs.signature_optimistic_mode.is_some());
+    }
+
+    #[test]
+    fn missing_mode() {
+        assert_eq!(
+            builder().build().unwrap_err(),
+            FepInputsError::MissingField("mode")
+        );
+    }
+
+    #[test]
+    fn claim_block_not_after_prev_block() {
+        assert_eq!(
+            builder().claim_block_num(10).sp1_proof().build().unwrap_err(),
+            FepInputsError::ClaimBlockNotAfterPrevBlock {
+                claim_block_num: 10,
+                prev_block_num: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn block_number_overflow() {
+        let block_num = u64::from(u32::MAX) + 1;
+        assert_eq!(
+            builder()
+                .claim_block_num(block_num)
+                .sp1_proof()
+                .build()
+                .unwrap_err(),
+            FepInputsError::BlockNumberOverflow {
+                field: "claim_block_num",
+                block_num,
+            }
+        );
+        assert_eq!(
+            builder()
+                .prev_block_num(block_num)
+                .sp1_proof()
+                .build()
+                .unwrap_err(),
+            FepInputsError::BlockNumberOverflow {
+                field: "prev_block_num",
+                block_num,
+            }
+        );
+    }
+
+    #[test]
+    fn zero_roots() {
+        assert_eq!(
+            builder()
+                .new_output(Digest::ZERO, digest(6), digest(7))
+                .sp1_proof()
+                .build()
+                .unwrap_err(),
+            FepInputsError::ZeroValue("new_state_root")
+        );
+    }
+
+    #[test]
+    fn mode_consistency() {
+        assert_eq!(
+            builder()
+                .range_vkey_commitment([0u8; 32])
+                .sp1_proof()
+                .build()
+                .unwrap_err(),
+            FepInputsError::ZeroValue("range_vkey_commitment")
+        );
+
+        let signature = Signature::new(U256::ZERO, U256::ZERO, false);
+        assert_eq!(
+            builder()
+                .trusted_sequencer(alloy_primitives::Address::ZERO.into())
+                .optimistic(signature)
+                .build()
+                .unwrap_err(),
+            FepInputsError::ZeroValue("trusted_sequencer")
+        );
+    }
+}
@@ -1,7 +1,4 @@
-use agglayer_primitives::{
-    keccak::{keccak256, keccak256_combine},
-    Address, Digest,
-};
+use agglayer_primitives::{Address, Digest};
 use alloy_primitives::{FixedBytes, B256, U256};
 use alloy_sol_types::{sol, SolValue};
 use p3_baby_bear::BabyBear;
@@ -11,10 +8,14 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest as Sha256Digest, Sha256};
 use unified_bridge::{L1InfoTreeLeaf, MerkleProof};
 
-use crate::{error::ProofError, vkey_hash::HashU32};
-
-/// Hardcoded for now, might see if we might need it as input
-pub const OUTPUT_ROOT_VERSION: [u8; 32] = [0u8; 32];
+pub use crate::output_root::OUTPUT_ROOT_VERSION;
+use crate::{
+    cycles::{self, MerkleProofKind},
+    error::ProofError,
+    keccak::{keccak256, keccak256_combine},
+    output_root::{L2Output, OpStackOutputRoot, OutputRootScheme},
+    vkey_hash::HashU32,
+};
 
 /// L2PreRoot is the representation of the previous OutputRoot
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -228,9 +229,12 @@ impl FepInputs {
                      {new_local_exit_root:?} || commit_imported_bridge_exits: \
                      {commit_imported_bridge_exits:?})"
                 );
+
                 return Err(ProofError::InvalidSigner {
                     declared: self.trusted_sequencer,
                     recovered: recovered_signer,
+                    signed_commitment: signature_commitment,
+                    y_parity: signature.v(),
                 });
             }
 
@@ -267,10 +271,12 @@ impl FepInputs {
             });
         }
 
-        let inclusion_proof_valid = self.l1_head_inclusion_proof.verify(
-            self.l1_info_tree_leaf.hash(),
-            self.l1_info_tree_leaf.l1_info_tree_index,
-        );
+        let inclusion_proof_valid = cycles::track(MerkleProofKind::L1Head, || {
+            self.l1_head_inclusion_proof.verify(
+                self.l1_info_tree_leaf.hash(),
+                self.l1_info_tree_leaf.l1_info_tree_index,
+            )
+        });
 
         // TODO: proper error
         if !(inclusion_proof_valid && l1_info_root == self.l1_head_inclusion_proof.root) {
@@ -286,21 +292,22 @@ impl FepInputs {
 
     /// Compute l2 pre root.
     pub fn compute_l2_pre_root(&self) -> L2PreRoot {
-        compute_output_root(
-            self.prev_state_root.0,
-            self.prev_withdrawal_storage_root.0,
-            self.prev_block_hash.0,
-        )
-        .into()
+        OpStackOutputRoot
+            .output_root(&L2Output {
+                state_root: self.prev_state_root,
+                withdrawal_storage_root: self.prev_withdrawal_storage_root,
+                block_hash: self.prev_block_hash,
+            })
+            .into()
     }
 
     /// Compute claim root.
     pub fn compute_claim_root(&self) -> ClaimRoot {
-        compute_output_root(
-            self.new_state_root.0,
-            self.new_withdrawal_storage_root.0,
-            self.new_block_hash.0,
-        )
+        OpStackOutputRoot.output_root(&L2Output {
+            state_root: self.new_state_root,
+            withdrawal_storage_root: self.new_withdrawal_storage_root,
+            block_hash: self.new_block_hash,
+        })
     }
 }
 
@@ -311,12 +318,11 @@ pub(crate) fn compute_output_root(
     withdrawal_storage_root: [u8; 32],
     block_hash: [u8; 32],
 ) -> ClaimRoot {
-    ClaimRoot(keccak256_combine([
-        OUTPUT_ROOT_VERSION,
-        state_root,
-        withdrawal_storage_root,
-        block_hash,
-    ]))
+    OpStackOutputRoot.output_root(&L2Output {
+        state_root: Digest(state_root),
+        withdrawal_storage_root: Digest(withdrawal_storage_root),
+        block_hash: Digest(block_hash),
+    })
 }
 
 #[cfg(test)]
@@ -0,0 +1,136 @@
+//! Keccak256 hashing of the aggchain proof program.
+//!
+//! The hashes are the ones of [`agglayer_primitives::keccak`], whose
+//! tiny-keccak is patched with the SP1 one in the program, hence they go
+//! through the keccak precompile in the zkVM.
+//!
+//! Each hash is delimited as the `keccak256` region of the cycle tracker when
+//! the program is built with the `cycle-tracker` feature. The program then
+//! also hashes samples with and without the precompile through
+//! [`compare_with_software_keccak`], and the `cycle-budgets` harness of the
+//! aggchain proof builder compares both paths against [`CYCLE_BUDGET`].
+
+use agglayer_primitives::Digest;
+
+use crate::cycles::track_region;
+
+/// Label of the region of the cycle tracker.
+pub const CYCLE_TRACKER_LABEL: &str = "keccak256";
+
+/// Label of the region of the cycle tracker of the sample hashes computed
+/// without the precompile.
+pub const SOFTWARE_CYCLE_TRACKER_LABEL: &str = "keccak256-software";
+
+/// Cycles of one hash at most, on average, with the keccak precompile.
+///
+/// The hashes computed without the precompile are expected over it, the
+/// `cycle-budgets` harness printing the cycles of both paths to set it from.
+pub const CYCLE_BUDGET: u64 = 1_000;
+
+/// Hashes the given data.
+pub fn keccak256(data: &[u8]) -> Digest {
+    track_region(CYCLE_TRACKER_LABEL, || {
+        agglayer_primitives::keccak::keccak256(data)
+    })
+}
+
+/// Hashes the concatenation of the given items.
+pub fn keccak256_combine<I, T>(items: I) -> Digest
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    track_region(CYCLE_TRACKER_LABEL, || {
+        agglayer_primitives::keccak::keccak256_combine(items)
+    })
+}
+
+/// Hashes the same samples with and without the keccak precompile, the
+/// latter within the `keccak256-software` region of the cycle tracker.
+///
+/// The samples are pairs of digests, as hashed by the merkle proofs.
+#[cfg(feature = "cycle-tracker")]
+pub fn compare_with_software_keccak() {
+    const SAMPLES: u8 = 16;
+
+    for sample in 0..SAMPLES {
+        let data = [[sample; 32], [!sample; 32]].concat();
+
+        let precompile = keccak256(&data);
+        let software = track_region(SOFTWARE_CYCLE_TRACKER_LABEL, || software::keccak256(&data));
+        assert_eq!(precompile, software);
+    }
+}
+
+/// Keccak256 over the keccak-f permutation of the `keccak` crate, which is
+/// not patched in the program.
+#[cfg(any(test, feature = "cycle-tracker"))]
+mod software {
+    use agglayer_primitives::Digest;
+
+    /// Rate of keccak256 in bytes.
+    pub(super) const RATE: usize = 136;
+
+    pub(super) fn keccak256(data: &[u8]) -> Digest {
+        let mut state = [0u64; 25];
+
+        let mut blocks = data.chunks_exact(RATE);
+        for block in &mut blocks {
+            absorb(&mut state, block);
+        }
+
+        // Padding of the original keccak, and not the one of sha3.
+        let remainder = blocks.remainder();
+        let mut last = [0u8; RATE];
+        last[..remainder.len()].copy_from_slice(remainder);
+        last[remainder.len()] ^= 0x01;
+        last[RATE - 1] ^= 0x80;
+        absorb(&mut state, &last);
+
+        let mut output = [0u8; 32];
+        for (bytes, lane) in output.chunks_exact_mut(8).zip(state) {
+            bytes.copy_from_slice(&lane.to_le_bytes());
+        }
+        Digest(output)
+    }
+
+    fn absorb(state: &mut [u64; 25], block: &[u8]) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+            *lane ^= u64::from_le_bytes(bytes.try_into().expect("8 bytes lane"));
+        }
+        keccak::f1600(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_digests_as_agglayer_primitives() {
+        let long = vec![0xabu8; 3 * software::RATE + 7];
+        let cases: [&[&[u8]]; 5] = [
+            &[],
+            &[b""],
+            &[b"agglayer"],
+            &[&[1u8; 32], &[2u8; 32], &[3u8; 32]],
+            &[&long[..software::RATE - 1], &long[software::RATE - 1..]],
+        ];
+
+        for items in cases {
+            assert_eq!(
+                keccak256_combine(items.iter()),
+                agglayer_primitives::keccak::keccak256_combine(items.iter())
+            );
+            assert_eq!(
+                software::keccak256(&items.concat()),
+                agglayer_primitives::keccak::keccak256_combine(items.iter())
+            );
+        }
+
+        assert_eq!(
+            keccak256(b"agglayer"),
+            agglayer_primitives::keccak::keccak256(b"agglayer")
+        );
+    }
+}
@@ -1,9 +1,13 @@
 pub mod bridge;
+pub mod cycles;
 pub mod error;
+pub mod fep_inputs_builder;
 pub mod full_execution_proof;
+pub mod keccak;
+pub mod output_root;
 pub mod proof;
 pub mod vkey_hash;
 
 include!(concat!(env!("OUT_DIR"), "/version.rs"));
 
-pub const AGGCHAIN_TYPE: u16 = 0x0001;
+pub const AGGCHAIN_TYPE: u16 = output_root::AGGCHAIN_TYPE_OP_STACK;
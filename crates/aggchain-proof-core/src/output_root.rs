@@ -0,0 +1,70 @@
+//! Output root commitments of the supported rollup stacks.
+//!
+//! The claim root and the L2 pre root exposed in the aggchain params are
+//! commitments over one L2 output. Their layout depends on the rollup stack of
+//! the aggchain, hence the computation is abstracted behind
+//! [`OutputRootScheme`]. The program only supports the OP Stack layout, given by
+//! its [`crate::AGGCHAIN_TYPE`].
+use agglayer_primitives::Digest;
+use serde::{Deserialize, Serialize};
+
+use crate::{full_execution_proof::ClaimRoot, keccak::keccak256_combine};
+
+/// Hardcoded for now, might see if we might need it as input
+pub const OUTPUT_ROOT_VERSION: [u8; 32] = [0u8; 32];
+
+/// Aggchain type of the chains settling with an OP Stack output root.
+pub const AGGCHAIN_TYPE_OP_STACK: u16 = 0x0001;
+
+/// L2 output values which can take part in an output root commitment.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct L2Output {
+    /// State root of the L2 block.
+    pub state_root: Digest,
+    /// Storage root of the withdrawal contract.
+    pub withdrawal_storage_root: Digest,
+    /// Hash of the L2 block.
+    pub block_hash: Digest,
+}
+
+/// Computes the output root commitment of one L2 output.
+pub trait OutputRootScheme {
+    fn output_root(&self, output: &L2Output) -> ClaimRoot;
+}
+
+/// Output root as defined here:
+/// https://specs.optimism.io/protocol/proposals.html#l2-output-commitment-construction
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpStackOutputRoot;
+
+impl OutputRootScheme for OpStackOutputRoot {
+    fn output_root(&self, output: &L2Output) -> ClaimRoot {
+        ClaimRoot(keccak256_combine([
+            OUTPUT_ROOT_VERSION,
+            output.state_root.0,
+            output.withdrawal_storage_root.0,
+            output.block_hash.0,
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output() -> L2Output {
+        L2Output {
+            state_root: Digest([1u8; 32]),
+            withdrawal_storage_root: Digest([2u8; 32]),
+            block_hash: Digest([3u8; 32]),
+        }
+    }
+
+    #[test]
+    fn op_stack_layout() {
+        assert_eq!(
+            OpStackOutputRoot.output_root(&output()).0,
+            keccak256_combine([OUTPUT_ROOT_VERSION, [1u8; 32], [2u8; 32], [3u8; 32]])
+        );
+    }
+}
@@ -64,6 +64,7 @@ impl AggchainProofWitness {
             ger_addr: L2_GER_ADDR, // set as constant for now
             prev_l2_block_hash: self.fep.prev_block_hash,
             new_l2_block_hash: self.fep.new_block_hash,
+            prev_local_exit_root: self.prev_local_exit_root,
             new_local_exit_root: self.new_local_exit_root,
             l1_info_root: self.l1_info_root,
             commit_imported_bridge_exits: self.commit_imported_bridge_exits,
@@ -9,4 +9,7 @@ pub fn main() {
     let aggchain_proof_public_values = aggchain_witness.verify_aggchain_inputs().unwrap();
 
     sp1_zkvm::io::commit(&aggchain_proof_public_values);
+
+    #[cfg(feature = "cycle-tracker")]
+    aggchain_proof_core::keccak::compare_with_software_keccak();
 }
@@ -0,0 +1,323 @@
+//! Fault injection in the downstream services of the aggchain proof service,
+//! used to exercise the resilience of the orchestration.
+//!
+//! The faults are described in a scenario file, e.g.
+//!
+//! ```toml
+//! seed = 42
+//!
+//! [[rules]]
+//! target = "proposer-service"
+//! probability = 0.1
+//! fault = { kind = "connection-reset" }
+//!
+//! [[rules]]
+//! target = "aggchain-proof-builder"
+//! probability = 0.5
+//! fault = { kind = "delay", duration = "30s" }
+//! ```
+//!
+//! The rules of a target are evaluated in order on every request, the first
+//! one triggered injects its fault.
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use proposer_client::error::{Error as ProposerClientError, ProofRequestError};
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+use serde::{Deserialize, Serialize};
+use tower::{util::BoxCloneService, Layer, Service};
+use tracing::warn;
+
+use crate::service::AggchainProofService;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChaosScenarioError {
+    #[error("Unable to read the chaos scenario {path:?}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Unable to parse the chaos scenario {path:?}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+}
+
+/// Downstream service in which faults are injected.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChaosTarget {
+    ProposerService,
+    AggchainProofBuilder,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Fault {
+    /// Delays the request, which is then forwarded to the service.
+    Delay {
+        #[serde(with = "prover_utils::with::HumanDuration")]
+        duration: Duration,
+    },
+    /// Fails the request without forwarding it.
+    Error { message: String },
+    /// Fails the request as if the connection to the service was reset.
+    ConnectionReset,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ChaosRule {
+    pub target: ChaosTarget,
+    /// Probability for the rule to be triggered by a request.
+    pub probability: f64,
+    pub fault: Fault,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ChaosScenario {
+    /// Seed of the draws, making the injected faults reproducible. Random if
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub rules: Vec<ChaosRule>,
+}
+
+impl ChaosScenario {
+    pub fn load(path: &Path) -> Result<Self, ChaosScenarioError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ChaosScenarioError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        toml::from_str(&content).map_err(|source| ChaosScenarioError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Layer injecting the faults of `target`, `into_error` turning the
+    /// injected failures into errors of the service.
+    pub fn layer<E>(
+        &self,
+        target: ChaosTarget,
+        into_error: fn(InjectedFailure) -> E,
+    ) -> ChaosLayer<E> {
+        let rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        ChaosLayer {
+            target,
+            rules: self
+                .rules
+                .iter()
+                .filter(|rule| rule.target == target)
+                .cloned()
+                .collect::<Vec<_>>()
+                .into(),
+            rng: Arc::new(Mutex::new(rng)),
+            into_error,
+        }
+    }
+
+    /// Injects the faults of the scenario in the downstream services of
+    /// `service`.
+    pub(crate) fn inject(&self, service: &mut AggchainProofService) {
+        warn!("Injecting faults in the downstream services");
+
+        service.proposer_service = BoxCloneService::new(
+            self.layer(ChaosTarget::ProposerService, |failure| {
+                proposer_service::Error::Client(ProposerClientError::Requesting(Box::new(
+                    ProofRequestError::Grpc(failure.into()),
+                )))
+            })
+            .layer(service.proposer_service.clone()),
+        );
+        service.aggchain_proof_builder = BoxCloneService::new(
+            self.layer(ChaosTarget::AggchainProofBuilder, |failure| {
+                aggchain_proof_builder::Error::ProverServiceError(failure.to_string())
+            })
+            .layer(service.aggchain_proof_builder.clone()),
+        );
+    }
+}
+
+/// Failure injected in place of the response of the service.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InjectedFailure {
+    #[error("Injected error: {0}")]
+    Error(String),
+    #[error("Injected connection reset")]
+    ConnectionReset,
+}
+
+impl From<InjectedFailure> for tonic::Status {
+    fn from(failure: InjectedFailure) -> Self {
+        match failure {
+            InjectedFailure::Error(message) => tonic::Status::internal(message),
+            InjectedFailure::ConnectionReset => {
+                tonic::Status::unavailable("connection reset by peer")
+            }
+        }
+    }
+}
+
+pub struct ChaosLayer<E> {
+    target: ChaosTarget,
+    rules: Arc<[ChaosRule]>,
+    rng: Arc<Mutex<StdRng>>,
+    into_error: fn(InjectedFailure) -> E,
+}
+
+impl<E> Clone for ChaosLayer<E> {
+    fn clone(&self) -> Self {
+        Self {
+            target: self.target,
+            rules: self.rules.clone(),
+            rng: self.rng.clone(),
+            into_error: self.into_error,
+        }
+    }
+}
+
+impl<E> ChaosLayer<E> {
+    /// Fault to inject in the next request, if any.
+    fn draw(&self) -> Option<Fault> {
+        let mut rng = self.rng.lock().expect("chaos rng lock poisoned");
+
+        self.rules
+            .iter()
+            .find(|rule| rng.gen_bool(rule.probability.clamp(0.0, 1.0)))
+            .map(|rule| rule.fault.clone())
+    }
+}
+
+impl<S, E> Layer<S> for ChaosLayer<E> {
+    type Service = ChaosService<S, E>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ChaosService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+pub struct ChaosService<S, E> {
+    inner: S,
+    layer: ChaosLayer<E>,
+}
+
+impl<S: Clone, E> Clone for ChaosService<S, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            layer: self.layer.clone(),
+        }
+    }
+}
+
+impl<S, E, Request> Service<Request> for ChaosService<S, E>
+where
+    S: Service<Request, Error = E>,
+    S::Future: Send + 'static,
+    S::Response: 'static,
+    E: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = E;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, E>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let target = self.layer.target;
+        let failure = match self.layer.draw() {
+            None => return Box::pin(self.inner.call(request)),
+            Some(Fault::Delay { duration }) => {
+                warn!(?target, ?duration, "Injecting a delay");
+                let future = self.inner.call(request);
+
+                return Box::pin(async move {
+                    tokio::time::sleep(duration).await;
+                    future.await
+                });
+            }
+            Some(Fault::Error { message }) => InjectedFailure::Error(message),
+            Some(Fault::ConnectionReset) => InjectedFailure::ConnectionReset,
+        };
+
+        warn!(?target, %failure, "Injecting a failure");
+        let error = (self.layer.into_error)(failure);
+        Box::pin(async move { Err(error) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tower::{service_fn, ServiceExt as _};
+
+    use super::*;
+
+    const SCENARIO: &str = r#"
+        seed = 7
+
+        [[rules]]
+        target = "proposer-service"
+        probability = 0.5
+        fault = { kind = "connection-reset" }
+
+        [[rules]]
+        target = "proposer-service"
+        probability = 1.0
+        fault = { kind = "delay", duration = "10ms" }
+
+        [[rules]]
+        target = "aggchain-proof-builder"
+        probability = 1.0
+        fault = { kind = "error", message = "boom" }
+    "#;
+
+    #[tokio::test]
+    async fn faults_are_injected_per_target() {
+        let scenario: ChaosScenario = toml::from_str(SCENARIO).unwrap();
+        assert_eq!(scenario.rules.len(), 3);
+
+        let echo = service_fn(|request: u32| async move { Ok::<_, InjectedFailure>(request) });
+
+        let builder = scenario
+            .layer(ChaosTarget::AggchainProofBuilder, |failure| failure)
+            .layer(echo);
+        assert_eq!(
+            builder.oneshot(1).await,
+            Err(InjectedFailure::Error("boom".to_string()))
+        );
+
+        // Every request is either reset or delayed then served.
+        let proposer = scenario.layer(ChaosTarget::ProposerService, |failure| failure);
+        let mut outcomes = vec![];
+        for request in 0..20 {
+            outcomes.push(proposer.layer(echo).oneshot(request).await);
+        }
+        assert!(outcomes.contains(&Err(InjectedFailure::ConnectionReset)));
+        assert!(outcomes.iter().any(Result::is_ok));
+        assert!(outcomes
+            .iter()
+            .all(|outcome| matches!(outcome, Ok(_) | Err(InjectedFailure::ConnectionReset))));
+    }
+}
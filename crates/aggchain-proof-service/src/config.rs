@@ -1,13 +1,170 @@
-use std::fmt::Debug;
+use std::{borrow::Cow, collections::BTreeMap, fmt::Debug, time::Duration};
 
 use aggchain_proof_builder::config::AggchainProofBuilderConfig;
 use proposer_service::config::ProposerServiceConfig;
+use prover_config::ProverType;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    freshness::FreshnessPolicy, ger_liveness::GerLivenessConfig,
+    l1_info_tree::L1InfoTreeMirrorConfig, optimistic_mode::OptimisticModePolicy,
+    request_cost::RequestCostModel, warm_pipeline::WarmPipelineConfig,
+};
 
 /// The Aggchain proof service configuration
-#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, Default)]
 #[serde(rename_all = "kebab-case")]
 pub struct AggchainProofServiceConfig {
     pub aggchain_proof_builder: AggchainProofBuilderConfig,
     pub proposer_service: ProposerServiceConfig,
+    /// Policy on the optimistic mode requests.
+    #[serde(default)]
+    pub optimistic_mode: OptimisticModePolicy,
+    /// Policy on the stale and replayed requests.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub freshness: FreshnessPolicy,
+    /// Check of the GERs of the requests against the l1, before proving.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub ger_liveness: GerLivenessConfig,
+    /// Mirror of the l1 info tree the requests are checked against, rather
+    /// than l1, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l1_info_tree_mirror: Option<L1InfoTreeMirrorConfig>,
+    /// Estimation of the proving cost of the requests, rejecting the too
+    /// costly ones.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub request_cost: RequestCostModel,
+    /// Prefetching of the chain data of the range expected to be requested
+    /// next, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warm_pipeline: Option<WarmPipelineConfig>,
+    /// Overrides of the settings for given networks, keyed by network id,
+    /// e.g. `[aggchain-proof-service.network.1.prover]`.
+    #[serde(
+        default,
+        rename = "network",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    #[schemars(with = "BTreeMap<String, NetworkOverrides>")]
+    pub networks: BTreeMap<u32, NetworkOverrides>,
+    /// Scenario of the faults injected in the downstream services.
+    #[cfg(feature = "chaos")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chaos_scenario: Option<std::path::PathBuf>,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// Settings overridden for one network.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworkOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prover: Option<NetworkProverOverrides>,
+}
+
+/// Proving settings overridden for one network, the unset ones being taken
+/// from the aggchain proof builder configuration.
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworkProverOverrides {
+    /// Prover of the aggchain proofs, replacing the primary prover.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend: Option<ProverType>,
+
+    /// Timeout of the generation of one aggchain proof.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<prover_utils::with::HumanDuration>")]
+    #[schemars(with = "Option<prover_utils::with::HumanDuration>")]
+    pub proving_timeout: Option<Duration>,
+
+    /// Maximum number of L2 blocks covered by one aggchain proof, the
+    /// requests for more blocks being proven up to this limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_range_size: Option<u64>,
+}
+
+impl AggchainProofServiceConfig {
+    /// Proving overrides of the network of the aggchain proof builder.
+    pub fn prover_overrides(&self) -> Option<&NetworkProverOverrides> {
+        self.networks
+            .get(&self.aggchain_proof_builder.network_id)?
+            .prover
+            .as_ref()
+    }
+
+    /// Configuration of the aggchain proof builder, with the overrides of its
+    /// network applied.
+    pub fn effective_aggchain_proof_builder(&self) -> Cow<'_, AggchainProofBuilderConfig> {
+        let Some(overrides) = self.prover_overrides() else {
+            return Cow::Borrowed(&self.aggchain_proof_builder);
+        };
+
+        let mut config = self.aggchain_proof_builder.clone();
+        if let Some(backend) = &overrides.backend {
+            config.primary_prover = backend.clone();
+        }
+        if let Some(proving_timeout) = overrides.proving_timeout {
+            config.proving_timeout = proving_timeout;
+        }
+
+        Cow::Owned(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prover_config::CpuProverConfig;
+
+    use super::*;
+
+    #[test]
+    fn overrides_of_the_network_apply() {
+        let cpu_prover = ProverType::CpuProver(CpuProverConfig::default());
+        let mut config = AggchainProofServiceConfig::default();
+        config.aggchain_proof_builder.network_id = 1;
+        config.networks.insert(
+            2,
+            NetworkOverrides {
+                prover: Some(NetworkProverOverrides {
+                    backend: Some(cpu_prover.clone()),
+                    ..Default::default()
+                }),
+            },
+        );
+
+        assert_eq!(config.prover_overrides(), None);
+        assert!(matches!(
+            config.effective_aggchain_proof_builder(),
+            Cow::Borrowed(_)
+        ));
+
+        config.networks.insert(
+            1,
+            NetworkOverrides {
+                prover: Some(NetworkProverOverrides {
+                    backend: Some(cpu_prover.clone()),
+                    proving_timeout: Some(Duration::from_secs(600)),
+                    max_range_size: Some(100),
+                }),
+            },
+        );
+
+        let builder = config.effective_aggchain_proof_builder();
+        assert_eq!(builder.primary_prover, cpu_prover);
+        assert_eq!(builder.proving_timeout, Duration::from_secs(600));
+        assert_eq!(
+            config
+                .prover_overrides()
+                .and_then(|overrides| overrides.max_range_size),
+            Some(100)
+        );
+    }
 }
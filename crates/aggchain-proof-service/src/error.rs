@@ -1,26 +1,143 @@
-#[derive(thiserror::Error, Debug)]
+use prover_errors::ErrorCode;
+use serde::{Deserialize, Serialize};
+
+/// Error of the aggchain proof service.
+///
+/// The errors are serializable, such that the workers proving the dispatched
+/// requests report them as is, but the ones wrapping the errors of the
+/// dependencies, which the workers send as [`Error::WorkerFailed`] instead.
+#[derive(thiserror::Error, Debug, Serialize, Deserialize)]
 pub enum Error {
+    #[error("Optimistic mode request rejected")]
+    OptimisticModeRejected(#[source] crate::optimistic_mode::OptimisticModeRejection),
+
+    #[error("Monthly cycle budget exhausted")]
+    CycleBudgetExhausted(#[source] prover_executor::cost::BudgetExhausted),
+
+    #[error("Stale proof request rejected")]
+    StaleRequest(#[source] crate::freshness::StaleRequest),
+
+    #[error("Proof request citing a GER unknown to l1")]
+    InvalidGer(#[source] crate::ger_liveness::InvalidGer),
+
+    #[error("Proof request citing an l1 info tree differing from the one of l1")]
+    L1InfoTreeMismatch(#[source] crate::l1_info_tree::L1InfoTreeMismatch),
+
+    #[error("Proof request too costly")]
+    RequestTooCostly(#[source] crate::request_cost::RequestTooCostly),
+
+    #[error("Requested aggchain proof program not served")]
+    UnknownProgram(#[source] aggchain_proof_builder::programs::UnknownProgram),
+
+    /// Error of a dependency on the worker proving a dispatched request,
+    /// along with its code.
+    #[error("Proof request failed on the worker: {message}")]
+    WorkerFailed { code: u16, message: String },
+
+    // Not serializable, hence last such that the serializable variants are
+    // numbered alike by the serializer and the deserializer.
     #[error("Unable to create alloy provider")]
+    #[serde(skip)]
     AlloyProviderInitializationFailed(#[source] anyhow::Error),
 
     #[error("Unable to setup proposer service")]
+    #[serde(skip)]
     ProposerServiceInitFailed(#[source] proposer_service::Error),
 
     #[error("Proposer service returned an error during operation")]
+    #[serde(skip)]
     ProposerServiceError(#[source] proposer_service::Error),
 
     #[error("Proposer service request failed")]
+    #[serde(skip)]
     ProposerServiceRequestFailed(#[source] proposer_service::Error),
 
     #[error("Unable to setup aggchain proof builder")]
+    #[serde(skip)]
     AggchainProofBuilderInitFailed(#[source] aggchain_proof_builder::Error),
 
     #[error("Aggchain proof builder service request failed")]
+    #[serde(skip)]
     AggchainProofBuilderRequestFailed(#[source] aggchain_proof_builder::Error),
 
     #[error("Unable to setup aggchain contracts client")]
+    #[serde(skip)]
     ContractsClientInitFailed(#[source] aggchain_proof_contracts::Error),
 
+    #[error("Unable to setup the freshness guard")]
+    #[serde(skip)]
+    FreshnessInitFailed(#[source] crate::freshness::FreshnessError),
+
+    #[error("Unable to check the GERs of the proof request against l1")]
+    #[serde(skip)]
+    GerLivenessCheckFailed(#[source] aggchain_proof_contracts::Error),
+
     #[error("Unable to resolve aggchain proof vkey")]
+    #[serde(skip)]
     AggchainProofVkeyResolveFailed(#[source] aggchain_proof_contracts::Error),
+
+    #[cfg(feature = "chaos")]
+    #[error("Unable to load the chaos scenario")]
+    #[serde(skip)]
+    ChaosScenario(#[source] crate::chaos::ChaosScenarioError),
+}
+
+impl Error {
+    /// Stable code the error is reported with on the gRPC APIs.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Error::OptimisticModeRejected(_) => ErrorCode::RequestRejected,
+            Error::CycleBudgetExhausted(_) => ErrorCode::CycleBudgetExhausted,
+            Error::StaleRequest(_) => ErrorCode::StaleRequest,
+            Error::InvalidGer(_) => ErrorCode::InvalidGer,
+            Error::L1InfoTreeMismatch(_) => ErrorCode::L1InfoTreeMismatch,
+            Error::RequestTooCostly(_) => ErrorCode::RequestTooCostly,
+            Error::UnknownProgram(_) => ErrorCode::UnknownProgram,
+            Error::GerLivenessCheckFailed(_) | Error::AggchainProofVkeyResolveFailed(_) => {
+                ErrorCode::L1Unavailable
+            }
+            Error::ProposerServiceError(_) | Error::ProposerServiceRequestFailed(_) => {
+                ErrorCode::ProposerFailed
+            }
+            Error::AggchainProofBuilderRequestFailed(_) => ErrorCode::ProvingFailed,
+            Error::AlloyProviderInitializationFailed(_)
+            | Error::ProposerServiceInitFailed(_)
+            | Error::AggchainProofBuilderInitFailed(_)
+            | Error::ContractsClientInitFailed(_)
+            | Error::FreshnessInitFailed(_) => ErrorCode::Internal,
+            #[cfg(feature = "chaos")]
+            Error::ChaosScenario(_) => ErrorCode::Internal,
+            Error::WorkerFailed { code, .. } => {
+                ErrorCode::try_from(*code).unwrap_or(ErrorCode::Internal)
+            }
+        }
+    }
+
+    /// Error as reported by a worker, the errors of the dependencies being
+    /// reduced to their code and their message, sources included.
+    pub fn into_serializable(self) -> Self {
+        match self {
+            Error::OptimisticModeRejected(_)
+            | Error::CycleBudgetExhausted(_)
+            | Error::StaleRequest(_)
+            | Error::InvalidGer(_)
+            | Error::L1InfoTreeMismatch(_)
+            | Error::RequestTooCostly(_)
+            | Error::UnknownProgram(_)
+            | Error::WorkerFailed { .. } => self,
+            error => {
+                let mut message = error.to_string();
+                let mut source = std::error::Error::source(&error);
+                while let Some(cause) = source {
+                    message.push_str(&format!(": {cause}"));
+                    source = cause.source();
+                }
+
+                Error::WorkerFailed {
+                    code: error.code().code(),
+                    message,
+                }
+            }
+        }
+    }
 }
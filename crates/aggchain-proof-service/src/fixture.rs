@@ -0,0 +1,228 @@
+//! Request fixtures: aggchain proof requests recorded as JSON files, e.g. from
+//! the traffic of an aggsender, and replayed in the local test runs.
+//!
+//! The hashes and the byte strings are hex encoded, so that the fixtures can
+//! be read and edited by hand.
+use std::path::{Path, PathBuf};
+
+use crate::service::AggchainProofServiceRequest;
+
+/// Extension of the request fixture files.
+pub const FIXTURE_EXTENSION: &str = "json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum FixtureError {
+    #[error("Unable to access the request fixture {}", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Unable to encode or decode the request fixture {}", .path.display())]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> FixtureError + '_ {
+    |source| FixtureError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+pub fn load_request(path: &Path) -> Result<AggchainProofServiceRequest, FixtureError> {
+    let bytes = std::fs::read(path).map_err(io_error(path))?;
+
+    serde_json::from_slice(&bytes).map_err(|source| FixtureError::Json {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Loads every request fixture of the directory, in the order of their file
+/// names, which is the order they were recorded in when named after it.
+pub fn load_requests(
+    dir: &Path,
+) -> Result<Vec<(PathBuf, AggchainProofServiceRequest)>, FixtureError> {
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(io_error(dir))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io_error(dir))?;
+    paths.retain(|path| {
+        path.is_file() && path.extension().is_some_and(|ext| ext == FIXTURE_EXTENSION)
+    });
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| load_request(&path).map(|request| (path, request)))
+        .collect()
+}
+
+pub fn save_request(
+    path: &Path,
+    request: &AggchainProofServiceRequest,
+) -> Result<(), FixtureError> {
+    let json = serde_json::to_vec_pretty(request).map_err(|source| FixtureError::Json {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(io_error(dir))?;
+    }
+    // Written aside then renamed, so that a fixture is never read partially.
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, json).map_err(io_error(&partial))?;
+    std::fs::rename(&partial, path).map_err(io_error(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aggchain_proof_types::{
+        inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+        AggchainProofInputs,
+    };
+    use aggkit_prover_types::{
+        envelope::ProofMode, stage_timings::StageTimings, vkey_hash::VKeyHash,
+    };
+    use agglayer_interop::types::{Digest, L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+    use unified_bridge::AggchainProofPublicValues;
+
+    use super::*;
+    use crate::service::AggchainProofServiceResponse;
+
+    fn leaf(ger: Digest) -> L1InfoTreeLeaf {
+        L1InfoTreeLeaf {
+            l1_info_tree_index: 3,
+            rer: Digest([1; 32]),
+            mer: Digest([2; 32]),
+            inner: L1InfoTreeLeafInner {
+                global_exit_root: ger,
+                block_hash: Digest([4; 32]),
+                timestamp: 1_700_000_000,
+            },
+        }
+    }
+
+    fn request() -> AggchainProofServiceRequest {
+        let ger = Digest([5; 32]);
+
+        AggchainProofServiceRequest::Normal(AggchainProofInputs {
+            last_proven_block: 100,
+            requested_end_block: 200,
+            l1_info_tree_root_hash: Digest([6; 32]),
+            l1_info_tree_leaf: leaf(Digest([7; 32])),
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest([6; 32]), [Digest([8; 32]); 32]),
+            ger_leaves: HashMap::from([(
+                ger.to_string(),
+                InsertedGerWithBlockNumber {
+                    block_number: 150,
+                    block_index: 1,
+                    inserted_ger: InsertedGer {
+                        proof_ger_l1root: MerkleProof::new(Digest([6; 32]), [Digest([9; 32]); 32]),
+                        l1_leaf: leaf(ger),
+                    },
+                },
+            )]),
+            imported_bridge_exits: vec![],
+            requested_vkey_hash: Some(Digest([11; 32])),
+        })
+    }
+
+    fn fixtures_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "aggchain-proof-fixtures-{name}-{}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn requests_are_replayed_as_recorded() {
+        let dir = fixtures_dir("replay");
+        let request = request();
+        save_request(&dir.join("0002.json"), &request).unwrap();
+        save_request(&dir.join("0001.json"), &request).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a fixture").unwrap();
+
+        let loaded = load_requests(&dir).unwrap();
+        assert_eq!(
+            loaded
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect::<Vec<_>>(),
+            [dir.join("0001.json"), dir.join("0002.json")]
+        );
+
+        // Saved back, the fixture is the same file.
+        let (path, loaded) = &loaded[0];
+        let resaved = dir.join("resaved.json");
+        save_request(&resaved, loaded).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(path).unwrap(),
+            std::fs::read_to_string(&resaved).unwrap()
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn hashes_are_hex_encoded() {
+        let json = serde_json::to_string(&request()).unwrap();
+        assert!(json.contains(&format!("0x{}", hex::encode([6; 32]))));
+
+        let response = AggchainProofServiceResponse {
+            proof: vec![0xab, 0xcd],
+            aggchain_params: Digest([1; 32]),
+            vkey: vec![0x01],
+            vkey_hash: VKeyHash::from_hash_u32([1, 2, 3, 4, 5, 6, 7, 8]),
+            proof_mode: ProofMode::Compressed,
+            last_proven_block: 100,
+            end_block: 200,
+            requested_end_block: 200,
+            local_exit_root_hash: Digest([2; 32]),
+            custom_chain_data: vec![0x02, 0x03],
+            public_values: AggchainProofPublicValues {
+                prev_local_exit_root: Digest([1; 32]),
+                new_local_exit_root: Digest([2; 32]),
+                l1_info_root: Digest([3; 32]),
+                origin_network: 7_u32.into(),
+                commit_imported_bridge_exits: Digest([4; 32]),
+                aggchain_params: Digest([5; 32]),
+            },
+            stage_timings: StageTimings::default(),
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["proof"], "0xabcd");
+        assert_eq!(json["vkey"], "0x01");
+        assert_eq!(json["custom_chain_data"], "0x0203");
+
+        let decoded: AggchainProofServiceResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.proof, response.proof);
+    }
+
+    #[test]
+    fn errors_point_at_the_fixture() {
+        let dir = fixtures_dir("errors");
+        let path = dir.join("broken.json");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "{").unwrap();
+
+        let error = load_request(&path).unwrap_err();
+        assert!(
+            matches!(&error, FixtureError::Json { path: error_path, .. } if *error_path == path)
+        );
+        assert!(matches!(
+            load_request(&dir.join("missing.json")),
+            Err(FixtureError::Io { .. })
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
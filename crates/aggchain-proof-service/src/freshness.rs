@@ -0,0 +1,393 @@
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Weak,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aggchain_proof_contracts::contracts::L1SettledBlockFetcher;
+use aggchain_proof_types::AggchainProofInputs;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, warn};
+
+/// Policy rejecting the proof requests which refer to a stale l1 state, or
+/// which replay blocks whose certificate is already settled, rather than
+/// proving them.
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct FreshnessPolicy {
+    /// Maximum age of the l1 info tree leaf of a request, from its
+    /// timestamp. Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<prover_utils::with::HumanDuration>")]
+    #[schemars(with = "Option<prover_utils::with::HumanDuration>")]
+    pub max_l1_info_age: Option<Duration>,
+
+    /// Whether the requests starting before the latest block settled on l1
+    /// are rejected.
+    #[serde(default)]
+    pub reject_replays: bool,
+
+    /// Interval between two reads of the latest block settled on l1.
+    #[serde(default = "default_settlement_poll_interval")]
+    #[serde_as(as = "prover_utils::with::HumanDuration")]
+    #[schemars(with = "prover_utils::with::HumanDuration")]
+    pub settlement_poll_interval: Duration,
+
+    /// File the latest settled block is persisted to, for the replays to be
+    /// rejected on restart before l1 is read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settled_block_path: Option<PathBuf>,
+}
+
+impl Default for FreshnessPolicy {
+    fn default() -> Self {
+        Self {
+            max_l1_info_age: None,
+            reject_replays: false,
+            settlement_poll_interval: default_settlement_poll_interval(),
+            settled_block_path: None,
+        }
+    }
+}
+
+const fn default_settlement_poll_interval() -> Duration {
+    Duration::from_secs(60)
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum FreshnessError {
+    #[error("Unable to access the settled block file {path:?}")]
+    SettledBlockFile {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Reason for which one proof request is rejected as stale.
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum StaleRequest {
+    #[error(
+        "The l1 info tree leaf {l1_info_tree_index} is {age}s old, the maximum being {max_age}s"
+    )]
+    L1InfoTooOld {
+        l1_info_tree_index: u32,
+        age: u64,
+        max_age: u64,
+    },
+
+    #[error("The blocks after {last_proven_block} are settled up to the block {settled_block}")]
+    Replayed {
+        last_proven_block: u64,
+        settled_block: u64,
+    },
+}
+
+/// Enforces the [`FreshnessPolicy`], tracking the latest block settled on l1.
+#[derive(Debug, Default)]
+pub struct FreshnessGuard {
+    policy: FreshnessPolicy,
+    settled_block: AtomicU64,
+}
+
+impl FreshnessGuard {
+    /// Guard starting from the settled block persisted, if any.
+    pub fn new(policy: FreshnessPolicy) -> Result<Self, FreshnessError> {
+        let settled_block = match &policy.settled_block_path {
+            Some(path) => load_settled_block(path)?.unwrap_or_default(),
+            None => 0,
+        };
+
+        Ok(Self {
+            policy,
+            settled_block: AtomicU64::new(settled_block),
+        })
+    }
+
+    /// Guard whose settled block is read from l1 at once, then on every poll
+    /// until the guard is dropped, when the replays are rejected.
+    pub async fn spawn<ContractsClient>(
+        client: Arc<ContractsClient>,
+        policy: FreshnessPolicy,
+    ) -> Result<Arc<Self>, FreshnessError>
+    where
+        ContractsClient: L1SettledBlockFetcher + Send + Sync + 'static,
+    {
+        let guard = Arc::new(Self::new(policy)?);
+        if !guard.policy.reject_replays {
+            return Ok(guard);
+        }
+
+        if let Err(error) = guard.update(client.as_ref()).await {
+            warn!(
+                ?error,
+                settled_block = guard.settled_block.load(Ordering::Relaxed),
+                "Unable to read the latest settled block from l1"
+            );
+        }
+        tokio::spawn(Self::watch(
+            Arc::downgrade(&guard),
+            client,
+            guard.policy.settlement_poll_interval,
+        ));
+
+        Ok(guard)
+    }
+
+    pub fn check(&self, inputs: &AggchainProofInputs) -> Result<(), StaleRequest> {
+        self.check_at(inputs, SystemTime::now())
+    }
+
+    /// Records the latest block settled on l1, persisting it if it advanced.
+    pub fn record_settled(&self, settled_block: u64) {
+        let previous = self
+            .settled_block
+            .fetch_max(settled_block, Ordering::Relaxed);
+        if settled_block <= previous {
+            return;
+        }
+
+        debug!(settled_block, "Latest settled block advanced");
+        if let Some(path) = &self.policy.settled_block_path {
+            if let Err(error) = save_settled_block(path, settled_block) {
+                warn!(?error, "Unable to persist the latest settled block");
+            }
+        }
+    }
+
+    async fn watch<ContractsClient>(
+        guard: Weak<Self>,
+        client: Arc<ContractsClient>,
+        poll_interval: Duration,
+    ) where
+        ContractsClient: L1SettledBlockFetcher + Send + Sync,
+    {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes at once, the guard being just updated.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            let Some(guard) = guard.upgrade() else {
+                return;
+            };
+            if let Err(error) = guard.update(client.as_ref()).await {
+                warn!(?error, "Unable to read the latest settled block from l1");
+            }
+        }
+    }
+
+    async fn update<ContractsClient>(
+        &self,
+        client: &ContractsClient,
+    ) -> Result<(), aggchain_proof_contracts::Error>
+    where
+        ContractsClient: L1SettledBlockFetcher + Send + Sync,
+    {
+        let settled_block = client.get_l1_settled_block().await?;
+        self.record_settled(settled_block);
+
+        Ok(())
+    }
+
+    fn check_at(&self, inputs: &AggchainProofInputs, now: SystemTime) -> Result<(), StaleRequest> {
+        if let Some(max_age) = self.policy.max_l1_info_age {
+            let leaf = &inputs.l1_info_tree_leaf;
+            let age = now
+                .duration_since(UNIX_EPOCH + Duration::from_secs(leaf.inner.timestamp))
+                .unwrap_or_default();
+            if age > max_age {
+                return Err(StaleRequest::L1InfoTooOld {
+                    l1_info_tree_index: leaf.l1_info_tree_index,
+                    age: age.as_secs(),
+                    max_age: max_age.as_secs(),
+                });
+            }
+        }
+
+        if self.policy.reject_replays {
+            let settled_block = self.settled_block.load(Ordering::Relaxed);
+            if inputs.last_proven_block < settled_block {
+                return Err(StaleRequest::Replayed {
+                    last_proven_block: inputs.last_proven_block,
+                    settled_block,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the settled block persisted to `path`, none being persisted yet if
+/// the file does not exist.
+fn load_settled_block(path: &Path) -> Result<Option<u64>, FreshnessError> {
+    let error = |source| FreshnessError::SettledBlockFile {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    match std::fs::read_to_string(path) {
+        Ok(content) => content
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|source| error(std::io::Error::new(ErrorKind::InvalidData, source))),
+        Err(source) if source.kind() == ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(error(source)),
+    }
+}
+
+/// Writes the settled block to a temporary file renamed over `path`, for a
+/// partially written block not to be read.
+fn save_settled_block(path: &Path, settled_block: u64) -> Result<(), FreshnessError> {
+    let error = |source| FreshnessError::SettledBlockFile {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, settled_block.to_string()).map_err(error)?;
+    std::fs::rename(&partial, path).map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_interop::types::{Digest, L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+
+    use super::*;
+
+    fn inputs(last_proven_block: u64, timestamp: u64) -> AggchainProofInputs {
+        AggchainProofInputs {
+            last_proven_block,
+            requested_end_block: last_proven_block + 100,
+            l1_info_tree_root_hash: Default::default(),
+            l1_info_tree_leaf: L1InfoTreeLeaf {
+                l1_info_tree_index: 7,
+                rer: Default::default(),
+                mer: Default::default(),
+                inner: L1InfoTreeLeafInner {
+                    global_exit_root: Default::default(),
+                    block_hash: Default::default(),
+                    timestamp,
+                },
+            },
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+            ger_leaves: Default::default(),
+            imported_bridge_exits: Default::default(),
+            requested_vkey_hash: None,
+        }
+    }
+
+    #[test]
+    fn stale_l1_info_is_rejected() {
+        let guard = FreshnessGuard::new(FreshnessPolicy {
+            max_l1_info_age: Some(Duration::from_secs(3600)),
+            ..Default::default()
+        })
+        .unwrap();
+        let now = UNIX_EPOCH + Duration::from_secs(10_000);
+
+        assert_eq!(guard.check_at(&inputs(0, 6_400), now), Ok(()));
+        assert_eq!(
+            guard.check_at(&inputs(0, 6_399), now),
+            Err(StaleRequest::L1InfoTooOld {
+                l1_info_tree_index: 7,
+                age: 3_601,
+                max_age: 3_600,
+            })
+        );
+        // Leaves from the future aren't stale.
+        assert_eq!(guard.check_at(&inputs(0, 20_000), now), Ok(()));
+    }
+
+    #[test]
+    fn replays_of_settled_blocks_are_rejected() {
+        let now = SystemTime::now();
+        let lenient = FreshnessGuard::default();
+        let guard = FreshnessGuard::new(FreshnessPolicy {
+            reject_replays: true,
+            ..Default::default()
+        })
+        .unwrap();
+        for guard in [&lenient, &guard] {
+            guard.record_settled(200);
+            guard.record_settled(100);
+        }
+
+        assert_eq!(lenient.check_at(&inputs(100, 0), now), Ok(()));
+        // A retry of the last request is not a replay.
+        assert_eq!(guard.check_at(&inputs(200, 0), now), Ok(()));
+        assert_eq!(
+            guard.check_at(&inputs(100, 0), now),
+            Err(StaleRequest::Replayed {
+                last_proven_block: 100,
+                settled_block: 200,
+            })
+        );
+    }
+
+    #[test]
+    fn settled_block_is_persisted() {
+        let dir = std::env::temp_dir().join(format!("settled-block-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let policy = FreshnessPolicy {
+            reject_replays: true,
+            settled_block_path: Some(dir.join("settled-block")),
+            ..Default::default()
+        };
+
+        let guard = FreshnessGuard::new(policy.clone()).unwrap();
+        guard.record_settled(200);
+        guard.record_settled(100);
+
+        let restarted = FreshnessGuard::new(policy).unwrap();
+        assert_eq!(
+            restarted.check_at(&inputs(100, 0), SystemTime::now()),
+            Err(StaleRequest::Replayed {
+                last_proven_block: 100,
+                settled_block: 200,
+            })
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn settled_block_is_read_from_l1() {
+        struct SettledOnL1(u64);
+
+        #[async_trait::async_trait]
+        impl L1SettledBlockFetcher for SettledOnL1 {
+            async fn get_l1_settled_block(&self) -> Result<u64, aggchain_proof_contracts::Error> {
+                Ok(self.0)
+            }
+        }
+
+        let policy = FreshnessPolicy {
+            reject_replays: true,
+            ..Default::default()
+        };
+        let guard = FreshnessGuard::spawn(Arc::new(SettledOnL1(200)), policy)
+            .await
+            .unwrap();
+
+        assert_eq!(guard.check_at(&inputs(200, 0), SystemTime::now()), Ok(()));
+        assert_eq!(
+            guard.check_at(&inputs(100, 0), SystemTime::now()),
+            Err(StaleRequest::Replayed {
+                last_proven_block: 100,
+                settled_block: 200,
+            })
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Check of the GERs claimed by the proof requests against the l1, rejecting
+//! the requests citing a GER which the global exit root manager of the l1
+//! does not know before anything is proven.
+//!
+//! The GER of a leaf is added on l1 in the block of the leaf, hence at its
+//! timestamp. A GER stays in the global exit root manager once added, so the
+//! GERs found are remembered rather than read again for the next requests.
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use aggchain_proof_contracts::contracts::L1GlobalExitRootFetcher;
+use aggchain_proof_types::AggchainProofInputs;
+use agglayer_interop::types::Digest;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::Error;
+
+/// Check of the GERs claimed by the proof requests against the l1.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct GerLivenessConfig {
+    /// Number of GERs found on l1 which are remembered, the oldest ones being
+    /// read again once forgotten.
+    #[serde(default = "default_cache_size")]
+    pub cache_size: usize,
+}
+
+impl Default for GerLivenessConfig {
+    fn default() -> Self {
+        Self {
+            cache_size: default_cache_size(),
+        }
+    }
+}
+
+const fn default_cache_size() -> usize {
+    10_000
+}
+
+/// GER claimed by a proof request which is not the one of the l1.
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum InvalidGer {
+    #[error(
+        "The GER {ger} inserted at block {block_number} (index {block_index}) does not exist on l1"
+    )]
+    Missing {
+        ger: Digest,
+        block_number: u64,
+        block_index: u64,
+    },
+
+    #[error(
+        "The GER {ger} inserted at block {block_number} (index {block_index}) was added on l1 at \
+         {l1_timestamp}, not at the timestamp {timestamp} of its l1 info tree leaf"
+    )]
+    TimestampMismatch {
+        ger: Digest,
+        block_number: u64,
+        block_index: u64,
+        timestamp: u64,
+        l1_timestamp: u64,
+    },
+}
+
+/// Checks the GERs of the requests, remembering the ones found on l1.
+pub struct GerLivenessCheck<ContractsClient> {
+    contracts_client: Arc<ContractsClient>,
+    cache_size: usize,
+    found: Mutex<FoundGers>,
+}
+
+/// Timestamps of the GERs found on l1, forgotten in the order they were
+/// found.
+#[derive(Default)]
+struct FoundGers {
+    timestamps: HashMap<Digest, u64>,
+    order: VecDeque<Digest>,
+}
+
+impl<ContractsClient> GerLivenessCheck<ContractsClient>
+where
+    ContractsClient: L1GlobalExitRootFetcher + Send + Sync,
+{
+    pub fn new(contracts_client: Arc<ContractsClient>, config: &GerLivenessConfig) -> Self {
+        Self {
+            contracts_client,
+            cache_size: config.cache_size,
+            found: Mutex::new(FoundGers::default()),
+        }
+    }
+
+    /// Checks the GERs of the request in their order of insertion, failing on
+    /// the first one which is not on l1.
+    pub async fn check(&self, inputs: &AggchainProofInputs) -> Result<(), Error> {
+        let mut inserted_gers = inputs.ger_leaves.values().collect::<Vec<_>>();
+        inserted_gers
+            .sort_by_key(|inserted_ger| (inserted_ger.block_number, inserted_ger.block_index));
+
+        for inserted_ger in inserted_gers {
+            let leaf = &inserted_ger.inserted_ger.l1_leaf.inner;
+            let ger = leaf.global_exit_root;
+
+            let cached = self
+                .found
+                .lock()
+                .expect("GER liveness lock poisoned")
+                .timestamps
+                .get(&ger)
+                .copied();
+            let l1_timestamp = match cached {
+                Some(l1_timestamp) => l1_timestamp,
+                None => {
+                    let l1_timestamp = self
+                        .contracts_client
+                        .get_l1_global_exit_root_timestamp(ger)
+                        .await
+                        .map_err(Error::GerLivenessCheckFailed)?;
+                    if l1_timestamp == 0 {
+                        return Err(Error::InvalidGer(InvalidGer::Missing {
+                            ger,
+                            block_number: inserted_ger.block_number,
+                            block_index: inserted_ger.block_index,
+                        }));
+                    }
+                    self.remember(ger, l1_timestamp);
+                    l1_timestamp
+                }
+            };
+
+            if l1_timestamp != leaf.timestamp {
+                return Err(Error::InvalidGer(InvalidGer::TimestampMismatch {
+                    ger,
+                    block_number: inserted_ger.block_number,
+                    block_index: inserted_ger.block_index,
+                    timestamp: leaf.timestamp,
+                    l1_timestamp,
+                }));
+            }
+        }
+
+        debug!(gers = inputs.ger_leaves.len(), "GERs found on l1");
+        Ok(())
+    }
+
+    fn remember(&self, ger: Digest, l1_timestamp: u64) {
+        if self.cache_size == 0 {
+            return;
+        }
+
+        let mut found = self.found.lock().expect("GER liveness lock poisoned");
+        if found.timestamps.insert(ger, l1_timestamp).is_none() {
+            found.order.push_back(ger);
+        }
+        while found.order.len() > self.cache_size {
+            if let Some(forgotten) = found.order.pop_front() {
+                found.timestamps.remove(&forgotten);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::atomic::AtomicUsize};
+
+    use aggchain_proof_types::inserted_ger::{InsertedGer, InsertedGerWithBlockNumber};
+    use agglayer_interop::types::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+
+    use super::*;
+
+    /// Global exit root manager of the l1, counting the GERs read.
+    #[derive(Default)]
+    struct FakeL1 {
+        timestamps: HashMap<Digest, u64>,
+        reads: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl L1GlobalExitRootFetcher for FakeL1 {
+        async fn get_l1_global_exit_root_timestamp(
+            &self,
+            ger: Digest,
+        ) -> Result<u64, aggchain_proof_contracts::Error> {
+            self.reads
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(self.timestamps.get(&ger).copied().unwrap_or_default())
+        }
+    }
+
+    fn inserted_ger(ger: Digest, block_number: u64, timestamp: u64) -> InsertedGerWithBlockNumber {
+        InsertedGerWithBlockNumber {
+            block_number,
+            block_index: 0,
+            inserted_ger: InsertedGer {
+                proof_ger_l1root: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+                l1_leaf: L1InfoTreeLeaf {
+                    l1_info_tree_index: 1,
+                    rer: Default::default(),
+                    mer: Default::default(),
+                    inner: L1InfoTreeLeafInner {
+                        global_exit_root: ger,
+                        block_hash: Default::default(),
+                        timestamp,
+                    },
+                },
+            },
+        }
+    }
+
+    fn inputs(inserted_gers: Vec<InsertedGerWithBlockNumber>) -> AggchainProofInputs {
+        AggchainProofInputs {
+            last_proven_block: 0,
+            requested_end_block: 100,
+            l1_info_tree_root_hash: Default::default(),
+            l1_info_tree_leaf: inserted_ger(Digest::default(), 0, 0).inserted_ger.l1_leaf,
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+            ger_leaves: inserted_gers
+                .into_iter()
+                .map(|inserted_ger| {
+                    let ger = inserted_ger.inserted_ger.l1_leaf.inner.global_exit_root;
+                    (ger.to_string(), inserted_ger)
+                })
+                .collect(),
+            imported_bridge_exits: Default::default(),
+            requested_vkey_hash: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn gers_found_on_l1_are_remembered() {
+        let (first, second) = (Digest([1; 32]), Digest([2; 32]));
+        let l1 = Arc::new(FakeL1 {
+            timestamps: HashMap::from([(first, 100), (second, 200)]),
+            ..Default::default()
+        });
+        let check = GerLivenessCheck::new(l1.clone(), &GerLivenessConfig::default());
+        let inputs = inputs(vec![
+            inserted_ger(first, 10, 100),
+            inserted_ger(second, 20, 200),
+        ]);
+
+        check.check(&inputs).await.unwrap();
+        check.check(&inputs).await.unwrap();
+
+        assert_eq!(l1.reads.load(std::sync::atomic::Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn unknown_gers_are_rejected() {
+        let (known, unknown) = (Digest([1; 32]), Digest([2; 32]));
+        let l1 = Arc::new(FakeL1 {
+            timestamps: HashMap::from([(known, 100)]),
+            ..Default::default()
+        });
+        let check = GerLivenessCheck::new(l1, &GerLivenessConfig::default());
+
+        let error = check
+            .check(&inputs(vec![
+                inserted_ger(known, 10, 100),
+                inserted_ger(unknown, 20, 200),
+            ]))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::InvalidGer(InvalidGer::Missing {
+                    ger,
+                    block_number: 20,
+                    ..
+                }) if ger == unknown
+            ),
+            "unexpected error {error:?}"
+        );
+
+        // Found on l1, but not at the timestamp of its leaf.
+        let error = check
+            .check(&inputs(vec![inserted_ger(known, 10, 150)]))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(
+                error,
+                Error::InvalidGer(InvalidGer::TimestampMismatch {
+                    timestamp: 150,
+                    l1_timestamp: 100,
+                    ..
+                })
+            ),
+            "unexpected error {error:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn oldest_gers_are_forgotten() {
+        let gers = [Digest([1; 32]), Digest([2; 32]), Digest([3; 32])];
+        let l1 = Arc::new(FakeL1 {
+            timestamps: gers.iter().map(|ger| (*ger, 100)).collect(),
+            ..Default::default()
+        });
+        let check = GerLivenessCheck::new(l1.clone(), &GerLivenessConfig { cache_size: 2 });
+
+        for (block_number, ger) in gers.iter().enumerate() {
+            check
+                .check(&inputs(vec![inserted_ger(*ger, block_number as u64, 100)]))
+                .await
+                .unwrap();
+        }
+        let reads = || l1.reads.load(std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(reads(), 3);
+
+        // The last GERs are remembered, the first one is read again.
+        check
+            .check(&inputs(vec![
+                inserted_ger(gers[1], 10, 100),
+                inserted_ger(gers[2], 11, 100),
+            ]))
+            .await
+            .unwrap();
+        assert_eq!(reads(), 3);
+        check
+            .check(&inputs(vec![inserted_ger(gers[0], 12, 100)]))
+            .await
+            .unwrap();
+        assert_eq!(reads(), 4);
+    }
+}
@@ -0,0 +1,429 @@
+//! Mirror of the l1 info tree, updated in the background from the events of
+//! the l1 global exit root manager, such that the l1 info tree leaves and root
+//! of the requests are checked locally rather than read from l1 per request.
+//!
+//! Each leaf is appended to a local copy of the tree, whose root is checked
+//! against the one emitted along with the leaf. The mirror stops at the first
+//! inconsistency, the requests beyond it being checked against l1 as before.
+use std::{
+    sync::{Arc, RwLock, Weak},
+    time::Duration,
+};
+
+use aggchain_proof_contracts::contracts::{IndexedL1InfoTreeLeaf, L1InfoTreeLeavesFetcher};
+use aggchain_proof_types::AggchainProofInputs;
+use agglayer_interop::types::{Digest, L1InfoTreeLeaf};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error, warn};
+use unified_bridge::LocalExitTree;
+
+use crate::Error;
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct L1InfoTreeMirrorConfig {
+    /// L1 block the leaves are read from, at most the block of the first leaf
+    /// of the tree.
+    #[serde(default)]
+    pub from_block: u64,
+
+    /// Largest block range of the l1 info tree events read at once.
+    #[serde(default = "default_max_block_range")]
+    pub max_block_range: u64,
+
+    /// Interval between two updates of the mirror.
+    #[serde(
+        default = "default_poll_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub poll_interval: Duration,
+}
+
+const fn default_max_block_range() -> u64 {
+    10_000
+}
+
+const fn default_poll_interval() -> Duration {
+    Duration::from_secs(12)
+}
+
+/// L1 info tree data of a request which differs from the one of the l1.
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum L1InfoTreeMismatch {
+    #[error("The l1 info tree leaf {l1_info_tree_index} differs from the one of l1")]
+    Leaf { l1_info_tree_index: u32 },
+
+    #[error(
+        "The l1 info tree leaf {l1_info_tree_index} of the GER {ger} differs from the one of l1"
+    )]
+    GerLeaf {
+        ger: Digest,
+        l1_info_tree_index: u32,
+    },
+
+    #[error(
+        "The l1 info tree root {root} differs from the root {mirrored} of l1 after the leaf \
+         {l1_info_tree_index}"
+    )]
+    Root {
+        l1_info_tree_index: u32,
+        root: Digest,
+        mirrored: Digest,
+    },
+}
+
+/// Leaf of the mirrored tree, along with the root of the tree once added.
+struct MirroredLeaf {
+    leaf: L1InfoTreeLeaf,
+    root: Digest,
+}
+
+pub struct L1InfoTreeMirror<ContractsClient> {
+    client: Arc<ContractsClient>,
+    config: L1InfoTreeMirrorConfig,
+    leaves: RwLock<Vec<MirroredLeaf>>,
+}
+
+/// Progress of the updates, owned by the update task.
+struct Indexing {
+    /// Last block whose leaves are mirrored.
+    indexed_up_to: Option<u64>,
+    tree: LocalExitTree,
+    /// Set once the leaves of l1 are inconsistent, the mirror being stopped.
+    stopped: bool,
+}
+
+impl<ContractsClient> L1InfoTreeMirror<ContractsClient>
+where
+    ContractsClient: L1InfoTreeLeavesFetcher + Send + Sync + 'static,
+{
+    /// Starts mirroring the l1 info tree, until the mirror is dropped.
+    pub fn spawn(client: Arc<ContractsClient>, config: L1InfoTreeMirrorConfig) -> Arc<Self> {
+        let mirror = Arc::new(Self::new(client, config));
+        tokio::spawn(Self::watch(
+            Arc::downgrade(&mirror),
+            mirror.config.poll_interval,
+        ));
+
+        mirror
+    }
+
+    fn new(client: Arc<ContractsClient>, config: L1InfoTreeMirrorConfig) -> Self {
+        Self {
+            client,
+            config,
+            leaves: RwLock::default(),
+        }
+    }
+
+    /// Checks the l1 info tree leaf and root of the request, along with the
+    /// leaves of its GERs, returning whether all of them are mirrored yet.
+    pub fn check(&self, inputs: &AggchainProofInputs) -> Result<bool, Error> {
+        let leaves = self
+            .leaves
+            .read()
+            .expect("l1 info tree mirror lock poisoned");
+        let mirrored = |leaf: &L1InfoTreeLeaf| leaves.get(leaf.l1_info_tree_index as usize);
+
+        let Some(head) = mirrored(&inputs.l1_info_tree_leaf) else {
+            return Ok(false);
+        };
+        let l1_info_tree_index = inputs.l1_info_tree_leaf.l1_info_tree_index;
+        if !same_leaf(&head.leaf, &inputs.l1_info_tree_leaf) {
+            return Err(Error::L1InfoTreeMismatch(L1InfoTreeMismatch::Leaf {
+                l1_info_tree_index,
+            }));
+        }
+        if head.root != inputs.l1_info_tree_root_hash {
+            return Err(Error::L1InfoTreeMismatch(L1InfoTreeMismatch::Root {
+                l1_info_tree_index,
+                root: inputs.l1_info_tree_root_hash,
+                mirrored: head.root,
+            }));
+        }
+
+        // The GERs are proven against the same root, hence mirrored as well.
+        for inserted_ger in inputs.ger_leaves.values() {
+            let leaf = &inserted_ger.inserted_ger.l1_leaf;
+            if !mirrored(leaf).is_some_and(|mirrored| same_leaf(&mirrored.leaf, leaf)) {
+                return Err(Error::L1InfoTreeMismatch(L1InfoTreeMismatch::GerLeaf {
+                    ger: leaf.inner.global_exit_root,
+                    l1_info_tree_index: leaf.l1_info_tree_index,
+                }));
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn watch(mirror: Weak<Self>, poll_interval: Duration) {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        let mut indexing = Indexing::new();
+        while !indexing.stopped {
+            interval.tick().await;
+            let Some(mirror) = mirror.upgrade() else {
+                return;
+            };
+            if let Err(error) = mirror.update(&mut indexing).await {
+                warn!(?error, "Unable to update the l1 info tree mirror");
+            }
+        }
+    }
+
+    /// Mirrors the leaves added up to the l1 head.
+    async fn update(&self, indexing: &mut Indexing) -> Result<(), aggchain_proof_contracts::Error> {
+        let l1_head = self.client.get_l1_head().await?;
+        let mut from_block = indexing
+            .indexed_up_to
+            .map_or(self.config.from_block, |indexed_up_to| indexed_up_to + 1);
+        let max_block_range = self.config.max_block_range.max(1);
+
+        while from_block <= l1_head {
+            let range_end = l1_head.min(from_block.saturating_add(max_block_range - 1));
+            let leaves = self
+                .client
+                .get_l1_info_tree_leaves(from_block, range_end)
+                .await?;
+
+            for indexed in leaves {
+                if let Err(reason) = indexing.append(&indexed) {
+                    error!(
+                        block_number = indexed.block_number,
+                        l1_info_tree_index = indexed.leaf.l1_info_tree_index,
+                        reason,
+                        "Stopping the l1 info tree mirror"
+                    );
+                    indexing.stopped = true;
+                    return Ok(());
+                }
+                self.leaves
+                    .write()
+                    .expect("l1 info tree mirror lock poisoned")
+                    .push(MirroredLeaf {
+                        leaf: indexed.leaf,
+                        root: indexed.root,
+                    });
+            }
+
+            indexing.indexed_up_to = Some(range_end);
+            from_block = range_end + 1;
+        }
+
+        debug!(
+            l1_head,
+            leaves = indexing.tree.leaf_count,
+            "L1 info tree mirror updated"
+        );
+        Ok(())
+    }
+}
+
+impl Indexing {
+    fn new() -> Self {
+        Self {
+            indexed_up_to: None,
+            tree: LocalExitTree::new(),
+            stopped: false,
+        }
+    }
+
+    /// Appends the leaf to the tree, checking it against the emitted root.
+    fn append(&mut self, indexed: &IndexedL1InfoTreeLeaf) -> Result<(), &'static str> {
+        if indexed.leaf.l1_info_tree_index != self.tree.leaf_count {
+            return Err("non contiguous leaf");
+        }
+        self.tree
+            .add_leaf(indexed.leaf.hash())
+            .map_err(|_| "full tree")?;
+        if self.tree.get_root() != indexed.root {
+            return Err("root differing from the emitted one");
+        }
+
+        Ok(())
+    }
+}
+
+fn same_leaf(mirrored: &L1InfoTreeLeaf, leaf: &L1InfoTreeLeaf) -> bool {
+    mirrored.l1_info_tree_index == leaf.l1_info_tree_index
+        && mirrored.rer == leaf.rer
+        && mirrored.mer == leaf.mer
+        && mirrored.inner.global_exit_root == leaf.inner.global_exit_root
+        && mirrored.inner.block_hash == leaf.inner.block_hash
+        && mirrored.inner.timestamp == leaf.inner.timestamp
+}
+
+#[cfg(test)]
+mod tests {
+    use aggchain_proof_types::inserted_ger::{InsertedGer, InsertedGerWithBlockNumber};
+    use agglayer_interop::types::{L1InfoTreeLeafInner, MerkleProof};
+
+    use super::*;
+
+    /// Global exit root manager of the l1, with a leaf every ten blocks.
+    struct FakeL1 {
+        leaves: Vec<IndexedL1InfoTreeLeaf>,
+    }
+
+    impl FakeL1 {
+        fn new(leaf_count: u32) -> Self {
+            let mut tree = LocalExitTree::new();
+            let leaves = (0..leaf_count)
+                .map(|l1_info_tree_index| {
+                    let leaf = leaf(l1_info_tree_index);
+                    tree.add_leaf(leaf.hash()).unwrap();
+                    IndexedL1InfoTreeLeaf {
+                        block_number: l1_info_tree_index as u64 * 10,
+                        leaf,
+                        root: tree.get_root(),
+                    }
+                })
+                .collect();
+
+            Self { leaves }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl L1InfoTreeLeavesFetcher for FakeL1 {
+        async fn get_l1_head(&self) -> Result<u64, aggchain_proof_contracts::Error> {
+            Ok(self.leaves.len() as u64 * 10)
+        }
+
+        async fn get_l1_info_tree_leaves(
+            &self,
+            from_block: u64,
+            to_block: u64,
+        ) -> Result<Vec<IndexedL1InfoTreeLeaf>, aggchain_proof_contracts::Error> {
+            Ok(self
+                .leaves
+                .iter()
+                .filter(|indexed| (from_block..=to_block).contains(&indexed.block_number))
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn leaf(l1_info_tree_index: u32) -> L1InfoTreeLeaf {
+        let byte = l1_info_tree_index as u8;
+        L1InfoTreeLeaf {
+            l1_info_tree_index,
+            rer: Digest([byte; 32]),
+            mer: Digest([byte + 1; 32]),
+            inner: L1InfoTreeLeafInner {
+                global_exit_root: Digest([byte + 2; 32]),
+                block_hash: Digest([byte + 3; 32]),
+                timestamp: 1_000 + l1_info_tree_index as u64,
+            },
+        }
+    }
+
+    fn inputs(l1: &FakeL1, l1_info_tree_index: u32, gers: &[u32]) -> AggchainProofInputs {
+        let proof = || MerkleProof::new(Digest::default(), [Digest::default(); 32]);
+        AggchainProofInputs {
+            last_proven_block: 0,
+            requested_end_block: 100,
+            l1_info_tree_root_hash: l1
+                .leaves
+                .get(l1_info_tree_index as usize)
+                .map_or(Digest::default(), |indexed| indexed.root),
+            l1_info_tree_leaf: leaf(l1_info_tree_index),
+            l1_info_tree_merkle_proof: proof(),
+            ger_leaves: gers
+                .iter()
+                .map(|index| {
+                    let inserted_ger = InsertedGerWithBlockNumber {
+                        block_number: *index as u64,
+                        block_index: 0,
+                        inserted_ger: InsertedGer {
+                            proof_ger_l1root: proof(),
+                            l1_leaf: leaf(*index),
+                        },
+                    };
+                    (index.to_string(), inserted_ger)
+                })
+                .collect(),
+            imported_bridge_exits: Default::default(),
+            requested_vkey_hash: None,
+        }
+    }
+
+    fn mirror(l1: FakeL1, max_block_range: u64) -> L1InfoTreeMirror<FakeL1> {
+        L1InfoTreeMirror::new(
+            Arc::new(l1),
+            L1InfoTreeMirrorConfig {
+                from_block: 0,
+                max_block_range,
+                poll_interval: default_poll_interval(),
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn requests_are_checked_against_the_mirror() {
+        let mirror = mirror(FakeL1::new(8), 25);
+        let mut indexing = Indexing::new();
+        mirror.update(&mut indexing).await.unwrap();
+        assert_eq!(
+            mirror
+                .leaves
+                .read()
+                .expect("l1 info tree mirror lock poisoned")
+                .len(),
+            8
+        );
+
+        let l1 = &mirror.client;
+        assert!(mirror.check(&inputs(l1, 5, &[1, 3])).unwrap());
+        // Not mirrored yet, left to the l1 reads.
+        assert!(!mirror.check(&inputs(l1, 9, &[1])).unwrap());
+
+        let mut wrong_root = inputs(l1, 5, &[]);
+        wrong_root.l1_info_tree_root_hash = l1.leaves[4].root;
+        assert!(matches!(
+            mirror.check(&wrong_root),
+            Err(Error::L1InfoTreeMismatch(L1InfoTreeMismatch::Root {
+                l1_info_tree_index: 5,
+                ..
+            }))
+        ));
+
+        let mut wrong_ger = inputs(l1, 5, &[2]);
+        for inserted_ger in wrong_ger.ger_leaves.values_mut() {
+            inserted_ger.inserted_ger.l1_leaf.inner.timestamp += 1;
+        }
+        assert!(matches!(
+            mirror.check(&wrong_ger),
+            Err(Error::L1InfoTreeMismatch(L1InfoTreeMismatch::GerLeaf {
+                l1_info_tree_index: 2,
+                ..
+            }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn inconsistent_leaves_stop_the_mirror() {
+        let mut l1 = FakeL1::new(4);
+        l1.leaves[2].root = Digest([0xff; 32]);
+        let mirror = mirror(l1, 100);
+        let mut indexing = Indexing::new();
+
+        mirror.update(&mut indexing).await.unwrap();
+
+        assert!(indexing.stopped);
+        assert_eq!(
+            mirror
+                .leaves
+                .read()
+                .expect("l1 info tree mirror lock poisoned")
+                .len(),
+            2
+        );
+        let l1 = &mirror.client;
+        assert!(mirror.check(&inputs(l1, 1, &[0])).unwrap());
+        assert!(!mirror.check(&inputs(l1, 3, &[0])).unwrap());
+    }
+}
@@ -1,9 +1,18 @@
+#[cfg(feature = "chaos")]
+pub mod chaos;
 pub mod config;
 
 mod custom_chain_data;
 mod error;
+pub mod fixture;
+pub mod freshness;
+pub mod ger_liveness;
+pub mod l1_info_tree;
+pub mod optimistic_mode;
+pub mod request_cost;
 pub mod service;
+pub mod warm_pipeline;
 
-pub use aggchain_proof_builder::AGGCHAIN_PROOF_ELF;
+pub use aggchain_proof_builder::{AGGCHAIN_PROGRAM_NAME, AGGCHAIN_PROOF_ELF};
 pub use custom_chain_data::AGGCHAIN_VKEY_SELECTOR;
 pub use error::Error;
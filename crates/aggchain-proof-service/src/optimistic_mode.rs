@@ -0,0 +1,181 @@
+use std::ops::RangeInclusive;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Policy deciding whether optimistic (ECDSA) mode requests are accepted.
+///
+/// Optimistic requests bypass the FEP verification, hence they are only
+/// accepted for the networks and the block ranges allowed by the policy. No
+/// network is allowed by default.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct OptimisticModePolicy {
+    /// Whether optimistic mode requests are accepted at all.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Networks for which optimistic mode requests are accepted. None if
+    /// empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_networks: Vec<u32>,
+
+    /// L2 block ranges within which optimistic mode requests are accepted.
+    /// Any block if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_block_ranges: Vec<BlockRange>,
+
+    /// Maximum number of L2 blocks covered by one optimistic mode request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_blocks_per_request: Option<u64>,
+}
+
+impl Default for OptimisticModePolicy {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            allowed_networks: vec![],
+            allowed_block_ranges: vec![],
+            max_blocks_per_request: None,
+        }
+    }
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Inclusive range of L2 blocks.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct BlockRange {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl BlockRange {
+    fn contains(&self, blocks: &RangeInclusive<u64>) -> bool {
+        self.start <= *blocks.start() && *blocks.end() <= self.end
+    }
+}
+
+/// Reason for which one optimistic mode request is rejected.
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum OptimisticModeRejection {
+    #[error("Optimistic mode is disabled")]
+    Disabled,
+
+    #[error("Optimistic mode is not allowed for the network {0}")]
+    NetworkNotAllowed(u32),
+
+    #[error("Optimistic mode is not allowed for the blocks {start} to {end}")]
+    BlocksNotAllowed { start: u64, end: u64 },
+
+    #[error("Optimistic mode request covers {requested} blocks, the maximum being {max}")]
+    TooManyBlocks { requested: u64, max: u64 },
+
+    #[error("Optimistic mode request is for the network {requested}, the prover serving {served}")]
+    NetworkNotServed { requested: u32, served: u32 },
+}
+
+impl OptimisticModePolicy {
+    /// Checks whether one optimistic mode request proving the given blocks of
+    /// the given network is allowed.
+    pub fn check(
+        &self,
+        network_id: u32,
+        blocks: RangeInclusive<u64>,
+    ) -> Result<(), OptimisticModeRejection> {
+        if !self.enabled {
+            return Err(OptimisticModeRejection::Disabled);
+        }
+
+        if !self.allowed_networks.contains(&network_id) {
+            return Err(OptimisticModeRejection::NetworkNotAllowed(network_id));
+        }
+
+        if !self.allowed_block_ranges.is_empty()
+            && !self
+                .allowed_block_ranges
+                .iter()
+                .any(|range| range.contains(&blocks))
+        {
+            return Err(OptimisticModeRejection::BlocksNotAllowed {
+                start: *blocks.start(),
+                end: *blocks.end(),
+            });
+        }
+
+        if let Some(max) = self.max_blocks_per_request {
+            let requested = blocks
+                .end()
+                .saturating_sub(*blocks.start())
+                .saturating_add(1);
+            if requested > max {
+                return Err(OptimisticModeRejection::TooManyBlocks { requested, max });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_allows_no_network() {
+        assert_eq!(
+            OptimisticModePolicy::default().check(1, 1..=1000),
+            Err(OptimisticModeRejection::NetworkNotAllowed(1))
+        );
+    }
+
+    #[test]
+    fn disabled() {
+        let policy = OptimisticModePolicy {
+            enabled: false,
+            allowed_networks: vec![1],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            policy.check(1, 1..=10),
+            Err(OptimisticModeRejection::Disabled)
+        );
+    }
+
+    #[test]
+    fn restricted_networks_and_blocks() {
+        let policy = OptimisticModePolicy {
+            allowed_networks: vec![1],
+            allowed_block_ranges: vec![BlockRange {
+                start: 100,
+                end: 200,
+            }],
+            max_blocks_per_request: Some(50),
+            ..Default::default()
+        };
+
+        assert_eq!(policy.check(1, 100..=149), Ok(()));
+        assert_eq!(
+            policy.check(2, 100..=149),
+            Err(OptimisticModeRejection::NetworkNotAllowed(2))
+        );
+        assert_eq!(
+            policy.check(1, 150..=250),
+            Err(OptimisticModeRejection::BlocksNotAllowed {
+                start: 150,
+                end: 250
+            })
+        );
+        assert_eq!(
+            policy.check(1, 100..=200),
+            Err(OptimisticModeRejection::TooManyBlocks {
+                requested: 101,
+                max: 50
+            })
+        );
+    }
+}
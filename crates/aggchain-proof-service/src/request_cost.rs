@@ -0,0 +1,182 @@
+use aggchain_proof_core::cycles::MerkleProofKind;
+use aggchain_proof_types::AggchainProofInputs;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Model estimating the proving cost of one request from its size, before
+/// proving it, to reject the requests too large to be proven at once.
+///
+/// The cycles per item are rough figures, to calibrate with `aggkit-prover
+/// bench` on the chain at hand. The merkle proofs verified in the guest are
+/// accounted for with their budgets, see [`MerkleProofKind::cycle_budget`],
+/// on top of the configured figures.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RequestCostModel {
+    /// Cycles of any request, whatever its size, besides the l1 head
+    /// inclusion proof.
+    #[serde(default = "default_base_cycles")]
+    pub base_cycles: u64,
+
+    /// Cycles per block of the requested range.
+    #[serde(default = "default_cycles_per_block")]
+    pub cycles_per_block: u64,
+
+    /// Cycles per inserted GER, besides its inclusion proof.
+    #[serde(default = "default_cycles_per_inserted_ger")]
+    pub cycles_per_inserted_ger: u64,
+
+    /// Cycles per imported bridge exit.
+    #[serde(default = "default_cycles_per_imported_bridge_exit")]
+    pub cycles_per_imported_bridge_exit: u64,
+
+    /// Maximum estimated cycles of one request. Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cycles: Option<u64>,
+}
+
+impl Default for RequestCostModel {
+    fn default() -> Self {
+        Self {
+            base_cycles: default_base_cycles(),
+            cycles_per_block: default_cycles_per_block(),
+            cycles_per_inserted_ger: default_cycles_per_inserted_ger(),
+            cycles_per_imported_bridge_exit: default_cycles_per_imported_bridge_exit(),
+            max_cycles: None,
+        }
+    }
+}
+
+/// Request whose estimated proving cost exceeds the maximum, along with the
+/// end block to request instead.
+#[derive(thiserror::Error, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "The request is estimated to {estimated_cycles} cycles, the maximum being {max_cycles}: split \
+     it, requesting up to the block {suggested_end_block} first"
+)]
+pub struct RequestTooCostly {
+    pub estimated_cycles: u64,
+    pub max_cycles: u64,
+    pub suggested_end_block: u64,
+}
+
+impl RequestCostModel {
+    /// Estimated cycles of proving the request.
+    pub fn estimate(&self, inputs: &AggchainProofInputs) -> u64 {
+        let blocks = inputs
+            .requested_end_block
+            .saturating_sub(inputs.last_proven_block);
+
+        let cycles_per_inserted_ger = self
+            .cycles_per_inserted_ger
+            .saturating_add(MerkleProofKind::InsertedGer.cycle_budget());
+
+        self.base_cycles
+            .saturating_add(MerkleProofKind::L1Head.cycle_budget())
+            .saturating_add(self.cycles_per_block.saturating_mul(blocks))
+            .saturating_add(cycles_per_inserted_ger.saturating_mul(inputs.ger_leaves.len() as u64))
+            .saturating_add(
+                self.cycles_per_imported_bridge_exit
+                    .saturating_mul(inputs.imported_bridge_exits.len() as u64),
+            )
+    }
+
+    /// Checks that the estimated cycles of the request don't exceed the
+    /// maximum, suggesting otherwise a range whose share of the estimate
+    /// fits.
+    pub fn check(&self, inputs: &AggchainProofInputs) -> Result<(), RequestTooCostly> {
+        let Some(max_cycles) = self.max_cycles else {
+            return Ok(());
+        };
+
+        let estimated_cycles = self.estimate(inputs);
+        if estimated_cycles <= max_cycles {
+            return Ok(());
+        }
+
+        let blocks = inputs
+            .requested_end_block
+            .saturating_sub(inputs.last_proven_block);
+        // The inserted GERs and imported bridge exits are assumed to be spread
+        // evenly over the range.
+        let suggested_blocks =
+            (u128::from(blocks) * u128::from(max_cycles) / u128::from(estimated_cycles)) as u64;
+
+        Err(RequestTooCostly {
+            estimated_cycles,
+            max_cycles,
+            suggested_end_block: inputs.last_proven_block + suggested_blocks.max(1),
+        })
+    }
+}
+
+const fn default_base_cycles() -> u64 {
+    100_000_000
+}
+
+const fn default_cycles_per_block() -> u64 {
+    50_000_000
+}
+
+const fn default_cycles_per_inserted_ger() -> u64 {
+    2_000_000
+}
+
+const fn default_cycles_per_imported_bridge_exit() -> u64 {
+    1_000_000
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_interop::types::{Digest, L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+
+    use super::*;
+
+    fn inputs(last_proven_block: u64, requested_end_block: u64) -> AggchainProofInputs {
+        AggchainProofInputs {
+            last_proven_block,
+            requested_end_block,
+            l1_info_tree_root_hash: Default::default(),
+            l1_info_tree_leaf: L1InfoTreeLeaf {
+                l1_info_tree_index: 0,
+                rer: Default::default(),
+                mer: Default::default(),
+                inner: L1InfoTreeLeafInner {
+                    global_exit_root: Default::default(),
+                    block_hash: Default::default(),
+                    timestamp: 0,
+                },
+            },
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+            ger_leaves: Default::default(),
+            imported_bridge_exits: Default::default(),
+            requested_vkey_hash: None,
+        }
+    }
+
+    #[test]
+    fn costly_requests_are_rejected_with_a_split_point() {
+        let l1_head = MerkleProofKind::L1Head.cycle_budget();
+        let model = RequestCostModel {
+            base_cycles: 0,
+            cycles_per_block: l1_head / 100,
+            max_cycles: Some(2 * l1_head),
+            ..Default::default()
+        };
+
+        assert_eq!(model.estimate(&inputs(100, 200)), 2 * l1_head);
+        assert_eq!(model.check(&inputs(100, 200)), Ok(()));
+        assert_eq!(
+            model.check(&inputs(100, 500)),
+            Err(RequestTooCostly {
+                estimated_cycles: 5 * l1_head,
+                max_cycles: 2 * l1_head,
+                suggested_end_block: 260,
+            })
+        );
+
+        // Unlimited by default.
+        let unlimited = RequestCostModel::default();
+        assert_eq!(unlimited.check(&inputs(0, 1_000_000)), Ok(()));
+    }
+}
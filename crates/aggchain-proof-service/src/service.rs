@@ -3,27 +3,44 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Instant,
 };
 
-use aggchain_proof_builder::{AggchainProofBuilder, FepVerification};
-use aggchain_proof_contracts::AggchainContractsRpcClient;
+use aggchain_proof_builder::{programs::ProgramRegistry, AggchainProofBuilder, FepVerification};
+use aggchain_proof_contracts::{prefetch::PrefetchingClient, AggchainContractsRpcClient};
 use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
+use aggkit_prover_types::{
+    envelope::ProofMode,
+    stage_timings::{Stage, StageTimings},
+    vkey_hash::VKeyHash,
+};
 use agglayer_interop::types::Digest;
 use alloy_primitives::B256;
 use futures::FutureExt as _;
 use proposer_client::FepProposerRequest;
-use proposer_service::ProposerService;
+use proposer_service::{cache::SpanProofCache, pending::PendingRequests, ProposerService};
+use prover_alloy::AlloyFillProvider;
+use prover_executor::cost::CostLedger;
+use serde::{Deserialize, Serialize};
 use tower::{util::BoxCloneService, Service as _, ServiceExt as _};
-use tracing::debug;
+use tracing::{debug, info_span, Instrument as _};
 use unified_bridge::AggchainProofPublicValues;
 
 use crate::{
-    config::AggchainProofServiceConfig, custom_chain_data::compute_custom_chain_data, error::Error,
+    config::AggchainProofServiceConfig,
+    custom_chain_data::compute_custom_chain_data,
+    error::Error,
+    freshness::FreshnessGuard,
+    ger_liveness::GerLivenessCheck,
+    l1_info_tree::L1InfoTreeMirror,
+    optimistic_mode::{OptimisticModePolicy, OptimisticModeRejection},
+    request_cost::RequestCostModel,
+    warm_pipeline::WarmPipeline,
 };
 
 /// A request for the AggchainProofService to generate the
 /// aggchain proof for the range of blocks.
-#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum AggchainProofServiceRequest {
     /// Aggchain proof request information
     Normal(AggchainProofInputs),
@@ -32,23 +49,36 @@ pub enum AggchainProofServiceRequest {
 }
 
 /// Resulting generated Aggchain proof
+#[derive(Serialize, Deserialize)]
 pub struct AggchainProofServiceResponse {
     /// Aggchain proof generated by the `aggchain-proof-builder` service
     /// per `agg-sender` request.
+    #[serde(with = "prover_utils::with::HexBytes")]
     pub proof: Vec<u8>,
 
     /// Aggchain params
     pub aggchain_params: Digest,
 
     /// Aggchain verification key
+    #[serde(with = "prover_utils::with::HexBytes")]
     pub vkey: Vec<u8>,
 
+    /// Hash of the aggchain verification key.
+    pub vkey_hash: VKeyHash,
+
+    /// SP1 proof mode of the aggchain proof.
+    pub proof_mode: ProofMode,
+
     /// Last block proven before this aggchain proof.
     pub last_proven_block: u64,
 
     /// Last block in the aggchain proof (inclusive).
     pub end_block: u64,
 
+    /// End block requested by the agg-sender, after `end_block` when the
+    /// proven range was shortened.
+    pub requested_end_block: u64,
+
     /// Local exit root calculated by the aggkit-prover for all the bridge
     /// changes included in the proof. Mismatch between LER calculation on the
     /// agglayer and the L2 is possible due to the field -
@@ -59,10 +89,16 @@ pub struct AggchainProofServiceResponse {
     /// agg-sender to fill in related certificate field.
     /// Consists off the two bytes for aggchain selector, 32 bytes for the
     /// output_root (new state root) and the l2 end block number.
+    #[serde(with = "prover_utils::with::HexBytes")]
     pub custom_chain_data: Vec<u8>,
 
     /// The AggchainProof's public inputs that were produced by the prover.
     pub public_values: AggchainProofPublicValues,
+
+    /// Time spent in each stage of the request, by the service and the
+    /// services it called.
+    #[serde(default)]
+    pub stage_timings: StageTimings,
 }
 
 /// The Aggchain proof service is responsible for orchestrating an Aggchain
@@ -83,65 +119,229 @@ pub struct AggchainProofService {
         aggchain_proof_builder::AggchainProofBuilderResponse,
         aggchain_proof_builder::Error,
     >,
+    /// Network id of the l2 chain for which the proofs are generated.
+    pub(crate) network_id: u32,
+    /// Policy on the optimistic mode requests.
+    pub(crate) optimistic_mode_policy: Arc<OptimisticModePolicy>,
+    /// Rejection of the stale and replayed requests.
+    pub(crate) freshness: Arc<FreshnessGuard>,
+    /// Rejection of the requests citing GERs unknown to l1.
+    pub(crate) ger_liveness:
+        Arc<GerLivenessCheck<PrefetchingClient<AggchainContractsRpcClient<AlloyFillProvider>>>>,
+    /// Mirror of the l1 info tree, sparing the l1 reads of the GER liveness
+    /// check for the requests it covers.
+    pub(crate) l1_info_tree_mirror: Option<
+        Arc<L1InfoTreeMirror<PrefetchingClient<AggchainContractsRpcClient<AlloyFillProvider>>>>,
+    >,
+    /// Estimation of the proving cost of the requests.
+    pub(crate) request_cost: Arc<RequestCostModel>,
+    /// Maximum number of L2 blocks covered by one aggchain proof.
+    pub(crate) max_range_size: Option<u64>,
+    /// Running totals of the proving cost, enforcing the cycle budget.
+    pub(crate) cost_ledger: Option<Arc<CostLedger>>,
+    /// Served aggchain proof programs.
+    pub(crate) programs: Arc<ProgramRegistry>,
+    /// Aggregation proofs already acquired, when cached.
+    pub(crate) proof_cache: Option<Arc<SpanProofCache>>,
+    /// Aggregation proofs requested to the proposer and not acquired yet.
+    pub(crate) pending_proposer_requests: Arc<PendingRequests>,
+    /// Prefetching of the chain data of the next expected range.
+    pub(crate) warm_pipeline:
+        Option<Arc<WarmPipeline<AggchainContractsRpcClient<AlloyFillProvider>>>>,
 }
 
 impl AggchainProofService {
     pub async fn new(config: &AggchainProofServiceConfig) -> Result<Self, Error> {
         debug!("Initializing AggchainProofService");
-        let client = prover_alloy::AlloyProvider::new(
+        let client = prover_alloy::AlloyProvider::connect(
             &config.proposer_service.l1_rpc_endpoint.url,
             prover_alloy::DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS,
             prover_alloy::DEFAULT_HTTP_RPC_NODE_BACKOFF_MAX_RETRIES,
         )
+        .await
         .map_err(Error::AlloyProviderInitializationFailed)?;
         let l1_rpc_client = Arc::new(client);
         debug!("L1 RPC client initialized");
 
-        let contract_l1_client = Arc::new(
+        let contract_l1_client = Arc::new(PrefetchingClient::new(Arc::new(
             AggchainContractsRpcClient::new(
                 config.aggchain_proof_builder.network_id,
                 &config.aggchain_proof_builder.contracts,
             )
             .await
             .map_err(Error::ContractsClientInitFailed)?,
-        );
+        )));
         debug!("Contract L1 client initialized");
 
-        let proposer_service = if config.proposer_service.mock {
-            tower::ServiceBuilder::new()
-                .service(
+        let (proposer_service, proof_cache, pending_proposer_requests) =
+            if config.proposer_service.mock {
+                let proposer_service =
                     ProposerService::new_mock(&config.proposer_service, l1_rpc_client)
                         .await
-                        .map_err(Error::ProposerServiceInitFailed)?,
+                        .map_err(Error::ProposerServiceInitFailed)?;
+                let proof_cache = proposer_service.proof_cache().cloned();
+                let pending_requests = proposer_service.pending_requests().clone();
+                (
+                    tower::ServiceBuilder::new()
+                        .service(proposer_service)
+                        .boxed_clone(),
+                    proof_cache,
+                    pending_requests,
                 )
-                .boxed_clone()
-        } else {
-            tower::ServiceBuilder::new()
-                .service(
+            } else {
+                let proposer_service =
                     ProposerService::new_network(&config.proposer_service, l1_rpc_client)
                         .await
-                        .map_err(Error::ProposerServiceInitFailed)?,
+                        .map_err(Error::ProposerServiceInitFailed)?;
+                let proof_cache = proposer_service.proof_cache().cloned();
+                let pending_requests = proposer_service.pending_requests().clone();
+                (
+                    tower::ServiceBuilder::new()
+                        .service(proposer_service)
+                        .boxed_clone(),
+                    proof_cache,
+                    pending_requests,
                 )
-                .boxed_clone()
-        };
+            };
         debug!("ProposerService initialized");
 
+        let aggchain_proof_builder = AggchainProofBuilder::new(
+            &config.effective_aggchain_proof_builder(),
+            contract_l1_client.clone(),
+        )
+        .await
+        .map_err(Error::AggchainProofBuilderInitFailed)?;
+        let cost_ledger = aggchain_proof_builder.cost_ledger().cloned();
+        let programs = aggchain_proof_builder.programs().clone();
         let aggchain_proof_builder = tower::ServiceBuilder::new()
-            .service(
-                AggchainProofBuilder::new(
-                    &config.aggchain_proof_builder,
-                    contract_l1_client.clone(),
-                )
-                .await
-                .map_err(Error::AggchainProofBuilderInitFailed)?,
-            )
+            .service(aggchain_proof_builder)
             .boxed_clone();
         debug!("AggchainProofBuilder initialized");
 
-        Ok(AggchainProofService {
+        let max_range_size = config
+            .prover_overrides()
+            .and_then(|overrides| overrides.max_range_size);
+        let freshness = FreshnessGuard::spawn(contract_l1_client.clone(), config.freshness.clone())
+            .await
+            .map_err(Error::FreshnessInitFailed)?;
+        let ger_liveness = Arc::new(GerLivenessCheck::new(
+            contract_l1_client.clone(),
+            &config.ger_liveness,
+        ));
+        let l1_info_tree_mirror = config.l1_info_tree_mirror.clone().map(|mirror| {
+            debug!("L1 info tree mirror started");
+            L1InfoTreeMirror::spawn(contract_l1_client.clone(), mirror)
+        });
+        let warm_pipeline = config.warm_pipeline.clone().map(|warm_pipeline| {
+            debug!("Warm pipeline started");
+            WarmPipeline::spawn(contract_l1_client, warm_pipeline, max_range_size)
+        });
+
+        #[allow(unused_mut)]
+        let mut service = AggchainProofService {
             proposer_service,
             aggchain_proof_builder,
-        })
+            network_id: config.aggchain_proof_builder.network_id,
+            optimistic_mode_policy: Arc::new(config.optimistic_mode.clone()),
+            freshness,
+            ger_liveness,
+            l1_info_tree_mirror,
+            request_cost: Arc::new(config.request_cost.clone()),
+            max_range_size,
+            cost_ledger,
+            programs,
+            proof_cache,
+            pending_proposer_requests,
+            warm_pipeline,
+        };
+
+        #[cfg(feature = "chaos")]
+        if let Some(path) = &config.chaos_scenario {
+            crate::chaos::ChaosScenario::load(path)
+                .map_err(Error::ChaosScenario)?
+                .inject(&mut service);
+        }
+
+        Ok(service)
+    }
+
+    /// Running totals of the proving cost, when accounted for.
+    pub fn cost_ledger(&self) -> Option<&Arc<CostLedger>> {
+        self.cost_ledger.as_ref()
+    }
+
+    /// Served aggchain proof programs.
+    pub fn programs(&self) -> &Arc<ProgramRegistry> {
+        &self.programs
+    }
+
+    /// Aggregation proofs already acquired, when cached.
+    pub fn proof_cache(&self) -> Option<&Arc<SpanProofCache>> {
+        self.proof_cache.as_ref()
+    }
+
+    /// Aggregation proofs requested to the proposer and not acquired yet.
+    pub fn pending_proposer_requests(&self) -> &Arc<PendingRequests> {
+        &self.pending_proposer_requests
+    }
+
+    /// Rejects the requests exceeding the cycle budget, stale, too costly or
+    /// for an unknown program.
+    fn validate(&self, req: &AggchainProofServiceRequest) -> Result<(), Error> {
+        if let Some(cost_ledger) = &self.cost_ledger {
+            cost_ledger
+                .check_budget(self.network_id)
+                .map_err(Error::CycleBudgetExhausted)?;
+        }
+
+        let aggchain_proof_inputs = match req {
+            AggchainProofServiceRequest::Normal(inputs) => inputs,
+            AggchainProofServiceRequest::Optimistic(inputs) => &inputs.aggchain_proof_inputs,
+        };
+        self.freshness
+            .check(aggchain_proof_inputs)
+            .map_err(Error::StaleRequest)?;
+        self.request_cost
+            .check(aggchain_proof_inputs)
+            .map_err(Error::RequestTooCostly)?;
+        self.programs
+            .check(aggchain_proof_inputs.requested_vkey_hash)
+            .map_err(Error::UnknownProgram)?;
+
+        Ok(())
+    }
+
+    /// Checks one optimistic mode request against the policy and the range
+    /// limit of the prover. The signed range is proven as is, hence it is
+    /// rejected rather than shortened when over the limit.
+    fn check_optimistic_request(
+        &self,
+        network_id: u32,
+        aggchain_proof_inputs: &AggchainProofInputs,
+    ) -> Result<(), OptimisticModeRejection> {
+        if network_id != self.network_id {
+            return Err(OptimisticModeRejection::NetworkNotServed {
+                requested: network_id,
+                served: self.network_id,
+            });
+        }
+
+        let blocks = (aggchain_proof_inputs.last_proven_block + 1)
+            ..=aggchain_proof_inputs.requested_end_block;
+        self.optimistic_mode_policy
+            .check(network_id, blocks.clone())?;
+
+        if let Some(max) = self.max_range_size {
+            let requested = blocks
+                .end()
+                .saturating_sub(*blocks.start())
+                .saturating_add(1);
+            if requested > max {
+                return Err(OptimisticModeRejection::TooManyBlocks { requested, max });
+            }
+        }
+
+        Ok(())
     }
 
     fn handle_normal_request(
@@ -150,23 +350,39 @@ impl AggchainProofService {
     ) -> AggchainProofServiceFuture {
         let l1_block_hash = aggchain_proof_inputs.l1_info_tree_leaf.inner.block_hash;
 
+        // The requests for more blocks than allowed for the network are
+        // proven up to the limit, the response telling the end block.
+        let requested_end_block = match self.max_range_size {
+            Some(max_range_size) => aggchain_proof_inputs.requested_end_block.min(
+                aggchain_proof_inputs
+                    .last_proven_block
+                    .saturating_add(max_range_size),
+            ),
+            None => aggchain_proof_inputs.requested_end_block,
+        };
+
         let proposer_request = FepProposerRequest {
             last_proven_block: aggchain_proof_inputs.last_proven_block,
-            requested_end_block: aggchain_proof_inputs.requested_end_block,
+            requested_end_block,
             l1_block_hash: B256::from(l1_block_hash.0),
         };
 
         let mut proposer_service = self.proposer_service.clone();
         let mut proof_builder = self.aggchain_proof_builder.clone();
+        let warm_pipeline = self.warm_pipeline.clone();
 
         async move {
             let last_proven_block = aggchain_proof_inputs.last_proven_block;
+            let requested_end_block = aggchain_proof_inputs.requested_end_block;
             // The ProposerResponse contains the start and end block number
             // It also contains the generated proof.
+            let started = Instant::now();
             let aggregation_proof_response = proposer_service
                 .call(proposer_request)
+                .instrument(info_span!("proposer"))
                 .await
                 .map_err(Error::ProposerServiceError)?;
+            let proposer = started.elapsed();
 
             let aggchain_proof_builder_request =
                 aggchain_proof_builder::AggchainProofBuilderRequest {
@@ -185,18 +401,29 @@ impl AggchainProofService {
                 .await
                 .map_err(Error::AggchainProofBuilderRequestFailed)?;
 
+            if let Some(warm_pipeline) = warm_pipeline {
+                warm_pipeline.observe(last_proven_block, end_block);
+            }
+
             let custom_chain_data =
                 compute_custom_chain_data(aggchain_proof_response.output_root, end_block);
 
+            let mut stage_timings = aggchain_proof_response.stage_timings;
+            stage_timings.record(Stage::Proposer, proposer);
+
             Ok(AggchainProofServiceResponse {
                 proof: aggchain_proof_response.proof,
                 aggchain_params: aggchain_proof_response.aggchain_params,
                 last_proven_block,
                 vkey: aggchain_proof_response.vkey,
+                vkey_hash: aggchain_proof_response.vkey_hash,
+                proof_mode: aggchain_proof_response.proof_mode,
                 end_block,
+                requested_end_block,
                 local_exit_root_hash: aggchain_proof_response.new_local_exit_root,
                 custom_chain_data,
                 public_values: aggchain_proof_response.public_values,
+                stage_timings,
             })
         }
         .boxed()
@@ -205,14 +432,21 @@ impl AggchainProofService {
     fn handle_optimistic_request(
         &mut self,
         OptimisticAggchainProofInputs {
+            network_id,
             aggchain_proof_inputs,
             signature_optimistic_mode,
         }: OptimisticAggchainProofInputs,
     ) -> AggchainProofServiceFuture {
+        if let Err(rejection) = self.check_optimistic_request(network_id, &aggchain_proof_inputs) {
+            return futures::future::ready(Err(Error::OptimisticModeRejected(rejection))).boxed();
+        }
+
         let mut proof_builder = self.aggchain_proof_builder.clone();
+        let warm_pipeline = self.warm_pipeline.clone();
 
         async move {
             let last_proven_block = aggchain_proof_inputs.last_proven_block;
+            let requested_end_block = aggchain_proof_inputs.requested_end_block;
 
             let aggchain_proof_builder_request =
                 aggchain_proof_builder::AggchainProofBuilderRequest {
@@ -231,6 +465,10 @@ impl AggchainProofService {
                 .await
                 .map_err(Error::AggchainProofBuilderRequestFailed)?;
 
+            if let Some(warm_pipeline) = warm_pipeline {
+                warm_pipeline.observe(last_proven_block, end_block);
+            }
+
             let custom_chain_data =
                 compute_custom_chain_data(aggchain_proof_response.output_root, end_block);
 
@@ -239,10 +477,14 @@ impl AggchainProofService {
                 aggchain_params: aggchain_proof_response.aggchain_params,
                 last_proven_block,
                 vkey: aggchain_proof_response.vkey,
+                vkey_hash: aggchain_proof_response.vkey_hash,
+                proof_mode: aggchain_proof_response.proof_mode,
                 end_block,
+                requested_end_block,
                 local_exit_root_hash: aggchain_proof_response.new_local_exit_root,
                 custom_chain_data,
                 public_values: aggchain_proof_response.public_values,
+                stage_timings: aggchain_proof_response.stage_timings,
             })
         }
         .boxed()
@@ -269,13 +511,51 @@ impl tower::Service<AggchainProofServiceRequest> for AggchainProofService {
     }
 
     fn call(&mut self, req: AggchainProofServiceRequest) -> Self::Future {
-        match req {
+        let started = Instant::now();
+        if let Err(error) = info_span!("validation").in_scope(|| self.validate(&req)) {
+            return futures::future::ready(Err(error)).boxed();
+        }
+        let validation = started.elapsed();
+
+        // The GERs are checked against l1 before the request is handled, as
+        // the aggregation proof is requested first.
+        let ger_liveness = self.ger_liveness.clone();
+        let l1_info_tree_mirror = self.l1_info_tree_mirror.clone();
+        let aggchain_proof_inputs = match &req {
+            AggchainProofServiceRequest::Normal(inputs) => inputs,
+            AggchainProofServiceRequest::Optimistic(inputs) => &inputs.aggchain_proof_inputs,
+        }
+        .clone();
+
+        let response = match req {
             AggchainProofServiceRequest::Normal(aggchain_proof_inputs) => {
                 self.handle_normal_request(aggchain_proof_inputs)
             }
             AggchainProofServiceRequest::Optimistic(optimistic_aggchain_proof_inputs) => {
                 self.handle_optimistic_request(optimistic_aggchain_proof_inputs)
             }
+        };
+
+        async move {
+            let started = Instant::now();
+            let mirrored = match &l1_info_tree_mirror {
+                Some(mirror) => info_span!("l1_info_tree_mirror")
+                    .in_scope(|| mirror.check(&aggchain_proof_inputs))?,
+                None => false,
+            };
+            // The GERs of the leaves mirrored are known to l1.
+            if !mirrored {
+                ger_liveness
+                    .check(&aggchain_proof_inputs)
+                    .instrument(info_span!("ger_liveness"))
+                    .await?;
+            }
+            let validation = validation + started.elapsed();
+
+            let mut response = response.await?;
+            response.stage_timings.record(Stage::Validation, validation);
+            Ok(response)
         }
+        .boxed()
     }
 }
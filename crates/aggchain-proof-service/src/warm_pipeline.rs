@@ -0,0 +1,211 @@
+//! Warm pipeline prefetching the chain data of the block range expected to be
+//! requested next, so that most of the latency of the chain data retrieval is
+//! paid before the aggsender requests the proof.
+//!
+//! The next range is expected to start at the end block of the last proven
+//! one, and to be as long as it. Its start block data is prefetched at once,
+//! its end block data once the watched l2 head reaches it.
+use std::{
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use aggchain_proof_contracts::{
+    contracts::{
+        L2EvmStateSketchFetcher, L2HeadFetcher, L2LocalExitRootFetcher, L2OutputAtBlockFetcher,
+    },
+    prefetch::PrefetchingClient,
+};
+use prover_alloy::BlockFinality;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, info, warn};
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct WarmPipelineConfig {
+    /// Interval between two checks of the l2 head.
+    #[serde(
+        default = "default_poll_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub poll_interval: Duration,
+
+    /// Finality of the l2 blocks the data is prefetched for, which should be
+    /// the one of the blocks requested by the aggsender.
+    #[serde(default)]
+    pub l2_head_finality: BlockFinality,
+
+    /// Number of blocks of the expected ranges, the one of the last proven
+    /// range when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_size: Option<u64>,
+}
+
+const fn default_poll_interval() -> Duration {
+    Duration::from_secs(12)
+}
+
+/// Range expected to be requested next.
+#[derive(Debug, PartialEq, Eq)]
+struct ExpectedRange {
+    last_proven_block: u64,
+    range_size: u64,
+    end_prefetched: bool,
+}
+
+impl ExpectedRange {
+    /// End block of the range, once reached by the l2 head, if not prefetched
+    /// yet.
+    fn end_to_prefetch(&mut self, l2_head: u64) -> Option<u64> {
+        if self.end_prefetched || self.range_size == 0 {
+            return None;
+        }
+
+        let end_block = self.last_proven_block.checked_add(self.range_size)?;
+        self.end_prefetched = l2_head >= end_block;
+
+        self.end_prefetched.then_some(end_block)
+    }
+}
+
+pub struct WarmPipeline<ContractsClient> {
+    client: Arc<PrefetchingClient<ContractsClient>>,
+    config: WarmPipelineConfig,
+    /// Maximum number of blocks of the proven ranges.
+    max_range_size: Option<u64>,
+    expected: Mutex<Option<ExpectedRange>>,
+}
+
+impl<ContractsClient> WarmPipeline<ContractsClient>
+where
+    ContractsClient: L2LocalExitRootFetcher
+        + L2OutputAtBlockFetcher
+        + L2EvmStateSketchFetcher
+        + L2HeadFetcher
+        + Send
+        + Sync
+        + 'static,
+{
+    /// Starts watching the l2 head, until the pipeline is dropped.
+    pub fn spawn(
+        client: Arc<PrefetchingClient<ContractsClient>>,
+        config: WarmPipelineConfig,
+        max_range_size: Option<u64>,
+    ) -> Arc<Self> {
+        let pipeline = Arc::new(Self {
+            client,
+            config,
+            max_range_size,
+            expected: Mutex::default(),
+        });
+        tokio::spawn(Self::watch(
+            Arc::downgrade(&pipeline),
+            pipeline.config.poll_interval,
+        ));
+
+        pipeline
+    }
+
+    /// Records the last proven range, the next one being expected to follow
+    /// it.
+    pub fn observe(&self, last_proven_block: u64, end_block: u64) {
+        let range_size = self
+            .config
+            .range_size
+            .unwrap_or(end_block.saturating_sub(last_proven_block));
+        let range_size = self
+            .max_range_size
+            .map_or(range_size, |max_range_size| range_size.min(max_range_size));
+        debug!(end_block, range_size, "Expecting the next range");
+        *self.expected.lock().expect("warm pipeline lock poisoned") = Some(ExpectedRange {
+            last_proven_block: end_block,
+            range_size,
+            end_prefetched: false,
+        });
+
+        self.client.prune(end_block);
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            if let Err(error) = client.prefetch_range_start(end_block).await {
+                warn!(?error, end_block, "Unable to prefetch the next range start");
+            }
+        });
+    }
+
+    async fn watch(pipeline: Weak<Self>, poll_interval: Duration) {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            let Some(pipeline) = pipeline.upgrade() else {
+                return;
+            };
+            pipeline.prefetch_range_end().await;
+        }
+    }
+
+    async fn prefetch_range_end(&self) {
+        if self
+            .expected
+            .lock()
+            .expect("warm pipeline lock poisoned")
+            .is_none()
+        {
+            return;
+        }
+
+        let l2_head = match self.client.get_l2_head(self.config.l2_head_finality).await {
+            Ok(l2_head) => l2_head,
+            Err(error) => {
+                warn!(?error, "Unable to retrieve the l2 head");
+                return;
+            }
+        };
+        let end_block = self
+            .expected
+            .lock()
+            .expect("warm pipeline lock poisoned")
+            .as_mut()
+            .and_then(|expected| expected.end_to_prefetch(l2_head));
+        let Some(end_block) = end_block else {
+            return;
+        };
+
+        info!(end_block, l2_head, "Prefetching the next range end");
+        if let Err(error) = self.client.prefetch_range_end(end_block).await {
+            warn!(?error, end_block, "Unable to prefetch the next range end");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_end_is_prefetched_once_reached() {
+        let mut expected = ExpectedRange {
+            last_proven_block: 100,
+            range_size: 20,
+            end_prefetched: false,
+        };
+
+        assert_eq!(expected.end_to_prefetch(119), None);
+        assert_eq!(expected.end_to_prefetch(125), Some(120));
+        assert_eq!(expected.end_to_prefetch(130), None);
+    }
+
+    #[test]
+    fn empty_range_is_not_prefetched() {
+        let mut expected = ExpectedRange {
+            last_proven_block: 100,
+            range_size: 0,
+            end_prefetched: false,
+        };
+
+        assert_eq!(expected.end_to_prefetch(100), None);
+    }
+}
@@ -35,11 +35,17 @@ pub struct AggchainProofInputs {
 
     /// Imported bridge exits.
     pub imported_bridge_exits: Vec<ImportedBridgeExitWithBlockNumber>,
+
+    /// Hash of the verifying key of the aggchain proof program to prove with,
+    /// the current program when unset.
+    pub requested_vkey_hash: Option<Digest>,
 }
 
 /// Data needed as the input for the aggchain proof generation.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct OptimisticAggchainProofInputs {
+    /// Network the optimistic aggchain proof is requested for.
+    pub network_id: u32,
     pub aggchain_proof_inputs: AggchainProofInputs,
     pub signature_optimistic_mode: agglayer_primitives::Signature,
 }
@@ -0,0 +1,29 @@
+use aggkit_prover_types::{v2::AggchainProofError, validation::ValidationError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Unable to connect to the aggkit prover")]
+    Connect(#[from] tonic::transport::Error),
+
+    /// Key of the GER map of the inputs which is not a hex encoded digest.
+    #[error("Invalid GER {0}, expected a hex encoded digest")]
+    InvalidGer(String),
+
+    #[error("The GER {ger} differs from the one of its l1 info tree leaf")]
+    MismatchedGer { ger: String },
+
+    /// Request the prover would reject, every faulty field being reported.
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+
+    /// Failure of the prover, once the retries are exhausted for the
+    /// transient ones.
+    #[error("The aggkit prover failed: {}", .0.message())]
+    Status(#[source] tonic::Status),
+
+    #[error("The aggchain proof request {request_id} failed: {}", .error.message)]
+    Failed {
+        request_id: String,
+        error: AggchainProofError,
+    },
+}
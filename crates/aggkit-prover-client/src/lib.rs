@@ -0,0 +1,534 @@
+//! Typed client of the aggkit prover v2 gRPC API, for the aggsender and the
+//! other integrators.
+//!
+//! The requests are built and checked by an [`AggchainProofRequestBuilder`].
+//! The channel reconnects to the prover by itself, the transient failures of
+//! the prover are retried with an exponential backoff, and the status stream
+//! of a request is subscribed to again when it breaks before the request
+//! completes, e.g. when the prover restarts.
+use std::time::{Duration, Instant};
+
+use aggkit_prover_types::v2::{
+    aggchain_proof_service_client::AggchainProofServiceClient, AggchainProofStage,
+    AggchainProofStatus, GenerateAggchainProofResponse, GetProverInfoRequest,
+    GetProverInfoResponse, WatchAggchainProofStatusRequest,
+};
+pub use aggkit_prover_types::v2::{
+    GenerateAggchainProofRequest, GenerateOptimisticAggchainProofRequest,
+};
+use futures::{stream::BoxStream, StreamExt as _};
+use prover_errors::is_retriable;
+use tonic::{
+    codec::Streaming,
+    metadata::MetadataValue,
+    transport::{Channel, Endpoint},
+    Status,
+};
+use tracing::{debug, warn};
+
+pub use crate::{error::Error, request::AggchainProofRequestBuilder};
+
+mod error;
+mod request;
+
+/// Metadata carrying the version of the client, checked by the prover.
+const CLIENT_VERSION_METADATA: &str = "x-client-version";
+
+/// Version of the client, reported to the prover.
+pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Behavior of the [`AggkitProverClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// Time given to a proof request, retries included, unbounded when unset.
+    pub deadline: Option<Duration>,
+    /// Retries of the transient failures of the prover, and of the
+    /// subscriptions to a status stream in a row.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each of the next ones.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            deadline: Some(Duration::from_secs(60 * 60)),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Delay before the given retry, counted from zero.
+    fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+}
+
+/// Client of the aggkit prover.
+#[derive(Debug, Clone)]
+pub struct AggkitProverClient {
+    client: AggchainProofServiceClient<Channel>,
+    config: ClientConfig,
+}
+
+impl AggkitProverClient {
+    /// Connects to the prover at the given URI, e.g. `http://aggkit-prover:4446`.
+    pub async fn connect(uri: &str, config: ClientConfig) -> Result<Self, Error> {
+        let channel = Endpoint::from_shared(uri.to_string())?.connect().await?;
+
+        Ok(Self::new(channel, config))
+    }
+
+    pub fn new(channel: Channel, config: ClientConfig) -> Self {
+        Self {
+            client: AggchainProofServiceClient::new(channel),
+            config,
+        }
+    }
+
+    /// Proves the aggchain proof of the request, built by an
+    /// [`AggchainProofRequestBuilder`].
+    pub async fn generate_aggchain_proof(
+        &self,
+        request: GenerateAggchainProofRequest,
+    ) -> Result<GenerateAggchainProofResponse, Error> {
+        let request_id = request.request_id.clone();
+
+        self.with_retries(&request_id, request, |mut client, request| async move {
+            client.generate_aggchain_proof(request).await
+        })
+        .await
+    }
+
+    /// Proves the aggchain proof of the request in optimistic mode.
+    pub async fn generate_optimistic_aggchain_proof(
+        &self,
+        request: GenerateOptimisticAggchainProofRequest,
+    ) -> Result<GenerateAggchainProofResponse, Error> {
+        let request_id = request
+            .aggchain_proof_request
+            .as_ref()
+            .map(|request| request.request_id.clone())
+            .unwrap_or_default();
+
+        self.with_retries(&request_id, request, |mut client, request| async move {
+            client.generate_optimistic_aggchain_proof(request).await
+        })
+        .await
+    }
+
+    /// Versions and capabilities of the prover, which also tells whether this
+    /// client is supported.
+    pub async fn get_prover_info(&self) -> Result<GetProverInfoResponse, Error> {
+        let request = GetProverInfoRequest {
+            client_version: CLIENT_VERSION.to_string(),
+        };
+
+        self.with_retries("", request, |mut client, request| async move {
+            client.get_prover_info(request).await
+        })
+        .await
+    }
+
+    /// Statuses of the request, from the current one up to its completion or
+    /// failure.
+    ///
+    /// The stream is subscribed to again when it breaks before the end, in
+    /// which case the current status is yielded again.
+    pub fn watch_status(
+        &self,
+        request_id: impl Into<String>,
+    ) -> BoxStream<'static, Result<AggchainProofStatus, Error>> {
+        let watch = Watch {
+            client: self.client.clone(),
+            config: self.config.clone(),
+            request_id: request_id.into(),
+            statuses: None,
+            retry: 0,
+            done: false,
+        };
+
+        futures::stream::try_unfold(watch, |mut watch| async move {
+            let status = watch.next().await?;
+            Ok(status.map(|status| (status, watch)))
+        })
+        .boxed()
+    }
+
+    /// Waits for the request to complete, failing with its error if it fails.
+    pub async fn wait_for_completion(
+        &self,
+        request_id: impl Into<String>,
+    ) -> Result<AggchainProofStatus, Error> {
+        let request_id = request_id.into();
+        let mut statuses = self.watch_status(request_id.clone());
+
+        let mut last = None;
+        while let Some(status) = statuses.next().await {
+            last = Some(status?);
+        }
+
+        match last {
+            Some(status) if status.stage() == AggchainProofStage::Failed => Err(Error::Failed {
+                request_id,
+                error: status.error.unwrap_or_default(),
+            }),
+            Some(status) => Ok(status),
+            None => Err(Error::Status(Status::unavailable(
+                "The status stream ended without any status",
+            ))),
+        }
+    }
+
+    /// Calls the prover, retrying its transient failures within the
+    /// deadline.
+    async fn with_retries<Req, Res, F, Fut>(
+        &self,
+        request_id: &str,
+        request: Req,
+        call: F,
+    ) -> Result<Res, Error>
+    where
+        Req: Clone,
+        F: Fn(AggchainProofServiceClient<Channel>, tonic::Request<Req>) -> Fut,
+        Fut: std::future::Future<Output = Result<tonic::Response<Res>, Status>>,
+    {
+        let deadline = self
+            .config
+            .deadline
+            .map(|deadline| Instant::now() + deadline);
+        let mut retry = 0;
+        loop {
+            let mut attempt = with_client_version(request.clone());
+            if let Some(deadline) = deadline {
+                attempt.set_timeout(deadline.saturating_duration_since(Instant::now()));
+            }
+
+            let status = match call(self.client.clone(), attempt).await {
+                Ok(response) => {
+                    debug!(%request_id, retries = retry, "Aggkit prover responded");
+                    return Ok(response.into_inner());
+                }
+                Err(status) => status,
+            };
+            let backoff = self.config.backoff(retry);
+            if !is_retriable(&status)
+                || retry >= self.config.max_retries
+                || deadline.is_some_and(|deadline| Instant::now() + backoff >= deadline)
+            {
+                return Err(Error::Status(status));
+            }
+
+            retry += 1;
+            warn!(
+                %request_id,
+                code = ?status.code(),
+                message = status.message(),
+                retry,
+                ?backoff,
+                "Aggkit prover failed transiently, retrying"
+            );
+            tokio::time::sleep(backoff).await;
+        }
+    }
+}
+
+/// Subscription to the status stream of one request.
+struct Watch {
+    client: AggchainProofServiceClient<Channel>,
+    config: ClientConfig,
+    request_id: String,
+    statuses: Option<Streaming<AggchainProofStatus>>,
+    /// Subscriptions which failed in a row.
+    retry: u32,
+    done: bool,
+}
+
+impl Watch {
+    async fn next(&mut self) -> Result<Option<AggchainProofStatus>, Error> {
+        while !self.done {
+            let mut statuses = match self.statuses.take() {
+                Some(statuses) => statuses,
+                None => {
+                    let request = with_client_version(WatchAggchainProofStatusRequest {
+                        request_id: self.request_id.clone(),
+                    });
+                    match self.client.watch_aggchain_proof_status(request).await {
+                        Ok(response) => response.into_inner(),
+                        Err(status) => {
+                            self.back_off(status).await?;
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            match statuses.message().await {
+                Ok(Some(status)) => {
+                    self.statuses = Some(statuses);
+                    self.retry = 0;
+                    self.done = matches!(
+                        status.stage(),
+                        AggchainProofStage::Completed | AggchainProofStage::Failed
+                    );
+
+                    return Ok(Some(status));
+                }
+                Ok(None) => {
+                    self.back_off(Status::unavailable("The status stream ended early"))
+                        .await?;
+                }
+                Err(status) => self.back_off(status).await?,
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Waits before subscribing again, failing once the retries are
+    /// exhausted or on a permanent failure, e.g. an unknown request.
+    async fn back_off(&mut self, status: Status) -> Result<(), Error> {
+        if !is_retriable(&status) || self.retry >= self.config.max_retries {
+            return Err(Error::Status(status));
+        }
+
+        let backoff = self.config.backoff(self.retry);
+        self.retry += 1;
+        warn!(
+            request_id = %self.request_id,
+            code = ?status.code(),
+            message = status.message(),
+            retry = self.retry,
+            ?backoff,
+            "Status stream of the aggkit prover broke, subscribing again"
+        );
+        tokio::time::sleep(backoff).await;
+
+        Ok(())
+    }
+}
+
+fn with_client_version<T>(message: T) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    request.metadata_mut().insert(
+        CLIENT_VERSION_METADATA,
+        MetadataValue::from_static(CLIENT_VERSION),
+    );
+
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::SocketAddr,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+    };
+
+    use aggkit_prover_types::v2::{
+        aggchain_proof_service_server::{AggchainProofService, AggchainProofServiceServer},
+        AggchainProofError, AggchainProofErrorKind,
+    };
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tokio_util::sync::CancellationToken;
+    use tonic::{transport::Server, Code, Request, Response};
+
+    use super::*;
+
+    /// Prover whose status streams break until the given number of
+    /// subscriptions, as when restarting, then yield the final status.
+    struct FakeProver {
+        breaking_subscriptions: u32,
+        subscriptions: AtomicU32,
+        final_stage: AggchainProofStage,
+    }
+
+    #[tonic::async_trait]
+    impl AggchainProofService for FakeProver {
+        type WatchAggchainProofStatusStream =
+            BoxStream<'static, Result<AggchainProofStatus, Status>>;
+
+        async fn generate_aggchain_proof(
+            &self,
+            _request: Request<GenerateAggchainProofRequest>,
+        ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+            Err(Status::unimplemented("not proving"))
+        }
+
+        async fn generate_optimistic_aggchain_proof(
+            &self,
+            _request: Request<GenerateOptimisticAggchainProofRequest>,
+        ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+            Err(Status::unimplemented("not proving"))
+        }
+
+        async fn watch_aggchain_proof_status(
+            &self,
+            request: Request<WatchAggchainProofStatusRequest>,
+        ) -> Result<Response<Self::WatchAggchainProofStatusStream>, Status> {
+            let request_id = request.into_inner().request_id;
+            if request_id != "request" {
+                return Err(Status::not_found("unknown request"));
+            }
+
+            let status = |stage: AggchainProofStage| AggchainProofStatus {
+                request_id: request_id.clone(),
+                stage: stage as i32,
+                error: (stage == AggchainProofStage::Failed).then(|| AggchainProofError {
+                    kind: AggchainProofErrorKind::ProvingFailed as i32,
+                    message: "proving failed".to_string(),
+                    field_path: String::new(),
+                }),
+                ..Default::default()
+            };
+            let statuses = if self.subscriptions.fetch_add(1, Ordering::SeqCst)
+                < self.breaking_subscriptions
+            {
+                vec![Err(Status::unavailable("restarting"))]
+            } else {
+                vec![
+                    Ok(status(AggchainProofStage::Proving)),
+                    Ok(status(self.final_stage)),
+                ]
+            };
+
+            Ok(Response::new(futures::stream::iter(statuses).boxed()))
+        }
+
+        async fn get_prover_info(
+            &self,
+            request: Request<GetProverInfoRequest>,
+        ) -> Result<Response<GetProverInfoResponse>, Status> {
+            assert_eq!(
+                request.metadata().get(CLIENT_VERSION_METADATA).unwrap(),
+                CLIENT_VERSION
+            );
+
+            Ok(Response::new(GetProverInfoResponse {
+                client_supported: true,
+                ..Default::default()
+            }))
+        }
+    }
+
+    async fn spawn_fake_prover(
+        breaking_subscriptions: u32,
+        final_stage: AggchainProofStage,
+        cancellation_token: CancellationToken,
+    ) -> (Arc<FakeProver>, AggkitProverClient) {
+        let prover = Arc::new(FakeProver {
+            breaking_subscriptions,
+            subscriptions: AtomicU32::new(0),
+            final_stage,
+        });
+        let listener = tokio::net::TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(AggchainProofServiceServer::from_arc(prover.clone()))
+                .serve_with_incoming_shutdown(
+                    TcpListenerStream::new(listener),
+                    cancellation_token.cancelled_owned(),
+                ),
+        );
+
+        let client = AggkitProverClient::connect(
+            &format!("http://{addr}"),
+            ClientConfig {
+                max_retries: 2,
+                initial_backoff: Duration::from_millis(10),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        (prover, client)
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_max() {
+        let config = ClientConfig {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+            ..Default::default()
+        };
+
+        assert_eq!(config.backoff(0), Duration::from_secs(1));
+        assert_eq!(config.backoff(1), Duration::from_secs(2));
+        assert_eq!(config.backoff(3), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn client_version_is_reported() {
+        let cancellation_token = CancellationToken::new();
+        let (_, client) =
+            spawn_fake_prover(0, AggchainProofStage::Completed, cancellation_token.clone()).await;
+
+        assert!(client.get_prover_info().await.unwrap().client_supported);
+
+        cancellation_token.cancel();
+    }
+
+    #[tokio::test]
+    async fn broken_status_streams_are_subscribed_again() {
+        let cancellation_token = CancellationToken::new();
+        let (prover, client) =
+            spawn_fake_prover(2, AggchainProofStage::Completed, cancellation_token.clone()).await;
+
+        let stages = client
+            .watch_status("request")
+            .map(|status| status.unwrap().stage())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            stages,
+            [AggchainProofStage::Proving, AggchainProofStage::Completed]
+        );
+        assert_eq!(prover.subscriptions.load(Ordering::SeqCst), 3);
+
+        cancellation_token.cancel();
+    }
+
+    #[tokio::test]
+    async fn status_streams_fail_once_the_retries_are_exhausted() {
+        let cancellation_token = CancellationToken::new();
+        let (_, client) =
+            spawn_fake_prover(3, AggchainProofStage::Completed, cancellation_token.clone()).await;
+
+        let error = client.wait_for_completion("request").await.unwrap_err();
+        assert!(matches!(error, Error::Status(status) if status.code() == Code::Unavailable));
+
+        let error = client.wait_for_completion("unknown").await.unwrap_err();
+        assert!(matches!(error, Error::Status(status) if status.code() == Code::NotFound));
+
+        cancellation_token.cancel();
+    }
+
+    #[tokio::test]
+    async fn failed_requests_are_reported() {
+        let cancellation_token = CancellationToken::new();
+        let (_, client) =
+            spawn_fake_prover(0, AggchainProofStage::Failed, cancellation_token.clone()).await;
+
+        let error = client.wait_for_completion("request").await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::Failed { request_id, error } if request_id == "request"
+                && error.kind() == AggchainProofErrorKind::ProvingFailed
+        ));
+
+        cancellation_token.cancel();
+    }
+}
@@ -0,0 +1,250 @@
+//! Builders of the aggchain proof requests.
+//!
+//! The requests are built from the typed inputs of the aggchain proof. The
+//! GERs of the inputs, a map keyed by their hex encoded digest, are encoded
+//! into the list of the v2 API, in the order of their insertion on the chain.
+//! The requests are then checked as the prover would check them, e.g. the
+//! lengths of their hashes and merkle proofs, so that the malformed ones fail
+//! without a round trip.
+use aggchain_proof_types::AggchainProofInputs;
+use aggkit_prover_types::{
+    v2,
+    validation::{Validate as _, ValidationLimits},
+    Digest,
+};
+use agglayer_interop::{
+    grpc::v1::{FixedBytes32, FixedBytes65},
+    types::U256,
+};
+use prost::bytes::Bytes;
+
+use crate::Error;
+
+/// Builder of the requests of an aggchain proof.
+#[derive(Debug, Clone)]
+pub struct AggchainProofRequestBuilder {
+    request_id: String,
+    inputs: AggchainProofInputs,
+    limits: ValidationLimits,
+}
+
+impl AggchainProofRequestBuilder {
+    /// Builder of the request identified by `request_id`, which is the one to
+    /// watch the status of.
+    pub fn new(request_id: impl Into<String>, inputs: AggchainProofInputs) -> Self {
+        Self {
+            request_id: request_id.into(),
+            inputs,
+            limits: ValidationLimits::default(),
+        }
+    }
+
+    /// Limits the request is checked against, the default ones of the prover
+    /// unless configured otherwise.
+    pub fn limits(mut self, limits: ValidationLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub fn build(self) -> Result<v2::GenerateAggchainProofRequest, Error> {
+        let limits = self.limits;
+        let request = self.encode()?;
+        request.validate(&limits)?;
+
+        Ok(request)
+    }
+
+    /// Request of the aggchain proof in optimistic mode for the network
+    /// `network_id`, signed by the trusted sequencer.
+    pub fn build_optimistic(
+        self,
+        network_id: u32,
+        signature: agglayer_primitives::Signature,
+    ) -> Result<v2::GenerateOptimisticAggchainProofRequest, Error> {
+        let limits = self.limits;
+        let request = v2::GenerateOptimisticAggchainProofRequest {
+            network_id,
+            aggchain_proof_request: Some(self.encode()?),
+            optimistic_mode_signature: Some(FixedBytes65 {
+                value: Bytes::copy_from_slice(&signature.as_bytes()),
+            }),
+        };
+        request.validate(&limits)?;
+
+        Ok(request)
+    }
+
+    fn encode(self) -> Result<v2::GenerateAggchainProofRequest, Error> {
+        let inputs = self.inputs;
+
+        let mut inserted_gers = inputs
+            .ger_leaves
+            .into_iter()
+            .map(|(ger, leaf)| {
+                let ger = decode_ger(&ger, leaf.inserted_ger.l1_leaf.inner.global_exit_root)?;
+
+                Ok(v2::ProvenInsertedGer {
+                    ger: Some(ger),
+                    block_number: leaf.block_number,
+                    block_index: leaf.block_index,
+                    proof_ger_l1root: Some(leaf.inserted_ger.proof_ger_l1root.into()),
+                    l1_leaf: Some(leaf.inserted_ger.l1_leaf.into()),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        inserted_gers
+            .sort_by_key(|inserted_ger| (inserted_ger.block_number, inserted_ger.block_index));
+
+        let imported_bridge_exits = inputs
+            .imported_bridge_exits
+            .into_iter()
+            .map(|exit| v2::ImportedBridgeExitWithBlockNumber {
+                block_number: exit.block_number,
+                global_index: Some(
+                    Digest(U256::from(exit.global_index).to_be_bytes::<32>()).into(),
+                ),
+                bridge_exit_hash: Some(exit.bridge_exit_hash.0.into()),
+            })
+            .collect();
+
+        Ok(v2::GenerateAggchainProofRequest {
+            request_id: self.request_id,
+            last_proven_block: inputs.last_proven_block,
+            requested_end_block: inputs.requested_end_block,
+            l1_info_tree_root_hash: Some(inputs.l1_info_tree_root_hash.into()),
+            l1_info_tree_leaf: Some(inputs.l1_info_tree_leaf.into()),
+            l1_info_tree_merkle_proof: Some(inputs.l1_info_tree_merkle_proof.into()),
+            inserted_gers,
+            imported_bridge_exits,
+            requested_vkey_hash: inputs.requested_vkey_hash.map(Into::into),
+        })
+    }
+}
+
+/// Decodes the key of the GER map, whose length is left to the validation so
+/// that it is reported along with the other violations.
+fn decode_ger(ger: &str, leaf_ger: Digest) -> Result<FixedBytes32, Error> {
+    let bytes = hex::decode(ger.strip_prefix("0x").unwrap_or(ger))
+        .map_err(|_| Error::InvalidGer(ger.to_string()))?;
+    if bytes.len() == 32 && bytes[..] != leaf_ger.0 {
+        return Err(Error::MismatchedGer {
+            ger: ger.to_string(),
+        });
+    }
+
+    Ok(FixedBytes32 {
+        value: Bytes::from(bytes),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use aggchain_proof_types::inserted_ger::{InsertedGer, InsertedGerWithBlockNumber};
+    use agglayer_interop::types::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+
+    use super::*;
+
+    fn leaf(ger: Digest) -> L1InfoTreeLeaf {
+        L1InfoTreeLeaf {
+            l1_info_tree_index: 1,
+            rer: Default::default(),
+            mer: Default::default(),
+            inner: L1InfoTreeLeafInner {
+                global_exit_root: ger,
+                block_hash: Default::default(),
+                timestamp: 0u64,
+            },
+        }
+    }
+
+    fn inserted_ger(ger: Digest, block_number: u64) -> InsertedGerWithBlockNumber {
+        InsertedGerWithBlockNumber {
+            block_number,
+            block_index: 0,
+            inserted_ger: InsertedGer {
+                proof_ger_l1root: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+                l1_leaf: leaf(ger),
+            },
+        }
+    }
+
+    fn inputs(ger_leaves: HashMap<String, InsertedGerWithBlockNumber>) -> AggchainProofInputs {
+        AggchainProofInputs {
+            last_proven_block: 0,
+            requested_end_block: 100,
+            l1_info_tree_root_hash: Default::default(),
+            l1_info_tree_leaf: leaf(Digest::default()),
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+            ger_leaves,
+            imported_bridge_exits: Default::default(),
+            requested_vkey_hash: None,
+        }
+    }
+
+    #[test]
+    fn gers_are_encoded_in_insertion_order() {
+        let (first, second) = (Digest([1; 32]), Digest([2; 32]));
+        let ger_leaves = HashMap::from([
+            (second.to_string(), inserted_ger(second, 20)),
+            (first.to_string(), inserted_ger(first, 10)),
+        ]);
+
+        let request = AggchainProofRequestBuilder::new("request", inputs(ger_leaves))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request
+                .inserted_gers
+                .iter()
+                .map(|inserted_ger| inserted_ger.ger.clone().unwrap())
+                .collect::<Vec<_>>(),
+            [first.into(), second.into()]
+        );
+
+        // The prover decodes them back into the same map.
+        let decoded = AggchainProofInputs::try_from(request).unwrap();
+        assert_eq!(decoded.ger_leaves[&first.to_string()].block_number, 10);
+        assert_eq!(decoded.ger_leaves[&second.to_string()].block_number, 20);
+    }
+
+    #[test]
+    fn malformed_gers_are_rejected() {
+        let ger = Digest([1; 32]);
+
+        let inputs_with =
+            |key: &str| inputs(HashMap::from([(key.to_string(), inserted_ger(ger, 10))]));
+
+        let error = AggchainProofRequestBuilder::new("request", inputs_with("0x0102"))
+            .build()
+            .unwrap_err();
+        let Error::Invalid(error) = error else {
+            panic!("unexpected error {error:?}");
+        };
+        assert_eq!(error.0.len(), 1);
+        assert_eq!(error.0[0].field_path, "inserted_gers.0.ger");
+
+        assert!(matches!(
+            AggchainProofRequestBuilder::new("request", inputs_with("not a digest")).build(),
+            Err(Error::InvalidGer(_))
+        ));
+        assert!(matches!(
+            AggchainProofRequestBuilder::new("request", inputs_with(&Digest([2; 32]).to_string()))
+                .build(),
+            Err(Error::MismatchedGer { .. })
+        ));
+    }
+
+    #[test]
+    fn requests_are_validated() {
+        let error = AggchainProofRequestBuilder::new("", inputs(HashMap::new()))
+            .build()
+            .unwrap_err();
+
+        let Error::Invalid(error) = error else {
+            panic!("unexpected error {error:?}");
+        };
+        assert_eq!(error.0[0].field_path, "request_id");
+    }
+}
@@ -0,0 +1,71 @@
+use std::{net::SocketAddr, time::Duration};
+
+use prover_utils::secret::Secret;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the HTTP API used by the operators to inspect and reset
+/// the state of the prover.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AdminConfig {
+    /// Address the admin API listens on, disabled when unset. It isn't
+    /// authenticated, so it should not be reachable from outside.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub addr: Option<SocketAddr>,
+
+    /// Interval between the refreshes of the health of the dependencies
+    /// reported by `/healthz/details`.
+    #[serde(
+        default = "default_health_refresh_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub health_refresh_interval: Duration,
+
+    /// CPU and heap profiling endpoints under `/debug/pprof`, disabled when
+    /// unset. Requires a build with the `profiling` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profiling: Option<ProfilingConfig>,
+}
+
+impl Default for AdminConfig {
+    fn default() -> Self {
+        Self {
+            addr: None,
+            health_refresh_interval: default_health_refresh_interval(),
+            profiling: None,
+        }
+    }
+}
+
+/// Profiling of the running prover, for the performance investigations.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProfilingConfig {
+    /// Bearer token expected in the `Authorization` header of the profiling
+    /// requests.
+    pub token: Secret,
+
+    /// Longest CPU profile which may be requested.
+    #[serde(
+        default = "default_max_profile_duration",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub max_duration: Duration,
+
+    /// Sampling frequency of the CPU profiles, in hertz.
+    #[serde(default = "default_frequency")]
+    pub frequency: u32,
+}
+
+const fn default_health_refresh_interval() -> Duration {
+    Duration::from_secs(30)
+}
+
+const fn default_max_profile_duration() -> Duration {
+    Duration::from_secs(60)
+}
+
+const fn default_frequency() -> u32 {
+    99
+}
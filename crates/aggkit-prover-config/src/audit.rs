@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use prover_utils::retention::RetentionPolicy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the audit log of the proof requests, an append-only log
+/// where every record is chained to the previous one by its hash.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AuditLogConfig {
+    /// Directory of the JSONL files of the log, one per
+    /// `max-file-size` bytes of records.
+    pub dir: PathBuf,
+
+    /// Size in bytes beyond which the records are written to a new file.
+    #[serde(default = "default_max_file_size")]
+    pub max_file_size: u64,
+
+    /// Files kept, by age of their last record and by count, the older ones
+    /// being removed by the garbage collection. The file being written is
+    /// always kept.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub retention: RetentionPolicy,
+}
+
+const fn default_max_file_size() -> u64 {
+    64 * 1024 * 1024
+}
@@ -0,0 +1,15 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Versions of the clients served, the requests of the older ones being
+/// rejected with an upgrade message.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct CompatibilityConfig {
+    /// Oldest client version served, e.g. `0.7.0`, any when unset. The
+    /// clients report their version in the `x-client-version` metadata, the
+    /// requests without it being served.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Option<String>")]
+    pub min_client_version: Option<semver::Version>,
+}
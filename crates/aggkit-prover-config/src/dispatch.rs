@@ -0,0 +1,108 @@
+use std::{
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
+
+use aggkit_prover_types::codec::Codec;
+use prover_utils::{encryption::EncryptionConfig, retention::RetentionPolicy, secret::Secret};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Where the proof requests received by the gRPC API are proven.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum DispatchConfig {
+    /// Proven by the process receiving them.
+    #[default]
+    Local,
+
+    /// Enqueued into a Redis stream, and proven by the processes started
+    /// with `--worker`. Requires the `redis` feature.
+    RedisStream(RedisStreamConfig),
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RedisStreamConfig {
+    /// URL of the Redis server, e.g. `redis://redis:6379/0`.
+    pub url: Secret,
+
+    /// Stream of the proof jobs.
+    #[serde(default = "default_stream")]
+    pub stream: String,
+
+    /// Consumer group of the workers, each job being proven by one of them.
+    #[serde(default = "default_consumer_group")]
+    pub consumer_group: String,
+
+    /// Time the gRPC API waits for the result of a job, queueing included.
+    #[serde(
+        default = "default_result_timeout",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub result_timeout: Duration,
+
+    /// Number of jobs proven at once by one worker.
+    #[serde(default = "default_worker_concurrency")]
+    pub worker_concurrency: NonZeroUsize,
+
+    /// Time a job stays pending on a worker which stopped renewing it, e.g.
+    /// having died, before another worker claims it.
+    #[serde(
+        default = "default_claim_idle_timeout",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub claim_idle_timeout: Duration,
+
+    /// Number of times a job is delivered to the workers, claims included,
+    /// before being failed, e.g. when proving it kills them.
+    #[serde(default = "default_max_deliveries")]
+    pub max_deliveries: NonZeroU32,
+
+    /// Encoding of the jobs and of their outcome, the same on the gRPC API
+    /// and on the workers.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub codec: Codec,
+
+    /// Zstd level the jobs and their outcome are compressed with while in
+    /// Redis, uncompressed when unset. The compressed and uncompressed
+    /// payloads are both read, whatever the level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression_level: Option<i32>,
+
+    /// Keys the jobs and their outcome are encrypted with while in Redis,
+    /// the same on the gRPC API and on the workers. Plain when unset, the
+    /// plain payloads being read whatever the keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<EncryptionConfig>,
+
+    /// Jobs kept in the stream, by age and by count, the older ones being
+    /// trimmed by the garbage collection, e.g. the jobs of the workers which
+    /// died while proving them.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub retention: RetentionPolicy,
+}
+
+fn default_stream() -> String {
+    "aggkit-prover:jobs".to_string()
+}
+
+fn default_consumer_group() -> String {
+    "aggkit-prover-workers".to_string()
+}
+
+const fn default_result_timeout() -> Duration {
+    Duration::from_secs(2 * 60 * 60)
+}
+
+const fn default_worker_concurrency() -> NonZeroUsize {
+    NonZeroUsize::MIN
+}
+
+const fn default_claim_idle_timeout() -> Duration {
+    Duration::from_secs(5 * 60)
+}
+
+const fn default_max_deliveries() -> NonZeroU32 {
+    NonZeroU32::new(3).unwrap()
+}
@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Background removal of the persisted state beyond the `retention` of the
+/// audit log, of the proof cache and of the dispatch queue.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct GarbageCollectionConfig {
+    /// Interval between two collections, the first one being run at startup.
+    #[serde(
+        default = "default_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub interval: Duration,
+}
+
+impl Default for GarbageCollectionConfig {
+    fn default() -> Self {
+        Self {
+            interval: default_interval(),
+        }
+    }
+}
+
+const fn default_interval() -> Duration {
+    Duration::from_secs(60 * 60)
+}
@@ -0,0 +1,115 @@
+use std::{path::PathBuf, time::Duration};
+
+use prover_utils::secret::Secret;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Election of the instance serving the proof requests among a hot/standby
+/// pair, the standby taking over once the lease of the leader expires.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct LeaderElectionConfig {
+    /// Store of the lease held by the leader.
+    pub backend: LeaseBackend,
+
+    /// Time after which the lease of a leader not renewing it expires.
+    #[serde(
+        default = "default_lease_duration",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub lease_duration: Duration,
+
+    /// Interval between the attempts to acquire or renew the lease, shorter
+    /// than the lease duration.
+    #[serde(
+        default = "default_renew_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub renew_interval: Duration,
+
+    /// Identity of the instance in the lease, the host name followed by the
+    /// process id when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder_id: Option<String>,
+}
+
+/// Store of the lease.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum LeaseBackend {
+    /// Lease file on a storage shared by the instances, whose clocks are
+    /// expected to be in sync.
+    File(FileLeaseConfig),
+
+    /// Lease key in Redis, requiring the `redis` feature.
+    Redis(RedisLeaseConfig),
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct FileLeaseConfig {
+    /// Path of the lease file, e.g. on an NFS volume mounted by both
+    /// instances.
+    pub path: PathBuf,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RedisLeaseConfig {
+    /// URL of the Redis server, e.g. `redis://redis:6379/0`.
+    pub url: Secret,
+
+    /// Key of the lease, to tell apart the pairs sharing the Redis server.
+    #[serde(default = "default_key")]
+    pub key: String,
+}
+
+const fn default_lease_duration() -> Duration {
+    Duration::from_secs(15)
+}
+
+const fn default_renew_interval() -> Duration {
+    Duration::from_secs(5)
+}
+
+fn default_key() -> String {
+    "aggkit-prover:leader".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backend_selection() {
+        let config: LeaderElectionConfig = toml::from_str(
+            r#"
+            [backend.file]
+            path = "/shared/aggkit-prover.lease"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.backend,
+            LeaseBackend::File(FileLeaseConfig {
+                path: "/shared/aggkit-prover.lease".into()
+            })
+        );
+        assert_eq!(config.lease_duration, Duration::from_secs(15));
+        assert_eq!(config.renew_interval, Duration::from_secs(5));
+
+        let config: LeaderElectionConfig = toml::from_str(
+            r#"
+            lease-duration = "30s"
+            [backend.redis]
+            url = "redis://redis:6379/0"
+            "#,
+        )
+        .unwrap();
+        let LeaseBackend::Redis(redis) = config.backend else {
+            panic!("unexpected backend {:?}", config.backend);
+        };
+        assert_eq!(redis.key, "aggkit-prover:leader");
+        assert_eq!(config.lease_duration, Duration::from_secs(30));
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     net::{IpAddr, Ipv4Addr, SocketAddr},
     path::Path,
 };
@@ -6,17 +7,62 @@ use std::{
 use aggchain_proof_service::config::AggchainProofServiceConfig;
 use prover_config::{NetworkProverConfig, ProverType};
 use prover_logger::log::Log;
+use prover_utils::format::ConfigParseError;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-pub use crate::{shutdown::ShutdownConfig, telemetry::TelemetryConfig};
+pub use crate::{
+    admin::{AdminConfig, ProfilingConfig},
+    audit::AuditLogConfig,
+    compatibility::CompatibilityConfig,
+    dispatch::{DispatchConfig, RedisStreamConfig},
+    garbage_collection::GarbageCollectionConfig,
+    leader_election::{FileLeaseConfig, LeaderElectionConfig, LeaseBackend, RedisLeaseConfig},
+    mirroring::MirroringConfig,
+    networks::NetworksConfig,
+    proving_runtime::ProvingRuntimeConfig,
+    rate_limiting::{
+        ClientRateLimit, RateLimit, RateLimitingBackend, RateLimitingConfig, RedisBackendConfig,
+    },
+    readiness::{Dependency, ReadinessConfig},
+    shutdown::ShutdownConfig,
+    telemetry::TelemetryConfig,
+    tenancy::TenantConfig,
+    validation::ValidationError,
+    verifier::VerifierConfig,
+    watchdog::{ExpectedStageDurations, WatchdogConfig},
+};
+pub use prover_utils::format::ConfigFormat;
 
+pub(crate) mod admin;
+pub(crate) mod audit;
+pub(crate) mod compatibility;
+pub(crate) mod dispatch;
+pub(crate) mod garbage_collection;
+pub(crate) mod leader_election;
+pub(crate) mod mirroring;
+pub(crate) mod networks;
+pub(crate) mod proving_runtime;
+pub(crate) mod rate_limiting;
+pub(crate) mod readiness;
 pub mod shutdown;
 pub(crate) mod telemetry;
+pub(crate) mod tenancy;
+mod validation;
+pub(crate) mod verifier;
+pub(crate) mod watchdog;
+
+/// Default configuration, commented for the operators.
+pub const COMMENTED_DEFAULT_CONFIG: &str = include_str!("default.toml");
+
+/// Prefix of the environment variables overriding the configuration fields,
+/// e.g. `AGGKIT_PROVER__TELEMETRY__PROMETHEUS_ADDR`.
+pub const ENV_PREFIX: &str = "AGGKIT_PROVER";
 
 pub(crate) const DEFAULT_IP: std::net::Ipv4Addr = std::net::Ipv4Addr::new(0, 0, 0, 0);
 
 /// The Aggkit Prover configuration.
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ProverConfig {
     /// The gRPC endpoint used by the prover.
@@ -34,6 +80,18 @@ pub struct ProverConfig {
     #[serde(default)]
     pub telemetry: TelemetryConfig,
 
+    /// Admin API configuration.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub admin: AdminConfig,
+
+    /// Audit log of the proof requests, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_log: Option<AuditLogConfig>,
+
+    /// Removal of the persisted state beyond its retention.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub garbage_collection: GarbageCollectionConfig,
+
     /// The list of configuration options used during shutdown.
     #[serde(default)]
     pub shutdown: ShutdownConfig,
@@ -41,6 +99,49 @@ pub struct ProverConfig {
     #[serde(default)]
     pub aggchain_proof_service: AggchainProofServiceConfig,
 
+    /// Limits of the proof requests.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub rate_limiting: RateLimitingConfig,
+
+    /// Tenants sharing the prover, keyed by their name, isolated from each
+    /// other by their own concurrency and cycle limits.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tenants: BTreeMap<String, TenantConfig>,
+
+    /// Versions of the clients served.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub compatibility: CompatibilityConfig,
+
+    /// Networks whose proof requests are served.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub networks: NetworksConfig,
+
+    /// Mirroring of the proof requests to a secondary prover, disabled when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mirroring: Option<MirroringConfig>,
+
+    /// Where the proof requests are proven.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub dispatch: DispatchConfig,
+
+    /// Runtime proving the requests when they are proven in this process.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub proving_runtime: ProvingRuntimeConfig,
+
+    /// Hot/standby election of the instance serving the proof requests,
+    /// every instance serving them when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leader_election: Option<LeaderElectionConfig>,
+
+    /// Probing of the dependencies, before reporting the prover as serving.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub readiness: ReadinessConfig,
+
+    /// Watchdog of the slow proof requests, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watchdog: Option<WatchdogConfig>,
+
     /// The primary prover to be used for generation proofs
     #[serde(default)]
     pub primary_prover: ProverType,
@@ -48,6 +149,10 @@ pub struct ProverConfig {
     /// The fallback prover to be used for generation proofs
     #[serde(default)]
     pub fallback_prover: Option<ProverType>,
+
+    /// Proof verification API served in verify-only mode.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub verifier: VerifierConfig,
 }
 
 impl Default for ProverConfig {
@@ -56,17 +161,52 @@ impl Default for ProverConfig {
             grpc_endpoint: default_socket_addr(),
             log: Log::default(),
             telemetry: TelemetryConfig::default(),
+            admin: AdminConfig::default(),
+            audit_log: None,
+            garbage_collection: GarbageCollectionConfig::default(),
             shutdown: ShutdownConfig::default(),
             aggchain_proof_service: AggchainProofServiceConfig::default(),
+            rate_limiting: RateLimitingConfig::default(),
+            tenants: BTreeMap::new(),
+            compatibility: CompatibilityConfig::default(),
+            networks: NetworksConfig::default(),
+            mirroring: None,
+            dispatch: DispatchConfig::default(),
+            proving_runtime: ProvingRuntimeConfig::default(),
+            leader_election: None,
+            readiness: ReadinessConfig::default(),
+            watchdog: None,
             primary_prover: ProverType::NetworkProver(NetworkProverConfig::default()),
             fallback_prover: None,
             grpc: Default::default(),
+            verifier: VerifierConfig::default(),
         }
     }
 }
 
 impl ProverConfig {
+    /// JSON schema of the configuration files, whatever their format.
+    pub fn schema() -> schemars::Schema {
+        schemars::schema_for!(ProverConfig)
+    }
+
+    /// Default configuration as a table, giving the type of the fields missing
+    /// from the configuration files to their environment overrides.
+    fn defaults() -> toml::Table {
+        match toml::Value::try_from(Self::default()) {
+            Ok(toml::Value::Table(defaults)) => defaults,
+            _ => toml::Table::new(),
+        }
+    }
+
+    /// Loads the configuration file, in the format given by its extension.
     pub fn try_load(path: &Path) -> Result<Self, ConfigurationError> {
+        Self::try_load_as(path, ConfigFormat::from_path(path))
+    }
+
+    /// Loads the configuration file, the fields being overridden by the
+    /// environment variables prefixed with [`ENV_PREFIX`].
+    pub fn try_load_as(path: &Path, format: ConfigFormat) -> Result<Self, ConfigurationError> {
         let reader = std::fs::read_to_string(path).map_err(|source| {
             ConfigurationError::UnableToReadConfigFile {
                 path: path.to_path_buf(),
@@ -74,13 +214,28 @@ impl ProverConfig {
             }
         })?;
 
-        let deserializer = toml::de::Deserializer::new(&reader);
-        serde::Deserialize::deserialize(deserializer)
+        let mut config = format.parse(&reader)?;
+        prover_utils::env::apply_env_overrides(&mut config, &Self::defaults(), ENV_PREFIX)?;
+
+        toml::Value::Table(config)
+            .try_into()
             .map_err(ConfigurationError::DeserializationError)
     }
+
+    /// Loads the configuration like [`Self::try_load_as`], then checks its
+    /// consistency.
+    pub fn try_load_validated(
+        path: &Path,
+        format: ConfigFormat,
+    ) -> Result<Self, ConfigurationError> {
+        let config = Self::try_load_as(path, format)?;
+        config.validate().map_err(ConfigurationError::Invalid)?;
+
+        Ok(config)
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct GrpcConfig {
     #[serde(
@@ -140,10 +295,58 @@ pub enum ConfigurationError {
         source: std::io::Error,
     },
 
+    #[error("Failed to parse the configuration: {0}")]
+    ParseError(#[from] ConfigParseError),
+
     #[error("Failed to deserialize the configuration: {0}")]
     DeserializationError(#[from] toml::de::Error),
+
+    #[error("Invalid environment override: {0}")]
+    EnvOverride(#[from] prover_utils::env::EnvOverrideError),
+
+    #[error("Invalid configuration:{}", format_validation_errors(.0))]
+    Invalid(Vec<ValidationError>),
+}
+
+fn format_validation_errors(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(|error| format!("\n  - {error}"))
+        .collect()
 }
 
 pub(crate) fn default<T: Default + PartialEq>(t: &T) -> bool {
     *t == Default::default()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commented_default_config_matches_the_defaults() {
+        let config: ProverConfig = toml::from_str(COMMENTED_DEFAULT_CONFIG).unwrap();
+
+        assert_eq!(
+            toml::to_string(&config).unwrap(),
+            toml::to_string(&ProverConfig::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn schema_covers_the_configuration() {
+        let schema = ProverConfig::schema();
+        let properties = schema
+            .get("properties")
+            .and_then(|properties| properties.as_object())
+            .unwrap();
+
+        let config = toml::Value::try_from(ProverConfig::default()).unwrap();
+        for key in config.as_table().unwrap().keys() {
+            assert!(
+                properties.contains_key(key),
+                "{key} is missing from the schema"
+            );
+        }
+    }
+}
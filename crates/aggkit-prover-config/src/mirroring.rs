@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Mirroring of a share of the proof requests to a secondary prover, e.g. one
+/// running a new version of the aggchain proof program. The public values of
+/// its proofs are compared with the served ones, the divergences being
+/// logged, and its responses are never served.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct MirroringConfig {
+    /// gRPC endpoint of the secondary prover, e.g.
+    /// `http://aggkit-prover-canary:4446`.
+    pub endpoint: Url,
+
+    /// Percentage of the proof requests mirrored, from 0 to 100.
+    #[serde(default = "default_percentage")]
+    pub percentage: u8,
+
+    /// Time the secondary prover is given to answer one mirrored request.
+    #[serde(
+        default = "default_timeout",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub timeout: Duration,
+}
+
+const fn default_percentage() -> u8 {
+    10
+}
+
+const fn default_timeout() -> Duration {
+    Duration::from_secs(60 * 60)
+}
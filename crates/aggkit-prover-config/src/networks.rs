@@ -0,0 +1,15 @@
+use std::collections::BTreeSet;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Networks whose proof requests are served.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworksConfig {
+    /// Ids of the networks whose proof requests are rejected, e.g. during an
+    /// incident on one rollup, the provers of the other networks sharing this
+    /// configuration serving theirs. Toggled at runtime through the admin API.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub disabled: BTreeSet<u32>,
+}
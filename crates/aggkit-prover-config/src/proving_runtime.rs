@@ -0,0 +1,36 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Runtime proving the requests locally, apart from the one serving the gRPC
+/// API, the admin API and the health checks, which stay responsive while big
+/// jobs are running.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProvingRuntimeConfig {
+    /// Threads assembling the witnesses and running the other asynchronous
+    /// tasks of the proof requests.
+    #[serde(default = "default_worker_threads")]
+    pub worker_threads: usize,
+
+    /// Threads running the local proving and the other blocking tasks of the
+    /// proof requests, the others waiting for one of them to be free.
+    #[serde(default = "default_blocking_threads")]
+    pub blocking_threads: usize,
+}
+
+impl Default for ProvingRuntimeConfig {
+    fn default() -> Self {
+        Self {
+            worker_threads: default_worker_threads(),
+            blocking_threads: default_blocking_threads(),
+        }
+    }
+}
+
+const fn default_worker_threads() -> usize {
+    4
+}
+
+const fn default_blocking_threads() -> usize {
+    8
+}
@@ -0,0 +1,98 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use prover_utils::secret::Secret;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Limits of the proof requests, counted over fixed time windows.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RateLimitingConfig {
+    /// Limit of the requests for the network, shared by the clients without
+    /// a limit of their own. Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<RateLimit>,
+
+    /// Clients identified by the API key sent in the `x-api-key` metadata,
+    /// keyed by their name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub clients: BTreeMap<String, ClientRateLimit>,
+
+    /// Store of the request counters.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub backend: RateLimitingBackend,
+}
+
+/// Store of the request counters.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum RateLimitingBackend {
+    /// Counters local to the process, each replica enforcing the limits on
+    /// its own.
+    #[default]
+    InMemory,
+
+    /// Counters shared by the replicas, requiring the `redis` feature.
+    Redis(RedisBackendConfig),
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RedisBackendConfig {
+    /// URL of the Redis server, e.g. `redis://redis:6379/0`.
+    pub url: Secret,
+
+    /// Prefix of the keys of the counters, to tell apart the deployments
+    /// sharing the Redis server.
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: String,
+}
+
+fn default_key_prefix() -> String {
+    "aggkit-prover:rate-limit".to_string()
+}
+
+/// Maximum number of requests per period.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RateLimit {
+    pub requests: u32,
+    #[serde(with = "prover_utils::with::HumanDuration")]
+    pub period: Duration,
+}
+
+/// Client authenticated by its API key.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ClientRateLimit {
+    pub api_key: Secret,
+
+    /// Limit of the requests of the client, which shares the limit of the
+    /// network when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<RateLimit>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backend_selection() {
+        let config: RateLimitingConfig = toml::from_str("").unwrap();
+        assert_eq!(config.backend, RateLimitingBackend::InMemory);
+
+        let config: RateLimitingConfig = toml::from_str(
+            r#"
+            [backend.redis]
+            url = "redis://redis:6379/0"
+            "#,
+        )
+        .unwrap();
+        let RateLimitingBackend::Redis(redis) = config.backend else {
+            panic!("unexpected backend {:?}", config.backend);
+        };
+        assert_eq!(redis.url.expose(), "redis://redis:6379/0");
+        assert_eq!(redis.key_prefix, "aggkit-prover:rate-limit");
+    }
+}
@@ -0,0 +1,98 @@
+use std::{collections::BTreeSet, time::Duration};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Probing of the dependencies at startup, the prover reporting itself as
+/// not serving to the health checks until the mandatory ones respond.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ReadinessConfig {
+    /// Dependencies which have to respond before the prover serves, the
+    /// other ones being probed once and reported when down.
+    #[serde(default = "default_mandatory")]
+    pub mandatory: BTreeSet<Dependency>,
+
+    /// Interval between the probes of the mandatory dependencies not
+    /// responding yet.
+    #[serde(
+        default = "default_probe_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub probe_interval: Duration,
+
+    /// Time given to a dependency to respond to a probe.
+    #[serde(
+        default = "default_probe_timeout",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub probe_timeout: Duration,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        Self {
+            mandatory: default_mandatory(),
+            probe_interval: default_probe_interval(),
+            probe_timeout: default_probe_timeout(),
+        }
+    }
+}
+
+/// Dependency of the prover probed at startup.
+#[derive(
+    Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Dependency {
+    /// Proving cluster of the network or gRPC primary prover, and the one
+    /// the aggregation proofs are fetched from.
+    Sp1Cluster,
+    /// Proposer generating the aggregation proofs, unless mocked.
+    Proposer,
+    /// JSON-RPC endpoints of the l1 node.
+    L1Rpc,
+    /// JSON-RPC endpoints of the l2 execution and rollup nodes.
+    L2Rpc,
+}
+
+impl Dependency {
+    pub const ALL: [Self; 4] = [Self::Sp1Cluster, Self::Proposer, Self::L1Rpc, Self::L2Rpc];
+}
+
+fn default_mandatory() -> BTreeSet<Dependency> {
+    Dependency::ALL.into()
+}
+
+const fn default_probe_interval() -> Duration {
+    Duration::from_secs(5)
+}
+
+const fn default_probe_timeout() -> Duration {
+    Duration::from_secs(5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mandatory_dependencies() {
+        let config: ReadinessConfig = toml::from_str("").unwrap();
+        assert_eq!(config, ReadinessConfig::default());
+        assert_eq!(config.mandatory.len(), Dependency::ALL.len());
+
+        let config: ReadinessConfig = toml::from_str(
+            r#"
+            mandatory = ["l1-rpc", "proposer"]
+            probe-interval = "30s"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.mandatory,
+            BTreeSet::from([Dependency::Proposer, Dependency::L1Rpc])
+        );
+        assert_eq!(config.probe_interval, Duration::from_secs(30));
+    }
+}
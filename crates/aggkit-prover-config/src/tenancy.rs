@@ -0,0 +1,23 @@
+use std::{collections::BTreeSet, num::NonZeroUsize};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Customer of a shared prover, grouping the clients of its API keys.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct TenantConfig {
+    /// Names of the clients of `rate-limiting.clients` belonging to the
+    /// tenant, each client belonging to one tenant at most.
+    pub clients: BTreeSet<String>,
+
+    /// Largest number of requests of the tenant proven at once, the others
+    /// being rejected. Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<NonZeroUsize>,
+
+    /// Estimated cycles the tenant may spend per calendar month (UTC), after
+    /// `aggchain-proof-service.request-cost`. Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_cycle_budget: Option<u64>,
+}
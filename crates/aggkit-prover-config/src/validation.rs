@@ -0,0 +1,1105 @@
+use std::{collections::BTreeMap, net::SocketAddr, time::Duration};
+
+use aggkit_prover_types::compression::COMPRESSION_LEVELS;
+use prover_config::ProverType;
+use prover_utils::retention::RetentionPolicy;
+use url::Url;
+
+use crate::{DispatchConfig, ProverConfig, RateLimit};
+
+/// Inconsistency between the fields of a configuration, which deserializes
+/// fine but would fail once running.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    #[error("{first} and {second} both listen on {addr}")]
+    ListenerCollision {
+        first: &'static str,
+        second: &'static str,
+        addr: SocketAddr,
+    },
+
+    #[error("{field} is zero")]
+    ZeroTimeout { field: String },
+
+    #[error(
+        "{field} ({request_timeout:?}) is shorter than the proving timeout ({proving_timeout:?})"
+    )]
+    RequestTimeoutTooShort {
+        field: String,
+        request_timeout: Duration,
+        proving_timeout: Duration,
+    },
+
+    #[error("{field} has the unsupported scheme {scheme:?} ({url})")]
+    UnsupportedScheme {
+        field: String,
+        url: String,
+        scheme: String,
+    },
+
+    #[error(
+        "{stage} ({stage_timeout:?}) exceeds {field} ({timeout:?}), the stage would never time \
+         out on its own: raise {field} or lower {stage}"
+    )]
+    StageTimeoutTooLong {
+        field: String,
+        timeout: Duration,
+        stage: String,
+        stage_timeout: Duration,
+    },
+
+    #[error("{field} is the same as the primary prover, remove it or pick another prover")]
+    RedundantFallback { field: String },
+
+    #[error(
+        "{field} is a mock prover while the primary prover is not, the failed proofs would be \
+         replaced with mock ones: pick a cpu or network prover"
+    )]
+    MockFallback { field: String },
+
+    #[error("{field} is zero, no request would ever be accepted")]
+    ZeroRateLimit { field: String },
+
+    #[error("tenants.{tenant}.clients lists {client}, which is not one of rate-limiting.clients")]
+    UnknownTenantClient { tenant: String, client: String },
+
+    #[error("{client} belongs to both tenants.{first} and tenants.{second}")]
+    SharedTenantClient {
+        client: String,
+        first: String,
+        second: String,
+    },
+
+    #[error("{field} is zero, no request of the tenant would ever be accepted")]
+    ZeroCycleBudget { field: String },
+
+    #[error("{field} ({level}) is not a zstd level, pick one from 1 to 22")]
+    InvalidCompressionLevel { field: String, level: i32 },
+
+    #[error("{field} keeps nothing, unset it to keep everything")]
+    ZeroRetention { field: String },
+
+    #[error(
+        "dispatch.redis-stream.retention.max-age ({max_age:?}) is shorter than \
+         dispatch.redis-stream.result-timeout ({result_timeout:?}), the jobs would be trimmed \
+         while awaited"
+    )]
+    JobRetentionTooShort {
+        max_age: Duration,
+        result_timeout: Duration,
+    },
+
+    #[error("{field} is set but admin.addr is not, the admin API serving it is disabled")]
+    AdminDisabled { field: String },
+
+    #[error("{field} ({percentage}) is not a percentage, pick one from 0 to 100")]
+    InvalidPercentage { field: String, percentage: u8 },
+
+    #[error("{field} is zero, the runtime would have no thread to run the tasks")]
+    ZeroThreads { field: String },
+
+    #[error("{field} is zero, the block ranges of the events would be empty")]
+    ZeroBlockRange { field: String },
+
+    #[error("{field} is zero, the bridge events would never be read")]
+    ZeroConcurrentReads { field: String },
+
+    #[error("{field} is zero, no bridge exits witness could be built")]
+    ZeroRetainedBlocks { field: String },
+
+    #[error("{field} is zero, every request would be reported as slow")]
+    ZeroMultiplier { field: String },
+
+    #[error(
+        "leader-election.renew-interval ({renew_interval:?}) is not shorter than \
+         leader-election.lease-duration ({lease_duration:?}), the lease would expire before \
+         being renewed"
+    )]
+    LeaseNotRenewed {
+        renew_interval: Duration,
+        lease_duration: Duration,
+    },
+}
+
+const HTTP_SCHEMES: &[&str] = &["http", "https"];
+const RPC_SCHEMES: &[&str] = &["http", "https", "ws", "wss"];
+
+impl ProverConfig {
+    /// Checks the consistency of the configuration, returning every issue
+    /// found.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+
+        if overlaps(self.grpc_endpoint, self.telemetry.addr) {
+            errors.push(ValidationError::ListenerCollision {
+                first: "grpc-endpoint",
+                second: "telemetry.prometheus-addr",
+                addr: self.grpc_endpoint,
+            });
+        }
+        if let Some(admin_addr) = self.admin.addr {
+            for (field, addr) in [
+                ("grpc-endpoint", self.grpc_endpoint),
+                ("telemetry.prometheus-addr", self.telemetry.addr),
+            ] {
+                if overlaps(addr, admin_addr) {
+                    errors.push(ValidationError::ListenerCollision {
+                        first: field,
+                        second: "admin.addr",
+                        addr: admin_addr,
+                    });
+                }
+            }
+        }
+
+        if let Some(profiling) = &self.admin.profiling {
+            if self.admin.addr.is_none() {
+                errors.push(ValidationError::AdminDisabled {
+                    field: "admin.profiling".to_string(),
+                });
+            }
+            if profiling.max_duration.is_zero() {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: "admin.profiling.max-duration".to_string(),
+                });
+            }
+        }
+
+        if let Some(audit_log) = &self.audit_log {
+            validate_retention(&mut errors, "audit-log.retention", &audit_log.retention);
+        }
+
+        if self.shutdown.runtime_timeout.is_zero() {
+            errors.push(ValidationError::ZeroTimeout {
+                field: "shutdown.runtime-timeout".to_string(),
+            });
+        }
+        for (field, timeout) in [
+            ("readiness.probe-interval", self.readiness.probe_interval),
+            ("readiness.probe-timeout", self.readiness.probe_timeout),
+            (
+                "admin.health-refresh-interval",
+                self.admin.health_refresh_interval,
+            ),
+            (
+                "garbage-collection.interval",
+                self.garbage_collection.interval,
+            ),
+        ] {
+            if timeout.is_zero() {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: field.to_string(),
+                });
+            }
+        }
+
+        validate_prover(&mut errors, "primary-prover", &self.primary_prover);
+        if let Some(fallback) = &self.fallback_prover {
+            validate_prover(&mut errors, "fallback-prover", fallback);
+            validate_fallback(
+                &mut errors,
+                "fallback-prover",
+                &self.primary_prover,
+                fallback,
+            );
+        }
+
+        let builder = &self.aggchain_proof_service.aggchain_proof_builder;
+        if builder.proving_timeout.is_zero() {
+            errors.push(ValidationError::ZeroTimeout {
+                field: "aggchain-proof-service.aggchain-proof-builder.proving-timeout".to_string(),
+            });
+        }
+        validate_prover(
+            &mut errors,
+            "aggchain-proof-service.aggchain-proof-builder.primary-prover",
+            &builder.primary_prover,
+        );
+        if let Some(fallback) = &builder.fallback_prover {
+            validate_prover(
+                &mut errors,
+                "aggchain-proof-service.aggchain-proof-builder.fallback-prover",
+                fallback,
+            );
+            validate_fallback(
+                &mut errors,
+                "aggchain-proof-service.aggchain-proof-builder.fallback-prover",
+                &builder.primary_prover,
+                fallback,
+            );
+        }
+        if builder.local_exit_root_check.max_block_range == 0 {
+            errors.push(ValidationError::ZeroBlockRange {
+                field: "aggchain-proof-service.aggchain-proof-builder.local-exit-root-check.\
+                        max-block-range"
+                    .to_string(),
+            });
+        }
+        if builder.local_exit_root_check.max_concurrent_reads == 0 {
+            errors.push(ValidationError::ZeroConcurrentReads {
+                field: "aggchain-proof-service.aggchain-proof-builder.local-exit-root-check.\
+                        max-concurrent-reads"
+                    .to_string(),
+            });
+        }
+        if builder.local_exit_root_check.retained_blocks == 0 {
+            errors.push(ValidationError::ZeroRetainedBlocks {
+                field: "aggchain-proof-service.aggchain-proof-builder.local-exit-root-check.\
+                        retained-blocks"
+                    .to_string(),
+            });
+        }
+        if self
+            .aggchain_proof_service
+            .freshness
+            .settlement_poll_interval
+            .is_zero()
+        {
+            errors.push(ValidationError::ZeroTimeout {
+                field: "aggchain-proof-service.freshness.settlement-poll-interval".to_string(),
+            });
+        }
+        if let Some(mirror) = &self.aggchain_proof_service.l1_info_tree_mirror {
+            if mirror.max_block_range == 0 {
+                errors.push(ValidationError::ZeroBlockRange {
+                    field: "aggchain-proof-service.l1-info-tree-mirror.max-block-range".to_string(),
+                });
+            }
+            if mirror.poll_interval.is_zero() {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: "aggchain-proof-service.l1-info-tree-mirror.poll-interval".to_string(),
+                });
+            }
+        }
+
+        // The proving timeout of the builder bounds the whole generation of
+        // one aggchain proof, the aggregation proof included.
+        let stages = [
+            (
+                "aggchain-proof-service.aggchain-proof-builder.primary-prover.proving-timeout",
+                Some(proving_timeout(&builder.primary_prover)),
+            ),
+            (
+                "aggchain-proof-service.aggchain-proof-builder.fallback-prover.proving-timeout",
+                builder.fallback_prover.as_ref().map(proving_timeout),
+            ),
+            (
+                "aggchain-proof-service.proposer-service.client.proving-timeout",
+                Some(
+                    self.aggchain_proof_service
+                        .proposer_service
+                        .client
+                        .proving_timeout,
+                ),
+            ),
+        ];
+        for (stage, stage_timeout) in stages {
+            if let Some(stage_timeout) = stage_timeout {
+                if stage_timeout > builder.proving_timeout {
+                    errors.push(ValidationError::StageTimeoutTooLong {
+                        field: "aggchain-proof-service.aggchain-proof-builder.proving-timeout"
+                            .to_string(),
+                        timeout: builder.proving_timeout,
+                        stage: stage.to_string(),
+                        stage_timeout,
+                    });
+                }
+            }
+        }
+
+        if let DispatchConfig::RedisStream(queue) = &self.dispatch {
+            if builder.proving_timeout > queue.result_timeout {
+                errors.push(ValidationError::StageTimeoutTooLong {
+                    field: "dispatch.redis-stream.result-timeout".to_string(),
+                    timeout: queue.result_timeout,
+                    stage: "aggchain-proof-service.aggchain-proof-builder.proving-timeout"
+                        .to_string(),
+                    stage_timeout: builder.proving_timeout,
+                });
+            }
+            validate_retention(
+                &mut errors,
+                "dispatch.redis-stream.retention",
+                &queue.retention,
+            );
+            if let Some(max_age) = queue.retention.max_age {
+                if !max_age.is_zero() && max_age < queue.result_timeout {
+                    errors.push(ValidationError::JobRetentionTooShort {
+                        max_age,
+                        result_timeout: queue.result_timeout,
+                    });
+                }
+            }
+            if let Some(level) = queue.compression_level {
+                if !COMPRESSION_LEVELS.contains(&level) {
+                    errors.push(ValidationError::InvalidCompressionLevel {
+                        field: "dispatch.redis-stream.compression-level".to_string(),
+                        level,
+                    });
+                }
+            }
+        }
+
+        for (network_id, overrides) in &self.aggchain_proof_service.networks {
+            let Some(prover) = &overrides.prover else {
+                continue;
+            };
+            let field = format!("aggchain-proof-service.network.{network_id}.prover");
+            if let Some(backend) = &prover.backend {
+                validate_prover(&mut errors, &format!("{field}.backend"), backend);
+            }
+            if prover
+                .proving_timeout
+                .is_some_and(|timeout| timeout.is_zero())
+            {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: format!("{field}.proving-timeout"),
+                });
+            }
+        }
+
+        if let Some(limit) = &self.rate_limiting.network {
+            validate_rate_limit(&mut errors, "rate-limiting.network", limit);
+        }
+        for (name, client) in &self.rate_limiting.clients {
+            if let Some(limit) = &client.limit {
+                validate_rate_limit(
+                    &mut errors,
+                    &format!("rate-limiting.clients.{name}.limit"),
+                    limit,
+                );
+            }
+        }
+
+        let mut tenant_of_client = BTreeMap::new();
+        for (tenant, config) in &self.tenants {
+            for client in &config.clients {
+                if !self.rate_limiting.clients.contains_key(client) {
+                    errors.push(ValidationError::UnknownTenantClient {
+                        tenant: tenant.clone(),
+                        client: client.clone(),
+                    });
+                }
+                if let Some(first) = tenant_of_client.insert(client, tenant) {
+                    errors.push(ValidationError::SharedTenantClient {
+                        client: client.clone(),
+                        first: first.clone(),
+                        second: tenant.clone(),
+                    });
+                }
+            }
+            if config.monthly_cycle_budget == Some(0) {
+                errors.push(ValidationError::ZeroCycleBudget {
+                    field: format!("tenants.{tenant}.monthly-cycle-budget"),
+                });
+            }
+        }
+
+        if let Some(election) = &self.leader_election {
+            if election.renew_interval.is_zero() {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: "leader-election.renew-interval".to_string(),
+                });
+            } else if election.renew_interval >= election.lease_duration {
+                errors.push(ValidationError::LeaseNotRenewed {
+                    renew_interval: election.renew_interval,
+                    lease_duration: election.lease_duration,
+                });
+            }
+        }
+
+        for (field, threads) in [
+            (
+                "proving-runtime.worker-threads",
+                self.proving_runtime.worker_threads,
+            ),
+            (
+                "proving-runtime.blocking-threads",
+                self.proving_runtime.blocking_threads,
+            ),
+        ] {
+            if threads == 0 {
+                errors.push(ValidationError::ZeroThreads {
+                    field: field.to_string(),
+                });
+            }
+        }
+
+        if let Some(mirroring) = &self.mirroring {
+            validate_scheme(
+                &mut errors,
+                "mirroring.endpoint",
+                &mirroring.endpoint,
+                HTTP_SCHEMES,
+            );
+            if mirroring.percentage > 100 {
+                errors.push(ValidationError::InvalidPercentage {
+                    field: "mirroring.percentage".to_string(),
+                    percentage: mirroring.percentage,
+                });
+            }
+            if mirroring.timeout.is_zero() {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: "mirroring.timeout".to_string(),
+                });
+            }
+        }
+
+        if let Some(watchdog) = &self.watchdog {
+            if watchdog.multiplier == 0 {
+                errors.push(ValidationError::ZeroMultiplier {
+                    field: "watchdog.multiplier".to_string(),
+                });
+            }
+            if watchdog.check_interval.is_zero() {
+                errors.push(ValidationError::ZeroTimeout {
+                    field: "watchdog.check-interval".to_string(),
+                });
+            }
+        }
+
+        let contracts = &builder.contracts;
+        validate_scheme(
+            &mut errors,
+            "aggchain-proof-service.aggchain-proof-builder.contracts.l1-rpc-endpoint",
+            &contracts.l1_rpc_endpoint.url,
+            RPC_SCHEMES,
+        );
+        for url in &contracts.l1_rpc_fallback_endpoints {
+            validate_scheme(
+                &mut errors,
+                "aggchain-proof-service.aggchain-proof-builder.contracts.\
+                 l1-rpc-fallback-endpoints",
+                url,
+                RPC_SCHEMES,
+            );
+        }
+        validate_scheme(
+            &mut errors,
+            "aggchain-proof-service.aggchain-proof-builder.contracts.\
+             l2-execution-layer-rpc-endpoint",
+            &contracts.l2_execution_layer_rpc_endpoint,
+            RPC_SCHEMES,
+        );
+        for url in &contracts.l2_execution_layer_rpc_fallback_endpoints {
+            validate_scheme(
+                &mut errors,
+                "aggchain-proof-service.aggchain-proof-builder.contracts.\
+                 l2-execution-layer-rpc-fallback-endpoints",
+                url,
+                RPC_SCHEMES,
+            );
+        }
+        validate_scheme(
+            &mut errors,
+            "aggchain-proof-service.aggchain-proof-builder.contracts.\
+             l2-consensus-layer-rpc-endpoint",
+            &contracts.l2_consensus_layer_rpc_endpoint,
+            HTTP_SCHEMES,
+        );
+
+        let proposer = &self.aggchain_proof_service.proposer_service;
+        validate_scheme(
+            &mut errors,
+            "aggchain-proof-service.proposer-service.l1-rpc-endpoint",
+            &proposer.l1_rpc_endpoint.url,
+            RPC_SCHEMES,
+        );
+        validate_scheme(
+            &mut errors,
+            "aggchain-proof-service.proposer-service.client.sp1-cluster-endpoint",
+            &proposer.client.sp1_cluster_endpoint,
+            HTTP_SCHEMES,
+        );
+        if proposer.client.request_timeout.is_zero() {
+            errors.push(ValidationError::ZeroTimeout {
+                field: "aggchain-proof-service.proposer-service.client.request-timeout".to_string(),
+            });
+        }
+        if proposer.client.proving_timeout.is_zero() {
+            errors.push(ValidationError::ZeroTimeout {
+                field: "aggchain-proof-service.proposer-service.client.proving-timeout".to_string(),
+            });
+        }
+        if let Some(cache) = &proposer.proof_cache {
+            if let Some(level) = cache.compression_level {
+                if !COMPRESSION_LEVELS.contains(&level) {
+                    errors.push(ValidationError::InvalidCompressionLevel {
+                        field: "aggchain-proof-service.proposer-service.proof-cache.\
+                                compression-level"
+                            .to_string(),
+                        level,
+                    });
+                }
+            }
+            validate_retention(
+                &mut errors,
+                "aggchain-proof-service.proposer-service.proof-cache.retention",
+                &cache.retention,
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+fn validate_prover(errors: &mut Vec<ValidationError>, field: &str, prover: &ProverType) {
+    let (proving_timeout, proving_request_timeout, endpoint) = match prover {
+        ProverType::NetworkProver(config) => (
+            config.proving_timeout,
+            config.proving_request_timeout,
+            Some(("sp1-cluster-endpoint", &config.sp1_cluster_endpoint)),
+        ),
+        ProverType::GrpcProver(config) => (
+            config.proving_timeout,
+            config.proving_request_timeout,
+            Some(("endpoint", &config.endpoint)),
+        ),
+        ProverType::CpuProver(config) => {
+            (config.proving_timeout, config.proving_request_timeout, None)
+        }
+        ProverType::MockProver(config) => {
+            (config.proving_timeout, config.proving_request_timeout, None)
+        }
+    };
+
+    if proving_timeout.is_zero() {
+        errors.push(ValidationError::ZeroTimeout {
+            field: format!("{field}.proving-timeout"),
+        });
+    }
+    if let Some(request_timeout) = proving_request_timeout {
+        if request_timeout < proving_timeout {
+            errors.push(ValidationError::RequestTimeoutTooShort {
+                field: format!("{field}.proving-request-timeout"),
+                request_timeout,
+                proving_timeout,
+            });
+        }
+    }
+    if let Some((name, url)) = endpoint {
+        validate_scheme(errors, &format!("{field}.{name}"), url, HTTP_SCHEMES);
+    }
+}
+
+fn validate_fallback(
+    errors: &mut Vec<ValidationError>,
+    field: &str,
+    primary: &ProverType,
+    fallback: &ProverType,
+) {
+    if fallback == primary {
+        errors.push(ValidationError::RedundantFallback {
+            field: field.to_string(),
+        });
+    } else if matches!(fallback, ProverType::MockProver(_))
+        && !matches!(primary, ProverType::MockProver(_))
+    {
+        errors.push(ValidationError::MockFallback {
+            field: field.to_string(),
+        });
+    }
+}
+
+fn validate_rate_limit(errors: &mut Vec<ValidationError>, field: &str, limit: &RateLimit) {
+    if limit.requests == 0 {
+        errors.push(ValidationError::ZeroRateLimit {
+            field: format!("{field}.requests"),
+        });
+    }
+    if limit.period.is_zero() {
+        errors.push(ValidationError::ZeroRateLimit {
+            field: format!("{field}.period"),
+        });
+    }
+}
+
+fn validate_retention(errors: &mut Vec<ValidationError>, field: &str, retention: &RetentionPolicy) {
+    if retention.max_age.is_some_and(|max_age| max_age.is_zero()) {
+        errors.push(ValidationError::ZeroRetention {
+            field: format!("{field}.max-age"),
+        });
+    }
+    if retention.max_count == Some(0) {
+        errors.push(ValidationError::ZeroRetention {
+            field: format!("{field}.max-count"),
+        });
+    }
+}
+
+fn proving_timeout(prover: &ProverType) -> Duration {
+    match prover {
+        ProverType::NetworkProver(config) => config.proving_timeout,
+        ProverType::CpuProver(config) => config.proving_timeout,
+        ProverType::MockProver(config) => config.proving_timeout,
+        ProverType::GrpcProver(config) => config.proving_timeout,
+    }
+}
+
+fn validate_scheme(errors: &mut Vec<ValidationError>, field: &str, url: &Url, schemes: &[&str]) {
+    if !schemes.contains(&url.scheme()) {
+        errors.push(ValidationError::UnsupportedScheme {
+            field: field.to_string(),
+            url: url.to_string(),
+            scheme: url.scheme().to_string(),
+        });
+    }
+}
+
+/// Whether two listeners would be bound to the same address.
+fn overlaps(first: SocketAddr, second: SocketAddr) -> bool {
+    first.port() == second.port()
+        && (first.ip() == second.ip()
+            || first.ip().is_unspecified()
+            || second.ip().is_unspecified())
+}
+
+#[cfg(test)]
+mod tests {
+    use prover_config::{CpuProverConfig, GrpcProverConfig, MockProverConfig, NetworkProverConfig};
+
+    use super::*;
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_eq!(ProverConfig::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn every_issue_is_reported() {
+        let mut config = ProverConfig::default();
+        config.grpc_endpoint = "127.0.0.1:3001".parse().unwrap();
+        config.shutdown.runtime_timeout = Duration::ZERO;
+        config.primary_prover = ProverType::CpuProver(CpuProverConfig {
+            proving_request_timeout: Some(Duration::from_secs(1)),
+            ..Default::default()
+        });
+        config
+            .aggchain_proof_service
+            .aggchain_proof_builder
+            .contracts
+            .l2_consensus_layer_rpc_endpoint = "ftp://localhost:21".parse().unwrap();
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert!(matches!(
+            errors[0],
+            ValidationError::ListenerCollision { .. }
+        ));
+        assert!(matches!(errors[1], ValidationError::ZeroTimeout { .. }));
+        assert!(matches!(
+            errors[2],
+            ValidationError::RequestTimeoutTooShort { .. }
+        ));
+        assert!(matches!(
+            errors[3],
+            ValidationError::UnsupportedScheme { .. }
+        ));
+    }
+
+    #[test]
+    fn admin_listener_collision() {
+        let mut config = ProverConfig::default();
+        config.admin.addr = Some("127.0.0.1:3001".parse().unwrap());
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationError::ListenerCollision {
+                first: "telemetry.prometheus-addr",
+                second: "admin.addr",
+                addr: "127.0.0.1:3001".parse().unwrap(),
+            }])
+        );
+    }
+
+    #[test]
+    fn profiling_without_admin_api() {
+        let mut config = ProverConfig::default();
+        config.admin = toml::from_str(
+            r#"
+            [profiling]
+            token = "profiling-token"
+            max-duration = "0s"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::AdminDisabled {
+                    field: "admin.profiling".to_string(),
+                },
+                ValidationError::ZeroTimeout {
+                    field: "admin.profiling.max-duration".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn grpc_prover_endpoint_scheme() {
+        let mut config = ProverConfig::default();
+        config.fallback_prover = Some(ProverType::GrpcProver(GrpcProverConfig {
+            endpoint: "tcp://prover:50051".parse().unwrap(),
+            api_key: None,
+            proving_request_timeout: None,
+            proving_timeout: Duration::from_secs(300),
+        }));
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationError::UnsupportedScheme {
+                field: "fallback-prover.endpoint".to_string(),
+                url: "tcp://prover:50051".to_string(),
+                scheme: "tcp".to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn dispatch_result_timeout() {
+        let mut config = ProverConfig::default();
+        config.dispatch = toml::from_str(
+            r#"
+            [redis-stream]
+            url = "redis://redis:6379"
+            result-timeout = "30m"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationError::StageTimeoutTooLong {
+                field: "dispatch.redis-stream.result-timeout".to_string(),
+                timeout: Duration::from_secs(30 * 60),
+                stage: "aggchain-proof-service.aggchain-proof-builder.proving-timeout".to_string(),
+                stage_timeout: Duration::from_secs(60 * 60),
+            }])
+        );
+    }
+
+    #[test]
+    fn dispatch_compression_level() {
+        let mut config = ProverConfig::default();
+        config.dispatch = toml::from_str(
+            r#"
+            [redis-stream]
+            url = "redis://redis:6379"
+            compression-level = 0
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationError::InvalidCompressionLevel {
+                field: "dispatch.redis-stream.compression-level".to_string(),
+                level: 0,
+            }])
+        );
+
+        let DispatchConfig::RedisStream(queue) = &mut config.dispatch else {
+            unreachable!()
+        };
+        queue.compression_level = Some(3);
+        assert_eq!(config.validate(), Ok(()));
+    }
+
+    #[test]
+    fn retention_policies() {
+        let mut config = ProverConfig::default();
+        config.dispatch = toml::from_str(
+            r#"
+            [redis-stream]
+            url = "redis://redis:6379"
+            retention = { max-age = "1h", max-count = 0 }
+            "#,
+        )
+        .unwrap();
+        config.audit_log = Some(
+            toml::from_str(
+                r#"
+                dir = "/var/lib/aggkit-prover/audit"
+                retention = { max-age = 0 }
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::ZeroRetention {
+                    field: "audit-log.retention.max-age".to_string(),
+                },
+                ValidationError::ZeroRetention {
+                    field: "dispatch.redis-stream.retention.max-count".to_string(),
+                },
+                ValidationError::JobRetentionTooShort {
+                    max_age: Duration::from_secs(60 * 60),
+                    result_timeout: Duration::from_secs(2 * 60 * 60),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn leader_election_renew_interval() {
+        let mut config = ProverConfig::default();
+        config.leader_election = Some(
+            toml::from_str(
+                r#"
+                lease-duration = "10s"
+                renew-interval = "10s"
+                [backend.file]
+                path = "/shared/aggkit-prover.lease"
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationError::LeaseNotRenewed {
+                renew_interval: Duration::from_secs(10),
+                lease_duration: Duration::from_secs(10),
+            }])
+        );
+    }
+
+    #[test]
+    fn tenants() {
+        let mut config = ProverConfig::default();
+        config.rate_limiting = toml::from_str(
+            r#"
+            [clients.aggsender-a]
+            api-key = "key-a"
+            [clients.aggsender-b]
+            api-key = "key-b"
+            "#,
+        )
+        .unwrap();
+        config.tenants = toml::from_str(
+            r#"
+            [acme]
+            clients = ["aggsender-a", "aggsender-c"]
+            monthly-cycle-budget = 0
+            [globex]
+            clients = ["aggsender-a", "aggsender-b"]
+            max-concurrent-requests = 2
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::UnknownTenantClient {
+                    tenant: "acme".to_string(),
+                    client: "aggsender-c".to_string(),
+                },
+                ValidationError::ZeroCycleBudget {
+                    field: "tenants.acme.monthly-cycle-budget".to_string(),
+                },
+                ValidationError::SharedTenantClient {
+                    client: "aggsender-a".to_string(),
+                    first: "acme".to_string(),
+                    second: "globex".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn proving_runtime() {
+        let mut config = ProverConfig::default();
+        config.proving_runtime.worker_threads = 0;
+        config.proving_runtime.blocking_threads = 0;
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::ZeroThreads {
+                    field: "proving-runtime.worker-threads".to_string(),
+                },
+                ValidationError::ZeroThreads {
+                    field: "proving-runtime.blocking-threads".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn watchdog() {
+        let mut config = ProverConfig::default();
+        config.watchdog = Some(
+            toml::from_str(
+                r#"
+                multiplier = 0
+                check-interval = "0s"
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::ZeroMultiplier {
+                    field: "watchdog.multiplier".to_string(),
+                },
+                ValidationError::ZeroTimeout {
+                    field: "watchdog.check-interval".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn local_exit_root_check() {
+        let mut config = ProverConfig::default();
+        config
+            .aggchain_proof_service
+            .aggchain_proof_builder
+            .local_exit_root_check = toml::from_str(
+            r#"
+            from-block = 100
+            max-block-range = 0
+            max-concurrent-reads = 0
+            retained-blocks = 0
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::ZeroBlockRange {
+                    field: "aggchain-proof-service.aggchain-proof-builder.local-exit-root-check.\
+                            max-block-range"
+                        .to_string(),
+                },
+                ValidationError::ZeroConcurrentReads {
+                    field: "aggchain-proof-service.aggchain-proof-builder.local-exit-root-check.\
+                            max-concurrent-reads"
+                        .to_string(),
+                },
+                ValidationError::ZeroRetainedBlocks {
+                    field: "aggchain-proof-service.aggchain-proof-builder.local-exit-root-check.\
+                            retained-blocks"
+                        .to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn settlement_poll_interval() {
+        let mut config = ProverConfig::default();
+        config.aggchain_proof_service.freshness =
+            toml::from_str(r#"settlement-poll-interval = "0s""#).unwrap();
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationError::ZeroTimeout {
+                field: "aggchain-proof-service.freshness.settlement-poll-interval".to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn l1_info_tree_mirror() {
+        let mut config = ProverConfig::default();
+        config.aggchain_proof_service.l1_info_tree_mirror = Some(
+            toml::from_str(
+                r#"
+                max-block-range = 0
+                poll-interval = "0s"
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::ZeroBlockRange {
+                    field: "aggchain-proof-service.l1-info-tree-mirror.max-block-range".to_string(),
+                },
+                ValidationError::ZeroTimeout {
+                    field: "aggchain-proof-service.l1-info-tree-mirror.poll-interval".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn mirroring() {
+        let mut config = ProverConfig::default();
+        config.mirroring = Some(
+            toml::from_str(
+                r#"
+                endpoint = "grpc://aggkit-prover-canary:4446"
+                percentage = 101
+                timeout = "0s"
+                "#,
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationError::UnsupportedScheme {
+                    field: "mirroring.endpoint".to_string(),
+                    url: "grpc://aggkit-prover-canary:4446".to_string(),
+                    scheme: "grpc".to_string(),
+                },
+                ValidationError::InvalidPercentage {
+                    field: "mirroring.percentage".to_string(),
+                    percentage: 101,
+                },
+                ValidationError::ZeroTimeout {
+                    field: "mirroring.timeout".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn stage_timeouts_and_fallbacks() {
+        let mut config = ProverConfig::default();
+        config.fallback_prover = Some(ProverType::NetworkProver(NetworkProverConfig::default()));
+        let builder = &mut config.aggchain_proof_service.aggchain_proof_builder;
+        builder.proving_timeout = Duration::from_secs(600);
+        builder.fallback_prover = Some(ProverType::MockProver(MockProverConfig::default()));
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::RedundantFallback {
+                    field: "fallback-prover".to_string(),
+                },
+                ValidationError::MockFallback {
+                    field: "aggchain-proof-service.aggchain-proof-builder.fallback-prover"
+                        .to_string(),
+                },
+                ValidationError::StageTimeoutTooLong {
+                    field: "aggchain-proof-service.aggchain-proof-builder.proving-timeout"
+                        .to_string(),
+                    timeout: Duration::from_secs(600),
+                    stage: "aggchain-proof-service.proposer-service.client.proving-timeout"
+                        .to_string(),
+                    stage_timeout: Duration::from_secs(3600),
+                },
+            ]
+        );
+    }
+}
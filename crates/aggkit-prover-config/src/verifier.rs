@@ -0,0 +1,33 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of the verify-only mode, `aggkit-prover run --verify-only`,
+/// where the proof verification API is served instead of the proving one.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct VerifierConfig {
+    /// Whether the proofs of the aggchain proof program built into the prover
+    /// are accepted.
+    #[serde(default = "default_builtin_aggchain_vkey")]
+    pub builtin_aggchain_vkey: bool,
+
+    /// Paths to the bincode encoded verifying keys of the other programs
+    /// whose proofs are accepted, by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub vkeys: BTreeMap<String, PathBuf>,
+}
+
+impl Default for VerifierConfig {
+    fn default() -> Self {
+        Self {
+            builtin_aggchain_vkey: default_builtin_aggchain_vkey(),
+            vkeys: BTreeMap::new(),
+        }
+    }
+}
+
+const fn default_builtin_aggchain_vkey() -> bool {
+    true
+}
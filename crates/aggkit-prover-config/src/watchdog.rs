@@ -0,0 +1,146 @@
+use std::{path::PathBuf, time::Duration};
+
+use aggkit_prover_types::stage_timings::Stage;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Watchdog of the slow proof requests, capturing a diagnostic bundle when
+/// one spends `multiplier` times the expected duration of a stage in it.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct WatchdogConfig {
+    /// Factor of the expected duration of a stage above which a request
+    /// spending that long in it is slow.
+    #[serde(default = "default_multiplier")]
+    pub multiplier: u32,
+
+    /// Interval between the checks of the requests in flight.
+    #[serde(
+        default = "default_check_interval",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub check_interval: Duration,
+
+    /// Time the threads are sampled for their stacks, when the prover is
+    /// built with the `profiling` feature.
+    #[serde(
+        default = "default_stack_sampling",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub stack_sampling: Duration,
+
+    /// Directory the bundles are written to as JSON files, besides being
+    /// logged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub directory: Option<PathBuf>,
+
+    /// Expected duration of each stage of the requests.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub expected: ExpectedStageDurations,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            multiplier: default_multiplier(),
+            check_interval: default_check_interval(),
+            stack_sampling: default_stack_sampling(),
+            directory: None,
+            expected: ExpectedStageDurations::default(),
+        }
+    }
+}
+
+/// Expected duration of each stage of the proof requests.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct ExpectedStageDurations {
+    #[serde(default = "default_intake", with = "prover_utils::with::HumanDuration")]
+    pub intake: Duration,
+
+    #[serde(
+        default = "default_validation",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub validation: Duration,
+
+    #[serde(
+        default = "default_proposer",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub proposer: Duration,
+
+    #[serde(
+        default = "default_witness",
+        with = "prover_utils::with::HumanDuration"
+    )]
+    pub witness: Duration,
+
+    #[serde(default = "default_prove", with = "prover_utils::with::HumanDuration")]
+    pub prove: Duration,
+
+    #[serde(default = "default_verify", with = "prover_utils::with::HumanDuration")]
+    pub verify: Duration,
+}
+
+impl ExpectedStageDurations {
+    pub fn get(&self, stage: Stage) -> Duration {
+        match stage {
+            Stage::Intake => self.intake,
+            Stage::Validation => self.validation,
+            Stage::Proposer => self.proposer,
+            Stage::Witness => self.witness,
+            Stage::Prove => self.prove,
+            Stage::Verify => self.verify,
+        }
+    }
+}
+
+impl Default for ExpectedStageDurations {
+    fn default() -> Self {
+        Self {
+            intake: default_intake(),
+            validation: default_validation(),
+            proposer: default_proposer(),
+            witness: default_witness(),
+            prove: default_prove(),
+            verify: default_verify(),
+        }
+    }
+}
+
+const fn default_multiplier() -> u32 {
+    3
+}
+
+const fn default_check_interval() -> Duration {
+    Duration::from_secs(10)
+}
+
+const fn default_stack_sampling() -> Duration {
+    Duration::from_secs(1)
+}
+
+const fn default_intake() -> Duration {
+    Duration::from_secs(1)
+}
+
+const fn default_validation() -> Duration {
+    Duration::from_secs(5)
+}
+
+const fn default_proposer() -> Duration {
+    Duration::from_secs(30 * 60)
+}
+
+const fn default_witness() -> Duration {
+    Duration::from_secs(5 * 60)
+}
+
+const fn default_prove() -> Duration {
+    Duration::from_secs(30 * 60)
+}
+
+const fn default_verify() -> Duration {
+    Duration::from_secs(60)
+}
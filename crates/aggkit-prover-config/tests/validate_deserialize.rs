@@ -1,8 +1,9 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use aggkit_prover_config::ProverConfig as Config;
 use insta::assert_toml_snapshot;
 use pretty_assertions::assert_eq;
+use prover_config::{CpuProverConfig, ProverType};
 
 #[test]
 fn empty_rpcs() {
@@ -43,3 +44,28 @@ fn prover_grpc_max_decoding_message_size() {
 
     assert_eq!(config.grpc.max_decoding_message_size, 100 * 1024 * 1024);
 }
+
+#[test]
+fn network_overrides() {
+    let input = "./tests/fixtures/validate_config/network_overrides.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+    assert_eq!(config.validate(), Ok(()));
+
+    let service = &config.aggchain_proof_service;
+    assert_eq!(service.networks.len(), 2);
+    assert_eq!(
+        service
+            .prover_overrides()
+            .and_then(|overrides| overrides.max_range_size),
+        Some(1000)
+    );
+
+    let builder = service.effective_aggchain_proof_builder();
+    assert_eq!(builder.proving_timeout, Duration::from_secs(2 * 3600));
+    assert!(matches!(
+        builder.primary_prover,
+        ProverType::CpuProver(CpuProverConfig { proving_timeout, .. })
+            if proving_timeout == Duration::from_secs(3600)
+    ));
+}
@@ -0,0 +1,118 @@
+//! Encoding of the payloads exchanged between the services, chosen per
+//! integration point.
+//!
+//! Both ends of an integration point have to use the same codec. Bincode is
+//! the most compact one but depends on the exact layout of the types, which
+//! the golden files of the tests pin down across versions.
+use agglayer_interop::types::bincode;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Codec of the payloads of an integration point.
+#[derive(Serialize, Deserialize, JsonSchema, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Codec {
+    /// Bincode, with the options of SP1 v4.
+    #[default]
+    Bincode,
+    /// CBOR (RFC 8949), with definite lengths and the fields in declaration
+    /// order, so that a payload is always encoded the same way.
+    Cbor,
+    /// JSON, readable by the operators.
+    Json,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CodecError {
+    #[error("Unable to encode or decode the payload with bincode")]
+    Bincode(#[source] anyhow::Error),
+
+    #[error("Unable to encode or decode the payload as CBOR")]
+    Cbor(#[source] anyhow::Error),
+
+    #[error("Unable to encode or decode the payload as JSON")]
+    Json(#[source] serde_json::Error),
+}
+
+impl Codec {
+    pub fn encode<T: Serialize>(&self, value: &T) -> Result<Vec<u8>, CodecError> {
+        match self {
+            Codec::Bincode => bincode::sp1v4()
+                .serialize(value)
+                .map_err(|error| CodecError::Bincode(error.into())),
+            Codec::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(value, &mut bytes)
+                    .map_err(|error| CodecError::Cbor(error.into()))?;
+
+                Ok(bytes)
+            }
+            Codec::Json => serde_json::to_vec(value).map_err(CodecError::Json),
+        }
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, CodecError> {
+        match self {
+            Codec::Bincode => bincode::sp1v4()
+                .deserialize(bytes)
+                .map_err(|error| CodecError::Bincode(error.into())),
+            Codec::Cbor => {
+                ciborium::from_reader(bytes).map_err(|error| CodecError::Cbor(error.into()))
+            }
+            Codec::Json => serde_json::from_slice(bytes).map_err(CodecError::Json),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        envelope::{ProofEnvelope, ProofMode, PROOF_ENVELOPE_VERSION},
+        vkey_hash::VKeyHash,
+    };
+
+    fn envelope() -> ProofEnvelope {
+        ProofEnvelope {
+            version: PROOF_ENVELOPE_VERSION,
+            proof_mode: ProofMode::Compressed,
+            vkey_hash: VKeyHash::from_hash_u32([1, 2, 3, 4, 5, 6, 7, 8]),
+            proof: vec![0xaa; 4].into(),
+            public_values: vec![0xbb; 2].into(),
+            prover_version: "v1".to_string(),
+            created_at: 1_700_000_000,
+        }
+    }
+
+    /// Reads a golden file, one field of the payload per line.
+    fn golden(hex: &str) -> Vec<u8> {
+        hex::decode(hex.split_whitespace().collect::<String>()).unwrap()
+    }
+
+    #[test]
+    fn every_codec_roundtrips() {
+        let envelope = envelope();
+
+        for codec in [Codec::Bincode, Codec::Cbor, Codec::Json] {
+            let bytes = codec.encode(&envelope).unwrap();
+            assert_eq!(
+                codec.decode::<ProofEnvelope>(&bytes).unwrap(),
+                envelope,
+                "{codec:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bincode_layout_is_stable() {
+        let golden = golden(include_str!(
+            "../tests/wire-format/v2/proof_envelope.bincode.hex"
+        ));
+
+        assert_eq!(Codec::Bincode.encode(&envelope()).unwrap(), golden);
+        assert_eq!(
+            Codec::Bincode.decode::<ProofEnvelope>(&golden).unwrap(),
+            envelope()
+        );
+    }
+}
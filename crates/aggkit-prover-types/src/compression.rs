@@ -0,0 +1,55 @@
+//! Zstd compression of the payloads kept at rest, the proofs and their inputs
+//! being large.
+//!
+//! The compressed payloads are told apart by the zstd magic number, which
+//! none of the codecs starts a payload of the prover with. The compressed and
+//! uncompressed payloads are thus read alike, whatever the configured level,
+//! and compression can be switched on or off without rewriting them.
+use std::{borrow::Cow, io};
+
+/// First bytes of a zstd frame.
+pub const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Levels accepted in the configuration.
+pub const COMPRESSION_LEVELS: std::ops::RangeInclusive<i32> = 1..=22;
+
+/// Compresses the encoded payload with the given level, left as is when
+/// unset.
+pub fn compress(bytes: Vec<u8>, level: Option<i32>) -> io::Result<Vec<u8>> {
+    match level {
+        Some(level) => zstd::bulk::compress(&bytes, level),
+        None => Ok(bytes),
+    }
+}
+
+/// Decompresses the payload if compressed.
+pub fn decompress(bytes: &[u8]) -> io::Result<Cow<'_, [u8]>> {
+    if bytes.starts_with(&ZSTD_MAGIC) {
+        return zstd::stream::decode_all(bytes).map(Cow::Owned);
+    }
+
+    Ok(Cow::Borrowed(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::Codec;
+
+    #[test]
+    fn compressed_and_plain_payloads_are_read_alike() {
+        let payload = vec!["proof".to_string(); 100];
+
+        for codec in [Codec::Bincode, Codec::Cbor, Codec::Json] {
+            let plain = compress(codec.encode(&payload).unwrap(), None).unwrap();
+            let compressed = compress(codec.encode(&payload).unwrap(), Some(3)).unwrap();
+            assert!(!plain.starts_with(&ZSTD_MAGIC), "{codec:?}");
+            assert!(compressed.len() < plain.len(), "{codec:?}");
+
+            for bytes in [plain, compressed] {
+                let decoded: Vec<String> = codec.decode(&decompress(&bytes).unwrap()).unwrap();
+                assert_eq!(decoded, payload, "{codec:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! Typed conversions from the proto primitives, annotated with the path of the
+//! field being converted.
+//!
+//! The proto messages carry fixed-size values as length-unchecked bytes, hence
+//! converting them can fail. Wrapping a field in [`Field`] before converting it
+//! makes the resulting [`FieldError`] point at the faulty field:
+//!
+//! ```ignore
+//! let root: B256 = Field::new("l1_info_tree_root_hash", request.l1_info_tree_root_hash)
+//!     .try_into()?;
+//! ```
+use agglayer_interop::{
+    grpc::v1::{FixedBytes20, FixedBytes32, FixedBytes65},
+    types::{Digest, Signature},
+};
+use alloy_primitives::{Address, B256, U256};
+
+/// Optional proto field along with its path in the request.
+#[derive(Clone, Debug)]
+pub struct Field<T> {
+    path: String,
+    value: Option<T>,
+}
+
+impl<T> Field<T> {
+    pub fn new(path: impl Into<String>, value: Option<T>) -> Self {
+        Self {
+            path: path.into(),
+            value,
+        }
+    }
+
+    /// Field which is always set, such as the repeated fields.
+    pub fn required(path: impl Into<String>, value: T) -> Self {
+        Self::new(path, Some(value))
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn into_value(self) -> Result<(String, T), FieldError> {
+        match self.value {
+            Some(value) => Ok((self.path, value)),
+            None => Err(FieldError {
+                field_path: self.path,
+                kind: FieldErrorKind::Missing,
+            }),
+        }
+    }
+}
+
+/// Error of the conversion of one proto field.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid field {field_path}: {kind}")]
+pub struct FieldError {
+    pub field_path: String,
+    pub kind: FieldErrorKind,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum FieldErrorKind {
+    #[error("missing value")]
+    Missing,
+
+    #[error("expected {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("invalid signature: {0}")]
+    InvalidSignature(String),
+}
+
+fn fixed_bytes<const N: usize>(field_path: String, bytes: &[u8]) -> Result<[u8; N], FieldError> {
+    bytes.try_into().map_err(|_| FieldError {
+        field_path,
+        kind: FieldErrorKind::InvalidLength {
+            expected: N,
+            actual: bytes.len(),
+        },
+    })
+}
+
+impl TryFrom<Field<FixedBytes20>> for Address {
+    type Error = FieldError;
+
+    fn try_from(field: Field<FixedBytes20>) -> Result<Self, Self::Error> {
+        let (path, value) = field.into_value()?;
+
+        fixed_bytes::<20>(path, &value.value).map(Address::from)
+    }
+}
+
+impl TryFrom<Field<FixedBytes32>> for B256 {
+    type Error = FieldError;
+
+    fn try_from(field: Field<FixedBytes32>) -> Result<Self, Self::Error> {
+        let (path, value) = field.into_value()?;
+
+        fixed_bytes::<32>(path, &value.value).map(B256::from)
+    }
+}
+
+impl TryFrom<Field<FixedBytes32>> for Digest {
+    type Error = FieldError;
+
+    fn try_from(field: Field<FixedBytes32>) -> Result<Self, Self::Error> {
+        let (path, value) = field.into_value()?;
+
+        fixed_bytes::<32>(path, &value.value).map(Digest)
+    }
+}
+
+/// Big-endian encoded 256-bit integer.
+impl TryFrom<Field<FixedBytes32>> for U256 {
+    type Error = FieldError;
+
+    fn try_from(field: Field<FixedBytes32>) -> Result<Self, Self::Error> {
+        let (path, value) = field.into_value()?;
+
+        fixed_bytes::<32>(path, &value.value).map(U256::from_be_bytes)
+    }
+}
+
+/// Siblings of a merkle proof of depth 32.
+impl TryFrom<Field<Vec<FixedBytes32>>> for [Digest; 32] {
+    type Error = FieldError;
+
+    fn try_from(field: Field<Vec<FixedBytes32>>) -> Result<Self, Self::Error> {
+        let (path, hashes) = field.into_value()?;
+
+        let actual = hashes.len();
+        let hashes: [FixedBytes32; 32] = hashes.try_into().map_err(|_| FieldError {
+            field_path: path.clone(),
+            kind: FieldErrorKind::InvalidLength {
+                expected: 32,
+                actual,
+            },
+        })?;
+
+        let mut digests = [Digest::default(); 32];
+        for (i, (digest, hash)) in digests.iter_mut().zip(hashes).enumerate() {
+            *digest = Field::required(format!("{path}.{i}"), hash).try_into()?;
+        }
+
+        Ok(digests)
+    }
+}
+
+impl TryFrom<Field<FixedBytes65>> for Signature {
+    type Error = FieldError;
+
+    fn try_from(field: Field<FixedBytes65>) -> Result<Self, Self::Error> {
+        let (path, value) = field.into_value()?;
+        let bytes = fixed_bytes::<65>(path.clone(), &value.value)?;
+
+        Signature::try_from(bytes.as_slice()).map_err(|error| FieldError {
+            field_path: path,
+            kind: FieldErrorKind::InvalidSignature(error.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::bytes::Bytes;
+
+    use super::*;
+
+    fn bytes32(value: &[u8]) -> FixedBytes32 {
+        FixedBytes32 {
+            value: Bytes::copy_from_slice(value),
+        }
+    }
+
+    #[test]
+    fn fixed_size_values() {
+        let mut be = [0u8; 32];
+        be[31] = 42;
+
+        let value: U256 = Field::required("amount", bytes32(&be)).try_into().unwrap();
+        assert_eq!(value, U256::from(42u64));
+
+        let value: B256 = Field::required("hash", bytes32(&be)).try_into().unwrap();
+        assert_eq!(value, B256::from(be));
+
+        let address: Address = Field::required(
+            "address",
+            FixedBytes20 {
+                value: Bytes::copy_from_slice(&[1u8; 20]),
+            },
+        )
+        .try_into()
+        .unwrap();
+        assert_eq!(address, Address::repeat_byte(1));
+    }
+
+    #[test]
+    fn errors_point_at_the_field() {
+        let error = B256::try_from(Field::<FixedBytes32>::new("root", None)).unwrap_err();
+        assert_eq!(
+            error,
+            FieldError {
+                field_path: "root".to_string(),
+                kind: FieldErrorKind::Missing,
+            }
+        );
+
+        let error = B256::try_from(Field::required("root", bytes32(&[0u8; 31]))).unwrap_err();
+        assert_eq!(
+            error.kind,
+            FieldErrorKind::InvalidLength {
+                expected: 32,
+                actual: 31
+            }
+        );
+
+        let mut siblings = vec![bytes32(&[0u8; 32]); 32];
+        siblings[7] = bytes32(&[0u8; 33]);
+        let error =
+            <[Digest; 32]>::try_from(Field::required("proof.siblings", siblings)).unwrap_err();
+        assert_eq!(error.field_path, "proof.siblings.7");
+
+        let error =
+            <[Digest; 32]>::try_from(Field::required("proof.siblings", vec![])).unwrap_err();
+        assert_eq!(
+            error.kind,
+            FieldErrorKind::InvalidLength {
+                expected: 32,
+                actual: 0
+            }
+        );
+    }
+}
@@ -1 +1,3 @@
+pub mod field;
 pub mod v1;
+pub mod v2;
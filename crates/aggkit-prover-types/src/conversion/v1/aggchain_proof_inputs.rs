@@ -1,4 +1,5 @@
 use aggchain_proof_types::AggchainProofInputs;
+use agglayer_interop::types::Digest;
 
 use crate::{error::AggchainProofRequestError as Error, v1};
 
@@ -63,6 +64,14 @@ impl TryFrom<v1::GenerateAggchainProofRequest> for AggchainProofInputs {
                     field_path: "imported_bridge_exits".to_string(),
                     source: anyhow::Error::from(error),
                 })?,
+            requested_vkey_hash: value
+                .requested_vkey_hash
+                .map(Digest::try_from)
+                .transpose()
+                .map_err(|error| Error::InvalidDigest {
+                    field_path: "requested_vkey_hash".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
         })
     }
 }
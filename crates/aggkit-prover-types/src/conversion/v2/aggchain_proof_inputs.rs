@@ -0,0 +1,70 @@
+use aggchain_proof_types::{inserted_ger::InsertedGerWithBlockNumber, AggchainProofInputs};
+use agglayer_interop::types::Digest;
+
+use crate::{conversion::field::Field, error::AggchainProofRequestError as Error, v2};
+
+impl TryFrom<v2::GenerateAggchainProofRequest> for AggchainProofInputs {
+    type Error = Error;
+
+    fn try_from(value: v2::GenerateAggchainProofRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            last_proven_block: value.last_proven_block,
+            requested_end_block: value.requested_end_block,
+            l1_info_tree_root_hash: Field::new(
+                "l1_info_tree_root_hash",
+                value.l1_info_tree_root_hash,
+            )
+            .try_into()?,
+            l1_info_tree_leaf: value
+                .l1_info_tree_leaf
+                .ok_or(Error::MissingL1InfoTreeLeaf {
+                    field_path: "l1_info_tree_leaf".to_string(),
+                })?
+                .try_into()
+                .map_err(|error| Error::InvalidL1InfoTreeLeaf {
+                    field_path: "l1_info_tree_leaf".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
+            l1_info_tree_merkle_proof: value
+                .l1_info_tree_merkle_proof
+                .ok_or_else(|| Error::MissingL1InfoTreeMerkleProof {
+                    field_path: "l1_info_tree_merkle_proof".to_string(),
+                })?
+                .try_into()
+                .map_err(|source| Error::InvalidL1InfoTreeMerkleProof {
+                    field_path: "l1_info_tree_merkle_proof".to_string(),
+                    source: anyhow::Error::from(source),
+                })?,
+            // The v1 map is keyed by the GER, which v2 carries as a typed digest.
+            ger_leaves: value
+                .inserted_gers
+                .into_iter()
+                .enumerate()
+                .map(|(i, inserted_ger)| {
+                    let (ger, inserted_ger): (Digest, InsertedGerWithBlockNumber) =
+                        inserted_ger.try_into().map_err(|error: Error| {
+                            Error::InvalidInsertedGerWithBlockNumberConversion {
+                                field_path: format!("inserted_gers.{i}.{}", error.field_path()),
+                                source: anyhow::Error::from(error),
+                            }
+                        })?;
+
+                    Ok((ger.to_string(), inserted_ger))
+                })
+                .collect::<Result<_, _>>()?,
+            imported_bridge_exits: value
+                .imported_bridge_exits
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()
+                .map_err(|error| Error::InvalidImportedBridgeExit {
+                    field_path: "imported_bridge_exits".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
+            requested_vkey_hash: value
+                .requested_vkey_hash
+                .map(|hash| Field::required("requested_vkey_hash", hash).try_into())
+                .transpose()?,
+        })
+    }
+}
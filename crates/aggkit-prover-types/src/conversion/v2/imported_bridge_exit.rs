@@ -0,0 +1,28 @@
+use aggchain_proof_types::imported_bridge_exit::{
+    BridgeExitHash, ImportedBridgeExitWithBlockNumber,
+};
+
+use crate::{conversion::field::Field, error::AggchainProofRequestError as Error, v2};
+
+impl TryFrom<v2::ImportedBridgeExitWithBlockNumber> for ImportedBridgeExitWithBlockNumber {
+    type Error = Error;
+
+    fn try_from(value: v2::ImportedBridgeExitWithBlockNumber) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_number: value.block_number,
+            global_index: value
+                .global_index
+                .ok_or_else(|| Error::MissingGlobalIndex {
+                    field_path: "global_index".to_string(),
+                })?
+                .try_into()
+                .map_err(|error| Error::InvalidDigest {
+                    field_path: "global_index".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
+            bridge_exit_hash: BridgeExitHash(
+                Field::new("bridge_exit_hash", value.bridge_exit_hash).try_into()?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,50 @@
+use aggchain_proof_types::inserted_ger::{InsertedGer, InsertedGerWithBlockNumber};
+use agglayer_interop::types::Digest;
+
+use crate::{conversion::field::Field, error::AggchainProofRequestError as Error, v2};
+
+impl TryFrom<v2::ProvenInsertedGer> for (Digest, InsertedGerWithBlockNumber) {
+    type Error = Error;
+
+    fn try_from(value: v2::ProvenInsertedGer) -> Result<Self, Self::Error> {
+        let ger: Digest = Field::new("ger", value.ger).try_into()?;
+
+        let inserted_ger = InsertedGer {
+            proof_ger_l1root: value
+                .proof_ger_l1root
+                .ok_or_else(|| Error::MissingInsertedGer {
+                    field_path: "proof_ger_l1root".to_string(),
+                })?
+                .try_into()
+                .map_err(|error| Error::InvalidInsertedGer {
+                    field_path: "proof_ger_l1root".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
+            l1_leaf: value
+                .l1_leaf
+                .ok_or_else(|| Error::MissingL1InfoTreeLeaf {
+                    field_path: "l1_leaf".to_string(),
+                })?
+                .try_into()
+                .map_err(|error| Error::InvalidL1InfoTreeLeaf {
+                    field_path: "l1_leaf".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
+        };
+
+        if inserted_ger.l1_leaf.inner.global_exit_root != ger {
+            return Err(Error::MismatchInsertedGer {
+                field_path: "ger".to_string(),
+            });
+        }
+
+        Ok((
+            ger,
+            InsertedGerWithBlockNumber {
+                block_number: value.block_number,
+                block_index: value.block_index,
+                inserted_ger,
+            },
+        ))
+    }
+}
@@ -0,0 +1,8 @@
+//! Conversions from the `aggkit.prover.v2` messages to the prover types.
+//!
+//! The conversion context is shared with v1, see [`super::v1::context`].
+
+mod aggchain_proof_inputs;
+mod imported_bridge_exit;
+mod inserted_ger;
+mod optimistic_aggchain_proof_types;
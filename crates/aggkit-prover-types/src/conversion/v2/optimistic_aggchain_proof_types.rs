@@ -0,0 +1,28 @@
+use aggchain_proof_types::OptimisticAggchainProofInputs;
+
+use crate::{conversion::field::Field, error::AggchainProofRequestError as Error, v2};
+
+impl TryFrom<v2::GenerateOptimisticAggchainProofRequest> for OptimisticAggchainProofInputs {
+    type Error = Error;
+
+    fn try_from(value: v2::GenerateOptimisticAggchainProofRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            network_id: value.network_id,
+            signature_optimistic_mode: Field::new(
+                "optimistic_mode_signature",
+                value.optimistic_mode_signature,
+            )
+            .try_into()?,
+            aggchain_proof_inputs: value
+                .aggchain_proof_request
+                .ok_or_else(|| Error::MissingAggchainProofRequest {
+                    field_path: "aggchain_proof_request".to_string(),
+                })?
+                .try_into()
+                .map_err(|error| Error::InvalidAggchainProofRequest {
+                    field_path: "aggchain_proof_request".to_string(),
+                    source: anyhow::Error::from(error),
+                })?,
+        })
+    }
+}
@@ -0,0 +1,221 @@
+//! Self-describing container for the generated proofs.
+//!
+//! The envelope carries, next to the proof bytes, what is needed to tell which
+//! program produced the proof and how, so that downstream consumers can check
+//! it before handing it to a verifier.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use agglayer_interop::types::bincode;
+use alloy_primitives::Bytes;
+use serde::{Deserialize, Serialize};
+
+use crate::vkey_hash::VKeyHash;
+
+/// Current version of the envelope format.
+///
+/// The version 1 recorded whether the aggchain proof was optimistic instead
+/// of the SP1 proof mode.
+pub const PROOF_ENVELOPE_VERSION: u8 = 2;
+
+/// SP1 proof mode of the proof, telling how to verify it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProofMode {
+    /// Proof of every shard of the execution.
+    Core,
+    /// Constant size STARK proof, verifiable within SP1 for aggregation.
+    Compressed,
+    /// Groth16 SNARK proof, verifiable on-chain.
+    Groth16,
+    /// Plonk SNARK proof, verifiable on-chain.
+    Plonk,
+}
+
+impl From<ProofMode> for crate::v1::Sp1ProofMode {
+    fn from(mode: ProofMode) -> Self {
+        match mode {
+            ProofMode::Core => Self::Core,
+            ProofMode::Compressed => Self::Compressed,
+            ProofMode::Groth16 => Self::Groth16,
+            ProofMode::Plonk => Self::Plonk,
+        }
+    }
+}
+
+impl From<ProofMode> for crate::v2::Sp1ProofMode {
+    fn from(mode: ProofMode) -> Self {
+        match mode {
+            ProofMode::Core => Self::Core,
+            ProofMode::Compressed => Self::Compressed,
+            ProofMode::Groth16 => Self::Groth16,
+            ProofMode::Plonk => Self::Plonk,
+        }
+    }
+}
+
+#[cfg(feature = "sp1")]
+impl From<ProofMode> for sp1_sdk::SP1ProofMode {
+    fn from(mode: ProofMode) -> Self {
+        match mode {
+            ProofMode::Core => Self::Core,
+            ProofMode::Compressed => Self::Compressed,
+            ProofMode::Groth16 => Self::Groth16,
+            ProofMode::Plonk => Self::Plonk,
+        }
+    }
+}
+
+#[cfg(feature = "sp1")]
+impl From<sp1_sdk::SP1ProofMode> for ProofMode {
+    fn from(mode: sp1_sdk::SP1ProofMode) -> Self {
+        match mode {
+            sp1_sdk::SP1ProofMode::Core => Self::Core,
+            sp1_sdk::SP1ProofMode::Compressed => Self::Compressed,
+            sp1_sdk::SP1ProofMode::Groth16 => Self::Groth16,
+            sp1_sdk::SP1ProofMode::Plonk => Self::Plonk,
+        }
+    }
+}
+
+/// Proof along with the information describing how it got produced.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProofEnvelope {
+    /// Version of the envelope format.
+    pub version: u8,
+    pub proof_mode: ProofMode,
+    /// Hash of the verifying key of the program which produced the proof.
+    pub vkey_hash: VKeyHash,
+    pub proof: Bytes,
+    pub public_values: Bytes,
+    /// Version of the prover which produced the proof.
+    pub prover_version: String,
+    /// Creation time of the proof, in seconds since the UNIX epoch.
+    pub created_at: u64,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ProofEnvelopeError {
+    #[error("Unable to encode or decode the proof envelope with bincode")]
+    Bincode(#[source] anyhow::Error),
+
+    #[error("Unable to encode or decode the proof envelope as JSON")]
+    Json(#[source] serde_json::Error),
+
+    #[error("Unsupported proof envelope version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("Proof produced by the program {got:?} instead of {expected:?}")]
+    VKeyHashMismatch { expected: VKeyHash, got: VKeyHash },
+}
+
+impl ProofEnvelope {
+    /// Wraps a proof freshly generated by this prover.
+    pub fn new(
+        proof_mode: ProofMode,
+        vkey_hash: VKeyHash,
+        proof: impl Into<Bytes>,
+        public_values: impl Into<Bytes>,
+        prover_version: impl Into<String>,
+    ) -> Self {
+        Self {
+            version: PROOF_ENVELOPE_VERSION,
+            proof_mode,
+            vkey_hash,
+            proof: proof.into(),
+            public_values: public_values.into(),
+            prover_version: prover_version.into(),
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+        }
+    }
+
+    /// Checks that the proof has been produced by the expected program.
+    pub fn check_vkey_hash(&self, expected: VKeyHash) -> Result<(), ProofEnvelopeError> {
+        if self.vkey_hash != expected {
+            return Err(ProofEnvelopeError::VKeyHashMismatch {
+                expected,
+                got: self.vkey_hash,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn to_bincode(&self) -> Result<Vec<u8>, ProofEnvelopeError> {
+        bincode::sp1v4()
+            .serialize(self)
+            .map_err(|error| ProofEnvelopeError::Bincode(error.into()))
+    }
+
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, ProofEnvelopeError> {
+        bincode::sp1v4()
+            .deserialize::<Self>(bytes)
+            .map_err(|error| ProofEnvelopeError::Bincode(error.into()))?
+            .checked()
+    }
+
+    pub fn to_json(&self) -> Result<String, ProofEnvelopeError> {
+        serde_json::to_string(self).map_err(ProofEnvelopeError::Json)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ProofEnvelopeError> {
+        serde_json::from_str::<Self>(json)
+            .map_err(ProofEnvelopeError::Json)?
+            .checked()
+    }
+
+    fn checked(self) -> Result<Self, ProofEnvelopeError> {
+        if self.version != PROOF_ENVELOPE_VERSION {
+            return Err(ProofEnvelopeError::UnsupportedVersion(self.version));
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope() -> ProofEnvelope {
+        ProofEnvelope::new(
+            ProofMode::Compressed,
+            VKeyHash::from_hash_u32([1, 2, 3, 4, 5, 6, 7, 8]),
+            vec![0xaa; 16],
+            vec![0xbb; 8],
+            "aggkit-prover v1.0.0",
+        )
+    }
+
+    #[test]
+    fn roundtrips() {
+        let envelope = envelope();
+
+        let bytes = envelope.to_bincode().unwrap();
+        assert_eq!(ProofEnvelope::from_bincode(&bytes).unwrap(), envelope);
+
+        let json = envelope.to_json().unwrap();
+        assert_eq!(ProofEnvelope::from_json(&json).unwrap(), envelope);
+    }
+
+    #[test]
+    fn checks() {
+        let envelope = envelope();
+        envelope.check_vkey_hash(envelope.vkey_hash).unwrap();
+        assert!(matches!(
+            envelope.check_vkey_hash(VKeyHash::from_hash_u32([0; 8])),
+            Err(ProofEnvelopeError::VKeyHashMismatch { .. })
+        ));
+
+        let future = ProofEnvelope {
+            version: PROOF_ENVELOPE_VERSION + 1,
+            ..envelope
+        };
+        assert!(matches!(
+            ProofEnvelope::from_json(&future.to_json().unwrap()),
+            Err(ProofEnvelopeError::UnsupportedVersion(_))
+        ));
+    }
+}
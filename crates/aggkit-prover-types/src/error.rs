@@ -1,3 +1,5 @@
+use crate::conversion::field::FieldError;
+
 /// Represents the errors that could happen with the grpc request
 /// to generate the aggchain proof
 #[derive(thiserror::Error, Debug)]
@@ -65,6 +67,8 @@ pub enum AggchainProofRequestError {
         field_path: String,
         source: anyhow::Error,
     },
+    #[error("Inserted ger doesn't match the ger of its l1 info tree leaf")]
+    MismatchInsertedGer { field_path: String },
     #[error("Invalid optimistic mode signature")]
     InvalidOptimisticModeSignature {
         field_path: String,
@@ -80,6 +84,9 @@ pub enum AggchainProofRequestError {
     },
     #[error("Missing aggchain-proof request")]
     MissingAggchainProofRequest { field_path: String },
+
+    #[error(transparent)]
+    InvalidField(#[from] FieldError),
 }
 
 impl AggchainProofRequestError {
@@ -101,6 +108,7 @@ impl AggchainProofRequestError {
             | AggchainProofRequestError::MissingL1InfoTreeRootHash { field_path }
             | AggchainProofRequestError::MissingInsertedGer { field_path }
             | AggchainProofRequestError::InvalidInsertedGer { field_path, .. }
+            | AggchainProofRequestError::MismatchInsertedGer { field_path }
             | AggchainProofRequestError::MissingImportedBridgeExit { field_path }
             | AggchainProofRequestError::MissingInclusionProof { field_path }
             | AggchainProofRequestError::InvalidDigest { field_path, .. }
@@ -110,6 +118,7 @@ impl AggchainProofRequestError {
             | AggchainProofRequestError::InvalidOptimisticModeSignature { field_path, .. } => {
                 field_path
             }
+            AggchainProofRequestError::InvalidField(error) => &error.field_path,
         }
     }
 }
@@ -24,6 +24,10 @@ pub struct GenerateAggchainProofRequest {
     /// bridge exits
     #[prost(message, repeated, tag="7")]
     pub imported_bridge_exits: ::prost::alloc::vec::Vec<ImportedBridgeExitWithBlockNumber>,
+    /// Hash of the verifying key of the aggchain proof program to prove with,
+    /// pinning the program during an upgrade. The current program when unset.
+    #[prost(message, optional, tag="8")]
+    pub requested_vkey_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
 }
 /// The request message for generating optimistic aggchain proof.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -33,6 +37,10 @@ pub struct GenerateOptimisticAggchainProofRequest {
     /// Signature in the "OptimisticMode" case.
     #[prost(message, optional, tag="2")]
     pub optimistic_mode_signature: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes65>,
+    /// Network the optimistic aggchain proof is requested for, checked against
+    /// the optimistic mode policy of the prover.
+    #[prost(uint32, tag="3")]
+    pub network_id: u32,
 }
 /// The aggchain proof response message.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -52,6 +60,17 @@ pub struct GenerateAggchainProofResponse {
     /// Custom chain data.
     #[prost(bytes="bytes", tag="5")]
     pub custom_chain_data: ::prost::bytes::Bytes,
+    /// Envelope of the aggchain proof encoded with bincode, telling the program
+    /// and the SP1 proof mode which produced it.
+    #[prost(bytes="bytes", tag="6")]
+    pub proof_envelope: ::prost::bytes::Bytes,
+    /// SP1 proof mode of the aggchain proof.
+    #[prost(enumeration="Sp1ProofMode", tag="7")]
+    pub sp1_proof_mode: i32,
+    /// End block requested, after the end block of the proof when the proven
+    /// range was shortened.
+    #[prost(uint64, tag="8")]
+    pub requested_end_block: u64,
 }
 /// The optimistic aggchain proof response message.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -65,6 +84,17 @@ pub struct GenerateOptimisticAggchainProofResponse {
     /// Custom chain data.
     #[prost(bytes="bytes", tag="3")]
     pub custom_chain_data: ::prost::bytes::Bytes,
+    /// Envelope of the aggchain proof encoded with bincode, telling the program
+    /// and the SP1 proof mode which produced it.
+    #[prost(bytes="bytes", tag="4")]
+    pub proof_envelope: ::prost::bytes::Bytes,
+    /// SP1 proof mode of the aggchain proof.
+    #[prost(enumeration="Sp1ProofMode", tag="5")]
+    pub sp1_proof_mode: i32,
+    /// End block requested, after the end block of the proof when the proven
+    /// range was shortened.
+    #[prost(uint64, tag="6")]
+    pub requested_end_block: u64,
 }
 /// Imported bridge exit with block number.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -102,9 +132,50 @@ pub struct ProvenInsertedGer {
     #[prost(message, optional, tag="2")]
     pub l1_leaf: ::core::option::Option<::agglayer_interop::grpc::v1::L1InfoTreeLeafWithContext>,
 }
+/// SP1 proof mode of a generated proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Sp1ProofMode {
+    /// Unspecified proof mode.
+    Unspecified = 0,
+    /// Proof of every shard of the execution.
+    Core = 1,
+    /// Constant size STARK proof, verifiable within SP1 for aggregation.
+    Compressed = 2,
+    /// Groth16 SNARK proof, verifiable on-chain.
+    Groth16 = 3,
+    /// Plonk SNARK proof, verifiable on-chain.
+    Plonk = 4,
+}
+impl Sp1ProofMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SP1_PROOF_MODE_UNSPECIFIED",
+            Self::Core => "SP1_PROOF_MODE_CORE",
+            Self::Compressed => "SP1_PROOF_MODE_COMPRESSED",
+            Self::Groth16 => "SP1_PROOF_MODE_GROTH16",
+            Self::Plonk => "SP1_PROOF_MODE_PLONK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SP1_PROOF_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SP1_PROOF_MODE_CORE" => Some(Self::Core),
+            "SP1_PROOF_MODE_COMPRESSED" => Some(Self::Compressed),
+            "SP1_PROOF_MODE_GROTH16" => Some(Self::Groth16),
+            "SP1_PROOF_MODE_PLONK" => Some(Self::Plonk),
+            _ => None,
+        }
+    }
+}
 /// Encoded file descriptor set for the `aggkit.prover.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xda, 0x2e, 0x0a, 0x30, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2f, 0x70, 0x72, 0x6f, 0x76,
+    0x0a, 0x80, 0x41, 0x0a, 0x30, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2f, 0x70, 0x72, 0x6f, 0x76,
     0x65, 0x72, 0x2f, 0x76, 0x31, 0x2f, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70,
     0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
     0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72,
@@ -118,7 +189,7 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x2f, 0x76, 0x31, 0x2f, 0x63, 0x6c, 0x61, 0x69, 0x6d, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a,
     0x2c, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
     0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x6d, 0x65, 0x72, 0x6b, 0x6c,
-    0x65, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xd3, 0x05,
+    0x65, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xac, 0x06,
     0x0a, 0x1c, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
     0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x2a,
     0x0a, 0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c,
@@ -157,327 +228,474 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x74, 0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x57, 0x69, 0x74,
     0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x13, 0x69, 0x6d,
     0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74,
-    0x73, 0x1a, 0x70, 0x0a, 0x0e, 0x47, 0x65, 0x72, 0x4c, 0x65, 0x61, 0x76, 0x65, 0x73, 0x45, 0x6e,
-    0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x48, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x32, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72,
-    0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e,
-    0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f,
-    0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a,
-    0x02, 0x38, 0x01, 0x22, 0xf3, 0x01, 0x0a, 0x26, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
-    0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
-    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x64,
-    0x0a, 0x16, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66,
-    0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e,
-    0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
-    0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
-    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x52, 0x14,
-    0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71,
-    0x75, 0x65, 0x73, 0x74, 0x12, 0x63, 0x0a, 0x19, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74,
-    0x69, 0x63, 0x5f, 0x6d, 0x6f, 0x64, 0x65, 0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
-    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
-    0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
-    0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x36, 0x35,
-    0x52, 0x17, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65,
-    0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0xbf, 0x02, 0x0a, 0x1d, 0x47, 0x65,
-    0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72,
-    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e, 0x61,
-    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69,
-    0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
-    0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0d, 0x61,
-    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2a, 0x0a, 0x11,
-    0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63,
-    0x6b, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0f, 0x6c, 0x61, 0x73, 0x74, 0x50, 0x72, 0x6f,
-    0x76, 0x65, 0x6e, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x1b, 0x0a, 0x09, 0x65, 0x6e, 0x64, 0x5f,
-    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x65, 0x6e, 0x64,
-    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x58, 0x0a, 0x14, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65,
-    0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69,
-    0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
-    0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x6c, 0x6f,
-    0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12,
-    0x2a, 0x0a, 0x11, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f,
-    0x64, 0x61, 0x74, 0x61, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74,
-    0x6f, 0x6d, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x22, 0x80, 0x02, 0x0a, 0x27,
-    0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74,
-    0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e, 0x61, 0x67, 0x67, 0x63, 0x68,
-    0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
-    0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
-    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63,
-    0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0d, 0x61, 0x67, 0x67, 0x63, 0x68,
-    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x58, 0x0a, 0x14, 0x6c, 0x6f, 0x63, 0x61,
-    0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68,
-    0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
-    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
-    0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52,
-    0x11, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x52, 0x6f, 0x6f, 0x74, 0x48, 0x61,
-    0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x5f, 0x63, 0x68, 0x61,
-    0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63,
-    0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x22, 0xe5,
-    0x01, 0x0a, 0x21, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67,
-    0x65, 0x45, 0x78, 0x69, 0x74, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75,
-    0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75,
-    0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63,
-    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x4a, 0x0a, 0x0c, 0x67, 0x6c, 0x6f, 0x62, 0x61,
-    0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e,
-    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
-    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42,
-    0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0b, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x49, 0x6e,
-    0x64, 0x65, 0x78, 0x12, 0x51, 0x0a, 0x10, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x5f, 0x65, 0x78,
-    0x69, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e,
-    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
-    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42,
-    0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78,
-    0x69, 0x74, 0x48, 0x61, 0x73, 0x68, 0x22, 0xbb, 0x01, 0x0a, 0x20, 0x50, 0x72, 0x6f, 0x76, 0x65,
-    0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x57, 0x69, 0x74, 0x68,
-    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62,
-    0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x53,
-    0x0a, 0x13, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65,
-    0x64, 0x5f, 0x67, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x67,
-    0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50,
-    0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52,
-    0x52, 0x11, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64,
-    0x47, 0x65, 0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x69, 0x6e, 0x64,
-    0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x49,
-    0x6e, 0x64, 0x65, 0x78, 0x22, 0xb4, 0x01, 0x0a, 0x11, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49,
-    0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x12, 0x50, 0x0a, 0x10, 0x70, 0x72,
-    0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x72, 0x5f, 0x6c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
-    0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
-    0x2e, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0e, 0x70, 0x72,
-    0x6f, 0x6f, 0x66, 0x47, 0x65, 0x72, 0x4c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x12, 0x4d, 0x0a, 0x07,
-    0x6c, 0x31, 0x5f, 0x6c, 0x65, 0x61, 0x66, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x34, 0x2e,
-    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70,
-    0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x31, 0x49, 0x6e, 0x66, 0x6f,
-    0x54, 0x72, 0x65, 0x65, 0x4c, 0x65, 0x61, 0x66, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x74,
-    0x65, 0x78, 0x74, 0x52, 0x06, 0x6c, 0x31, 0x4c, 0x65, 0x61, 0x66, 0x32, 0xa9, 0x02, 0x0a, 0x14,
-    0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x65, 0x72,
-    0x76, 0x69, 0x63, 0x65, 0x12, 0x78, 0x0a, 0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
-    0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2e, 0x2e,
+    0x73, 0x12, 0x57, 0x0a, 0x13, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x76,
+    0x6b, 0x65, 0x79, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27,
+    0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f,
+    0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64,
+    0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x65, 0x64, 0x56, 0x6b, 0x65, 0x79, 0x48, 0x61, 0x73, 0x68, 0x1a, 0x70, 0x0a, 0x0e, 0x47, 0x65,
+    0x72, 0x4c, 0x65, 0x61, 0x76, 0x65, 0x73, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03,
+    0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x48,
+    0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x32, 0x2e,
     0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
-    0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f, 0x2e,
+    0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47,
+    0x45, 0x52, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65,
+    0x72, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x3a, 0x02, 0x38, 0x01, 0x22, 0x92, 0x02, 0x0a,
+    0x26, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73,
+    0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x64, 0x0a, 0x16, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74,
+    0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72,
+    0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x52, 0x14, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x63, 0x0a,
+    0x19, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x5f, 0x6d, 0x6f, 0x64, 0x65,
+    0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
+    0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x36, 0x35, 0x52, 0x17, 0x6f, 0x70, 0x74, 0x69, 0x6d,
+    0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f, 0x69, 0x64,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x49,
+    0x64, 0x22, 0xdc, 0x03, 0x0a, 0x1d, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67,
+    0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0d, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2a, 0x0a, 0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x0f, 0x6c, 0x61, 0x73, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x42, 0x6c, 0x6f, 0x63, 0x6b,
+    0x12, 0x1b, 0x0a, 0x09, 0x65, 0x6e, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x08, 0x65, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x58, 0x0a,
+    0x14, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74,
+    0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74,
+    0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x52,
+    0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63, 0x75, 0x73, 0x74, 0x6f,
+    0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x44,
+    0x61, 0x74, 0x61, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x65, 0x6e, 0x76,
+    0x65, 0x6c, 0x6f, 0x70, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x44, 0x0a, 0x0e, 0x73, 0x70,
+    0x31, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x6d, 0x6f, 0x64, 0x65, 0x18, 0x07, 0x20, 0x01,
+    0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f,
+    0x64, 0x65, 0x52, 0x0c, 0x73, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65,
+    0x12, 0x2e, 0x0a, 0x13, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x65, 0x6e,
+    0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x45, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b,
+    0x22, 0x9d, 0x03, 0x0a, 0x27, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74,
+    0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x4f, 0x0a, 0x0e,
+    0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
+    0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31,
+    0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0d,
+    0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x58, 0x0a,
+    0x14, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f, 0x74,
+    0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67,
+    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74,
+    0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74, 0x52,
+    0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63, 0x75, 0x73, 0x74, 0x6f,
+    0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x44,
+    0x61, 0x74, 0x61, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x65, 0x6e, 0x76,
+    0x65, 0x6c, 0x6f, 0x70, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x44, 0x0a, 0x0e, 0x73, 0x70,
+    0x31, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x6d, 0x6f, 0x64, 0x65, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f,
+    0x64, 0x65, 0x52, 0x0c, 0x73, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65,
+    0x12, 0x2e, 0x0a, 0x13, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x65, 0x6e,
+    0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x45, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b,
+    0x22, 0xe5, 0x01, 0x0a, 0x21, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72, 0x69,
+    0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b,
+    0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x4a, 0x0a, 0x0c, 0x67, 0x6c, 0x6f,
+    0x62, 0x61, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65,
+    0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0b, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
+    0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x51, 0x0a, 0x10, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x5f,
+    0x65, 0x78, 0x69, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65,
+    0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65,
+    0x45, 0x78, 0x69, 0x74, 0x48, 0x61, 0x73, 0x68, 0x22, 0xbb, 0x01, 0x0a, 0x20, 0x50, 0x72, 0x6f,
+    0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x57, 0x69,
+    0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a,
+    0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x12, 0x53, 0x0a, 0x13, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x69, 0x6e, 0x73, 0x65, 0x72,
+    0x74, 0x65, 0x64, 0x5f, 0x67, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e,
     0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
-    0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x96,
-    0x01, 0x0a, 0x1f, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d,
-    0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f,
-    0x6f, 0x66, 0x12, 0x38, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76,
-    0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70,
-    0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
-    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x39, 0x2e, 0x61,
-    0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e,
-    0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74,
-    0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x96, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d, 0x2e,
+    0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47,
+    0x45, 0x52, 0x52, 0x11, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74,
+    0x65, 0x64, 0x47, 0x65, 0x72, 0x12, 0x1f, 0x0a, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x69,
+    0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x22, 0xb4, 0x01, 0x0a, 0x11, 0x50, 0x72, 0x6f, 0x76, 0x65,
+    0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x45, 0x52, 0x12, 0x50, 0x0a, 0x10,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x72, 0x5f, 0x6c, 0x31, 0x72, 0x6f, 0x6f, 0x74,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0e,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65, 0x72, 0x4c, 0x31, 0x72, 0x6f, 0x6f, 0x74, 0x12, 0x4d,
+    0x0a, 0x07, 0x6c, 0x31, 0x5f, 0x6c, 0x65, 0x61, 0x66, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x34, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x31, 0x49, 0x6e,
+    0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x4c, 0x65, 0x61, 0x66, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f,
+    0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x06, 0x6c, 0x31, 0x4c, 0x65, 0x61, 0x66, 0x2a, 0x9c, 0x01,
+    0x0a, 0x0c, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x1e,
+    0x0a, 0x1a, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45,
+    0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x17,
+    0x0a, 0x13, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45,
+    0x5f, 0x43, 0x4f, 0x52, 0x45, 0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x53, 0x50, 0x31, 0x5f, 0x50,
+    0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x43, 0x4f, 0x4d, 0x50, 0x52, 0x45,
+    0x53, 0x53, 0x45, 0x44, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52,
+    0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x47, 0x52, 0x4f, 0x54, 0x48, 0x31, 0x36,
+    0x10, 0x03, 0x12, 0x18, 0x0a, 0x14, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f,
+    0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x50, 0x4c, 0x4f, 0x4e, 0x4b, 0x10, 0x04, 0x32, 0xa9, 0x02, 0x0a,
+    0x14, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x65,
+    0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x78, 0x0a, 0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
+    0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2e,
+    0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f,
+    0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x96, 0x01, 0x0a, 0x1f, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69,
+    0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x12, 0x38, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f,
+    0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x39, 0x2e,
     0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31,
-    0x42, 0x1c, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x47,
-    0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01,
-    0xa2, 0x02, 0x03, 0x41, 0x50, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e,
-    0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b,
-    0x69, 0x74, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c, 0x41,
-    0x67, 0x67, 0x6b, 0x69, 0x74, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0x5c,
-    0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41, 0x67,
-    0x67, 0x6b, 0x69, 0x74, 0x3a, 0x3a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x3a, 0x3a, 0x56, 0x31,
-    0x4a, 0xb2, 0x18, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x6e, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c,
-    0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a,
-    0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01,
-    0x12, 0x03, 0x05, 0x00, 0x2f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06, 0x00, 0x2f,
-    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x07, 0x00, 0x36, 0x0a, 0x34, 0x0a, 0x02, 0x06,
-    0x00, 0x12, 0x04, 0x0a, 0x00, 0x10, 0x01, 0x1a, 0x28, 0x20, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
-    0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67,
-    0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x1c, 0x0a, 0x48, 0x0a,
-    0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x62, 0x1a, 0x3b, 0x20, 0x47, 0x65, 0x6e,
-    0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
-    0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67, 0x69,
-    0x76, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f,
-    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x0c, 0x06, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03,
-    0x0c, 0x1c, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x43,
-    0x60, 0x0a, 0x55, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x0f, 0x02, 0x80, 0x01, 0x1a,
-    0x47, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f,
-    0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61,
-    0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x67,
-    0x69, 0x76, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e,
-    0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x0f, 0x06, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x02, 0x12,
-    0x03, 0x0f, 0x26, 0x4c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0f,
-    0x57, 0x7e, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x13, 0x00, 0x28, 0x01, 0x1a, 0x34,
-    0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73,
-    0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-    0x69, 0x6e, 0x67, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
-    0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x13, 0x08, 0x24,
-    0x0a, 0x48, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x15, 0x02, 0x1f, 0x1a, 0x3b, 0x20,
-    0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20,
-    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68,
-    0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x15, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x15, 0x1d, 0x1e, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02,
-    0x21, 0x1a, 0x3e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x78, 0x20, 0x65, 0x6e, 0x64, 0x20,
-    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
-    0x6f, 0x66, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x09, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x1f, 0x20, 0x0a, 0x28, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x02, 0x44, 0x1a, 0x1b, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e,
-    0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x20, 0x28, 0x68,
-    0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03,
-    0x1b, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x29,
-    0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x42, 0x43, 0x0a,
-    0x20, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1e, 0x02, 0x4c, 0x1a, 0x13, 0x20, 0x4c,
-    0x31, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x35, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1e, 0x36, 0x47, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1e, 0x4a, 0x4b, 0x0a, 0x2d, 0x0a, 0x04, 0x04,
-    0x00, 0x02, 0x04, 0x12, 0x03, 0x21, 0x02, 0x46, 0x1a, 0x20, 0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e,
-    0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x20, 0x28,
-    0x5b, 0x33, 0x32, 0x5d, 0x68, 0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x04, 0x06, 0x12, 0x03, 0x21, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
-    0x01, 0x12, 0x03, 0x21, 0x28, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04, 0x03, 0x12,
-    0x03, 0x21, 0x44, 0x45, 0x0a, 0x77, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03, 0x24, 0x02,
-    0x3f, 0x1a, 0x6a, 0x20, 0x4d, 0x61, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x47,
-    0x45, 0x52, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x67, 0x65,
-    0x72, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x3a, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x47, 0x45, 0x52, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x20, 0x69,
-    0x73, 0x20, 0x61, 0x20, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64,
-    0x65, 0x64, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x47, 0x45, 0x52, 0x20, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x24, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x24, 0x30, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x05, 0x03, 0x12, 0x03, 0x24, 0x3d, 0x3e, 0x0a, 0x1b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x06, 0x12,
-    0x03, 0x27, 0x02, 0x47, 0x1a, 0x0e, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78,
-    0x69, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12, 0x03, 0x27,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x27, 0x0b, 0x2c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x27, 0x2d, 0x42, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x27, 0x45, 0x46, 0x0a, 0x4b, 0x0a, 0x02,
-    0x04, 0x01, 0x12, 0x04, 0x2b, 0x00, 0x2f, 0x01, 0x1a, 0x3f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72,
-    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66,
-    0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x70,
-    0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
-    0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01,
-    0x12, 0x03, 0x2b, 0x08, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x2c,
-    0x02, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x2c, 0x02, 0x1e,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2c, 0x1f, 0x35, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2c, 0x38, 0x39, 0x0a, 0x36, 0x0a, 0x04,
-    0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x47, 0x1a, 0x29, 0x20, 0x53, 0x69, 0x67, 0x6e,
-    0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x22, 0x4f, 0x70,
-    0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x22, 0x20, 0x63, 0x61,
-    0x73, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x2e,
-    0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e, 0x29, 0x42,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x45, 0x46, 0x0a, 0x32,
-    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x32, 0x00, 0x41, 0x01, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65,
+    0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73,
+    0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x96, 0x01, 0x0a, 0x14, 0x63, 0x6f, 0x6d,
+    0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
+    0x31, 0x42, 0x1c, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50,
+    0x01, 0xa2, 0x02, 0x03, 0x41, 0x50, 0x58, 0xaa, 0x02, 0x10, 0x41, 0x67, 0x67, 0x6b, 0x69, 0x74,
+    0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x10, 0x41, 0x67, 0x67,
+    0x6b, 0x69, 0x74, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1c,
+    0x41, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31,
+    0x5c, 0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x12, 0x41,
+    0x67, 0x67, 0x6b, 0x69, 0x74, 0x3a, 0x3a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x3a, 0x3a, 0x56,
+    0x31, 0x4a, 0x87, 0x26, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00, 0x99, 0x01, 0x01, 0x0a, 0x08, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
+    0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x32, 0x0a, 0x09, 0x0a, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x05, 0x00, 0x2f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x02, 0x12, 0x03, 0x06,
+    0x00, 0x2f, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x07, 0x00, 0x36, 0x0a, 0x34, 0x0a,
+    0x02, 0x06, 0x00, 0x12, 0x04, 0x0a, 0x00, 0x10, 0x01, 0x1a, 0x28, 0x20, 0x53, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69,
+    0x6e, 0x67, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f,
+    0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x1c, 0x0a,
+    0x48, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x62, 0x1a, 0x3b, 0x20, 0x47,
+    0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20,
+    0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x0c, 0x06, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x03, 0x0c, 0x1c, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x0c, 0x43, 0x60, 0x0a, 0x55, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x01, 0x12, 0x04, 0x0f, 0x02, 0x80,
+    0x01, 0x1a, 0x47, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x73, 0x20, 0x61, 0x6e,
+    0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61,
+    0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x0f, 0x06, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01,
+    0x02, 0x12, 0x03, 0x0f, 0x26, 0x4c, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x0f, 0x57, 0x7e, 0x0a, 0x40, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x13, 0x00, 0x2c, 0x01,
+    0x1a, 0x34, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72,
+    0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x13,
+    0x08, 0x24, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x15, 0x02, 0x1f, 0x1a,
+    0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x15, 0x1d, 0x1e, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
+    0x18, 0x02, 0x21, 0x1a, 0x3e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x78, 0x20, 0x65, 0x6e,
+    0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65,
+    0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x09, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x1f, 0x20, 0x0a, 0x28, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x02, 0x44, 0x1a, 0x1b, 0x20, 0x4c, 0x31, 0x20,
+    0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x2e, 0x20,
+    0x28, 0x68, 0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06,
+    0x12, 0x03, 0x1b, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03,
+    0x1b, 0x29, 0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x42,
+    0x43, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1e, 0x02, 0x4c, 0x1a, 0x13,
+    0x20, 0x4c, 0x31, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65,
+    0x61, 0x66, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x06, 0x12, 0x03, 0x1e, 0x02,
+    0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1e, 0x36, 0x47, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1e, 0x4a, 0x4b, 0x0a, 0x2d, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x21, 0x02, 0x46, 0x1a, 0x20, 0x20, 0x4c, 0x31, 0x20,
+    0x49, 0x6e, 0x66, 0x6f, 0x20, 0x74, 0x72, 0x65, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e,
+    0x20, 0x28, 0x5b, 0x33, 0x32, 0x5d, 0x68, 0x61, 0x73, 0x68, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x04, 0x06, 0x12, 0x03, 0x21, 0x02, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x21, 0x28, 0x41, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x04,
+    0x03, 0x12, 0x03, 0x21, 0x44, 0x45, 0x0a, 0x77, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x05, 0x12, 0x03,
+    0x24, 0x02, 0x3f, 0x1a, 0x6a, 0x20, 0x4d, 0x61, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x47, 0x45, 0x52, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20,
+    0x67, 0x65, 0x72, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x2e, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x3a, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x47, 0x45, 0x52, 0x20, 0x28, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29,
+    0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x62, 0x61, 0x73, 0x65, 0x36, 0x34, 0x20, 0x65, 0x6e, 0x63,
+    0x6f, 0x64, 0x65, 0x64, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x47, 0x45, 0x52, 0x20, 0x64, 0x69, 0x67, 0x65, 0x73, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x05, 0x06, 0x12, 0x03, 0x24, 0x02, 0x2f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x24, 0x30, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x05, 0x03, 0x12, 0x03, 0x24, 0x3d, 0x3e, 0x0a, 0x1b, 0x0a, 0x04, 0x04, 0x00, 0x02,
+    0x06, 0x12, 0x03, 0x27, 0x02, 0x47, 0x1a, 0x0e, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20,
+    0x65, 0x78, 0x69, 0x74, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x04, 0x12,
+    0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x06, 0x12, 0x03, 0x27,
+    0x0b, 0x2c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x27, 0x2d, 0x42,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x06, 0x03, 0x12, 0x03, 0x27, 0x45, 0x46, 0x0a, 0x9e,
+    0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x07, 0x12, 0x03, 0x2b, 0x02, 0x41, 0x1a, 0x90, 0x01, 0x20,
+    0x48, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x76, 0x65, 0x72, 0x69,
+    0x66, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x6b, 0x65, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
     0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20,
-    0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
-    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x32, 0x08, 0x25, 0x0a, 0x1e,
-    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x34, 0x02, 0x3d, 0x1a, 0x11, 0x20, 0x41, 0x67,
-    0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x34, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x34, 0x2a, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x34, 0x3b, 0x3c, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01,
-    0x12, 0x03, 0x37, 0x02, 0x1f, 0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74,
-    0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65,
-    0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65,
-    0x64, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x37, 0x02, 0x08,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x37, 0x09, 0x1a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x37, 0x1d, 0x1e, 0x0a, 0x33, 0x0a, 0x04,
-    0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x3a, 0x02, 0x17, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x3a, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3a, 0x09, 0x12, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x3a, 0x15, 0x16, 0x0a, 0x24, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x03, 0x12, 0x03, 0x3d, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63, 0x61, 0x6c,
-    0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x06, 0x12, 0x03, 0x3d, 0x02, 0x28, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3d, 0x29, 0x3d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x3d, 0x40, 0x41, 0x0a, 0x21, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x04, 0x12, 0x03, 0x40, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f,
-    0x6d, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x40, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x40, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x04, 0x03, 0x12, 0x03, 0x40, 0x1c, 0x1d, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
-    0x44, 0x00, 0x4d, 0x01, 0x1a, 0x31, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d,
+    0x70, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x2c, 0x0a, 0x20, 0x70, 0x69, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x20, 0x64, 0x75, 0x72, 0x69,
+    0x6e, 0x67, 0x20, 0x61, 0x6e, 0x20, 0x75, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x2e, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x67, 0x72,
+    0x61, 0x6d, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x07, 0x06, 0x12, 0x03, 0x2b, 0x02, 0x28, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2b, 0x29, 0x3c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2b, 0x3f, 0x40, 0x0a, 0x4b, 0x0a, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x2f, 0x00, 0x36, 0x01, 0x1a, 0x3f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d,
+    0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x2f,
+    0x08, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x30, 0x02, 0x3a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x30, 0x02, 0x1e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x30, 0x1f, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x30, 0x38, 0x39, 0x0a, 0x36, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x01, 0x12, 0x03, 0x32, 0x02, 0x47, 0x1a, 0x29, 0x20, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x22, 0x4f, 0x70, 0x74, 0x69, 0x6d,
+    0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x22, 0x20, 0x63, 0x61, 0x73, 0x65, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x32, 0x02, 0x28, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x32, 0x29, 0x42, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x32, 0x45, 0x46, 0x0a, 0x81, 0x01, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x35, 0x02, 0x18, 0x1a, 0x74, 0x20, 0x4e, 0x65, 0x74, 0x77,
+    0x6f, 0x72, 0x6b, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74,
+    0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f,
+    0x66, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x66,
+    0x6f, 0x72, 0x2c, 0x20, 0x63, 0x68, 0x65, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61, 0x69,
+    0x6e, 0x73, 0x74, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73,
+    0x74, 0x69, 0x63, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x35, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x35, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x35, 0x16, 0x17, 0x0a, 0x32, 0x0a, 0x02, 0x04, 0x02, 0x12,
+    0x04, 0x39, 0x00, 0x53, 0x01, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f,
+    0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x39, 0x08, 0x25, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x00, 0x12, 0x03, 0x3b, 0x02, 0x3d, 0x1a, 0x11, 0x20, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x3b, 0x02, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x3b, 0x2a, 0x38, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x3b, 0x3b, 0x3c, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3e, 0x02, 0x1f,
+    0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x75, 0x74, 0x65, 0x64, 0x20, 0x61, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3e, 0x09, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x3e, 0x1d, 0x1e, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x41, 0x02, 0x17, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x02, 0x05, 0x12, 0x03, 0x41, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x41, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x41, 0x15, 0x16, 0x0a, 0x24, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x44, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63, 0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74,
+    0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x03, 0x06, 0x12, 0x03, 0x44, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x44, 0x29, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03,
+    0x03, 0x12, 0x03, 0x44, 0x40, 0x41, 0x0a, 0x21, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x47, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x20, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x04, 0x05, 0x12, 0x03, 0x47, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x47, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x03, 0x12, 0x03,
+    0x47, 0x1c, 0x1d, 0x0a, 0x82, 0x01, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12, 0x03, 0x4b, 0x02,
+    0x1b, 0x1a, 0x75, 0x20, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20,
+    0x62, 0x69, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x2c, 0x20, 0x74, 0x65, 0x6c, 0x6c, 0x69, 0x6e, 0x67,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x0a, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x53, 0x50, 0x31, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20,
+    0x6d, 0x6f, 0x64, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x70, 0x72, 0x6f, 0x64, 0x75,
+    0x63, 0x65, 0x64, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05,
+    0x05, 0x12, 0x03, 0x4b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12,
+    0x03, 0x4b, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4b,
+    0x19, 0x1a, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x4e, 0x02, 0x22, 0x1a,
+    0x27, 0x20, 0x53, 0x50, 0x31, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06,
+    0x06, 0x12, 0x03, 0x4e, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x01, 0x12,
+    0x03, 0x4e, 0x0f, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x06, 0x03, 0x12, 0x03, 0x4e,
+    0x20, 0x21, 0x0a, 0x6a, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x07, 0x12, 0x03, 0x52, 0x02, 0x21, 0x1a,
+    0x5d, 0x20, 0x45, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x0a, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x77,
+    0x61, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x65, 0x6e, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x07, 0x05, 0x12, 0x03, 0x52, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x07, 0x01, 0x12, 0x03, 0x52, 0x09, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x52, 0x1f, 0x20, 0x0a, 0x3d, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04,
+    0x56, 0x00, 0x6a, 0x01, 0x1a, 0x31, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x70, 0x74, 0x69, 0x6d,
     0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70,
     0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x6d, 0x65,
     0x73, 0x73, 0x61, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
-    0x44, 0x08, 0x2f, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x46, 0x02, 0x3d,
+    0x56, 0x08, 0x2f, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x58, 0x02, 0x3d,
     0x1a, 0x11, 0x20, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f,
-    0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x46, 0x02,
-    0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x46, 0x2a, 0x38, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x46, 0x3b, 0x3c, 0x0a, 0x24, 0x0a,
-    0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x49, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63,
+    0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x58, 0x02,
+    0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x58, 0x2a, 0x38, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x58, 0x3b, 0x3c, 0x0a, 0x24, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x5b, 0x02, 0x42, 0x1a, 0x17, 0x20, 0x4c, 0x6f, 0x63,
     0x61, 0x6c, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x72, 0x6f, 0x6f, 0x74, 0x20, 0x68, 0x61, 0x73,
-    0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x49, 0x02,
-    0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x49, 0x29, 0x3d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x49, 0x40, 0x41, 0x0a, 0x21, 0x0a,
-    0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x4c, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73,
+    0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x5b, 0x02,
+    0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5b, 0x29, 0x3d, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5b, 0x40, 0x41, 0x0a, 0x21, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x5e, 0x02, 0x1e, 0x1a, 0x14, 0x20, 0x43, 0x75, 0x73,
     0x74, 0x6f, 0x6d, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x4c, 0x02, 0x07, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4c, 0x1c, 0x1d, 0x0a, 0x35, 0x0a, 0x02, 0x04, 0x04,
-    0x12, 0x04, 0x50, 0x00, 0x59, 0x01, 0x1a, 0x29, 0x20, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65,
-    0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x77, 0x69,
-    0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e,
-    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x50, 0x08, 0x29, 0x0a, 0x3c, 0x0a,
-    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x52, 0x02, 0x1a, 0x1a, 0x2f, 0x20, 0x54, 0x68, 0x65,
-    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72,
-    0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x52, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x52, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x52, 0x18, 0x19, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x01, 0x12, 0x03, 0x55,
-    0x02, 0x3a, 0x1a, 0x2b, 0x20, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x69, 0x6e, 0x64, 0x65,
-    0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65,
-    0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x55, 0x02, 0x28, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x55, 0x29, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x55, 0x38, 0x39, 0x0a, 0x20, 0x0a, 0x04, 0x04, 0x04, 0x02,
-    0x02, 0x12, 0x03, 0x58, 0x02, 0x3e, 0x1a, 0x13, 0x20, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20,
-    0x65, 0x78, 0x69, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x58, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x58, 0x29, 0x39, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x03,
-    0x12, 0x03, 0x58, 0x3c, 0x3d, 0x0a, 0x2d, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x5c, 0x00, 0x65,
-    0x01, 0x1a, 0x21, 0x20, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52,
-    0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62,
-    0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x5c, 0x08, 0x28,
-    0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x1a, 0x1a, 0x1e, 0x20,
-    0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
-    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x5e, 0x18, 0x19, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x01, 0x12,
-    0x03, 0x61, 0x02, 0x2c, 0x1a, 0x11, 0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x73, 0x65, 0x72,
-    0x74, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x06,
-    0x12, 0x03, 0x61, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x61, 0x14, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x01, 0x03, 0x12, 0x03, 0x61, 0x2a,
-    0x2b, 0x0a, 0x3b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12, 0x03, 0x64, 0x02, 0x19, 0x1a, 0x2e,
-    0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x69, 0x6e, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x20, 0x65,
-    0x76, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x64, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x64, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x02, 0x03, 0x12, 0x03, 0x64, 0x17, 0x18, 0x0a, 0x22, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
-    0x68, 0x00, 0x6e, 0x01, 0x1a, 0x16, 0x20, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x69, 0x6e,
-    0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x06, 0x01, 0x12, 0x03, 0x68, 0x08, 0x19, 0x0a, 0x27, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00,
-    0x12, 0x03, 0x6a, 0x02, 0x3d, 0x1a, 0x1a, 0x20, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x72,
-    0x6f, 0x6d, 0x20, 0x47, 0x45, 0x52, 0x20, 0x74, 0x6f, 0x20, 0x4c, 0x31, 0x52, 0x6f, 0x6f, 0x74,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03, 0x6a, 0x02, 0x27, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6a, 0x28, 0x38, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6a, 0x3b, 0x3c, 0x0a, 0x1e, 0x0a, 0x04, 0x04,
-    0x06, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x02, 0x42, 0x1a, 0x11, 0x20, 0x4c, 0x31, 0x49, 0x6e, 0x66,
-    0x6f, 0x54, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x06, 0x02, 0x01, 0x06, 0x12, 0x03, 0x6d, 0x02, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x6d, 0x36, 0x3d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x6d, 0x40, 0x41, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x5e, 0x02, 0x07, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x5e, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x5e, 0x1c, 0x1d, 0x0a, 0x82, 0x01, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x03, 0x12, 0x03, 0x62, 0x02, 0x1b, 0x1a, 0x75, 0x20, 0x45, 0x6e, 0x76, 0x65, 0x6c,
+    0x6f, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65,
+    0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x69, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x2c, 0x20,
+    0x74, 0x65, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x67,
+    0x72, 0x61, 0x6d, 0x0a, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x53, 0x50, 0x31,
+    0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x70, 0x72, 0x6f, 0x64, 0x75, 0x63, 0x65, 0x64, 0x20, 0x69, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03, 0x62, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x62, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x62, 0x19, 0x1a, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x04, 0x12, 0x03, 0x65, 0x02, 0x22, 0x1a, 0x27, 0x20, 0x53, 0x50, 0x31, 0x20, 0x70, 0x72, 0x6f,
+    0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x06, 0x12, 0x03, 0x65, 0x02, 0x0e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x65, 0x0f, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x65, 0x20, 0x21, 0x0a, 0x6a, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x05, 0x12, 0x03, 0x69, 0x02, 0x21, 0x1a, 0x5d, 0x20, 0x45, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x2c, 0x20, 0x61, 0x66,
+    0x74, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x0a, 0x20,
+    0x72, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x68, 0x6f, 0x72, 0x74, 0x65,
+    0x6e, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05, 0x12, 0x03,
+    0x69, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x69, 0x09,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x69, 0x1f, 0x20, 0x0a,
+    0x35, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x6d, 0x00, 0x76, 0x01, 0x1a, 0x29, 0x20, 0x49, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78,
+    0x69, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x6d,
+    0x08, 0x29, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x6f, 0x02, 0x1a, 0x1a,
+    0x2f, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74,
+    0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6f, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6f, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6f, 0x18, 0x19, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x04,
+    0x02, 0x01, 0x12, 0x03, 0x72, 0x02, 0x3a, 0x1a, 0x2b, 0x20, 0x47, 0x6c, 0x6f, 0x62, 0x61, 0x6c,
+    0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d,
+    0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78,
+    0x69, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x06, 0x12, 0x03, 0x72,
+    0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x03, 0x72, 0x29, 0x35,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x03, 0x72, 0x38, 0x39, 0x0a, 0x20,
+    0x0a, 0x04, 0x04, 0x04, 0x02, 0x02, 0x12, 0x03, 0x75, 0x02, 0x3e, 0x1a, 0x13, 0x20, 0x42, 0x72,
+    0x69, 0x64, 0x67, 0x65, 0x20, 0x65, 0x78, 0x69, 0x74, 0x20, 0x68, 0x61, 0x73, 0x68, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x06, 0x12, 0x03, 0x75, 0x02, 0x28, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x75, 0x29, 0x39, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x75, 0x3c, 0x3d, 0x0a, 0x2e, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x05, 0x79, 0x00, 0x82, 0x01, 0x01, 0x1a, 0x21, 0x20, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74,
+    0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05,
+    0x01, 0x12, 0x03, 0x79, 0x08, 0x28, 0x0a, 0x2b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03,
+    0x7b, 0x02, 0x1a, 0x1a, 0x1e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7b, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7b, 0x09, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7b, 0x18, 0x19, 0x0a, 0x1e, 0x0a,
+    0x04, 0x04, 0x05, 0x02, 0x01, 0x12, 0x03, 0x7e, 0x02, 0x2c, 0x1a, 0x11, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x05, 0x02, 0x01, 0x06, 0x12, 0x03, 0x7e, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x05, 0x02, 0x01, 0x01, 0x12, 0x03, 0x7e, 0x14, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x7e, 0x2a, 0x2b, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x02, 0x12,
+    0x04, 0x81, 0x01, 0x02, 0x19, 0x1a, 0x2e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65,
+    0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x6a, 0x65, 0x63, 0x74, 0x65,
+    0x64, 0x20, 0x47, 0x45, 0x52, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x04,
+    0x81, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x04, 0x81,
+    0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x04, 0x81, 0x01,
+    0x17, 0x18, 0x0a, 0x24, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x06, 0x85, 0x01, 0x00, 0x8b, 0x01, 0x01,
+    0x1a, 0x16, 0x20, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x20, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74,
+    0x65, 0x64, 0x20, 0x47, 0x45, 0x52, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12,
+    0x04, 0x85, 0x01, 0x08, 0x19, 0x0a, 0x28, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x04, 0x87,
+    0x01, 0x02, 0x3d, 0x1a, 0x1a, 0x20, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x47, 0x45, 0x52, 0x20, 0x74, 0x6f, 0x20, 0x4c, 0x31, 0x52, 0x6f, 0x6f, 0x74, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x04, 0x87, 0x01, 0x02, 0x27, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0x87, 0x01, 0x28, 0x38, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0x87, 0x01, 0x3b, 0x3c, 0x0a, 0x1f, 0x0a, 0x04,
+    0x04, 0x06, 0x02, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x42, 0x1a, 0x11, 0x20, 0x4c, 0x31, 0x49,
+    0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x20, 0x6c, 0x65, 0x61, 0x66, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x01, 0x06, 0x12, 0x04, 0x8a, 0x01, 0x02, 0x35, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x36, 0x3d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x40, 0x41, 0x0a, 0x34, 0x0a, 0x02, 0x05, 0x00,
+    0x12, 0x06, 0x8e, 0x01, 0x00, 0x99, 0x01, 0x01, 0x1a, 0x26, 0x20, 0x53, 0x50, 0x31, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x67,
+    0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a,
+    0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x01, 0x05, 0x11, 0x0a, 0x27, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x04, 0x90, 0x01, 0x02, 0x21, 0x1a, 0x19, 0x20, 0x55, 0x6e,
+    0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20,
+    0x6d, 0x6f, 0x64, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x90, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04,
+    0x90, 0x01, 0x1f, 0x20, 0x0a, 0x36, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x04, 0x92, 0x01,
+    0x02, 0x1a, 0x1a, 0x28, 0x20, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76,
+    0x65, 0x72, 0x79, 0x20, 0x73, 0x68, 0x61, 0x72, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x92, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x92, 0x01, 0x18, 0x19, 0x0a, 0x51, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x02, 0x12, 0x04, 0x94, 0x01, 0x02, 0x20, 0x1a, 0x43, 0x20, 0x43, 0x6f, 0x6e, 0x73, 0x74,
+    0x61, 0x6e, 0x74, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x20, 0x53, 0x54, 0x41, 0x52, 0x4b, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x53, 0x50, 0x31, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x94, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x94, 0x01, 0x1e, 0x1f, 0x0a, 0x39, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x03, 0x12, 0x04, 0x96, 0x01, 0x02, 0x1d, 0x1a, 0x2b, 0x20, 0x47, 0x72, 0x6f, 0x74,
+    0x68, 0x31, 0x36, 0x20, 0x53, 0x4e, 0x41, 0x52, 0x4b, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2c,
+    0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x6f, 0x6e, 0x2d, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0x96, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04,
+    0x96, 0x01, 0x1b, 0x1c, 0x0a, 0x37, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x04, 0x98, 0x01,
+    0x02, 0x1b, 0x1a, 0x29, 0x20, 0x50, 0x6c, 0x6f, 0x6e, 0x6b, 0x20, 0x53, 0x4e, 0x41, 0x52, 0x4b,
+    0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x61, 0x62,
+    0x6c, 0x65, 0x20, 0x6f, 0x6e, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0x98, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0x98, 0x01, 0x19, 0x1a, 0x62, 0x06, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x33,
 ];
 include!("aggkit.prover.v1.serde.rs");
 include!("aggkit.prover.v1.tonic.rs");
@@ -28,6 +28,9 @@ impl serde::Serialize for GenerateAggchainProofRequest {
         if !self.imported_bridge_exits.is_empty() {
             len += 1;
         }
+        if self.requested_vkey_hash.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.GenerateAggchainProofRequest", len)?;
         if self.last_proven_block != 0 {
             #[allow(clippy::needless_borrow)]
@@ -54,6 +57,9 @@ impl serde::Serialize for GenerateAggchainProofRequest {
         if !self.imported_bridge_exits.is_empty() {
             struct_ser.serialize_field("importedBridgeExits", &self.imported_bridge_exits)?;
         }
+        if let Some(v) = self.requested_vkey_hash.as_ref() {
+            struct_ser.serialize_field("requestedVkeyHash", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -78,6 +84,8 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
             "gerLeaves",
             "imported_bridge_exits",
             "importedBridgeExits",
+            "requested_vkey_hash",
+            "requestedVkeyHash",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -89,6 +97,7 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
             L1InfoTreeMerkleProof,
             GerLeaves,
             ImportedBridgeExits,
+            RequestedVkeyHash,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -117,6 +126,7 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
                             "l1InfoTreeMerkleProof" | "l1_info_tree_merkle_proof" => Ok(GeneratedField::L1InfoTreeMerkleProof),
                             "gerLeaves" | "ger_leaves" => Ok(GeneratedField::GerLeaves),
                             "importedBridgeExits" | "imported_bridge_exits" => Ok(GeneratedField::ImportedBridgeExits),
+                            "requestedVkeyHash" | "requested_vkey_hash" => Ok(GeneratedField::RequestedVkeyHash),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -143,6 +153,7 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
                 let mut l1_info_tree_merkle_proof__ = None;
                 let mut ger_leaves__ = None;
                 let mut imported_bridge_exits__ = None;
+                let mut requested_vkey_hash__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::LastProvenBlock => {
@@ -193,6 +204,12 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
                             }
                             imported_bridge_exits__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::RequestedVkeyHash => {
+                            if requested_vkey_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedVkeyHash"));
+                            }
+                            requested_vkey_hash__ = map_.next_value()?;
+                        }
                     }
                 }
                 Ok(GenerateAggchainProofRequest {
@@ -203,6 +220,7 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
                     l1_info_tree_merkle_proof: l1_info_tree_merkle_proof__,
                     ger_leaves: ger_leaves__.unwrap_or_default(),
                     imported_bridge_exits: imported_bridge_exits__.unwrap_or_default(),
+                    requested_vkey_hash: requested_vkey_hash__,
                 })
             }
         }
@@ -232,6 +250,15 @@ impl serde::Serialize for GenerateAggchainProofResponse {
         if !self.custom_chain_data.is_empty() {
             len += 1;
         }
+        if !self.proof_envelope.is_empty() {
+            len += 1;
+        }
+        if self.sp1_proof_mode != 0 {
+            len += 1;
+        }
+        if self.requested_end_block != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.GenerateAggchainProofResponse", len)?;
         if let Some(v) = self.aggchain_proof.as_ref() {
             struct_ser.serialize_field("aggchainProof", v)?;
@@ -254,6 +281,21 @@ impl serde::Serialize for GenerateAggchainProofResponse {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("customChainData", pbjson::private::base64::encode(&self.custom_chain_data).as_str())?;
         }
+        if !self.proof_envelope.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proofEnvelope", pbjson::private::base64::encode(&self.proof_envelope).as_str())?;
+        }
+        if self.sp1_proof_mode != 0 {
+            let v = Sp1ProofMode::try_from(self.sp1_proof_mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.sp1_proof_mode)))?;
+            struct_ser.serialize_field("sp1ProofMode", &v)?;
+        }
+        if self.requested_end_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("requestedEndBlock", ToString::to_string(&self.requested_end_block).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -274,6 +316,12 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
             "localExitRootHash",
             "custom_chain_data",
             "customChainData",
+            "proof_envelope",
+            "proofEnvelope",
+            "sp1_proof_mode",
+            "sp1ProofMode",
+            "requested_end_block",
+            "requestedEndBlock",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -283,6 +331,9 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
             EndBlock,
             LocalExitRootHash,
             CustomChainData,
+            ProofEnvelope,
+            Sp1ProofMode,
+            RequestedEndBlock,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -309,6 +360,9 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                             "endBlock" | "end_block" => Ok(GeneratedField::EndBlock),
                             "localExitRootHash" | "local_exit_root_hash" => Ok(GeneratedField::LocalExitRootHash),
                             "customChainData" | "custom_chain_data" => Ok(GeneratedField::CustomChainData),
+                            "proofEnvelope" | "proof_envelope" => Ok(GeneratedField::ProofEnvelope),
+                            "sp1ProofMode" | "sp1_proof_mode" => Ok(GeneratedField::Sp1ProofMode),
+                            "requestedEndBlock" | "requested_end_block" => Ok(GeneratedField::RequestedEndBlock),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -333,6 +387,9 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                 let mut end_block__ = None;
                 let mut local_exit_root_hash__ = None;
                 let mut custom_chain_data__ = None;
+                let mut proof_envelope__ = None;
+                let mut sp1_proof_mode__ = None;
+                let mut requested_end_block__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AggchainProof => {
@@ -371,6 +428,28 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ProofEnvelope => {
+                            if proof_envelope__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofEnvelope"));
+                            }
+                            proof_envelope__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sp1ProofMode => {
+                            if sp1_proof_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sp1ProofMode"));
+                            }
+                            sp1_proof_mode__ = Some(map_.next_value::<Sp1ProofMode>()? as i32);
+                        }
+                        GeneratedField::RequestedEndBlock => {
+                            if requested_end_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedEndBlock"));
+                            }
+                            requested_end_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(GenerateAggchainProofResponse {
@@ -379,6 +458,9 @@ impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
                     end_block: end_block__.unwrap_or_default(),
                     local_exit_root_hash: local_exit_root_hash__,
                     custom_chain_data: custom_chain_data__.unwrap_or_default(),
+                    proof_envelope: proof_envelope__.unwrap_or_default(),
+                    sp1_proof_mode: sp1_proof_mode__.unwrap_or_default(),
+                    requested_end_block: requested_end_block__.unwrap_or_default(),
                 })
             }
         }
@@ -399,6 +481,9 @@ impl serde::Serialize for GenerateOptimisticAggchainProofRequest {
         if self.optimistic_mode_signature.is_some() {
             len += 1;
         }
+        if self.network_id != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.GenerateOptimisticAggchainProofRequest", len)?;
         if let Some(v) = self.aggchain_proof_request.as_ref() {
             struct_ser.serialize_field("aggchainProofRequest", v)?;
@@ -406,6 +491,9 @@ impl serde::Serialize for GenerateOptimisticAggchainProofRequest {
         if let Some(v) = self.optimistic_mode_signature.as_ref() {
             struct_ser.serialize_field("optimisticModeSignature", v)?;
         }
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
         struct_ser.end()
     }
 }
@@ -420,12 +508,15 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofRequest {
             "aggchainProofRequest",
             "optimistic_mode_signature",
             "optimisticModeSignature",
+            "network_id",
+            "networkId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             AggchainProofRequest,
             OptimisticModeSignature,
+            NetworkId,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -449,6 +540,7 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofRequest {
                         match value {
                             "aggchainProofRequest" | "aggchain_proof_request" => Ok(GeneratedField::AggchainProofRequest),
                             "optimisticModeSignature" | "optimistic_mode_signature" => Ok(GeneratedField::OptimisticModeSignature),
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -470,6 +562,7 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofRequest {
             {
                 let mut aggchain_proof_request__ = None;
                 let mut optimistic_mode_signature__ = None;
+                let mut network_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AggchainProofRequest => {
@@ -484,11 +577,20 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofRequest {
                             }
                             optimistic_mode_signature__ = map_.next_value()?;
                         }
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(GenerateOptimisticAggchainProofRequest {
                     aggchain_proof_request: aggchain_proof_request__,
                     optimistic_mode_signature: optimistic_mode_signature__,
+                    network_id: network_id__.unwrap_or_default(),
                 })
             }
         }
@@ -512,6 +614,15 @@ impl serde::Serialize for GenerateOptimisticAggchainProofResponse {
         if !self.custom_chain_data.is_empty() {
             len += 1;
         }
+        if !self.proof_envelope.is_empty() {
+            len += 1;
+        }
+        if self.sp1_proof_mode != 0 {
+            len += 1;
+        }
+        if self.requested_end_block != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("aggkit.prover.v1.GenerateOptimisticAggchainProofResponse", len)?;
         if let Some(v) = self.aggchain_proof.as_ref() {
             struct_ser.serialize_field("aggchainProof", v)?;
@@ -524,6 +635,21 @@ impl serde::Serialize for GenerateOptimisticAggchainProofResponse {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("customChainData", pbjson::private::base64::encode(&self.custom_chain_data).as_str())?;
         }
+        if !self.proof_envelope.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proofEnvelope", pbjson::private::base64::encode(&self.proof_envelope).as_str())?;
+        }
+        if self.sp1_proof_mode != 0 {
+            let v = Sp1ProofMode::try_from(self.sp1_proof_mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.sp1_proof_mode)))?;
+            struct_ser.serialize_field("sp1ProofMode", &v)?;
+        }
+        if self.requested_end_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("requestedEndBlock", ToString::to_string(&self.requested_end_block).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -540,6 +666,12 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
             "localExitRootHash",
             "custom_chain_data",
             "customChainData",
+            "proof_envelope",
+            "proofEnvelope",
+            "sp1_proof_mode",
+            "sp1ProofMode",
+            "requested_end_block",
+            "requestedEndBlock",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -547,6 +679,9 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
             AggchainProof,
             LocalExitRootHash,
             CustomChainData,
+            ProofEnvelope,
+            Sp1ProofMode,
+            RequestedEndBlock,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -571,6 +706,9 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
                             "aggchainProof" | "aggchain_proof" => Ok(GeneratedField::AggchainProof),
                             "localExitRootHash" | "local_exit_root_hash" => Ok(GeneratedField::LocalExitRootHash),
                             "customChainData" | "custom_chain_data" => Ok(GeneratedField::CustomChainData),
+                            "proofEnvelope" | "proof_envelope" => Ok(GeneratedField::ProofEnvelope),
+                            "sp1ProofMode" | "sp1_proof_mode" => Ok(GeneratedField::Sp1ProofMode),
+                            "requestedEndBlock" | "requested_end_block" => Ok(GeneratedField::RequestedEndBlock),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -593,6 +731,9 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
                 let mut aggchain_proof__ = None;
                 let mut local_exit_root_hash__ = None;
                 let mut custom_chain_data__ = None;
+                let mut proof_envelope__ = None;
+                let mut sp1_proof_mode__ = None;
+                let mut requested_end_block__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::AggchainProof => {
@@ -615,12 +756,37 @@ impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofResponse {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::ProofEnvelope => {
+                            if proof_envelope__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofEnvelope"));
+                            }
+                            proof_envelope__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sp1ProofMode => {
+                            if sp1_proof_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sp1ProofMode"));
+                            }
+                            sp1_proof_mode__ = Some(map_.next_value::<Sp1ProofMode>()? as i32);
+                        }
+                        GeneratedField::RequestedEndBlock => {
+                            if requested_end_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedEndBlock"));
+                            }
+                            requested_end_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                     }
                 }
                 Ok(GenerateOptimisticAggchainProofResponse {
                     aggchain_proof: aggchain_proof__,
                     local_exit_root_hash: local_exit_root_hash__,
                     custom_chain_data: custom_chain_data__.unwrap_or_default(),
+                    proof_envelope: proof_envelope__.unwrap_or_default(),
+                    sp1_proof_mode: sp1_proof_mode__.unwrap_or_default(),
+                    requested_end_block: requested_end_block__.unwrap_or_default(),
                 })
             }
         }
@@ -1005,3 +1171,83 @@ impl<'de> serde::Deserialize<'de> for ProvenInsertedGerWithBlockNumber {
         deserializer.deserialize_struct("aggkit.prover.v1.ProvenInsertedGERWithBlockNumber", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Sp1ProofMode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SP1_PROOF_MODE_UNSPECIFIED",
+            Self::Core => "SP1_PROOF_MODE_CORE",
+            Self::Compressed => "SP1_PROOF_MODE_COMPRESSED",
+            Self::Groth16 => "SP1_PROOF_MODE_GROTH16",
+            Self::Plonk => "SP1_PROOF_MODE_PLONK",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Sp1ProofMode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SP1_PROOF_MODE_UNSPECIFIED",
+            "SP1_PROOF_MODE_CORE",
+            "SP1_PROOF_MODE_COMPRESSED",
+            "SP1_PROOF_MODE_GROTH16",
+            "SP1_PROOF_MODE_PLONK",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Sp1ProofMode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SP1_PROOF_MODE_UNSPECIFIED" => Ok(Sp1ProofMode::Unspecified),
+                    "SP1_PROOF_MODE_CORE" => Ok(Sp1ProofMode::Core),
+                    "SP1_PROOF_MODE_COMPRESSED" => Ok(Sp1ProofMode::Compressed),
+                    "SP1_PROOF_MODE_GROTH16" => Ok(Sp1ProofMode::Groth16),
+                    "SP1_PROOF_MODE_PLONK" => Ok(Sp1ProofMode::Plonk),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
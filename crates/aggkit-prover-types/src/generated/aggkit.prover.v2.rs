@@ -0,0 +1,663 @@
+// @generated
+// This file is @generated by prost-build.
+/// The request message for generating aggchain proof.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateAggchainProofRequest {
+    /// Identifier of the request, chosen by the client.
+    #[prost(string, tag="1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// The last proven block before the requested aggchain proof.
+    #[prost(uint64, tag="2")]
+    pub last_proven_block: u64,
+    /// The max end block for which the aggchain proof is requested.
+    #[prost(uint64, tag="3")]
+    pub requested_end_block: u64,
+    /// L1 Info tree root.
+    #[prost(message, optional, tag="4")]
+    pub l1_info_tree_root_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// L1 Info tree leaf.
+    #[prost(message, optional, tag="5")]
+    pub l1_info_tree_leaf: ::core::option::Option<::agglayer_interop::grpc::v1::L1InfoTreeLeafWithContext>,
+    /// L1 Info tree proof.
+    #[prost(message, optional, tag="6")]
+    pub l1_info_tree_merkle_proof: ::core::option::Option<::agglayer_interop::grpc::v1::MerkleProof>,
+    /// Inserted GERs with their inclusion proof.
+    #[prost(message, repeated, tag="7")]
+    pub inserted_gers: ::prost::alloc::vec::Vec<ProvenInsertedGer>,
+    /// Imported bridge exits.
+    #[prost(message, repeated, tag="8")]
+    pub imported_bridge_exits: ::prost::alloc::vec::Vec<ImportedBridgeExitWithBlockNumber>,
+    /// Hash of the verifying key of the aggchain proof program to prove with,
+    /// pinning the program during an upgrade. The current program when unset.
+    #[prost(message, optional, tag="9")]
+    pub requested_vkey_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
+/// The request message for generating optimistic aggchain proof.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateOptimisticAggchainProofRequest {
+    /// Aggchain proof request.
+    #[prost(message, optional, tag="1")]
+    pub aggchain_proof_request: ::core::option::Option<GenerateAggchainProofRequest>,
+    /// Signature in the "OptimisticMode" case.
+    #[prost(message, optional, tag="2")]
+    pub optimistic_mode_signature: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes65>,
+    /// Network the optimistic aggchain proof is requested for, checked against
+    /// the optimistic mode policy of the prover.
+    #[prost(uint32, tag="3")]
+    pub network_id: u32,
+}
+/// The aggchain proof response message.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenerateAggchainProofResponse {
+    /// Identifier of the request.
+    #[prost(string, tag="1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Aggchain proof.
+    #[prost(message, optional, tag="2")]
+    pub aggchain_proof: ::core::option::Option<::agglayer_interop::grpc::v1::AggchainProof>,
+    /// The last proven block before the computed aggchain proof.
+    #[prost(uint64, tag="3")]
+    pub last_proven_block: u64,
+    /// The end block of the aggchain proof.
+    #[prost(uint64, tag="4")]
+    pub end_block: u64,
+    /// Local exit root hash.
+    #[prost(message, optional, tag="5")]
+    pub local_exit_root_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Custom chain data.
+    #[prost(bytes="bytes", tag="6")]
+    pub custom_chain_data: ::prost::bytes::Bytes,
+    /// Envelope of the aggchain proof encoded with bincode, telling the program
+    /// and the SP1 proof mode which produced it.
+    #[prost(bytes="bytes", tag="7")]
+    pub proof_envelope: ::prost::bytes::Bytes,
+    /// SP1 proof mode of the aggchain proof.
+    #[prost(enumeration="Sp1ProofMode", tag="8")]
+    pub sp1_proof_mode: i32,
+    /// End block requested, after the end block of the proof when the proven
+    /// range was shortened.
+    #[prost(uint64, tag="9")]
+    pub requested_end_block: u64,
+}
+/// Imported bridge exit with block number.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportedBridgeExitWithBlockNumber {
+    /// The block number of the imported bridge exit.
+    #[prost(uint64, tag="1")]
+    pub block_number: u64,
+    /// Global index of the imported bridge exit.
+    #[prost(message, optional, tag="2")]
+    pub global_index: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Bridge exit hash.
+    #[prost(message, optional, tag="3")]
+    pub bridge_exit_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+}
+/// Inserted GER with its inclusion proof.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProvenInsertedGer {
+    /// The inserted GER.
+    #[prost(message, optional, tag="1")]
+    pub ger: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// The block number of the GER.
+    #[prost(uint64, tag="2")]
+    pub block_number: u64,
+    /// The index of the injected GER event in block.
+    #[prost(uint64, tag="3")]
+    pub block_index: u64,
+    /// Proof from GER to L1Root.
+    #[prost(message, optional, tag="4")]
+    pub proof_ger_l1root: ::core::option::Option<::agglayer_interop::grpc::v1::MerkleProof>,
+    /// L1InfoTree leaf.
+    #[prost(message, optional, tag="5")]
+    pub l1_leaf: ::core::option::Option<::agglayer_interop::grpc::v1::L1InfoTreeLeafWithContext>,
+}
+/// The request message for watching the status of one aggchain proof request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchAggchainProofStatusRequest {
+    /// Identifier of the request.
+    #[prost(string, tag="1")]
+    pub request_id: ::prost::alloc::string::String,
+}
+/// Status of one aggchain proof request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggchainProofStatus {
+    /// Identifier of the request.
+    #[prost(string, tag="1")]
+    pub request_id: ::prost::alloc::string::String,
+    /// Current stage of the request.
+    #[prost(enumeration="AggchainProofStage", tag="2")]
+    pub stage: i32,
+    /// Error, set when the stage is `AGGCHAIN_PROOF_STAGE_FAILED`.
+    #[prost(message, optional, tag="3")]
+    pub error: ::core::option::Option<AggchainProofError>,
+    /// Position of the request among the ones waiting to be proven, 1 being the
+    /// next one, set when the stage is `AGGCHAIN_PROOF_STAGE_RECEIVED`.
+    #[prost(uint32, tag="4")]
+    pub queue_position: u32,
+    /// Estimated start of the proving, in milliseconds since the Unix epoch, set
+    /// along with the queue position once the prover has been busy enough to
+    /// estimate it.
+    #[prost(uint64, tag="5")]
+    pub estimated_start_ms: u64,
+}
+/// Structured error of one aggchain proof request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AggchainProofError {
+    /// The kind of error that occurred.
+    #[prost(enumeration="AggchainProofErrorKind", tag="1")]
+    pub kind: i32,
+    /// Human readable error message.
+    #[prost(string, tag="2")]
+    pub message: ::prost::alloc::string::String,
+    /// Path of the request field at fault, if any.
+    #[prost(string, tag="3")]
+    pub field_path: ::prost::alloc::string::String,
+}
+/// The request message for the versions and capabilities of the prover.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProverInfoRequest {
+    /// Semantic version of the client, e.g. `0.7.0`, checked against the oldest
+    /// version supported by the prover.
+    #[prost(string, tag="1")]
+    pub client_version: ::prost::alloc::string::String,
+}
+/// Versions and capabilities of the prover.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetProverInfoResponse {
+    /// Semantic version of the prover.
+    #[prost(string, tag="1")]
+    pub version: ::prost::alloc::string::String,
+    /// Packages of the gRPC APIs served, e.g. `aggkit.prover.v2`.
+    #[prost(string, repeated, tag="2")]
+    pub api_versions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Modes of the aggchain proofs accepted.
+    #[prost(enumeration="AggchainProofMode", repeated, tag="3")]
+    pub proof_modes: ::prost::alloc::vec::Vec<i32>,
+    /// SP1 circuit version of the proofs.
+    #[prost(string, tag="4")]
+    pub sp1_version: ::prost::alloc::string::String,
+    /// Aggchain proof programs served, empty when proven by remote workers.
+    #[prost(message, repeated, tag="5")]
+    pub programs: ::prost::alloc::vec::Vec<ServedProgram>,
+    /// Limit of the requests of the client, unset when unlimited.
+    #[prost(message, optional, tag="6")]
+    pub rate_limit: ::core::option::Option<RateLimitPolicy>,
+    /// Oldest client version supported, empty when any.
+    #[prost(string, tag="7")]
+    pub min_client_version: ::prost::alloc::string::String,
+    /// Whether the client version of the request is supported.
+    #[prost(bool, tag="8")]
+    pub client_supported: bool,
+}
+/// Aggchain proof program served by the prover.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ServedProgram {
+    /// Hash of the verifying key of the program.
+    #[prost(message, optional, tag="1")]
+    pub vkey_hash: ::core::option::Option<::agglayer_interop::grpc::v1::FixedBytes32>,
+    /// Whether the requests which don't pin a program are proven with it.
+    #[prost(bool, tag="2")]
+    pub current: bool,
+}
+/// Maximum number of requests per period.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RateLimitPolicy {
+    /// Requests accepted per period.
+    #[prost(uint32, tag="1")]
+    pub requests: u32,
+    /// Length of the period, in milliseconds.
+    #[prost(uint64, tag="2")]
+    pub period_ms: u64,
+}
+/// Stage of one aggchain proof request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AggchainProofStage {
+    /// Unspecified stage.
+    Unspecified = 0,
+    /// The request has been received.
+    Received = 1,
+    /// The aggchain proof is being generated.
+    Proving = 2,
+    /// The aggchain proof has been generated.
+    Completed = 3,
+    /// The request failed.
+    Failed = 4,
+}
+impl AggchainProofStage {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "AGGCHAIN_PROOF_STAGE_UNSPECIFIED",
+            Self::Received => "AGGCHAIN_PROOF_STAGE_RECEIVED",
+            Self::Proving => "AGGCHAIN_PROOF_STAGE_PROVING",
+            Self::Completed => "AGGCHAIN_PROOF_STAGE_COMPLETED",
+            Self::Failed => "AGGCHAIN_PROOF_STAGE_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AGGCHAIN_PROOF_STAGE_UNSPECIFIED" => Some(Self::Unspecified),
+            "AGGCHAIN_PROOF_STAGE_RECEIVED" => Some(Self::Received),
+            "AGGCHAIN_PROOF_STAGE_PROVING" => Some(Self::Proving),
+            "AGGCHAIN_PROOF_STAGE_COMPLETED" => Some(Self::Completed),
+            "AGGCHAIN_PROOF_STAGE_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+/// The kind of error that occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AggchainProofErrorKind {
+    /// Unspecified error.
+    Unspecified = 0,
+    /// The request is invalid.
+    InvalidArgument = 1,
+    /// The request has been rejected by the prover policy.
+    Rejected = 2,
+    /// The proof generation failed.
+    ProvingFailed = 3,
+    /// Internal error of the prover.
+    Internal = 4,
+    /// The request refers to a stale state or replays a settled range.
+    Stale = 5,
+}
+impl AggchainProofErrorKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "AGGCHAIN_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::InvalidArgument => "AGGCHAIN_PROOF_ERROR_KIND_INVALID_ARGUMENT",
+            Self::Rejected => "AGGCHAIN_PROOF_ERROR_KIND_REJECTED",
+            Self::ProvingFailed => "AGGCHAIN_PROOF_ERROR_KIND_PROVING_FAILED",
+            Self::Internal => "AGGCHAIN_PROOF_ERROR_KIND_INTERNAL",
+            Self::Stale => "AGGCHAIN_PROOF_ERROR_KIND_STALE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AGGCHAIN_PROOF_ERROR_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "AGGCHAIN_PROOF_ERROR_KIND_INVALID_ARGUMENT" => Some(Self::InvalidArgument),
+            "AGGCHAIN_PROOF_ERROR_KIND_REJECTED" => Some(Self::Rejected),
+            "AGGCHAIN_PROOF_ERROR_KIND_PROVING_FAILED" => Some(Self::ProvingFailed),
+            "AGGCHAIN_PROOF_ERROR_KIND_INTERNAL" => Some(Self::Internal),
+            "AGGCHAIN_PROOF_ERROR_KIND_STALE" => Some(Self::Stale),
+            _ => None,
+        }
+    }
+}
+/// Mode of the aggchain proofs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum AggchainProofMode {
+    /// Unspecified mode.
+    Unspecified = 0,
+    /// Proof of the execution of the blocks, see `GenerateAggchainProof`.
+    Normal = 1,
+    /// Proof of the signature of the trusted sequencer, see
+    /// `GenerateOptimisticAggchainProof`.
+    Optimistic = 2,
+}
+impl AggchainProofMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "AGGCHAIN_PROOF_MODE_UNSPECIFIED",
+            Self::Normal => "AGGCHAIN_PROOF_MODE_NORMAL",
+            Self::Optimistic => "AGGCHAIN_PROOF_MODE_OPTIMISTIC",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "AGGCHAIN_PROOF_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "AGGCHAIN_PROOF_MODE_NORMAL" => Some(Self::Normal),
+            "AGGCHAIN_PROOF_MODE_OPTIMISTIC" => Some(Self::Optimistic),
+            _ => None,
+        }
+    }
+}
+/// SP1 proof mode of a generated proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Sp1ProofMode {
+    /// Unspecified proof mode.
+    Unspecified = 0,
+    /// Proof of every shard of the execution.
+    Core = 1,
+    /// Constant size STARK proof, verifiable within SP1 for aggregation.
+    Compressed = 2,
+    /// Groth16 SNARK proof, verifiable on-chain.
+    Groth16 = 3,
+    /// Plonk SNARK proof, verifiable on-chain.
+    Plonk = 4,
+}
+impl Sp1ProofMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SP1_PROOF_MODE_UNSPECIFIED",
+            Self::Core => "SP1_PROOF_MODE_CORE",
+            Self::Compressed => "SP1_PROOF_MODE_COMPRESSED",
+            Self::Groth16 => "SP1_PROOF_MODE_GROTH16",
+            Self::Plonk => "SP1_PROOF_MODE_PLONK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SP1_PROOF_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SP1_PROOF_MODE_CORE" => Some(Self::Core),
+            "SP1_PROOF_MODE_COMPRESSED" => Some(Self::Compressed),
+            "SP1_PROOF_MODE_GROTH16" => Some(Self::Groth16),
+            "SP1_PROOF_MODE_PLONK" => Some(Self::Plonk),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `aggkit.prover.v2` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xce, 0x23, 0x0a, 0x30, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2f, 0x70, 0x72, 0x6f, 0x76,
+    0x65, 0x72, 0x2f, 0x76, 0x32, 0x2f, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72,
+    0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x1a, 0x28, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65,
+    0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f,
+    0x76, 0x31, 0x2f, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x1a, 0x25, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2f, 0x76, 0x31, 0x2f, 0x62, 0x79, 0x74,
+    0x65, 0x73, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x2c, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x2f, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2f, 0x74, 0x79, 0x70, 0x65, 0x73,
+    0x2f, 0x76, 0x31, 0x2f, 0x6d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0xc5, 0x05, 0x0a, 0x1c, 0x47, 0x65, 0x6e, 0x65, 0x72,
+    0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x2a, 0x0a, 0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70,
+    0x72, 0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0f, 0x6c, 0x61, 0x73, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x42, 0x6c, 0x6f,
+    0x63, 0x6b, 0x12, 0x2e, 0x0a, 0x13, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f,
+    0x65, 0x6e, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x11, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x45, 0x6e, 0x64, 0x42, 0x6c, 0x6f,
+    0x63, 0x6b, 0x12, 0x5b, 0x0a, 0x16, 0x6c, 0x31, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x5f, 0x74, 0x72,
+    0x65, 0x65, 0x5f, 0x72, 0x6f, 0x6f, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e,
+    0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46,
+    0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x12, 0x6c, 0x31, 0x49,
+    0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x52, 0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12,
+    0x5f, 0x0a, 0x11, 0x6c, 0x31, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x5f, 0x74, 0x72, 0x65, 0x65, 0x5f,
+    0x6c, 0x65, 0x61, 0x66, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x34, 0x2e, 0x61, 0x67, 0x67,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79,
+    0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x31, 0x49, 0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65,
+    0x65, 0x4c, 0x65, 0x61, 0x66, 0x57, 0x69, 0x74, 0x68, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+    0x52, 0x0e, 0x6c, 0x31, 0x49, 0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x4c, 0x65, 0x61, 0x66,
+    0x12, 0x60, 0x0a, 0x19, 0x6c, 0x31, 0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x5f, 0x74, 0x72, 0x65, 0x65,
+    0x5f, 0x6d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69,
+    0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e,
+    0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x15, 0x6c, 0x31, 0x49,
+    0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x12, 0x48, 0x0a, 0x0d, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x67,
+    0x65, 0x72, 0x73, 0x18, 0x07, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x67, 0x67, 0x6b,
+    0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x50, 0x72, 0x6f,
+    0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x65, 0x72, 0x52, 0x0c,
+    0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x65, 0x72, 0x73, 0x12, 0x67, 0x0a, 0x15,
+    0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x5f, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x5f,
+    0x65, 0x78, 0x69, 0x74, 0x73, 0x18, 0x08, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x33, 0x2e, 0x61, 0x67,
+    0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x49,
+    0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69,
+    0x74, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x52, 0x13, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72, 0x69, 0x64, 0x67, 0x65,
+    0x45, 0x78, 0x69, 0x74, 0x73, 0x12, 0x57, 0x0a, 0x13, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x65, 0x64, 0x5f, 0x76, 0x6b, 0x65, 0x79, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x09, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e,
+    0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46,
+    0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x56, 0x6b, 0x65, 0x79, 0x48, 0x61, 0x73, 0x68, 0x22, 0x92,
+    0x02, 0x0a, 0x26, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74, 0x69, 0x6d,
+    0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x64, 0x0a, 0x16, 0x61, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x72, 0x65, 0x71, 0x75,
+    0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6b,
+    0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x47, 0x65, 0x6e,
+    0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x52, 0x14, 0x61, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+    0x63, 0x0a, 0x19, 0x6f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x5f, 0x6d, 0x6f,
+    0x64, 0x65, 0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e,
+    0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46,
+    0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x36, 0x35, 0x52, 0x17, 0x6f, 0x70, 0x74,
+    0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x4d, 0x6f, 0x64, 0x65, 0x53, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x49, 0x64, 0x22, 0xfb, 0x03, 0x0a, 0x1d, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
+    0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73,
+    0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x49, 0x64, 0x12, 0x4f, 0x0a, 0x0e, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x0d, 0x61, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x2a, 0x0a, 0x11, 0x6c, 0x61, 0x73, 0x74, 0x5f, 0x70, 0x72,
+    0x6f, 0x76, 0x65, 0x6e, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x0f, 0x6c, 0x61, 0x73, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x42, 0x6c, 0x6f, 0x63,
+    0x6b, 0x12, 0x1b, 0x0a, 0x09, 0x65, 0x6e, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x65, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x58,
+    0x0a, 0x14, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x72, 0x6f, 0x6f,
+    0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79,
+    0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x11, 0x6c, 0x6f, 0x63, 0x61, 0x6c, 0x45, 0x78, 0x69, 0x74,
+    0x52, 0x6f, 0x6f, 0x74, 0x48, 0x61, 0x73, 0x68, 0x12, 0x2a, 0x0a, 0x11, 0x63, 0x75, 0x73, 0x74,
+    0x6f, 0x6d, 0x5f, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x75, 0x73, 0x74, 0x6f, 0x6d, 0x43, 0x68, 0x61, 0x69, 0x6e,
+    0x44, 0x61, 0x74, 0x61, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x65, 0x6e,
+    0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x12, 0x44, 0x0a, 0x0e, 0x73,
+    0x70, 0x31, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x6d, 0x6f, 0x64, 0x65, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x0e, 0x32, 0x1e, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d,
+    0x6f, 0x64, 0x65, 0x52, 0x0c, 0x73, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64,
+    0x65, 0x12, 0x2e, 0x0a, 0x13, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x5f, 0x65,
+    0x6e, 0x64, 0x5f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x45, 0x6e, 0x64, 0x42, 0x6c, 0x6f, 0x63,
+    0x6b, 0x22, 0xe5, 0x01, 0x0a, 0x21, 0x49, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x42, 0x72,
+    0x69, 0x64, 0x67, 0x65, 0x45, 0x78, 0x69, 0x74, 0x57, 0x69, 0x74, 0x68, 0x42, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x4a, 0x0a, 0x0c, 0x67, 0x6c,
+    0x6f, 0x62, 0x61, 0x6c, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
+    0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0b, 0x67, 0x6c, 0x6f, 0x62, 0x61,
+    0x6c, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x51, 0x0a, 0x10, 0x62, 0x72, 0x69, 0x64, 0x67, 0x65,
+    0x5f, 0x65, 0x78, 0x69, 0x74, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65,
+    0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78,
+    0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x0e, 0x62, 0x72, 0x69, 0x64, 0x67,
+    0x65, 0x45, 0x78, 0x69, 0x74, 0x48, 0x61, 0x73, 0x68, 0x22, 0xb3, 0x02, 0x0a, 0x11, 0x50, 0x72,
+    0x6f, 0x76, 0x65, 0x6e, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x65, 0x64, 0x47, 0x65, 0x72, 0x12,
+    0x39, 0x0a, 0x03, 0x67, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61,
+    0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e,
+    0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79,
+    0x74, 0x65, 0x73, 0x33, 0x32, 0x52, 0x03, 0x67, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x1f, 0x0a,
+    0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x03, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x0a, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x50,
+    0x0a, 0x10, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65, 0x72, 0x5f, 0x6c, 0x31, 0x72, 0x6f,
+    0x6f, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x65, 0x72, 0x6b, 0x6c, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x52, 0x0e, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65, 0x72, 0x4c, 0x31, 0x72, 0x6f, 0x6f, 0x74,
+    0x12, 0x4d, 0x0a, 0x07, 0x6c, 0x31, 0x5f, 0x6c, 0x65, 0x61, 0x66, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x0b, 0x32, 0x34, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74,
+    0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x31,
+    0x49, 0x6e, 0x66, 0x6f, 0x54, 0x72, 0x65, 0x65, 0x4c, 0x65, 0x61, 0x66, 0x57, 0x69, 0x74, 0x68,
+    0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x06, 0x6c, 0x31, 0x4c, 0x65, 0x61, 0x66, 0x22,
+    0x40, 0x0a, 0x1f, 0x57, 0x61, 0x74, 0x63, 0x68, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49,
+    0x64, 0x22, 0xac, 0x01, 0x0a, 0x13, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x72,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x49, 0x64, 0x12, 0x3a, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x67,
+    0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x24, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74,
+    0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x74, 0x61, 0x67, 0x65, 0x52, 0x05, 0x73,
+    0x74, 0x61, 0x67, 0x65, 0x12, 0x3a, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f,
+    0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x22, 0x8b, 0x01, 0x0a, 0x12, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x3c, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70,
+    0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x52,
+    0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12,
+    0x1d, 0x0a, 0x0a, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x5f, 0x70, 0x61, 0x74, 0x68, 0x18, 0x03, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x09, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x50, 0x61, 0x74, 0x68, 0x22, 0x3d,
+    0x0a, 0x14, 0x47, 0x65, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x49, 0x6e, 0x66, 0x6f, 0x52,
+    0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x25, 0x0a, 0x0e, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74,
+    0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0d,
+    0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x22, 0x93, 0x03,
+    0x0a, 0x15, 0x47, 0x65, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x49, 0x6e, 0x66, 0x6f, 0x52,
+    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69,
+    0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f,
+    0x6e, 0x12, 0x21, 0x0a, 0x0c, 0x61, 0x70, 0x69, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x61, 0x70, 0x69, 0x56, 0x65, 0x72, 0x73,
+    0x69, 0x6f, 0x6e, 0x73, 0x12, 0x44, 0x0a, 0x0b, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x6d, 0x6f,
+    0x64, 0x65, 0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0e, 0x32, 0x23, 0x2e, 0x61, 0x67, 0x67, 0x6b,
+    0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x67, 0x67,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65, 0x52, 0x0a,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x73, 0x70,
+    0x31, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x0a, 0x73, 0x70, 0x31, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x3b, 0x0a, 0x08, 0x70,
+    0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e,
+    0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32,
+    0x2e, 0x53, 0x65, 0x72, 0x76, 0x65, 0x64, 0x50, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x52, 0x08,
+    0x70, 0x72, 0x6f, 0x67, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x40, 0x0a, 0x0a, 0x72, 0x61, 0x74, 0x65,
+    0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x61,
+    0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e,
+    0x52, 0x61, 0x74, 0x65, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x52,
+    0x09, 0x72, 0x61, 0x74, 0x65, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x2c, 0x0a, 0x12, 0x6d, 0x69,
+    0x6e, 0x5f, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x5f, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e,
+    0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x10, 0x6d, 0x69, 0x6e, 0x43, 0x6c, 0x69, 0x65, 0x6e,
+    0x74, 0x56, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6c, 0x69, 0x65,
+    0x6e, 0x74, 0x5f, 0x73, 0x75, 0x70, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x18, 0x08, 0x20, 0x01,
+    0x28, 0x08, 0x52, 0x0f, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x53, 0x75, 0x70, 0x70, 0x6f, 0x72,
+    0x74, 0x65, 0x64, 0x22, 0x6f, 0x0a, 0x0d, 0x53, 0x65, 0x72, 0x76, 0x65, 0x64, 0x50, 0x72, 0x6f,
+    0x67, 0x72, 0x61, 0x6d, 0x12, 0x44, 0x0a, 0x09, 0x76, 0x6b, 0x65, 0x79, 0x5f, 0x68, 0x61, 0x73,
+    0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x2e, 0x69, 0x6e, 0x74, 0x65, 0x72, 0x6f, 0x70, 0x2e, 0x74, 0x79, 0x70, 0x65, 0x73,
+    0x2e, 0x76, 0x31, 0x2e, 0x46, 0x69, 0x78, 0x65, 0x64, 0x42, 0x79, 0x74, 0x65, 0x73, 0x33, 0x32,
+    0x52, 0x08, 0x76, 0x6b, 0x65, 0x79, 0x48, 0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x75,
+    0x72, 0x72, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x63, 0x75, 0x72,
+    0x72, 0x65, 0x6e, 0x74, 0x22, 0x4a, 0x0a, 0x0f, 0x52, 0x61, 0x74, 0x65, 0x4c, 0x69, 0x6d, 0x69,
+    0x74, 0x50, 0x6f, 0x6c, 0x69, 0x63, 0x79, 0x12, 0x1a, 0x0a, 0x08, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x73, 0x12, 0x1b, 0x0a, 0x09, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x5f, 0x6d, 0x73,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x70, 0x65, 0x72, 0x69, 0x6f, 0x64, 0x4d, 0x73,
+    0x2a, 0xc4, 0x01, 0x0a, 0x12, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x53, 0x74, 0x61, 0x67, 0x65, 0x12, 0x24, 0x0a, 0x20, 0x41, 0x47, 0x47, 0x43, 0x48,
+    0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x53, 0x54, 0x41, 0x47, 0x45, 0x5f,
+    0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x21, 0x0a,
+    0x1d, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f,
+    0x53, 0x54, 0x41, 0x47, 0x45, 0x5f, 0x52, 0x45, 0x43, 0x45, 0x49, 0x56, 0x45, 0x44, 0x10, 0x01,
+    0x12, 0x20, 0x0a, 0x1c, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f,
+    0x4f, 0x46, 0x5f, 0x53, 0x54, 0x41, 0x47, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x56, 0x49, 0x4e, 0x47,
+    0x10, 0x02, 0x12, 0x22, 0x0a, 0x1e, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50,
+    0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x53, 0x54, 0x41, 0x47, 0x45, 0x5f, 0x43, 0x4f, 0x4d, 0x50, 0x4c,
+    0x45, 0x54, 0x45, 0x44, 0x10, 0x03, 0x12, 0x1f, 0x0a, 0x1b, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41,
+    0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x53, 0x54, 0x41, 0x47, 0x45, 0x5f, 0x46,
+    0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x04, 0x2a, 0x96, 0x02, 0x0a, 0x16, 0x41, 0x67, 0x67, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69,
+    0x6e, 0x64, 0x12, 0x29, 0x0a, 0x25, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50,
+    0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x2e, 0x0a,
+    0x2a, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f,
+    0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x49, 0x4e, 0x56, 0x41, 0x4c,
+    0x49, 0x44, 0x5f, 0x41, 0x52, 0x47, 0x55, 0x4d, 0x45, 0x4e, 0x54, 0x10, 0x01, 0x12, 0x26, 0x0a,
+    0x22, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f,
+    0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x52, 0x45, 0x4a, 0x45, 0x43,
+    0x54, 0x45, 0x44, 0x10, 0x02, 0x12, 0x2c, 0x0a, 0x28, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49,
+    0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49,
+    0x4e, 0x44, 0x5f, 0x50, 0x52, 0x4f, 0x56, 0x49, 0x4e, 0x47, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45,
+    0x44, 0x10, 0x03, 0x12, 0x26, 0x0a, 0x22, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f,
+    0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44,
+    0x5f, 0x49, 0x4e, 0x54, 0x45, 0x52, 0x4e, 0x41, 0x4c, 0x10, 0x04, 0x12, 0x23, 0x0a, 0x1f, 0x41,
+    0x47, 0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x45, 0x52,
+    0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x53, 0x54, 0x41, 0x4c, 0x45, 0x10, 0x05,
+    0x2a, 0x7c, 0x0a, 0x11, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x23, 0x0a, 0x1f, 0x41, 0x47, 0x47, 0x43, 0x48, 0x41, 0x49,
+    0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x55, 0x4e, 0x53,
+    0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x1e, 0x0a, 0x1a, 0x41, 0x47,
+    0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44,
+    0x45, 0x5f, 0x4e, 0x4f, 0x52, 0x4d, 0x41, 0x4c, 0x10, 0x01, 0x12, 0x22, 0x0a, 0x1e, 0x41, 0x47,
+    0x47, 0x43, 0x48, 0x41, 0x49, 0x4e, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44,
+    0x45, 0x5f, 0x4f, 0x50, 0x54, 0x49, 0x4d, 0x49, 0x53, 0x54, 0x49, 0x43, 0x10, 0x02, 0x2a, 0x9c,
+    0x01, 0x0a, 0x0c, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65, 0x12,
+    0x1e, 0x0a, 0x1a, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44,
+    0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12,
+    0x17, 0x0a, 0x13, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44,
+    0x45, 0x5f, 0x43, 0x4f, 0x52, 0x45, 0x10, 0x01, 0x12, 0x1d, 0x0a, 0x19, 0x53, 0x50, 0x31, 0x5f,
+    0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x43, 0x4f, 0x4d, 0x50, 0x52,
+    0x45, 0x53, 0x53, 0x45, 0x44, 0x10, 0x02, 0x12, 0x1a, 0x0a, 0x16, 0x53, 0x50, 0x31, 0x5f, 0x50,
+    0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x47, 0x52, 0x4f, 0x54, 0x48, 0x31,
+    0x36, 0x10, 0x03, 0x12, 0x18, 0x0a, 0x14, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46,
+    0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x50, 0x4c, 0x4f, 0x4e, 0x4b, 0x10, 0x04, 0x32, 0xf9, 0x03,
+    0x0a, 0x14, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53,
+    0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x78, 0x0a, 0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61,
+    0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12,
+    0x2e, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e,
+    0x76, 0x32, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a,
+    0x2f, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e,
+    0x76, 0x32, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x12, 0x8c, 0x01, 0x0a, 0x1f, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x4f, 0x70, 0x74,
+    0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x12, 0x38, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72,
+    0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
+    0x4f, 0x70, 0x74, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x2f,
+    0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
+    0x32, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12,
+    0x76, 0x0a, 0x18, 0x57, 0x61, 0x74, 0x63, 0x68, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x31, 0x2e, 0x61, 0x67,
+    0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x57,
+    0x61, 0x74, 0x63, 0x68, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x25,
+    0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
+    0x32, 0x2e, 0x41, 0x67, 0x67, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53,
+    0x74, 0x61, 0x74, 0x75, 0x73, 0x30, 0x01, 0x12, 0x60, 0x0a, 0x0d, 0x47, 0x65, 0x74, 0x50, 0x72,
+    0x6f, 0x76, 0x65, 0x72, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x26, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69,
+    0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x32, 0x2e, 0x47, 0x65, 0x74, 0x50,
+    0x72, 0x6f, 0x76, 0x65, 0x72, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+    0x1a, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72,
+    0x2e, 0x76, 0x32, 0x2e, 0x47, 0x65, 0x74, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x49, 0x6e, 0x66,
+    0x6f, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x33,
+];
+include!("aggkit.prover.v2.serde.rs");
+include!("aggkit.prover.v2.tonic.rs");
@@ -0,0 +1,2179 @@
+// @generated
+impl serde::Serialize for AggchainProofError {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.kind != 0 {
+            len += 1;
+        }
+        if !self.message.is_empty() {
+            len += 1;
+        }
+        if !self.field_path.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.AggchainProofError", len)?;
+        if self.kind != 0 {
+            let v = AggchainProofErrorKind::try_from(self.kind)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.kind)))?;
+            struct_ser.serialize_field("kind", &v)?;
+        }
+        if !self.message.is_empty() {
+            struct_ser.serialize_field("message", &self.message)?;
+        }
+        if !self.field_path.is_empty() {
+            struct_ser.serialize_field("fieldPath", &self.field_path)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofError {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "kind",
+            "message",
+            "field_path",
+            "fieldPath",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Kind,
+            Message,
+            FieldPath,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "kind" => Ok(GeneratedField::Kind),
+                            "message" => Ok(GeneratedField::Message),
+                            "fieldPath" | "field_path" => Ok(GeneratedField::FieldPath),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofError;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.AggchainProofError")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainProofError, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut kind__ = None;
+                let mut message__ = None;
+                let mut field_path__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Kind => {
+                            if kind__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("kind"));
+                            }
+                            kind__ = Some(map_.next_value::<AggchainProofErrorKind>()? as i32);
+                        }
+                        GeneratedField::Message => {
+                            if message__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("message"));
+                            }
+                            message__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FieldPath => {
+                            if field_path__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fieldPath"));
+                            }
+                            field_path__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(AggchainProofError {
+                    kind: kind__.unwrap_or_default(),
+                    message: message__.unwrap_or_default(),
+                    field_path: field_path__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.AggchainProofError", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProofErrorKind {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "AGGCHAIN_PROOF_ERROR_KIND_UNSPECIFIED",
+            Self::InvalidArgument => "AGGCHAIN_PROOF_ERROR_KIND_INVALID_ARGUMENT",
+            Self::Rejected => "AGGCHAIN_PROOF_ERROR_KIND_REJECTED",
+            Self::ProvingFailed => "AGGCHAIN_PROOF_ERROR_KIND_PROVING_FAILED",
+            Self::Internal => "AGGCHAIN_PROOF_ERROR_KIND_INTERNAL",
+            Self::Stale => "AGGCHAIN_PROOF_ERROR_KIND_STALE",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofErrorKind {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "AGGCHAIN_PROOF_ERROR_KIND_UNSPECIFIED",
+            "AGGCHAIN_PROOF_ERROR_KIND_INVALID_ARGUMENT",
+            "AGGCHAIN_PROOF_ERROR_KIND_REJECTED",
+            "AGGCHAIN_PROOF_ERROR_KIND_PROVING_FAILED",
+            "AGGCHAIN_PROOF_ERROR_KIND_INTERNAL",
+            "AGGCHAIN_PROOF_ERROR_KIND_STALE",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofErrorKind;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "AGGCHAIN_PROOF_ERROR_KIND_UNSPECIFIED" => Ok(AggchainProofErrorKind::Unspecified),
+                    "AGGCHAIN_PROOF_ERROR_KIND_INVALID_ARGUMENT" => Ok(AggchainProofErrorKind::InvalidArgument),
+                    "AGGCHAIN_PROOF_ERROR_KIND_REJECTED" => Ok(AggchainProofErrorKind::Rejected),
+                    "AGGCHAIN_PROOF_ERROR_KIND_PROVING_FAILED" => Ok(AggchainProofErrorKind::ProvingFailed),
+                    "AGGCHAIN_PROOF_ERROR_KIND_INTERNAL" => Ok(AggchainProofErrorKind::Internal),
+                    "AGGCHAIN_PROOF_ERROR_KIND_STALE" => Ok(AggchainProofErrorKind::Stale),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProofMode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "AGGCHAIN_PROOF_MODE_UNSPECIFIED",
+            Self::Normal => "AGGCHAIN_PROOF_MODE_NORMAL",
+            Self::Optimistic => "AGGCHAIN_PROOF_MODE_OPTIMISTIC",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofMode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "AGGCHAIN_PROOF_MODE_UNSPECIFIED",
+            "AGGCHAIN_PROOF_MODE_NORMAL",
+            "AGGCHAIN_PROOF_MODE_OPTIMISTIC",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofMode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "AGGCHAIN_PROOF_MODE_UNSPECIFIED" => Ok(AggchainProofMode::Unspecified),
+                    "AGGCHAIN_PROOF_MODE_NORMAL" => Ok(AggchainProofMode::Normal),
+                    "AGGCHAIN_PROOF_MODE_OPTIMISTIC" => Ok(AggchainProofMode::Optimistic),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProofStage {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "AGGCHAIN_PROOF_STAGE_UNSPECIFIED",
+            Self::Received => "AGGCHAIN_PROOF_STAGE_RECEIVED",
+            Self::Proving => "AGGCHAIN_PROOF_STAGE_PROVING",
+            Self::Completed => "AGGCHAIN_PROOF_STAGE_COMPLETED",
+            Self::Failed => "AGGCHAIN_PROOF_STAGE_FAILED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofStage {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "AGGCHAIN_PROOF_STAGE_UNSPECIFIED",
+            "AGGCHAIN_PROOF_STAGE_RECEIVED",
+            "AGGCHAIN_PROOF_STAGE_PROVING",
+            "AGGCHAIN_PROOF_STAGE_COMPLETED",
+            "AGGCHAIN_PROOF_STAGE_FAILED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofStage;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "AGGCHAIN_PROOF_STAGE_UNSPECIFIED" => Ok(AggchainProofStage::Unspecified),
+                    "AGGCHAIN_PROOF_STAGE_RECEIVED" => Ok(AggchainProofStage::Received),
+                    "AGGCHAIN_PROOF_STAGE_PROVING" => Ok(AggchainProofStage::Proving),
+                    "AGGCHAIN_PROOF_STAGE_COMPLETED" => Ok(AggchainProofStage::Completed),
+                    "AGGCHAIN_PROOF_STAGE_FAILED" => Ok(AggchainProofStage::Failed),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AggchainProofStatus {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if self.stage != 0 {
+            len += 1;
+        }
+        if self.error.is_some() {
+            len += 1;
+        }
+        if self.queue_position != 0 {
+            len += 1;
+        }
+        if self.estimated_start_ms != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.AggchainProofStatus", len)?;
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if self.stage != 0 {
+            let v = AggchainProofStage::try_from(self.stage)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.stage)))?;
+            struct_ser.serialize_field("stage", &v)?;
+        }
+        if let Some(v) = self.error.as_ref() {
+            struct_ser.serialize_field("error", v)?;
+        }
+        if self.queue_position != 0 {
+            struct_ser.serialize_field("queuePosition", &self.queue_position)?;
+        }
+        if self.estimated_start_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("estimatedStartMs", ToString::to_string(&self.estimated_start_ms).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AggchainProofStatus {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "request_id",
+            "requestId",
+            "stage",
+            "error",
+            "queue_position",
+            "queuePosition",
+            "estimated_start_ms",
+            "estimatedStartMs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestId,
+            Stage,
+            Error,
+            QueuePosition,
+            EstimatedStartMs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "stage" => Ok(GeneratedField::Stage),
+                            "error" => Ok(GeneratedField::Error),
+                            "queuePosition" | "queue_position" => Ok(GeneratedField::QueuePosition),
+                            "estimatedStartMs" | "estimated_start_ms" => Ok(GeneratedField::EstimatedStartMs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AggchainProofStatus;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.AggchainProofStatus")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AggchainProofStatus, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut request_id__ = None;
+                let mut stage__ = None;
+                let mut error__ = None;
+                let mut queue_position__ = None;
+                let mut estimated_start_ms__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Stage => {
+                            if stage__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stage"));
+                            }
+                            stage__ = Some(map_.next_value::<AggchainProofStage>()? as i32);
+                        }
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
+                            }
+                            error__ = map_.next_value()?;
+                        }
+                        GeneratedField::QueuePosition => {
+                            if queue_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("queuePosition"));
+                            }
+                            queue_position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EstimatedStartMs => {
+                            if estimated_start_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("estimatedStartMs"));
+                            }
+                            estimated_start_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(AggchainProofStatus {
+                    request_id: request_id__.unwrap_or_default(),
+                    stage: stage__.unwrap_or_default(),
+                    error: error__,
+                    queue_position: queue_position__.unwrap_or_default(),
+                    estimated_start_ms: estimated_start_ms__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.AggchainProofStatus", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenerateAggchainProofRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if self.last_proven_block != 0 {
+            len += 1;
+        }
+        if self.requested_end_block != 0 {
+            len += 1;
+        }
+        if self.l1_info_tree_root_hash.is_some() {
+            len += 1;
+        }
+        if self.l1_info_tree_leaf.is_some() {
+            len += 1;
+        }
+        if self.l1_info_tree_merkle_proof.is_some() {
+            len += 1;
+        }
+        if !self.inserted_gers.is_empty() {
+            len += 1;
+        }
+        if !self.imported_bridge_exits.is_empty() {
+            len += 1;
+        }
+        if self.requested_vkey_hash.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.GenerateAggchainProofRequest", len)?;
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if self.last_proven_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("lastProvenBlock", ToString::to_string(&self.last_proven_block).as_str())?;
+        }
+        if self.requested_end_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("requestedEndBlock", ToString::to_string(&self.requested_end_block).as_str())?;
+        }
+        if let Some(v) = self.l1_info_tree_root_hash.as_ref() {
+            struct_ser.serialize_field("l1InfoTreeRootHash", v)?;
+        }
+        if let Some(v) = self.l1_info_tree_leaf.as_ref() {
+            struct_ser.serialize_field("l1InfoTreeLeaf", v)?;
+        }
+        if let Some(v) = self.l1_info_tree_merkle_proof.as_ref() {
+            struct_ser.serialize_field("l1InfoTreeMerkleProof", v)?;
+        }
+        if !self.inserted_gers.is_empty() {
+            struct_ser.serialize_field("insertedGers", &self.inserted_gers)?;
+        }
+        if !self.imported_bridge_exits.is_empty() {
+            struct_ser.serialize_field("importedBridgeExits", &self.imported_bridge_exits)?;
+        }
+        if let Some(v) = self.requested_vkey_hash.as_ref() {
+            struct_ser.serialize_field("requestedVkeyHash", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenerateAggchainProofRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "request_id",
+            "requestId",
+            "last_proven_block",
+            "lastProvenBlock",
+            "requested_end_block",
+            "requestedEndBlock",
+            "l1_info_tree_root_hash",
+            "l1InfoTreeRootHash",
+            "l1_info_tree_leaf",
+            "l1InfoTreeLeaf",
+            "l1_info_tree_merkle_proof",
+            "l1InfoTreeMerkleProof",
+            "inserted_gers",
+            "insertedGers",
+            "imported_bridge_exits",
+            "importedBridgeExits",
+            "requested_vkey_hash",
+            "requestedVkeyHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestId,
+            LastProvenBlock,
+            RequestedEndBlock,
+            L1InfoTreeRootHash,
+            L1InfoTreeLeaf,
+            L1InfoTreeMerkleProof,
+            InsertedGers,
+            ImportedBridgeExits,
+            RequestedVkeyHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "lastProvenBlock" | "last_proven_block" => Ok(GeneratedField::LastProvenBlock),
+                            "requestedEndBlock" | "requested_end_block" => Ok(GeneratedField::RequestedEndBlock),
+                            "l1InfoTreeRootHash" | "l1_info_tree_root_hash" => Ok(GeneratedField::L1InfoTreeRootHash),
+                            "l1InfoTreeLeaf" | "l1_info_tree_leaf" => Ok(GeneratedField::L1InfoTreeLeaf),
+                            "l1InfoTreeMerkleProof" | "l1_info_tree_merkle_proof" => Ok(GeneratedField::L1InfoTreeMerkleProof),
+                            "insertedGers" | "inserted_gers" => Ok(GeneratedField::InsertedGers),
+                            "importedBridgeExits" | "imported_bridge_exits" => Ok(GeneratedField::ImportedBridgeExits),
+                            "requestedVkeyHash" | "requested_vkey_hash" => Ok(GeneratedField::RequestedVkeyHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenerateAggchainProofRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.GenerateAggchainProofRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenerateAggchainProofRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut request_id__ = None;
+                let mut last_proven_block__ = None;
+                let mut requested_end_block__ = None;
+                let mut l1_info_tree_root_hash__ = None;
+                let mut l1_info_tree_leaf__ = None;
+                let mut l1_info_tree_merkle_proof__ = None;
+                let mut inserted_gers__ = None;
+                let mut imported_bridge_exits__ = None;
+                let mut requested_vkey_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::LastProvenBlock => {
+                            if last_proven_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastProvenBlock"));
+                            }
+                            last_proven_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RequestedEndBlock => {
+                            if requested_end_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedEndBlock"));
+                            }
+                            requested_end_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::L1InfoTreeRootHash => {
+                            if l1_info_tree_root_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoTreeRootHash"));
+                            }
+                            l1_info_tree_root_hash__ = map_.next_value()?;
+                        }
+                        GeneratedField::L1InfoTreeLeaf => {
+                            if l1_info_tree_leaf__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoTreeLeaf"));
+                            }
+                            l1_info_tree_leaf__ = map_.next_value()?;
+                        }
+                        GeneratedField::L1InfoTreeMerkleProof => {
+                            if l1_info_tree_merkle_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1InfoTreeMerkleProof"));
+                            }
+                            l1_info_tree_merkle_proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::InsertedGers => {
+                            if inserted_gers__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("insertedGers"));
+                            }
+                            inserted_gers__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ImportedBridgeExits => {
+                            if imported_bridge_exits__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("importedBridgeExits"));
+                            }
+                            imported_bridge_exits__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RequestedVkeyHash => {
+                            if requested_vkey_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedVkeyHash"));
+                            }
+                            requested_vkey_hash__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(GenerateAggchainProofRequest {
+                    request_id: request_id__.unwrap_or_default(),
+                    last_proven_block: last_proven_block__.unwrap_or_default(),
+                    requested_end_block: requested_end_block__.unwrap_or_default(),
+                    l1_info_tree_root_hash: l1_info_tree_root_hash__,
+                    l1_info_tree_leaf: l1_info_tree_leaf__,
+                    l1_info_tree_merkle_proof: l1_info_tree_merkle_proof__,
+                    inserted_gers: inserted_gers__.unwrap_or_default(),
+                    imported_bridge_exits: imported_bridge_exits__.unwrap_or_default(),
+                    requested_vkey_hash: requested_vkey_hash__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.GenerateAggchainProofRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenerateAggchainProofResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        if self.aggchain_proof.is_some() {
+            len += 1;
+        }
+        if self.last_proven_block != 0 {
+            len += 1;
+        }
+        if self.end_block != 0 {
+            len += 1;
+        }
+        if self.local_exit_root_hash.is_some() {
+            len += 1;
+        }
+        if !self.custom_chain_data.is_empty() {
+            len += 1;
+        }
+        if !self.proof_envelope.is_empty() {
+            len += 1;
+        }
+        if self.sp1_proof_mode != 0 {
+            len += 1;
+        }
+        if self.requested_end_block != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.GenerateAggchainProofResponse", len)?;
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        if let Some(v) = self.aggchain_proof.as_ref() {
+            struct_ser.serialize_field("aggchainProof", v)?;
+        }
+        if self.last_proven_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("lastProvenBlock", ToString::to_string(&self.last_proven_block).as_str())?;
+        }
+        if self.end_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endBlock", ToString::to_string(&self.end_block).as_str())?;
+        }
+        if let Some(v) = self.local_exit_root_hash.as_ref() {
+            struct_ser.serialize_field("localExitRootHash", v)?;
+        }
+        if !self.custom_chain_data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("customChainData", pbjson::private::base64::encode(&self.custom_chain_data).as_str())?;
+        }
+        if !self.proof_envelope.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proofEnvelope", pbjson::private::base64::encode(&self.proof_envelope).as_str())?;
+        }
+        if self.sp1_proof_mode != 0 {
+            let v = Sp1ProofMode::try_from(self.sp1_proof_mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.sp1_proof_mode)))?;
+            struct_ser.serialize_field("sp1ProofMode", &v)?;
+        }
+        if self.requested_end_block != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("requestedEndBlock", ToString::to_string(&self.requested_end_block).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenerateAggchainProofResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "request_id",
+            "requestId",
+            "aggchain_proof",
+            "aggchainProof",
+            "last_proven_block",
+            "lastProvenBlock",
+            "end_block",
+            "endBlock",
+            "local_exit_root_hash",
+            "localExitRootHash",
+            "custom_chain_data",
+            "customChainData",
+            "proof_envelope",
+            "proofEnvelope",
+            "sp1_proof_mode",
+            "sp1ProofMode",
+            "requested_end_block",
+            "requestedEndBlock",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestId,
+            AggchainProof,
+            LastProvenBlock,
+            EndBlock,
+            LocalExitRootHash,
+            CustomChainData,
+            ProofEnvelope,
+            Sp1ProofMode,
+            RequestedEndBlock,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            "aggchainProof" | "aggchain_proof" => Ok(GeneratedField::AggchainProof),
+                            "lastProvenBlock" | "last_proven_block" => Ok(GeneratedField::LastProvenBlock),
+                            "endBlock" | "end_block" => Ok(GeneratedField::EndBlock),
+                            "localExitRootHash" | "local_exit_root_hash" => Ok(GeneratedField::LocalExitRootHash),
+                            "customChainData" | "custom_chain_data" => Ok(GeneratedField::CustomChainData),
+                            "proofEnvelope" | "proof_envelope" => Ok(GeneratedField::ProofEnvelope),
+                            "sp1ProofMode" | "sp1_proof_mode" => Ok(GeneratedField::Sp1ProofMode),
+                            "requestedEndBlock" | "requested_end_block" => Ok(GeneratedField::RequestedEndBlock),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenerateAggchainProofResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.GenerateAggchainProofResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenerateAggchainProofResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut request_id__ = None;
+                let mut aggchain_proof__ = None;
+                let mut last_proven_block__ = None;
+                let mut end_block__ = None;
+                let mut local_exit_root_hash__ = None;
+                let mut custom_chain_data__ = None;
+                let mut proof_envelope__ = None;
+                let mut sp1_proof_mode__ = None;
+                let mut requested_end_block__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AggchainProof => {
+                            if aggchain_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainProof"));
+                            }
+                            aggchain_proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::LastProvenBlock => {
+                            if last_proven_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastProvenBlock"));
+                            }
+                            last_proven_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndBlock => {
+                            if end_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endBlock"));
+                            }
+                            end_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LocalExitRootHash => {
+                            if local_exit_root_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("localExitRootHash"));
+                            }
+                            local_exit_root_hash__ = map_.next_value()?;
+                        }
+                        GeneratedField::CustomChainData => {
+                            if custom_chain_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("customChainData"));
+                            }
+                            custom_chain_data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofEnvelope => {
+                            if proof_envelope__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofEnvelope"));
+                            }
+                            proof_envelope__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sp1ProofMode => {
+                            if sp1_proof_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sp1ProofMode"));
+                            }
+                            sp1_proof_mode__ = Some(map_.next_value::<Sp1ProofMode>()? as i32);
+                        }
+                        GeneratedField::RequestedEndBlock => {
+                            if requested_end_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestedEndBlock"));
+                            }
+                            requested_end_block__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GenerateAggchainProofResponse {
+                    request_id: request_id__.unwrap_or_default(),
+                    aggchain_proof: aggchain_proof__,
+                    last_proven_block: last_proven_block__.unwrap_or_default(),
+                    end_block: end_block__.unwrap_or_default(),
+                    local_exit_root_hash: local_exit_root_hash__,
+                    custom_chain_data: custom_chain_data__.unwrap_or_default(),
+                    proof_envelope: proof_envelope__.unwrap_or_default(),
+                    sp1_proof_mode: sp1_proof_mode__.unwrap_or_default(),
+                    requested_end_block: requested_end_block__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.GenerateAggchainProofResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenerateOptimisticAggchainProofRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.aggchain_proof_request.is_some() {
+            len += 1;
+        }
+        if self.optimistic_mode_signature.is_some() {
+            len += 1;
+        }
+        if self.network_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.GenerateOptimisticAggchainProofRequest", len)?;
+        if let Some(v) = self.aggchain_proof_request.as_ref() {
+            struct_ser.serialize_field("aggchainProofRequest", v)?;
+        }
+        if let Some(v) = self.optimistic_mode_signature.as_ref() {
+            struct_ser.serialize_field("optimisticModeSignature", v)?;
+        }
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenerateOptimisticAggchainProofRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "aggchain_proof_request",
+            "aggchainProofRequest",
+            "optimistic_mode_signature",
+            "optimisticModeSignature",
+            "network_id",
+            "networkId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AggchainProofRequest,
+            OptimisticModeSignature,
+            NetworkId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "aggchainProofRequest" | "aggchain_proof_request" => Ok(GeneratedField::AggchainProofRequest),
+                            "optimisticModeSignature" | "optimistic_mode_signature" => Ok(GeneratedField::OptimisticModeSignature),
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenerateOptimisticAggchainProofRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.GenerateOptimisticAggchainProofRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenerateOptimisticAggchainProofRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut aggchain_proof_request__ = None;
+                let mut optimistic_mode_signature__ = None;
+                let mut network_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AggchainProofRequest => {
+                            if aggchain_proof_request__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("aggchainProofRequest"));
+                            }
+                            aggchain_proof_request__ = map_.next_value()?;
+                        }
+                        GeneratedField::OptimisticModeSignature => {
+                            if optimistic_mode_signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("optimisticModeSignature"));
+                            }
+                            optimistic_mode_signature__ = map_.next_value()?;
+                        }
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(GenerateOptimisticAggchainProofRequest {
+                    aggchain_proof_request: aggchain_proof_request__,
+                    optimistic_mode_signature: optimistic_mode_signature__,
+                    network_id: network_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.GenerateOptimisticAggchainProofRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetProverInfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.client_version.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.GetProverInfoRequest", len)?;
+        if !self.client_version.is_empty() {
+            struct_ser.serialize_field("clientVersion", &self.client_version)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetProverInfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "client_version",
+            "clientVersion",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ClientVersion,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "clientVersion" | "client_version" => Ok(GeneratedField::ClientVersion),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetProverInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.GetProverInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetProverInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut client_version__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ClientVersion => {
+                            if client_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientVersion"));
+                            }
+                            client_version__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetProverInfoRequest {
+                    client_version: client_version__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.GetProverInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GetProverInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.version.is_empty() {
+            len += 1;
+        }
+        if !self.api_versions.is_empty() {
+            len += 1;
+        }
+        if !self.proof_modes.is_empty() {
+            len += 1;
+        }
+        if !self.sp1_version.is_empty() {
+            len += 1;
+        }
+        if !self.programs.is_empty() {
+            len += 1;
+        }
+        if self.rate_limit.is_some() {
+            len += 1;
+        }
+        if !self.min_client_version.is_empty() {
+            len += 1;
+        }
+        if self.client_supported {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.GetProverInfoResponse", len)?;
+        if !self.version.is_empty() {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if !self.api_versions.is_empty() {
+            struct_ser.serialize_field("apiVersions", &self.api_versions)?;
+        }
+        if !self.proof_modes.is_empty() {
+            let v = self.proof_modes.iter().cloned().map(|v| {
+                AggchainProofMode::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("proofModes", &v)?;
+        }
+        if !self.sp1_version.is_empty() {
+            struct_ser.serialize_field("sp1Version", &self.sp1_version)?;
+        }
+        if !self.programs.is_empty() {
+            struct_ser.serialize_field("programs", &self.programs)?;
+        }
+        if let Some(v) = self.rate_limit.as_ref() {
+            struct_ser.serialize_field("rateLimit", v)?;
+        }
+        if !self.min_client_version.is_empty() {
+            struct_ser.serialize_field("minClientVersion", &self.min_client_version)?;
+        }
+        if self.client_supported {
+            struct_ser.serialize_field("clientSupported", &self.client_supported)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GetProverInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "version",
+            "api_versions",
+            "apiVersions",
+            "proof_modes",
+            "proofModes",
+            "sp1_version",
+            "sp1Version",
+            "programs",
+            "rate_limit",
+            "rateLimit",
+            "min_client_version",
+            "minClientVersion",
+            "client_supported",
+            "clientSupported",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Version,
+            ApiVersions,
+            ProofModes,
+            Sp1Version,
+            Programs,
+            RateLimit,
+            MinClientVersion,
+            ClientSupported,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "version" => Ok(GeneratedField::Version),
+                            "apiVersions" | "api_versions" => Ok(GeneratedField::ApiVersions),
+                            "proofModes" | "proof_modes" => Ok(GeneratedField::ProofModes),
+                            "sp1Version" | "sp1_version" => Ok(GeneratedField::Sp1Version),
+                            "programs" => Ok(GeneratedField::Programs),
+                            "rateLimit" | "rate_limit" => Ok(GeneratedField::RateLimit),
+                            "minClientVersion" | "min_client_version" => Ok(GeneratedField::MinClientVersion),
+                            "clientSupported" | "client_supported" => Ok(GeneratedField::ClientSupported),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GetProverInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.GetProverInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetProverInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut version__ = None;
+                let mut api_versions__ = None;
+                let mut proof_modes__ = None;
+                let mut sp1_version__ = None;
+                let mut programs__ = None;
+                let mut rate_limit__ = None;
+                let mut min_client_version__ = None;
+                let mut client_supported__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
+                            }
+                            version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ApiVersions => {
+                            if api_versions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("apiVersions"));
+                            }
+                            api_versions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ProofModes => {
+                            if proof_modes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofModes"));
+                            }
+                            proof_modes__ = Some(map_.next_value::<Vec<AggchainProofMode>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::Sp1Version => {
+                            if sp1_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sp1Version"));
+                            }
+                            sp1_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Programs => {
+                            if programs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("programs"));
+                            }
+                            programs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::RateLimit => {
+                            if rate_limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateLimit"));
+                            }
+                            rate_limit__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinClientVersion => {
+                            if min_client_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minClientVersion"));
+                            }
+                            min_client_version__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ClientSupported => {
+                            if client_supported__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("clientSupported"));
+                            }
+                            client_supported__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(GetProverInfoResponse {
+                    version: version__.unwrap_or_default(),
+                    api_versions: api_versions__.unwrap_or_default(),
+                    proof_modes: proof_modes__.unwrap_or_default(),
+                    sp1_version: sp1_version__.unwrap_or_default(),
+                    programs: programs__.unwrap_or_default(),
+                    rate_limit: rate_limit__,
+                    min_client_version: min_client_version__.unwrap_or_default(),
+                    client_supported: client_supported__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.GetProverInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ImportedBridgeExitWithBlockNumber {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_number != 0 {
+            len += 1;
+        }
+        if self.global_index.is_some() {
+            len += 1;
+        }
+        if self.bridge_exit_hash.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.ImportedBridgeExitWithBlockNumber", len)?;
+        if self.block_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("blockNumber", ToString::to_string(&self.block_number).as_str())?;
+        }
+        if let Some(v) = self.global_index.as_ref() {
+            struct_ser.serialize_field("globalIndex", v)?;
+        }
+        if let Some(v) = self.bridge_exit_hash.as_ref() {
+            struct_ser.serialize_field("bridgeExitHash", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ImportedBridgeExitWithBlockNumber {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_number",
+            "blockNumber",
+            "global_index",
+            "globalIndex",
+            "bridge_exit_hash",
+            "bridgeExitHash",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockNumber,
+            GlobalIndex,
+            BridgeExitHash,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockNumber" | "block_number" => Ok(GeneratedField::BlockNumber),
+                            "globalIndex" | "global_index" => Ok(GeneratedField::GlobalIndex),
+                            "bridgeExitHash" | "bridge_exit_hash" => Ok(GeneratedField::BridgeExitHash),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ImportedBridgeExitWithBlockNumber;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.ImportedBridgeExitWithBlockNumber")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ImportedBridgeExitWithBlockNumber, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_number__ = None;
+                let mut global_index__ = None;
+                let mut bridge_exit_hash__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockNumber => {
+                            if block_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockNumber"));
+                            }
+                            block_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::GlobalIndex => {
+                            if global_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("globalIndex"));
+                            }
+                            global_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::BridgeExitHash => {
+                            if bridge_exit_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeExitHash"));
+                            }
+                            bridge_exit_hash__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ImportedBridgeExitWithBlockNumber {
+                    block_number: block_number__.unwrap_or_default(),
+                    global_index: global_index__,
+                    bridge_exit_hash: bridge_exit_hash__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.ImportedBridgeExitWithBlockNumber", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProvenInsertedGer {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.ger.is_some() {
+            len += 1;
+        }
+        if self.block_number != 0 {
+            len += 1;
+        }
+        if self.block_index != 0 {
+            len += 1;
+        }
+        if self.proof_ger_l1root.is_some() {
+            len += 1;
+        }
+        if self.l1_leaf.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.ProvenInsertedGer", len)?;
+        if let Some(v) = self.ger.as_ref() {
+            struct_ser.serialize_field("ger", v)?;
+        }
+        if self.block_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("blockNumber", ToString::to_string(&self.block_number).as_str())?;
+        }
+        if self.block_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("blockIndex", ToString::to_string(&self.block_index).as_str())?;
+        }
+        if let Some(v) = self.proof_ger_l1root.as_ref() {
+            struct_ser.serialize_field("proofGerL1root", v)?;
+        }
+        if let Some(v) = self.l1_leaf.as_ref() {
+            struct_ser.serialize_field("l1Leaf", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProvenInsertedGer {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "ger",
+            "block_number",
+            "blockNumber",
+            "block_index",
+            "blockIndex",
+            "proof_ger_l1root",
+            "proofGerL1root",
+            "l1_leaf",
+            "l1Leaf",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Ger,
+            BlockNumber,
+            BlockIndex,
+            ProofGerL1root,
+            L1Leaf,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "ger" => Ok(GeneratedField::Ger),
+                            "blockNumber" | "block_number" => Ok(GeneratedField::BlockNumber),
+                            "blockIndex" | "block_index" => Ok(GeneratedField::BlockIndex),
+                            "proofGerL1root" | "proof_ger_l1root" => Ok(GeneratedField::ProofGerL1root),
+                            "l1Leaf" | "l1_leaf" => Ok(GeneratedField::L1Leaf),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProvenInsertedGer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.ProvenInsertedGer")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProvenInsertedGer, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut ger__ = None;
+                let mut block_number__ = None;
+                let mut block_index__ = None;
+                let mut proof_ger_l1root__ = None;
+                let mut l1_leaf__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Ger => {
+                            if ger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ger"));
+                            }
+                            ger__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockNumber => {
+                            if block_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockNumber"));
+                            }
+                            block_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BlockIndex => {
+                            if block_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockIndex"));
+                            }
+                            block_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ProofGerL1root => {
+                            if proof_ger_l1root__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofGerL1root"));
+                            }
+                            proof_ger_l1root__ = map_.next_value()?;
+                        }
+                        GeneratedField::L1Leaf => {
+                            if l1_leaf__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("l1Leaf"));
+                            }
+                            l1_leaf__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(ProvenInsertedGer {
+                    ger: ger__,
+                    block_number: block_number__.unwrap_or_default(),
+                    block_index: block_index__.unwrap_or_default(),
+                    proof_ger_l1root: proof_ger_l1root__,
+                    l1_leaf: l1_leaf__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.ProvenInsertedGer", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RateLimitPolicy {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.requests != 0 {
+            len += 1;
+        }
+        if self.period_ms != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.RateLimitPolicy", len)?;
+        if self.requests != 0 {
+            struct_ser.serialize_field("requests", &self.requests)?;
+        }
+        if self.period_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("periodMs", ToString::to_string(&self.period_ms).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RateLimitPolicy {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "requests",
+            "period_ms",
+            "periodMs",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Requests,
+            PeriodMs,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requests" => Ok(GeneratedField::Requests),
+                            "periodMs" | "period_ms" => Ok(GeneratedField::PeriodMs),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RateLimitPolicy;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.RateLimitPolicy")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RateLimitPolicy, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut requests__ = None;
+                let mut period_ms__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Requests => {
+                            if requests__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requests"));
+                            }
+                            requests__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PeriodMs => {
+                            if period_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("periodMs"));
+                            }
+                            period_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(RateLimitPolicy {
+                    requests: requests__.unwrap_or_default(),
+                    period_ms: period_ms__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.RateLimitPolicy", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ServedProgram {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.vkey_hash.is_some() {
+            len += 1;
+        }
+        if self.current {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.ServedProgram", len)?;
+        if let Some(v) = self.vkey_hash.as_ref() {
+            struct_ser.serialize_field("vkeyHash", v)?;
+        }
+        if self.current {
+            struct_ser.serialize_field("current", &self.current)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ServedProgram {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "vkey_hash",
+            "vkeyHash",
+            "current",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            VkeyHash,
+            Current,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "vkeyHash" | "vkey_hash" => Ok(GeneratedField::VkeyHash),
+                            "current" => Ok(GeneratedField::Current),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ServedProgram;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.ServedProgram")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ServedProgram, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut vkey_hash__ = None;
+                let mut current__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::VkeyHash => {
+                            if vkey_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("vkeyHash"));
+                            }
+                            vkey_hash__ = map_.next_value()?;
+                        }
+                        GeneratedField::Current => {
+                            if current__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("current"));
+                            }
+                            current__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(ServedProgram {
+                    vkey_hash: vkey_hash__,
+                    current: current__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.ServedProgram", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Sp1ProofMode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SP1_PROOF_MODE_UNSPECIFIED",
+            Self::Core => "SP1_PROOF_MODE_CORE",
+            Self::Compressed => "SP1_PROOF_MODE_COMPRESSED",
+            Self::Groth16 => "SP1_PROOF_MODE_GROTH16",
+            Self::Plonk => "SP1_PROOF_MODE_PLONK",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Sp1ProofMode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SP1_PROOF_MODE_UNSPECIFIED",
+            "SP1_PROOF_MODE_CORE",
+            "SP1_PROOF_MODE_COMPRESSED",
+            "SP1_PROOF_MODE_GROTH16",
+            "SP1_PROOF_MODE_PLONK",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Sp1ProofMode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SP1_PROOF_MODE_UNSPECIFIED" => Ok(Sp1ProofMode::Unspecified),
+                    "SP1_PROOF_MODE_CORE" => Ok(Sp1ProofMode::Core),
+                    "SP1_PROOF_MODE_COMPRESSED" => Ok(Sp1ProofMode::Compressed),
+                    "SP1_PROOF_MODE_GROTH16" => Ok(Sp1ProofMode::Groth16),
+                    "SP1_PROOF_MODE_PLONK" => Ok(Sp1ProofMode::Plonk),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WatchAggchainProofStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.request_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aggkit.prover.v2.WatchAggchainProofStatusRequest", len)?;
+        if !self.request_id.is_empty() {
+            struct_ser.serialize_field("requestId", &self.request_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WatchAggchainProofStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "request_id",
+            "requestId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RequestId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "requestId" | "request_id" => Ok(GeneratedField::RequestId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WatchAggchainProofStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aggkit.prover.v2.WatchAggchainProofStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WatchAggchainProofStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut request_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RequestId => {
+                            if request_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("requestId"));
+                            }
+                            request_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(WatchAggchainProofStatusRequest {
+                    request_id: request_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aggkit.prover.v2.WatchAggchainProofStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
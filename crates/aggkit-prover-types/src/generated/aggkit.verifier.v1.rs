@@ -0,0 +1,110 @@
+// @generated
+// This file is @generated by prost-build.
+/// The request message for verifying a proof.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyProofRequest {
+    /// Proof envelope, encoded with bincode or as JSON.
+    #[prost(bytes="bytes", tag="1")]
+    pub proof_envelope: ::prost::bytes::Bytes,
+    /// Expected public values, checked against the ones of the proof when set.
+    #[prost(bytes="bytes", tag="2")]
+    pub public_values: ::prost::bytes::Bytes,
+}
+/// The verdict on one proof.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VerifyProofResponse {
+    /// The verdict.
+    #[prost(enumeration="ProofVerdict", tag="1")]
+    pub verdict: i32,
+    /// Name of the verifying key of the program which produced the proof, if known.
+    #[prost(string, tag="2")]
+    pub vkey_name: ::prost::alloc::string::String,
+    /// Human readable reason of the verdict, when the proof is not valid.
+    #[prost(string, tag="3")]
+    pub message: ::prost::alloc::string::String,
+}
+/// The verdict on one proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProofVerdict {
+    /// Unspecified verdict.
+    Unspecified = 0,
+    /// The proof is valid.
+    Valid = 1,
+    /// The proof does not verify against the verifying key of its program.
+    Invalid = 2,
+    /// The proof has been produced by a program whose verifying key is not configured.
+    UnknownVkey = 3,
+    /// The public values of the proof differ from the expected ones.
+    PublicValuesMismatch = 4,
+}
+impl ProofVerdict {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PROOF_VERDICT_UNSPECIFIED",
+            Self::Valid => "PROOF_VERDICT_VALID",
+            Self::Invalid => "PROOF_VERDICT_INVALID",
+            Self::UnknownVkey => "PROOF_VERDICT_UNKNOWN_VKEY",
+            Self::PublicValuesMismatch => "PROOF_VERDICT_PUBLIC_VALUES_MISMATCH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PROOF_VERDICT_UNSPECIFIED" => Some(Self::Unspecified),
+            "PROOF_VERDICT_VALID" => Some(Self::Valid),
+            "PROOF_VERDICT_INVALID" => Some(Self::Invalid),
+            "PROOF_VERDICT_UNKNOWN_VKEY" => Some(Self::UnknownVkey),
+            "PROOF_VERDICT_PUBLIC_VALUES_MISMATCH" => Some(Self::PublicValuesMismatch),
+            _ => None,
+        }
+    }
+}
+/// Encoded file descriptor set for the `aggkit.verifier.v1` package
+pub const FILE_DESCRIPTOR_SET: &[u8] = &[
+    0x0a, 0xe0, 0x04, 0x0a, 0x2b, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2f, 0x76, 0x65, 0x72, 0x69,
+    0x66, 0x69, 0x65, 0x72, 0x2f, 0x76, 0x31, 0x2f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x76, 0x65,
+    0x72, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x12, 0x12, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65,
+    0x72, 0x2e, 0x76, 0x31, 0x22, 0x60, 0x0a, 0x12, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x5f, 0x65, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x45, 0x6e, 0x76, 0x65, 0x6c, 0x6f, 0x70,
+    0x65, 0x12, 0x23, 0x0a, 0x0d, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x76, 0x61, 0x6c, 0x75,
+    0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63,
+    0x56, 0x61, 0x6c, 0x75, 0x65, 0x73, 0x22, 0x88, 0x01, 0x0a, 0x13, 0x56, 0x65, 0x72, 0x69, 0x66,
+    0x79, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a,
+    0x0a, 0x07, 0x76, 0x65, 0x72, 0x64, 0x69, 0x63, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x20, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65,
+    0x72, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x56, 0x65, 0x72, 0x64, 0x69, 0x63,
+    0x74, 0x52, 0x07, 0x76, 0x65, 0x72, 0x64, 0x69, 0x63, 0x74, 0x12, 0x1b, 0x0a, 0x09, 0x76, 0x6b,
+    0x65, 0x79, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x76,
+    0x6b, 0x65, 0x79, 0x4e, 0x61, 0x6d, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67,
+    0x65, 0x2a, 0xab, 0x01, 0x0a, 0x0c, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x56, 0x65, 0x72, 0x64, 0x69,
+    0x63, 0x74, 0x12, 0x1d, 0x0a, 0x19, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x56, 0x45, 0x52, 0x44,
+    0x49, 0x43, 0x54, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10,
+    0x00, 0x12, 0x17, 0x0a, 0x13, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x56, 0x45, 0x52, 0x44, 0x49,
+    0x43, 0x54, 0x5f, 0x56, 0x41, 0x4c, 0x49, 0x44, 0x10, 0x01, 0x12, 0x19, 0x0a, 0x15, 0x50, 0x52,
+    0x4f, 0x4f, 0x46, 0x5f, 0x56, 0x45, 0x52, 0x44, 0x49, 0x43, 0x54, 0x5f, 0x49, 0x4e, 0x56, 0x41,
+    0x4c, 0x49, 0x44, 0x10, 0x02, 0x12, 0x1e, 0x0a, 0x1a, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x56,
+    0x45, 0x52, 0x44, 0x49, 0x43, 0x54, 0x5f, 0x55, 0x4e, 0x4b, 0x4e, 0x4f, 0x57, 0x4e, 0x5f, 0x56,
+    0x4b, 0x45, 0x59, 0x10, 0x03, 0x12, 0x28, 0x0a, 0x24, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x56,
+    0x45, 0x52, 0x44, 0x49, 0x43, 0x54, 0x5f, 0x50, 0x55, 0x42, 0x4c, 0x49, 0x43, 0x5f, 0x56, 0x41,
+    0x4c, 0x55, 0x45, 0x53, 0x5f, 0x4d, 0x49, 0x53, 0x4d, 0x41, 0x54, 0x43, 0x48, 0x10, 0x04, 0x32,
+    0x7a, 0x0a, 0x18, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x56, 0x65, 0x72, 0x69, 0x66, 0x69, 0x63, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x5e, 0x0a, 0x0b, 0x56,
+    0x65, 0x72, 0x69, 0x66, 0x79, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x26, 0x2e, 0x61, 0x67, 0x67,
+    0x6b, 0x69, 0x74, 0x2e, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e,
+    0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x1a, 0x27, 0x2e, 0x61, 0x67, 0x67, 0x6b, 0x69, 0x74, 0x2e, 0x76, 0x65, 0x72, 0x69,
+    0x66, 0x69, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x56, 0x65, 0x72, 0x69, 0x66, 0x79, 0x50, 0x72,
+    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x62, 0x06, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x33,
+];
+include!("aggkit.verifier.v1.serde.rs");
+include!("aggkit.verifier.v1.tonic.rs");
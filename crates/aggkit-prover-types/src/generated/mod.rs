@@ -6,5 +6,17 @@ pub mod aggkit {
             include!("aggkit.prover.v1.rs");
             // @@protoc_insertion_point(aggkit.prover.v1)
         }
+        // @@protoc_insertion_point(attribute:aggkit.prover.v2)
+        pub mod v2 {
+            include!("aggkit.prover.v2.rs");
+            // @@protoc_insertion_point(aggkit.prover.v2)
+        }
+    }
+    pub mod verifier {
+        // @@protoc_insertion_point(attribute:aggkit.verifier.v1)
+        pub mod v1 {
+            include!("aggkit.verifier.v1.rs");
+            // @@protoc_insertion_point(aggkit.verifier.v1)
+        }
     }
 }
@@ -2,10 +2,25 @@
 #[rustfmt::skip]
 #[allow(warnings)]
 pub mod v1;
+#[path = "generated/aggkit.prover.v2.rs"]
+#[rustfmt::skip]
+#[allow(warnings)]
+pub mod v2;
+#[path = "generated/aggkit.verifier.v1.rs"]
+#[rustfmt::skip]
+#[allow(warnings)]
+pub mod verifier;
+pub mod codec;
+pub mod compression;
 pub mod conversion;
+pub mod envelope;
 pub mod error;
+pub mod public_values;
+pub mod stage_timings;
+pub mod validation;
 #[cfg(feature = "sp1")]
 pub mod vkey;
 pub mod vkey_hash;
+pub mod wire_format;
 
 pub use agglayer_interop::types::{bincode, Digest};
@@ -0,0 +1,211 @@
+//! Decoding of the public values committed by the proofs, for the explorers
+//! and the verify-only mode to render the content of a proof without linking
+//! the programs.
+//!
+//! Each program commits its public values with its own encoding. The kind of
+//! some public values is detected by decoding them with each known layout,
+//! only the layout encoding the exact same bytes back being accepted.
+use agglayer_interop::types::{bincode, Digest};
+use alloy_sol_types::{sol, SolType as _};
+use serde::{Deserialize, Serialize};
+pub use unified_bridge::AggchainProofPublicValues;
+
+sol! {
+    /// Public values of the aggregation proof of the full execution of the L2
+    /// blocks, ABI-encoded by the aggregation program.
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+    #[serde(rename_all = "kebab-case")]
+    struct FepPublicValues {
+        bytes32 l1_head;
+        bytes32 l2_pre_root;
+        bytes32 l2_post_root;
+        uint64 l2_block_number;
+        bytes32 rollup_config_hash;
+        bytes32 multi_block_vkey;
+        address prover_address;
+    }
+}
+
+/// Outputs of the pessimistic proof, encoded with the bincode options of the
+/// contracts.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct PessimisticProofOutput {
+    pub prev_local_exit_root: Digest,
+    pub prev_pessimistic_root: Digest,
+    pub l1_info_root: Digest,
+    pub origin_network: u32,
+    pub aggchain_hash: Digest,
+    pub new_local_exit_root: Digest,
+    pub new_pessimistic_root: Digest,
+}
+
+/// Program which committed some public values.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PublicValuesKind {
+    /// Aggregation program of the full execution proofs.
+    Fep,
+    /// Aggchain proof program.
+    Aggchain,
+    /// Pessimistic proof program.
+    Pessimistic,
+}
+
+impl PublicValuesKind {
+    pub const ALL: [Self; 3] = [Self::Fep, Self::Aggchain, Self::Pessimistic];
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PublicValuesError {
+    #[error("The public values are not the ones of a {0:?} proof")]
+    Mismatch(PublicValuesKind),
+
+    #[error("The public values are not the ones of a known proof")]
+    Unknown,
+}
+
+/// Public values of a proof, decoded by the program which committed them.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "kind", content = "values", rename_all = "kebab-case")]
+pub enum PublicValues {
+    Fep(FepPublicValues),
+    Aggchain(AggchainProofPublicValues),
+    Pessimistic(PessimisticProofOutput),
+}
+
+impl PublicValues {
+    /// Decodes public values of an unknown kind, detected from their layout.
+    pub fn decode(bytes: &[u8]) -> Result<Self, PublicValuesError> {
+        PublicValuesKind::ALL
+            .into_iter()
+            .find_map(|kind| Self::decode_as(kind, bytes).ok())
+            .ok_or(PublicValuesError::Unknown)
+    }
+
+    /// Decodes public values committed by the given kind of program.
+    pub fn decode_as(kind: PublicValuesKind, bytes: &[u8]) -> Result<Self, PublicValuesError> {
+        let decoded = match kind {
+            PublicValuesKind::Fep => FepPublicValues::abi_decode(bytes).ok().map(Self::Fep),
+            PublicValuesKind::Aggchain => {
+                bincode::sp1v4().deserialize(bytes).ok().map(Self::Aggchain)
+            }
+            PublicValuesKind::Pessimistic => bincode::contracts()
+                .deserialize(bytes)
+                .ok()
+                .map(Self::Pessimistic),
+        };
+
+        // Bytes left over, or decoded leniently, belong to another layout.
+        decoded
+            .filter(|decoded| decoded.encode().as_deref() == Some(bytes))
+            .ok_or(PublicValuesError::Mismatch(kind))
+    }
+
+    pub fn kind(&self) -> PublicValuesKind {
+        match self {
+            Self::Fep(_) => PublicValuesKind::Fep,
+            Self::Aggchain(_) => PublicValuesKind::Aggchain,
+            Self::Pessimistic(_) => PublicValuesKind::Pessimistic,
+        }
+    }
+
+    /// Encodes the public values as committed by their program.
+    pub fn encode(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::Fep(values) => Some(FepPublicValues::abi_encode(values)),
+            Self::Aggchain(values) => bincode::sp1v4().serialize(values).ok(),
+            Self::Pessimistic(output) => bincode::contracts().serialize(output).ok(),
+        }
+    }
+}
+
+#[cfg(feature = "sp1")]
+impl TryFrom<&sp1_sdk::SP1PublicValues> for PublicValues {
+    type Error = PublicValuesError;
+
+    fn try_from(public_values: &sp1_sdk::SP1PublicValues) -> Result<Self, Self::Error> {
+        Self::decode(public_values.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, B256};
+
+    use super::*;
+
+    fn fep() -> FepPublicValues {
+        FepPublicValues {
+            l1_head: B256::repeat_byte(1),
+            l2_pre_root: B256::repeat_byte(2),
+            l2_post_root: B256::repeat_byte(3),
+            l2_block_number: 200,
+            rollup_config_hash: B256::repeat_byte(4),
+            multi_block_vkey: B256::repeat_byte(5),
+            prover_address: Address::repeat_byte(6),
+        }
+    }
+
+    fn aggchain() -> AggchainProofPublicValues {
+        AggchainProofPublicValues {
+            prev_local_exit_root: Digest([1; 32]),
+            new_local_exit_root: Digest([2; 32]),
+            l1_info_root: Digest([3; 32]),
+            origin_network: 7_u32.into(),
+            commit_imported_bridge_exits: Digest([4; 32]),
+            aggchain_params: Digest([5; 32]),
+        }
+    }
+
+    fn pessimistic() -> PessimisticProofOutput {
+        PessimisticProofOutput {
+            prev_local_exit_root: Digest([1; 32]),
+            prev_pessimistic_root: Digest([2; 32]),
+            l1_info_root: Digest([3; 32]),
+            origin_network: 7,
+            aggchain_hash: Digest([4; 32]),
+            new_local_exit_root: Digest([5; 32]),
+            new_pessimistic_root: Digest([6; 32]),
+        }
+    }
+
+    #[test]
+    fn kind_is_detected() {
+        let samples = [
+            PublicValues::Fep(fep()),
+            PublicValues::Aggchain(aggchain()),
+            PublicValues::Pessimistic(pessimistic()),
+        ];
+
+        for sample in samples {
+            let bytes = sample.encode().unwrap();
+            let decoded = PublicValues::decode(&bytes).unwrap();
+
+            assert_eq!(decoded.kind(), sample.kind());
+            assert_eq!(decoded.encode().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn other_layouts_are_rejected() {
+        let bytes = PublicValues::Fep(fep()).encode().unwrap();
+
+        assert!(matches!(
+            PublicValues::decode_as(PublicValuesKind::Aggchain, &bytes),
+            Err(PublicValuesError::Mismatch(PublicValuesKind::Aggchain))
+        ));
+        assert!(matches!(
+            PublicValues::decode(&bytes[1..]),
+            Err(PublicValuesError::Unknown)
+        ));
+    }
+
+    #[test]
+    fn json_names_the_kind() {
+        let json = serde_json::to_value(PublicValues::Fep(fep())).unwrap();
+
+        assert_eq!(json["kind"], "fep");
+        assert_eq!(json["values"]["l2-block-number"], 200);
+    }
+}
@@ -0,0 +1,142 @@
+//! Time spent in each stage of an aggchain proof request, returned to the
+//! aggsender in the metadata of the response for its operators to see where
+//! the time went without access to the traces of the prover.
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Stage of an aggchain proof request, each one traced in a span of the same
+/// name under the span of the request.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Stage {
+    /// Admission of the request, by the leader and the rate limiter.
+    Intake,
+    /// Checks of the request and of its cost, freshness and program.
+    Validation,
+    /// Generation of the aggregation proof by the proposer.
+    Proposer,
+    /// Retrieval of the chain data the aggchain proof is generated from.
+    Witness,
+    /// Proving of the aggchain proof.
+    Prove,
+    /// Verification of the generated aggchain proof.
+    Verify,
+}
+
+impl Stage {
+    pub const ALL: [Self; 6] = [
+        Self::Intake,
+        Self::Validation,
+        Self::Proposer,
+        Self::Witness,
+        Self::Prove,
+        Self::Verify,
+    ];
+
+    /// Name of the span the stage is traced in.
+    pub const fn span_name(self) -> &'static str {
+        match self {
+            Self::Intake => "intake",
+            Self::Validation => "validation",
+            Self::Proposer => "proposer",
+            Self::Witness => "witness",
+            Self::Prove => "prove",
+            Self::Verify => "verify",
+        }
+    }
+}
+
+/// Milliseconds spent in each stage of a request, zero for the stages it
+/// skipped, e.g. the proposer in optimistic mode.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct StageTimings {
+    pub intake_ms: u64,
+    pub validation_ms: u64,
+    pub proposer_ms: u64,
+    pub witness_ms: u64,
+    pub prove_ms: u64,
+    pub verify_ms: u64,
+}
+
+impl StageTimings {
+    /// Adds the time spent in a stage, which may be spread over the layers
+    /// serving the request.
+    pub fn record(&mut self, stage: Stage, elapsed: Duration) {
+        let ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        let timing = self.get_mut(stage);
+        *timing = timing.saturating_add(ms);
+    }
+
+    /// Adds the time spent in the stages by another layer serving the request.
+    pub fn merge(&mut self, other: &StageTimings) {
+        for stage in Stage::ALL {
+            let timing = self.get_mut(stage);
+            *timing = timing.saturating_add(other.get(stage));
+        }
+    }
+
+    /// Milliseconds spent in a stage.
+    pub fn get(&self, stage: Stage) -> u64 {
+        match stage {
+            Stage::Intake => self.intake_ms,
+            Stage::Validation => self.validation_ms,
+            Stage::Proposer => self.proposer_ms,
+            Stage::Witness => self.witness_ms,
+            Stage::Prove => self.prove_ms,
+            Stage::Verify => self.verify_ms,
+        }
+    }
+
+    /// Milliseconds spent in all the stages.
+    pub fn total_ms(&self) -> u64 {
+        Stage::ALL
+            .into_iter()
+            .fold(0, |total, stage| total.saturating_add(self.get(stage)))
+    }
+
+    fn get_mut(&mut self, stage: Stage) -> &mut u64 {
+        match stage {
+            Stage::Intake => &mut self.intake_ms,
+            Stage::Validation => &mut self.validation_ms,
+            Stage::Proposer => &mut self.proposer_ms,
+            Stage::Witness => &mut self.witness_ms,
+            Stage::Prove => &mut self.prove_ms,
+            Stage::Verify => &mut self.verify_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stages_add_up() {
+        let mut timings = StageTimings::default();
+        timings.record(Stage::Validation, Duration::from_millis(2));
+        timings.record(Stage::Prove, Duration::from_secs(60));
+        timings.record(Stage::Validation, Duration::from_micros(3_500));
+
+        assert_eq!(timings.get(Stage::Validation), 5);
+        assert_eq!(timings.get(Stage::Proposer), 0);
+        assert_eq!(timings.total_ms(), 60_005);
+
+        let mut merged = StageTimings {
+            intake_ms: 1,
+            ..Default::default()
+        };
+        merged.merge(&timings);
+        assert_eq!(merged.get(Stage::Intake), 1);
+        assert_eq!(merged.total_ms(), 60_006);
+
+        let json = serde_json::to_value(timings).unwrap();
+        assert_eq!(json["prove-ms"], 60_000);
+        assert_eq!(
+            serde_json::from_str::<StageTimings>(r#"{"prove-ms":60000,"validation-ms":5}"#)
+                .unwrap(),
+            timings
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! Validation of the incoming proto messages, run before converting them.
+//!
+//! The validation reports every violation at once, each one pointing at the
+//! faulty field, so that the client can fix its request in one go.
+use agglayer_interop::grpc::v1::{FixedBytes32, FixedBytes65, MerkleProof};
+
+use crate::{v1, v2};
+
+/// Depth of the merkle trees whose proofs are carried by the requests.
+const MERKLE_PROOF_DEPTH: usize = 32;
+
+/// Limits on the size of the incoming requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidationLimits {
+    /// Maximum number of inserted GERs in one request.
+    pub max_inserted_gers: usize,
+    /// Maximum number of imported bridge exits in one request.
+    pub max_imported_bridge_exits: usize,
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        Self {
+            max_inserted_gers: 1024,
+            max_imported_bridge_exits: 4096,
+        }
+    }
+}
+
+/// One invalid field of a request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub field_path: String,
+    pub description: String,
+}
+
+/// Every invalid field of a request.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+#[error("Invalid request: {} violation(s)", .0.len())]
+pub struct ValidationError(pub Vec<Violation>);
+
+/// Proto messages which can be validated before conversion.
+pub trait Validate {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationError> {
+        let mut violations = Violations::default();
+        self.collect_violations("", limits, &mut violations);
+
+        if violations.0.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError(violations.0))
+        }
+    }
+
+    /// Records the violations of the message, whose path is `prefix`.
+    fn collect_violations(
+        &self,
+        prefix: &str,
+        limits: &ValidationLimits,
+        violations: &mut Violations,
+    );
+}
+
+/// Violations collected while validating one request.
+#[derive(Default)]
+pub struct Violations(Vec<Violation>);
+
+impl Violations {
+    fn push(&mut self, field_path: String, description: impl Into<String>) {
+        self.0.push(Violation {
+            field_path,
+            description: description.into(),
+        });
+    }
+
+    fn check_block_range(
+        &mut self,
+        prefix: &str,
+        last_proven_block: u64,
+        requested_end_block: u64,
+    ) {
+        if requested_end_block <= last_proven_block {
+            self.push(
+                path(prefix, "requested_end_block"),
+                "requested_end_block must be greater than last_proven_block",
+            );
+        }
+    }
+
+    fn check_len(&mut self, field_path: String, len: usize, max: usize) {
+        if len > max {
+            self.push(
+                field_path,
+                format!("at most {max} entries are allowed, got {len}"),
+            );
+        }
+    }
+
+    fn check_bytes32(&mut self, field_path: String, value: Option<&FixedBytes32>) {
+        match value {
+            None => self.push(field_path, "missing value"),
+            Some(value) if value.value.len() != 32 => self.push(
+                field_path,
+                format!("expected 32 bytes, got {}", value.value.len()),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    fn check_bytes65(&mut self, field_path: String, value: Option<&FixedBytes65>) {
+        match value {
+            None => self.push(field_path, "missing value"),
+            Some(value) if value.value.len() != 65 => self.push(
+                field_path,
+                format!("expected 65 bytes, got {}", value.value.len()),
+            ),
+            Some(_) => {}
+        }
+    }
+
+    fn check_merkle_proof(&mut self, field_path: String, proof: Option<&MerkleProof>) {
+        let Some(proof) = proof else {
+            self.push(field_path, "missing value");
+            return;
+        };
+
+        self.check_bytes32(path(&field_path, "root"), proof.root.as_ref());
+
+        if proof.siblings.len() != MERKLE_PROOF_DEPTH {
+            self.push(
+                path(&field_path, "siblings"),
+                format!(
+                    "expected {MERKLE_PROOF_DEPTH} siblings, got {}",
+                    proof.siblings.len()
+                ),
+            );
+        }
+
+        for (i, sibling) in proof.siblings.iter().enumerate() {
+            self.check_bytes32(path(&field_path, &format!("siblings.{i}")), Some(sibling));
+        }
+    }
+}
+
+fn path(prefix: &str, field: &str) -> String {
+    if prefix.is_empty() {
+        field.to_string()
+    } else {
+        format!("{prefix}.{field}")
+    }
+}
+
+impl Validate for v1::GenerateAggchainProofRequest {
+    fn collect_violations(
+        &self,
+        prefix: &str,
+        limits: &ValidationLimits,
+        violations: &mut Violations,
+    ) {
+        violations.check_block_range(prefix, self.last_proven_block, self.requested_end_block);
+        violations.check_bytes32(
+            path(prefix, "l1_info_tree_root_hash"),
+            self.l1_info_tree_root_hash.as_ref(),
+        );
+        if self.l1_info_tree_leaf.is_none() {
+            violations.push(path(prefix, "l1_info_tree_leaf"), "missing value");
+        }
+        violations.check_merkle_proof(
+            path(prefix, "l1_info_tree_merkle_proof"),
+            self.l1_info_tree_merkle_proof.as_ref(),
+        );
+
+        violations.check_len(
+            path(prefix, "ger_leaves"),
+            self.ger_leaves.len(),
+            limits.max_inserted_gers,
+        );
+        for (ger, leaf) in &self.ger_leaves {
+            let field_path = path(prefix, &format!("ger_leaves.{ger}.proven_inserted_ger"));
+            match &leaf.proven_inserted_ger {
+                Some(inserted_ger) => violations.check_merkle_proof(
+                    path(&field_path, "proof_ger_l1root"),
+                    inserted_ger.proof_ger_l1root.as_ref(),
+                ),
+                None => violations.push(field_path, "missing value"),
+            }
+        }
+
+        violations.check_len(
+            path(prefix, "imported_bridge_exits"),
+            self.imported_bridge_exits.len(),
+            limits.max_imported_bridge_exits,
+        );
+        for (i, exit) in self.imported_bridge_exits.iter().enumerate() {
+            let field_path = path(prefix, &format!("imported_bridge_exits.{i}"));
+            violations.check_bytes32(
+                path(&field_path, "global_index"),
+                exit.global_index.as_ref(),
+            );
+            violations.check_bytes32(
+                path(&field_path, "bridge_exit_hash"),
+                exit.bridge_exit_hash.as_ref(),
+            );
+        }
+
+        if let Some(hash) = &self.requested_vkey_hash {
+            violations.check_bytes32(path(prefix, "requested_vkey_hash"), Some(hash));
+        }
+    }
+}
+
+impl Validate for v1::GenerateOptimisticAggchainProofRequest {
+    fn collect_violations(
+        &self,
+        prefix: &str,
+        limits: &ValidationLimits,
+        violations: &mut Violations,
+    ) {
+        let field_path = path(prefix, "aggchain_proof_request");
+        match &self.aggchain_proof_request {
+            Some(request) => request.collect_violations(&field_path, limits, violations),
+            None => violations.push(field_path, "missing value"),
+        }
+
+        violations.check_bytes65(
+            path(prefix, "optimistic_mode_signature"),
+            self.optimistic_mode_signature.as_ref(),
+        );
+    }
+}
+
+impl Validate for v2::GenerateAggchainProofRequest {
+    fn collect_violations(
+        &self,
+        prefix: &str,
+        limits: &ValidationLimits,
+        violations: &mut Violations,
+    ) {
+        if self.request_id.is_empty() {
+            violations.push(path(prefix, "request_id"), "missing value");
+        }
+
+        violations.check_block_range(prefix, self.last_proven_block, self.requested_end_block);
+        violations.check_bytes32(
+            path(prefix, "l1_info_tree_root_hash"),
+            self.l1_info_tree_root_hash.as_ref(),
+        );
+        if self.l1_info_tree_leaf.is_none() {
+            violations.push(path(prefix, "l1_info_tree_leaf"), "missing value");
+        }
+        violations.check_merkle_proof(
+            path(prefix, "l1_info_tree_merkle_proof"),
+            self.l1_info_tree_merkle_proof.as_ref(),
+        );
+
+        violations.check_len(
+            path(prefix, "inserted_gers"),
+            self.inserted_gers.len(),
+            limits.max_inserted_gers,
+        );
+        for (i, inserted_ger) in self.inserted_gers.iter().enumerate() {
+            let field_path = path(prefix, &format!("inserted_gers.{i}"));
+            violations.check_bytes32(path(&field_path, "ger"), inserted_ger.ger.as_ref());
+            violations.check_merkle_proof(
+                path(&field_path, "proof_ger_l1root"),
+                inserted_ger.proof_ger_l1root.as_ref(),
+            );
+        }
+
+        violations.check_len(
+            path(prefix, "imported_bridge_exits"),
+            self.imported_bridge_exits.len(),
+            limits.max_imported_bridge_exits,
+        );
+        for (i, exit) in self.imported_bridge_exits.iter().enumerate() {
+            let field_path = path(prefix, &format!("imported_bridge_exits.{i}"));
+            violations.check_bytes32(
+                path(&field_path, "global_index"),
+                exit.global_index.as_ref(),
+            );
+            violations.check_bytes32(
+                path(&field_path, "bridge_exit_hash"),
+                exit.bridge_exit_hash.as_ref(),
+            );
+        }
+
+        if let Some(hash) = &self.requested_vkey_hash {
+            violations.check_bytes32(path(prefix, "requested_vkey_hash"), Some(hash));
+        }
+    }
+}
+
+impl Validate for v2::GenerateOptimisticAggchainProofRequest {
+    fn collect_violations(
+        &self,
+        prefix: &str,
+        limits: &ValidationLimits,
+        violations: &mut Violations,
+    ) {
+        let field_path = path(prefix, "aggchain_proof_request");
+        match &self.aggchain_proof_request {
+            Some(request) => request.collect_violations(&field_path, limits, violations),
+            None => violations.push(field_path, "missing value"),
+        }
+
+        violations.check_bytes65(
+            path(prefix, "optimistic_mode_signature"),
+            self.optimistic_mode_signature.as_ref(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::bytes::Bytes;
+
+    use super::*;
+
+    fn bytes32(len: usize) -> FixedBytes32 {
+        FixedBytes32 {
+            value: Bytes::from(vec![0u8; len]),
+        }
+    }
+
+    fn merkle_proof() -> MerkleProof {
+        MerkleProof {
+            root: Some(bytes32(32)),
+            siblings: vec![bytes32(32); MERKLE_PROOF_DEPTH],
+        }
+    }
+
+    fn request() -> v2::GenerateAggchainProofRequest {
+        v2::GenerateAggchainProofRequest {
+            request_id: "request".to_string(),
+            last_proven_block: 10,
+            requested_end_block: 20,
+            l1_info_tree_root_hash: Some(bytes32(32)),
+            l1_info_tree_leaf: Some(Default::default()),
+            l1_info_tree_merkle_proof: Some(merkle_proof()),
+            ..Default::default()
+        }
+    }
+
+    fn field_paths(error: ValidationError) -> Vec<String> {
+        error.0.into_iter().map(|v| v.field_path).collect()
+    }
+
+    #[test]
+    fn valid_request() {
+        request().validate(&ValidationLimits::default()).unwrap();
+    }
+
+    #[test]
+    fn reports_every_violation() {
+        let mut proof = merkle_proof();
+        proof.siblings[3] = bytes32(31);
+
+        let request = v2::GenerateAggchainProofRequest {
+            requested_end_block: 10,
+            l1_info_tree_root_hash: Some(bytes32(33)),
+            inserted_gers: vec![v2::ProvenInsertedGer {
+                ger: None,
+                proof_ger_l1root: Some(proof),
+                ..Default::default()
+            }],
+            ..request()
+        };
+
+        let error = request.validate(&ValidationLimits::default()).unwrap_err();
+        assert_eq!(
+            field_paths(error),
+            [
+                "requested_end_block",
+                "l1_info_tree_root_hash",
+                "inserted_gers.0.ger",
+                "inserted_gers.0.proof_ger_l1root.siblings.3",
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_paths_and_limits() {
+        let request = v2::GenerateOptimisticAggchainProofRequest {
+            network_id: 1,
+            aggchain_proof_request: Some(v2::GenerateAggchainProofRequest {
+                imported_bridge_exits: vec![Default::default(); 2],
+                ..request()
+            }),
+            optimistic_mode_signature: Some(FixedBytes65 {
+                value: Bytes::from(vec![0u8; 65]),
+            }),
+        };
+        let limits = ValidationLimits {
+            max_imported_bridge_exits: 1,
+            ..Default::default()
+        };
+
+        let error = request.validate(&limits).unwrap_err();
+        assert_eq!(
+            field_paths(error),
+            [
+                "aggchain_proof_request.imported_bridge_exits",
+                "aggchain_proof_request.imported_bridge_exits.0.global_index",
+                "aggchain_proof_request.imported_bridge_exits.0.bridge_exit_hash",
+                "aggchain_proof_request.imported_bridge_exits.1.global_index",
+                "aggchain_proof_request.imported_bridge_exits.1.bridge_exit_hash",
+            ]
+        );
+    }
+}
@@ -3,6 +3,11 @@ use alloy_primitives::B256;
 use serde::{Deserialize, Serialize};
 
 /// SP1 verifying key hash.
+///
+/// Held as the `hash_u32` of the verifying key, its bytes being the
+/// big-endian words, as in the `bytes32` string of the verifying key. Parsed
+/// from and formatted as that string, with or without its `0x` prefix, and
+/// serialized as such.
 #[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(from = "B256", into = "B256")]
 pub struct VKeyHash(HashU32);
@@ -29,6 +34,10 @@ impl VKeyHash {
         Self(hash_u32)
     }
 
+    pub const fn from_byte_array(bytes: [u8; 32]) -> Self {
+        Self::from_bytes(B256::new(bytes))
+    }
+
     #[cfg(feature = "sp1")]
     pub fn from_vkey<K: sp1_sdk::HashableKey>(vkey: &K) -> Self {
         Self::from_hash_u32(vkey.hash_u32())
@@ -50,11 +59,39 @@ impl VKeyHash {
         B256::new(bytes)
     }
 
+    pub const fn to_byte_array(&self) -> [u8; 32] {
+        self.to_bytes().0
+    }
+
     pub const fn to_hash_u32(&self) -> HashU32 {
         self.0
     }
 }
 
+impl From<HashU32> for VKeyHash {
+    fn from(hash: HashU32) -> Self {
+        Self::from_hash_u32(hash)
+    }
+}
+
+impl From<VKeyHash> for HashU32 {
+    fn from(hash: VKeyHash) -> Self {
+        hash.to_hash_u32()
+    }
+}
+
+impl From<[u8; 32]> for VKeyHash {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self::from_byte_array(bytes)
+    }
+}
+
+impl From<VKeyHash> for [u8; 32] {
+    fn from(hash: VKeyHash) -> Self {
+        hash.to_byte_array()
+    }
+}
+
 impl From<B256> for VKeyHash {
     fn from(bytes: B256) -> Self {
         Self::from_bytes(bytes)
@@ -81,6 +118,12 @@ impl std::fmt::Debug for VKeyHash {
     }
 }
 
+impl std::fmt::Display for VKeyHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_bytes().fmt(f)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use alloy_primitives::b256;
@@ -102,5 +145,23 @@ mod test {
 
         let roundtrip = VKeyHash::from_bytes(from_hash_u32.to_bytes());
         assert_eq!(from_hash_u32, roundtrip);
+
+        assert_eq!(VKeyHash::from(from_hash_u32.to_byte_array()), from_hash_u32);
+        assert_eq!(VKeyHash::from(HashU32::from(from_hash_u32)), from_hash_u32);
+    }
+
+    #[test]
+    fn bytes32_string_roundtrips() {
+        let bytes32 = "0x000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        let vkey_hash: VKeyHash = bytes32.parse().unwrap();
+
+        assert_eq!(vkey_hash.to_string(), bytes32);
+        assert_eq!(format!("{vkey_hash:?}"), bytes32);
+        assert_eq!(bytes32[2..].parse::<VKeyHash>().unwrap(), vkey_hash);
+        assert!("0x0001".parse::<VKeyHash>().is_err());
+
+        let json = serde_json::to_string(&vkey_hash).unwrap();
+        assert_eq!(json, format!("\"{bytes32}\""));
+        assert_eq!(serde_json::from_str::<VKeyHash>(&json).unwrap(), vkey_hash);
     }
 }
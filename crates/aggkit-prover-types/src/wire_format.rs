@@ -0,0 +1,150 @@
+//! Versioning of the wire formats of the payloads.
+//!
+//! The encoding of a sample of each payload sent over the wire, with bincode
+//! or as a proto message, is checked against the fixtures of the current
+//! version in `tests/wire-format/v<version>`. Changing an encoding requires
+//! bumping [`WIRE_FORMAT_VERSION`], then writing the fixtures of the new
+//! version by running the tests with `UPDATE_WIRE_FORMAT=1`. The fixtures of a
+//! version are never overwritten.
+
+/// Version of the wire formats, bumped on any change of an encoding.
+pub const WIRE_FORMAT_VERSION: u32 = 2;
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use agglayer_interop::{grpc::v1::FixedBytes32, types::bincode};
+    use prost::{bytes::Bytes, Message};
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use super::*;
+    use crate::{
+        envelope::{ProofEnvelope, ProofMode, PROOF_ENVELOPE_VERSION},
+        v1, v2,
+        vkey_hash::VKeyHash,
+    };
+
+    const UPDATE_ENV: &str = "UPDATE_WIRE_FORMAT";
+
+    /// Encoding of a sample payload, along with whether some bytes decode
+    /// back to the sample.
+    struct Sample {
+        name: &'static str,
+        bytes: Vec<u8>,
+        decodes: Box<dyn Fn(&[u8]) -> bool>,
+    }
+
+    fn bincode_sample<T>(name: &'static str, value: T) -> Sample
+    where
+        T: Serialize + DeserializeOwned + PartialEq + 'static,
+    {
+        Sample {
+            name,
+            bytes: bincode::sp1v4().serialize(&value).unwrap(),
+            decodes: Box::new(move |bytes| {
+                bincode::sp1v4()
+                    .deserialize::<T>(bytes)
+                    .is_ok_and(|decoded| decoded == value)
+            }),
+        }
+    }
+
+    fn proto_sample<T: Message + Default + PartialEq + 'static>(
+        name: &'static str,
+        value: T,
+    ) -> Sample {
+        Sample {
+            name,
+            bytes: value.encode_to_vec(),
+            decodes: Box::new(move |bytes| T::decode(bytes).is_ok_and(|decoded| decoded == value)),
+        }
+    }
+
+    fn samples() -> Vec<Sample> {
+        let vkey_hash = VKeyHash::from_hash_u32([1, 2, 3, 4, 5, 6, 7, 8]);
+
+        vec![
+            bincode_sample(
+                "proof_envelope.bincode",
+                ProofEnvelope {
+                    version: PROOF_ENVELOPE_VERSION,
+                    proof_mode: ProofMode::Compressed,
+                    vkey_hash,
+                    proof: vec![0xaa; 4].into(),
+                    public_values: vec![0xbb; 2].into(),
+                    prover_version: "v1".to_string(),
+                    created_at: 1_700_000_000,
+                },
+            ),
+            bincode_sample("vkey_hash.bincode", vkey_hash),
+            proto_sample(
+                "v1_generate_aggchain_proof_request.proto",
+                v1::GenerateAggchainProofRequest {
+                    last_proven_block: 100,
+                    requested_end_block: 200,
+                    requested_vkey_hash: Some(FixedBytes32 {
+                        value: Bytes::from(vec![0x11; 32]),
+                    }),
+                    ..Default::default()
+                },
+            ),
+            proto_sample(
+                "v2_aggchain_proof_error.proto",
+                v2::AggchainProofError {
+                    kind: v2::AggchainProofErrorKind::Rejected as i32,
+                    message: "too costly".to_string(),
+                    field_path: "requested_end_block".to_string(),
+                },
+            ),
+        ]
+    }
+
+    fn fixtures_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/wire-format")
+            .join(format!("v{WIRE_FORMAT_VERSION}"))
+    }
+
+    /// Reads a fixture, the whitespaces between the hex digits being ignored.
+    fn read_fixture(path: &Path) -> Option<Vec<u8>> {
+        let hex = std::fs::read_to_string(path).ok()?;
+
+        Some(hex::decode(hex.split_whitespace().collect::<String>()).unwrap())
+    }
+
+    fn write_fixture(path: &Path, bytes: &[u8]) {
+        let lines: Vec<String> = bytes.chunks(32).map(hex::encode).collect();
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, lines.join("\n") + "\n").unwrap();
+    }
+
+    #[test]
+    fn encodings_match_the_fixtures_of_the_version() {
+        let update = std::env::var_os(UPDATE_ENV).is_some();
+        let mut changed = Vec::new();
+
+        for sample in samples() {
+            let path = fixtures_dir().join(format!("{}.hex", sample.name));
+            let fixture = match read_fixture(&path) {
+                Some(fixture) => fixture,
+                None if update => {
+                    write_fixture(&path, &sample.bytes);
+                    continue;
+                }
+                None => panic!("Missing fixture {path:?}, write it with {UPDATE_ENV}=1"),
+            };
+
+            if fixture != sample.bytes || !(sample.decodes)(&fixture) {
+                changed.push(sample.name);
+            }
+        }
+
+        assert!(
+            changed.is_empty(),
+            "The encoding of {changed:?} changed, bump WIRE_FORMAT_VERSION and write the \
+             fixtures of the new version with {UPDATE_ENV}=1"
+        );
+    }
+}
@@ -0,0 +1,376 @@
+//! HTTP API of the operators, served apart from the gRPC API.
+//!
+//! - `GET /rate-limits` lists the state of the windows of the rate limits.
+//! - `POST /rate-limits/network/{network_id}/reset` and `POST
+//!   /rate-limits/clients/{name}/reset` start a new window for one limit.
+//! - `GET /networks` reports whether the proof requests of the network are
+//!   served, and lists the disabled networks.
+//! - `POST /networks/{network_id}/disable` and `POST
+//!   /networks/{network_id}/enable` reject or serve again the proof requests
+//!   of one network, until the next restart.
+//! - `GET /tenants` lists the requests in flight and the estimated cycles of
+//!   the tenants over the current month, along with their limits.
+//! - `GET /costs` lists the proving cost of the networks over the current
+//!   month, when accounted for.
+//! - `GET /snapshot` takes a snapshot of the state of the prover, to restore
+//!   with `run --restore`.
+//! - `GET /programs` lists the served aggchain proof programs, when proven
+//!   locally.
+//! - `POST /programs` loads a new aggchain proof program from an ELF file, the
+//!   previous one being served until the end of the transition window.
+//! - `DELETE /programs/{vkey_hash}` stops serving a previous program.
+//! - `GET /healthz/details` reports the health of the dependencies, as of the
+//!   last refresh of the health monitor.
+//! - `GET /debug/pprof/profile` and `GET /debug/pprof/heap` take CPU and heap
+//!   profiles, when configured and built with the `profiling` feature. See
+//!   [`crate::profiling`].
+use std::{path::PathBuf, sync::Arc};
+
+use aggchain_proof_builder::programs::{ProgramRegistry, ProgramState};
+use aggkit_prover_config::ProfilingConfig;
+use aggkit_prover_types::vkey_hash::VKeyHash;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use prover_config::ProgramCheckConfig;
+use prover_executor::cost::{CostLedger, NetworkCost};
+use serde::Deserialize;
+use tracing::{error, info, warn};
+
+use crate::{
+    health::{HealthMonitor, HealthReport},
+    rpc::{
+        network_switch::{NetworkSwitch, NetworksState},
+        rate_limit::{RateLimitKey, RateLimitState, RateLimiter},
+        tenancy::{TenantUsage, Tenants},
+    },
+    snapshot::{SnapshotStores, StateSnapshot},
+};
+
+pub fn router(
+    rate_limiter: Arc<RateLimiter>,
+    network_switch: Arc<NetworkSwitch>,
+    tenants: Arc<Tenants>,
+    snapshot_stores: SnapshotStores,
+    programs: Option<Arc<ProgramRegistry>>,
+    health: Arc<HealthMonitor>,
+    profiling: Option<ProfilingConfig>,
+) -> Router {
+    let router = Router::new()
+        .route("/healthz/details", get(health_details))
+        .with_state(health)
+        .route("/programs", get(list_programs).post(load_program))
+        .route("/programs/{vkey_hash}", delete(retire_program))
+        .with_state(programs)
+        .route("/costs", get(costs))
+        .with_state(snapshot_stores.cost_ledger.clone())
+        .route("/snapshot", get(snapshot))
+        .with_state(snapshot_stores)
+        .route("/rate-limits", get(rate_limits))
+        .route(
+            "/rate-limits/network/{network_id}/reset",
+            post(reset_network_rate_limit),
+        )
+        .route(
+            "/rate-limits/clients/{name}/reset",
+            post(reset_client_rate_limit),
+        )
+        .with_state(rate_limiter)
+        .route("/networks", get(networks))
+        .route("/networks/{network_id}/disable", post(disable_network))
+        .route("/networks/{network_id}/enable", post(enable_network))
+        .with_state(network_switch)
+        .route("/tenants", get(tenants_usage))
+        .with_state(tenants);
+
+    match profiling {
+        #[cfg(feature = "profiling")]
+        Some(profiling) => router.merge(crate::profiling::router(profiling)),
+        #[cfg(not(feature = "profiling"))]
+        Some(_) => {
+            tracing::warn!(
+                "Profiling is configured but the prover is built without the profiling feature"
+            );
+            router
+        }
+        None => router,
+    }
+}
+
+async fn health_details(State(health): State<Arc<HealthMonitor>>) -> Json<HealthReport> {
+    Json(health.report().as_ref().clone())
+}
+
+async fn costs(
+    State(cost_ledger): State<Option<Arc<CostLedger>>>,
+) -> Result<Json<Vec<NetworkCost>>, StatusCode> {
+    cost_ledger
+        .map(|cost_ledger| Json(cost_ledger.costs()))
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn snapshot(State(stores): State<SnapshotStores>) -> Result<Json<StateSnapshot>, StatusCode> {
+    StateSnapshot::take(&stores)
+        .await
+        .map(Json)
+        .map_err(|error| {
+            error!(?error, "Unable to take a snapshot of the state");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+/// Aggchain proof program to load.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+struct LoadProgram {
+    elf_path: PathBuf,
+
+    /// Expected identity of the program, checked before serving it.
+    #[serde(flatten)]
+    program_check: ProgramCheckConfig,
+}
+
+async fn list_programs(
+    State(programs): State<Option<Arc<ProgramRegistry>>>,
+) -> Result<Json<Vec<ProgramState>>, StatusCode> {
+    programs
+        .map(|programs| Json(programs.states()))
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn load_program(
+    State(programs): State<Option<Arc<ProgramRegistry>>>,
+    Json(request): Json<LoadProgram>,
+) -> Result<Json<VKeyHash>, StatusCode> {
+    let programs = programs.ok_or(StatusCode::NOT_FOUND)?;
+    let elf = tokio::fs::read(&request.elf_path).await.map_err(|error| {
+        error!(elf_path = ?request.elf_path, ?error, "Unable to read the program");
+        StatusCode::BAD_REQUEST
+    })?;
+
+    // Setting up the provers of the program blocks.
+    tokio::task::spawn_blocking(move || programs.load(&elf, &request.program_check))
+        .await
+        .map_err(|error| {
+            error!(?error, "Unable to load the program");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map(Json)
+        .map_err(|error| {
+            error!(?error, "Rejected the program");
+            StatusCode::UNPROCESSABLE_ENTITY
+        })
+}
+
+async fn retire_program(
+    State(programs): State<Option<Arc<ProgramRegistry>>>,
+    Path(vkey_hash): Path<String>,
+) -> StatusCode {
+    let Some(programs) = programs else {
+        return StatusCode::NOT_FOUND;
+    };
+    let Ok(vkey_hash) = vkey_hash.parse() else {
+        return StatusCode::BAD_REQUEST;
+    };
+
+    match programs.retire(vkey_hash) {
+        Ok(true) => StatusCode::NO_CONTENT,
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(error) => {
+            error!(?vkey_hash, ?error, "Unable to retire the program");
+            StatusCode::CONFLICT
+        }
+    }
+}
+
+async fn rate_limits(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+) -> Result<Json<Vec<RateLimitState>>, StatusCode> {
+    rate_limiter.state().await.map(Json).map_err(|error| {
+        error!(?error, "Unable to read the rate limits");
+        StatusCode::SERVICE_UNAVAILABLE
+    })
+}
+
+async fn reset_network_rate_limit(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    Path(network_id): Path<u32>,
+) -> StatusCode {
+    reset(&rate_limiter, RateLimitKey::Network(network_id)).await
+}
+
+async fn reset_client_rate_limit(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    Path(name): Path<String>,
+) -> StatusCode {
+    reset(&rate_limiter, RateLimitKey::Client(name)).await
+}
+
+async fn reset(rate_limiter: &RateLimiter, key: RateLimitKey) -> StatusCode {
+    match rate_limiter.reset(&key).await {
+        Ok(true) => {
+            info!(?key, "Rate limit reset");
+            StatusCode::NO_CONTENT
+        }
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(error) => {
+            error!(?key, ?error, "Unable to reset the rate limit");
+            StatusCode::SERVICE_UNAVAILABLE
+        }
+    }
+}
+
+async fn networks(State(network_switch): State<Arc<NetworkSwitch>>) -> Json<NetworksState> {
+    Json(network_switch.state())
+}
+
+async fn disable_network(
+    State(network_switch): State<Arc<NetworkSwitch>>,
+    Path(network_id): Path<u32>,
+) -> StatusCode {
+    if network_switch.set_enabled(network_id, false) {
+        warn!(network_id, "Proof requests of the network disabled");
+    }
+
+    StatusCode::NO_CONTENT
+}
+
+async fn enable_network(
+    State(network_switch): State<Arc<NetworkSwitch>>,
+    Path(network_id): Path<u32>,
+) -> StatusCode {
+    if network_switch.set_enabled(network_id, true) {
+        info!(network_id, "Proof requests of the network enabled");
+    }
+
+    StatusCode::NO_CONTENT
+}
+
+async fn tenants_usage(State(tenants): State<Arc<Tenants>>) -> Json<Vec<TenantUsage>> {
+    Json(tenants.usage())
+}
+
+#[cfg(test)]
+mod tests {
+    use aggkit_prover_config::RateLimitingConfig;
+    use prover_config::CostAccountingConfig;
+    use serde_json::json;
+    use tonic::metadata::MetadataMap;
+
+    use super::*;
+    use crate::rpc::rate_limit::API_KEY_METADATA;
+
+    #[tokio::test]
+    async fn rate_limits_can_be_inspected_and_reset() {
+        let config: RateLimitingConfig = toml::from_str(
+            r#"
+            network = { requests = 2, period = "1m" }
+
+            [clients.noisy]
+            api-key = "noisy-key"
+            limit = { requests = 1, period = "1m" }
+            "#,
+        )
+        .unwrap();
+        let rate_limiter = Arc::new(RateLimiter::new(&config, 1));
+
+        let mut metadata = MetadataMap::new();
+        metadata.insert(API_KEY_METADATA, "noisy-key".parse().unwrap());
+        rate_limiter.check(&metadata).await.unwrap();
+        assert!(rate_limiter.check(&metadata).await.is_err());
+
+        let Json(state) = rate_limits(State(rate_limiter.clone())).await.unwrap();
+        let state = serde_json::to_value(state).unwrap();
+        assert_eq!(
+            state[0],
+            json!({ "key": { "network": 1 }, "requests": 2, "period": "1m", "remaining": 2 })
+        );
+        assert_eq!(state[1]["key"], json!({ "client": "noisy" }));
+        assert_eq!(state[1]["remaining"], 0);
+        assert!(state[1]["reset-in"].is_string());
+
+        assert_eq!(
+            reset_client_rate_limit(State(rate_limiter.clone()), Path("noisy".to_string())).await,
+            StatusCode::NO_CONTENT
+        );
+        assert!(rate_limiter.check(&metadata).await.is_ok());
+        assert_eq!(
+            reset_client_rate_limit(State(rate_limiter.clone()), Path("quiet".to_string())).await,
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            reset_network_rate_limit(State(rate_limiter), Path(1)).await,
+            StatusCode::NO_CONTENT
+        );
+    }
+
+    #[tokio::test]
+    async fn networks_can_be_disabled_and_enabled() {
+        let network_switch = Arc::new(NetworkSwitch::new(&Default::default(), 1));
+
+        assert_eq!(
+            disable_network(State(network_switch.clone()), Path(1)).await,
+            StatusCode::NO_CONTENT
+        );
+        assert!(network_switch.check().is_err());
+        let Json(state) = networks(State(network_switch.clone())).await;
+        assert_eq!(
+            serde_json::to_value(state).unwrap(),
+            json!({ "network-id": 1, "enabled": false, "disabled": [1] })
+        );
+
+        assert_eq!(
+            enable_network(State(network_switch.clone()), Path(1)).await,
+            StatusCode::NO_CONTENT
+        );
+        assert!(network_switch.check().is_ok());
+    }
+
+    #[tokio::test]
+    async fn costs_are_listed_when_accounted_for() {
+        assert_eq!(costs(State(None)).await.unwrap_err(), StatusCode::NOT_FOUND);
+
+        let cost_ledger = Arc::new(CostLedger::new(&CostAccountingConfig {
+            credits_per_million_cycles: 1_000,
+            monthly_cycle_budget: Some(50_000_000),
+        }));
+        cost_ledger.record_estimate(1, 2_000_000);
+        cost_ledger.record_actual(1, 2_000_000);
+
+        let Json(costs) = costs(State(Some(cost_ledger))).await.unwrap();
+        let costs = serde_json::to_value(costs).unwrap();
+        assert_eq!(costs[0]["network-id"], 1);
+        assert_eq!(
+            costs[0]["actual"],
+            json!({ "requests": 1, "cycles": 2_000_000, "credits": 2_000 })
+        );
+        assert_eq!(costs[0]["monthly-cycle-budget"], 50_000_000);
+    }
+
+    #[tokio::test]
+    async fn programs_are_managed_only_when_proven_locally() {
+        assert_eq!(
+            list_programs(State(None)).await.unwrap_err(),
+            StatusCode::NOT_FOUND
+        );
+
+        let request: LoadProgram = serde_json::from_value(json!({
+            "elf-path": "aggchain.elf",
+            "expected-vkey": "0x00",
+        }))
+        .unwrap();
+        assert_eq!(request.program_check.expected_vkey.as_deref(), Some("0x00"));
+        assert_eq!(
+            load_program(State(None), Json(request)).await.unwrap_err(),
+            StatusCode::NOT_FOUND
+        );
+
+        assert_eq!(
+            retire_program(State(None), Path("0x00".to_string())).await,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
@@ -0,0 +1,530 @@
+//! Append-only audit log of the proof requests served, kept for the
+//! compliance reviews.
+//!
+//! The records are written as JSON lines to the files of the configured
+//! directory, named after the sequence number of their first record and
+//! rolled once they exceed the configured size. Every record holds the hash
+//! of the previous one and its own hash, the SHA-256 of its JSON encoding
+//! without the `hash` field, such that removing or altering a record breaks
+//! the chain. The oldest files may be removed as per the retention, the
+//! chain starting from the first record kept.
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead as _, BufReader, Write as _},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aggkit_prover_config::AuditLogConfig;
+use prover_utils::retention::RetentionPolicy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use tonic::{Response, Status};
+use tracing::{debug, error};
+
+use crate::gc::Reclaimed;
+
+const FILE_PREFIX: &str = "audit-";
+const FILE_EXTENSION: &str = "jsonl";
+
+/// Hash of the previous record of the first one.
+const GENESIS_HASH: [u8; 32] = [0; 32];
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuditLogError {
+    #[error("Unable to access the audit log {path:?}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid audit log record at {path:?}:{line}")]
+    InvalidRecord {
+        path: PathBuf,
+        line: usize,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Broken audit log chain at the record {sequence}: {reason}")]
+    BrokenChain { sequence: u64, reason: &'static str },
+}
+
+/// Outcome of a proof request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case", tag = "status")]
+pub enum AuditOutcome {
+    /// The proof was served, `result-hash` being the SHA-256 of the protobuf
+    /// encoding of the response.
+    Proven {
+        result_hash: String,
+    },
+    Failed {
+        code: String,
+        message: String,
+    },
+}
+
+/// Content of a record, as hashed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AuditRecordBody {
+    pub sequence: u64,
+    pub prev_hash: String,
+    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Name of the client authenticated by its API key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<String>,
+    /// SHA-256 of the protobuf encoding of the request.
+    pub request_digest: String,
+    /// Timestamps of the stages, in milliseconds since the unix epoch.
+    pub received_at: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proving_started_at: Option<u64>,
+    pub completed_at: u64,
+    pub outcome: AuditOutcome,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AuditRecord {
+    #[serde(flatten)]
+    pub body: AuditRecordBody,
+    pub hash: String,
+}
+
+impl AuditRecordBody {
+    fn hash(&self) -> String {
+        let encoded = serde_json::to_vec(self).expect("audit records are serializable");
+
+        encode_hash(Sha256::digest(encoded).into())
+    }
+}
+
+/// Proof request being served, recorded once completed.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    method: &'static str,
+    request_id: Option<String>,
+    caller: Option<String>,
+    request_digest: String,
+    received_at: u64,
+    proving_started_at: Option<u64>,
+}
+
+impl AuditEntry {
+    pub fn received(
+        method: &'static str,
+        caller: Option<String>,
+        request: &impl prost::Message,
+    ) -> Self {
+        Self {
+            method,
+            request_id: None,
+            caller,
+            request_digest: digest(request),
+            received_at: now(),
+            proving_started_at: None,
+        }
+    }
+
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    pub fn proving_started(&mut self) {
+        self.proving_started_at = Some(now());
+    }
+
+    /// Completes the entry with the result returned to the caller.
+    pub fn complete<T: prost::Message>(
+        self,
+        result: &Result<Response<T>, Status>,
+    ) -> CompletedEntry {
+        let outcome = match result {
+            Ok(response) => AuditOutcome::Proven {
+                result_hash: digest(response.get_ref()),
+            },
+            Err(status) => AuditOutcome::Failed {
+                code: format!("{:?}", status.code()),
+                message: status.message().to_string(),
+            },
+        };
+        CompletedEntry(AuditRecordBody {
+            // Set once appended.
+            sequence: 0,
+            prev_hash: String::new(),
+            method: self.method.to_string(),
+            request_id: self.request_id,
+            caller: self.caller,
+            request_digest: self.request_digest,
+            received_at: self.received_at,
+            proving_started_at: self.proving_started_at,
+            completed_at: now(),
+            outcome,
+        })
+    }
+}
+
+/// Proof request completed, to append to the log.
+#[derive(Debug, Clone)]
+pub struct CompletedEntry(AuditRecordBody);
+
+/// Request being served, appended to its audit log once completed.
+pub struct AuditTrail {
+    log: Arc<AuditLog>,
+    entry: AuditEntry,
+}
+
+impl AuditTrail {
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.entry = self.entry.with_request_id(request_id);
+        self
+    }
+
+    pub fn proving_started(&mut self) {
+        self.entry.proving_started();
+    }
+
+    /// Appends the record of the request in the background, never failing
+    /// the request.
+    pub fn record<T: prost::Message>(self, result: &Result<Response<T>, Status>) {
+        let entry = self.entry.complete(result);
+        let log = self.log;
+        tokio::task::spawn_blocking(move || {
+            if let Err(error) = log.append(entry) {
+                error!(?error, "Unable to append to the audit log");
+            }
+        });
+    }
+}
+
+pub struct AuditLog {
+    dir: PathBuf,
+    max_file_size: u64,
+    writer: Mutex<Writer>,
+}
+
+struct Writer {
+    file: Option<(File, u64)>,
+    next_sequence: u64,
+    last_hash: String,
+}
+
+impl AuditLog {
+    /// Opens the log, appending to the last file of the directory.
+    pub fn open(config: &AuditLogConfig) -> Result<Self, AuditLogError> {
+        std::fs::create_dir_all(&config.dir).map_err(io_error(&config.dir))?;
+
+        let mut writer = Writer {
+            file: None,
+            next_sequence: 0,
+            last_hash: encode_hash(GENESIS_HASH),
+        };
+        if let Some(path) = files(&config.dir)?.pop() {
+            if let Some(last) = read_records(&path)?.pop() {
+                writer.next_sequence = last.body.sequence + 1;
+                writer.last_hash = last.hash;
+            }
+            let file = OpenOptions::new()
+                .append(true)
+                .open(&path)
+                .map_err(io_error(&path))?;
+            let size = file.metadata().map_err(io_error(&path))?.len();
+            writer.file = Some((file, size));
+        }
+        debug!(
+            dir = %config.dir.display(),
+            next_sequence = writer.next_sequence,
+            "Audit log opened"
+        );
+
+        Ok(Self {
+            dir: config.dir.clone(),
+            max_file_size: config.max_file_size,
+            writer: Mutex::new(writer),
+        })
+    }
+
+    /// Starts the audit trail of a request received.
+    pub fn start(
+        self: &Arc<Self>,
+        method: &'static str,
+        caller: Option<String>,
+        request: &impl prost::Message,
+    ) -> AuditTrail {
+        AuditTrail {
+            log: self.clone(),
+            entry: AuditEntry::received(method, caller, request),
+        }
+    }
+
+    /// Appends the record of a completed proof request, synced to the disk
+    /// before returning.
+    ///
+    /// Blocks on the disk, hence is to be called off the async runtime.
+    pub fn append(&self, entry: CompletedEntry) -> Result<AuditRecord, AuditLogError> {
+        let mut writer = self.writer.lock().expect("audit log lock poisoned");
+        let mut body = entry.0;
+        body.sequence = writer.next_sequence;
+        body.prev_hash = writer.last_hash.clone();
+        let record = AuditRecord {
+            hash: body.hash(),
+            body,
+        };
+        let mut line = serde_json::to_vec(&record).expect("audit records are serializable");
+        line.push(b'\n');
+
+        let path = self.dir.join(file_name(record.body.sequence));
+        let roll = writer
+            .file
+            .as_ref()
+            .is_none_or(|(_, size)| *size > 0 && size + line.len() as u64 > self.max_file_size);
+        if roll {
+            writer.file = Some((create(&path)?, 0));
+        }
+        let (file, size) = writer.file.as_mut().expect("the file is open");
+        file.write_all(&line).map_err(io_error(&path))?;
+        file.sync_data().map_err(io_error(&path))?;
+        *size += line.len() as u64;
+
+        writer.next_sequence += 1;
+        writer.last_hash = record.hash.clone();
+
+        Ok(record)
+    }
+
+    /// Removes the files beyond the retention, ranked from the most recent
+    /// one, along with all the older ones. The file being written is always
+    /// kept.
+    pub fn prune(&self, retention: &RetentionPolicy) -> Result<Reclaimed, AuditLogError> {
+        let now = SystemTime::now();
+        let mut files = files(&self.dir)?;
+        files.pop();
+
+        let mut reclaimed = Reclaimed::default();
+        let mut expired = false;
+        for (rank, path) in files.iter().rev().enumerate() {
+            let metadata = std::fs::metadata(path).map_err(io_error(path))?;
+            let age = metadata
+                .modified()
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .unwrap_or_default();
+            // Ranked after the file being written.
+            expired = expired || retention.expires(age, rank + 1);
+            if expired {
+                std::fs::remove_file(path).map_err(io_error(path))?;
+                reclaimed.items += 1;
+                reclaimed.bytes += metadata.len();
+            }
+        }
+
+        Ok(reclaimed)
+    }
+
+    /// Checks the chain of the records of the directory, returning their
+    /// number.
+    pub fn verify(dir: &Path) -> Result<u64, AuditLogError> {
+        let mut expected_sequence = None;
+        let mut prev_hash = encode_hash(GENESIS_HASH);
+        let mut count = 0;
+
+        for path in files(dir)? {
+            for record in read_records(&path)? {
+                let sequence = record.body.sequence;
+                let broken = |reason| AuditLogError::BrokenChain { sequence, reason };
+                if expected_sequence.is_some_and(|expected| expected != sequence) {
+                    return Err(broken("unexpected sequence number"));
+                }
+                // The first record may follow records which were archived.
+                if (expected_sequence.is_some() || sequence == 0)
+                    && record.body.prev_hash != prev_hash
+                {
+                    return Err(broken("previous hash mismatch"));
+                }
+                if record.body.hash() != record.hash {
+                    return Err(broken("hash mismatch"));
+                }
+
+                expected_sequence = Some(sequence + 1);
+                prev_hash = record.hash;
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+fn file_name(first_sequence: u64) -> String {
+    format!("{FILE_PREFIX}{first_sequence:020}.{FILE_EXTENSION}")
+}
+
+/// Files of the log, in the order of their records.
+fn files(dir: &Path) -> Result<Vec<PathBuf>, AuditLogError> {
+    let mut files = std::fs::read_dir(dir)
+        .map_err(io_error(dir))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == FILE_EXTENSION)
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(FILE_PREFIX))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+
+    Ok(files)
+}
+
+fn read_records(path: &Path) -> Result<Vec<AuditRecord>, AuditLogError> {
+    let file = File::open(path).map_err(io_error(path))?;
+
+    BufReader::new(file)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.is_empty()))
+        .map(|(index, line)| {
+            let line = line.map_err(io_error(path))?;
+            serde_json::from_str(&line).map_err(|source| AuditLogError::InvalidRecord {
+                path: path.to_path_buf(),
+                line: index + 1,
+                source,
+            })
+        })
+        .collect()
+}
+
+fn create(path: &Path) -> Result<File, AuditLogError> {
+    OpenOptions::new()
+        .create_new(true)
+        .append(true)
+        .open(path)
+        .map_err(io_error(path))
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> AuditLogError + '_ {
+    move |source| AuditLogError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn digest(message: &impl prost::Message) -> String {
+    encode_hash(Sha256::digest(message.encode_to_vec()).into())
+}
+
+fn encode_hash(hash: [u8; 32]) -> String {
+    format!("0x{}", hex::encode(hash))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use aggkit_prover_types::v1::GenerateAggchainProofResponse;
+
+    use super::*;
+
+    fn entry() -> AuditEntry {
+        AuditEntry::received(
+            "GenerateAggchainProof",
+            Some("aggsender".to_string()),
+            &GenerateAggchainProofResponse::default(),
+        )
+    }
+
+    fn proven() -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        Ok(Response::new(GenerateAggchainProofResponse {
+            end_block: 10,
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn records_are_chained_across_files_and_restarts() {
+        let dir = std::env::temp_dir().join(format!("audit-log-{}", std::process::id()));
+        _ = std::fs::remove_dir_all(&dir);
+        let config = AuditLogConfig {
+            dir: dir.clone(),
+            max_file_size: 1,
+            retention: Default::default(),
+        };
+
+        let log = AuditLog::open(&config).unwrap();
+        let first = log.append(entry().complete(&proven())).unwrap();
+        let failed: Result<Response<GenerateAggchainProofResponse>, _> =
+            Err(Status::internal("failed"));
+        let second = log.append(entry().complete(&failed)).unwrap();
+        assert_eq!(second.body.prev_hash, first.hash);
+        assert!(matches!(
+            second.body.outcome,
+            AuditOutcome::Failed { ref code, .. } if code == "Internal"
+        ));
+        drop(log);
+
+        let log = AuditLog::open(&config).unwrap();
+        let third = log.append(entry().complete(&proven())).unwrap();
+        assert_eq!(third.body.sequence, 2);
+        assert_eq!(third.body.prev_hash, second.hash);
+        assert_eq!(files(&dir).unwrap().len(), 3);
+        assert_eq!(AuditLog::verify(&dir).unwrap(), 3);
+
+        // Altering a record breaks the chain.
+        let path = dir.join(file_name(1));
+        let altered = std::fs::read_to_string(&path)
+            .unwrap()
+            .replace("aggsender", "someone");
+        std::fs::write(&path, altered).unwrap();
+        assert!(matches!(
+            AuditLog::verify(&dir),
+            Err(AuditLogError::BrokenChain { sequence: 1, .. })
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    #[test]
+    fn oldest_files_are_pruned() {
+        let dir = std::env::temp_dir().join(format!("audit-log-prune-{}", std::process::id()));
+        _ = std::fs::remove_dir_all(&dir);
+        let config = AuditLogConfig {
+            dir: dir.clone(),
+            max_file_size: 1,
+            retention: Default::default(),
+        };
+
+        let log = AuditLog::open(&config).unwrap();
+        for _ in 0..4 {
+            log.append(entry().complete(&proven())).unwrap();
+        }
+        assert_eq!(log.prune(&config.retention).unwrap(), Reclaimed::default());
+
+        let retention = RetentionPolicy {
+            max_age: None,
+            max_count: Some(2),
+        };
+        let reclaimed = log.prune(&retention).unwrap();
+        assert_eq!(reclaimed.items, 2);
+        assert!(reclaimed.bytes > 0);
+
+        // The chain starts from the first record kept, and goes on.
+        assert_eq!(files(&dir).unwrap()[0], dir.join(file_name(2)));
+        log.append(entry().complete(&proven())).unwrap();
+        assert_eq!(AuditLog::verify(&dir).unwrap(), 3);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use aggkit_prover_config::ConfigFormat;
 use clap::{Parser, Subcommand, ValueHint};
 
 use crate::version;
@@ -16,12 +17,40 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     Run {
-        /// The path to the configuration file.
+        /// The path to the configuration file, in TOML, YAML or JSON.
         #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "aggkit-prover.toml", env = "CONFIG_PATH")]
         config_path: PathBuf,
+
+        /// The format of the configuration file, from its extension if unset.
+        #[arg(long, value_name = "toml|yaml|json", env = "CONFIG_FORMAT")]
+        config_format: Option<ConfigFormat>,
+
+        /// Prove the jobs enqueued by the gRPC API of other processes, see
+        /// `[dispatch]`, instead of serving the gRPC API.
+        #[arg(long)]
+        worker: bool,
+
+        /// The path to a state snapshot to restore at startup, as taken by
+        /// `GET /snapshot` on the admin API.
+        #[arg(long, value_hint = ValueHint::FilePath, conflicts_with = "worker")]
+        restore: Option<PathBuf>,
+
+        /// Serve the proof verification API only, see `[verifier]`, instead
+        /// of the proving one.
+        #[arg(long, conflicts_with_all = ["worker", "restore"])]
+        verify_only: bool,
     },
 
-    Config,
+    /// Print the default configuration, or check a configuration file.
+    #[command(args_conflicts_with_subcommands = true)]
+    Config {
+        /// Print the JSON schema of the configuration instead.
+        #[arg(long)]
+        schema: bool,
+
+        #[command(subcommand)]
+        cmd: Option<ConfigCommands>,
+    },
 
     ValidateConfig {
         /// The path to the aggkit-prover configuration file.
@@ -29,9 +58,146 @@ pub enum Commands {
         config_path: PathBuf,
     },
 
-    /// Proof verification key.
-    Vkey,
+    /// Proof verification key, vkey hash and SP1 version of a program.
+    Vkey {
+        /// The path to the program ELF, the aggchain proof program if unset.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        elf: Option<PathBuf>,
+
+        /// Print the output as JSON.
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Proof verification key selector.
     VkeySelector,
+
+    /// Verify a proof offline and print its public values.
+    VerifyProof {
+        /// The path to the proof, a proof envelope unless `--raw` is set.
+        #[arg(value_hint = ValueHint::FilePath)]
+        proof: PathBuf,
+
+        /// The proof is an SP1 proof with its public values, as saved by the
+        /// SP1 SDK.
+        #[arg(long)]
+        raw: bool,
+
+        /// The path to the bincode encoded verifying key.
+        #[arg(long, value_hint = ValueHint::FilePath, conflicts_with = "elf")]
+        vkey: Option<PathBuf>,
+
+        /// The path to the program ELF, the aggchain proof program if neither
+        /// this nor `--vkey` is set.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        elf: Option<PathBuf>,
+
+        /// Print the output as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Benchmark a program on a golden vector, executing it and optionally
+    /// proving it locally.
+    Bench {
+        /// The path to the golden vector providing the witness.
+        #[arg(value_hint = ValueHint::FilePath)]
+        fixture: PathBuf,
+
+        /// The number of runs of every phase.
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+
+        /// Also prove the program locally, with the proof type of the golden
+        /// vector.
+        #[arg(long)]
+        prove: bool,
+
+        /// The path to the program ELF, the aggchain proof program if unset.
+        #[arg(long, value_hint = ValueHint::FilePath)]
+        elf: Option<PathBuf>,
+
+        /// Print the report as JSON.
+        #[arg(long)]
+        json: bool,
+
+        /// The key the golden vector is encrypted with, as a secret
+        /// reference, e.g. `env:GOLDEN_KEY`.
+        #[arg(long, value_name = "secret")]
+        encryption_key: Option<String>,
+    },
+
+    /// Replay the captured golden vectors against the current aggchain proof
+    /// program, failing on any drift of the public values.
+    ReplayGoldenVectors {
+        /// The directory holding the golden vectors.
+        #[arg(value_hint = ValueHint::DirPath)]
+        dir: PathBuf,
+
+        /// The keys the golden vectors are encrypted with, as secret
+        /// references, e.g. `env:GOLDEN_KEY`. Repeated across a rotation.
+        #[arg(long, value_name = "secret")]
+        encryption_key: Vec<String>,
+    },
+
+    /// Check the hash chain of the records of an audit log.
+    VerifyAuditLog {
+        /// The directory holding the audit log files.
+        #[arg(value_hint = ValueHint::DirPath)]
+        dir: PathBuf,
+    },
+
+    /// Remove the aggregation proofs cached on disk beyond the retention of
+    /// the proof cache, then the proofs no longer referenced.
+    GcProofCache {
+        /// The path to the configuration file, in TOML, YAML or JSON.
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "aggkit-prover.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+
+        /// The format of the configuration file, from its extension if unset.
+        #[arg(long, value_name = "toml|yaml|json", env = "CONFIG_FORMAT")]
+        config_format: Option<ConfigFormat>,
+
+        /// Report what would be removed, without removing it.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Write the default configuration, commented, to a file.
+    Init {
+        /// The path to the configuration file to write.
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "aggkit-prover.toml")]
+        output: PathBuf,
+
+        /// Overwrite the file if it already exists.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Check the consistency of the configuration, e.g. that the listeners
+    /// don't collide and that the timeouts are sane.
+    Validate {
+        /// The path to the configuration file, in TOML, YAML or JSON.
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "aggkit-prover.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+
+        /// The format of the configuration file, from its extension if unset.
+        #[arg(long, value_name = "toml|yaml|json", env = "CONFIG_FORMAT")]
+        config_format: Option<ConfigFormat>,
+    },
+
+    /// Print the configuration resolved from the file, the environment and
+    /// the defaults.
+    PrintEffective {
+        /// The path to the configuration file, in TOML, YAML or JSON.
+        #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "aggkit-prover.toml", env = "CONFIG_PATH")]
+        config_path: PathBuf,
+
+        /// The format of the configuration file, from its extension if unset.
+        #[arg(long, value_name = "toml|yaml|json", env = "CONFIG_FORMAT")]
+        config_format: Option<ConfigFormat>,
+    },
 }
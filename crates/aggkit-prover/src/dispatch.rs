@@ -0,0 +1,655 @@
+//! Dispatch of the proof requests to a fleet of workers through a Redis
+//! stream, so that the gRPC API and the proving scale independently.
+//!
+//! The gRPC API appends every request to the stream as a job, then waits for
+//! its outcome on a list keyed by the id of the job. The workers share a
+//! consumer group, each job being delivered to one of them, which proves it
+//! with its own aggchain proof service and pushes the outcome to the list.
+//! The outcome is the result of the service, its errors included.
+//!
+//! A worker renews the jobs it is proving, such that the jobs of a worker
+//! which died are claimed by another one once idle for the claim timeout.
+//! The jobs delivered more than the maximum number of times are failed
+//! instead, e.g. when proving them kills the workers.
+//!
+//! The jobs and the outcomes carry the inputs and the proofs, and are
+//! compressed with zstd, then encrypted, when configured. Either is read, so
+//! that a fleet can switch compression or encryption on one process at a
+//! time.
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aggchain_proof_service::{
+    service::{AggchainProofService, AggchainProofServiceRequest, AggchainProofServiceResponse},
+    Error as ServiceError,
+};
+use aggkit_prover_config::RedisStreamConfig;
+use aggkit_prover_types::{
+    codec::{Codec, CodecError},
+    compression,
+};
+use prover_errors::ErrorCode;
+use prover_utils::{
+    encryption::{self, Cipher, EncryptionError},
+    retention::RetentionPolicy,
+};
+use redis::{
+    aio::ConnectionManager,
+    streams::{
+        StreamId, StreamPendingCountReply, StreamRangeReply, StreamReadOptions, StreamReadReply,
+    },
+    AsyncCommands as _, Client,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use tokio::sync::{OnceCell, Semaphore};
+use tokio_util::sync::CancellationToken;
+use tower::{BoxError, Service, ServiceExt as _};
+use tracing::{error, info, warn};
+
+use crate::snapshot::DispatchedJob;
+
+/// Field of the stream entries holding the job.
+const JOB_FIELD: &str = "job";
+
+/// Time the outcome of a job is kept, should the gRPC API have stopped
+/// waiting for it.
+const OUTCOME_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Time a worker waits for a job before checking for the shutdown.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Number of times a worker renews its jobs within the claim timeout.
+const RENEWALS_PER_CLAIM_TIMEOUT: u32 = 4;
+
+#[derive(Debug, thiserror::Error)]
+pub enum DispatchError {
+    #[error("Redis dispatch failure")]
+    Redis(#[from] redis::RedisError),
+
+    #[error("Unable to encode or decode a proof job")]
+    Codec(#[from] CodecError),
+
+    #[error("Unable to compress or decompress a proof job")]
+    Compression(#[source] std::io::Error),
+
+    #[error("Unable to encrypt or decrypt a proof job")]
+    Encryption(#[from] EncryptionError),
+
+    #[error("No outcome of the proof job {id} after {timeout:?}")]
+    Timeout { id: String, timeout: Duration },
+}
+
+/// Outcome of a job, pushed by the worker: the result of its service, the
+/// errors being made serializable.
+type JobOutcome = Result<AggchainProofServiceResponse, ServiceError>;
+
+/// Outcome of the jobs failed by the worker itself, e.g. unreadable.
+fn job_failed(message: String) -> JobOutcome {
+    Err(ServiceError::WorkerFailed {
+        code: ErrorCode::Internal.code(),
+        message,
+    })
+}
+
+fn outcome_key(stream: &str, id: &str) -> String {
+    format!("{stream}:outcome:{id}")
+}
+
+/// Encodes a job or an outcome, compressed with the configured level then
+/// encrypted with the configured key.
+fn encode<T: Serialize>(
+    config: &RedisStreamConfig,
+    cipher: Option<&Cipher>,
+    value: &T,
+) -> Result<Vec<u8>, DispatchError> {
+    let bytes = compression::compress(config.codec.encode(value)?, config.compression_level)
+        .map_err(DispatchError::Compression)?;
+
+    Ok(encryption::encrypt(bytes, cipher)?)
+}
+
+/// Decodes a job or an outcome, decrypting then decompressing it first if
+/// encrypted or compressed.
+fn decode<T: DeserializeOwned>(
+    codec: Codec,
+    cipher: Option<&Cipher>,
+    bytes: &[u8],
+) -> Result<T, DispatchError> {
+    let bytes = encryption::decrypt(bytes, cipher)?;
+    let bytes = compression::decompress(&bytes).map_err(DispatchError::Compression)?;
+
+    Ok(codec.decode(&bytes)?)
+}
+
+/// Service enqueueing the proof requests for the workers, and waiting for
+/// their outcome.
+#[derive(Clone)]
+pub struct RedisStreamDispatcher {
+    client: Client,
+    connection: Arc<OnceCell<ConnectionManager>>,
+    config: Arc<RedisStreamConfig>,
+    cipher: Option<Cipher>,
+}
+
+impl RedisStreamDispatcher {
+    /// Connects lazily, on the first job enqueued.
+    pub fn new(config: &RedisStreamConfig) -> Result<Self, DispatchError> {
+        Ok(Self {
+            client: Client::open(config.url.expose())?,
+            connection: Arc::new(OnceCell::new()),
+            config: Arc::new(config.clone()),
+            cipher: config.encryption.as_ref().map(Cipher::new),
+        })
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager, DispatchError> {
+        let connection = self
+            .connection
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await?;
+
+        Ok(connection.clone())
+    }
+
+    async fn dispatch(
+        self,
+        request: AggchainProofServiceRequest,
+    ) -> Result<JobOutcome, DispatchError> {
+        let job = encode(&self.config, self.cipher.as_ref(), &request)?;
+        // The outcome is awaited with a blocking command, holding its
+        // connection until the job is proven, hence not the shared one.
+        let mut waiting = self.client.get_multiplexed_async_connection().await?;
+        let mut connection = self.connection().await?;
+        let id: String = connection
+            .xadd(&self.config.stream, "*", &[(JOB_FIELD, job)])
+            .await?;
+        info!(%id, "Proof job enqueued");
+
+        let outcome: Option<(String, Vec<u8>)> = waiting
+            .blpop(
+                outcome_key(&self.config.stream, &id),
+                self.config.result_timeout.as_secs_f64(),
+            )
+            .await?;
+        let Some((_, outcome)) = outcome else {
+            // Not proven yet, the job is dropped if still queued.
+            let _: () = connection.xdel(&self.config.stream, &[&id]).await?;
+            return Err(DispatchError::Timeout {
+                id,
+                timeout: self.config.result_timeout,
+            });
+        };
+
+        decode(self.config.codec, self.cipher.as_ref(), &outcome)
+    }
+
+    /// Number of jobs in the stream, waiting or being proven.
+    pub async fn queue_depth(&self) -> Result<u64, DispatchError> {
+        let mut connection = self.connection().await?;
+
+        Ok(connection.xlen(&self.config.stream).await?)
+    }
+
+    /// Trims the jobs of the stream beyond the retention, returning their
+    /// number. The trimming is approximate, Redis removing whole nodes of the
+    /// stream only.
+    pub async fn trim(&self, retention: &RetentionPolicy) -> Result<u64, DispatchError> {
+        let mut connection = self.connection().await?;
+        let mut trimmed = 0;
+
+        if let Some(max_count) = retention.max_count {
+            let removed: u64 = redis::cmd("XTRIM")
+                .arg(&self.config.stream)
+                .arg("MAXLEN")
+                .arg("~")
+                .arg(max_count)
+                .query_async(&mut connection)
+                .await?;
+            trimmed += removed;
+        }
+        if let Some(max_age) = retention.max_age {
+            // The ids of the entries start with the time they were added.
+            let min_time = SystemTime::now()
+                .checked_sub(max_age)
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .unwrap_or_default();
+            let removed: u64 = redis::cmd("XTRIM")
+                .arg(&self.config.stream)
+                .arg("MINID")
+                .arg("~")
+                .arg(format!("{}-0", min_time.as_millis()))
+                .query_async(&mut connection)
+                .await?;
+            trimmed += removed;
+        }
+
+        Ok(trimmed)
+    }
+
+    /// Jobs of the stream, waiting or being proven, as stored in it.
+    pub async fn export_jobs(&self) -> Result<Vec<DispatchedJob>, DispatchError> {
+        let mut connection = self.connection().await?;
+        let entries: StreamRangeReply = connection.xrange_all(&self.config.stream).await?;
+
+        Ok(entries
+            .ids
+            .into_iter()
+            .filter_map(|entry| {
+                let job = entry.get::<Vec<u8>>(JOB_FIELD)?;
+                Some(DispatchedJob {
+                    id: entry.id,
+                    job: hex::encode(job),
+                })
+            })
+            .collect())
+    }
+
+    /// Appends the jobs to the stream with their ids, returning the number of
+    /// jobs appended. The jobs already in the stream, or older than its last
+    /// one, are skipped.
+    pub async fn import_jobs(&self, jobs: Vec<DispatchedJob>) -> u64 {
+        let mut connection = match self.connection().await {
+            Ok(connection) => connection,
+            Err(error) => {
+                warn!(
+                    ?error,
+                    "Unable to connect to Redis, dropping the proof jobs"
+                );
+                return 0;
+            }
+        };
+        let mut imported = 0;
+
+        for DispatchedJob { id, job } in jobs {
+            let job = match hex::decode(&job) {
+                Ok(job) => job,
+                Err(error) => {
+                    warn!(%id, ?error, "Invalid proof job, skipping it");
+                    continue;
+                }
+            };
+            let added: Result<String, _> = connection
+                .xadd(&self.config.stream, &id, &[(JOB_FIELD, job)])
+                .await;
+            match added {
+                Ok(_) => imported += 1,
+                Err(error) => warn!(%id, ?error, "Unable to enqueue the proof job, skipping it"),
+            }
+        }
+
+        imported
+    }
+}
+
+impl Service<AggchainProofServiceRequest> for RedisStreamDispatcher {
+    type Response = AggchainProofServiceResponse;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: AggchainProofServiceRequest) -> Self::Future {
+        let dispatcher = self.clone();
+
+        // The errors of the workers are reported by the gRPC API as if
+        // proven locally.
+        Box::pin(async move { Ok(dispatcher.dispatch(request).await??) })
+    }
+}
+
+/// Proves the jobs of the stream until cancelled, then waits for the jobs
+/// being proven.
+///
+/// The jobs left pending by the workers which died are claimed once idle for
+/// the claim timeout, before the new jobs are read.
+pub async fn run_worker(
+    config: &RedisStreamConfig,
+    consumer: &str,
+    service: AggchainProofService,
+    cancellation_token: CancellationToken,
+) -> Result<(), DispatchError> {
+    let client = Client::open(config.url.expose())?;
+    // The blocking reads hold their connection, the outcomes are pushed and
+    // the jobs renewed through another one.
+    let mut jobs = ConnectionManager::new(client.clone()).await?;
+    let outcomes = ConnectionManager::new(client).await?;
+
+    // Created at the start of the stream, such that the jobs enqueued before
+    // the first worker started are delivered as well.
+    match jobs
+        .xgroup_create_mkstream::<_, _, _, ()>(&config.stream, &config.consumer_group, "0")
+        .await
+    {
+        Ok(()) => {}
+        Err(error) if error.code() == Some("BUSYGROUP") => {}
+        Err(error) => return Err(error.into()),
+    }
+
+    let cipher = config.encryption.as_ref().map(Cipher::new);
+    let concurrency = config.worker_concurrency.get();
+    let permits = Arc::new(Semaphore::new(concurrency));
+    let options = StreamReadOptions::default()
+        .group(&config.consumer_group, consumer)
+        .count(1)
+        .block(READ_TIMEOUT.as_millis() as usize);
+    let mut claim_cursor = "0-0".to_string();
+    info!(stream = %config.stream, %consumer, %concurrency, "Waiting for proof jobs");
+
+    loop {
+        let permit = tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            permit = permits.clone().acquire_owned() => permit.expect("the semaphore is never closed"),
+        };
+        let next = tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            next = next_job(&mut jobs, config, consumer, &options, &mut claim_cursor) => next,
+        };
+        let (entry, deliveries) = match next {
+            Ok(Some(next)) => next,
+            Ok(None) => continue,
+            Err(error) => {
+                warn!(?error, "Unable to read the proof jobs, retrying");
+                tokio::time::sleep(READ_TIMEOUT).await;
+                continue;
+            }
+        };
+
+        let service = service.clone();
+        let mut outcomes = outcomes.clone();
+        let config = config.clone();
+        let cipher = cipher.clone();
+        let consumer = consumer.to_string();
+        tokio::spawn(async move {
+            let outcome = if deliveries > config.max_deliveries.get() as usize {
+                warn!(id = %entry.id, %deliveries, "Failing the proof job delivered too many times");
+                job_failed(format!(
+                    "Proof job delivered {deliveries} times, the workers proving it having died"
+                ))
+            } else {
+                let proving = prove(service, config.codec, cipher.as_ref(), &entry);
+                renewing(&mut outcomes, &config, &consumer, &entry.id, proving).await
+            };
+            if let Err(error) =
+                complete(outcomes, &config, cipher.as_ref(), &entry.id, &outcome).await
+            {
+                error!(id = %entry.id, ?error, "Unable to complete the proof job");
+            }
+            drop(permit);
+        });
+    }
+
+    info!("Waiting for the proof jobs being proven");
+    let _ = permits.acquire_many(concurrency as u32).await;
+
+    Ok(())
+}
+
+/// Next job to prove along with the number of times it was delivered: a job
+/// left pending by a worker which died, else a new one.
+async fn next_job(
+    connection: &mut ConnectionManager,
+    config: &RedisStreamConfig,
+    consumer: &str,
+    options: &StreamReadOptions,
+    claim_cursor: &mut String,
+) -> Result<Option<(StreamId, usize)>, DispatchError> {
+    if let Some(entry) = claim(connection, config, consumer, claim_cursor).await? {
+        let pending: StreamPendingCountReply = connection
+            .xpending_count(
+                &config.stream,
+                &config.consumer_group,
+                &entry.id,
+                &entry.id,
+                1,
+            )
+            .await?;
+        let deliveries = pending
+            .ids
+            .first()
+            .map_or(1, |pending| pending.times_delivered);
+        info!(id = %entry.id, %deliveries, "Proof job of a stopped worker claimed");
+
+        return Ok(Some((entry, deliveries)));
+    }
+
+    let reply: Option<StreamReadReply> = connection
+        .xread_options(&[&config.stream], &[">"], options)
+        .await?;
+
+    Ok(reply
+        .into_iter()
+        .flat_map(|reply| reply.keys)
+        .flat_map(|key| key.ids)
+        .next()
+        .map(|entry| (entry, 1)))
+}
+
+/// Claims a job idle for the claim timeout, its worker having stopped
+/// renewing it. The pending jobs are scanned from the cursor, which wraps
+/// around once they are all scanned.
+async fn claim(
+    connection: &mut ConnectionManager,
+    config: &RedisStreamConfig,
+    consumer: &str,
+    cursor: &mut String,
+) -> Result<Option<StreamId>, DispatchError> {
+    // The reply is the next cursor, the claimed jobs, then the ids of the
+    // pending jobs which were deleted from the stream.
+    let reply: Vec<redis::Value> = redis::cmd("XAUTOCLAIM")
+        .arg(&config.stream)
+        .arg(&config.consumer_group)
+        .arg(consumer)
+        .arg(config.claim_idle_timeout.as_millis() as u64)
+        .arg(cursor.as_str())
+        .arg("COUNT")
+        .arg(1)
+        .query_async(connection)
+        .await?;
+    let (Some(next), Some(claimed)) = (reply.first(), reply.get(1)) else {
+        return Ok(None);
+    };
+    *cursor = redis::from_redis_value(next)?;
+    let claimed: StreamRangeReply = redis::from_redis_value(claimed)?;
+
+    Ok(claimed.ids.into_iter().next())
+}
+
+/// Proves the job, renewing it meanwhile such that no other worker claims it.
+async fn renewing(
+    connection: &mut ConnectionManager,
+    config: &RedisStreamConfig,
+    consumer: &str,
+    id: &str,
+    proving: impl Future<Output = JobOutcome>,
+) -> JobOutcome {
+    tokio::pin!(proving);
+    let mut renewals = tokio::time::interval(
+        (config.claim_idle_timeout / RENEWALS_PER_CLAIM_TIMEOUT).max(Duration::from_secs(1)),
+    );
+
+    loop {
+        tokio::select! {
+            outcome = &mut proving => return outcome,
+            _ = renewals.tick() => {
+                // Claimed again by its worker, which resets its idle time
+                // without counting a delivery.
+                let renewed: Result<redis::Value, _> = redis::cmd("XCLAIM")
+                    .arg(&config.stream)
+                    .arg(&config.consumer_group)
+                    .arg(consumer)
+                    .arg(0)
+                    .arg(id)
+                    .arg("JUSTID")
+                    .query_async(connection)
+                    .await;
+                if let Err(error) = renewed {
+                    warn!(%id, ?error, "Unable to renew the proof job");
+                }
+            }
+        }
+    }
+}
+
+async fn prove(
+    service: AggchainProofService,
+    codec: Codec,
+    cipher: Option<&Cipher>,
+    entry: &StreamId,
+) -> JobOutcome {
+    let request = match entry
+        .get::<Vec<u8>>(JOB_FIELD)
+        .map(|job| decode(codec, cipher, &job))
+    {
+        Some(Ok(request)) => request,
+        Some(Err(error)) => return job_failed(error.to_string()),
+        None => return job_failed(format!("Missing {JOB_FIELD} field")),
+    };
+    info!(id = %entry.id, "Proving the proof job");
+
+    service.oneshot(request).await.map_err(|error| {
+        let error = error.into_serializable();
+        if let ServiceError::WorkerFailed { message, .. } = &error {
+            error!(id = %entry.id, %message, "Unable to prove the proof job");
+        }
+        error
+    })
+}
+
+/// Pushes the outcome of the job, then removes it from the stream.
+async fn complete(
+    mut connection: ConnectionManager,
+    config: &RedisStreamConfig,
+    cipher: Option<&Cipher>,
+    id: &str,
+    outcome: &JobOutcome,
+) -> Result<(), DispatchError> {
+    let key = outcome_key(&config.stream, id);
+    let () = redis::pipe()
+        .atomic()
+        .rpush(&key, encode(config, cipher, outcome)?)
+        .ignore()
+        .expire(&key, OUTCOME_TTL.as_secs() as i64)
+        .ignore()
+        .xack(&config.stream, &config.consumer_group, &[id])
+        .ignore()
+        .xdel(&config.stream, &[id])
+        .ignore()
+        .query_async(&mut connection)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aggchain_proof_service::{
+        ger_liveness::InvalidGer, optimistic_mode::OptimisticModeRejection,
+    };
+    use prover_executor::cost::BudgetExhausted;
+
+    use super::*;
+
+    fn round_trip(codec: Codec, outcome: JobOutcome) -> JobOutcome {
+        codec.decode(&codec.encode(&outcome).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn outcomes_round_trip() {
+        for codec in [Codec::Bincode, Codec::Cbor, Codec::Json] {
+            let rejection = OptimisticModeRejection::TooManyBlocks {
+                requested: 200,
+                max: 100,
+            };
+            let outcome = round_trip(
+                codec,
+                Err(ServiceError::OptimisticModeRejected(rejection.clone())),
+            );
+            assert!(
+                matches!(outcome, Err(ServiceError::OptimisticModeRejected(decoded)) if decoded == rejection)
+            );
+
+            let exhausted = BudgetExhausted {
+                network_id: 1,
+                month: "2026-10".parse().unwrap(),
+                budget: 1_000,
+                used: 1_200,
+            };
+            let outcome = round_trip(
+                codec,
+                Err(ServiceError::CycleBudgetExhausted(exhausted.clone())),
+            );
+            assert!(
+                matches!(outcome, Err(ServiceError::CycleBudgetExhausted(decoded)) if decoded == exhausted)
+            );
+
+            let outcome = round_trip(codec, job_failed("out of cycles".to_string()));
+            assert!(matches!(
+                outcome,
+                Err(ServiceError::WorkerFailed { message, .. }) if message == "out of cycles"
+            ));
+
+            let invalid = InvalidGer::Missing {
+                ger: agglayer_interop::types::Digest([1; 32]),
+                block_number: 150,
+                block_index: 1,
+            };
+            let outcome = round_trip(codec, Err(ServiceError::InvalidGer(invalid.clone())));
+            assert!(
+                matches!(outcome, Err(ServiceError::InvalidGer(decoded)) if decoded == invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn errors_of_the_dependencies_keep_their_code() {
+        let error = ServiceError::AlloyProviderInitializationFailed(anyhow::anyhow!("no l1 rpc"))
+            .into_serializable();
+
+        let outcome = round_trip(Codec::Bincode, Err(error));
+        let Err(error) = outcome else {
+            panic!("expected an error");
+        };
+        assert_eq!(error.code(), ErrorCode::Internal);
+        assert!(matches!(
+            error,
+            ServiceError::WorkerFailed { message, .. }
+                if message == "Unable to create alloy provider: no l1 rpc"
+        ));
+    }
+
+    #[test]
+    fn outcomes_are_compressed_then_encrypted() {
+        let config: RedisStreamConfig = serde_json::from_value(serde_json::json!({
+            "url": "redis://localhost:6379/0",
+            "compression-level": 3,
+            "encryption": { "key": "11".repeat(32) },
+        }))
+        .unwrap();
+        let cipher = config.encryption.as_ref().map(Cipher::new);
+        let outcome = job_failed("out of cycles".repeat(100));
+
+        let encrypted = encode(&config, cipher.as_ref(), &outcome).unwrap();
+        assert!(encrypted.starts_with(&encryption::ENCRYPTION_MAGIC));
+        let decoded: JobOutcome = decode(config.codec, cipher.as_ref(), &encrypted).unwrap();
+        assert!(matches!(
+            decoded,
+            Err(ServiceError::WorkerFailed { message, .. }) if message.len() == 1_300
+        ));
+        assert!(matches!(
+            decode::<JobOutcome>(config.codec, None, &encrypted),
+            Err(DispatchError::Encryption(_))
+        ));
+
+        // The jobs written before encryption was switched on are still read.
+        let plain = encode(&config, None, &outcome).unwrap();
+        assert!(plain.starts_with(&compression::ZSTD_MAGIC));
+        assert!(decode::<JobOutcome>(config.codec, cipher.as_ref(), &plain).is_ok());
+    }
+}
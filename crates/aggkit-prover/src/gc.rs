@@ -0,0 +1,177 @@
+//! Background removal of the state persisted beyond its retention: the files
+//! of the audit log, the aggregation proofs cached on disk and the jobs of the
+//! dispatch queue, so that long-running deployments don't have to prune them
+//! by hand.
+use std::{
+    path::PathBuf,
+    sync::{Arc, LazyLock},
+    time::Duration,
+};
+
+use opentelemetry::{global, metrics::Counter, KeyValue};
+use proposer_service::config::ProofCacheConfig;
+use prover_utils::retention::RetentionPolicy;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+
+use crate::audit::AuditLog;
+
+const METER_NAME: &str = "aggkit_prover";
+
+static GC_REMOVED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("gc_removed")
+        .with_description("Files, proofs or jobs removed by the garbage collection, by store")
+        .build()
+});
+
+static GC_RECLAIMED_BYTES: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("gc_reclaimed_bytes")
+        .with_description("Disk space reclaimed by the garbage collection, by store")
+        .with_unit("By")
+        .build()
+});
+
+/// What a collection removed from a store.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Reclaimed {
+    pub items: u64,
+    /// Unknown for the dispatch queue, whose memory is managed by Redis.
+    pub bytes: u64,
+}
+
+enum Store {
+    AuditLog(Arc<AuditLog>, RetentionPolicy),
+    ProofCache(PathBuf, RetentionPolicy),
+    #[cfg(feature = "redis")]
+    JobStore(crate::dispatch::RedisStreamDispatcher, RetentionPolicy),
+}
+
+impl Store {
+    fn name(&self) -> &'static str {
+        match self {
+            Store::AuditLog(..) => "audit-log",
+            Store::ProofCache(..) => "proof-cache",
+            #[cfg(feature = "redis")]
+            Store::JobStore(..) => "job-store",
+        }
+    }
+
+    async fn collect(&self) -> anyhow::Result<Reclaimed> {
+        match self {
+            Store::AuditLog(log, retention) => {
+                let (log, retention) = (log.clone(), *retention);
+
+                Ok(tokio::task::spawn_blocking(move || log.prune(&retention)).await??)
+            }
+            Store::ProofCache(dir, retention) => {
+                let (dir, retention) = (dir.clone(), *retention);
+                let collection = tokio::task::spawn_blocking(move || {
+                    proposer_service::cache::collect_garbage(&dir, &retention, false)
+                })
+                .await??;
+
+                Ok(Reclaimed {
+                    items: collection.removed_proofs as u64,
+                    bytes: collection.freed_bytes,
+                })
+            }
+            #[cfg(feature = "redis")]
+            Store::JobStore(dispatcher, retention) => Ok(Reclaimed {
+                items: dispatcher.trim(retention).await?,
+                bytes: 0,
+            }),
+        }
+    }
+}
+
+/// Collector of the stores with a retention, run every interval.
+pub struct GarbageCollector {
+    interval: Duration,
+    stores: Vec<Store>,
+}
+
+impl GarbageCollector {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            stores: Vec::new(),
+        }
+    }
+
+    pub fn with_audit_log(mut self, log: Arc<AuditLog>, retention: &RetentionPolicy) -> Self {
+        if !retention.keeps_all() {
+            self.stores.push(Store::AuditLog(log, *retention));
+        }
+
+        self
+    }
+
+    /// Collects the proofs of the cache written to disk.
+    pub fn with_proof_cache(mut self, config: &ProofCacheConfig) -> Self {
+        if let Some(dir) = &config.disk_dir {
+            if !config.retention.keeps_all() {
+                self.stores
+                    .push(Store::ProofCache(dir.clone(), config.retention));
+            }
+        }
+
+        self
+    }
+
+    #[cfg(feature = "redis")]
+    pub fn with_job_store(
+        mut self,
+        dispatcher: crate::dispatch::RedisStreamDispatcher,
+        retention: &RetentionPolicy,
+    ) -> Self {
+        if !retention.keeps_all() {
+            self.stores.push(Store::JobStore(dispatcher, *retention));
+        }
+
+        self
+    }
+
+    /// Whether no store has a retention, nothing being ever collected.
+    pub fn is_empty(&self) -> bool {
+        self.stores.is_empty()
+    }
+
+    /// Collects the stores every interval, until cancelled.
+    pub async fn run(self, cancellation_token: CancellationToken) {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            self.collect().await;
+        }
+    }
+
+    async fn collect(&self) {
+        for store in &self.stores {
+            let name = store.name();
+            match store.collect().await {
+                Ok(reclaimed) => {
+                    let labels = [KeyValue::new("store", name)];
+                    GC_REMOVED.add(reclaimed.items, &labels);
+                    GC_RECLAIMED_BYTES.add(reclaimed.bytes, &labels);
+                    if reclaimed.items > 0 {
+                        info!(
+                            store = name,
+                            removed = reclaimed.items,
+                            bytes = reclaimed.bytes,
+                            "Garbage collected"
+                        );
+                    }
+                }
+                Err(error) => warn!(store = name, ?error, "Unable to collect the garbage"),
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Health of the dependencies, refreshed in the background for the operators
+//! to triage an outage at a glance.
+use std::{
+    sync::{Arc, RwLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aggchain_proof_service::config::AggchainProofServiceConfig;
+use aggkit_prover_config::Dependency;
+use futures::future::BoxFuture;
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::readiness::{probes, Endpoint, Probe, ProbeError};
+
+/// Number of jobs in the dispatch queue, waiting or being proven.
+pub type QueueDepth = Box<dyn Fn() -> BoxFuture<'static, anyhow::Result<u64>> + Send + Sync>;
+
+/// Health of one endpoint of a dependency.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct EndpointHealth {
+    pub dependency: Dependency,
+
+    /// Scheme, host and port of the endpoint.
+    pub endpoint: String,
+
+    pub reachable: bool,
+
+    /// Time the endpoint took to respond to the probe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+
+    /// Age of the latest block of the JSON-RPC nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_lag_secs: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Health of the dependencies, as of the last refresh.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "kebab-case")]
+pub struct HealthReport {
+    /// Time of the refresh, in seconds since the UNIX epoch, zero until the
+    /// first one.
+    pub checked_at: u64,
+
+    pub endpoints: Vec<EndpointHealth>,
+
+    /// Number of jobs in the dispatch queue, waiting or being proven, when
+    /// dispatched.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_depth: Option<u64>,
+}
+
+/// Background monitor of the dependencies, keeping the last health report.
+pub struct HealthMonitor {
+    probes: Vec<Probe>,
+    queue_depth: Option<QueueDepth>,
+    refresh_interval: Duration,
+    probe_timeout: Duration,
+    report: RwLock<Arc<HealthReport>>,
+}
+
+impl HealthMonitor {
+    pub fn new(
+        service: &AggchainProofServiceConfig,
+        refresh_interval: Duration,
+        probe_timeout: Duration,
+    ) -> Self {
+        Self {
+            probes: probes(service),
+            queue_depth: None,
+            refresh_interval,
+            probe_timeout,
+            report: Default::default(),
+        }
+    }
+
+    pub fn with_queue_depth(mut self, queue_depth: QueueDepth) -> Self {
+        self.queue_depth = Some(queue_depth);
+
+        self
+    }
+
+    /// Last health report.
+    pub fn report(&self) -> Arc<HealthReport> {
+        self.report
+            .read()
+            .expect("health report lock poisoned")
+            .clone()
+    }
+
+    /// Refreshes the health report every refresh interval, until cancelled.
+    pub async fn run(self: Arc<Self>, cancellation_token: CancellationToken) {
+        let mut interval = tokio::time::interval(self.refresh_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            self.refresh().await;
+        }
+    }
+
+    async fn refresh(&self) {
+        let endpoints = self.probes.iter().flat_map(|probe| {
+            probe
+                .endpoints
+                .iter()
+                .map(|endpoint| self.check(probe.dependency, endpoint))
+        });
+        let endpoints = futures::future::join_all(endpoints).await;
+
+        let queue_depth = match &self.queue_depth {
+            Some(queue_depth) => match queue_depth().await {
+                Ok(depth) => Some(depth),
+                Err(error) => {
+                    warn!(?error, "Unable to get the depth of the dispatch queue");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        *self.report.write().expect("health report lock poisoned") = Arc::new(HealthReport {
+            checked_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            endpoints,
+            queue_depth,
+        });
+    }
+
+    async fn check(&self, dependency: Dependency, endpoint: &Endpoint) -> EndpointHealth {
+        let started = Instant::now();
+        let probed = tokio::time::timeout(self.probe_timeout, endpoint.probe())
+            .await
+            .unwrap_or(Err(ProbeError::Timeout(self.probe_timeout)));
+        let latency = started.elapsed();
+
+        let head_lag = match probed {
+            Ok(()) => tokio::time::timeout(self.probe_timeout, endpoint.head_lag())
+                .await
+                .unwrap_or(Err(ProbeError::Timeout(self.probe_timeout))),
+            Err(error) => Err(error),
+        };
+
+        let (reachable, head_lag, error) = match head_lag {
+            Ok(head_lag) => (true, head_lag, None),
+            Err(error) => (
+                false,
+                None,
+                Some(format!("{:#}", anyhow::Error::from(error))),
+            ),
+        };
+
+        EndpointHealth {
+            dependency,
+            endpoint: endpoint.redacted(),
+            reachable,
+            latency_ms: reachable.then_some(latency.as_millis() as u64),
+            head_lag_secs: head_lag.map(|head_lag| head_lag.as_secs()),
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    fn monitor(endpoints: Vec<Endpoint>) -> HealthMonitor {
+        HealthMonitor {
+            probes: vec![Probe {
+                dependency: Dependency::Proposer,
+                endpoints,
+            }],
+            queue_depth: Some(Box::new(|| Box::pin(async { Ok(3) }))),
+            refresh_interval: Duration::from_secs(1),
+            probe_timeout: Duration::from_secs(1),
+            report: Default::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn report_of_each_endpoint() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let up = format!("http://{}/secret", listener.local_addr().unwrap());
+        let down = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            format!("http://{}", listener.local_addr().unwrap())
+        };
+        let monitor = monitor(vec![
+            Endpoint::Connect(up.parse().unwrap()),
+            Endpoint::Connect(down.parse().unwrap()),
+        ]);
+        assert_eq!(monitor.report().checked_at, 0);
+
+        monitor.refresh().await;
+        let report = monitor.report();
+
+        assert_ne!(report.checked_at, 0);
+        assert_eq!(report.queue_depth, Some(3));
+        assert!(report.endpoints[0].reachable && report.endpoints[0].latency_ms.is_some());
+        assert!(!report.endpoints[0].endpoint.contains("secret"));
+        assert!(!report.endpoints[1].reachable && report.endpoints[1].error.is_some());
+    }
+}
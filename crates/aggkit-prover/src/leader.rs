@@ -0,0 +1,380 @@
+//! Hot/standby election of the instance serving the proof requests.
+//!
+//! The instances of a pair contend for a lease, the holder renewing it every
+//! `renew-interval` while it runs. The standby keeps its service ready and
+//! takes over once the lease of the leader expires, e.g. because the leader
+//! crashed, hung or lost access to the lease store. An instance unable to
+//! renew its lease steps down right away, before it expires.
+use std::{
+    fs::OpenOptions,
+    io::{ErrorKind, Write as _},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aggkit_prover_config::{LeaderElectionConfig, LeaseBackend};
+use prover_errors::ErrorCode;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tonic::Status;
+use tracing::{error, info, warn};
+
+#[cfg(feature = "redis")]
+mod redis_lease;
+
+#[derive(Debug, thiserror::Error)]
+pub enum LeaseError {
+    #[error("Unable to access the lease file {path}")]
+    File {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[cfg(feature = "redis")]
+    #[error("Redis lease store failure")]
+    Redis(#[from] ::redis::RedisError),
+
+    #[cfg(not(feature = "redis"))]
+    #[error("The redis leader election backend requires the redis feature")]
+    RedisUnsupported,
+}
+
+/// Store of the lease held by the leader.
+pub enum LeaseStore {
+    File(FileLease),
+    #[cfg(feature = "redis")]
+    Redis(redis_lease::RedisLease),
+}
+
+impl LeaseStore {
+    pub fn new(backend: &LeaseBackend) -> Result<Self, LeaseError> {
+        match backend {
+            LeaseBackend::File(config) => Ok(Self::File(FileLease::new(config.path.clone()))),
+            #[cfg(feature = "redis")]
+            LeaseBackend::Redis(config) => Ok(Self::Redis(redis_lease::RedisLease::new(config)?)),
+            #[cfg(not(feature = "redis"))]
+            LeaseBackend::Redis(_) => Err(LeaseError::RedisUnsupported),
+        }
+    }
+
+    /// Acquires the lease for `holder`, or renews it if already held,
+    /// returning whether it is held.
+    async fn try_acquire(&self, holder: &str, duration: Duration) -> Result<bool, LeaseError> {
+        match self {
+            Self::File(lease) => {
+                let lease = lease.clone();
+                let holder = holder.to_string();
+                tokio::task::spawn_blocking(move || lease.try_acquire(&holder, duration, now()))
+                    .await
+                    .expect("the lease file access doesn't panic")
+            }
+            #[cfg(feature = "redis")]
+            Self::Redis(lease) => lease.try_acquire(holder, duration).await,
+        }
+    }
+
+    /// Releases the lease if held by `holder`, for the standby to take over
+    /// without waiting for its expiry.
+    async fn release(&self, holder: &str) -> Result<(), LeaseError> {
+        match self {
+            Self::File(lease) => {
+                let lease = lease.clone();
+                let holder = holder.to_string();
+                tokio::task::spawn_blocking(move || lease.release(&holder))
+                    .await
+                    .expect("the lease file access doesn't panic")
+            }
+            #[cfg(feature = "redis")]
+            Self::Redis(lease) => lease.release(holder).await,
+        }
+    }
+}
+
+/// Lease as written in the lease file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+struct LeaseRecord {
+    holder: String,
+    /// Expiry of the lease, in milliseconds since the Unix epoch.
+    expires_at: u64,
+}
+
+/// Lease file on a storage shared by the instances.
+///
+/// The file is only updated by the instance which created its lock file
+/// next to it, such that two instances finding the lease expired at once
+/// don't both take it.
+#[derive(Debug, Clone)]
+pub struct FileLease {
+    path: PathBuf,
+    lock_path: PathBuf,
+}
+
+impl FileLease {
+    pub fn new(path: PathBuf) -> Self {
+        let mut lock_path = path.clone().into_os_string();
+        lock_path.push(".lock");
+
+        Self {
+            path,
+            lock_path: lock_path.into(),
+        }
+    }
+
+    fn try_acquire(&self, holder: &str, duration: Duration, now: u64) -> Result<bool, LeaseError> {
+        if let Some(record) = self.read()? {
+            if record.holder != holder && record.expires_at > now {
+                return Ok(false);
+            }
+        }
+
+        let Some(_lock) = self.lock(duration)? else {
+            return Ok(false);
+        };
+        // Taken by the other instance since read.
+        if let Some(record) = self.read()? {
+            if record.holder != holder && record.expires_at > now {
+                return Ok(false);
+            }
+        }
+        let record = LeaseRecord {
+            holder: holder.to_string(),
+            expires_at: now.saturating_add(duration.as_millis() as u64),
+        };
+        self.write(&record)?;
+
+        Ok(true)
+    }
+
+    fn release(&self, holder: &str) -> Result<(), LeaseError> {
+        if self.read()?.is_some_and(|record| record.holder == holder) {
+            std::fs::remove_file(&self.path).or_else(|source| match source.kind() {
+                ErrorKind::NotFound => Ok(()),
+                _ => Err(self.error(source)),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Current lease, the unreadable ones being considered expired.
+    fn read(&self) -> Result<Option<LeaseRecord>, LeaseError> {
+        match std::fs::read(&self.path) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)
+                .inspect_err(|error| warn!(?error, "Ignoring the malformed lease file"))
+                .ok()),
+            Err(source) if source.kind() == ErrorKind::NotFound => Ok(None),
+            Err(source) => Err(self.error(source)),
+        }
+    }
+
+    /// Writes the lease to a temporary file renamed over the lease file, for
+    /// the readers to never see it partly written.
+    fn write(&self, record: &LeaseRecord) -> Result<(), LeaseError> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let bytes = serde_json::to_vec(record).expect("the lease record is serializable");
+        std::fs::write(&tmp_path, bytes).map_err(|source| self.error(source))?;
+        std::fs::rename(&tmp_path, &self.path).map_err(|source| self.error(source))
+    }
+
+    /// Creates the lock file, unless already created by the other instance.
+    /// A lock file older than the lease is left by a crashed instance, and is
+    /// removed.
+    fn lock(&self, duration: Duration) -> Result<Option<LockFile<'_>>, LeaseError> {
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&self.lock_path)
+        {
+            Ok(mut file) => {
+                _ = writeln!(file, "{}", std::process::id());
+                Ok(Some(LockFile(&self.lock_path)))
+            }
+            Err(source) if source.kind() == ErrorKind::AlreadyExists => {
+                let stale = std::fs::metadata(&self.lock_path)
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+                    .and_then(|modified| modified.elapsed().ok())
+                    .is_some_and(|age| age > duration);
+                if stale {
+                    warn!(path = ?self.lock_path, "Removing the stale lease lock file");
+                    _ = std::fs::remove_file(&self.lock_path);
+                }
+
+                Ok(None)
+            }
+            Err(source) => Err(LeaseError::File {
+                path: self.lock_path.clone(),
+                source,
+            }),
+        }
+    }
+
+    fn error(&self, source: std::io::Error) -> LeaseError {
+        LeaseError::File {
+            path: self.path.clone(),
+            source,
+        }
+    }
+}
+
+/// Lock file removed once the lease file is updated.
+struct LockFile<'a>(&'a Path);
+
+impl Drop for LockFile<'_> {
+    fn drop(&mut self) {
+        _ = std::fs::remove_file(self.0);
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Whether this instance serves the proof requests, which it always does
+/// when no election is configured.
+#[derive(Clone, Default)]
+pub struct Leadership(Option<watch::Receiver<bool>>);
+
+impl Leadership {
+    pub fn new(leader: watch::Receiver<bool>) -> Self {
+        Self(Some(leader))
+    }
+
+    /// Rejects the requests while standing by, for the clients to retry
+    /// against the leader.
+    pub fn check(&self) -> Result<(), Status> {
+        match &self.0 {
+            Some(leader) if !*leader.borrow() => Err(ErrorCode::Standby.status(
+                "This prover instance is on standby, the requests are served by the leader",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Contender for the lease, whose leadership is watched by the gRPC API and
+/// the health checks.
+pub struct LeaderElection {
+    store: LeaseStore,
+    holder_id: String,
+    lease_duration: Duration,
+    renew_interval: Duration,
+}
+
+impl LeaderElection {
+    pub fn new(config: &LeaderElectionConfig) -> Result<Self, LeaseError> {
+        let holder_id = config.holder_id.clone().unwrap_or_else(|| {
+            format!(
+                "{}-{}",
+                std::env::var("HOSTNAME").unwrap_or_else(|_| "aggkit-prover".to_string()),
+                std::process::id()
+            )
+        });
+
+        Ok(Self {
+            store: LeaseStore::new(&config.backend)?,
+            holder_id,
+            lease_duration: config.lease_duration,
+            renew_interval: config.renew_interval,
+        })
+    }
+
+    /// Contends for the lease until cancelled, releasing it then if held.
+    pub async fn run(self, leader: watch::Sender<bool>, cancellation_token: CancellationToken) {
+        let mut interval = tokio::time::interval(self.renew_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            let is_leader = match self
+                .store
+                .try_acquire(&self.holder_id, self.lease_duration)
+                .await
+            {
+                Ok(is_leader) => is_leader,
+                Err(error) => {
+                    error!(?error, "Unable to acquire or renew the lease");
+                    false
+                }
+            };
+            leader.send_if_modified(|was_leader| {
+                if *was_leader == is_leader {
+                    return false;
+                }
+                if is_leader {
+                    info!(holder_id = %self.holder_id, "Acquired the lease, serving the requests");
+                } else {
+                    warn!(holder_id = %self.holder_id, "Lost the lease, standing by");
+                }
+                *was_leader = is_leader;
+                true
+            });
+        }
+
+        if leader.send_replace(false) {
+            info!(holder_id = %self.holder_id, "Releasing the lease");
+            if let Err(error) = self.store.release(&self.holder_id).await {
+                error!(?error, "Unable to release the lease");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_lease_is_held_by_one_instance() {
+        let dir = std::env::temp_dir().join(format!("aggkit-prover-lease-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let lease = FileLease::new(dir.join("leader.lease"));
+        let duration = Duration::from_secs(10);
+
+        assert!(lease.try_acquire("first", duration, 1_000).unwrap());
+        assert!(!lease.try_acquire("second", duration, 2_000).unwrap());
+        // Renewed by its holder.
+        assert!(lease.try_acquire("first", duration, 5_000).unwrap());
+        assert!(!lease.try_acquire("second", duration, 12_000).unwrap());
+
+        // Taken over once expired.
+        assert!(lease.try_acquire("second", duration, 15_001).unwrap());
+        assert!(!lease.try_acquire("first", duration, 16_000).unwrap());
+
+        // Released by its holder only.
+        lease.release("first").unwrap();
+        assert!(!lease.try_acquire("first", duration, 17_000).unwrap());
+        lease.release("second").unwrap();
+        assert!(lease.try_acquire("first", duration, 17_000).unwrap());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn standby_rejects_the_requests() {
+        assert!(Leadership::default().check().is_ok());
+
+        let (leader, receiver) = watch::channel(false);
+        let leadership = Leadership::new(receiver);
+        assert_eq!(
+            leadership.check().unwrap_err().code(),
+            tonic::Code::Unavailable
+        );
+
+        leader.send_replace(true);
+        assert!(leadership.check().is_ok());
+    }
+}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use aggkit_prover_config::RedisLeaseConfig;
+use redis::{aio::ConnectionManager, Client, Script};
+use tokio::sync::OnceCell;
+
+use super::LeaseError;
+
+/// Takes the lease if free or already held by the holder, extending it.
+const ACQUIRE: &str = r#"
+local holder = redis.call('GET', KEYS[1])
+if holder and holder ~= ARGV[1] then
+    return 0
+end
+redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+return 1
+"#;
+
+/// Deletes the lease if held by the holder.
+const RELEASE: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// Lease key expiring with the lease.
+pub struct RedisLease {
+    client: Client,
+    connection: OnceCell<ConnectionManager>,
+    key: String,
+    acquire: Script,
+    release: Script,
+}
+
+impl RedisLease {
+    /// Connects lazily, on the first attempt to acquire the lease.
+    pub fn new(config: &RedisLeaseConfig) -> Result<Self, LeaseError> {
+        Ok(Self {
+            client: Client::open(config.url.expose())?,
+            connection: OnceCell::new(),
+            key: config.key.clone(),
+            acquire: Script::new(ACQUIRE),
+            release: Script::new(RELEASE),
+        })
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager, LeaseError> {
+        let connection = self
+            .connection
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await?;
+
+        Ok(connection.clone())
+    }
+
+    pub(super) async fn try_acquire(
+        &self,
+        holder: &str,
+        duration: Duration,
+    ) -> Result<bool, LeaseError> {
+        let mut connection = self.connection().await?;
+        let acquired: bool = self
+            .acquire
+            .key(&self.key)
+            .arg(holder)
+            .arg(duration.as_millis() as u64)
+            .invoke_async(&mut connection)
+            .await?;
+
+        Ok(acquired)
+    }
+
+    pub(super) async fn release(&self, holder: &str) -> Result<(), LeaseError> {
+        let mut connection = self.connection().await?;
+        let () = self
+            .release
+            .key(&self.key)
+            .arg(holder)
+            .invoke_async(&mut connection)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,22 +1,75 @@
 use std::{path::PathBuf, sync::Arc};
 
-use aggkit_prover_types::v1::aggchain_proof_service_server::AggchainProofServiceServer;
+use aggkit_prover_config::{ConfigFormat, DispatchConfig};
+use aggkit_prover_types::{
+    v1::aggchain_proof_service_server::AggchainProofServiceServer,
+    v2::aggchain_proof_service_server::AggchainProofServiceServer as AggchainProofServiceServerV2,
+    verifier::proof_verification_service_server::ProofVerificationServiceServer,
+};
+use audit::AuditLog;
+use gc::GarbageCollector;
+use health::HealthMonitor;
+use leader::{LeaderElection, Leadership};
 use prover_engine::ProverEngine;
-use rpc::GrpcService;
+use readiness::Readiness;
+use rpc::{
+    compatibility::ClientCompatibility,
+    mirror::Mirror,
+    network_switch::NetworkSwitch,
+    rate_limit::{RateLimitStore, RateLimiter},
+    tenancy::Tenants,
+    verifier::ProofVerificationGrpcService,
+    GrpcService,
+};
+use snapshot::{SnapshotStores, StateSnapshot};
 use tokio_util::sync::CancellationToken;
 use tracing::info;
+use verify::VKeySet;
+use watchdog::Watchdog;
 
+pub mod admin;
+pub mod audit;
 pub mod cli;
+#[cfg(feature = "redis")]
+pub mod dispatch;
+pub mod gc;
+pub mod health;
+pub mod leader;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+pub mod readiness;
 pub mod rpc;
+pub mod snapshot;
+pub mod verify;
+pub mod watchdog;
 
 #[cfg(test)]
 mod tests;
 
-pub fn runtime(cfg: PathBuf, version: &str) -> anyhow::Result<()> {
-    let config = Arc::new(aggkit_prover_config::ProverConfig::try_load(&cfg)?);
+pub fn runtime(
+    cfg: PathBuf,
+    format: ConfigFormat,
+    restore: Option<PathBuf>,
+    version: &str,
+) -> anyhow::Result<()> {
+    let config = Arc::new(aggkit_prover_config::ProverConfig::try_load_validated(
+        &cfg, format,
+    )?);
+    let snapshot = restore.as_deref().map(StateSnapshot::load).transpose()?;
+    let audit_log = config
+        .audit_log
+        .as_ref()
+        .map(AuditLog::open)
+        .transpose()?
+        .map(Arc::new);
+    let watchdog = config
+        .watchdog
+        .clone()
+        .map(|watchdog| Arc::new(Watchdog::new(watchdog)));
 
-    // Initialize the logger
-    prover_logger::tracing(&config.log);
+    // Initialize the logger, along with the watchdog following the requests
+    // through their spans.
+    prover_logger::tracing_with_layers(&config.log, watchdog.iter().map(Watchdog::layer).collect());
 
     let global_cancellation_token = CancellationToken::new();
 
@@ -33,26 +86,320 @@ pub fn runtime(cfg: PathBuf, version: &str) -> anyhow::Result<()> {
         .enable_all()
         .build()?;
 
-    let aggchain_proof_service = prover_runtime.block_on(async {
-        let grpc_service = GrpcService::new(&config.aggchain_proof_service).await?;
-        Ok::<AggchainProofServiceServer<GrpcService>, aggchain_proof_service::Error>(
-            AggchainProofServiceServer::new(grpc_service),
-        )
-    })?;
+    let network_id = config
+        .aggchain_proof_service
+        .aggchain_proof_builder
+        .network_id;
+    let rate_limit_store = RateLimitStore::new(&config.rate_limiting.backend)?;
+    let rate_limiter =
+        Arc::new(RateLimiter::new(&config.rate_limiting, network_id).with_store(rate_limit_store));
+    let network_switch = Arc::new(NetworkSwitch::new(&config.networks, network_id));
+    let tenants = Arc::new(Tenants::new(
+        &config.tenants,
+        &config.aggchain_proof_service.request_cost,
+    ));
+    let mut proving_runtime = None;
+    let grpc_service = match &config.dispatch {
+        // The requests are proven apart from the runtime serving the gRPC API,
+        // the admin API and the health checks.
+        DispatchConfig::Local => {
+            let proving_runtime = proving_runtime.insert(
+                tokio::runtime::Builder::new_multi_thread()
+                    .thread_name("aggkit-prover-proving")
+                    .worker_threads(config.proving_runtime.worker_threads)
+                    .max_blocking_threads(config.proving_runtime.blocking_threads)
+                    .enable_all()
+                    .build()?,
+            );
+            proving_runtime.block_on(GrpcService::with_proving_runtime(
+                &config.aggchain_proof_service,
+                proving_runtime.handle().clone(),
+            ))?
+        }
+        #[cfg(feature = "redis")]
+        DispatchConfig::RedisStream(queue) => GrpcService::with_service(
+            tower::util::BoxService::new(dispatch::RedisStreamDispatcher::new(queue)?),
+        ),
+        #[cfg(not(feature = "redis"))]
+        DispatchConfig::RedisStream(_) => {
+            anyhow::bail!("The redis-stream dispatch requires the redis feature")
+        }
+    }
+    .with_rate_limiter(rate_limiter.clone())
+    .with_network_switch(network_switch.clone())
+    .with_tenants(tenants.clone())
+    .with_compatibility(Arc::new(ClientCompatibility::new(&config.compatibility)))
+    .with_optimistic_mode(config.aggchain_proof_service.optimistic_mode.enabled);
+    let grpc_service = match audit_log.clone() {
+        Some(audit_log) => grpc_service.with_audit_log(audit_log),
+        None => grpc_service,
+    };
+    let grpc_service = match &config.mirroring {
+        Some(mirroring) => {
+            let _guard = prover_runtime.enter();
+            grpc_service.with_mirror(Arc::new(Mirror::new(mirroring)?))
+        }
+        None => grpc_service,
+    };
+
+    // The standby builds its service as well, ready to take over at once.
+    let leader = match &config.leader_election {
+        Some(election) => {
+            let election = LeaderElection::new(election)?;
+            let (sender, leader) = tokio::sync::watch::channel(false);
+            prover_runtime.spawn(election.run(sender, global_cancellation_token.clone()));
+            Some(leader)
+        }
+        None => None,
+    };
+    let grpc_service = match &leader {
+        Some(leader) => grpc_service.with_leadership(Leadership::new(leader.clone())),
+        None => grpc_service,
+    };
+
+    // Dispatched, the proof cache and the pending proposer requests are the
+    // workers' own, the jobs being held by the stream.
+    let snapshot_stores = SnapshotStores {
+        cost_ledger: grpc_service.cost_ledger().cloned(),
+        proof_cache: grpc_service.proof_cache().cloned(),
+        proposer_requests: grpc_service.pending_proposer_requests().cloned(),
+        #[cfg(feature = "redis")]
+        job_store: match &config.dispatch {
+            DispatchConfig::Local => None,
+            DispatchConfig::RedisStream(queue) => {
+                Some(dispatch::RedisStreamDispatcher::new(queue)?)
+            }
+        },
+    };
+    let programs = grpc_service.programs().cloned();
+    if let Some(snapshot) = snapshot {
+        prover_runtime.block_on(snapshot.restore(&snapshot_stores));
+    }
+
+    // The v1 API stays mounted for the aggsenders which are not migrated yet.
+    let aggchain_proof_service_v2 = AggchainProofServiceServerV2::new(grpc_service.v2());
+    let aggchain_proof_service = AggchainProofServiceServer::new(grpc_service);
+
+    let mut garbage_collector = GarbageCollector::new(config.garbage_collection.interval);
+    if let (Some(audit_log), Some(audit)) = (audit_log, &config.audit_log) {
+        garbage_collector = garbage_collector.with_audit_log(audit_log, &audit.retention);
+    }
+    // Dispatched, the aggregation proofs are cached by the workers.
+    match &config.dispatch {
+        DispatchConfig::Local => {
+            if let Some(cache) = &config.aggchain_proof_service.proposer_service.proof_cache {
+                garbage_collector = garbage_collector.with_proof_cache(cache);
+            }
+        }
+        #[cfg(feature = "redis")]
+        DispatchConfig::RedisStream(queue) => {
+            garbage_collector = garbage_collector.with_job_store(
+                dispatch::RedisStreamDispatcher::new(queue)?,
+                &queue.retention,
+            );
+        }
+        #[cfg(not(feature = "redis"))]
+        DispatchConfig::RedisStream(_) => {}
+    }
+    if !garbage_collector.is_empty() {
+        prover_runtime.spawn(garbage_collector.run(global_cancellation_token.clone()));
+    }
+    if let Some(watchdog) = &watchdog {
+        prover_runtime.spawn(watchdog.clone().run(global_cancellation_token.clone()));
+    }
+
+    let mut engine = ProverEngine::new(
+        config.grpc_endpoint,
+        config.telemetry.addr,
+        config.shutdown.runtime_timeout,
+    );
+    if let Some(admin_addr) = config.admin.addr {
+        let health = HealthMonitor::new(
+            &config.aggchain_proof_service,
+            config.admin.health_refresh_interval,
+            config.readiness.probe_timeout,
+        );
+        let health = match &config.dispatch {
+            DispatchConfig::Local => health,
+            #[cfg(feature = "redis")]
+            DispatchConfig::RedisStream(queue) => {
+                let dispatcher = dispatch::RedisStreamDispatcher::new(queue)?;
+                health.with_queue_depth(Box::new(move || {
+                    let dispatcher = dispatcher.clone();
+                    Box::pin(async move { Ok(dispatcher.queue_depth().await?) })
+                }))
+            }
+            #[cfg(not(feature = "redis"))]
+            DispatchConfig::RedisStream(_) => health,
+        };
+        let health = Arc::new(health);
+        prover_runtime.spawn(health.clone().run(global_cancellation_token.clone()));
+        if let Some(watchdog) = &watchdog {
+            watchdog.set_health(health.clone());
+        }
+
+        engine = engine.set_admin_server(
+            admin_addr,
+            admin::router(
+                rate_limiter,
+                network_switch,
+                tenants,
+                snapshot_stores,
+                programs,
+                health,
+                config.admin.profiling.clone(),
+            ),
+        );
+    }
+    // Dispatched, the requests are proven by the workers and their
+    // dependencies.
+    let ready = match &config.dispatch {
+        DispatchConfig::Local => {
+            let readiness = Readiness::new(&config.readiness, &config.aggchain_proof_service);
+            let (sender, ready) = tokio::sync::watch::channel(false);
+            prover_runtime.spawn(readiness.run(sender, global_cancellation_token.clone()));
+            Some(ready)
+        }
+        DispatchConfig::RedisStream(_) => None,
+    };
+    let serving = match (ready, leader) {
+        (Some(ready), Some(leader)) => {
+            let _guard = prover_runtime.enter();
+            Some(readiness::both(ready, leader))
+        }
+        (ready, leader) => ready.or(leader),
+    };
+    if let Some(serving) = serving {
+        engine = engine.set_serving(serving);
+    }
+
+    let result = engine
+        .add_rpc_service(aggchain_proof_service)
+        .add_rpc_service(aggchain_proof_service_v2)
+        .add_reflection_service(aggkit_prover_types::v1::FILE_DESCRIPTOR_SET)
+        .add_reflection_service(aggkit_prover_types::v2::FILE_DESCRIPTOR_SET)
+        .set_rpc_runtime(prover_runtime)
+        .set_metrics_runtime(metrics_runtime)
+        .set_cancellation_token(global_cancellation_token)
+        .start();
+
+    if let Some(proving_runtime) = proving_runtime {
+        proving_runtime.shutdown_timeout(config.shutdown.runtime_timeout);
+    }
+
+    result
+}
+
+/// Serves the proof verification API only, against the verifying keys of the
+/// `[verifier]` configuration, until terminated.
+pub fn verifier(cfg: PathBuf, format: ConfigFormat, version: &str) -> anyhow::Result<()> {
+    let config = aggkit_prover_config::ProverConfig::try_load_validated(&cfg, format)?;
+
+    // Initialize the logger
+    prover_logger::tracing(&config.log);
+
+    info!("Starting AggKit Prover verifier version info: {}", version);
+
+    let vkeys = VKeySet::load(&config.verifier)?;
+    if vkeys.is_empty() {
+        anyhow::bail!("No verifying key is accepted, see the [verifier] configuration");
+    }
 
     ProverEngine::new(
         config.grpc_endpoint,
         config.telemetry.addr,
         config.shutdown.runtime_timeout,
     )
-    .add_rpc_service(aggchain_proof_service)
-    .add_reflection_service(aggkit_prover_types::v1::FILE_DESCRIPTOR_SET)
-    .set_rpc_runtime(prover_runtime)
-    .set_metrics_runtime(metrics_runtime)
-    .set_cancellation_token(global_cancellation_token)
+    .add_rpc_service(ProofVerificationServiceServer::new(
+        ProofVerificationGrpcService::new(vkeys),
+    ))
+    .add_reflection_service(aggkit_prover_types::verifier::FILE_DESCRIPTOR_SET)
     .start()
 }
 
+/// Proves the jobs enqueued by the gRPC API of other processes, as set up in
+/// the `[dispatch]` configuration, until terminated.
+pub fn worker(cfg: PathBuf, format: ConfigFormat, version: &str) -> anyhow::Result<()> {
+    let config = aggkit_prover_config::ProverConfig::try_load_validated(&cfg, format)?;
+
+    // Initialize the logger
+    prover_logger::tracing(&config.log);
+
+    info!("Starting AggKit Prover worker version info: {}", version);
+
+    let DispatchConfig::RedisStream(queue) = &config.dispatch else {
+        anyhow::bail!("The workers require the proof requests to be dispatched to a queue");
+    };
+
+    run_worker(&config, queue)
+}
+
+#[cfg(feature = "redis")]
+fn run_worker(
+    config: &aggkit_prover_config::ProverConfig,
+    queue: &aggkit_prover_config::RedisStreamConfig,
+) -> anyhow::Result<()> {
+    use anyhow::Context as _;
+
+    let worker_runtime = tokio::runtime::Builder::new_multi_thread()
+        .thread_name("aggkit-prover-worker")
+        .enable_all()
+        .build()?;
+
+    worker_runtime.block_on(async {
+        let service = aggchain_proof_service::service::AggchainProofService::new(
+            &config.aggchain_proof_service,
+        )
+        .await?;
+        let consumer = format!(
+            "{}-{}",
+            std::env::var("HOSTNAME").unwrap_or_else(|_| "aggkit-prover".to_string()),
+            std::process::id()
+        );
+
+        let cancellation_token = CancellationToken::new();
+        if let Some(cache) = &config.aggchain_proof_service.proposer_service.proof_cache {
+            let garbage_collector =
+                GarbageCollector::new(config.garbage_collection.interval).with_proof_cache(cache);
+            if !garbage_collector.is_empty() {
+                tokio::spawn(garbage_collector.run(cancellation_token.clone()));
+            }
+        }
+        let worker = dispatch::run_worker(queue, &consumer, service, cancellation_token.clone());
+        tokio::pin!(worker);
+        tokio::select! {
+            result = &mut worker => return result.map_err(anyhow::Error::from),
+            _ = shutdown_signal() => info!("Received shutdown signal, finishing the running jobs"),
+        }
+
+        cancellation_token.cancel();
+        tokio::time::timeout(config.shutdown.runtime_timeout, worker)
+            .await
+            .context("The running jobs did not finish in time")??;
+
+        Ok::<_, anyhow::Error>(())
+    })
+}
+
+#[cfg(not(feature = "redis"))]
+fn run_worker(
+    _: &aggkit_prover_config::ProverConfig,
+    _: &aggkit_prover_config::RedisStreamConfig,
+) -> anyhow::Result<()> {
+    anyhow::bail!("The redis-stream dispatch requires the redis feature")
+}
+
+#[cfg(feature = "redis")]
+async fn shutdown_signal() {
+    let mut terminate = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+        .expect("Fail to setup SIGTERM signal");
+
+    tokio::select! {
+        _ = terminate.recv() => {}
+        _ = tokio::signal::ctrl_c() => {}
+    }
+}
+
 /// Common version information about the executed agglayer binary.
 pub fn version() -> String {
     let pkg_name = env!("CARGO_PKG_NAME");
@@ -1,25 +1,121 @@
-use aggchain_proof_service::AGGCHAIN_VKEY_SELECTOR;
-use aggkit_prover::version;
+use aggchain_proof_service::{AGGCHAIN_PROGRAM_NAME, AGGCHAIN_PROOF_ELF, AGGCHAIN_VKEY_SELECTOR};
+use aggkit_prover::{
+    cli::ConfigCommands,
+    verify::{load_vkey, verifier_client, ProofArtifact},
+    version,
+};
+use aggkit_prover_config::ConfigFormat;
+use aggkit_prover_types::public_values::PublicValues;
 use anyhow::Context as _;
 use clap::Parser as _;
-use prover_config::{CpuProverConfig, ProverType};
+use prover_executor::{
+    bench::{Bench, PhaseReport},
+    golden::{GoldenVector, ReplayRunner},
+    ProgramIdentity,
+};
+use prover_utils::encryption::{Cipher, EncryptionConfig, EncryptionKey};
 use sp1_sdk::HashableKey as _;
 
+#[cfg(feature = "profiling")]
+#[global_allocator]
+static ALLOCATOR: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+/// Heap profiling of jemalloc, sampling every 512 KiB allocated on average
+/// once activated by the profiling configuration.
+#[cfg(feature = "profiling")]
+#[export_name = "_rjem_malloc_conf"]
+static MALLOC_CONF: &[u8] = b"prof:true,prof_active:false,lg_prof_sample:19\0";
+
 fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
     let cli = aggkit_prover::cli::Cli::parse();
 
     match cli.cmd {
-        aggkit_prover::cli::Commands::Run { config_path } => {
-            aggkit_prover::runtime(config_path, &version())?
+        aggkit_prover::cli::Commands::Run {
+            config_path,
+            config_format,
+            worker,
+            restore,
+            verify_only,
+        } => {
+            let format = config_format.unwrap_or_else(|| ConfigFormat::from_path(&config_path));
+            if verify_only {
+                aggkit_prover::verifier(config_path, format, &version())?
+            } else if worker {
+                aggkit_prover::worker(config_path, format, &version())?
+            } else {
+                aggkit_prover::runtime(config_path, format, restore, &version())?
+            }
         }
-        aggkit_prover::cli::Commands::Config => {
+        aggkit_prover::cli::Commands::Config {
+            schema: true,
+            cmd: None,
+        } => {
+            let schema =
+                serde_json::to_string_pretty(&aggkit_prover_config::ProverConfig::schema())
+                    .context("Failed to serialize the configuration schema to JSON")?;
+
+            println!("{schema}");
+        }
+        aggkit_prover::cli::Commands::Config {
+            schema: false,
+            cmd: None,
+        } => {
             let config = toml::to_string_pretty(&aggkit_prover_config::ProverConfig::default())
                 .context("Failed to serialize ProverConfig to TOML")?;
 
             println!("{config}");
         }
+        aggkit_prover::cli::Commands::Config {
+            cmd: Some(ConfigCommands::Init { output, force }),
+            ..
+        } => {
+            if output.exists() && !force {
+                anyhow::bail!(
+                    "{} already exists, use --force to overwrite it",
+                    output.display()
+                );
+            }
+            std::fs::write(&output, aggkit_prover_config::COMMENTED_DEFAULT_CONFIG)
+                .with_context(|| format!("Failed to write {}", output.display()))?;
+
+            println!("Default configuration written to {}", output.display());
+        }
+        aggkit_prover::cli::Commands::Config {
+            cmd:
+                Some(ConfigCommands::Validate {
+                    config_path,
+                    config_format,
+                }),
+            ..
+        } => {
+            let format = config_format.unwrap_or_else(|| ConfigFormat::from_path(&config_path));
+            aggkit_prover_config::ProverConfig::try_load_validated(&config_path, format)?;
+
+            println!("{} is valid", config_path.display());
+        }
+        aggkit_prover::cli::Commands::Config {
+            cmd:
+                Some(ConfigCommands::PrintEffective {
+                    config_path,
+                    config_format,
+                }),
+            ..
+        } => {
+            let format = config_format.unwrap_or_else(|| ConfigFormat::from_path(&config_path));
+            let config = aggkit_prover_config::ProverConfig::try_load_as(&config_path, format)?;
+            if let Err(errors) = config.validate() {
+                for error in errors {
+                    eprintln!("warning: {error}");
+                }
+            }
+
+            let config = toml::to_string_pretty(&config)
+                .context("Failed to serialize ProverConfig to TOML")?;
+
+            println!("{config}");
+        }
         aggkit_prover::cli::Commands::ValidateConfig { config_path } => {
             match aggkit_prover_config::ProverConfig::try_load(config_path.as_path()) {
                 Ok(config) => {
@@ -31,22 +127,240 @@ fn main() -> anyhow::Result<()> {
                 Err(error) => eprintln!("{error}"),
             }
         }
-        aggkit_prover::cli::Commands::Vkey => {
-            let executor = prover_executor::Executor::new(
-                &ProverType::CpuProver(CpuProverConfig::default()),
-                &None,
-                aggchain_proof_service::AGGCHAIN_PROOF_ELF,
-            );
-            let vkey = executor.get_vkey();
-            let vkey_hex = hex::encode(vkey.hash_bytes());
-            println!("0x{vkey_hex}");
+        aggkit_prover::cli::Commands::Vkey { elf, json } => {
+            let identity = match elf {
+                Some(path) => {
+                    let elf = std::fs::read(&path)
+                        .with_context(|| format!("Failed to read the ELF {}", path.display()))?;
+                    ProgramIdentity::compute(&elf)
+                }
+                None => ProgramIdentity::compute(AGGCHAIN_PROOF_ELF),
+            };
+
+            let output = if json {
+                serde_json::to_string_pretty(&identity)
+                    .context("Failed to serialize the program identity to JSON")?
+            } else {
+                identity.to_string()
+            };
+            println!("{output}");
         }
 
         aggkit_prover::cli::Commands::VkeySelector => {
             let vkey_selector_hex = hex::encode(AGGCHAIN_VKEY_SELECTOR.to_be_bytes());
             println!("0x{vkey_selector_hex}");
         }
+
+        aggkit_prover::cli::Commands::VerifyProof {
+            proof,
+            raw,
+            vkey,
+            elf,
+            json,
+        } => {
+            let vkey = match (vkey, elf) {
+                (Some(path), _) => load_vkey(&path)?,
+                (None, Some(path)) => {
+                    let elf = std::fs::read(&path)
+                        .with_context(|| format!("Failed to read the ELF {}", path.display()))?;
+                    prover_executor::Executor::compute_program_vkey(&elf)
+                }
+                (None, None) => prover_executor::Executor::compute_program_vkey(AGGCHAIN_PROOF_ELF),
+            };
+
+            let artifact = if raw {
+                ProofArtifact::load_sp1(&proof)?
+            } else {
+                ProofArtifact::load_envelope(&proof)?
+            };
+            let verified = artifact.verify(&verifier_client(), &vkey)?;
+
+            let output = if json {
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "vkey": vkey.bytes32(),
+                    "public-values": format!("0x{}", hex::encode(&verified.public_values)),
+                    "aggchain-public-values": match &verified.decoded {
+                        Some(PublicValues::Aggchain(aggchain)) => Some(aggchain),
+                        _ => None,
+                    },
+                    "decoded-public-values": verified.decoded,
+                }))
+                .context("Failed to serialize the verified proof to JSON")?
+            } else {
+                let mut output = format!(
+                    "Proof verified against the vkey {}\npublic values: 0x{}",
+                    vkey.bytes32(),
+                    hex::encode(&verified.public_values)
+                );
+                match &verified.decoded {
+                    Some(PublicValues::Aggchain(aggchain)) => output.push_str(&format!(
+                        "\nprev_local_exit_root: {:?}\nnew_local_exit_root: {:?}\nl1_info_root: \
+                         {:?}\norigin_network: {:?}\naggchain_params: {:?}\n\
+                         commit_imported_bridge_exits: {:?}",
+                        aggchain.prev_local_exit_root,
+                        aggchain.new_local_exit_root,
+                        aggchain.l1_info_root,
+                        aggchain.origin_network,
+                        aggchain.aggchain_params,
+                        aggchain.commit_imported_bridge_exits
+                    )),
+                    Some(decoded) => output.push_str(&format!(
+                        "\n{:?} public values: {decoded:#?}",
+                        decoded.kind()
+                    )),
+                    None => {}
+                }
+                output
+            };
+            println!("{output}");
+        }
+
+        aggkit_prover::cli::Commands::Bench {
+            fixture,
+            iterations,
+            prove,
+            elf,
+            json,
+            encryption_key,
+        } => {
+            let cipher = cipher(encryption_key)?;
+            let vector = GoldenVector::load(&fixture, cipher.as_ref())
+                .with_context(|| format!("Failed to load the fixture {}", fixture.display()))?;
+            let bench = match elf {
+                Some(path) => {
+                    let elf = std::fs::read(&path)
+                        .with_context(|| format!("Failed to read the ELF {}", path.display()))?;
+                    Bench::new(&elf)
+                }
+                None => Bench::new(AGGCHAIN_PROOF_ELF),
+            };
+
+            let report = bench.run(
+                &vector.stdin,
+                iterations,
+                prove.then_some(vector.proof_type),
+            )?;
+
+            let output = if json {
+                serde_json::to_string_pretty(&report)
+                    .context("Failed to serialize the benchmark report to JSON")?
+            } else {
+                let mut output = format!(
+                    "{}\niterations:    {}\ncycles:        {}\nsetup:         {:.1} ms\n\
+                     execution:     {}",
+                    report.identity,
+                    report.iterations,
+                    report.cycles,
+                    report.setup_ms,
+                    format_phase(&report.execution)
+                );
+                if let Some(proving) = &report.proving {
+                    output.push_str(&format!("\nproving:       {}", format_phase(proving)));
+                }
+                output
+            };
+            println!("{output}");
+        }
+
+        aggkit_prover::cli::Commands::ReplayGoldenVectors {
+            dir,
+            encryption_key,
+        } => {
+            let mut runner = ReplayRunner::new(AGGCHAIN_PROGRAM_NAME, AGGCHAIN_PROOF_ELF);
+            if let Some(cipher) = cipher(encryption_key)? {
+                runner = runner.with_cipher(cipher);
+            }
+            let outcomes = tokio::runtime::Runtime::new()?.block_on(runner.replay_dir(&dir))?;
+
+            let mut failures = 0;
+            for outcome in &outcomes {
+                match outcome {
+                    Ok(outcome) if outcome.has_drifted() => {
+                        failures += 1;
+                        println!("DRIFT {}", outcome.path.display());
+                    }
+                    Ok(outcome) if outcome.vkey_changed => {
+                        println!(
+                            "ok    {} (captured with another vkey)",
+                            outcome.path.display()
+                        );
+                    }
+                    Ok(outcome) => println!("ok    {}", outcome.path.display()),
+                    Err(error) => {
+                        failures += 1;
+                        println!("ERROR {error:?}");
+                    }
+                }
+            }
+
+            if failures > 0 {
+                anyhow::bail!("{failures} of {} golden vectors failed", outcomes.len());
+            }
+        }
+
+        aggkit_prover::cli::Commands::VerifyAuditLog { dir } => {
+            let records = aggkit_prover::audit::AuditLog::verify(&dir)?;
+            println!("ok    {records} records");
+        }
+
+        aggkit_prover::cli::Commands::GcProofCache {
+            config_path,
+            config_format,
+            dry_run,
+        } => {
+            let format = config_format.unwrap_or_else(|| ConfigFormat::from_path(&config_path));
+            let config = aggkit_prover_config::ProverConfig::try_load_as(&config_path, format)?;
+            let Some(cache) = config.aggchain_proof_service.proposer_service.proof_cache else {
+                anyhow::bail!("The proof cache is not configured");
+            };
+            let Some(dir) = cache.disk_dir else {
+                anyhow::bail!("The proof cache is kept in memory only, see its disk-dir");
+            };
+
+            let collection =
+                proposer_service::cache::collect_garbage(&dir, &cache.retention, dry_run)
+                    .with_context(|| format!("Unable to collect {}", dir.display()))?;
+            println!(
+                "{} {} expired references, {} unreferenced proofs ({} MiB), {} references and \
+                 {} proofs kept",
+                if dry_run { "would remove" } else { "removed" },
+                collection.expired_refs,
+                collection.removed_proofs,
+                collection.freed_bytes / (1024 * 1024),
+                collection.refs,
+                collection.kept_proofs,
+            );
+        }
     }
 
     Ok(())
 }
+
+/// Cipher of the given keys, the first one being the current one.
+fn cipher(keys: impl IntoIterator<Item = String>) -> anyhow::Result<Option<Cipher>> {
+    let mut keys = keys
+        .into_iter()
+        .map(|key| EncryptionKey::resolve(&key).context("Invalid encryption key"))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if keys.is_empty() {
+        return Ok(None);
+    }
+
+    let key = keys.remove(0);
+    Ok(Some(Cipher::new(&EncryptionConfig {
+        key,
+        previous_keys: keys,
+    })))
+}
+
+fn format_phase(phase: &PhaseReport) -> String {
+    let mut output = format!(
+        "min {:.1} ms, mean {:.1} ms, max {:.1} ms",
+        phase.min_ms, phase.mean_ms, phase.max_ms
+    );
+    if let Some(bytes) = phase.peak_rss_bytes {
+        output.push_str(&format!(", peak rss {} MiB", bytes / (1024 * 1024)));
+    }
+
+    output
+}
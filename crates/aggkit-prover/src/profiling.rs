@@ -0,0 +1,252 @@
+//! CPU and heap profiles of the running prover, served on the admin API for
+//! the performance investigations, e.g. of the witness assembly.
+//!
+//! - `GET /debug/pprof/profile?seconds=30` samples the CPU for the given
+//!   duration, 30 seconds by default, and returns a pprof protobuf profile to
+//!   open with `go tool pprof`.
+//! - `GET /debug/pprof/heap` dumps the heap profile of jemalloc, to open with
+//!   `jeprof` along with the binary.
+//!
+//! The requests send the configured token as bearer. One profile is taken at
+//! a time, the concurrent requests being rejected.
+//!
+//! The stacks of the threads are also sampled by the watchdog of the slow
+//! requests, see [`crate::watchdog`].
+use std::{
+    collections::BTreeMap, ffi::CString, os::unix::ffi::OsStrExt as _, sync::Arc, time::Duration,
+};
+
+use aggkit_prover_config::ProfilingConfig;
+use axum::{
+    extract::{Query, Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use pprof::protos::Message as _;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+const DEFAULT_PROFILE_DURATION: Duration = Duration::from_secs(30);
+
+/// Libraries whose frames are not sampled, unwinding through them being
+/// unreliable.
+const BLOCKLIST: &[&str] = &["libc", "libgcc", "pthread", "vdso"];
+
+/// Stacks kept per thread, the most sampled ones.
+const MAX_STACKS_PER_THREAD: usize = 3;
+
+/// Stack of a thread, sampled while it was running.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ThreadStack {
+    pub thread: String,
+    pub samples: isize,
+    /// Functions of the stack, the innermost first.
+    pub frames: Vec<String>,
+}
+
+struct Profiler {
+    config: ProfilingConfig,
+    /// Held while taking a profile.
+    running: Mutex<()>,
+}
+
+pub fn router(config: ProfilingConfig) -> Router {
+    // The heap is only sampled once profiling is configured.
+    // SAFETY: `prof.active` takes a boolean.
+    if let Err(error) = unsafe { tikv_jemalloc_ctl::raw::write(b"prof.active\0", true) } {
+        warn!(%error, "Unable to activate the heap profiling of jemalloc");
+    }
+
+    let profiler = Arc::new(Profiler {
+        config,
+        running: Mutex::new(()),
+    });
+
+    Router::new()
+        .route("/debug/pprof/profile", get(cpu_profile))
+        .route("/debug/pprof/heap", get(heap_profile))
+        .route_layer(middleware::from_fn_with_state(profiler.clone(), authorize))
+        .with_state(profiler)
+}
+
+async fn authorize(
+    State(profiler): State<Arc<Profiler>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let token = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match token {
+        Some(token) if same_token(token, profiler.config.token.expose()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+/// Compares the tokens in a time independent of their common prefix.
+fn same_token(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (given, expected)| diff | (given ^ expected))
+            == 0
+}
+
+#[derive(Deserialize, Debug)]
+struct ProfileParams {
+    /// Duration of the profile, in seconds.
+    seconds: Option<u64>,
+}
+
+async fn cpu_profile(
+    State(profiler): State<Arc<Profiler>>,
+    Query(params): Query<ProfileParams>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let duration = params
+        .seconds
+        .map_or(DEFAULT_PROFILE_DURATION, Duration::from_secs);
+    if duration.is_zero() || duration > profiler.config.max_duration {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let _running = profiler
+        .running
+        .try_lock()
+        .map_err(|_| StatusCode::CONFLICT)?;
+
+    info!(?duration, "Taking a CPU profile");
+    let frequency = i32::try_from(profiler.config.frequency).unwrap_or(i32::MAX);
+    let profile = tokio::task::spawn_blocking(move || take_cpu_profile(duration, frequency))
+        .await
+        .map_err(|error| {
+            error!(?error, "Unable to take the CPU profile");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map_err(|error| {
+            error!(%error, "Unable to take the CPU profile");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/octet-stream")],
+        profile,
+    ))
+}
+
+/// Samples the CPU for the duration, blocking the calling thread.
+fn take_cpu_profile(duration: Duration, frequency: i32) -> Result<Vec<u8>, pprof::Error> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(BLOCKLIST)
+        .build()?;
+    std::thread::sleep(duration);
+
+    Ok(guard.report().build()?.pprof()?.encode_to_vec())
+}
+
+/// Samples the stacks of the running threads for the duration, blocking the
+/// calling thread. The threads blocked during the whole duration are missing.
+pub fn sample_thread_stacks(
+    duration: Duration,
+    frequency: i32,
+) -> Result<Vec<ThreadStack>, pprof::Error> {
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(BLOCKLIST)
+        .build()?;
+    std::thread::sleep(duration);
+    let report = guard.report().build()?;
+
+    let mut threads = BTreeMap::<_, Vec<_>>::new();
+    for (frames, samples) in report.data {
+        threads
+            .entry(frames.thread_name.clone())
+            .or_default()
+            .push(ThreadStack {
+                thread: frames.thread_name,
+                samples,
+                frames: frames
+                    .frames
+                    .iter()
+                    .flatten()
+                    .map(|symbol| symbol.name())
+                    .collect(),
+            });
+    }
+
+    Ok(threads
+        .into_values()
+        .flat_map(|mut stacks| {
+            stacks.sort_by_key(|stack| std::cmp::Reverse(stack.samples));
+            stacks.truncate(MAX_STACKS_PER_THREAD);
+            stacks
+        })
+        .collect())
+}
+
+async fn heap_profile(
+    State(profiler): State<Arc<Profiler>>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let _running = profiler
+        .running
+        .try_lock()
+        .map_err(|_| StatusCode::CONFLICT)?;
+
+    info!("Dumping the heap profile");
+    let profile = tokio::task::spawn_blocking(dump_heap_profile)
+        .await
+        .map_err(|error| {
+            error!(?error, "Unable to dump the heap profile");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?
+        .map_err(|error| {
+            error!(?error, "Unable to dump the heap profile");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/octet-stream")],
+        profile,
+    ))
+}
+
+/// Dumps the heap profile of jemalloc into a temporary file, and reads it
+/// back.
+fn dump_heap_profile() -> anyhow::Result<Vec<u8>> {
+    let path = std::env::temp_dir().join(format!("aggkit-prover-{}.heap", std::process::id()));
+    let c_path = CString::new(path.as_os_str().as_bytes())?;
+
+    // SAFETY: `prof.dump` takes a NUL-terminated path, which outlives the
+    // call.
+    unsafe { tikv_jemalloc_ctl::raw::write(b"prof.dump\0", c_path.as_ptr()) }
+        .map_err(|error| anyhow::anyhow!("jemalloc failed to dump the heap profile: {error}"))?;
+
+    let profile = std::fs::read(&path)?;
+    _ = std::fs::remove_file(&path);
+
+    Ok(profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_are_compared_whole() {
+        assert!(same_token("profiling-token", "profiling-token"));
+        assert!(!same_token("profiling-toke", "profiling-token"));
+        assert!(!same_token("profiling-tokeN", "profiling-token"));
+        assert!(!same_token("", "profiling-token"));
+    }
+}
@@ -0,0 +1,372 @@
+//! Readiness of the prover, reported to the health checks once the mandatory
+//! dependencies respond, instead of failing every request while one of them
+//! is down.
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aggchain_proof_service::config::AggchainProofServiceConfig;
+use aggkit_prover_config::{Dependency, ReadinessConfig};
+use jsonrpsee::{
+    core::{client::ClientT as _, ClientError},
+    http_client::HttpClientBuilder,
+    rpc_params,
+};
+use prover_config::ProverType;
+use tokio::{net::TcpStream, sync::watch};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use url::Url;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProbeError {
+    #[error("No response within {0:?}")]
+    Timeout(Duration),
+
+    #[error("Unable to connect")]
+    Connect(#[from] std::io::Error),
+
+    #[error("JSON-RPC request failed")]
+    JsonRpc(#[from] ClientError),
+
+    #[error("The endpoint {0} has no host or port")]
+    InvalidEndpoint(Url),
+}
+
+/// Endpoint of a dependency.
+#[derive(Debug, Clone)]
+pub(crate) enum Endpoint {
+    /// JSON-RPC node, responding to `eth_chainId` with either the chain id or
+    /// an error.
+    JsonRpc(Url),
+    /// Server accepting TCP connections, e.g. a gRPC one.
+    Connect(Url),
+}
+
+impl Endpoint {
+    pub(crate) async fn probe(&self) -> Result<(), ProbeError> {
+        match self {
+            Self::JsonRpc(url) if matches!(url.scheme(), "http" | "https") => {
+                let client = HttpClientBuilder::default().build(url.as_str())?;
+                match client
+                    .request::<serde_json::Value, _>("eth_chainId", rpc_params![])
+                    .await
+                {
+                    // An error response is a response, e.g. from a rollup
+                    // node not serving the eth namespace.
+                    Ok(_) | Err(ClientError::Call(_)) => Ok(()),
+                    Err(error) => Err(error.into()),
+                }
+            }
+            Self::JsonRpc(url) | Self::Connect(url) => {
+                let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+                    return Err(ProbeError::InvalidEndpoint(url.clone()));
+                };
+                TcpStream::connect((host, port)).await?;
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Age of the latest block of a JSON-RPC node serving the eth namespace,
+    /// none for the other endpoints.
+    pub(crate) async fn head_lag(&self) -> Result<Option<Duration>, ProbeError> {
+        let Self::JsonRpc(url) = self else {
+            return Ok(None);
+        };
+        if !matches!(url.scheme(), "http" | "https") {
+            return Ok(None);
+        }
+
+        let client = HttpClientBuilder::default().build(url.as_str())?;
+        let block = match client
+            .request::<serde_json::Value, _>("eth_getBlockByNumber", rpc_params!["latest", false])
+            .await
+        {
+            Ok(block) => block,
+            Err(ClientError::Call(_)) => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        let timestamp = block["timestamp"]
+            .as_str()
+            .and_then(|timestamp| u64::from_str_radix(timestamp.trim_start_matches("0x"), 16).ok());
+
+        Ok(timestamp.map(|timestamp| {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            now.saturating_sub(Duration::from_secs(timestamp))
+        }))
+    }
+
+    /// Scheme, host and port of the endpoint, its path and credentials
+    /// possibly holding a secret.
+    pub(crate) fn redacted(&self) -> String {
+        let (Self::JsonRpc(url) | Self::Connect(url)) = self;
+        match (url.host_str(), url.port_or_known_default()) {
+            (Some(host), Some(port)) => format!("{}://{host}:{port}", url.scheme()),
+            _ => url.scheme().to_string(),
+        }
+    }
+}
+
+/// Probe of a dependency, responding when any of its endpoints does, the
+/// requests being routed to the responsive ones.
+#[derive(Debug, Clone)]
+pub(crate) struct Probe {
+    pub(crate) dependency: Dependency,
+    pub(crate) endpoints: Vec<Endpoint>,
+}
+
+impl Probe {
+    fn new(dependency: Dependency, endpoints: impl IntoIterator<Item = Endpoint>) -> Self {
+        Self {
+            dependency,
+            endpoints: endpoints.into_iter().collect(),
+        }
+    }
+
+    async fn check(&self, timeout: Duration) -> Result<(), ProbeError> {
+        let mut last_error = ProbeError::Timeout(timeout);
+        for endpoint in &self.endpoints {
+            match tokio::time::timeout(timeout, endpoint.probe()).await {
+                Ok(Ok(())) => return Ok(()),
+                Ok(Err(error)) => last_error = error,
+                Err(_) => last_error = ProbeError::Timeout(timeout),
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+/// Prober of the dependencies of the aggchain proof service.
+pub struct Readiness {
+    mandatory: Vec<Probe>,
+    optional: Vec<Probe>,
+    probe_interval: Duration,
+    probe_timeout: Duration,
+}
+
+impl Readiness {
+    pub fn new(config: &ReadinessConfig, service: &AggchainProofServiceConfig) -> Self {
+        Self::with_probes(config, probes(service))
+    }
+
+    fn with_probes(config: &ReadinessConfig, probes: Vec<Probe>) -> Self {
+        let (mandatory, optional) = probes
+            .into_iter()
+            .filter(|probe| !probe.endpoints.is_empty())
+            .partition(|probe| config.mandatory.contains(&probe.dependency));
+
+        Self {
+            mandatory,
+            optional,
+            probe_interval: config.probe_interval,
+            probe_timeout: config.probe_timeout,
+        }
+    }
+
+    /// Probes the mandatory dependencies until they all respond, then reports
+    /// the prover as ready. The optional ones are probed once, only reported
+    /// when down.
+    pub async fn run(self, ready: watch::Sender<bool>, cancellation_token: CancellationToken) {
+        let probe_timeout = self.probe_timeout;
+        let optional = futures::future::join_all(self.optional.iter().map(|probe| async move {
+            if let Err(error) = probe.check(probe_timeout).await {
+                warn!(dependency = ?probe.dependency, ?error, "Optional dependency not responding");
+            }
+        }));
+
+        let mandatory = async {
+            let mut pending = self.mandatory.clone();
+            loop {
+                let results = futures::future::join_all(
+                    pending.iter().map(|probe| probe.check(probe_timeout)),
+                )
+                .await;
+                pending = pending
+                    .into_iter()
+                    .zip(results)
+                    .filter_map(|(probe, result)| {
+                        let error = result.err()?;
+                        warn!(
+                            dependency = ?probe.dependency,
+                            ?error,
+                            "Mandatory dependency not responding, not serving yet"
+                        );
+                        Some(probe)
+                    })
+                    .collect();
+
+                if pending.is_empty() {
+                    info!("Mandatory dependencies responding, serving the requests");
+                    ready.send_replace(true);
+                    return;
+                }
+
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => return,
+                    _ = tokio::time::sleep(self.probe_interval) => {}
+                }
+            }
+        };
+
+        futures::join!(optional, mandatory);
+    }
+}
+
+/// Probes of the dependencies of the service, as configured.
+pub(crate) fn probes(config: &AggchainProofServiceConfig) -> Vec<Probe> {
+    let builder = &config.aggchain_proof_builder;
+    let contracts = &builder.contracts;
+    let proposer = &config.proposer_service;
+
+    let mut sp1_cluster = Vec::new();
+    match &builder.primary_prover {
+        ProverType::NetworkProver(network) => {
+            sp1_cluster.push(Endpoint::Connect(network.sp1_cluster_endpoint.clone()))
+        }
+        ProverType::GrpcProver(grpc) => sp1_cluster.push(Endpoint::Connect(grpc.endpoint.clone())),
+        ProverType::CpuProver(_) | ProverType::MockProver(_) => {}
+    }
+
+    let mut proposer_endpoints = Vec::new();
+    if !proposer.mock {
+        sp1_cluster.push(Endpoint::Connect(
+            proposer.client.sp1_cluster_endpoint.clone(),
+        ));
+        if let Ok(url) = Url::parse(&proposer.client.proposer_endpoint.to_string()) {
+            proposer_endpoints.push(Endpoint::Connect(url));
+        }
+    }
+
+    let l1_rpc = std::iter::once(&contracts.l1_rpc_endpoint.url)
+        .chain(&contracts.l1_rpc_fallback_endpoints)
+        .chain(std::iter::once(&proposer.l1_rpc_endpoint.url))
+        .cloned()
+        .map(Endpoint::JsonRpc);
+    let l2_execution = std::iter::once(&contracts.l2_execution_layer_rpc_endpoint)
+        .chain(&contracts.l2_execution_layer_rpc_fallback_endpoints)
+        .cloned()
+        .map(Endpoint::JsonRpc);
+
+    vec![
+        Probe::new(Dependency::Sp1Cluster, sp1_cluster),
+        Probe::new(Dependency::Proposer, proposer_endpoints),
+        Probe::new(Dependency::L1Rpc, l1_rpc),
+        Probe::new(Dependency::L2Rpc, l2_execution),
+        Probe::new(
+            Dependency::L2Rpc,
+            [Endpoint::JsonRpc(
+                contracts.l2_consensus_layer_rpc_endpoint.clone(),
+            )],
+        ),
+    ]
+}
+
+/// Watches whether both watched values are true, e.g. whether the prover is
+/// both ready and the leader.
+pub fn both(
+    mut first: watch::Receiver<bool>,
+    mut second: watch::Receiver<bool>,
+) -> watch::Receiver<bool> {
+    let (sender, receiver) =
+        watch::channel(*first.borrow_and_update() && *second.borrow_and_update());
+
+    // The last value of a closed channel stays relevant, e.g. once ready.
+    tokio::spawn(async move {
+        let (mut first_open, mut second_open) = (true, true);
+        loop {
+            tokio::select! {
+                changed = first.changed(), if first_open => first_open = changed.is_ok(),
+                changed = second.changed(), if second_open => second_open = changed.is_ok(),
+                else => break,
+            }
+
+            let serving = *first.borrow() && *second.borrow();
+            sender.send_if_modified(|current| std::mem::replace(current, serving) != serving);
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    fn config(mandatory: &[Dependency]) -> ReadinessConfig {
+        ReadinessConfig {
+            mandatory: mandatory.iter().copied().collect(),
+            probe_interval: Duration::from_millis(10),
+            probe_timeout: Duration::from_secs(1),
+        }
+    }
+
+    async fn listening() -> (TcpListener, Endpoint) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        (listener, Endpoint::Connect(url.parse().unwrap()))
+    }
+
+    /// Endpoint of a port nothing listens on anymore.
+    async fn closed() -> Endpoint {
+        listening().await.1
+    }
+
+    #[tokio::test]
+    async fn ready_once_the_mandatory_dependencies_respond() {
+        let (_listener, up) = listening().await;
+        let readiness = Readiness::with_probes(
+            &config(&[Dependency::Proposer]),
+            vec![
+                Probe::new(Dependency::Proposer, [closed().await, up]),
+                Probe::new(Dependency::L1Rpc, [closed().await]),
+            ],
+        );
+        let (sender, ready) = watch::channel(false);
+
+        readiness.run(sender, CancellationToken::new()).await;
+
+        assert!(*ready.borrow());
+    }
+
+    #[tokio::test]
+    async fn not_ready_while_a_mandatory_dependency_is_down() {
+        let readiness = Readiness::with_probes(
+            &config(&[Dependency::L1Rpc]),
+            vec![Probe::new(Dependency::L1Rpc, [closed().await])],
+        );
+        let (sender, ready) = watch::channel(false);
+        let cancellation_token = CancellationToken::new();
+        let run = tokio::spawn(readiness.run(sender, cancellation_token.clone()));
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!*ready.borrow());
+
+        cancellation_token.cancel();
+        run.await.unwrap();
+        assert!(!*ready.borrow());
+    }
+
+    #[tokio::test]
+    async fn serving_while_both_ready_and_leader() {
+        let (ready_sender, ready) = watch::channel(false);
+        let (leader_sender, leader) = watch::channel(true);
+        let mut serving = both(ready, leader);
+        assert!(!*serving.borrow_and_update());
+
+        // Still serving once the readiness is no longer watched.
+        ready_sender.send_replace(true);
+        drop(ready_sender);
+        serving.changed().await.unwrap();
+        assert!(*serving.borrow_and_update());
+
+        leader_sender.send_replace(false);
+        serving.changed().await.unwrap();
+        assert!(!*serving.borrow());
+    }
+}
@@ -1,45 +1,383 @@
+use std::{sync::Arc, time::Instant};
+
+use aggchain_proof_builder::programs::ProgramRegistry;
 use aggchain_proof_service::{
     config::AggchainProofServiceConfig,
-    service::{AggchainProofService, AggchainProofServiceRequest},
+    l1_info_tree::L1InfoTreeMismatch,
+    service::{AggchainProofService, AggchainProofServiceRequest, AggchainProofServiceResponse},
 };
 use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
 use aggkit_prover_types::{
     conversion::v1::context::Contextualize as _,
+    envelope::{ProofEnvelope, ProofEnvelopeError},
     error::AggchainProofRequestError,
+    stage_timings::{Stage, StageTimings},
     v1::{
         aggchain_proof_service_server::AggchainProofService as AggchainProofGrpcService,
         GenerateAggchainProofRequest, GenerateAggchainProofResponse,
         GenerateOptimisticAggchainProofRequest, GenerateOptimisticAggchainProofResponse,
+        Sp1ProofMode,
     },
+    validation::{Validate, ValidationError, ValidationLimits},
 };
 use agglayer_interop::{
     grpc::v1::{AggchainProof, Sp1StarkProof},
     types::bincode,
 };
+use compatibility::ClientCompatibility;
+use mirror::Mirror;
+use network_switch::NetworkSwitch;
+use proposer_service::{cache::SpanProofCache, pending::PendingRequests};
 use prost::bytes::Bytes;
+use prover_errors::ErrorCode;
+use prover_executor::cost::CostLedger;
+use proving_runtime::OnRuntime;
+use rate_limit::RateLimiter;
 use sp1_sdk::SP1_CIRCUIT_VERSION;
-use tonic::{Request, Response, Status};
-use tonic_types::{ErrorDetails, StatusExt};
-use tower::{buffer::Buffer, Service, ServiceExt};
-use tracing::{error, info, instrument};
+use tenancy::Tenants;
+use tokio::runtime::Handle;
+use tonic::{metadata::MetadataValue, Request, Response, Status};
+use tonic_types::ErrorDetails;
+use tower::{buffer::Buffer, util::BoxService, BoxError, Service, ServiceExt};
+use tracing::{error, info, info_span, instrument, warn, Instrument as _};
+
+use crate::{
+    audit::{AuditLog, AuditTrail},
+    leader::Leadership,
+};
+
+pub mod compatibility;
+pub mod mirror;
+pub mod network_switch;
+pub mod proving_runtime;
+pub mod rate_limit;
+pub mod tenancy;
+pub mod v2;
+pub mod verifier;
 
 const MAX_CONCURRENT_REQUESTS: usize = 100;
 
+/// Metadata of the proof responses reporting the time spent in each stage of
+/// the request.
+pub const STAGE_TIMINGS_METADATA: &str = "stage-timings";
+
+/// Service proving the aggchain proof requests, either locally or through
+/// the workers.
+pub type ProofService =
+    BoxService<AggchainProofServiceRequest, AggchainProofServiceResponse, BoxError>;
+
+pub(crate) type BufferedProofService = Buffer<ProofService, AggchainProofServiceRequest>;
+
 #[derive(Clone)]
 pub struct GrpcService {
-    service: Buffer<AggchainProofService, AggchainProofServiceRequest>,
+    service: BufferedProofService,
+    validation_limits: ValidationLimits,
+    rate_limiter: Arc<RateLimiter>,
+    tenants: Arc<Tenants>,
+    cost_ledger: Option<Arc<CostLedger>>,
+    programs: Option<Arc<ProgramRegistry>>,
+    proof_cache: Option<Arc<SpanProofCache>>,
+    pending_proposer_requests: Option<Arc<PendingRequests>>,
+    audit_log: Option<Arc<AuditLog>>,
+    leadership: Leadership,
+    compatibility: Arc<ClientCompatibility>,
+    network_switch: Arc<NetworkSwitch>,
+    mirror: Option<Arc<Mirror>>,
+    optimistic_mode: bool,
 }
 
 impl GrpcService {
     pub async fn new(
         config: &AggchainProofServiceConfig,
     ) -> Result<Self, aggchain_proof_service::Error> {
+        Self::build(config, None).await
+    }
+
+    /// Proves the requests on the given runtime, apart from the one serving
+    /// the gRPC API.
+    pub async fn with_proving_runtime(
+        config: &AggchainProofServiceConfig,
+        proving_runtime: Handle,
+    ) -> Result<Self, aggchain_proof_service::Error> {
+        Self::build(config, Some(proving_runtime)).await
+    }
+
+    async fn build(
+        config: &AggchainProofServiceConfig,
+        proving_runtime: Option<Handle>,
+    ) -> Result<Self, aggchain_proof_service::Error> {
+        let service = AggchainProofService::new(config).await?;
+        let cost_ledger = service.cost_ledger().cloned();
+        let programs = service.programs().clone();
+        let proof_cache = service.proof_cache().cloned();
+        let pending_proposer_requests = service.pending_proposer_requests().clone();
+        let service = match proving_runtime {
+            Some(runtime) => BoxService::new(OnRuntime::new(service, runtime)),
+            None => BoxService::new(service.map_err(BoxError::from)),
+        };
+
         Ok(GrpcService {
+            cost_ledger,
+            programs: Some(programs),
+            proof_cache,
+            pending_proposer_requests: Some(pending_proposer_requests),
+            ..Self::with_service(service)
+        })
+    }
+
+    /// Serves the proof requests with the given service, e.g. one dispatching
+    /// them to the workers.
+    pub fn with_service(service: ProofService) -> Self {
+        GrpcService {
             service: tower::ServiceBuilder::new()
                 .buffer(MAX_CONCURRENT_REQUESTS)
-                .service(AggchainProofService::new(config).await?),
+                .service(service),
+            validation_limits: ValidationLimits::default(),
+            rate_limiter: Default::default(),
+            tenants: Default::default(),
+            cost_ledger: None,
+            programs: None,
+            proof_cache: None,
+            pending_proposer_requests: None,
+            audit_log: None,
+            leadership: Leadership::default(),
+            compatibility: Default::default(),
+            network_switch: Default::default(),
+            mirror: None,
+            optimistic_mode: true,
+        }
+    }
+
+    /// Limits the proof requests, unlimited by default.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+
+        self
+    }
+
+    /// Limits the proof requests of the tenants, none by default.
+    pub fn with_tenants(mut self, tenants: Arc<Tenants>) -> Self {
+        self.tenants = tenants;
+
+        self
+    }
+
+    /// Records the proof requests served into the audit log.
+    pub fn with_audit_log(mut self, audit_log: Arc<AuditLog>) -> Self {
+        self.audit_log = Some(audit_log);
+
+        self
+    }
+
+    /// Serves the proof requests only while leading, always by default.
+    pub fn with_leadership(mut self, leadership: Leadership) -> Self {
+        self.leadership = leadership;
+
+        self
+    }
+
+    /// Rejects the requests of the unsupported client versions, any version
+    /// being served by default.
+    pub fn with_compatibility(mut self, compatibility: Arc<ClientCompatibility>) -> Self {
+        self.compatibility = compatibility;
+
+        self
+    }
+
+    /// Rejects the requests of the disabled networks, none by default.
+    pub fn with_network_switch(mut self, network_switch: Arc<NetworkSwitch>) -> Self {
+        self.network_switch = network_switch;
+
+        self
+    }
+
+    /// Mirrors a share of the proof requests to a secondary prover.
+    pub fn with_mirror(mut self, mirror: Arc<Mirror>) -> Self {
+        self.mirror = Some(mirror);
+
+        self
+    }
+
+    /// Reports the optimistic mode requests as accepted, as by default.
+    pub fn with_optimistic_mode(mut self, enabled: bool) -> Self {
+        self.optimistic_mode = enabled;
+
+        self
+    }
+
+    /// Running totals of the proving cost, when proven locally and accounted
+    /// for.
+    pub fn cost_ledger(&self) -> Option<&Arc<CostLedger>> {
+        self.cost_ledger.as_ref()
+    }
+
+    /// Served aggchain proof programs, when proven locally.
+    pub fn programs(&self) -> Option<&Arc<ProgramRegistry>> {
+        self.programs.as_ref()
+    }
+
+    /// Aggregation proofs already acquired, when proven locally and cached.
+    pub fn proof_cache(&self) -> Option<&Arc<SpanProofCache>> {
+        self.proof_cache.as_ref()
+    }
+
+    /// Aggregation proofs requested to the proposer and not acquired yet,
+    /// when proven locally.
+    pub fn pending_proposer_requests(&self) -> Option<&Arc<PendingRequests>> {
+        self.pending_proposer_requests.as_ref()
+    }
+
+    /// Returns the v2 API implementation, sharing the aggchain proof service
+    /// with this one.
+    pub fn v2(&self) -> v2::GrpcServiceV2 {
+        v2::GrpcServiceV2::new(
+            self.service.clone(),
+            self.validation_limits,
+            self.rate_limiter.clone(),
+            self.audit_log.clone(),
+            self.leadership.clone(),
+        )
+        .with_network_switch(self.network_switch.clone())
+        .with_tenants(self.tenants.clone())
+        .with_mirror(self.mirror.clone())
+        .with_prover_info(
+            self.compatibility.clone(),
+            self.programs.clone(),
+            self.optimistic_mode,
+        )
+    }
+}
+
+/// Starts the audit trail of a request, when audited.
+pub(crate) fn start_audit<T: prost::Message>(
+    audit_log: Option<&Arc<AuditLog>>,
+    rate_limiter: &RateLimiter,
+    method: &'static str,
+    request: &Request<T>,
+) -> Option<AuditTrail> {
+    audit_log.map(|audit_log| {
+        audit_log.start(
+            method,
+            rate_limiter.client_name(request.metadata()),
+            request.get_ref(),
+        )
+    })
+}
+
+/// Validates the request before any conversion, reporting every invalid field
+/// in the error details.
+pub(crate) fn validate_request<R: Validate>(
+    request: &R,
+    limits: &ValidationLimits,
+    message: &str,
+) -> Result<(), Status> {
+    request
+        .validate(limits)
+        .map_err(|ValidationError(violations)| {
+            let mut error_details = ErrorDetails::new();
+            for violation in violations {
+                error_details
+                    .add_bad_request_violation(violation.field_path, violation.description);
+            }
+
+            error!(?error_details, "{message}");
+            ErrorCode::InvalidArgument.status_with_details(message, error_details)
         })
+}
+
+/// Reports the milliseconds spent in each stage of the request in the
+/// [`STAGE_TIMINGS_METADATA`] metadata of the response, as JSON.
+pub(crate) fn with_stage_timings<T>(
+    mut response: Response<T>,
+    stage_timings: &StageTimings,
+) -> Response<T> {
+    match serde_json::to_string(stage_timings)
+        .ok()
+        .and_then(|json| MetadataValue::try_from(json).ok())
+    {
+        Some(value) => {
+            response
+                .metadata_mut()
+                .insert(STAGE_TIMINGS_METADATA, value);
+        }
+        None => warn!(?stage_timings, "Unable to report the stage timings"),
     }
+
+    response
+}
+
+/// Envelope of the generated aggchain proof, encoded with bincode, left empty
+/// should it fail to encode.
+pub(crate) fn proof_envelope(response: &AggchainProofServiceResponse) -> Bytes {
+    let envelope = bincode::sp1v4()
+        .serialize(&response.public_values)
+        .map_err(|error| ProofEnvelopeError::Bincode(error.into()))
+        .and_then(|public_values| {
+            ProofEnvelope::new(
+                response.proof_mode,
+                response.vkey_hash,
+                response.proof.clone(),
+                public_values,
+                compatibility::PROVER_VERSION,
+            )
+            .to_bincode()
+        });
+
+    match envelope {
+        Ok(envelope) => envelope.into(),
+        Err(error) => {
+            warn!(?error, "Unable to encode the proof envelope");
+            Bytes::new()
+        }
+    }
+}
+
+/// Status of a request failed in the aggchain proof service, with the code
+/// of the error. The field of the request it rejects, if any, is reported in
+/// the error details, `ger_field` being the one listing the GERs in the API
+/// of `method`.
+pub(crate) fn service_error_status(
+    method: &str,
+    ger_field: &str,
+    error: &aggchain_proof_service::Error,
+) -> Status {
+    use aggchain_proof_service::Error;
+
+    let code = error.code();
+    let (message, violation) = match error {
+        Error::OptimisticModeRejected(rejection) => (rejection.to_string(), None),
+        Error::CycleBudgetExhausted(exhausted) => (exhausted.to_string(), None),
+        Error::StaleRequest(stale) => (stale.to_string(), None),
+        Error::RequestTooCostly(too_costly) => (
+            too_costly.to_string(),
+            Some((
+                "requested_end_block",
+                format!("at most {}", too_costly.suggested_end_block),
+            )),
+        ),
+        Error::UnknownProgram(unknown) => (
+            unknown.to_string(),
+            Some(("requested_vkey_hash", unknown.to_string())),
+        ),
+        Error::InvalidGer(invalid) => (invalid.to_string(), Some((ger_field, invalid.to_string()))),
+        Error::L1InfoTreeMismatch(mismatch) => {
+            let field = match mismatch {
+                L1InfoTreeMismatch::Leaf { .. } => "l1_info_tree_leaf",
+                L1InfoTreeMismatch::GerLeaf { .. } => ger_field,
+                L1InfoTreeMismatch::Root { .. } => "l1_info_tree_root_hash",
+            };
+            (mismatch.to_string(), Some((field, mismatch.to_string())))
+        }
+        error => (error.to_string(), None),
+    };
+    error!(%code, ?error, "Unable to execute {method} request");
+
+    let mut error_details = ErrorDetails::new();
+    if let Some((field, description)) = violation {
+        error_details.add_bad_request_violation(field, description);
+    }
+
+    code.status_with_details(message, error_details)
 }
 
 #[tonic::async_trait]
@@ -49,7 +387,31 @@ impl AggchainProofGrpcService for GrpcService {
         &self,
         request: Request<GenerateAggchainProofRequest>,
     ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        let started = Instant::now();
+        let (quota, mut tenancy) = async {
+            self.leadership.check()?;
+            self.network_switch.check()?;
+            self.compatibility.check(request.metadata())?;
+            let quota = self.rate_limiter.check(request.metadata()).await?;
+            let client = self.rate_limiter.client_name(request.metadata());
+
+            Ok::<_, Status>((quota, self.tenants.admit(client.as_deref())?))
+        }
+        .instrument(info_span!("intake"))
+        .await?;
+        let mut stage_timings = StageTimings::default();
+        stage_timings.record(Stage::Intake, started.elapsed());
+        let mut audit = start_audit(
+            self.audit_log.as_ref(),
+            &self.rate_limiter,
+            "GenerateAggchainProof",
+            &request,
+        );
         let request = request.into_inner();
+        let shadow = self
+            .mirror
+            .as_ref()
+            .and_then(|mirror| mirror.sample(&request));
 
         let last_proven_block = request.last_proven_block;
         let requested_end_block = request.requested_end_block;
@@ -66,24 +428,14 @@ impl AggchainProofGrpcService for GrpcService {
             "Received GenerateAggchainProof request"
         );
 
-        if request.requested_end_block <= request.last_proven_block {
-            let mut error = ErrorDetails::new();
-            error.add_bad_request_violation(
-                "requested_end_block",
-                "requested_end_block must be greater than last_proven_block",
-            );
-
-            error!(%last_proven_block, %requested_end_block, ?error,
-                "Invalid GenerateAggchainProof request argument(s)");
-
-            return Err(Status::with_error_details(
-                tonic::Code::InvalidArgument,
+        let started = Instant::now();
+        let aggchain_proof_inputs: AggchainProofInputs = info_span!("validation").in_scope(|| {
+            validate_request(
+                &request,
+                &self.validation_limits,
                 "Invalid GenerateAggchainProof request argument(s)",
-                error,
-            ));
-        }
+            )?;
 
-        let aggchain_proof_inputs: AggchainProofInputs =
             request
                 .try_into()
                 .map_err(|error: AggchainProofRequestError| {
@@ -91,16 +443,20 @@ impl AggchainProofGrpcService for GrpcService {
                     let field = error.field_path();
                     let mut error_details = ErrorDetails::new();
                     error_details.add_bad_request_violation(field, error.to_string());
-                    Status::with_error_details(
-                        tonic::Code::InvalidArgument,
+                    ErrorCode::InvalidArgument.status_with_details(
                         "Invalid GenerateAggchainProof request data",
                         error_details,
                     )
-                })?;
+                })
+        })?;
+        stage_timings.record(Stage::Validation, started.elapsed());
 
         let mut context = aggchain_proof_inputs.context();
 
         let proof_request = AggchainProofServiceRequest::Normal(aggchain_proof_inputs);
+        if let Some(tenancy) = &mut tenancy {
+            tenancy.estimate(&proof_request);
+        }
 
         let mut service = self.service.clone();
 
@@ -108,9 +464,12 @@ impl AggchainProofGrpcService for GrpcService {
             .ready()
             .await
             .inspect_err(|e| error!(%last_proven_block, %requested_end_block, "Unable to use the aggchain proof service: {e:?} "))
-            .map_err(|_| Status::internal("Unable to use the aggchain proof service"))?;
+            .map_err(|_| ErrorCode::Internal.status("Unable to use the aggchain proof service"))?;
 
-        match service.call(proof_request).await {
+        if let Some(audit) = &mut audit {
+            audit.proving_started();
+        }
+        let result = match service.call(proof_request).await {
             Ok(response) => {
                 info!(?response.custom_chain_data,
                     "customchaindata: {}",
@@ -132,10 +491,13 @@ impl AggchainProofGrpcService for GrpcService {
                     "end_block".to_owned(),
                     Bytes::from(response.end_block.to_be_bytes().to_vec()),
                 );
+                stage_timings.merge(&response.stage_timings);
                 info!(last_proven_block = %response.last_proven_block,
                     end_block = %response.end_block,
+                    ?stage_timings,
                     "GenerateAggchainProof request executed successfully");
-                Ok(Response::new(GenerateAggchainProofResponse {
+                let proof_envelope = proof_envelope(&response);
+                let response = Response::new(GenerateAggchainProofResponse {
                     aggchain_proof: Some(AggchainProof {
                         aggchain_params: Some(response.aggchain_params.into()),
                         // Signature is handled by the initiator
@@ -153,15 +515,37 @@ impl AggchainProofGrpcService for GrpcService {
                     end_block: response.end_block,
                     local_exit_root_hash: Some(response.local_exit_root_hash.into()),
                     custom_chain_data: response.custom_chain_data.into(),
-                }))
+                    proof_envelope,
+                    sp1_proof_mode: Sp1ProofMode::from(response.proof_mode) as i32,
+                    requested_end_block: response.requested_end_block,
+                });
+                Ok(with_stage_timings(response, &stage_timings))
             }
             // TODO: Return a different error when the proof is not yet ready.
             // The gRPC API currently does not expose the status.
-            Err(error) => {
-                error!(%last_proven_block, %requested_end_block, ?error, "Unable to execute GenerateAggchainProof request");
-                Err(Status::internal(error.to_string()))
-            }
+            Err(error) => match error.downcast_ref::<aggchain_proof_service::Error>() {
+                Some(service_error) => Err(service_error_status(
+                    "GenerateAggchainProof",
+                    "ger_leaves",
+                    service_error,
+                )),
+                None => {
+                    error!(%last_proven_block, %requested_end_block, ?error, "Unable to execute GenerateAggchainProof request");
+                    Err(ErrorCode::Internal.status(error.to_string()))
+                }
+            },
+        };
+        if let Some(audit) = audit {
+            audit.record(&result);
+        }
+        if let Some(shadow) = shadow {
+            shadow.compare(&result);
         }
+        if let Some(tenancy) = tenancy {
+            tenancy.settle(&result);
+        }
+
+        quota.annotate(result)
     }
 
     #[instrument(skip(self, request))]
@@ -169,24 +553,57 @@ impl AggchainProofGrpcService for GrpcService {
         &self,
         request: Request<GenerateOptimisticAggchainProofRequest>,
     ) -> Result<Response<GenerateOptimisticAggchainProofResponse>, Status> {
+        let started = Instant::now();
+        let (quota, mut tenancy) = async {
+            self.leadership.check()?;
+            self.network_switch.check()?;
+            self.compatibility.check(request.metadata())?;
+            let quota = self.rate_limiter.check(request.metadata()).await?;
+            let client = self.rate_limiter.client_name(request.metadata());
+
+            Ok::<_, Status>((quota, self.tenants.admit(client.as_deref())?))
+        }
+        .instrument(info_span!("intake"))
+        .await?;
+        let mut stage_timings = StageTimings::default();
+        stage_timings.record(Stage::Intake, started.elapsed());
+        let mut audit = start_audit(
+            self.audit_log.as_ref(),
+            &self.rate_limiter,
+            "GenerateOptimisticAggchainProof",
+            &request,
+        );
         let request = request.into_inner();
+        let shadow = self
+            .mirror
+            .as_ref()
+            .and_then(|mirror| mirror.sample(&request));
 
-        let aggchain_proof_inputs: OptimisticAggchainProofInputs =
-            request
-                .try_into()
-                .map_err(|error: AggchainProofRequestError| {
-                    let field = error.field_path();
-                    let mut error_details = ErrorDetails::new();
-                    error_details.add_bad_request_violation(field, error.to_string());
-                    error!(
-                        "Invalid GenerateOptimisticAggchainProof request data: {error_details:?}"
-                    );
-                    Status::with_error_details(
-                        tonic::Code::InvalidArgument,
-                        "Invalid GenerateOptimisticAggchainProof request data",
-                        error_details,
-                    )
-                })?;
+        let started = Instant::now();
+        let aggchain_proof_inputs: OptimisticAggchainProofInputs = info_span!("validation")
+            .in_scope(|| {
+                validate_request(
+                    &request,
+                    &self.validation_limits,
+                    "Invalid GenerateOptimisticAggchainProof request argument(s)",
+                )?;
+
+                request
+                    .try_into()
+                    .map_err(|error: AggchainProofRequestError| {
+                        let field = error.field_path();
+                        let mut error_details = ErrorDetails::new();
+                        error_details.add_bad_request_violation(field, error.to_string());
+                        error!(
+                            "Invalid GenerateOptimisticAggchainProof request data: {error_details:?}"
+                        );
+                        ErrorCode::InvalidArgument.status_with_details(
+                            "Invalid GenerateOptimisticAggchainProof request data",
+                            error_details,
+                        )
+                    })
+            })?;
+        stage_timings.record(Stage::Validation, started.elapsed());
 
         let last_proven_block = aggchain_proof_inputs
             .aggchain_proof_inputs
@@ -204,32 +621,12 @@ impl AggchainProofGrpcService for GrpcService {
             ),
             "Received GenerateOptimisticAggchainProof request");
 
-        if aggchain_proof_inputs
-            .aggchain_proof_inputs
-            .requested_end_block
-            <= aggchain_proof_inputs
-                .aggchain_proof_inputs
-                .last_proven_block
-        {
-            let mut error = ErrorDetails::new();
-            error.add_bad_request_violation(
-                "requested_end_block",
-                "requested_end_block must be greater than last_proven_block",
-            );
-
-            error!(%last_proven_block, %requested_end_block,
-                "Invalid GenerateOptimisticAggchainProof request argument(s): {error:?}");
-
-            return Err(Status::with_error_details(
-                tonic::Code::InvalidArgument,
-                "Invalid GenerateOptimisticAggchainProof request argument(s)",
-                error,
-            ));
-        }
-
         let mut context = aggchain_proof_inputs.context();
 
         let proof_request = AggchainProofServiceRequest::Optimistic(aggchain_proof_inputs);
+        if let Some(tenancy) = &mut tenancy {
+            tenancy.estimate(&proof_request);
+        }
 
         let mut service = self.service.clone();
 
@@ -237,9 +634,12 @@ impl AggchainProofGrpcService for GrpcService {
             .ready()
             .await
             .inspect_err(|e| error!(%last_proven_block, %requested_end_block, "Unable to use the aggchain proof service: {e:?} "))
-            .map_err(|_| Status::internal("Unable to use the aggchain proof service"))?;
+            .map_err(|_| ErrorCode::Internal.status("Unable to use the aggchain proof service"))?;
 
-        match service.call(proof_request).await {
+        if let Some(audit) = &mut audit {
+            audit.proving_started();
+        }
+        let result = match service.call(proof_request).await {
             Ok(response) => {
                 context.insert(
                     "public_values".to_owned(),
@@ -257,10 +657,13 @@ impl AggchainProofGrpcService for GrpcService {
                     "end_block".to_owned(),
                     Bytes::from(response.end_block.to_be_bytes().to_vec()),
                 );
+                stage_timings.merge(&response.stage_timings);
                 info!(last_proven_block = %response.last_proven_block,
                     end_block = %response.end_block,
+                    ?stage_timings,
                     "Generate optimistic aggchain proof request executed successfully");
-                Ok(Response::new(GenerateOptimisticAggchainProofResponse {
+                let proof_envelope = proof_envelope(&response);
+                let response = Response::new(GenerateOptimisticAggchainProofResponse {
                     aggchain_proof: Some(AggchainProof {
                         aggchain_params: Some(response.aggchain_params.into()),
                         // Signature is handled by the initiator
@@ -276,14 +679,36 @@ impl AggchainProofGrpcService for GrpcService {
                     }),
                     local_exit_root_hash: Some(response.local_exit_root_hash.into()),
                     custom_chain_data: response.custom_chain_data.into(),
-                }))
+                    proof_envelope,
+                    sp1_proof_mode: Sp1ProofMode::from(response.proof_mode) as i32,
+                    requested_end_block: response.requested_end_block,
+                });
+                Ok(with_stage_timings(response, &stage_timings))
             }
             // TODO: Return a different error when the proof is not yet ready.
             // The gRPC API currently does not expose the status.
-            Err(error) => {
-                error!(%last_proven_block, %requested_end_block, ?error, "Unable to execute GenerateOptimisticAggchainProof request");
-                Err(Status::internal(error.to_string()))
-            }
+            Err(error) => match error.downcast_ref::<aggchain_proof_service::Error>() {
+                Some(service_error) => Err(service_error_status(
+                    "GenerateOptimisticAggchainProof",
+                    "ger_leaves",
+                    service_error,
+                )),
+                None => {
+                    error!(%last_proven_block, %requested_end_block, ?error, "Unable to execute GenerateOptimisticAggchainProof request");
+                    Err(ErrorCode::Internal.status(error.to_string()))
+                }
+            },
+        };
+        if let Some(audit) = audit {
+            audit.record(&result);
         }
+        if let Some(shadow) = shadow {
+            shadow.compare(&result);
+        }
+        if let Some(tenancy) = tenancy {
+            tenancy.settle(&result);
+        }
+
+        quota.annotate(result)
     }
 }
@@ -0,0 +1,140 @@
+//! Compatibility handshake with the clients.
+//!
+//! The clients report their semantic version in the
+//! [`CLIENT_VERSION_METADATA`] metadata, and the proof requests of the clients
+//! older than the configured minimum are rejected with a message asking to
+//! upgrade. The requests without it are served, the clients predating the
+//! handshake not reporting any. The versions and capabilities of the prover
+//! are returned by the `GetProverInfo` v2 RPC.
+use aggkit_prover_config::CompatibilityConfig;
+use prover_errors::ErrorCode;
+use semver::Version;
+use tonic::{metadata::MetadataMap, Status};
+use tonic_types::ErrorDetails;
+use tracing::warn;
+
+/// Metadata carrying the version of the client.
+pub const CLIENT_VERSION_METADATA: &str = "x-client-version";
+
+/// Version of the prover, reported to the clients.
+pub const PROVER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Packages of the gRPC APIs served.
+pub const API_VERSIONS: [&str; 2] = ["aggkit.prover.v1", "aggkit.prover.v2"];
+
+/// Check of the client versions against the oldest one served.
+#[derive(Debug, Clone, Default)]
+pub struct ClientCompatibility {
+    min_client_version: Option<Version>,
+}
+
+impl ClientCompatibility {
+    pub fn new(config: &CompatibilityConfig) -> Self {
+        Self {
+            min_client_version: config.min_client_version.clone(),
+        }
+    }
+
+    /// Oldest client version served, any when unset.
+    pub fn min_client_version(&self) -> Option<&Version> {
+        self.min_client_version.as_ref()
+    }
+
+    /// Rejects the request of a client reporting an unsupported version in
+    /// the request metadata.
+    pub fn check(&self, metadata: &MetadataMap) -> Result<(), Status> {
+        let Some(version) = metadata.get(CLIENT_VERSION_METADATA) else {
+            return Ok(());
+        };
+        let version = version
+            .to_str()
+            .map_err(|_| invalid_version("non-ASCII version"))?;
+
+        let (false, Some(min_client_version)) = (self.supports(version)?, &self.min_client_version)
+        else {
+            return Ok(());
+        };
+        warn!(client_version = version, %min_client_version, "Unsupported client version");
+
+        let mut error_details = ErrorDetails::new();
+        error_details.add_precondition_failure_violation(
+            "VERSION",
+            CLIENT_VERSION_METADATA,
+            format!("at least {min_client_version}"),
+        );
+
+        Err(ErrorCode::UnsupportedClientVersion.status_with_details(
+            format!(
+                "Client version {version} is not supported by the aggkit prover \
+                 {PROVER_VERSION}, upgrade the client to {min_client_version} or later"
+            ),
+            error_details,
+        ))
+    }
+
+    /// Whether the given client version is served, failing if it is not a
+    /// semantic version. A leading `v` is accepted.
+    pub fn supports(&self, version: &str) -> Result<bool, Status> {
+        let version = Version::parse(version.trim().trim_start_matches('v'))
+            .map_err(|error| invalid_version(&error.to_string()))?;
+
+        Ok(self
+            .min_client_version
+            .as_ref()
+            .is_none_or(|min_client_version| version >= *min_client_version))
+    }
+}
+
+fn invalid_version(description: &str) -> Status {
+    let mut error_details = ErrorDetails::new();
+    error_details.add_bad_request_violation(CLIENT_VERSION_METADATA, description);
+
+    ErrorCode::InvalidArgument.status_with_details("Invalid client version", error_details)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(version: Option<&str>) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        if let Some(version) = version {
+            metadata.insert(CLIENT_VERSION_METADATA, version.parse().unwrap());
+        }
+
+        metadata
+    }
+
+    #[test]
+    fn older_clients_are_rejected() {
+        let compatibility =
+            ClientCompatibility::new(&toml::from_str(r#"min-client-version = "0.7.0""#).unwrap());
+
+        assert!(compatibility.check(&metadata(None)).is_ok());
+        assert!(compatibility.check(&metadata(Some("0.7.0"))).is_ok());
+        assert!(compatibility.check(&metadata(Some("v1.2.3"))).is_ok());
+
+        let status = compatibility.check(&metadata(Some("0.6.9"))).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::FailedPrecondition);
+        assert_eq!(
+            ErrorCode::of(&status),
+            Some(ErrorCode::UnsupportedClientVersion)
+        );
+        assert!(status.message().contains("upgrade the client to 0.7.0"));
+        assert_eq!(
+            compatibility
+                .supports("0.7.0-rc.1")
+                .map_err(|status| status.code()),
+            Ok(false)
+        );
+
+        let status = compatibility.check(&metadata(Some("latest"))).unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+        let any = ClientCompatibility::default();
+        assert_eq!(
+            any.supports("0.1.0").map_err(|status| status.code()),
+            Ok(true)
+        );
+    }
+}
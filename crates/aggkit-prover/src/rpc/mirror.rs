@@ -0,0 +1,308 @@
+//! Mirroring of a share of the proof requests to a secondary prover, e.g. one
+//! running a new version of the aggchain proof program, before upgrading.
+//!
+//! The requests are mirrored once served, the secondary prover being called
+//! in the background and its responses never served. The public values of
+//! its proofs are compared with the served ones, and the outcomes are logged
+//! and counted by the `mirrored_requests` metric. The mirrored requests are
+//! spread evenly, `percentage` out of every hundred.
+use std::{
+    str::FromStr as _,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        LazyLock,
+    },
+};
+
+use aggkit_prover_config::MirroringConfig;
+use aggkit_prover_types::{v1, v2};
+use agglayer_interop::grpc::v1::AggchainProof;
+use futures::future::BoxFuture;
+use opentelemetry::{global, metrics::Counter, KeyValue};
+use prost::bytes::Bytes;
+use tonic::{
+    transport::{Channel, ClientTlsConfig, Endpoint},
+    Response, Status,
+};
+use tracing::{info, warn};
+
+const METER_NAME: &str = "aggkit_prover";
+
+static MIRRORED_REQUESTS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("mirrored_requests")
+        .with_description("Proof requests mirrored to the secondary prover, by method and outcome")
+        .build()
+});
+
+#[derive(Debug, thiserror::Error)]
+pub enum MirrorError {
+    #[error("Invalid mirroring endpoint")]
+    Endpoint(#[from] tonic::transport::Error),
+}
+
+/// Call of the secondary prover.
+pub type MirroredCall<T> = BoxFuture<'static, Result<Response<T>, Status>>;
+
+/// Proof request which can be mirrored to the secondary prover.
+pub trait MirroredRequest: Clone + Send + 'static {
+    type Response: Send + 'static;
+
+    /// Full name of the RPC, for the logs and the metrics.
+    const METHOD: &'static str;
+
+    fn call(self, channel: Channel) -> MirroredCall<Self::Response>;
+
+    /// Proof of the response, whose context carries the public values.
+    fn aggchain_proof(response: &Self::Response) -> Option<&AggchainProof>;
+}
+
+macro_rules! mirrored_request {
+    ($request:ty, $response:ty, $client:ty, $method:ident, $name:literal) => {
+        impl MirroredRequest for $request {
+            type Response = $response;
+
+            const METHOD: &'static str = $name;
+
+            fn call(self, channel: Channel) -> MirroredCall<Self::Response> {
+                Box::pin(async move { <$client>::new(channel).$method(self).await })
+            }
+
+            fn aggchain_proof(response: &Self::Response) -> Option<&AggchainProof> {
+                response.aggchain_proof.as_ref()
+            }
+        }
+    };
+}
+
+mirrored_request!(
+    v1::GenerateAggchainProofRequest,
+    v1::GenerateAggchainProofResponse,
+    v1::aggchain_proof_service_client::AggchainProofServiceClient<Channel>,
+    generate_aggchain_proof,
+    "aggkit.prover.v1.GenerateAggchainProof"
+);
+mirrored_request!(
+    v1::GenerateOptimisticAggchainProofRequest,
+    v1::GenerateOptimisticAggchainProofResponse,
+    v1::aggchain_proof_service_client::AggchainProofServiceClient<Channel>,
+    generate_optimistic_aggchain_proof,
+    "aggkit.prover.v1.GenerateOptimisticAggchainProof"
+);
+mirrored_request!(
+    v2::GenerateAggchainProofRequest,
+    v2::GenerateAggchainProofResponse,
+    v2::aggchain_proof_service_client::AggchainProofServiceClient<Channel>,
+    generate_aggchain_proof,
+    "aggkit.prover.v2.GenerateAggchainProof"
+);
+mirrored_request!(
+    v2::GenerateOptimisticAggchainProofRequest,
+    v2::GenerateAggchainProofResponse,
+    v2::aggchain_proof_service_client::AggchainProofServiceClient<Channel>,
+    generate_optimistic_aggchain_proof,
+    "aggkit.prover.v2.GenerateOptimisticAggchainProof"
+);
+
+/// Mirroring of the proof requests to the secondary prover.
+pub struct Mirror {
+    channel: Channel,
+    percentage: u64,
+    requests: AtomicU64,
+}
+
+impl Mirror {
+    /// Connects lazily, on the first mirrored request.
+    pub fn new(config: &MirroringConfig) -> Result<Self, MirrorError> {
+        let mut endpoint = Endpoint::from_str(config.endpoint.as_str())?.timeout(config.timeout);
+        if config.endpoint.scheme() == "https" {
+            endpoint = endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?;
+        }
+
+        Ok(Self {
+            channel: endpoint.connect_lazy(),
+            percentage: config.percentage.into(),
+            requests: AtomicU64::new(0),
+        })
+    }
+
+    /// Keeps a copy of the request when it is one of those mirrored.
+    pub fn sample<R: MirroredRequest>(&self, request: &R) -> Option<Shadow<R>> {
+        let index = self.requests.fetch_add(1, Ordering::Relaxed) % 100;
+        let mirrored = (index + 1) * self.percentage / 100 > index * self.percentage / 100;
+
+        mirrored.then(|| Shadow {
+            channel: self.channel.clone(),
+            request: request.clone(),
+        })
+    }
+}
+
+/// Copy of a proof request, mirrored once served.
+pub struct Shadow<R> {
+    channel: Channel,
+    request: R,
+}
+
+impl<R: MirroredRequest> Shadow<R> {
+    /// Mirrors the request in the background, comparing the outcome with the
+    /// served one.
+    pub fn compare(self, served: &Result<Response<R::Response>, Status>) {
+        let served = served
+            .as_ref()
+            .map(|response| public_values(R::aggchain_proof(response.get_ref())))
+            .map_err(Status::code);
+
+        tokio::spawn(async move {
+            let mirrored = self
+                .request
+                .call(self.channel)
+                .await
+                .map(|response| public_values(R::aggchain_proof(response.get_ref())));
+
+            report(R::METHOD, &served, &mirrored);
+        });
+    }
+}
+
+/// Outcome of a mirrored request, compared with the served one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Matched,
+    Diverged,
+    PrimaryFailed,
+    SecondaryFailed,
+    BothFailed,
+}
+
+impl Outcome {
+    fn of(
+        served: &Result<Option<Bytes>, tonic::Code>,
+        mirrored: &Result<Option<Bytes>, Status>,
+    ) -> Self {
+        match (served, mirrored) {
+            (Ok(served), Ok(mirrored)) if served == mirrored => Self::Matched,
+            (Ok(_), Ok(_)) => Self::Diverged,
+            (Err(_), Ok(_)) => Self::PrimaryFailed,
+            (Ok(_), Err(_)) => Self::SecondaryFailed,
+            (Err(_), Err(_)) => Self::BothFailed,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Matched => "matched",
+            Self::Diverged => "diverged",
+            Self::PrimaryFailed => "primary-failed",
+            Self::SecondaryFailed => "secondary-failed",
+            Self::BothFailed => "both-failed",
+        }
+    }
+}
+
+fn report(
+    method: &'static str,
+    served: &Result<Option<Bytes>, tonic::Code>,
+    mirrored: &Result<Option<Bytes>, Status>,
+) {
+    let outcome = Outcome::of(served, mirrored);
+    MIRRORED_REQUESTS.add(
+        1,
+        &[
+            KeyValue::new("method", method),
+            KeyValue::new("outcome", outcome.as_str()),
+        ],
+    );
+
+    match (outcome, served, mirrored) {
+        (Outcome::Diverged, Ok(served), Ok(mirrored)) => warn!(
+            method,
+            served = ?served.as_deref().map(hex::encode),
+            mirrored = ?mirrored.as_deref().map(hex::encode),
+            "Public values of the mirrored request diverged"
+        ),
+        (Outcome::PrimaryFailed, Err(code), _) => warn!(
+            method,
+            served = ?code,
+            "Mirrored request succeeded on the secondary prover only"
+        ),
+        (Outcome::SecondaryFailed, _, Err(status)) => warn!(
+            method,
+            mirrored = %status,
+            "Mirrored request failed on the secondary prover only"
+        ),
+        (outcome, ..) => info!(
+            method,
+            outcome = outcome.as_str(),
+            "Mirrored request compared"
+        ),
+    }
+}
+
+/// Public values recorded in the context of the proof.
+fn public_values(aggchain_proof: Option<&AggchainProof>) -> Option<Bytes> {
+    aggchain_proof?.context.get("public_values").cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_are_sampled_evenly() {
+        let sampled = |percentage| {
+            let mirror = Mirror::new(&MirroringConfig {
+                endpoint: "http://aggkit-prover-canary:4446".parse().unwrap(),
+                percentage,
+                timeout: std::time::Duration::from_secs(60),
+            })
+            .unwrap();
+
+            (0..200)
+                .filter(|_| {
+                    mirror
+                        .sample(&v1::GenerateAggchainProofRequest::default())
+                        .is_some()
+                })
+                .count()
+        };
+
+        assert_eq!(sampled(0), 0);
+        assert_eq!(sampled(10), 20);
+        assert_eq!(sampled(33), 66);
+        assert_eq!(sampled(100), 200);
+    }
+
+    #[test]
+    fn public_values_are_compared() {
+        let values = |values: &'static [u8]| Ok(Some(Bytes::from_static(values)));
+
+        assert_eq!(Outcome::of(&values(b"a"), &values(b"a")), Outcome::Matched);
+        assert_eq!(Outcome::of(&values(b"a"), &values(b"b")), Outcome::Diverged);
+        assert_eq!(
+            Outcome::of(&Err(tonic::Code::Internal), &values(b"a")),
+            Outcome::PrimaryFailed
+        );
+        assert_eq!(
+            Outcome::of(&values(b"a"), &Err(Status::unavailable("down"))),
+            Outcome::SecondaryFailed
+        );
+        assert_eq!(
+            Outcome::of(
+                &Err(tonic::Code::Internal),
+                &Err(Status::internal("failed"))
+            ),
+            Outcome::BothFailed
+        );
+
+        let mut aggchain_proof = AggchainProof::default();
+        assert_eq!(public_values(Some(&aggchain_proof)), None);
+        aggchain_proof
+            .context
+            .insert("public_values".to_string(), Bytes::from_static(b"a"));
+        assert_eq!(
+            public_values(Some(&aggchain_proof)),
+            Some(Bytes::from_static(b"a"))
+        );
+    }
+}
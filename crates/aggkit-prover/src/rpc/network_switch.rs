@@ -0,0 +1,121 @@
+//! Switch disabling the proof requests of some networks, e.g. during an
+//! incident on one rollup, without stopping the prover.
+//!
+//! The disabled networks are initially the configured ones, then toggled
+//! through the admin API until the next restart. The requests of a disabled
+//! network are rejected as unavailable, with the
+//! [`ErrorCode::NetworkDisabled`] code.
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use aggkit_prover_config::NetworksConfig;
+use prover_errors::ErrorCode;
+use serde::Serialize;
+use tonic::Status;
+use tonic_types::ErrorDetails;
+
+/// Networks served, as reported by the admin API.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworksState {
+    /// Network of the proof requests served by this prover.
+    pub network_id: u32,
+    /// Whether the requests of that network are served.
+    pub enabled: bool,
+    pub disabled: BTreeSet<u32>,
+}
+
+/// Networks whose proof requests are rejected.
+#[derive(Debug, Default)]
+pub struct NetworkSwitch {
+    network_id: u32,
+    disabled: RwLock<BTreeSet<u32>>,
+}
+
+impl NetworkSwitch {
+    pub fn new(config: &NetworksConfig, network_id: u32) -> Self {
+        Self {
+            network_id,
+            disabled: RwLock::new(config.disabled.clone()),
+        }
+    }
+
+    /// Rejects the requests while the network served is disabled.
+    pub fn check(&self) -> Result<(), Status> {
+        if !self.disabled().contains(&self.network_id) {
+            return Ok(());
+        }
+
+        Err(ErrorCode::NetworkDisabled.status_with_metadata(
+            format!(
+                "The proof requests of the network {} are disabled",
+                self.network_id
+            ),
+            ErrorDetails::new(),
+            HashMap::from([("network-id".to_string(), self.network_id.to_string())]),
+        ))
+    }
+
+    /// Enables or disables the requests of the network, returning whether
+    /// that changed anything.
+    pub fn set_enabled(&self, network_id: u32, enabled: bool) -> bool {
+        let mut disabled = self.disabled_mut();
+        if enabled {
+            disabled.remove(&network_id)
+        } else {
+            disabled.insert(network_id)
+        }
+    }
+
+    pub fn state(&self) -> NetworksState {
+        let disabled = self.disabled().clone();
+
+        NetworksState {
+            network_id: self.network_id,
+            enabled: !disabled.contains(&self.network_id),
+            disabled,
+        }
+    }
+
+    fn disabled(&self) -> RwLockReadGuard<'_, BTreeSet<u32>> {
+        self.disabled.read().expect("network switch lock poisoned")
+    }
+
+    fn disabled_mut(&self) -> RwLockWriteGuard<'_, BTreeSet<u32>> {
+        self.disabled.write().expect("network switch lock poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic_types::StatusExt as _;
+
+    use super::*;
+
+    #[test]
+    fn disabled_networks_are_rejected() {
+        let switch = NetworkSwitch::new(&toml::from_str("disabled = [2]").unwrap(), 1);
+        assert!(switch.check().is_ok());
+
+        assert!(switch.set_enabled(1, false));
+        assert!(!switch.set_enabled(1, false));
+        let status = switch.check().unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        let error_info = status.get_details_error_info().unwrap();
+        assert_eq!(error_info.reason, ErrorCode::NetworkDisabled.reason());
+        assert_eq!(error_info.metadata["network-id"], "1");
+        assert_eq!(
+            switch.state(),
+            NetworksState {
+                network_id: 1,
+                enabled: false,
+                disabled: BTreeSet::from([1, 2]),
+            }
+        );
+
+        assert!(switch.set_enabled(1, true));
+        assert!(switch.check().is_ok());
+    }
+}
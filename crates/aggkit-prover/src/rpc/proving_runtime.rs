@@ -0,0 +1,126 @@
+//! Proving of the requests on a dedicated runtime, apart from the one serving
+//! the gRPC API, the admin API and the health checks.
+//!
+//! The witness assembly is polled by the workers of the proving runtime and
+//! the local proving runs on its blocking pool, so that big jobs neither
+//! starve the status RPCs nor the health checks. The task proving a request
+//! is traced under its span, and aborted when the request is cancelled by its
+//! client.
+use std::task::{Context, Poll};
+
+use futures::future::BoxFuture;
+use tokio::runtime::Handle;
+use tokio_util::task::AbortOnDropHandle;
+use tower::{BoxError, Service};
+use tracing::Instrument as _;
+
+/// Service calling the inner one on the proving runtime.
+#[derive(Clone)]
+pub struct OnRuntime<S> {
+    inner: S,
+    runtime: Handle,
+}
+
+impl<S> OnRuntime<S> {
+    pub fn new(inner: S, runtime: Handle) -> Self {
+        Self { inner, runtime }
+    }
+}
+
+impl<S, Request> Service<Request> for OnRuntime<S>
+where
+    S: Service<Request>,
+    S::Response: Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        // The stage spans of the request stay under its span.
+        let call = self.inner.call(request).in_current_span();
+        let task = AbortOnDropHandle::new(self.runtime.spawn(call));
+
+        Box::pin(async move { task.await?.map_err(Into::into) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use tokio::sync::Notify;
+    use tower::ServiceExt as _;
+
+    use super::*;
+
+    fn proving_runtime() -> tokio::runtime::Runtime {
+        tokio::runtime::Builder::new_multi_thread()
+            .thread_name("aggkit-prover-proving")
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn requests_are_proven_on_the_proving_runtime() {
+        let runtime = proving_runtime();
+        let service = OnRuntime::new(
+            tower::service_fn(|()| async {
+                Ok::<_, BoxError>(std::thread::current().name().map(str::to_string))
+            }),
+            runtime.handle().clone(),
+        );
+
+        assert_eq!(
+            service.oneshot(()).await.unwrap().as_deref(),
+            Some("aggkit-prover-proving")
+        );
+
+        runtime.shutdown_background();
+    }
+
+    #[tokio::test]
+    async fn cancelled_requests_are_aborted() {
+        struct Dropped(Arc<Notify>);
+
+        impl Drop for Dropped {
+            fn drop(&mut self) {
+                self.0.notify_one();
+            }
+        }
+
+        let runtime = proving_runtime();
+        let dropped = Arc::new(Notify::new());
+        let service = OnRuntime::new(
+            tower::service_fn({
+                let dropped = dropped.clone();
+                move |()| {
+                    let guard = Dropped(dropped.clone());
+                    async move {
+                        let _guard = guard;
+                        std::future::pending::<Result<(), BoxError>>().await
+                    }
+                }
+            }),
+            runtime.handle().clone(),
+        );
+
+        let request = service.oneshot(());
+        assert!(tokio::time::timeout(Duration::from_millis(10), request)
+            .await
+            .is_err());
+        tokio::time::timeout(Duration::from_secs(5), dropped.notified())
+            .await
+            .unwrap();
+
+        runtime.shutdown_background();
+    }
+}
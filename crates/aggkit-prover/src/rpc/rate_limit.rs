@@ -0,0 +1,526 @@
+//! Limits of the proof requests, per network and per authenticated client.
+//!
+//! The clients sending a known API key in the [`API_KEY_METADATA`] metadata
+//! are counted against their own limit, if configured, such that one
+//! misbehaving aggsender cannot starve the others sharing the network limit.
+//! The responses carry the requests left in the current window in the
+//! [`REMAINING_METADATA`] metadata.
+//!
+//! The counters are kept in memory, or in Redis with the `redis` feature so
+//! that the replicas behind a load balancer share them.
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use aggkit_prover_config::{RateLimit, RateLimitingBackend, RateLimitingConfig};
+use prover_errors::ErrorCode;
+use serde::Serialize;
+use tonic::{metadata::MetadataMap, Response, Status};
+use tonic_types::ErrorDetails;
+use tracing::{error, warn};
+
+#[cfg(feature = "redis")]
+mod redis_store;
+
+/// Metadata carrying the API key of the client.
+pub const API_KEY_METADATA: &str = "x-api-key";
+
+/// Metadata carrying the requests left to the client in the current window.
+pub const REMAINING_METADATA: &str = "ratelimit-remaining";
+
+/// Counter a request is accounted to.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum RateLimitKey {
+    Network(u32),
+    Client(String),
+}
+
+struct Client {
+    name: String,
+    api_key: String,
+    limit: Option<RateLimit>,
+}
+
+/// State of the current window of one limit.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RateLimitState {
+    pub key: RateLimitKey,
+    #[serde(flatten)]
+    pub limit: RateLimit,
+    /// Requests left until the end of the window.
+    pub remaining: u32,
+    /// Time left until the next window, unset when no request is counted.
+    #[serde(
+        with = "prover_utils::with::HumanDuration",
+        skip_serializing_if = "Duration::is_zero"
+    )]
+    pub reset_in: Duration,
+}
+
+/// Requests left to the client once its request is counted, unknown when the
+/// client is not limited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota(Option<u32>);
+
+impl Quota {
+    /// Adds the remaining requests to the metadata of the response.
+    pub fn annotate<T>(self, result: Result<Response<T>, Status>) -> Result<Response<T>, Status> {
+        let Some(remaining) = self.0 else {
+            return result;
+        };
+
+        match result {
+            Ok(mut response) => {
+                response
+                    .metadata_mut()
+                    .insert(REMAINING_METADATA, remaining.into());
+                Ok(response)
+            }
+            Err(mut status) => {
+                status
+                    .metadata_mut()
+                    .insert(REMAINING_METADATA, remaining.into());
+                Err(status)
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[cfg(feature = "redis")]
+    #[error("Redis rate limiting store failure")]
+    Redis(#[from] ::redis::RedisError),
+
+    #[cfg(not(feature = "redis"))]
+    #[error("The redis rate limiting backend requires the redis feature")]
+    RedisUnsupported,
+}
+
+/// Outcome of counting one request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Admission {
+    Accepted { remaining: u32 },
+    Rejected { retry_after: Duration },
+}
+
+/// Store of the request counters, one fixed window per [`RateLimitKey`].
+pub enum RateLimitStore {
+    InMemory(MemoryStore),
+    #[cfg(feature = "redis")]
+    Redis(redis_store::RedisStore),
+}
+
+impl Default for RateLimitStore {
+    fn default() -> Self {
+        Self::InMemory(MemoryStore::default())
+    }
+}
+
+impl RateLimitStore {
+    pub fn new(backend: &RateLimitingBackend) -> Result<Self, StoreError> {
+        match backend {
+            RateLimitingBackend::InMemory => Ok(Self::default()),
+            #[cfg(feature = "redis")]
+            RateLimitingBackend::Redis(config) => {
+                Ok(Self::Redis(redis_store::RedisStore::new(config)?))
+            }
+            #[cfg(not(feature = "redis"))]
+            RateLimitingBackend::Redis(_) => Err(StoreError::RedisUnsupported),
+        }
+    }
+
+    /// Counts one request in the current window of `key`, unless the limit is
+    /// reached. The time of the request is only used by the stores without a
+    /// clock of their own.
+    async fn acquire(
+        &self,
+        key: &RateLimitKey,
+        limit: &RateLimit,
+        now: Instant,
+    ) -> Result<Admission, StoreError> {
+        match self {
+            Self::InMemory(store) => Ok(store.acquire(key, limit, now)),
+            #[cfg(feature = "redis")]
+            Self::Redis(store) => store.acquire(key, limit).await,
+        }
+    }
+
+    /// Requests counted in the current window of `key` and time left until
+    /// the next one.
+    async fn window(
+        &self,
+        key: &RateLimitKey,
+        limit: &RateLimit,
+        now: Instant,
+    ) -> Result<Option<(u32, Duration)>, StoreError> {
+        match self {
+            Self::InMemory(store) => Ok(store.window(key, limit, now)),
+            #[cfg(feature = "redis")]
+            Self::Redis(store) => store.window(key).await,
+        }
+    }
+
+    async fn reset(&self, key: &RateLimitKey) -> Result<(), StoreError> {
+        match self {
+            Self::InMemory(store) => {
+                store.reset(key);
+                Ok(())
+            }
+            #[cfg(feature = "redis")]
+            Self::Redis(store) => store.reset(key).await,
+        }
+    }
+}
+
+struct Window {
+    start: Instant,
+    requests: u32,
+}
+
+/// Counters local to the process.
+#[derive(Default)]
+pub struct MemoryStore {
+    windows: Mutex<HashMap<RateLimitKey, Window>>,
+}
+
+impl MemoryStore {
+    fn windows(&self) -> MutexGuard<'_, HashMap<RateLimitKey, Window>> {
+        self.windows
+            .lock()
+            .expect("rate limit windows lock poisoned")
+    }
+
+    fn acquire(&self, key: &RateLimitKey, limit: &RateLimit, now: Instant) -> Admission {
+        let mut windows = self.windows();
+        let window = windows.entry(key.clone()).or_insert(Window {
+            start: now,
+            requests: 0,
+        });
+
+        let elapsed = now.saturating_duration_since(window.start);
+        if elapsed >= limit.period {
+            *window = Window {
+                start: now,
+                requests: 0,
+            };
+        } else if window.requests >= limit.requests {
+            return Admission::Rejected {
+                retry_after: limit.period - elapsed,
+            };
+        }
+        window.requests += 1;
+
+        Admission::Accepted {
+            remaining: limit.requests - window.requests,
+        }
+    }
+
+    fn window(
+        &self,
+        key: &RateLimitKey,
+        limit: &RateLimit,
+        now: Instant,
+    ) -> Option<(u32, Duration)> {
+        let windows = self.windows();
+        let window = windows.get(key)?;
+        let reset_in = limit
+            .period
+            .checked_sub(now.saturating_duration_since(window.start))?;
+
+        (!reset_in.is_zero()).then_some((window.requests, reset_in))
+    }
+
+    fn reset(&self, key: &RateLimitKey) {
+        self.windows().remove(key);
+    }
+}
+
+/// Fixed window rate limiter of the proof requests.
+#[derive(Default)]
+pub struct RateLimiter {
+    network_id: u32,
+    network: Option<RateLimit>,
+    clients: Vec<Client>,
+    store: RateLimitStore,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitingConfig, network_id: u32) -> Self {
+        Self {
+            network_id,
+            network: config.network,
+            clients: config
+                .clients
+                .iter()
+                .map(|(name, client)| Client {
+                    name: name.clone(),
+                    api_key: client.api_key.expose().to_string(),
+                    limit: client.limit,
+                })
+                .collect(),
+            store: RateLimitStore::default(),
+        }
+    }
+
+    pub fn with_store(mut self, store: RateLimitStore) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// Identifies the client from the request metadata and accounts the
+    /// request to its limit, or to the limit of the network.
+    ///
+    /// The requests are let through when the store is unavailable, the rate
+    /// limiting being a protection rather than a guarantee.
+    pub async fn check(&self, metadata: &MetadataMap) -> Result<Quota, Status> {
+        self.check_at(metadata, Instant::now()).await
+    }
+
+    /// State of the windows of the configured limits.
+    pub async fn state(&self) -> Result<Vec<RateLimitState>, StoreError> {
+        self.state_at(Instant::now()).await
+    }
+
+    /// Starts a new window for the given limit, returning whether it is
+    /// configured.
+    pub async fn reset(&self, key: &RateLimitKey) -> Result<bool, StoreError> {
+        if !self.limits().any(|(limit_key, _)| limit_key == *key) {
+            return Ok(false);
+        }
+        self.store.reset(key).await?;
+
+        Ok(true)
+    }
+
+    /// Name of the client authenticated by the API key of the request, if
+    /// any.
+    pub fn client_name(&self, metadata: &MetadataMap) -> Option<String> {
+        let api_key = metadata.get(API_KEY_METADATA)?.to_str().ok()?;
+
+        self.clients
+            .iter()
+            .find(|client| client.api_key == api_key)
+            .map(|client| client.name.clone())
+    }
+
+    fn limits(&self) -> impl Iterator<Item = (RateLimitKey, RateLimit)> + '_ {
+        let network = self
+            .network
+            .map(|limit| (RateLimitKey::Network(self.network_id), limit));
+        let clients = self.clients.iter().filter_map(|client| {
+            client
+                .limit
+                .map(|limit| (RateLimitKey::Client(client.name.clone()), limit))
+        });
+
+        network.into_iter().chain(clients)
+    }
+
+    async fn state_at(&self, now: Instant) -> Result<Vec<RateLimitState>, StoreError> {
+        let mut states = Vec::new();
+        for (key, limit) in self.limits() {
+            let (requests, reset_in) = self
+                .store
+                .window(&key, &limit, now)
+                .await?
+                .unwrap_or_default();
+
+            states.push(RateLimitState {
+                key,
+                limit,
+                remaining: limit.requests.saturating_sub(requests),
+                reset_in,
+            });
+        }
+
+        Ok(states)
+    }
+
+    /// Limit the requests of the client are accounted to, unset when
+    /// unlimited.
+    pub fn limit(&self, metadata: &MetadataMap) -> Result<Option<RateLimit>, Status> {
+        Ok(self.limit_of(metadata)?.map(|(_, limit)| limit))
+    }
+
+    fn limit_of(
+        &self,
+        metadata: &MetadataMap,
+    ) -> Result<Option<(RateLimitKey, RateLimit)>, Status> {
+        let limit = match metadata.get(API_KEY_METADATA) {
+            Some(api_key) => {
+                let client = api_key
+                    .to_str()
+                    .ok()
+                    .and_then(|api_key| {
+                        self.clients.iter().find(|client| client.api_key == api_key)
+                    })
+                    .ok_or_else(|| ErrorCode::Unauthenticated.status("Unknown API key"))?;
+
+                match client.limit {
+                    Some(limit) => Some((RateLimitKey::Client(client.name.clone()), limit)),
+                    None => self
+                        .network
+                        .map(|limit| (RateLimitKey::Network(self.network_id), limit)),
+                }
+            }
+            None => self
+                .network
+                .map(|limit| (RateLimitKey::Network(self.network_id), limit)),
+        };
+
+        Ok(limit)
+    }
+
+    async fn check_at(&self, metadata: &MetadataMap, now: Instant) -> Result<Quota, Status> {
+        let Some((key, limit)) = self.limit_of(metadata)? else {
+            return Ok(Quota(None));
+        };
+
+        match self.store.acquire(&key, &limit, now).await {
+            Ok(Admission::Accepted { remaining }) => Ok(Quota(Some(remaining))),
+            Ok(Admission::Rejected { retry_after }) => {
+                warn!(?key, ?retry_after, "Rate limit exceeded");
+                let mut status = ErrorCode::RateLimited.status_with_details(
+                    format!(
+                        "Rate limit of {} requests per {:?} exceeded",
+                        limit.requests, limit.period
+                    ),
+                    ErrorDetails::with_retry_info(Some(retry_after)),
+                );
+                status
+                    .metadata_mut()
+                    .insert(REMAINING_METADATA, 0u32.into());
+                Err(status)
+            }
+            Err(error) => {
+                error!(
+                    ?key,
+                    ?error,
+                    "Unable to count the request, letting it through"
+                );
+                Ok(Quota(None))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic_types::StatusExt as _;
+
+    use super::*;
+
+    fn limiter() -> RateLimiter {
+        let config: RateLimitingConfig = toml::from_str(
+            r#"
+            network = { requests = 2, period = "1m" }
+
+            [clients.noisy]
+            api-key = "noisy-key"
+            limit = { requests = 1, period = "1m" }
+
+            [clients.quiet]
+            api-key = "quiet-key"
+            "#,
+        )
+        .unwrap();
+
+        RateLimiter::new(&config, 1)
+    }
+
+    fn metadata(api_key: Option<&str>) -> MetadataMap {
+        let mut metadata = MetadataMap::new();
+        if let Some(api_key) = api_key {
+            metadata.insert(API_KEY_METADATA, api_key.parse().unwrap());
+        }
+        metadata
+    }
+
+    #[tokio::test]
+    async fn clients_have_their_own_limit() {
+        let limiter = limiter();
+        let now = Instant::now();
+
+        assert_eq!(
+            limiter
+                .check_at(&metadata(Some("noisy-key")), now)
+                .await
+                .unwrap(),
+            Quota(Some(0))
+        );
+        let status = limiter
+            .check_at(&metadata(Some("noisy-key")), now)
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert_eq!(ErrorCode::of(&status), Some(ErrorCode::RateLimited));
+        assert_eq!(
+            status.get_details_retry_info().unwrap().retry_delay,
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(status.metadata().get(REMAINING_METADATA).unwrap(), "0");
+
+        // The noisy client doesn't eat the quota of the network.
+        assert_eq!(
+            limiter
+                .check_at(&metadata(Some("quiet-key")), now)
+                .await
+                .unwrap(),
+            Quota(Some(1))
+        );
+        assert!(limiter.check_at(&metadata(None), now).await.is_ok());
+        assert_eq!(
+            limiter
+                .check_at(&metadata(None), now)
+                .await
+                .unwrap_err()
+                .code(),
+            tonic::Code::ResourceExhausted
+        );
+
+        let next_window = now + Duration::from_secs(60);
+        assert!(limiter
+            .check_at(&metadata(Some("noisy-key")), next_window)
+            .await
+            .is_ok());
+        assert!(limiter.check_at(&metadata(None), next_window).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn unknown_api_keys_are_rejected() {
+        let status = limiter()
+            .check_at(&metadata(Some("stolen-key")), Instant::now())
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::Unauthenticated);
+    }
+
+    #[tokio::test]
+    async fn unlimited_by_default() {
+        let config: RateLimitingConfig = toml::from_str(
+            r#"
+            [clients.client]
+            api-key = "client-key"
+            "#,
+        )
+        .unwrap();
+        let limiter = RateLimiter::new(&config, 1);
+
+        for _ in 0..100 {
+            assert!(limiter
+                .check_at(&metadata(Some("client-key")), Instant::now())
+                .await
+                .is_ok());
+            assert!(limiter
+                .check_at(&metadata(None), Instant::now())
+                .await
+                .is_ok());
+        }
+    }
+}
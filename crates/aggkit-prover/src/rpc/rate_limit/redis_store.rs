@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use aggkit_prover_config::{RateLimit, RedisBackendConfig};
+use redis::{aio::ConnectionManager, AsyncCommands, Client, Script};
+use tokio::sync::OnceCell;
+
+use super::{Admission, RateLimitKey, StoreError};
+
+/// Counts the request unless the limit is reached, starting the window with
+/// the first request. Returns whether the request is accepted, the requests
+/// counted in the window and the milliseconds left until the next one.
+const ACQUIRE: &str = r#"
+local requests = tonumber(redis.call('GET', KEYS[1]) or '0')
+if requests >= tonumber(ARGV[1]) then
+    return {0, requests, redis.call('PTTL', KEYS[1])}
+end
+requests = redis.call('INCR', KEYS[1])
+if redis.call('PTTL', KEYS[1]) < 0 then
+    redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return {1, requests, redis.call('PTTL', KEYS[1])}
+"#;
+
+/// Counters shared by the replicas of the prover, one key per window which
+/// expires with it.
+pub struct RedisStore {
+    client: Client,
+    connection: OnceCell<ConnectionManager>,
+    key_prefix: String,
+    acquire: Script,
+}
+
+impl RedisStore {
+    /// Connects lazily, on the first request counted.
+    pub fn new(config: &RedisBackendConfig) -> Result<Self, StoreError> {
+        Ok(Self {
+            client: Client::open(config.url.expose())?,
+            connection: OnceCell::new(),
+            key_prefix: config.key_prefix.clone(),
+            acquire: Script::new(ACQUIRE),
+        })
+    }
+
+    async fn connection(&self) -> Result<ConnectionManager, StoreError> {
+        let connection = self
+            .connection
+            .get_or_try_init(|| ConnectionManager::new(self.client.clone()))
+            .await?;
+
+        Ok(connection.clone())
+    }
+
+    fn key(&self, key: &RateLimitKey) -> String {
+        match key {
+            RateLimitKey::Network(network_id) => {
+                format!("{}:network:{network_id}", self.key_prefix)
+            }
+            RateLimitKey::Client(name) => format!("{}:client:{name}", self.key_prefix),
+        }
+    }
+
+    pub(super) async fn acquire(
+        &self,
+        key: &RateLimitKey,
+        limit: &RateLimit,
+    ) -> Result<Admission, StoreError> {
+        let mut connection = self.connection().await?;
+        let (accepted, requests, ttl): (bool, u32, i64) = self
+            .acquire
+            .key(self.key(key))
+            .arg(limit.requests)
+            .arg(limit.period.as_millis() as u64)
+            .invoke_async(&mut connection)
+            .await?;
+
+        Ok(if accepted {
+            Admission::Accepted {
+                remaining: limit.requests.saturating_sub(requests),
+            }
+        } else {
+            Admission::Rejected {
+                retry_after: ttl_to_duration(ttl),
+            }
+        })
+    }
+
+    pub(super) async fn window(
+        &self,
+        key: &RateLimitKey,
+    ) -> Result<Option<(u32, Duration)>, StoreError> {
+        let key = self.key(key);
+        let mut connection = self.connection().await?;
+        let (requests, ttl): (Option<u32>, i64) = redis::pipe()
+            .get(&key)
+            .pttl(&key)
+            .query_async(&mut connection)
+            .await?;
+
+        Ok(requests.map(|requests| (requests, ttl_to_duration(ttl))))
+    }
+
+    pub(super) async fn reset(&self, key: &RateLimitKey) -> Result<(), StoreError> {
+        let mut connection = self.connection().await?;
+        let () = connection.del(self.key(key)).await?;
+
+        Ok(())
+    }
+}
+
+/// Time left of a window, the negative values meaning that the key either
+/// expired in between or has no expiry.
+fn ttl_to_duration(ttl: i64) -> Duration {
+    Duration::from_millis(ttl.max(0) as u64)
+}
@@ -0,0 +1,416 @@
+//! Tenants sharing the prover, each one grouping the clients of its API keys
+//! and being isolated from the others by its own limits.
+//!
+//! A tenant is limited in the requests proven at once, and in the cycles
+//! estimated by the request cost model over the current calendar month. The
+//! requests beyond either limit are rejected as resource exhausted, with the
+//! [`ErrorCode::TenantConcurrencyExceeded`] or
+//! [`ErrorCode::TenantBudgetExhausted`] code. The cycles are charged once the request is
+//! proven, the requests accepted while some budget is left being proven in
+//! full.
+//!
+//! The requests, the requests in flight and the cycles are counted by the
+//! `tenant_requests`, `tenant_requests_in_flight` and `tenant_estimated_cycles`
+//! metrics, labeled by tenant. The clients of no tenant are not limited.
+use std::{
+    collections::{BTreeMap, HashMap},
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, LazyLock, Mutex,
+    },
+};
+
+use aggchain_proof_service::{
+    request_cost::RequestCostModel, service::AggchainProofServiceRequest,
+};
+use aggkit_prover_config::TenantConfig;
+use opentelemetry::{
+    global,
+    metrics::{Counter, UpDownCounter},
+    KeyValue,
+};
+use prover_errors::ErrorCode;
+use prover_executor::cost::Month;
+use serde::Serialize;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tonic::Status;
+use tonic_types::ErrorDetails;
+use tracing::warn;
+
+const METER_NAME: &str = "aggkit_prover";
+
+static TENANT_REQUESTS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("tenant_requests")
+        .with_description("Proof requests of the tenants, by tenant and outcome of their admission")
+        .build()
+});
+
+static TENANT_REQUESTS_IN_FLIGHT: LazyLock<UpDownCounter<i64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .i64_up_down_counter("tenant_requests_in_flight")
+        .with_description("Proof requests of the tenants being served, by tenant")
+        .build()
+});
+
+static TENANT_ESTIMATED_CYCLES: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("tenant_estimated_cycles")
+        .with_description("Estimated cycles of the proven requests of the tenants, by tenant")
+        .build()
+});
+
+/// Usage of one tenant, as reported by the admin API.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct TenantUsage {
+    pub tenant: String,
+    pub requests_in_flight: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_requests: Option<NonZeroUsize>,
+    pub month: Month,
+    /// Estimated cycles of the requests proven over the month.
+    pub cycles: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monthly_cycle_budget: Option<u64>,
+}
+
+struct Tenant {
+    name: String,
+    max_concurrent_requests: Option<(NonZeroUsize, Arc<Semaphore>)>,
+    monthly_cycle_budget: Option<u64>,
+    requests_in_flight: AtomicUsize,
+    spent: Mutex<(Month, u64)>,
+}
+
+impl Tenant {
+    /// Cycles spent over the month, none once it is over.
+    fn spent(&self, month: Month) -> u64 {
+        let spent = self.spent.lock().expect("tenant usage lock poisoned");
+        if spent.0 == month {
+            spent.1
+        } else {
+            0
+        }
+    }
+
+    fn spend(&self, month: Month, cycles: u64) {
+        let mut spent = self.spent.lock().expect("tenant usage lock poisoned");
+        if spent.0 == month {
+            spent.1 = spent.1.saturating_add(cycles);
+        } else if spent.0 < month {
+            *spent = (month, cycles);
+        }
+    }
+
+    fn labels(&self) -> [KeyValue; 1] {
+        [KeyValue::new("tenant", self.name.clone())]
+    }
+}
+
+/// Tenants of the clients, enforcing their limits.
+#[derive(Default)]
+pub struct Tenants {
+    tenants: Vec<Arc<Tenant>>,
+    tenant_of_client: HashMap<String, Arc<Tenant>>,
+    request_cost: Arc<RequestCostModel>,
+}
+
+impl Tenants {
+    pub fn new(config: &BTreeMap<String, TenantConfig>, request_cost: &RequestCostModel) -> Self {
+        let tenants = config
+            .iter()
+            .map(|(name, tenant)| {
+                Arc::new(Tenant {
+                    name: name.clone(),
+                    max_concurrent_requests: tenant
+                        .max_concurrent_requests
+                        .map(|max| (max, Arc::new(Semaphore::new(max.get())))),
+                    monthly_cycle_budget: tenant.monthly_cycle_budget,
+                    requests_in_flight: AtomicUsize::new(0),
+                    spent: Mutex::new((Month::now(), 0)),
+                })
+            })
+            .collect::<Vec<_>>();
+        let tenant_of_client = config
+            .values()
+            .zip(&tenants)
+            .flat_map(|(config, tenant)| {
+                config
+                    .clients
+                    .iter()
+                    .map(|client| (client.clone(), tenant.clone()))
+            })
+            .collect();
+
+        Self {
+            tenants,
+            tenant_of_client,
+            request_cost: Arc::new(request_cost.clone()),
+        }
+    }
+
+    /// Admits the request of the client into its tenant, if any, unless the
+    /// tenant is at one of its limits. The admission is held until the
+    /// request is served.
+    pub fn admit(&self, client: Option<&str>) -> Result<Option<TenantAdmission>, Status> {
+        self.admit_at(client, Month::now())
+    }
+
+    /// Usage of the tenants over the current month.
+    pub fn usage(&self) -> Vec<TenantUsage> {
+        let month = Month::now();
+
+        self.tenants
+            .iter()
+            .map(|tenant| TenantUsage {
+                tenant: tenant.name.clone(),
+                requests_in_flight: tenant.requests_in_flight.load(Ordering::Relaxed),
+                max_concurrent_requests: tenant
+                    .max_concurrent_requests
+                    .as_ref()
+                    .map(|(max, _)| *max),
+                month,
+                cycles: tenant.spent(month),
+                monthly_cycle_budget: tenant.monthly_cycle_budget,
+            })
+            .collect()
+    }
+
+    fn admit_at(
+        &self,
+        client: Option<&str>,
+        month: Month,
+    ) -> Result<Option<TenantAdmission>, Status> {
+        let Some(tenant) = client.and_then(|client| self.tenant_of_client.get(client)) else {
+            return Ok(None);
+        };
+
+        if let Some(budget) = tenant.monthly_cycle_budget {
+            let spent = tenant.spent(month);
+            if spent >= budget {
+                warn!(tenant = %tenant.name, spent, budget, "Tenant cycle budget exhausted");
+                return Err(rejection(
+                    tenant,
+                    ErrorCode::TenantBudgetExhausted,
+                    format!(
+                        "The tenant {} has spent its budget of {budget} cycles for {month}",
+                        tenant.name
+                    ),
+                ));
+            }
+        }
+        let slot = match &tenant.max_concurrent_requests {
+            Some((max, semaphore)) => match semaphore.clone().try_acquire_owned() {
+                Ok(slot) => Some(slot),
+                Err(_) => {
+                    warn!(tenant = %tenant.name, %max, "Tenant concurrency limit reached");
+                    return Err(rejection(
+                        tenant,
+                        ErrorCode::TenantConcurrencyExceeded,
+                        format!(
+                            "The tenant {} already has {max} requests being proven",
+                            tenant.name
+                        ),
+                    ));
+                }
+            },
+            None => None,
+        };
+
+        TENANT_REQUESTS.add(
+            1,
+            &[
+                KeyValue::new("tenant", tenant.name.clone()),
+                KeyValue::new("outcome", "accepted"),
+            ],
+        );
+        tenant.requests_in_flight.fetch_add(1, Ordering::Relaxed);
+        TENANT_REQUESTS_IN_FLIGHT.add(1, &tenant.labels());
+
+        Ok(Some(TenantAdmission {
+            tenant: tenant.clone(),
+            request_cost: self.request_cost.clone(),
+            month,
+            cycles: 0,
+            _slot: slot,
+        }))
+    }
+}
+
+fn rejection(tenant: &Tenant, code: ErrorCode, message: String) -> Status {
+    TENANT_REQUESTS.add(
+        1,
+        &[
+            KeyValue::new("tenant", tenant.name.clone()),
+            KeyValue::new("outcome", code.reason().to_lowercase()),
+        ],
+    );
+
+    code.status_with_metadata(
+        message,
+        ErrorDetails::new(),
+        HashMap::from([("tenant".to_string(), tenant.name.clone())]),
+    )
+}
+
+/// Request admitted into its tenant, counted as in flight until dropped.
+pub struct TenantAdmission {
+    tenant: Arc<Tenant>,
+    request_cost: Arc<RequestCostModel>,
+    month: Month,
+    cycles: u64,
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+impl TenantAdmission {
+    /// Estimates the cycles of the request, charged to the tenant once
+    /// proven.
+    pub fn estimate(&mut self, request: &AggchainProofServiceRequest) {
+        let inputs = match request {
+            AggchainProofServiceRequest::Normal(inputs) => inputs,
+            AggchainProofServiceRequest::Optimistic(inputs) => &inputs.aggchain_proof_inputs,
+        };
+        self.cycles = self.request_cost.estimate(inputs);
+    }
+
+    /// Charges the estimated cycles to the tenant when the request is proven.
+    pub fn settle<T>(self, result: &Result<T, Status>) {
+        if result.is_ok() {
+            self.tenant.spend(self.month, self.cycles);
+            TENANT_ESTIMATED_CYCLES.add(self.cycles, &self.tenant.labels());
+        }
+    }
+}
+
+impl Drop for TenantAdmission {
+    fn drop(&mut self) {
+        self.tenant
+            .requests_in_flight
+            .fetch_sub(1, Ordering::Relaxed);
+        TENANT_REQUESTS_IN_FLIGHT.add(-1, &self.tenant.labels());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aggchain_proof_types::AggchainProofInputs;
+    use agglayer_interop::types::{Digest, L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+    use tonic_types::StatusExt as _;
+
+    use super::*;
+
+    fn tenants() -> Tenants {
+        let config: BTreeMap<String, TenantConfig> = toml::from_str(
+            r#"
+            [acme]
+            clients = ["aggsender-a", "aggsender-b"]
+            max-concurrent-requests = 1
+            monthly-cycle-budget = 1_000
+
+            [globex]
+            clients = ["aggsender-c"]
+            "#,
+        )
+        .unwrap();
+
+        Tenants::new(
+            &config,
+            &RequestCostModel {
+                base_cycles: 600,
+                cycles_per_block: 0,
+                cycles_per_inserted_ger: 0,
+                cycles_per_imported_bridge_exit: 0,
+                max_cycles: None,
+            },
+        )
+    }
+
+    fn reason(status: &Status) -> String {
+        status.get_details_error_info().unwrap().reason
+    }
+
+    const OCTOBER: Month = Month {
+        year: 2026,
+        month: 10,
+    };
+    const NOVEMBER: Month = Month {
+        year: 2026,
+        month: 11,
+    };
+
+    #[test]
+    fn clients_of_a_tenant_share_its_concurrency() {
+        let tenants = tenants();
+
+        let admission = tenants.admit_at(Some("aggsender-a"), OCTOBER).unwrap();
+        assert!(admission.is_some());
+        let status = tenants
+            .admit_at(Some("aggsender-b"), OCTOBER)
+            .err()
+            .unwrap();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert_eq!(
+            reason(&status),
+            ErrorCode::TenantConcurrencyExceeded.reason()
+        );
+
+        // The other tenants and the clients of none are not limited.
+        assert!(tenants.admit_at(Some("aggsender-c"), OCTOBER).is_ok());
+        assert!(tenants.admit_at(Some("aggsender-c"), OCTOBER).is_ok());
+        assert!(tenants.admit_at(Some("other"), OCTOBER).unwrap().is_none());
+        assert!(tenants.admit_at(None, OCTOBER).unwrap().is_none());
+
+        drop(admission);
+        assert!(tenants.admit_at(Some("aggsender-b"), OCTOBER).is_ok());
+    }
+
+    #[test]
+    fn proven_requests_are_charged_to_the_monthly_budget() {
+        let tenants = tenants();
+        let request = AggchainProofServiceRequest::Normal(AggchainProofInputs {
+            last_proven_block: 0,
+            requested_end_block: 10,
+            l1_info_tree_root_hash: Default::default(),
+            l1_info_tree_leaf: L1InfoTreeLeaf {
+                l1_info_tree_index: 1,
+                rer: Default::default(),
+                mer: Default::default(),
+                inner: L1InfoTreeLeafInner {
+                    global_exit_root: Default::default(),
+                    block_hash: Default::default(),
+                    timestamp: 0,
+                },
+            },
+            l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
+            ger_leaves: Default::default(),
+            imported_bridge_exits: Default::default(),
+            requested_vkey_hash: None,
+        });
+        let serve = |result: Result<(), Status>| {
+            let mut admission = tenants
+                .admit_at(Some("aggsender-a"), OCTOBER)
+                .unwrap()
+                .unwrap();
+            admission.estimate(&request);
+            admission.settle(&result);
+        };
+
+        // The failed requests are not charged.
+        serve(Err(Status::internal("failed")));
+        serve(Ok(()));
+        assert_eq!(tenants.tenants[0].spent(OCTOBER), 600);
+
+        // Some budget is left, the request is proven in full.
+        serve(Ok(()));
+        let status = tenants
+            .admit_at(Some("aggsender-a"), OCTOBER)
+            .err()
+            .unwrap();
+        assert_eq!(reason(&status), ErrorCode::TenantBudgetExhausted.reason());
+
+        // The budget is renewed every month.
+        assert!(tenants.admit_at(Some("aggsender-a"), NOVEMBER).is_ok());
+    }
+}
@@ -0,0 +1,957 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aggchain_proof_builder::programs::ProgramRegistry;
+use aggchain_proof_service::service::{AggchainProofServiceRequest, AggchainProofServiceResponse};
+use aggchain_proof_types::{AggchainProofInputs, OptimisticAggchainProofInputs};
+use aggkit_prover_types::{
+    conversion::v1::context::Contextualize as _,
+    error::AggchainProofRequestError,
+    stage_timings::{Stage, StageTimings},
+    v2::{
+        aggchain_proof_service_server::AggchainProofService as AggchainProofGrpcService,
+        AggchainProofError, AggchainProofErrorKind, AggchainProofMode, AggchainProofStage,
+        AggchainProofStatus, GenerateAggchainProofRequest, GenerateAggchainProofResponse,
+        GenerateOptimisticAggchainProofRequest, GetProverInfoRequest, GetProverInfoResponse,
+        RateLimitPolicy, ServedProgram, Sp1ProofMode, WatchAggchainProofStatusRequest,
+    },
+    validation::ValidationLimits,
+};
+use agglayer_interop::{
+    grpc::v1::{AggchainProof, Sp1StarkProof},
+    types::{bincode, Digest},
+};
+use futures::Stream;
+use prost::bytes::Bytes;
+use prover_errors::ErrorCode;
+use sp1_sdk::SP1_CIRCUIT_VERSION;
+use tokio::sync::watch;
+use tonic::{Request, Response, Status};
+use tonic_types::{ErrorDetails, StatusExt as _};
+use tower::{Service, ServiceExt};
+use tracing::{error, info, info_span, instrument, Instrument as _};
+
+use super::{
+    compatibility::{ClientCompatibility, API_VERSIONS, CLIENT_VERSION_METADATA, PROVER_VERSION},
+    mirror::Mirror,
+    network_switch::NetworkSwitch,
+    proof_envelope,
+    rate_limit::RateLimiter,
+    service_error_status, start_audit,
+    tenancy::Tenants,
+    validate_request, with_stage_timings, BufferedProofService,
+};
+use crate::{
+    audit::{AuditLog, AuditTrail},
+    leader::Leadership,
+};
+
+/// Maximum number of requests whose status is kept for the watchers, beyond
+/// which the oldest finished ones are forgotten.
+const MAX_TRACKED_REQUESTS: usize = 1024;
+
+/// Weight of the last interval between two proving starts in their moving
+/// average.
+const START_INTERVAL_WEIGHT: f64 = 0.25;
+
+pub type AggchainProofStatusStream =
+    Pin<Box<dyn Stream<Item = Result<AggchainProofStatus, Status>> + Send + 'static>>;
+
+/// Implementation of the `aggkit.prover.v2` gRPC API, sharing the aggchain
+/// proof service with the v1 API.
+#[derive(Clone)]
+pub struct GrpcServiceV2 {
+    service: BufferedProofService,
+    validation_limits: ValidationLimits,
+    rate_limiter: Arc<RateLimiter>,
+    audit_log: Option<Arc<AuditLog>>,
+    leadership: Leadership,
+    statuses: StatusRegistry,
+    compatibility: Arc<ClientCompatibility>,
+    network_switch: Arc<NetworkSwitch>,
+    tenants: Arc<Tenants>,
+    mirror: Option<Arc<Mirror>>,
+    programs: Option<Arc<ProgramRegistry>>,
+    optimistic_mode: bool,
+}
+
+impl GrpcServiceV2 {
+    pub(crate) fn new(
+        service: BufferedProofService,
+        validation_limits: ValidationLimits,
+        rate_limiter: Arc<RateLimiter>,
+        audit_log: Option<Arc<AuditLog>>,
+        leadership: Leadership,
+    ) -> Self {
+        Self {
+            service,
+            validation_limits,
+            rate_limiter,
+            audit_log,
+            leadership,
+            statuses: StatusRegistry::default(),
+            compatibility: Default::default(),
+            network_switch: Default::default(),
+            tenants: Default::default(),
+            mirror: None,
+            programs: None,
+            optimistic_mode: true,
+        }
+    }
+
+    /// Rejects the requests of the disabled networks.
+    pub(crate) fn with_network_switch(mut self, network_switch: Arc<NetworkSwitch>) -> Self {
+        self.network_switch = network_switch;
+
+        self
+    }
+
+    /// Limits the proof requests of the tenants.
+    pub(crate) fn with_tenants(mut self, tenants: Arc<Tenants>) -> Self {
+        self.tenants = tenants;
+
+        self
+    }
+
+    /// Mirrors a share of the proof requests to a secondary prover.
+    pub(crate) fn with_mirror(mut self, mirror: Option<Arc<Mirror>>) -> Self {
+        self.mirror = mirror;
+
+        self
+    }
+
+    /// Sets what `GetProverInfo` reports beyond the version of the prover,
+    /// and the client versions served.
+    pub(crate) fn with_prover_info(
+        mut self,
+        compatibility: Arc<ClientCompatibility>,
+        programs: Option<Arc<ProgramRegistry>>,
+        optimistic_mode: bool,
+    ) -> Self {
+        self.compatibility = compatibility;
+        self.programs = programs;
+        self.optimistic_mode = optimistic_mode;
+
+        self
+    }
+
+    async fn generate(
+        &self,
+        method: &str,
+        request_id: &str,
+        proof_request: AggchainProofServiceRequest,
+        audit: &mut Option<AuditTrail>,
+    ) -> Result<AggchainProofServiceResponse, (Status, AggchainProofError)> {
+        let mut service = self.service.clone();
+
+        let waiting = Waiting {
+            statuses: &self.statuses,
+            request_id,
+        };
+        let service = service.ready().await.map_err(|error| {
+            error!(%request_id, "Unable to use the aggchain proof service: {error:?}");
+            let error = AggchainProofError {
+                kind: AggchainProofErrorKind::Internal as i32,
+                message: "Unable to use the aggchain proof service".to_string(),
+                field_path: String::new(),
+            };
+            (ErrorCode::Internal.status(&error.message), error)
+        })?;
+
+        self.statuses
+            .update(request_id, AggchainProofStage::Proving, None);
+        drop(waiting);
+        if let Some(audit) = audit {
+            audit.proving_started();
+        }
+
+        service.call(proof_request).await.map_err(|error| {
+            let status = match error.downcast_ref::<aggchain_proof_service::Error>() {
+                Some(service_error) => service_error_status(method, "inserted_gers", service_error),
+                None => {
+                    error!(%request_id, ?error, "Unable to execute {method} request");
+                    ErrorCode::ProvingFailed.status(error.to_string())
+                }
+            };
+            let error = status_error(&status);
+            (status, error)
+        })
+    }
+
+    /// Runs one proof request, keeping its status up to date.
+    async fn handle(
+        &self,
+        method: &str,
+        request_id: String,
+        proof_request: Result<AggchainProofServiceRequest, AggchainProofRequestError>,
+        mut audit: Option<AuditTrail>,
+        mut stage_timings: StageTimings,
+    ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        let result = match proof_request {
+            Ok(proof_request) => {
+                let mut context = match &proof_request {
+                    AggchainProofServiceRequest::Normal(inputs) => inputs.context(),
+                    AggchainProofServiceRequest::Optimistic(inputs) => inputs.context(),
+                };
+
+                self.generate(method, &request_id, proof_request, &mut audit)
+                    .await
+                    .map(|response| {
+                        stage_timings.merge(&response.stage_timings);
+                        build_response(request_id.clone(), response, &mut context)
+                    })
+            }
+            Err(error) => {
+                let error = AggchainProofError {
+                    kind: AggchainProofErrorKind::InvalidArgument as i32,
+                    message: error.to_string(),
+                    field_path: error.field_path().to_string(),
+                };
+                Err((to_status(ErrorCode::InvalidArgument, &error), error))
+            }
+        };
+
+        let result = match result {
+            Ok(response) => {
+                info!(%request_id,
+                    last_proven_block = %response.last_proven_block,
+                    end_block = %response.end_block,
+                    ?stage_timings,
+                    "Aggchain proof request executed successfully");
+                self.statuses
+                    .update(&request_id, AggchainProofStage::Completed, None);
+
+                Ok(with_stage_timings(Response::new(response), &stage_timings))
+            }
+            Err((status, error)) => {
+                error!(%request_id, ?error, "Unable to execute aggchain proof request");
+                self.statuses
+                    .update(&request_id, AggchainProofStage::Failed, Some(error));
+
+                Err(status)
+            }
+        };
+        if let Some(audit) = audit {
+            audit.record(&result);
+        }
+
+        result
+    }
+}
+
+#[tonic::async_trait]
+impl AggchainProofGrpcService for GrpcServiceV2 {
+    type WatchAggchainProofStatusStream = AggchainProofStatusStream;
+
+    #[instrument(skip(self, request))]
+    async fn generate_aggchain_proof(
+        &self,
+        request: Request<GenerateAggchainProofRequest>,
+    ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        let started = Instant::now();
+        let (quota, mut tenancy) = async {
+            self.leadership.check()?;
+            self.network_switch.check()?;
+            self.compatibility.check(request.metadata())?;
+            let quota = self.rate_limiter.check(request.metadata()).await?;
+            let client = self.rate_limiter.client_name(request.metadata());
+
+            Ok::<_, Status>((quota, self.tenants.admit(client.as_deref())?))
+        }
+        .instrument(info_span!("intake"))
+        .await?;
+        let mut stage_timings = StageTimings::default();
+        stage_timings.record(Stage::Intake, started.elapsed());
+        let audit = start_audit(
+            self.audit_log.as_ref(),
+            &self.rate_limiter,
+            "GenerateAggchainProof",
+            &request,
+        );
+        let request = request.into_inner();
+        let shadow = self
+            .mirror
+            .as_ref()
+            .and_then(|mirror| mirror.sample(&request));
+        let request_id = request.request_id.clone();
+        let audit = audit.map(|audit| audit.with_request_id(&request_id));
+
+        info!(%request_id,
+            last_proven_block = %request.last_proven_block,
+            requested_end_block = %request.requested_end_block,
+            "Received GenerateAggchainProof request");
+
+        let started = Instant::now();
+        let proof_request = info_span!("validation").in_scope(|| {
+            validate_request(
+                &request,
+                &self.validation_limits,
+                "Invalid GenerateAggchainProof request argument(s)",
+            )?;
+            self.statuses.register(&request_id)?;
+
+            Ok::<_, Status>(
+                AggchainProofInputs::try_from(request).map(AggchainProofServiceRequest::Normal),
+            )
+        })?;
+        stage_timings.record(Stage::Validation, started.elapsed());
+        if let (Some(tenancy), Ok(proof_request)) = (&mut tenancy, &proof_request) {
+            tenancy.estimate(proof_request);
+        }
+
+        let result = self
+            .handle(
+                "GenerateAggchainProof",
+                request_id,
+                proof_request,
+                audit,
+                stage_timings,
+            )
+            .await;
+        if let Some(shadow) = shadow {
+            shadow.compare(&result);
+        }
+        if let Some(tenancy) = tenancy {
+            tenancy.settle(&result);
+        }
+
+        quota.annotate(result)
+    }
+
+    #[instrument(skip(self, request))]
+    async fn generate_optimistic_aggchain_proof(
+        &self,
+        request: Request<GenerateOptimisticAggchainProofRequest>,
+    ) -> Result<Response<GenerateAggchainProofResponse>, Status> {
+        let started = Instant::now();
+        let (quota, mut tenancy) = async {
+            self.leadership.check()?;
+            self.network_switch.check()?;
+            self.compatibility.check(request.metadata())?;
+            let quota = self.rate_limiter.check(request.metadata()).await?;
+            let client = self.rate_limiter.client_name(request.metadata());
+
+            Ok::<_, Status>((quota, self.tenants.admit(client.as_deref())?))
+        }
+        .instrument(info_span!("intake"))
+        .await?;
+        let mut stage_timings = StageTimings::default();
+        stage_timings.record(Stage::Intake, started.elapsed());
+        let audit = start_audit(
+            self.audit_log.as_ref(),
+            &self.rate_limiter,
+            "GenerateOptimisticAggchainProof",
+            &request,
+        );
+        let request = request.into_inner();
+        let shadow = self
+            .mirror
+            .as_ref()
+            .and_then(|mirror| mirror.sample(&request));
+        let request_id = request
+            .aggchain_proof_request
+            .as_ref()
+            .map(|request| request.request_id.clone())
+            .unwrap_or_default();
+        let audit = audit.map(|audit| audit.with_request_id(&request_id));
+
+        info!(%request_id, "Received GenerateOptimisticAggchainProof request");
+
+        let started = Instant::now();
+        let proof_request = info_span!("validation").in_scope(|| {
+            validate_request(
+                &request,
+                &self.validation_limits,
+                "Invalid GenerateOptimisticAggchainProof request argument(s)",
+            )?;
+            self.statuses.register(&request_id)?;
+
+            Ok::<_, Status>(
+                OptimisticAggchainProofInputs::try_from(request)
+                    .map(AggchainProofServiceRequest::Optimistic),
+            )
+        })?;
+        stage_timings.record(Stage::Validation, started.elapsed());
+        if let (Some(tenancy), Ok(proof_request)) = (&mut tenancy, &proof_request) {
+            tenancy.estimate(proof_request);
+        }
+
+        let result = self
+            .handle(
+                "GenerateOptimisticAggchainProof",
+                request_id,
+                proof_request,
+                audit,
+                stage_timings,
+            )
+            .await;
+        if let Some(shadow) = shadow {
+            shadow.compare(&result);
+        }
+        if let Some(tenancy) = tenancy {
+            tenancy.settle(&result);
+        }
+
+        quota.annotate(result)
+    }
+
+    #[instrument(skip(self, request))]
+    async fn watch_aggchain_proof_status(
+        &self,
+        request: Request<WatchAggchainProofStatusRequest>,
+    ) -> Result<Response<Self::WatchAggchainProofStatusStream>, Status> {
+        let request_id = request.into_inner().request_id;
+        let receiver = self.statuses.subscribe(&request_id).ok_or_else(|| {
+            ErrorCode::UnknownRequest.status(format!("Unknown aggchain proof request {request_id}"))
+        })?;
+
+        // Yields the current status, then every update until a terminal stage.
+        let stream = futures::stream::unfold(Some((receiver, true)), |state| async move {
+            let (mut receiver, first) = state?;
+            if !first {
+                receiver.changed().await.ok()?;
+            }
+
+            let status = receiver.borrow_and_update().clone();
+            let next = (!is_terminal(&status)).then_some((receiver, false));
+
+            Some((Ok(status), next))
+        });
+
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn get_prover_info(
+        &self,
+        request: Request<GetProverInfoRequest>,
+    ) -> Result<Response<GetProverInfoResponse>, Status> {
+        let rate_limit =
+            self.rate_limiter
+                .limit(request.metadata())?
+                .map(|limit| RateLimitPolicy {
+                    requests: limit.requests,
+                    period_ms: u64::try_from(limit.period.as_millis()).unwrap_or(u64::MAX),
+                });
+        let client_version = match request.get_ref().client_version.as_str() {
+            "" => request
+                .metadata()
+                .get(CLIENT_VERSION_METADATA)
+                .and_then(|version| version.to_str().ok())
+                .unwrap_or_default(),
+            version => version,
+        };
+        // The clients which don't report their version are served.
+        let client_supported =
+            client_version.is_empty() || self.compatibility.supports(client_version)?;
+
+        let mut proof_modes = vec![AggchainProofMode::Normal as i32];
+        if self.optimistic_mode {
+            proof_modes.push(AggchainProofMode::Optimistic as i32);
+        }
+        let programs = self
+            .programs
+            .as_ref()
+            .map(|programs| programs.states())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|program| ServedProgram {
+                vkey_hash: Some(Digest(program.vkey_hash.into()).into()),
+                current: program.current,
+            })
+            .collect();
+
+        Ok(Response::new(GetProverInfoResponse {
+            version: PROVER_VERSION.to_string(),
+            api_versions: API_VERSIONS.iter().map(ToString::to_string).collect(),
+            proof_modes,
+            sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+            programs,
+            rate_limit,
+            min_client_version: self
+                .compatibility
+                .min_client_version()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+            client_supported,
+        }))
+    }
+}
+
+/// Statuses of the recent aggchain proof requests, by request id.
+#[derive(Clone, Default)]
+struct StatusRegistry {
+    inner: Arc<Mutex<StatusRegistryInner>>,
+}
+
+#[derive(Default)]
+struct StatusRegistryInner {
+    statuses: HashMap<String, watch::Sender<AggchainProofStatus>>,
+    /// Request ids, oldest first.
+    order: VecDeque<String>,
+    /// Requests waiting to be proven, with the time they were received, oldest
+    /// first.
+    queue: VecDeque<(String, Instant)>,
+    /// Last time a request started to be proven.
+    last_start: Option<Instant>,
+    /// Moving average of the time between two proving starts while requests
+    /// were waiting, from which the start of the queued requests is estimated.
+    start_interval: Option<Duration>,
+}
+
+impl StatusRegistryInner {
+    /// Forgets the oldest finished requests beyond [`MAX_TRACKED_REQUESTS`].
+    /// The requests in flight are kept whatever their number, their watchers
+    /// still awaiting their outcome.
+    fn evict_finished(&mut self) {
+        let mut excess = self.order.len().saturating_sub(MAX_TRACKED_REQUESTS);
+        let Self {
+            statuses, order, ..
+        } = self;
+
+        order.retain(|request_id| {
+            if excess == 0 {
+                return true;
+            }
+            let finished = statuses
+                .get(request_id)
+                .is_none_or(|sender| is_terminal(&sender.borrow()));
+            if finished {
+                statuses.remove(request_id);
+                excess -= 1;
+            }
+
+            !finished
+        });
+    }
+
+    fn leave_queue(&mut self, request_id: &str) -> Option<Instant> {
+        let index = self.queue.iter().position(|(id, _)| id == request_id)?;
+        let (_, received) = self.queue.remove(index)?;
+
+        Some(received)
+    }
+
+    fn record_start(&mut self, received: Instant) {
+        let now = Instant::now();
+        // Received before the previous start, the request waited for it: the
+        // interval is the pace of the prover rather than the one of the
+        // requests.
+        if let Some(last_start) = self.last_start.filter(|last_start| received <= *last_start) {
+            let interval = now - last_start;
+            self.start_interval = Some(match self.start_interval {
+                Some(average) => {
+                    average.mul_f64(1.0 - START_INTERVAL_WEIGHT)
+                        + interval.mul_f64(START_INTERVAL_WEIGHT)
+                }
+                None => interval,
+            });
+        }
+        self.last_start = Some(now);
+    }
+
+    /// Updates the queue position and the estimated start of the waiting
+    /// requests, the watchers being notified of the changes only.
+    fn refresh_queue(&self) {
+        let estimate = self.last_start.zip(self.start_interval);
+
+        for (queue_position, (request_id, _)) in (1..).zip(&self.queue) {
+            let estimated_start_ms = estimate.map_or(0, |(last_start, start_interval)| {
+                unix_millis(last_start + start_interval * queue_position)
+            });
+            if let Some(sender) = self.statuses.get(request_id) {
+                sender.send_if_modified(|status| {
+                    let modified = status.queue_position != queue_position
+                        || status.estimated_start_ms != estimated_start_ms;
+                    status.queue_position = queue_position;
+                    status.estimated_start_ms = estimated_start_ms;
+
+                    modified
+                });
+            }
+        }
+    }
+}
+
+impl StatusRegistry {
+    /// Tracks a new request, rejecting the ids already in use by requests
+    /// which are still in flight.
+    fn register(&self, request_id: &str) -> Result<(), Status> {
+        if request_id.is_empty() {
+            let mut error = ErrorDetails::new();
+            error.add_bad_request_violation("request_id", "request_id must not be empty");
+
+            return Err(ErrorCode::InvalidArgument
+                .status_with_details("Invalid aggchain proof request argument(s)", error));
+        }
+
+        let mut inner = self.inner.lock().expect("status registry lock poisoned");
+
+        if let Some(sender) = inner.statuses.get(request_id) {
+            if !is_terminal(&sender.borrow()) {
+                return Err(ErrorCode::DuplicateRequest.status(format!(
+                    "Aggchain proof request {request_id} is already in flight"
+                )));
+            }
+        } else {
+            inner.order.push_back(request_id.to_string());
+        }
+
+        let (sender, _) = watch::channel(AggchainProofStatus {
+            request_id: request_id.to_string(),
+            stage: AggchainProofStage::Received as i32,
+            ..Default::default()
+        });
+        inner.statuses.insert(request_id.to_string(), sender);
+        inner
+            .queue
+            .push_back((request_id.to_string(), Instant::now()));
+
+        inner.evict_finished();
+        inner.refresh_queue();
+
+        Ok(())
+    }
+
+    fn update(
+        &self,
+        request_id: &str,
+        stage: AggchainProofStage,
+        error: Option<AggchainProofError>,
+    ) {
+        let mut inner = self.inner.lock().expect("status registry lock poisoned");
+
+        if let Some(received) = inner.leave_queue(request_id) {
+            if stage == AggchainProofStage::Proving {
+                inner.record_start(received);
+            }
+            inner.refresh_queue();
+        }
+        if let Some(sender) = inner.statuses.get(request_id) {
+            sender.send_modify(|status| {
+                status.stage = stage as i32;
+                status.error = error;
+                status.queue_position = 0;
+                status.estimated_start_ms = 0;
+            });
+        }
+    }
+
+    /// Removes a request from the queue, e.g. once its client is gone.
+    fn leave_queue(&self, request_id: &str) {
+        let mut inner = self.inner.lock().expect("status registry lock poisoned");
+
+        if inner.leave_queue(request_id).is_some() {
+            inner.refresh_queue();
+            if let Some(sender) = inner.statuses.get(request_id) {
+                sender.send_modify(|status| {
+                    status.queue_position = 0;
+                    status.estimated_start_ms = 0;
+                });
+            }
+        }
+    }
+
+    fn subscribe(&self, request_id: &str) -> Option<watch::Receiver<AggchainProofStatus>> {
+        let inner = self.inner.lock().expect("status registry lock poisoned");
+
+        inner.statuses.get(request_id).map(watch::Sender::subscribe)
+    }
+}
+
+/// Request waiting for the aggchain proof service, leaving the queue when
+/// dropped, should the request be cancelled meanwhile.
+struct Waiting<'a> {
+    statuses: &'a StatusRegistry,
+    request_id: &'a str,
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.statuses.leave_queue(self.request_id);
+    }
+}
+
+/// Milliseconds since the Unix epoch of an instant.
+fn unix_millis(instant: Instant) -> u64 {
+    let (now, system_now) = (Instant::now(), SystemTime::now());
+    let time = match instant.checked_duration_since(now) {
+        Some(ahead) => system_now + ahead,
+        None => system_now - now.duration_since(instant),
+    };
+
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64)
+}
+
+fn is_terminal(status: &AggchainProofStatus) -> bool {
+    matches!(
+        AggchainProofStage::try_from(status.stage),
+        Ok(AggchainProofStage::Completed | AggchainProofStage::Failed)
+    )
+}
+
+/// Status of the failed request, reported with the same code as on the v1
+/// API.
+fn to_status(code: ErrorCode, error: &AggchainProofError) -> Status {
+    match AggchainProofErrorKind::try_from(error.kind) {
+        Ok(AggchainProofErrorKind::InvalidArgument) => {
+            let mut error_details = ErrorDetails::new();
+            error_details.add_bad_request_violation(&error.field_path, &error.message);
+
+            code.status_with_details("Invalid aggchain proof request data", error_details)
+        }
+        _ => code.status(&error.message),
+    }
+}
+
+/// Error reported in the status stream of a failed request, of the kind of
+/// the code of its status and pointing at the field rejected in its details.
+fn status_error(status: &Status) -> AggchainProofError {
+    let kind = match ErrorCode::of(status) {
+        Some(
+            ErrorCode::RequestRejected
+            | ErrorCode::CycleBudgetExhausted
+            | ErrorCode::RequestTooCostly
+            | ErrorCode::UnknownProgram,
+        ) => AggchainProofErrorKind::Rejected,
+        Some(ErrorCode::InvalidGer | ErrorCode::L1InfoTreeMismatch) => {
+            AggchainProofErrorKind::InvalidArgument
+        }
+        Some(ErrorCode::StaleRequest) => AggchainProofErrorKind::Stale,
+        _ => AggchainProofErrorKind::ProvingFailed,
+    };
+    let field_path = status
+        .get_error_details()
+        .bad_request()
+        .and_then(|bad_request| bad_request.field_violations.first())
+        .map(|violation| violation.field.clone())
+        .unwrap_or_default();
+
+    AggchainProofError {
+        kind: kind as i32,
+        message: status.message().to_string(),
+        field_path,
+    }
+}
+
+fn build_response(
+    request_id: String,
+    response: AggchainProofServiceResponse,
+    context: &mut HashMap<String, Bytes>,
+) -> GenerateAggchainProofResponse {
+    context.insert(
+        "public_values".to_owned(),
+        Bytes::from(
+            bincode::sp1v4()
+                .serialize(&response.public_values)
+                .unwrap_or_else(|_| b"bincode serialization failed".to_vec()),
+        ),
+    );
+    context.insert(
+        "local_exit_root_hash".to_owned(),
+        Bytes::from(response.local_exit_root_hash.as_bytes().to_vec()),
+    );
+    context.insert(
+        "end_block".to_owned(),
+        Bytes::from(response.end_block.to_be_bytes().to_vec()),
+    );
+
+    let proof_envelope = proof_envelope(&response);
+
+    GenerateAggchainProofResponse {
+        request_id,
+        aggchain_proof: Some(AggchainProof {
+            aggchain_params: Some(response.aggchain_params.into()),
+            // Signature is handled by the initiator
+            signature: None,
+            context: std::mem::take(context),
+            proof: Some(agglayer_interop::grpc::v1::aggchain_proof::Proof::Sp1Stark(
+                Sp1StarkProof {
+                    version: SP1_CIRCUIT_VERSION.to_string(),
+                    proof: response.proof.into(),
+                    vkey: response.vkey.into(),
+                },
+            )),
+        }),
+        last_proven_block: response.last_proven_block,
+        end_block: response.end_block,
+        local_exit_root_hash: Some(response.local_exit_root_hash.into()),
+        custom_chain_data: response.custom_chain_data.into(),
+        proof_envelope,
+        sp1_proof_mode: Sp1ProofMode::from(response.proof_mode) as i32,
+        requested_end_block: response.requested_end_block,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn status_updates() {
+        let statuses = StatusRegistry::default();
+        statuses.register("request").unwrap();
+
+        assert_eq!(
+            statuses.register("request").unwrap_err().code(),
+            tonic::Code::AlreadyExists
+        );
+
+        let mut receiver = statuses.subscribe("request").unwrap();
+        assert_eq!(
+            receiver.borrow_and_update().stage,
+            AggchainProofStage::Received as i32
+        );
+
+        statuses.update("request", AggchainProofStage::Proving, None);
+        receiver.changed().await.unwrap();
+        statuses.update("request", AggchainProofStage::Completed, None);
+        assert!(is_terminal(&receiver.borrow()));
+
+        // Finished requests can be retried under the same id.
+        statuses.register("request").unwrap();
+        assert!(statuses.subscribe("unknown").is_none());
+    }
+
+    #[test]
+    fn service_errors_are_reported_with_their_code() {
+        let rejected = aggchain_proof_service::Error::OptimisticModeRejected(
+            aggchain_proof_service::optimistic_mode::OptimisticModeRejection::Disabled,
+        );
+        let status = service_error_status(
+            "GenerateOptimisticAggchainProof",
+            "inserted_gers",
+            &rejected,
+        );
+        assert_eq!(ErrorCode::of(&status), Some(ErrorCode::RequestRejected));
+
+        let error = status_error(&status);
+        assert_eq!(error.kind, AggchainProofErrorKind::Rejected as i32);
+        assert_eq!(error.message, "Optimistic mode is disabled");
+        assert!(error.field_path.is_empty());
+    }
+
+    #[test]
+    fn queue_positions() {
+        let statuses = StatusRegistry::default();
+        for request_id in ["first", "second", "third"] {
+            statuses.register(request_id).unwrap();
+        }
+        let status = |request_id| {
+            let status = statuses.subscribe(request_id).unwrap().borrow().clone();
+            (status.queue_position, status.estimated_start_ms)
+        };
+        assert_eq!(status("first"), (1, 0));
+        assert_eq!(status("third"), (3, 0));
+
+        // Nothing to estimate the start from before the prover was busy.
+        statuses.update("first", AggchainProofStage::Proving, None);
+        assert_eq!(status("first"), (0, 0));
+        assert_eq!(status("second"), (1, 0));
+
+        // The second request waited for the start of the first one.
+        statuses.update("second", AggchainProofStage::Proving, None);
+        let (queue_position, estimated_start_ms) = status("third");
+        assert_eq!(queue_position, 1);
+        assert!(estimated_start_ms >= unix_millis(Instant::now()) - 1_000);
+
+        // The cancelled requests leave the queue.
+        statuses.register("fourth").unwrap();
+        assert_eq!(status("fourth").0, 2);
+        drop(Waiting {
+            statuses: &statuses,
+            request_id: "third",
+        });
+        assert_eq!(status("fourth").0, 1);
+        assert_eq!(status("third"), (0, 0));
+    }
+
+    #[test]
+    fn registry_is_bounded() {
+        let statuses = StatusRegistry::default();
+        for i in 0..=MAX_TRACKED_REQUESTS {
+            statuses.register(&i.to_string()).unwrap();
+            if i != 1 {
+                statuses.update(&i.to_string(), AggchainProofStage::Completed, None);
+            }
+        }
+
+        assert!(statuses.subscribe("0").is_none());
+        assert!(statuses
+            .subscribe(&MAX_TRACKED_REQUESTS.to_string())
+            .is_some());
+
+        // The requests in flight are kept, the finished ones being evicted in
+        // their place.
+        statuses.register("next").unwrap();
+        assert!(statuses.subscribe("1").is_some());
+        assert!(statuses.subscribe("2").is_none());
+    }
+
+    #[test]
+    fn requests_in_flight_are_not_evicted() {
+        let statuses = StatusRegistry::default();
+        for i in 0..=MAX_TRACKED_REQUESTS {
+            statuses.register(&i.to_string()).unwrap();
+        }
+
+        assert!(statuses.subscribe("0").is_some());
+        assert_eq!(
+            statuses.register("0").unwrap_err().code(),
+            tonic::Code::AlreadyExists
+        );
+    }
+
+    #[tokio::test]
+    async fn prover_info_reports_the_client_compatibility() {
+        let service = crate::rpc::GrpcService::with_service(tower::util::BoxService::new(
+            tower::service_fn(|_| async {
+                Err::<AggchainProofServiceResponse, tower::BoxError>("not proving".into())
+            }),
+        ))
+        .with_compatibility(Arc::new(ClientCompatibility::new(
+            &toml::from_str(r#"min-client-version = "0.7.0""#).unwrap(),
+        )))
+        .with_optimistic_mode(false)
+        .v2();
+
+        let mut request = Request::new(GetProverInfoRequest::default());
+        request
+            .metadata_mut()
+            .insert(CLIENT_VERSION_METADATA, "0.6.0".parse().unwrap());
+        let info = service.get_prover_info(request).await.unwrap().into_inner();
+        assert_eq!(info.version, PROVER_VERSION);
+        assert_eq!(info.api_versions, API_VERSIONS);
+        assert_eq!(info.proof_modes, [AggchainProofMode::Normal as i32]);
+        assert_eq!(info.min_client_version, "0.7.0");
+        assert!(!info.client_supported);
+        assert!(info.rate_limit.is_none());
+
+        let info = service
+            .get_prover_info(Request::new(GetProverInfoRequest {
+                client_version: "0.7.1".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(info.client_supported);
+
+        let mut request = Request::new(GenerateAggchainProofRequest::default());
+        request
+            .metadata_mut()
+            .insert(CLIENT_VERSION_METADATA, "0.6.0".parse().unwrap());
+        assert_eq!(
+            service
+                .generate_aggchain_proof(request)
+                .await
+                .unwrap_err()
+                .code(),
+            tonic::Code::FailedPrecondition
+        );
+    }
+}
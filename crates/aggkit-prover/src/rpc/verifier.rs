@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use aggkit_prover_types::{
+    envelope::ProofEnvelope,
+    verifier::{
+        proof_verification_service_server::ProofVerificationService, ProofVerdict,
+        VerifyProofRequest, VerifyProofResponse,
+    },
+};
+use prover_errors::ErrorCode;
+use sp1_sdk::CpuProver;
+use tonic::{Request, Response, Status};
+use tracing::{info, instrument};
+
+use crate::verify::{decode_envelope, verifier_client, ProofArtifact, VKeySet};
+
+/// Implementation of the `aggkit.verifier.v1` gRPC API, served in verify-only
+/// mode.
+#[derive(Clone)]
+pub struct ProofVerificationGrpcService {
+    vkeys: Arc<VKeySet>,
+    client: Arc<CpuProver>,
+}
+
+impl ProofVerificationGrpcService {
+    /// Builds the client verifying the proofs, shared by the requests.
+    pub fn new(vkeys: VKeySet) -> Self {
+        Self {
+            vkeys: Arc::new(vkeys),
+            client: Arc::new(verifier_client()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ProofVerificationService for ProofVerificationGrpcService {
+    #[instrument(skip(self, request))]
+    async fn verify_proof(
+        &self,
+        request: Request<VerifyProofRequest>,
+    ) -> Result<Response<VerifyProofResponse>, Status> {
+        let request = request.into_inner();
+        let envelope = decode_envelope(&request.proof_envelope)
+            .map_err(|error| ErrorCode::InvalidArgument.status(error.to_string()))?;
+
+        // The verification of a proof keeps a thread busy for a while.
+        let (vkeys, client) = (self.vkeys.clone(), self.client.clone());
+        let response = tokio::task::spawn_blocking(move || {
+            verdict(&vkeys, &client, envelope, &request.public_values)
+        })
+        .await
+        .map_err(|error| {
+            ErrorCode::Internal.status(format!("Proof verification aborted: {error}"))
+        })?;
+
+        info!(
+            verdict = response.verdict().as_str_name(),
+            vkey_name = %response.vkey_name,
+            "Proof verified"
+        );
+
+        Ok(Response::new(response))
+    }
+}
+
+fn verdict(
+    vkeys: &VKeySet,
+    client: &CpuProver,
+    envelope: ProofEnvelope,
+    expected_public_values: &[u8],
+) -> VerifyProofResponse {
+    let Some((vkey_name, vkey)) = vkeys.get(envelope.vkey_hash) else {
+        return response(
+            ProofVerdict::UnknownVkey,
+            "",
+            format!("No verifying key configured for {:?}", envelope.vkey_hash),
+        );
+    };
+    if !expected_public_values.is_empty()
+        && expected_public_values != envelope.public_values.as_ref()
+    {
+        return response(
+            ProofVerdict::PublicValuesMismatch,
+            vkey_name,
+            "The public values of the proof differ from the expected ones".to_string(),
+        );
+    }
+
+    match ProofArtifact::Envelope(envelope).verify(client, vkey) {
+        Ok(_) => response(ProofVerdict::Valid, vkey_name, String::new()),
+        Err(error) => response(
+            ProofVerdict::Invalid,
+            vkey_name,
+            format!("{:#}", anyhow::Error::from(error)),
+        ),
+    }
+}
+
+fn response(verdict: ProofVerdict, vkey_name: &str, message: String) -> VerifyProofResponse {
+    VerifyProofResponse {
+        verdict: verdict as i32,
+        vkey_name: vkey_name.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aggkit_prover_types::{envelope::ProofMode, vkey_hash::VKeyHash};
+
+    use super::*;
+
+    fn service() -> ProofVerificationGrpcService {
+        ProofVerificationGrpcService::new(VKeySet::default())
+    }
+
+    #[tokio::test]
+    async fn unknown_vkeys_are_reported() {
+        let envelope = ProofEnvelope::new(
+            ProofMode::Compressed,
+            VKeyHash::from_hash_u32([1, 2, 3, 4, 5, 6, 7, 8]),
+            vec![0xaa; 16],
+            vec![0xbb; 8],
+            "aggkit-prover v1.0.0",
+        );
+
+        let response = service()
+            .verify_proof(Request::new(VerifyProofRequest {
+                proof_envelope: envelope.to_bincode().unwrap().into(),
+                public_values: Default::default(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(response.verdict(), ProofVerdict::UnknownVkey);
+        assert!(response.vkey_name.is_empty());
+    }
+
+    #[tokio::test]
+    async fn malformed_envelopes_are_rejected() {
+        let status = service()
+            .verify_proof(Request::new(VerifyProofRequest {
+                proof_envelope: vec![0xff; 4].into(),
+                public_values: Default::default(),
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
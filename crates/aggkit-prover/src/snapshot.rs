@@ -0,0 +1,307 @@
+//! Snapshot of the state the prover keeps in memory, taken by `GET /snapshot`
+//! on the admin API and restored at startup by `run --restore`, such that a
+//! node can be migrated or recovered without starting its state over.
+//!
+//! The state consists of:
+//!
+//! - the proving cost of the current month, on which the monthly cycle budget
+//!   is enforced;
+//! - the aggregation proof requests being proven by the proposer, waited for
+//!   again instead of being requested again;
+//! - the aggregation proofs of the proof cache, in memory and on disk;
+//! - the proof jobs dispatched to the workers and not proven yet, as stored
+//!   in the Redis stream.
+//!
+//! The state of the workers, proving the dispatched jobs, is theirs: their
+//! proof cache is kept on disk, and their pending proposer requests are
+//! proven again once their jobs are claimed by another worker.
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use proposer_service::{
+    cache::{RangeProof, SpanProofCache},
+    pending::{PendingProposerRequest, PendingRequests},
+};
+use prover_executor::cost::{CostLedger, NetworkCost};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+#[cfg(feature = "redis")]
+use crate::dispatch::{DispatchError, RedisStreamDispatcher};
+
+/// Version of the snapshot format, bumped on breaking changes.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub struct StateSnapshot {
+    pub version: u32,
+    /// Seconds since the unix epoch.
+    pub taken_at: u64,
+    /// Proving cost of the networks, when accounted for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub costs: Vec<NetworkCost>,
+    /// Aggregation proof requests being proven by the proposer.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proposer_requests: Vec<PendingProposerRequest>,
+    /// Aggregation proofs of the proof cache, when enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proofs: Vec<RangeProof>,
+    /// Proof jobs dispatched to the workers and not proven yet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub jobs: Vec<DispatchedJob>,
+}
+
+/// Proof job of the Redis stream, as stored in it: encoded, then compressed
+/// and encrypted when configured.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct DispatchedJob {
+    /// Id of the entry of the stream, kept on restore.
+    pub id: String,
+    /// Hex encoded job.
+    pub job: String,
+}
+
+/// Stores the snapshots are taken from and restored to, those of the
+/// disabled features being unset.
+#[derive(Clone, Default)]
+pub struct SnapshotStores {
+    pub cost_ledger: Option<Arc<CostLedger>>,
+    pub proof_cache: Option<Arc<SpanProofCache>>,
+    pub proposer_requests: Option<Arc<PendingRequests>>,
+    #[cfg(feature = "redis")]
+    pub job_store: Option<RedisStreamDispatcher>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("Unable to read the state snapshot {path:?}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unable to parse the state snapshot {path:?}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Unsupported state snapshot version {0}, expected {SNAPSHOT_VERSION}")]
+    UnsupportedVersion(u32),
+
+    #[error("Unable to export the cached aggregation proofs")]
+    ProofCache(#[source] std::io::Error),
+
+    #[cfg(feature = "redis")]
+    #[error("Unable to export the dispatched proof jobs")]
+    JobStore(#[source] DispatchError),
+}
+
+impl StateSnapshot {
+    pub async fn take(stores: &SnapshotStores) -> Result<Self, SnapshotError> {
+        let proofs = match &stores.proof_cache {
+            Some(proof_cache) => proof_cache
+                .export()
+                .await
+                .map_err(SnapshotError::ProofCache)?,
+            None => Vec::new(),
+        };
+        #[cfg(feature = "redis")]
+        let jobs = match &stores.job_store {
+            Some(job_store) => job_store
+                .export_jobs()
+                .await
+                .map_err(SnapshotError::JobStore)?,
+            None => Vec::new(),
+        };
+        #[cfg(not(feature = "redis"))]
+        let jobs = Vec::new();
+
+        Ok(Self {
+            proofs,
+            jobs,
+            ..Self::from_memory(stores)
+        })
+    }
+
+    /// Snapshot of the stores held in memory only.
+    fn from_memory(stores: &SnapshotStores) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            taken_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            costs: stores
+                .cost_ledger
+                .as_deref()
+                .map(CostLedger::costs)
+                .unwrap_or_default(),
+            proposer_requests: stores
+                .proposer_requests
+                .as_deref()
+                .map(PendingRequests::list)
+                .unwrap_or_default(),
+            proofs: Vec::new(),
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Loads a snapshot saved as JSON.
+    pub fn load(path: &Path) -> Result<Self, SnapshotError> {
+        let bytes = std::fs::read(path).map_err(|source| SnapshotError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let snapshot: Self =
+            serde_json::from_slice(&bytes).map_err(|source| SnapshotError::Parse {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+        snapshot.check_version()
+    }
+
+    fn check_version(self) -> Result<Self, SnapshotError> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(self.version));
+        }
+
+        Ok(self)
+    }
+
+    /// Restores the state of the snapshot, the state of the disabled features
+    /// being dropped.
+    pub async fn restore(self, stores: &SnapshotStores) {
+        info!(taken_at = self.taken_at, "Restoring the state snapshot");
+
+        match &stores.cost_ledger {
+            Some(cost_ledger) => cost_ledger.restore(self.costs),
+            None if !self.costs.is_empty() => {
+                warn!("Proving cost not accounted for, dropping the costs of the snapshot")
+            }
+            None => {}
+        }
+
+        match &stores.proposer_requests {
+            Some(proposer_requests) => proposer_requests.restore(self.proposer_requests),
+            None if !self.proposer_requests.is_empty() => {
+                warn!("Proofs dispatched to the workers, dropping the proposer requests of the snapshot")
+            }
+            None => {}
+        }
+
+        match &stores.proof_cache {
+            Some(proof_cache) => {
+                for RangeProof { range, proof } in self.proofs {
+                    proof_cache.insert(range, proof).await;
+                }
+            }
+            None if !self.proofs.is_empty() => {
+                warn!("Aggregation proofs not cached, dropping the proofs of the snapshot")
+            }
+            None => {}
+        }
+
+        #[cfg(feature = "redis")]
+        if let Some(job_store) = &stores.job_store {
+            let restored = job_store.import_jobs(self.jobs).await;
+            info!(%restored, "Proof jobs of the snapshot enqueued again");
+            return;
+        }
+        if !self.jobs.is_empty() {
+            warn!("Proofs not dispatched to the workers, dropping the jobs of the snapshot")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proposer_service::cache::SpanProofKey;
+    use prover_config::CostAccountingConfig;
+
+    use super::*;
+
+    async fn round_trip(stores: &SnapshotStores, restored: &SnapshotStores) {
+        let snapshot = serde_json::to_vec(&StateSnapshot::take(stores).await.unwrap()).unwrap();
+        let snapshot: StateSnapshot = serde_json::from_slice(&snapshot).unwrap();
+        snapshot.check_version().unwrap().restore(restored).await;
+    }
+
+    #[tokio::test]
+    async fn costs_are_restored_from_the_snapshot() {
+        let config = CostAccountingConfig {
+            credits_per_million_cycles: 1_000,
+            monthly_cycle_budget: Some(50_000_000),
+        };
+        let cost_ledger = Arc::new(CostLedger::new(&config));
+        cost_ledger.record_actual(1, 2_000_000);
+        let restored = Arc::new(CostLedger::new(&config));
+
+        round_trip(
+            &SnapshotStores {
+                cost_ledger: Some(cost_ledger.clone()),
+                ..Default::default()
+            },
+            &SnapshotStores {
+                cost_ledger: Some(restored.clone()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert_eq!(restored.costs(), cost_ledger.costs());
+    }
+
+    #[tokio::test]
+    async fn proposer_requests_are_restored_from_the_snapshot() {
+        let proposer_requests = Arc::new(PendingRequests::default());
+        proposer_requests.restore(vec![PendingProposerRequest {
+            range: SpanProofKey {
+                last_proven_block: 100,
+                requested_end_block: 200,
+                l1_block_hash: Default::default(),
+            },
+            request_id: [7; 32].into(),
+            last_proven_block: 100,
+            end_block: 150,
+        }]);
+        let restored = Arc::new(PendingRequests::default());
+
+        round_trip(
+            &SnapshotStores {
+                proposer_requests: Some(proposer_requests.clone()),
+                ..Default::default()
+            },
+            &SnapshotStores {
+                proposer_requests: Some(restored.clone()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+        assert_eq!(restored.list(), proposer_requests.list());
+    }
+
+    #[tokio::test]
+    async fn snapshot_of_another_version_is_rejected() {
+        let snapshot = StateSnapshot {
+            version: SNAPSHOT_VERSION + 1,
+            ..StateSnapshot::take(&SnapshotStores::default())
+                .await
+                .unwrap()
+        };
+
+        assert!(matches!(
+            snapshot.check_version(),
+            Err(SnapshotError::UnsupportedVersion(_))
+        ));
+    }
+}
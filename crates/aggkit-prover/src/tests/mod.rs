@@ -2,14 +2,18 @@ use std::collections::HashMap;
 
 use aggchain_proof_service::{
     config::AggchainProofServiceConfig,
-    service::{AggchainProofService, AggchainProofServiceRequest},
+    service::{AggchainProofService, AggchainProofServiceRequest, AggchainProofServiceResponse},
 };
 use aggchain_proof_types::AggchainProofInputs;
 use aggkit_prover_types::{
+    bincode,
+    envelope::{ProofEnvelope, ProofMode},
+    stage_timings::StageTimings,
     v1::{
         aggchain_proof_service_client::AggchainProofServiceClient,
         aggchain_proof_service_server::AggchainProofServiceServer, GenerateAggchainProofRequest,
     },
+    vkey_hash::VKeyHash,
     Digest,
 };
 use agglayer_interop::types::{L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
@@ -18,8 +22,9 @@ use hyper_util::rt::TokioIo;
 use tonic::transport::{Endpoint, Server};
 use tonic_types::StatusExt;
 use tower::{service_fn, Service};
+use unified_bridge::AggchainProofPublicValues;
 
-use crate::rpc::GrpcService;
+use crate::rpc::{compatibility::PROVER_VERSION, proof_envelope, GrpcService};
 
 #[tokio::test]
 #[ignore]
@@ -48,6 +53,7 @@ async fn service_can_be_called() {
         l1_info_tree_merkle_proof: MerkleProof::new(Digest::default(), [Digest::default(); 32]),
         ger_leaves: Default::default(),
         imported_bridge_exits: Default::default(),
+        requested_vkey_hash: None,
     });
     let response = service.call(request).await;
     assert!(response.is_ok());
@@ -98,11 +104,15 @@ async fn testing_rpc_failure() {
     let request = tonic::Request::new(GenerateAggchainProofRequest {
         last_proven_block: 1000,
         requested_end_block: 999,
-        l1_info_tree_root_hash: None,
+        l1_info_tree_root_hash: Some(Digest::default().into()),
         l1_info_tree_leaf: None,
-        l1_info_tree_merkle_proof: None,
+        l1_info_tree_merkle_proof: Some(agglayer_interop::grpc::v1::MerkleProof {
+            root: Some(Digest::default().into()),
+            siblings: vec![Digest::default().into(); 32],
+        }),
         ger_leaves: HashMap::new(),
         imported_bridge_exits: vec![],
+        requested_vkey_hash: None,
     });
 
     let response = client.generate_aggchain_proof(request).await;
@@ -116,13 +126,49 @@ async fn testing_rpc_failure() {
     assert!(details.has_bad_request_violations());
     let violations = &details.bad_request().unwrap().field_violations;
 
-    assert_eq!(violations.len(), 1);
+    assert_eq!(violations.len(), 2);
 
-    violations.iter().for_each(|violation| {
-        assert_eq!(violation.field, "requested_end_block");
-        assert_eq!(
-            violation.description,
-            "requested_end_block must be greater than last_proven_block"
-        );
-    });
+    assert_eq!(violations[0].field, "requested_end_block");
+    assert_eq!(
+        violations[0].description,
+        "requested_end_block must be greater than last_proven_block"
+    );
+    assert_eq!(violations[1].field, "l1_info_tree_leaf");
+    assert_eq!(violations[1].description, "missing value");
+}
+
+#[test]
+fn proof_envelopes_describe_the_proofs() {
+    let response = AggchainProofServiceResponse {
+        proof: vec![0xab, 0xcd],
+        aggchain_params: Digest([1; 32]),
+        vkey: vec![0x01],
+        vkey_hash: VKeyHash::from_hash_u32([1, 2, 3, 4, 5, 6, 7, 8]),
+        proof_mode: ProofMode::Compressed,
+        last_proven_block: 100,
+        end_block: 200,
+        requested_end_block: 300,
+        local_exit_root_hash: Digest([2; 32]),
+        custom_chain_data: vec![0x02, 0x03],
+        public_values: AggchainProofPublicValues {
+            prev_local_exit_root: Digest([1; 32]),
+            new_local_exit_root: Digest([2; 32]),
+            l1_info_root: Digest([3; 32]),
+            origin_network: 7_u32.into(),
+            commit_imported_bridge_exits: Digest([4; 32]),
+            aggchain_params: Digest([5; 32]),
+        },
+        stage_timings: StageTimings::default(),
+    };
+
+    let envelope = ProofEnvelope::from_bincode(&proof_envelope(&response)).unwrap();
+
+    assert_eq!(envelope.proof_mode, ProofMode::Compressed);
+    envelope.check_vkey_hash(response.vkey_hash).unwrap();
+    assert_eq!(envelope.proof.as_ref(), response.proof);
+    assert_eq!(
+        envelope.public_values.as_ref(),
+        bincode::sp1v4().serialize(&response.public_values).unwrap()
+    );
+    assert_eq!(envelope.prover_version, PROVER_VERSION);
 }
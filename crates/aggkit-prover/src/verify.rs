@@ -0,0 +1,194 @@
+//! Offline verification of the proof artifacts, out of the proving pipeline.
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use aggchain_proof_service::{AGGCHAIN_PROGRAM_NAME, AGGCHAIN_PROOF_ELF};
+use aggkit_prover_config::VerifierConfig;
+use aggkit_prover_types::{
+    bincode,
+    envelope::{ProofEnvelope, ProofEnvelopeError, ProofMode},
+    public_values::PublicValues,
+    vkey_hash::VKeyHash,
+};
+use prover_executor::Executor;
+use serde::de::DeserializeOwned;
+use sp1_sdk::{
+    CpuProver, Prover as _, ProverClient, SP1Proof, SP1ProofWithPublicValues, SP1PublicValues,
+    SP1VerificationError, SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyProofError {
+    #[error("Unable to read {path:?}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Invalid proof envelope")]
+    Envelope(#[from] ProofEnvelopeError),
+
+    #[error("Unable to decode the proof")]
+    Decode(#[source] anyhow::Error),
+
+    #[error("Unable to decode the verifying key")]
+    Vkey(#[source] anyhow::Error),
+
+    #[error("Proof verification failed")]
+    Verification(#[from] SP1VerificationError),
+}
+
+/// Proof artifact, as produced by the provers.
+pub enum ProofArtifact {
+    /// Proof envelope holding a proof of its recorded mode, encoded as JSON or
+    /// bincode.
+    Envelope(ProofEnvelope),
+    /// SP1 proof along with its public values, as saved by the SP1 SDK.
+    Sp1(SP1ProofWithPublicValues),
+}
+
+impl ProofArtifact {
+    pub fn load_envelope(path: &Path) -> Result<Self, VerifyProofError> {
+        Ok(Self::Envelope(decode_envelope(&read(path)?)?))
+    }
+
+    pub fn load_sp1(path: &Path) -> Result<Self, VerifyProofError> {
+        SP1ProofWithPublicValues::load(path)
+            .map(Self::Sp1)
+            .map_err(VerifyProofError::Decode)
+    }
+
+    /// Verifies the proof against `vkey` with the given client, checking the
+    /// vkey hash carried by the envelope first.
+    pub fn verify(
+        self,
+        client: &CpuProver,
+        vkey: &SP1VerifyingKey,
+    ) -> Result<VerifiedProof, VerifyProofError> {
+        let proof = match self {
+            Self::Envelope(envelope) => {
+                envelope.check_vkey_hash(VKeyHash::from_vkey(vkey))?;
+
+                let proof = match envelope.proof_mode {
+                    ProofMode::Core => SP1Proof::Core(decode_proof(&envelope.proof)?),
+                    ProofMode::Compressed => {
+                        SP1Proof::Compressed(Box::new(decode_proof(&envelope.proof)?))
+                    }
+                    ProofMode::Groth16 => SP1Proof::Groth16(decode_proof(&envelope.proof)?),
+                    ProofMode::Plonk => SP1Proof::Plonk(decode_proof(&envelope.proof)?),
+                };
+
+                SP1ProofWithPublicValues {
+                    proof,
+                    public_values: SP1PublicValues::from(envelope.public_values.as_ref()),
+                    sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+                    tee_proof: None,
+                }
+            }
+            Self::Sp1(proof) => proof,
+        };
+
+        client.verify(&proof, vkey)?;
+
+        Ok(VerifiedProof::new(proof.public_values.to_vec()))
+    }
+}
+
+/// Client verifying the proofs, to build once as its setup is costly.
+pub fn verifier_client() -> CpuProver {
+    ProverClient::builder().cpu().build()
+}
+
+/// Decodes the proof of an envelope, encoded with bincode as the one of its
+/// mode.
+fn decode_proof<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, VerifyProofError> {
+    bincode::default()
+        .deserialize(bytes)
+        .map_err(|error| VerifyProofError::Decode(error.into()))
+}
+
+/// Public values of a successfully verified proof.
+pub struct VerifiedProof {
+    pub public_values: Vec<u8>,
+    /// Public values decoded as the ones of a known program, if they are.
+    pub decoded: Option<PublicValues>,
+}
+
+impl VerifiedProof {
+    fn new(public_values: Vec<u8>) -> Self {
+        let decoded = PublicValues::decode(&public_values).ok();
+
+        Self {
+            public_values,
+            decoded,
+        }
+    }
+}
+
+/// Verifying keys of the programs whose proofs are accepted, by name.
+#[derive(Default)]
+pub struct VKeySet {
+    vkeys: BTreeMap<VKeyHash, (String, SP1VerifyingKey)>,
+}
+
+impl VKeySet {
+    /// Loads the verifying keys of the `[verifier]` configuration, computing
+    /// the one of the aggchain proof program built into the prover if
+    /// accepted.
+    pub fn load(config: &VerifierConfig) -> Result<Self, VerifyProofError> {
+        let mut vkeys = Self::default();
+        if config.builtin_aggchain_vkey {
+            vkeys.insert(
+                AGGCHAIN_PROGRAM_NAME,
+                Executor::compute_program_vkey(AGGCHAIN_PROOF_ELF),
+            );
+        }
+        for (name, path) in &config.vkeys {
+            vkeys.insert(name, load_vkey(path)?);
+        }
+
+        Ok(vkeys)
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, vkey: SP1VerifyingKey) {
+        self.vkeys
+            .insert(VKeyHash::from_vkey(&vkey), (name.into(), vkey));
+    }
+
+    /// Name and verifying key of the program with the given vkey hash.
+    pub fn get(&self, vkey_hash: VKeyHash) -> Option<(&str, &SP1VerifyingKey)> {
+        self.vkeys
+            .get(&vkey_hash)
+            .map(|(name, vkey)| (name.as_str(), vkey))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vkeys.is_empty()
+    }
+}
+
+/// Decodes a proof envelope encoded either as JSON or with bincode.
+pub fn decode_envelope(bytes: &[u8]) -> Result<ProofEnvelope, ProofEnvelopeError> {
+    match std::str::from_utf8(bytes) {
+        Ok(json) if json.trim_start().starts_with('{') => ProofEnvelope::from_json(json),
+        _ => ProofEnvelope::from_bincode(bytes),
+    }
+}
+
+/// Loads a verifying key encoded with bincode, e.g. the one returned along
+/// with the aggchain proofs.
+pub fn load_vkey(path: &Path) -> Result<SP1VerifyingKey, VerifyProofError> {
+    bincode::default()
+        .deserialize(&read(path)?)
+        .map_err(|error| VerifyProofError::Vkey(error.into()))
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, VerifyProofError> {
+    std::fs::read(path).map_err(|source| VerifyProofError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
@@ -0,0 +1,459 @@
+//! Watchdog of the slow proof requests, capturing a diagnostic bundle while
+//! a request is stuck instead of the operators discovering it only once its
+//! client timed out.
+//!
+//! The requests in flight are followed through their spans, each stage being
+//! traced in a span named after it under the span of the request. Every check
+//! interval, the requests spending `multiplier` times the expected duration of
+//! their current stage in it are reported, once per stage, in a bundle made
+//! of:
+//!
+//! - the requests in flight and their current stage,
+//! - the threads of the process and their state, and their stacks when built
+//!   with the `profiling` feature,
+//! - the last health report of the dependencies, with their latencies, when
+//!   the admin API is served.
+//!
+//! The bundles are logged, written to the configured directory, and counted
+//! by the `slow_requests` metric. Dispatched, only the intake and the
+//! validation of the requests are watched, the workers proving them.
+use std::{
+    collections::HashMap,
+    path::Path,
+    sync::{Arc, LazyLock, Mutex, MutexGuard, OnceLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aggkit_prover_config::WatchdogConfig;
+use aggkit_prover_types::stage_timings::Stage;
+use opentelemetry::{global, metrics::Counter, KeyValue};
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    span::{Attributes, Id},
+    warn, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    filter::filter_fn,
+    layer::Context,
+    registry::{LookupSpan, Registry},
+    Layer,
+};
+
+use crate::health::{HealthMonitor, HealthReport};
+
+const METER_NAME: &str = "aggkit_prover";
+
+/// Target of the spans of the requests, those of the gRPC handlers.
+const REQUEST_TARGET: &str = "aggkit_prover::rpc";
+
+/// Frequency the stacks of the threads are sampled at.
+#[cfg(feature = "profiling")]
+const STACK_SAMPLING_FREQUENCY: i32 = 99;
+
+static SLOW_REQUESTS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("slow_requests")
+        .with_description("Proof requests reported by the watchdog, by stage")
+        .build()
+});
+
+/// Request in flight, as reported in the bundles.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RequestState {
+    /// Name of the span of the request, that of its gRPC handler.
+    pub method: &'static str,
+    pub elapsed_ms: u64,
+    /// Innermost stage the request is in, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<Stage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage_elapsed_ms: Option<u64>,
+}
+
+/// Thread of the process, as listed by `/proc/self/task`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ThreadState {
+    pub tid: u32,
+    pub name: String,
+    /// State letter of the thread, e.g. `R` when running and `S` when
+    /// sleeping.
+    pub state: String,
+    /// Kernel function the thread is waiting in, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wchan: Option<String>,
+}
+
+/// Diagnostics captured when requests are slow.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct DiagnosticBundle {
+    /// Time of the capture, in milliseconds since the UNIX epoch.
+    pub captured_at: u64,
+    pub slow: Vec<RequestState>,
+    pub in_flight: Vec<RequestState>,
+    pub threads: Vec<ThreadState>,
+    #[cfg(feature = "profiling")]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stacks: Vec<crate::profiling::ThreadStack>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<HealthReport>,
+}
+
+struct InFlight {
+    method: &'static str,
+    started: Instant,
+    /// Stages open, the innermost last.
+    stages: Vec<OpenStage>,
+}
+
+struct OpenStage {
+    span: Id,
+    stage: Stage,
+    started: Instant,
+    reported: bool,
+}
+
+impl InFlight {
+    fn state(&self, now: Instant) -> RequestState {
+        let stage = self.stages.last();
+
+        RequestState {
+            method: self.method,
+            elapsed_ms: millis(now.saturating_duration_since(self.started)),
+            stage: stage.map(|stage| stage.stage),
+            stage_elapsed_ms: stage
+                .map(|stage| millis(now.saturating_duration_since(stage.started))),
+        }
+    }
+}
+
+/// Watchdog of the requests in flight, fed by its [`layer`](Self::layer).
+pub struct Watchdog {
+    config: WatchdogConfig,
+    /// Requests in flight, by the id of their span.
+    requests: Mutex<HashMap<Id, InFlight>>,
+    health: OnceLock<Arc<HealthMonitor>>,
+}
+
+impl Watchdog {
+    pub fn new(config: WatchdogConfig) -> Self {
+        Self {
+            config,
+            requests: Default::default(),
+            health: OnceLock::new(),
+        }
+    }
+
+    /// Layer following the requests through their spans, to install along
+    /// with the logger.
+    pub fn layer(self: &Arc<Self>) -> Box<dyn Layer<Registry> + Send + Sync> {
+        WatchdogLayer(self.clone())
+            .with_filter(filter_fn(is_watched))
+            .boxed()
+    }
+
+    /// Reports the latencies of the dependencies in the bundles.
+    pub fn set_health(&self, health: Arc<HealthMonitor>) {
+        _ = self.health.set(health);
+    }
+
+    /// Checks the requests in flight every check interval, until cancelled.
+    pub async fn run(self: Arc<Self>, cancellation_token: CancellationToken) {
+        let mut interval = tokio::time::interval(self.config.check_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = interval.tick() => {}
+            }
+
+            let slow = self.slow_requests(Instant::now());
+            if slow.is_empty() {
+                continue;
+            }
+
+            let bundle = self.capture(slow).await;
+            self.export(&bundle).await;
+        }
+    }
+
+    /// Requests which became slow since the last check.
+    fn slow_requests(&self, now: Instant) -> Vec<RequestState> {
+        let mut slow = Vec::new();
+        for request in self.requests().values_mut() {
+            let Some(stage) = request.stages.last_mut() else {
+                continue;
+            };
+            let limit = self
+                .config
+                .expected
+                .get(stage.stage)
+                .saturating_mul(self.config.multiplier);
+            if stage.reported || now.saturating_duration_since(stage.started) <= limit {
+                continue;
+            }
+
+            stage.reported = true;
+            SLOW_REQUESTS.add(1, &[KeyValue::new("stage", stage.stage.span_name())]);
+            slow.push(request.state(now));
+        }
+
+        slow
+    }
+
+    async fn capture(&self, slow: Vec<RequestState>) -> DiagnosticBundle {
+        let now = Instant::now();
+        let in_flight = self
+            .requests()
+            .values()
+            .map(|request| request.state(now))
+            .collect();
+
+        DiagnosticBundle {
+            captured_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, millis),
+            slow,
+            in_flight,
+            threads: threads(),
+            #[cfg(feature = "profiling")]
+            stacks: self.sample_stacks().await,
+            health: self
+                .health
+                .get()
+                .map(|health| health.report().as_ref().clone()),
+        }
+    }
+
+    #[cfg(feature = "profiling")]
+    async fn sample_stacks(&self) -> Vec<crate::profiling::ThreadStack> {
+        let duration = self.config.stack_sampling;
+        let stacks = tokio::task::spawn_blocking(move || {
+            crate::profiling::sample_thread_stacks(duration, STACK_SAMPLING_FREQUENCY)
+        })
+        .await;
+
+        match stacks {
+            Ok(Ok(stacks)) => stacks,
+            Ok(Err(error)) => {
+                warn!(%error, "Unable to sample the stacks of the threads");
+                Vec::new()
+            }
+            Err(error) => {
+                warn!(?error, "Unable to sample the stacks of the threads");
+                Vec::new()
+            }
+        }
+    }
+
+    async fn export(&self, bundle: &DiagnosticBundle) {
+        let json = match serde_json::to_string(bundle) {
+            Ok(json) => json,
+            Err(error) => {
+                warn!(%error, "Unable to serialize the diagnostic bundle");
+                return;
+            }
+        };
+        warn!(
+            slow = bundle.slow.len(),
+            bundle = %json,
+            "Slow proof requests, diagnostics captured"
+        );
+
+        if let Some(directory) = &self.config.directory {
+            let path = directory.join(format!("slow-requests-{}.json", bundle.captured_at));
+            if let Err(error) = write(&path, json).await {
+                warn!(%error, ?path, "Unable to write the diagnostic bundle");
+            }
+        }
+    }
+
+    fn open_stage(&self, request: Id, method: &'static str, span: Id, stage: Stage) {
+        let now = Instant::now();
+        self.requests()
+            .entry(request)
+            .or_insert_with(|| InFlight {
+                method,
+                started: now,
+                stages: Vec::new(),
+            })
+            .stages
+            .push(OpenStage {
+                span,
+                stage,
+                started: now,
+                reported: false,
+            });
+    }
+
+    fn close(&self, span: &Id) {
+        let mut requests = self.requests();
+        if requests.remove(span).is_some() {
+            return;
+        }
+
+        for request in requests.values_mut() {
+            request.stages.retain(|stage| stage.span != *span);
+        }
+    }
+
+    fn requests(&self) -> MutexGuard<'_, HashMap<Id, InFlight>> {
+        self.requests.lock().expect("watchdog lock poisoned")
+    }
+}
+
+struct WatchdogLayer(Arc<Watchdog>);
+
+impl<S> Layer<S> for WatchdogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(stage) = stage_of(attributes.metadata().name()) else {
+            return;
+        };
+        // The outermost span is that of the request, unless the stage is
+        // traced on its own, e.g. by a worker.
+        let Some(request) = ctx
+            .span(id)
+            .and_then(|span| span.scope().from_root().next())
+        else {
+            return;
+        };
+        if stage_of(request.name()).is_some() {
+            return;
+        }
+
+        self.0
+            .open_stage(request.id(), request.name(), id.clone(), stage);
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        self.0.close(&id);
+    }
+}
+
+fn is_watched(metadata: &Metadata<'_>) -> bool {
+    metadata.is_span()
+        && (stage_of(metadata.name()).is_some() || metadata.target().starts_with(REQUEST_TARGET))
+}
+
+fn stage_of(span_name: &str) -> Option<Stage> {
+    Stage::ALL
+        .into_iter()
+        .find(|stage| stage.span_name() == span_name)
+}
+
+/// Threads of the process, none outside of Linux.
+fn threads() -> Vec<ThreadState> {
+    let Ok(tasks) = std::fs::read_dir("/proc/self/task") else {
+        return Vec::new();
+    };
+
+    let mut threads: Vec<_> = tasks
+        .filter_map(|task| {
+            let task = task.ok()?;
+            let tid = task.file_name().to_str()?.parse().ok()?;
+            let read = |file| std::fs::read_to_string(task.path().join(file)).ok();
+            // The state follows the name, which is in parentheses.
+            let stat = read("stat")?;
+            let state = stat.rsplit_once(") ")?.1.split(' ').next()?.to_string();
+            let wchan = read("wchan").filter(|wchan| !wchan.is_empty() && wchan != "0");
+
+            Some(ThreadState {
+                tid,
+                name: read("comm")?.trim_end().to_string(),
+                state,
+                wchan,
+            })
+        })
+        .collect();
+    threads.sort_by_key(|thread| thread.tid);
+
+    threads
+}
+
+async fn write(path: &Path, json: String) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        tokio::fs::create_dir_all(directory).await?;
+    }
+
+    tokio::fs::write(path, json).await
+}
+
+fn millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    use super::*;
+
+    #[test]
+    fn slow_stages_are_reported_once() {
+        let watchdog = Arc::new(Watchdog::new(WatchdogConfig::default()));
+        let subscriber = Registry::default().with(watchdog.layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let request = info_span!(target: "aggkit_prover::rpc", "generate_aggchain_proof");
+            let witness = info_span!(parent: &request, "witness");
+            // Traced on its own, e.g. by a worker.
+            let _prove = info_span!("prove");
+
+            let now = Instant::now();
+            assert_eq!(watchdog.requests().len(), 1);
+            assert!(watchdog.slow_requests(now).is_empty());
+
+            let later = now + Duration::from_secs(16 * 60);
+            let slow = watchdog.slow_requests(later);
+            assert_eq!(slow.len(), 1);
+            assert_eq!(slow[0].method, "generate_aggchain_proof");
+            assert_eq!(slow[0].stage, Some(Stage::Witness));
+            assert!(watchdog.slow_requests(later).is_empty());
+
+            let prove = info_span!(parent: &request, "prove");
+            assert_eq!(
+                watchdog
+                    .requests()
+                    .values()
+                    .next()
+                    .unwrap()
+                    .state(now)
+                    .stage,
+                Some(Stage::Prove)
+            );
+            drop(prove);
+            assert_eq!(
+                watchdog
+                    .requests()
+                    .values()
+                    .next()
+                    .unwrap()
+                    .state(now)
+                    .stage,
+                Some(Stage::Witness)
+            );
+
+            drop(witness);
+            drop(request);
+            assert!(watchdog.requests().is_empty());
+        });
+    }
+
+    #[test]
+    fn threads_are_listed() {
+        let threads = threads();
+        if cfg!(target_os = "linux") {
+            assert!(threads
+                .iter()
+                .any(|thread| thread.tid == std::process::id() && !thread.state.is_empty()));
+        }
+    }
+}
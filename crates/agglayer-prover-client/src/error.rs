@@ -0,0 +1,18 @@
+use agglayer_prover_types::bincode;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Unable to connect to the agglayer prover")]
+    Connect(#[from] tonic::transport::Error),
+
+    #[error("Unable to serialize the stdin")]
+    Stdin(#[source] bincode::Error),
+
+    /// Failure of the prover, once the retries are exhausted for the
+    /// transient ones.
+    #[error("The agglayer prover failed: {}", .0.message())]
+    Status(#[source] tonic::Status),
+
+    #[error("Unable to deserialize the proof")]
+    Proof(#[source] bincode::Error),
+}
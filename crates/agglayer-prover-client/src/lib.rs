@@ -0,0 +1,271 @@
+//! Typed client of the agglayer prover gRPC API.
+//!
+//! Wraps the generated stubs of the pessimistic proof service: the stdin is
+//! serialized and the proof deserialized by the client, the transient
+//! failures of the prover are retried with an exponential backoff, and the
+//! attempts are bounded by the deadline of the call, which the prover is
+//! told about.
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub use agglayer_prover_types::v1::Priority;
+use agglayer_prover_types::{
+    bincode,
+    v1::{
+        generate_proof_request::Stdin,
+        pessimistic_proof_service_client::PessimisticProofServiceClient, GenerateProofRequest,
+    },
+    Proof,
+};
+use prover_errors::is_retriable;
+use sp1_sdk::{SP1ProofWithPublicValues, SP1Stdin};
+use tonic::{
+    codec::CompressionEncoding,
+    transport::{Channel, Endpoint},
+};
+use tracing::{debug, warn};
+
+pub use crate::error::Error;
+
+mod error;
+
+/// Behavior of the [`AgglayerProverClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// Time given to a call, retries included, unbounded when unset.
+    pub deadline: Option<Duration>,
+    /// Retries of the transient failures of the prover.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled on each of the next ones.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            deadline: Some(Duration::from_secs(60 * 60)),
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Delay before the given retry, counted from zero.
+    fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retry))
+            .min(self.max_backoff)
+    }
+}
+
+/// Client of the agglayer prover.
+#[derive(Debug, Clone)]
+pub struct AgglayerProverClient {
+    client: PessimisticProofServiceClient<Channel>,
+    config: ClientConfig,
+}
+
+impl AgglayerProverClient {
+    /// Connects to the prover at the given URI, e.g. `http://agglayer-prover:8080`.
+    pub async fn connect(uri: &str, config: ClientConfig) -> Result<Self, Error> {
+        let channel = Endpoint::from_shared(uri.to_string())?.connect().await?;
+
+        Ok(Self::new(channel, config))
+    }
+
+    pub fn new(channel: Channel, config: ClientConfig) -> Self {
+        Self {
+            client: PessimisticProofServiceClient::new(channel)
+                .send_compressed(CompressionEncoding::Zstd)
+                .accept_compressed(CompressionEncoding::Zstd),
+            config,
+        }
+    }
+
+    /// Proves the pessimistic proof program on the given stdin, on behalf of
+    /// the network, the settlement deadline ordering the request when the
+    /// prover dispatches the earliest deadline first.
+    pub async fn generate_pessimistic_proof(
+        &self,
+        network_id: u32,
+        stdin: &SP1Stdin,
+        priority: Priority,
+        settlement_deadline: Option<SystemTime>,
+    ) -> Result<SP1ProofWithPublicValues, Error> {
+        let request = GenerateProofRequest {
+            priority: priority.into(),
+            network_id,
+            deadline_ms: settlement_deadline.map_or(0, |deadline| {
+                deadline
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |since| since.as_millis() as u64)
+            }),
+            stdin: Some(Stdin::Sp1Stdin(
+                bincode::default()
+                    .serialize(stdin)
+                    .map_err(Error::Stdin)?
+                    .into(),
+            )),
+        };
+
+        let deadline = self
+            .config
+            .deadline
+            .map(|deadline| Instant::now() + deadline);
+        let mut retry = 0;
+        let response = loop {
+            let mut attempt = tonic::Request::new(request.clone());
+            if let Some(deadline) = deadline {
+                attempt.set_timeout(deadline.saturating_duration_since(Instant::now()));
+            }
+
+            let status = match self.client.clone().generate_proof(attempt).await {
+                Ok(response) => break response.into_inner(),
+                Err(status) => status,
+            };
+            let backoff = self.config.backoff(retry);
+            if !is_retriable(&status)
+                || retry >= self.config.max_retries
+                || deadline.is_some_and(|deadline| Instant::now() + backoff >= deadline)
+            {
+                return Err(Error::Status(status));
+            }
+
+            retry += 1;
+            warn!(
+                network_id,
+                code = ?status.code(),
+                message = status.message(),
+                retry,
+                ?backoff,
+                "Agglayer prover failed transiently, retrying"
+            );
+            tokio::time::sleep(backoff).await;
+        };
+
+        let Proof::SP1(proof) = bincode::default()
+            .deserialize(&response.proof)
+            .map_err(Error::Proof)?;
+        debug!(network_id, retries = retry, "Pessimistic proof generated");
+
+        Ok(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_prover::fake::{FakeProver, FakeProverConfig};
+    use tokio_util::sync::CancellationToken;
+    use tonic::Code;
+
+    use super::*;
+
+    const ELF: &[u8] = include_bytes!("../../prover-dummy-program/elf/riscv32im-succinct-zkvm-elf");
+
+    async fn spawn_fake_prover(
+        config: FakeProverConfig,
+        cancellation_token: CancellationToken,
+    ) -> String {
+        let (addr, _handle) =
+            FakeProver::spawn(FakeProver::with_config(ELF, config), cancellation_token)
+                .await
+                .unwrap();
+
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_max() {
+        let config = ClientConfig {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+            ..Default::default()
+        };
+
+        assert_eq!(config.backoff(0), Duration::from_secs(1));
+        assert_eq!(config.backoff(1), Duration::from_secs(2));
+        assert_eq!(config.backoff(2), Duration::from_secs(4));
+        assert_eq!(config.backoff(3), Duration::from_secs(5));
+        assert_eq!(config.backoff(64), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn proofs_are_deserialized() {
+        let cancellation_token = CancellationToken::new();
+        let uri = spawn_fake_prover(FakeProverConfig::default(), cancellation_token.clone()).await;
+        let client = AgglayerProverClient::connect(&uri, ClientConfig::default())
+            .await
+            .unwrap();
+
+        client
+            .generate_pessimistic_proof(1, &SP1Stdin::new(), Priority::High, None)
+            .await
+            .unwrap();
+
+        cancellation_token.cancel();
+    }
+
+    #[tokio::test]
+    async fn transient_failures_are_retried() {
+        let cancellation_token = CancellationToken::new();
+        let config = FakeProverConfig {
+            failure_probability: 1.0,
+            failure_code: Code::Unavailable,
+            ..Default::default()
+        };
+        let uri = spawn_fake_prover(config, cancellation_token.clone()).await;
+        let client = AgglayerProverClient::connect(
+            &uri,
+            ClientConfig {
+                max_retries: 2,
+                initial_backoff: Duration::from_millis(10),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let started = Instant::now();
+        let error = client
+            .generate_pessimistic_proof(1, &SP1Stdin::new(), Priority::High, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Status(status) if status.code() == Code::Unavailable));
+        assert!(started.elapsed() >= Duration::from_millis(30));
+
+        cancellation_token.cancel();
+    }
+
+    #[tokio::test]
+    async fn permanent_failures_are_not_retried() {
+        let cancellation_token = CancellationToken::new();
+        let config = FakeProverConfig {
+            failure_probability: 1.0,
+            failure_code: Code::InvalidArgument,
+            ..Default::default()
+        };
+        let uri = spawn_fake_prover(config, cancellation_token.clone()).await;
+        let client = AgglayerProverClient::connect(
+            &uri,
+            ClientConfig {
+                initial_backoff: Duration::from_secs(60),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let error = tokio::time::timeout(
+            Duration::from_secs(10),
+            client.generate_pessimistic_proof(1, &SP1Stdin::new(), Priority::High, None),
+        )
+        .await
+        .unwrap()
+        .unwrap_err();
+        assert!(matches!(error, Error::Status(status) if status.code() == Code::InvalidArgument));
+
+        cancellation_token.cancel();
+    }
+}
@@ -4,20 +4,30 @@ use std::{
     time::Duration,
 };
 
-use prover_config::{default_max_concurrency_limit, NetworkProverConfig, ProverType};
+use prover_config::{
+    default_max_concurrency_limit, GoldenCaptureConfig, NetworkProverConfig, ProgramCheckConfig,
+    ProofType, ProverType,
+};
 use prover_logger::log::Log;
-use prover_utils::with;
+use prover_utils::{format::ConfigParseError, with};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-pub use crate::{shutdown::ShutdownConfig, telemetry::TelemetryConfig};
+pub use crate::{scheduler::SchedulerConfig, shutdown::ShutdownConfig, telemetry::TelemetryConfig};
+pub use prover_utils::format::ConfigFormat;
 
+pub mod scheduler;
 pub mod shutdown;
 pub(crate) mod telemetry;
 
+/// Prefix of the environment variables overriding the configuration fields,
+/// e.g. `AGGLAYER_PROVER__TELEMETRY__PROMETHEUS_ADDR`.
+pub const ENV_PREFIX: &str = "AGGLAYER_PROVER";
+
 pub(crate) const DEFAULT_IP: std::net::Ipv4Addr = std::net::Ipv4Addr::new(0, 0, 0, 0);
 
 /// The Agglayer Prover configuration.
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct ProverConfig {
     /// The gRPC endpoint used by the prover.
@@ -52,6 +62,15 @@ pub struct ProverConfig {
     #[serde(default = "default_max_buffered_queries")]
     pub max_buffered_queries: usize,
 
+    /// The scheduling of the buffered queries by priority.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub scheduler: SchedulerConfig,
+
+    /// The mode of the generated pessimistic proofs, which have to be
+    /// verifiable on-chain.
+    #[serde(default = "default_proof_type")]
+    pub proof_type: SettlementProofType,
+
     /// The primary prover to be used for generation of the pessimistic proof
     #[serde(default)]
     pub primary_prover: ProverType,
@@ -59,6 +78,14 @@ pub struct ProverConfig {
     /// The fallback prover to be used for generation of the pessimistic proof
     #[serde(default)]
     pub fallback_prover: Option<ProverType>,
+
+    /// The expected identity of the pessimistic proof program.
+    #[serde(default, skip_serializing_if = "crate::default")]
+    pub program_check: ProgramCheckConfig,
+
+    /// Capture of the proven requests as golden vectors, disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub golden_capture: Option<GoldenCaptureConfig>,
 }
 
 impl Default for ProverConfig {
@@ -71,15 +98,40 @@ impl Default for ProverConfig {
             max_concurrency_limit: default_max_concurrency_limit(),
             max_request_duration: default_max_request_duration(),
             max_buffered_queries: default_max_buffered_queries(),
+            scheduler: SchedulerConfig::default(),
+            proof_type: default_proof_type(),
             primary_prover: ProverType::NetworkProver(NetworkProverConfig::default()),
             fallback_prover: None,
             grpc: Default::default(),
+            program_check: ProgramCheckConfig::default(),
+            golden_capture: None,
         }
     }
 }
 
 impl ProverConfig {
+    /// JSON schema of the configuration files, whatever their format.
+    pub fn schema() -> schemars::Schema {
+        schemars::schema_for!(ProverConfig)
+    }
+
+    /// Default configuration as a table, giving the type of the fields missing
+    /// from the configuration files to their environment overrides.
+    fn defaults() -> toml::Table {
+        match toml::Value::try_from(Self::default()) {
+            Ok(toml::Value::Table(defaults)) => defaults,
+            _ => toml::Table::new(),
+        }
+    }
+
+    /// Loads the configuration file, in the format given by its extension.
     pub fn try_load(path: &Path) -> Result<Self, ConfigurationError> {
+        Self::try_load_as(path, ConfigFormat::from_path(path))
+    }
+
+    /// Loads the configuration file, the fields being overridden by the
+    /// environment variables prefixed with [`ENV_PREFIX`].
+    pub fn try_load_as(path: &Path, format: ConfigFormat) -> Result<Self, ConfigurationError> {
         let reader = std::fs::read_to_string(path).map_err(|source| {
             ConfigurationError::UnableToReadConfigFile {
                 path: path.to_path_buf(),
@@ -87,13 +139,16 @@ impl ProverConfig {
             }
         })?;
 
-        let deserializer = toml::de::Deserializer::new(&reader);
-        serde::Deserialize::deserialize(deserializer)
+        let mut config = format.parse(&reader)?;
+        prover_utils::env::apply_env_overrides(&mut config, &Self::defaults(), ENV_PREFIX)?;
+
+        toml::Value::Table(config)
+            .try_into()
             .map_err(ConfigurationError::DeserializationError)
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct GrpcConfig {
     #[serde(
@@ -117,6 +172,26 @@ impl Default for GrpcConfig {
     }
 }
 
+/// SP1 proof mode of the pessimistic proofs, among the ones verifiable by
+/// the settlement contracts.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum SettlementProofType {
+    /// Groth16 SNARK proof, the cheapest to verify on-chain.
+    Groth16,
+    /// Plonk SNARK proof, verifiable on-chain without a trusted setup.
+    Plonk,
+}
+
+impl From<SettlementProofType> for ProofType {
+    fn from(proof_type: SettlementProofType) -> Self {
+        match proof_type {
+            SettlementProofType::Groth16 => Self::Groth16,
+            SettlementProofType::Plonk => Self::Plonk,
+        }
+    }
+}
+
 #[derive(Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct ClientProverConfig {
@@ -124,6 +199,10 @@ pub struct ClientProverConfig {
     pub grpc: GrpcConfig,
 }
 
+const fn default_proof_type() -> SettlementProofType {
+    SettlementProofType::Plonk
+}
+
 const fn default_max_decoding_message_size() -> usize {
     4 * 1024 * 1024
 }
@@ -158,8 +237,14 @@ pub enum ConfigurationError {
         source: std::io::Error,
     },
 
+    #[error("Failed to parse the configuration: {0}")]
+    ParseError(#[from] ConfigParseError),
+
     #[error("Failed to deserialize the configuration: {0}")]
     DeserializationError(#[from] toml::de::Error),
+
+    #[error("Invalid environment override: {0}")]
+    EnvOverride(#[from] prover_utils::env::EnvOverrideError),
 }
 
 pub(crate) fn default<T: Default + PartialEq>(t: &T) -> bool {
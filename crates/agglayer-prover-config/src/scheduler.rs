@@ -0,0 +1,76 @@
+use std::{
+    collections::BTreeMap,
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Scheduling of the queued proof requests, the high priority ones being
+/// dispatched to the executor before the low priority ones unless dispatched
+/// by deadline.
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SchedulerConfig {
+    /// The maximum number of high priority requests dispatched in a row
+    /// while low priority requests are waiting, protecting the latter from
+    /// starvation.
+    #[serde(default = "default_max_consecutive_high_priority")]
+    pub max_consecutive_high_priority: NonZeroUsize,
+
+    /// The share of the prover of the networks, keyed by network id, as the
+    /// number of requests dispatched per round among the waiting networks of
+    /// a same priority. The unlisted networks weigh 1.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+    #[schemars(with = "BTreeMap<String, NonZeroU32>")]
+    pub network_weights: BTreeMap<u32, NonZeroU32>,
+
+    /// The order the queued requests are dispatched in.
+    #[serde(default)]
+    pub mode: SchedulingMode,
+
+    /// The proving time assumed for the networks whose proofs were not timed
+    /// yet, when dispatching the earliest deadline first.
+    #[serde(default = "default_proving_time")]
+    #[serde(with = "crate::with::HumanDuration")]
+    pub default_proving_time: Duration,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            max_consecutive_high_priority: default_max_consecutive_high_priority(),
+            network_weights: BTreeMap::new(),
+            mode: SchedulingMode::default(),
+            default_proving_time: default_proving_time(),
+        }
+    }
+}
+
+/// Order the queued proof requests are dispatched in.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SchedulingMode {
+    /// The requests of the high priority lane first, the networks of a lane
+    /// taking weighted turns.
+    #[default]
+    PriorityLanes,
+
+    /// The requests whose proving has to start first to meet their
+    /// settlement deadline, i.e. their deadline minus the proving time of
+    /// their network, the requests without a deadline coming last by
+    /// priority.
+    EarliestDeadlineFirst,
+}
+
+const fn default_max_consecutive_high_priority() -> NonZeroUsize {
+    NonZeroUsize::new(4).unwrap()
+}
+
+const fn default_proving_time() -> Duration {
+    Duration::from_secs(60 * 5)
+}
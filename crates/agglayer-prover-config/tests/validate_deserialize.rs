@@ -1,6 +1,8 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
-use agglayer_prover_config::ProverConfig as Config;
+use agglayer_prover_config::{
+    scheduler::SchedulingMode, ProverConfig as Config, SettlementProofType,
+};
 use insta::assert_toml_snapshot;
 use pretty_assertions::assert_eq;
 
@@ -43,3 +45,46 @@ fn prover_grpc_max_decoding_message_size() {
 
     assert_eq!(config.grpc.max_decoding_message_size, 100 * 1024 * 1024);
 }
+
+#[test]
+fn scheduler() {
+    let input = "./tests/fixtures/validate_config/scheduler.toml";
+
+    let config = Config::try_load(Path::new(input)).unwrap();
+
+    assert_eq!(config.scheduler.max_consecutive_high_priority.get(), 2);
+    assert_eq!(
+        config
+            .scheduler
+            .network_weights
+            .get(&1)
+            .map(|weight| weight.get()),
+        Some(3)
+    );
+    assert_eq!(config.scheduler.mode, SchedulingMode::EarliestDeadlineFirst);
+    assert_eq!(
+        config.scheduler.default_proving_time,
+        Duration::from_secs(10 * 60)
+    );
+    assert_eq!(
+        Config::default()
+            .scheduler
+            .max_consecutive_high_priority
+            .get(),
+        4
+    );
+    assert_eq!(
+        Config::default().scheduler.mode,
+        SchedulingMode::PriorityLanes
+    );
+}
+
+#[test]
+fn proof_type_is_verifiable_on_chain() {
+    let config: Config = toml::from_str(r#"proof-type = "groth16""#).unwrap();
+    assert_eq!(config.proof_type, SettlementProofType::Groth16);
+
+    for proof_type in ["core", "compressed"] {
+        assert!(toml::from_str::<Config>(&format!(r#"proof-type = "{proof_type}""#)).is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use prost::bytes::Bytes;
+use prover_errors::ErrorCode;
 pub use prover_executor::Error;
 use tonic::Status;
 
@@ -19,7 +20,7 @@ impl ErrorWrapper {
                 })?;
 
                 (
-                    tonic::Code::Internal,
+                    ErrorCode::ProverUnavailable,
                     "Unable to execute prover".to_string(),
                     details,
                 )
@@ -29,7 +30,7 @@ impl ErrorWrapper {
                     error: Bytes::new(),
                     error_type: ErrorKind::ProverFailed.into(),
                 })?;
-                (tonic::Code::Internal, value.to_string(), details)
+                (ErrorCode::ProverFailed, value.to_string(), details)
             }
             Error::ProofVerificationFailed(ref proof_verification_error) => {
                 let details = bincode::default().serialize(&GenerateProofError {
@@ -39,7 +40,11 @@ impl ErrorWrapper {
                     error_type: ErrorKind::ProofVerificationFailed.into(),
                 })?;
 
-                (tonic::Code::InvalidArgument, value.to_string(), details)
+                (
+                    ErrorCode::ProofVerificationFailed,
+                    value.to_string(),
+                    details,
+                )
             }
             Error::ExecutorFailed(ref proof_error) => {
                 let details = bincode::default().serialize(&GenerateProofError {
@@ -47,7 +52,7 @@ impl ErrorWrapper {
                     error_type: ErrorKind::ExecutorFailed.into(),
                 })?;
 
-                (tonic::Code::InvalidArgument, value.to_string(), details)
+                (ErrorCode::ExecutionFailed, value.to_string(), details)
             }
             Error::UnableToInitializePrimaryProver => {
                 let details = bincode::default().serialize(&GenerateProofError {
@@ -56,7 +61,7 @@ impl ErrorWrapper {
                 })?;
 
                 (
-                    tonic::Code::Internal,
+                    ErrorCode::ProverUnavailable,
                     "Executor failed to initialize the primary prover".to_string(),
                     details,
                 )
@@ -68,13 +73,18 @@ impl ErrorWrapper {
                 })?;
 
                 (
-                    tonic::Code::Internal,
+                    ErrorCode::ProverUnavailable,
                     "Executor failed to initialize the fallback prover".to_string(),
                     details,
                 )
             }
         };
 
-        Ok(Status::with_details(code, message, details.into()))
+        // The details are the ones parsed by the agglayer node, the code is
+        // only reported in the metadata.
+        let mut status = Status::with_details(code.grpc_code(), message, details.into());
+        code.annotate(&mut status);
+
+        Ok(status)
     }
 }
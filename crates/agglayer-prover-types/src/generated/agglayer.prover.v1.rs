@@ -3,6 +3,18 @@
 /// Type used to request a pessimistic proof generation.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenerateProofRequest {
+    /// Priority of the request in the prover queue.
+    #[prost(enumeration="Priority", tag="2")]
+    pub priority: i32,
+    /// Network id of the rollup the proof is requested for, the prover sharing
+    /// its capacity fairly between the networks.
+    #[prost(uint32, tag="3")]
+    pub network_id: u32,
+    /// Settlement deadline of the request, in milliseconds since the Unix epoch,
+    /// unset when none. Orders the requests when the prover dispatches the
+    /// earliest deadline first.
+    #[prost(uint64, tag="4")]
+    pub deadline_ms: u64,
     /// Represents the type of stdin of the request
     #[prost(oneof="generate_proof_request::Stdin", tags="1")]
     pub stdin: ::core::option::Option<generate_proof_request::Stdin>,
@@ -23,6 +35,9 @@ pub struct GenerateProofResponse {
     /// The generated proof.
     #[prost(bytes="bytes", tag="1")]
     pub proof: ::prost::bytes::Bytes,
+    /// SP1 proof mode of the generated proof.
+    #[prost(enumeration="Sp1ProofMode", tag="2")]
+    pub sp1_proof_mode: i32,
 }
 /// Error message returned when an error occurs during proof generation.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -75,142 +90,339 @@ impl ErrorKind {
         }
     }
 }
+/// Priority of a proof request, the high priority requests being proven
+/// before the low priority ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Priority {
+    /// Unspecified priority, handled as high.
+    Unspecified = 0,
+    /// Settlement critical request, e.g. a certificate close to its deadline.
+    High = 1,
+    /// Background request, e.g. a reproof or a benchmark.
+    Low = 2,
+}
+impl Priority {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "PRIORITY_UNSPECIFIED",
+            Self::High => "PRIORITY_HIGH",
+            Self::Low => "PRIORITY_LOW",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PRIORITY_UNSPECIFIED" => Some(Self::Unspecified),
+            "PRIORITY_HIGH" => Some(Self::High),
+            "PRIORITY_LOW" => Some(Self::Low),
+            _ => None,
+        }
+    }
+}
+/// SP1 proof mode of a generated proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Sp1ProofMode {
+    /// Unspecified proof mode.
+    Unspecified = 0,
+    /// Proof of every shard of the execution.
+    Core = 1,
+    /// Constant size STARK proof, verifiable within SP1 for aggregation.
+    Compressed = 2,
+    /// Groth16 SNARK proof, verifiable on-chain.
+    Groth16 = 3,
+    /// Plonk SNARK proof, verifiable on-chain.
+    Plonk = 4,
+}
+impl Sp1ProofMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SP1_PROOF_MODE_UNSPECIFIED",
+            Self::Core => "SP1_PROOF_MODE_CORE",
+            Self::Compressed => "SP1_PROOF_MODE_COMPRESSED",
+            Self::Groth16 => "SP1_PROOF_MODE_GROTH16",
+            Self::Plonk => "SP1_PROOF_MODE_PLONK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SP1_PROOF_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "SP1_PROOF_MODE_CORE" => Some(Self::Core),
+            "SP1_PROOF_MODE_COMPRESSED" => Some(Self::Compressed),
+            "SP1_PROOF_MODE_GROTH16" => Some(Self::Groth16),
+            "SP1_PROOF_MODE_PLONK" => Some(Self::Plonk),
+            _ => None,
+        }
+    }
+}
 /// Encoded file descriptor set for the `agglayer.prover.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xd4, 0x10, 0x0a, 0x29, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x70, 0x72,
+    0x0a, 0xef, 0x1f, 0x0a, 0x29, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2f, 0x70, 0x72,
     0x6f, 0x76, 0x65, 0x72, 0x2f, 0x76, 0x31, 0x2f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x67, 0x65,
     0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x12,
     0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e,
-    0x76, 0x31, 0x22, 0x3e, 0x0a, 0x14, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72,
-    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x09, 0x73, 0x70,
-    0x31, 0x5f, 0x73, 0x74, 0x64, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00, 0x52,
-    0x08, 0x73, 0x70, 0x31, 0x53, 0x74, 0x64, 0x69, 0x6e, 0x42, 0x07, 0x0a, 0x05, 0x73, 0x74, 0x64,
-    0x69, 0x6e, 0x22, 0x2d, 0x0a, 0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72,
-    0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x70,
-    0x72, 0x6f, 0x6f, 0x66, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x70, 0x72, 0x6f, 0x6f,
-    0x66, 0x22, 0x68, 0x0a, 0x12, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f,
-    0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x3c, 0x0a,
-    0x0a, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
-    0x0e, 0x32, 0x1d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f,
-    0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64,
-    0x52, 0x09, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x54, 0x79, 0x70, 0x65, 0x2a, 0xb8, 0x01, 0x0a, 0x09,
-    0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x1a, 0x0a, 0x16, 0x45, 0x52, 0x52,
-    0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
-    0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x27, 0x0a, 0x23, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b,
-    0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x41, 0x42, 0x4c, 0x45, 0x5f, 0x54, 0x4f, 0x5f, 0x45, 0x58,
-    0x45, 0x43, 0x55, 0x54, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x56, 0x45, 0x52, 0x10, 0x01, 0x12, 0x1c,
-    0x0a, 0x18, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x50, 0x52, 0x4f,
-    0x56, 0x45, 0x52, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x02, 0x12, 0x28, 0x0a, 0x24,
-    0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46,
-    0x5f, 0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x49, 0x4f, 0x4e, 0x5f, 0x46, 0x41,
-    0x49, 0x4c, 0x45, 0x44, 0x10, 0x03, 0x12, 0x1e, 0x0a, 0x1a, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f,
-    0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x45, 0x58, 0x45, 0x43, 0x55, 0x54, 0x4f, 0x52, 0x5f, 0x46, 0x41,
-    0x49, 0x4c, 0x45, 0x44, 0x10, 0x04, 0x32, 0x7f, 0x0a, 0x17, 0x50, 0x65, 0x73, 0x73, 0x69, 0x6d,
-    0x69, 0x73, 0x74, 0x69, 0x63, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63,
-    0x65, 0x12, 0x64, 0x0a, 0x0d, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f,
-    0x6f, 0x66, 0x12, 0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72,
-    0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
-    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x29, 0x2e, 0x61,
+    0x76, 0x31, 0x22, 0xb8, 0x01, 0x0a, 0x14, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x1d, 0x0a, 0x09, 0x73,
+    0x70, 0x31, 0x5f, 0x73, 0x74, 0x64, 0x69, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x48, 0x00,
+    0x52, 0x08, 0x73, 0x70, 0x31, 0x53, 0x74, 0x64, 0x69, 0x6e, 0x12, 0x38, 0x0a, 0x08, 0x70, 0x72,
+    0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x1c, 0x2e, 0x61,
     0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76,
-    0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52,
-    0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x42, 0x98, 0x01, 0x0a, 0x16, 0x63, 0x6f, 0x6d, 0x2e,
-    0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e,
-    0x76, 0x31, 0x42, 0x14, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-    0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x50, 0x58,
-    0xaa, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x50, 0x72, 0x6f, 0x76,
-    0x65, 0x72, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72,
-    0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1e, 0x41, 0x67, 0x67,
-    0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0x5c,
-    0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x14, 0x41, 0x67,
-    0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x3a, 0x3a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x3a, 0x3a,
-    0x56, 0x31, 0x4a, 0xda, 0x0a, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x2d, 0x01, 0x0a, 0x08, 0x0a,
-    0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00,
-    0x1b, 0x0a, 0x37, 0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x05, 0x00, 0x08, 0x01, 0x1a, 0x2b, 0x20,
-    0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65,
-    0x72, 0x61, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74,
-    0x69, 0x63, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00,
-    0x01, 0x12, 0x03, 0x05, 0x08, 0x1f, 0x0a, 0x37, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03,
-    0x07, 0x02, 0x4a, 0x1a, 0x2a, 0x20, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x20, 0x61,
-    0x20, 0x70, 0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x2d, 0x70, 0x72, 0x6f,
-    0x6f, 0x66, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x06, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x06, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x07, 0x14, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x06,
-    0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x33, 0x48, 0x0a, 0x42, 0x0a, 0x02, 0x04, 0x00, 0x12,
-    0x04, 0x0b, 0x00, 0x11, 0x01, 0x1a, 0x36, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65,
-    0x64, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x61, 0x20, 0x70,
-    0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66,
-    0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x1c, 0x0a, 0x3b, 0x0a, 0x04, 0x04, 0x00, 0x08,
-    0x00, 0x12, 0x04, 0x0d, 0x02, 0x10, 0x03, 0x1a, 0x2d, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73,
-    0x65, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66,
-    0x20, 0x73, 0x74, 0x64, 0x69, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65,
-    0x71, 0x75, 0x65, 0x73, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x08, 0x00, 0x01, 0x12,
-    0x03, 0x0d, 0x08, 0x0d, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x04,
-    0x18, 0x1a, 0x18, 0x20, 0x53, 0x54, 0x44, 0x49, 0x4e, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20,
-    0x73, 0x70, 0x31, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x00, 0x05, 0x12, 0x03, 0x0f, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x0f, 0x0a, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x0f, 0x16, 0x17, 0x0a, 0x46, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x14, 0x00, 0x17,
-    0x01, 0x1a, 0x3a, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x61, 0x73,
-    0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x70,
-    0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66,
-    0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x14, 0x08, 0x1d, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x16, 0x02, 0x12, 0x1a, 0x16, 0x20, 0x54, 0x68, 0x65, 0x20, 0x67, 0x65, 0x6e,
-    0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x16, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x16, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x16, 0x10, 0x11, 0x0a, 0x53, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04,
-    0x1a, 0x00, 0x25, 0x01, 0x1a, 0x47, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20,
-    0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x6f, 0x63,
-    0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
-    0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
-    0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x1a, 0x05, 0x0e, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x1c, 0x02, 0x1d, 0x1a, 0x14, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69,
-    0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x00, 0x02, 0x12, 0x03, 0x1c, 0x1b, 0x1c, 0x0a, 0x30, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x1e, 0x02, 0x2a, 0x1a, 0x23, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76,
-    0x65, 0x72, 0x20, 0x77, 0x61, 0x73, 0x20, 0x75, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f,
-    0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01,
-    0x02, 0x12, 0x03, 0x1e, 0x28, 0x29, 0x0a, 0x36, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03,
-    0x20, 0x02, 0x1f, 0x1a, 0x29, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72,
-    0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72,
-    0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x20, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x20, 0x1d, 0x1e, 0x0a, 0x39, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x03, 0x12, 0x03, 0x22, 0x02, 0x2b, 0x1a, 0x2c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x67, 0x65,
-    0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x61,
-    0x69, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66,
-    0x69, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x22, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x22, 0x29,
-    0x2a, 0x0a, 0x3f, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x24, 0x02, 0x21, 0x1a, 0x32,
-    0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x66, 0x61,
-    0x69, 0x6c, 0x65, 0x64, 0x20, 0x64, 0x75, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x24, 0x02, 0x1c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x24, 0x1f, 0x20, 0x0a, 0x52,
-    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x28, 0x00, 0x2d, 0x01, 0x1a, 0x46, 0x20, 0x45, 0x72, 0x72,
-    0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72,
-    0x6e, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x6e, 0x20, 0x65, 0x72, 0x72, 0x6f,
-    0x72, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x73, 0x20, 0x64, 0x75, 0x72, 0x69, 0x6e, 0x67, 0x20,
-    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
-    0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x28, 0x08, 0x1a, 0x0a, 0x2a,
-    0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x2a, 0x02, 0x12, 0x1a, 0x1d, 0x20, 0x54, 0x68,
-    0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20,
-    0x61, 0x73, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x2a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x2a, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x2a, 0x10, 0x11, 0x0a, 0x2f, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2c, 0x02,
-    0x1b, 0x1a, 0x22, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20,
-    0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72,
-    0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03,
-    0x2c, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2c, 0x0c,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2c, 0x19, 0x1a, 0x62,
-    0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x31, 0x2e, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x52, 0x08, 0x70, 0x72, 0x69, 0x6f,
+    0x72, 0x69, 0x74, 0x79, 0x12, 0x1d, 0x0a, 0x0a, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x5f,
+    0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x49, 0x64, 0x12, 0x1f, 0x0a, 0x0b, 0x64, 0x65, 0x61, 0x64, 0x6c, 0x69, 0x6e, 0x65, 0x5f,
+    0x6d, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x64, 0x65, 0x61, 0x64, 0x6c, 0x69,
+    0x6e, 0x65, 0x4d, 0x73, 0x42, 0x07, 0x0a, 0x05, 0x73, 0x74, 0x64, 0x69, 0x6e, 0x22, 0x75, 0x0a,
+    0x15, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x12, 0x46, 0x0a, 0x0e,
+    0x73, 0x70, 0x31, 0x5f, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x5f, 0x6d, 0x6f, 0x64, 0x65, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x20, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x4d, 0x6f, 0x64, 0x65, 0x52, 0x0c, 0x73, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f, 0x66,
+    0x4d, 0x6f, 0x64, 0x65, 0x22, 0x68, 0x0a, 0x12, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65,
+    0x50, 0x72, 0x6f, 0x6f, 0x66, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72,
+    0x72, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x12, 0x3c, 0x0a, 0x0a, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x5f, 0x74, 0x79, 0x70, 0x65, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0e, 0x32, 0x1d, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e,
+    0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b,
+    0x69, 0x6e, 0x64, 0x52, 0x09, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x54, 0x79, 0x70, 0x65, 0x2a, 0xb8,
+    0x01, 0x0a, 0x09, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x1a, 0x0a, 0x16,
+    0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45,
+    0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x27, 0x0a, 0x23, 0x45, 0x52, 0x52, 0x4f,
+    0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x55, 0x4e, 0x41, 0x42, 0x4c, 0x45, 0x5f, 0x54, 0x4f,
+    0x5f, 0x45, 0x58, 0x45, 0x43, 0x55, 0x54, 0x45, 0x5f, 0x50, 0x52, 0x4f, 0x56, 0x45, 0x52, 0x10,
+    0x01, 0x12, 0x1c, 0x0a, 0x18, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f,
+    0x50, 0x52, 0x4f, 0x56, 0x45, 0x52, 0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x02, 0x12,
+    0x28, 0x0a, 0x24, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x50, 0x52,
+    0x4f, 0x4f, 0x46, 0x5f, 0x56, 0x45, 0x52, 0x49, 0x46, 0x49, 0x43, 0x41, 0x54, 0x49, 0x4f, 0x4e,
+    0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x03, 0x12, 0x1e, 0x0a, 0x1a, 0x45, 0x52, 0x52,
+    0x4f, 0x52, 0x5f, 0x4b, 0x49, 0x4e, 0x44, 0x5f, 0x45, 0x58, 0x45, 0x43, 0x55, 0x54, 0x4f, 0x52,
+    0x5f, 0x46, 0x41, 0x49, 0x4c, 0x45, 0x44, 0x10, 0x04, 0x2a, 0x49, 0x0a, 0x08, 0x50, 0x72, 0x69,
+    0x6f, 0x72, 0x69, 0x74, 0x79, 0x12, 0x18, 0x0a, 0x14, 0x50, 0x52, 0x49, 0x4f, 0x52, 0x49, 0x54,
+    0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12,
+    0x11, 0x0a, 0x0d, 0x50, 0x52, 0x49, 0x4f, 0x52, 0x49, 0x54, 0x59, 0x5f, 0x48, 0x49, 0x47, 0x48,
+    0x10, 0x01, 0x12, 0x10, 0x0a, 0x0c, 0x50, 0x52, 0x49, 0x4f, 0x52, 0x49, 0x54, 0x59, 0x5f, 0x4c,
+    0x4f, 0x57, 0x10, 0x02, 0x2a, 0x9c, 0x01, 0x0a, 0x0c, 0x53, 0x70, 0x31, 0x50, 0x72, 0x6f, 0x6f,
+    0x66, 0x4d, 0x6f, 0x64, 0x65, 0x12, 0x1e, 0x0a, 0x1a, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f,
+    0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46,
+    0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x13, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f,
+    0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x43, 0x4f, 0x52, 0x45, 0x10, 0x01, 0x12, 0x1d,
+    0x0a, 0x19, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45,
+    0x5f, 0x43, 0x4f, 0x4d, 0x50, 0x52, 0x45, 0x53, 0x53, 0x45, 0x44, 0x10, 0x02, 0x12, 0x1a, 0x0a,
+    0x16, 0x53, 0x50, 0x31, 0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f,
+    0x47, 0x52, 0x4f, 0x54, 0x48, 0x31, 0x36, 0x10, 0x03, 0x12, 0x18, 0x0a, 0x14, 0x53, 0x50, 0x31,
+    0x5f, 0x50, 0x52, 0x4f, 0x4f, 0x46, 0x5f, 0x4d, 0x4f, 0x44, 0x45, 0x5f, 0x50, 0x4c, 0x4f, 0x4e,
+    0x4b, 0x10, 0x04, 0x32, 0x7f, 0x0a, 0x17, 0x50, 0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74,
+    0x69, 0x63, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x53, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x64,
+    0x0a, 0x0d, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x12,
+    0x28, 0x2e, 0x61, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65,
+    0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f,
+    0x6f, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x1a, 0x29, 0x2e, 0x61, 0x67, 0x67, 0x6c,
+    0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x2e, 0x47,
+    0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x52, 0x65, 0x73, 0x70,
+    0x6f, 0x6e, 0x73, 0x65, 0x42, 0x98, 0x01, 0x0a, 0x16, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x67, 0x67,
+    0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e, 0x76, 0x31, 0x42,
+    0x14, 0x50, 0x72, 0x6f, 0x6f, 0x66, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x50, 0x58, 0xaa, 0x02, 0x12,
+    0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x2e, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x2e,
+    0x56, 0x31, 0xca, 0x02, 0x12, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79, 0x65, 0x72, 0x5c, 0x50, 0x72,
+    0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x1e, 0x41, 0x67, 0x67, 0x6c, 0x61, 0x79,
+    0x65, 0x72, 0x5c, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42,
+    0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x14, 0x41, 0x67, 0x67, 0x6c, 0x61,
+    0x79, 0x65, 0x72, 0x3a, 0x3a, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x3a, 0x3a, 0x56, 0x31, 0x4a,
+    0xc8, 0x16, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x52, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12,
+    0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x1b, 0x0a, 0x37,
+    0x0a, 0x02, 0x06, 0x00, 0x12, 0x04, 0x05, 0x00, 0x08, 0x01, 0x1a, 0x2b, 0x20, 0x53, 0x65, 0x72,
+    0x76, 0x69, 0x63, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
+    0x69, 0x6e, 0x67, 0x20, 0x70, 0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x06, 0x00, 0x01, 0x12, 0x03,
+    0x05, 0x08, 0x1f, 0x0a, 0x37, 0x0a, 0x04, 0x06, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x4a,
+    0x1a, 0x2a, 0x20, 0x54, 0x72, 0x69, 0x67, 0x67, 0x65, 0x72, 0x73, 0x20, 0x61, 0x20, 0x70, 0x65,
+    0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x2d, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20,
+    0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x06, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x06, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00,
+    0x02, 0x00, 0x02, 0x12, 0x03, 0x07, 0x14, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x06, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x07, 0x33, 0x48, 0x0a, 0x42, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0b, 0x00,
+    0x1a, 0x01, 0x1a, 0x36, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74,
+    0x6f, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x61, 0x20, 0x70, 0x65, 0x73, 0x73,
+    0x69, 0x6d, 0x69, 0x73, 0x74, 0x69, 0x63, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x67, 0x65,
+    0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00,
+    0x01, 0x12, 0x03, 0x0b, 0x08, 0x1c, 0x0a, 0x3b, 0x0a, 0x04, 0x04, 0x00, 0x08, 0x00, 0x12, 0x04,
+    0x0d, 0x02, 0x10, 0x03, 0x1a, 0x2d, 0x20, 0x52, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74,
+    0x64, 0x69, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65,
+    0x73, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x08, 0x00, 0x01, 0x12, 0x03, 0x0d, 0x08,
+    0x0d, 0x0a, 0x25, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x04, 0x18, 0x1a, 0x18,
+    0x20, 0x53, 0x54, 0x44, 0x49, 0x4e, 0x20, 0x75, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x70, 0x31,
+    0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x0f, 0x04, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x0f, 0x0a, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0f,
+    0x16, 0x17, 0x0a, 0x3b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x18, 0x1a,
+    0x2e, 0x20, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x71, 0x75, 0x65, 0x75, 0x65, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x16, 0x17, 0x0a, 0x81, 0x01, 0x0a, 0x04, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x15, 0x02, 0x18, 0x1a, 0x74, 0x20, 0x4e, 0x65, 0x74, 0x77, 0x6f, 0x72,
+    0x6b, 0x20, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x6f, 0x6c, 0x6c,
+    0x75, 0x70, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x69, 0x73, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x65, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x2c, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x73, 0x68, 0x61, 0x72, 0x69, 0x6e,
+    0x67, 0x0a, 0x20, 0x69, 0x74, 0x73, 0x20, 0x63, 0x61, 0x70, 0x61, 0x63, 0x69, 0x74, 0x79, 0x20,
+    0x66, 0x61, 0x69, 0x72, 0x6c, 0x79, 0x20, 0x62, 0x65, 0x74, 0x77, 0x65, 0x65, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x15, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x15, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x15, 0x16, 0x17, 0x0a, 0xb8, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03,
+    0x12, 0x03, 0x19, 0x02, 0x1a, 0x1a, 0xaa, 0x01, 0x20, 0x53, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x20, 0x64, 0x65, 0x61, 0x64, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2c, 0x20, 0x69, 0x6e, 0x20,
+    0x6d, 0x69, 0x6c, 0x6c, 0x69, 0x73, 0x65, 0x63, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x73, 0x69, 0x6e,
+    0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x55, 0x6e, 0x69, 0x78, 0x20, 0x65, 0x70, 0x6f, 0x63,
+    0x68, 0x2c, 0x0a, 0x20, 0x75, 0x6e, 0x73, 0x65, 0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x6e,
+    0x6f, 0x6e, 0x65, 0x2e, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x64, 0x69, 0x73, 0x70, 0x61, 0x74, 0x63,
+    0x68, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x65, 0x61, 0x72, 0x6c, 0x69, 0x65, 0x73,
+    0x74, 0x20, 0x64, 0x65, 0x61, 0x64, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x19, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x19, 0x09, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x19, 0x17, 0x18, 0x0a, 0x46, 0x0a, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0x1d, 0x00, 0x23, 0x01, 0x1a, 0x3a, 0x20, 0x54, 0x79, 0x70, 0x65, 0x20,
+    0x75, 0x73, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+    0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x70, 0x65, 0x73, 0x73, 0x69, 0x6d, 0x69, 0x73, 0x74, 0x69,
+    0x63, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x1d, 0x08, 0x1d,
+    0x0a, 0x23, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x1f, 0x02, 0x12, 0x1a, 0x16, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x1f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x08,
+    0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x1f, 0x10, 0x11, 0x0a,
+    0x35, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x22, 0x02, 0x22, 0x1a, 0x28, 0x20, 0x53,
+    0x50, 0x31, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x70,
+    0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x22, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22,
+    0x0f, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x22, 0x20, 0x21,
+    0x0a, 0x53, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x26, 0x00, 0x31, 0x01, 0x1a, 0x47, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x61, 0x72, 0x65, 0x20, 0x72, 0x65, 0x70, 0x6f,
+    0x72, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x65, 0x72, 0x76,
+    0x69, 0x63, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x26, 0x05,
+    0x0e, 0x0a, 0x21, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02, 0x1d, 0x1a, 0x14,
+    0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x72, 0x72,
+    0x6f, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28,
+    0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x28, 0x1b, 0x1c,
+    0x0a, 0x30, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x02, 0x2a, 0x1a, 0x23, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x77, 0x61, 0x73, 0x20, 0x75,
+    0x6e, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2a, 0x02, 0x25,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x2a, 0x28, 0x29, 0x0a, 0x36,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x2c, 0x02, 0x1f, 0x1a, 0x29, 0x20, 0x54, 0x68,
+    0x65, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20,
+    0x74, 0x6f, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x2c, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x2c,
+    0x1d, 0x1e, 0x0a, 0x39, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x2e, 0x02, 0x2b, 0x1a,
+    0x2c, 0x20, 0x54, 0x68, 0x65, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20,
+    0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x62, 0x65, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2e, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x2e, 0x29, 0x2a, 0x0a, 0x3f, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x04, 0x12, 0x03, 0x30, 0x02, 0x21, 0x1a, 0x32, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65,
+    0x63, 0x75, 0x74, 0x6f, 0x72, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x64, 0x75, 0x72,
+    0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x67, 0x65,
+    0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x30, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04,
+    0x02, 0x12, 0x03, 0x30, 0x1f, 0x20, 0x0a, 0x52, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x34, 0x00,
+    0x39, 0x01, 0x1a, 0x46, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x20, 0x72, 0x65, 0x74, 0x75, 0x72, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x61, 0x6e, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x73,
+    0x20, 0x64, 0x75, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x67, 0x65,
+    0x6e, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02,
+    0x01, 0x12, 0x03, 0x34, 0x08, 0x1a, 0x0a, 0x2a, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03,
+    0x36, 0x02, 0x12, 0x1a, 0x1d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x61, 0x73, 0x20, 0x62, 0x79, 0x74, 0x65, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x36, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x36, 0x08, 0x0d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x36, 0x10, 0x11, 0x0a, 0x2f, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x38, 0x02, 0x1b, 0x1a, 0x22, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x6b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x6f, 0x63, 0x63, 0x75, 0x72, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x38, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x38, 0x0c, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x38, 0x19, 0x1a, 0x0a, 0x71, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x3d,
+    0x00, 0x44, 0x01, 0x1a, 0x65, 0x20, 0x50, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
+    0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x69, 0x67, 0x68, 0x20, 0x70, 0x72, 0x69, 0x6f,
+    0x72, 0x69, 0x74, 0x79, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x73, 0x20, 0x62, 0x65,
+    0x69, 0x6e, 0x67, 0x20, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x0a, 0x20, 0x62, 0x65, 0x66, 0x6f,
+    0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x77, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72,
+    0x69, 0x74, 0x79, 0x20, 0x6f, 0x6e, 0x65, 0x73, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01,
+    0x01, 0x12, 0x03, 0x3d, 0x05, 0x0d, 0x0a, 0x35, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03,
+    0x3f, 0x02, 0x1b, 0x1a, 0x28, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65,
+    0x64, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x2c, 0x20, 0x68, 0x61, 0x6e, 0x64,
+    0x6c, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x68, 0x69, 0x67, 0x68, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3f, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x3f, 0x19, 0x1a, 0x0a, 0x55, 0x0a, 0x04, 0x05, 0x01, 0x02,
+    0x01, 0x12, 0x03, 0x41, 0x02, 0x14, 0x1a, 0x48, 0x20, 0x53, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x6d,
+    0x65, 0x6e, 0x74, 0x20, 0x63, 0x72, 0x69, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x20, 0x72, 0x65, 0x71,
+    0x75, 0x65, 0x73, 0x74, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x61, 0x20, 0x63, 0x65, 0x72,
+    0x74, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x65, 0x20, 0x63, 0x6c, 0x6f, 0x73, 0x65, 0x20, 0x74,
+    0x6f, 0x20, 0x69, 0x74, 0x73, 0x20, 0x64, 0x65, 0x61, 0x64, 0x6c, 0x69, 0x6e, 0x65, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x41, 0x02, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x41, 0x12, 0x13, 0x0a, 0x41, 0x0a, 0x04,
+    0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x43, 0x02, 0x13, 0x1a, 0x34, 0x20, 0x42, 0x61, 0x63, 0x6b,
+    0x67, 0x72, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x2c, 0x20,
+    0x65, 0x2e, 0x67, 0x2e, 0x20, 0x61, 0x20, 0x72, 0x65, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6f,
+    0x72, 0x20, 0x61, 0x20, 0x62, 0x65, 0x6e, 0x63, 0x68, 0x6d, 0x61, 0x72, 0x6b, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x43, 0x02, 0x0e, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x43, 0x11, 0x12, 0x0a, 0x32, 0x0a, 0x02, 0x05,
+    0x02, 0x12, 0x04, 0x47, 0x00, 0x52, 0x01, 0x1a, 0x26, 0x20, 0x53, 0x50, 0x31, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x67, 0x65,
+    0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2e, 0x0a, 0x0a,
+    0x0a, 0x0a, 0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x47, 0x05, 0x11, 0x0a, 0x26, 0x0a, 0x04, 0x05,
+    0x02, 0x02, 0x00, 0x12, 0x03, 0x49, 0x02, 0x21, 0x1a, 0x19, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65,
+    0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6d, 0x6f, 0x64,
+    0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x49, 0x02,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03, 0x49, 0x1f, 0x20, 0x0a,
+    0x35, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4b, 0x02, 0x1a, 0x1a, 0x28, 0x20, 0x50,
+    0x72, 0x6f, 0x6f, 0x66, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x73, 0x68,
+    0x61, 0x72, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x78, 0x65, 0x63, 0x75,
+    0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x4b, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x4b,
+    0x18, 0x19, 0x0a, 0x50, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x03, 0x4d, 0x02, 0x20, 0x1a,
+    0x43, 0x20, 0x43, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x20, 0x73, 0x69, 0x7a, 0x65, 0x20,
+    0x53, 0x54, 0x41, 0x52, 0x4b, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x76, 0x65, 0x72,
+    0x69, 0x66, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x53,
+    0x50, 0x31, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x67, 0x67, 0x72, 0x65, 0x67, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4d,
+    0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12, 0x03, 0x4d, 0x1e, 0x1f,
+    0x0a, 0x38, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4f, 0x02, 0x1d, 0x1a, 0x2b, 0x20,
+    0x47, 0x72, 0x6f, 0x74, 0x68, 0x31, 0x36, 0x20, 0x53, 0x4e, 0x41, 0x52, 0x4b, 0x20, 0x70, 0x72,
+    0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x61, 0x62, 0x6c, 0x65, 0x20,
+    0x6f, 0x6e, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
+    0x02, 0x03, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03,
+    0x02, 0x12, 0x03, 0x4f, 0x1b, 0x1c, 0x0a, 0x36, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03,
+    0x51, 0x02, 0x1b, 0x1a, 0x29, 0x20, 0x50, 0x6c, 0x6f, 0x6e, 0x6b, 0x20, 0x53, 0x4e, 0x41, 0x52,
+    0x4b, 0x20, 0x70, 0x72, 0x6f, 0x6f, 0x66, 0x2c, 0x20, 0x76, 0x65, 0x72, 0x69, 0x66, 0x69, 0x61,
+    0x62, 0x6c, 0x65, 0x20, 0x6f, 0x6e, 0x2d, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x51, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x51, 0x19, 0x1a, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
+    0x6f, 0x33,
 ];
 include!("agglayer.prover.v1.serde.rs");
 include!("agglayer.prover.v1.tonic.rs");
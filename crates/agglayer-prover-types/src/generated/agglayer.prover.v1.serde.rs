@@ -202,10 +202,32 @@ impl serde::Serialize for GenerateProofRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
+        if self.priority != 0 {
+            len += 1;
+        }
+        if self.network_id != 0 {
+            len += 1;
+        }
+        if self.deadline_ms != 0 {
+            len += 1;
+        }
         if self.stdin.is_some() {
             len += 1;
         }
         let mut struct_ser = serializer.serialize_struct("agglayer.prover.v1.GenerateProofRequest", len)?;
+        if self.priority != 0 {
+            let v = Priority::try_from(self.priority)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.priority)))?;
+            struct_ser.serialize_field("priority", &v)?;
+        }
+        if self.network_id != 0 {
+            struct_ser.serialize_field("networkId", &self.network_id)?;
+        }
+        if self.deadline_ms != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("deadlineMs", ToString::to_string(&self.deadline_ms).as_str())?;
+        }
         if let Some(v) = self.stdin.as_ref() {
             match v {
                 generate_proof_request::Stdin::Sp1Stdin(v) => {
@@ -225,12 +247,20 @@ impl<'de> serde::Deserialize<'de> for GenerateProofRequest {
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "priority",
+            "network_id",
+            "networkId",
+            "deadline_ms",
+            "deadlineMs",
             "sp1_stdin",
             "sp1Stdin",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Priority,
+            NetworkId,
+            DeadlineMs,
             Sp1Stdin,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -253,6 +283,9 @@ impl<'de> serde::Deserialize<'de> for GenerateProofRequest {
                         E: serde::de::Error,
                     {
                         match value {
+                            "priority" => Ok(GeneratedField::Priority),
+                            "networkId" | "network_id" => Ok(GeneratedField::NetworkId),
+                            "deadlineMs" | "deadline_ms" => Ok(GeneratedField::DeadlineMs),
                             "sp1Stdin" | "sp1_stdin" => Ok(GeneratedField::Sp1Stdin),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
@@ -273,9 +306,34 @@ impl<'de> serde::Deserialize<'de> for GenerateProofRequest {
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut priority__ = None;
+                let mut network_id__ = None;
+                let mut deadline_ms__ = None;
                 let mut stdin__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::Priority => {
+                            if priority__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priority"));
+                            }
+                            priority__ = Some(map_.next_value::<Priority>()? as i32);
+                        }
+                        GeneratedField::NetworkId => {
+                            if network_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("networkId"));
+                            }
+                            network_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DeadlineMs => {
+                            if deadline_ms__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("deadlineMs"));
+                            }
+                            deadline_ms__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::Sp1Stdin => {
                             if stdin__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("sp1Stdin"));
@@ -285,6 +343,9 @@ impl<'de> serde::Deserialize<'de> for GenerateProofRequest {
                     }
                 }
                 Ok(GenerateProofRequest {
+                    priority: priority__.unwrap_or_default(),
+                    network_id: network_id__.unwrap_or_default(),
+                    deadline_ms: deadline_ms__.unwrap_or_default(),
                     stdin: stdin__,
                 })
             }
@@ -303,12 +364,20 @@ impl serde::Serialize for GenerateProofResponse {
         if !self.proof.is_empty() {
             len += 1;
         }
+        if self.sp1_proof_mode != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("agglayer.prover.v1.GenerateProofResponse", len)?;
         if !self.proof.is_empty() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("proof", pbjson::private::base64::encode(&self.proof).as_str())?;
         }
+        if self.sp1_proof_mode != 0 {
+            let v = Sp1ProofMode::try_from(self.sp1_proof_mode)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.sp1_proof_mode)))?;
+            struct_ser.serialize_field("sp1ProofMode", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -320,11 +389,14 @@ impl<'de> serde::Deserialize<'de> for GenerateProofResponse {
     {
         const FIELDS: &[&str] = &[
             "proof",
+            "sp1_proof_mode",
+            "sp1ProofMode",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Proof,
+            Sp1ProofMode,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -347,6 +419,7 @@ impl<'de> serde::Deserialize<'de> for GenerateProofResponse {
                     {
                         match value {
                             "proof" => Ok(GeneratedField::Proof),
+                            "sp1ProofMode" | "sp1_proof_mode" => Ok(GeneratedField::Sp1ProofMode),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -367,6 +440,7 @@ impl<'de> serde::Deserialize<'de> for GenerateProofResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut proof__ = None;
+                let mut sp1_proof_mode__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Proof => {
@@ -377,13 +451,174 @@ impl<'de> serde::Deserialize<'de> for GenerateProofResponse {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Sp1ProofMode => {
+                            if sp1_proof_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sp1ProofMode"));
+                            }
+                            sp1_proof_mode__ = Some(map_.next_value::<Sp1ProofMode>()? as i32);
+                        }
                     }
                 }
                 Ok(GenerateProofResponse {
                     proof: proof__.unwrap_or_default(),
+                    sp1_proof_mode: sp1_proof_mode__.unwrap_or_default(),
                 })
             }
         }
         deserializer.deserialize_struct("agglayer.prover.v1.GenerateProofResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Priority {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "PRIORITY_UNSPECIFIED",
+            Self::High => "PRIORITY_HIGH",
+            Self::Low => "PRIORITY_LOW",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Priority {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "PRIORITY_UNSPECIFIED",
+            "PRIORITY_HIGH",
+            "PRIORITY_LOW",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Priority;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "PRIORITY_UNSPECIFIED" => Ok(Priority::Unspecified),
+                    "PRIORITY_HIGH" => Ok(Priority::High),
+                    "PRIORITY_LOW" => Ok(Priority::Low),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Sp1ProofMode {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "SP1_PROOF_MODE_UNSPECIFIED",
+            Self::Core => "SP1_PROOF_MODE_CORE",
+            Self::Compressed => "SP1_PROOF_MODE_COMPRESSED",
+            Self::Groth16 => "SP1_PROOF_MODE_GROTH16",
+            Self::Plonk => "SP1_PROOF_MODE_PLONK",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for Sp1ProofMode {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "SP1_PROOF_MODE_UNSPECIFIED",
+            "SP1_PROOF_MODE_CORE",
+            "SP1_PROOF_MODE_COMPRESSED",
+            "SP1_PROOF_MODE_GROTH16",
+            "SP1_PROOF_MODE_PLONK",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Sp1ProofMode;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "SP1_PROOF_MODE_UNSPECIFIED" => Ok(Sp1ProofMode::Unspecified),
+                    "SP1_PROOF_MODE_CORE" => Ok(Sp1ProofMode::Core),
+                    "SP1_PROOF_MODE_COMPRESSED" => Ok(Sp1ProofMode::Compressed),
+                    "SP1_PROOF_MODE_GROTH16" => Ok(Sp1ProofMode::Groth16),
+                    "SP1_PROOF_MODE_PLONK" => Ok(Sp1ProofMode::Plonk),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
@@ -11,8 +11,20 @@ pub mod v1;
 pub enum Proof {
     SP1(SP1ProofWithPublicValues),
 }
+
+impl From<ProofType> for v1::Sp1ProofMode {
+    fn from(proof_type: ProofType) -> Self {
+        match proof_type {
+            ProofType::Core => Self::Core,
+            ProofType::Compressed => Self::Compressed,
+            ProofType::Groth16 => Self::Groth16,
+            ProofType::Plonk => Self::Plonk,
+        }
+    }
+}
 pub mod error;
 pub use agglayer_interop::types::bincode;
 pub use error::{Error, ErrorWrapper};
+use prover_executor::ProofType;
 use serde::{Deserialize, Serialize};
 use sp1_sdk::SP1ProofWithPublicValues;
@@ -1,4 +1,8 @@
-use std::{net::SocketAddr, sync::Arc};
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use agglayer_prover_types::{
     bincode,
@@ -10,25 +14,152 @@ use agglayer_prover_types::{
     },
     Error,
 };
-use sp1_sdk::{CpuProver, Prover as _, ProverClient, SP1Stdin};
-use tonic::{codec::CompressionEncoding, transport::Server};
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+use serde::{Deserialize, Serialize};
+use sp1_sdk::{CpuProver, Prover as _, ProverClient, SP1PublicValues, SP1Stdin};
+use tokio::net::TcpListener;
+use tonic::{
+    codec::CompressionEncoding,
+    transport::{server::TcpIncoming, Server},
+};
 use tracing::{debug, error, info, warn};
 
+/// Handle to the task serving the fake prover.
+pub type ServerHandle = tokio::task::JoinHandle<Result<(), tonic::transport::Error>>;
+
+/// Public values of the canned invalid proofs, which don't match the proof.
+const INVALID_PROOF_PUBLIC_VALUES: &[u8] = b"invalid fake proof";
+
+/// Latency added by the fake prover before answering a request.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FakeLatency {
+    #[default]
+    None,
+    Fixed(#[serde(with = "prover_utils::with::HumanDuration")] Duration),
+    /// Latency drawn uniformly between `min` and `max`, both included.
+    Uniform {
+        #[serde(with = "prover_utils::with::HumanDuration")]
+        min: Duration,
+        #[serde(with = "prover_utils::with::HumanDuration")]
+        max: Duration,
+    },
+}
+
+/// Behavior of the fake prover.
+///
+/// The outcome of every request is drawn from a RNG seeded with `seed`, hence
+/// a given sequence of requests always gets the same sequence of outcomes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct FakeProverConfig {
+    pub seed: u64,
+    pub latency: FakeLatency,
+    /// Probability for a request to fail with `failure_code`.
+    pub failure_probability: f64,
+    /// gRPC status code of the injected failures, given by its number in the
+    /// configuration, e.g. 14 for `UNAVAILABLE`.
+    #[serde(with = "grpc_code")]
+    pub failure_code: tonic::Code,
+    /// Probability for a request to be answered with a proof which doesn't
+    /// verify.
+    pub invalid_proof_probability: f64,
+}
+
+impl Default for FakeProverConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            latency: FakeLatency::None,
+            failure_probability: 0.0,
+            failure_code: tonic::Code::Unavailable,
+            invalid_proof_probability: 0.0,
+        }
+    }
+}
+
+/// gRPC status codes, (de)serialized as their number.
+pub mod grpc_code {
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(code: &tonic::Code, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(*code as i32)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<tonic::Code, D::Error> {
+        match i32::deserialize(deserializer)? {
+            code @ 0..=16 => Ok(tonic::Code::from_i32(code)),
+            code => Err(serde::de::Error::custom(format!(
+                "{code} is not a gRPC status code"
+            ))),
+        }
+    }
+}
+
+/// Outcome of one request, drawn before it is processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FakeOutcome {
+    latency: Duration,
+    kind: FakeOutcomeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FakeOutcomeKind {
+    Proof,
+    InvalidProof,
+    Failure,
+}
+
 pub struct FakeProver {
     prover: Arc<CpuProver>,
     proving_key: sp1_sdk::SP1ProvingKey,
+    config: FakeProverConfig,
+    rng: Mutex<StdRng>,
 }
 
 impl FakeProver {
     pub fn new(elf: &[u8]) -> Self {
+        Self::with_config(elf, FakeProverConfig::default())
+    }
+
+    pub fn with_config(elf: &[u8], config: FakeProverConfig) -> Self {
         let prover = ProverClient::builder().mock().build();
         let (proving_key, _verifying_key) = prover.setup(elf);
 
         Self {
             proving_key,
             prover: Arc::new(prover),
+            rng: Mutex::new(StdRng::seed_from_u64(config.seed)),
+            config,
         }
     }
+
+    fn draw_outcome(&self) -> FakeOutcome {
+        let mut rng = self.rng.lock().expect("fake prover rng lock poisoned");
+
+        let latency = match self.config.latency {
+            FakeLatency::None => Duration::ZERO,
+            FakeLatency::Fixed(latency) => latency,
+            FakeLatency::Uniform { min, max } if min >= max => min,
+            FakeLatency::Uniform { min, max } => rng.gen_range(min..=max),
+        };
+
+        // Both draws are always made, so that the sequence of outcomes
+        // doesn't depend on the outcomes themselves.
+        let failure = rng.gen_bool(self.config.failure_probability.clamp(0.0, 1.0));
+        let invalid = rng.gen_bool(self.config.invalid_proof_probability.clamp(0.0, 1.0));
+        let kind = if failure {
+            FakeOutcomeKind::Failure
+        } else if invalid {
+            FakeOutcomeKind::InvalidProof
+        } else {
+            FakeOutcomeKind::Proof
+        };
+
+        FakeOutcome { latency, kind }
+    }
 }
 
 impl FakeProver {
@@ -36,7 +167,40 @@ impl FakeProver {
         fake_prover: Self,
         endpoint: SocketAddr,
         cancellation_token: tokio_util::sync::CancellationToken,
-    ) -> Result<tokio::task::JoinHandle<Result<(), tonic::transport::Error>>, ()> {
+    ) -> Result<ServerHandle, ()> {
+        let listener = TcpListener::bind(endpoint)
+            .await
+            .map_err(|error| error!("Unable to bind the fake prover to {endpoint}: {error}"))?;
+
+        Self::spawn_with_listener(fake_prover, listener, cancellation_token)
+            .await
+            .map(|(_, handle)| handle)
+    }
+
+    /// Spawns the fake prover on an ephemeral local port, returning the
+    /// address it listens on.
+    pub async fn spawn(
+        fake_prover: Self,
+        cancellation_token: tokio_util::sync::CancellationToken,
+    ) -> Result<(SocketAddr, ServerHandle), ()> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .await
+            .map_err(|error| error!("Unable to bind the fake prover: {error}"))?;
+
+        Self::spawn_with_listener(fake_prover, listener, cancellation_token).await
+    }
+
+    async fn spawn_with_listener(
+        fake_prover: Self,
+        listener: TcpListener,
+        cancellation_token: tokio_util::sync::CancellationToken,
+    ) -> Result<(SocketAddr, ServerHandle), ()> {
+        let endpoint = listener
+            .local_addr()
+            .map_err(|error| error!("Unable to get the fake prover address: {error}"))?;
+        let incoming = TcpIncoming::from_listener(listener, true, None)
+            .map_err(|error| error!("Unable to listen on {endpoint}: {error}"))?;
+
         let svc = PessimisticProofServiceServer::new(fake_prover)
             .send_compressed(CompressionEncoding::Zstd)
             .accept_compressed(CompressionEncoding::Zstd);
@@ -61,7 +225,7 @@ impl FakeProver {
                 .add_service(reflection)
                 .add_service(health_service)
                 .add_service(svc)
-                .serve_with_shutdown(endpoint, cancellation_token.cancelled())
+                .serve_with_incoming_shutdown(incoming, cancellation_token.cancelled())
                 .await
             {
                 error!("Failed to start Agglayer Prover: {}", error);
@@ -72,7 +236,7 @@ impl FakeProver {
             Ok(())
         });
 
-        Ok(handle)
+        Ok((endpoint, handle))
     }
 }
 
@@ -84,6 +248,17 @@ impl PessimisticProofService for FakeProver {
     ) -> Result<tonic::Response<agglayer_prover_types::v1::GenerateProofResponse>, tonic::Status>
     {
         debug!("Received proof generation request");
+        let outcome = self.draw_outcome();
+        tokio::time::sleep(outcome.latency).await;
+
+        if outcome.kind == FakeOutcomeKind::Failure {
+            debug!("Injecting a failure of the fake prover");
+            return Err(tonic::Status::new(
+                self.config.failure_code,
+                "Injected fake prover failure",
+            ));
+        }
+
         let request_inner = request.into_inner();
         let stdin: SP1Stdin = match request_inner.stdin {
             Some(Stdin::Sp1Stdin(stdin)) => bincode::default()
@@ -101,7 +276,12 @@ impl PessimisticProofService for FakeProver {
             .run()
             .map_err(|error| Error::ProverFailed(error.to_string()));
         match result {
-            Ok(proof) => {
+            Ok(mut proof) => {
+                if outcome.kind == FakeOutcomeKind::InvalidProof {
+                    debug!("Answering with an invalid proof");
+                    proof.public_values = SP1PublicValues::from(INVALID_PROOF_PUBLIC_VALUES);
+                }
+
                 let proof = bincode::default()
                     .serialize(&agglayer_prover_types::Proof::SP1(proof))
                     .unwrap();
@@ -109,6 +289,7 @@ impl PessimisticProofService for FakeProver {
                 Ok(tonic::Response::new(
                     agglayer_prover_types::v1::GenerateProofResponse {
                         proof: proof.into(),
+                        sp1_proof_mode: agglayer_prover_types::v1::Sp1ProofMode::Plonk as i32,
                     },
                 ))
             }
@@ -119,3 +300,50 @@ impl PessimisticProofService for FakeProver {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ELF: &[u8] = include_bytes!("../../prover-dummy-program/elf/riscv32im-succinct-zkvm-elf");
+
+    #[test]
+    fn outcomes_are_deterministic_for_a_seed() {
+        let config = FakeProverConfig {
+            seed: 42,
+            latency: FakeLatency::Uniform {
+                min: Duration::from_millis(10),
+                max: Duration::from_millis(100),
+            },
+            failure_probability: 0.3,
+            invalid_proof_probability: 0.3,
+            ..Default::default()
+        };
+
+        let draw = |prover: &FakeProver| (0..50).map(|_| prover.draw_outcome()).collect::<Vec<_>>();
+        let outcomes = draw(&FakeProver::with_config(ELF, config.clone()));
+        assert_eq!(outcomes, draw(&FakeProver::with_config(ELF, config)));
+
+        assert!(outcomes.iter().all(|outcome| {
+            (Duration::from_millis(10)..=Duration::from_millis(100)).contains(&outcome.latency)
+        }));
+        for kind in [
+            FakeOutcomeKind::Proof,
+            FakeOutcomeKind::InvalidProof,
+            FakeOutcomeKind::Failure,
+        ] {
+            assert!(outcomes.iter().any(|outcome| outcome.kind == kind));
+        }
+    }
+
+    #[test]
+    fn default_config_always_proves() {
+        let prover = FakeProver::new(ELF);
+
+        assert!((0..50).all(|_| prover.draw_outcome()
+            == FakeOutcome {
+                latency: Duration::ZERO,
+                kind: FakeOutcomeKind::Proof,
+            }));
+    }
+}
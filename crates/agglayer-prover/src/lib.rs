@@ -6,6 +6,7 @@ use prover_engine::ProverEngine;
 pub mod fake;
 pub mod prover;
 mod rpc;
+mod scheduler;
 
 /// This is the main prover entrypoint.
 ///
@@ -36,8 +37,8 @@ pub fn main(cfg: PathBuf, version: &str, program: &'static [u8]) -> anyhow::Resu
         .enable_all()
         .build()?;
 
-    let pp_service =
-        prover_runtime.block_on(async { crate::prover::Prover::create_service(&config, program) });
+    let pp_service = prover_runtime
+        .block_on(async { crate::prover::Prover::create_service(&config, program) })?;
 
     ProverEngine::new(
         config.grpc_endpoint,
@@ -3,14 +3,17 @@ use std::sync::Arc;
 use agglayer_prover_config::ProverConfig;
 use agglayer_prover_types::v1::pessimistic_proof_service_server::PessimisticProofServiceServer;
 use anyhow::Result;
-use prover_executor::Executor;
+use prover_executor::{golden::GoldenCaptureLayer, Executor};
 use tokio::join;
 use tokio_util::sync::CancellationToken;
 use tonic::{codec::CompressionEncoding, transport::Server};
-use tower::{limit::ConcurrencyLimitLayer, ServiceExt as _};
+use tower::{limit::ConcurrencyLimitLayer, util::BoxCloneService, Layer as _, ServiceExt as _};
 use tracing::{debug, error};
 
-use crate::rpc::ProverRPC;
+use crate::{rpc::ProverRPC, scheduler::PriorityScheduler};
+
+/// Name of the program in the golden vectors.
+pub const PESSIMISTIC_PROGRAM_NAME: &str = "pessimistic";
 
 pub struct Prover {
     handle: tokio::task::JoinHandle<Result<(), tonic::transport::Error>>,
@@ -21,27 +24,39 @@ impl Prover {
     pub fn create_service(
         config: &ProverConfig,
         program: &[u8],
-    ) -> PessimisticProofServiceServer<ProverRPC> {
+    ) -> Result<PessimisticProofServiceServer<ProverRPC>> {
+        let executor = Executor::new(&config.primary_prover, &config.fallback_prover, program);
+        executor.check_compatibility(&config.program_check)?;
+
+        let executor = match &config.golden_capture {
+            Some(golden_capture) => BoxCloneService::new(
+                GoldenCaptureLayer::new(
+                    PESSIMISTIC_PROGRAM_NAME,
+                    executor.get_vkey().clone(),
+                    golden_capture,
+                )
+                .layer(executor),
+            ),
+            None => BoxCloneService::new(executor),
+        };
+
         let executor = tower::ServiceBuilder::new()
             .timeout(config.max_request_duration)
             .layer(ConcurrencyLimitLayer::new(config.max_concurrency_limit))
-            .service(Executor::new(
-                &config.primary_prover,
-                &config.fallback_prover,
-                program,
-            ))
+            .service(executor)
             .into_inner()
             .boxed();
 
-        let executor = tower::buffer::Buffer::new(executor, config.max_buffered_queries);
+        let executor =
+            PriorityScheduler::new(executor, config.max_buffered_queries, &config.scheduler);
 
-        let rpc = ProverRPC::new(executor);
+        let rpc = ProverRPC::new(executor, config.proof_type.into());
 
-        PessimisticProofServiceServer::new(rpc)
+        Ok(PessimisticProofServiceServer::new(rpc)
             .max_decoding_message_size(config.grpc.max_decoding_message_size)
             .max_encoding_message_size(config.grpc.max_encoding_message_size)
             .send_compressed(CompressionEncoding::Zstd)
-            .accept_compressed(CompressionEncoding::Zstd)
+            .accept_compressed(CompressionEncoding::Zstd))
     }
 
     /// Function that setups and starts the Agglayer Prover.
@@ -55,6 +70,7 @@ impl Prover {
     /// # Errors
     ///
     /// This function will return an error if:
+    /// - The program doesn't match the expected one.
     /// - The gRPC server failed to start.
     #[builder(entry = "builder", exit = "start", visibility = "pub(crate)")]
     pub async fn start(
@@ -62,7 +78,7 @@ impl Prover {
         cancellation_token: CancellationToken,
         program: &'static [u8],
     ) -> Result<Self> {
-        let svc = Self::create_service(&config, program);
+        let svc = Self::create_service(&config, program)?;
         let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
 
         health_reporter
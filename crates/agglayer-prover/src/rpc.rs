@@ -1,27 +1,35 @@
+use std::time::{Duration, UNIX_EPOCH};
+
 use agglayer_prover_types::{
     v1::{
-        generate_proof_request::Stdin, pessimistic_proof_service_server::PessimisticProofService,
+        self, generate_proof_request::Stdin,
+        pessimistic_proof_service_server::PessimisticProofService,
     },
     ErrorWrapper,
 };
 use agglayer_telemetry::prover::{
     PROVING_REQUEST_FAILED, PROVING_REQUEST_RECV, PROVING_REQUEST_SUCCEEDED,
 };
+use prover_errors::ErrorCode;
 use prover_executor::{ProofType, Request, Response};
 use sp1_sdk::SP1Stdin;
 use tonic::Status;
-use tower::{buffer::Buffer, util::BoxService, Service, ServiceExt};
+use tower::{Service, ServiceExt};
 use tracing::{debug, error, warn};
 
+use crate::scheduler::{Priority, PriorityScheduler};
+
 pub struct ProverRPC {
-    executor: Buffer<BoxService<Request, Response, prover_executor::Error>, Request>,
+    executor: PriorityScheduler<Request, Response>,
+    proof_type: ProofType,
 }
 
 impl ProverRPC {
-    pub fn new(
-        executor: Buffer<BoxService<Request, Response, prover_executor::Error>, Request>,
-    ) -> Self {
-        Self { executor }
+    pub fn new(executor: PriorityScheduler<Request, Response>, proof_type: ProofType) -> Self {
+        Self {
+            executor,
+            proof_type,
+        }
     }
 }
 
@@ -37,35 +45,46 @@ impl PessimisticProofService for ProverRPC {
         debug!("Got a request from {:?}", request.remote_addr());
 
         let request_inner = request.into_inner();
+        // The clients unaware of the priorities are assumed to be settling.
+        let priority = match request_inner.priority() {
+            v1::Priority::Low => Priority::Low,
+            v1::Priority::High | v1::Priority::Unspecified => Priority::High,
+        };
+        let deadline = (request_inner.deadline_ms != 0)
+            .then(|| UNIX_EPOCH + Duration::from_millis(request_inner.deadline_ms));
         let stdin: SP1Stdin = match request_inner.stdin {
             Some(Stdin::Sp1Stdin(stdin)) => agglayer_prover_types::bincode::default()
                 .deserialize(&stdin)
-                .map_err(|_| tonic::Status::invalid_argument("Unable to deserialize stdin"))?,
+                .map_err(|_| ErrorCode::InvalidArgument.status("Unable to deserialize stdin"))?,
             None => {
-                return Err(tonic::Status::invalid_argument("stdin is required"));
+                return Err(ErrorCode::InvalidArgument.status("stdin is required"));
             }
         };
 
         let mut executor = self.executor.clone();
-        let executor = executor
-            .ready()
-            .await
-            .map_err(|_error| tonic::Status::internal("Unable to get proof executor"))?;
+        let executor = executor.ready().await.map_err(|_error| {
+            ErrorCode::ProverUnavailable.status("Unable to get proof executor")
+        })?;
 
         let request = Request {
             stdin,
-            proof_type: ProofType::Plonk,
+            proof_type: self.proof_type,
         };
 
-        match executor.call(request).await {
+        match executor
+            .call((priority, request_inner.network_id, deadline, request))
+            .await
+        {
             Ok(result) => {
+                let sp1_proof_mode = v1::Sp1ProofMode::from(result.proof_type());
                 let response = agglayer_prover_types::v1::GenerateProofResponse {
                     proof: agglayer_prover_types::bincode::default()
                         .serialize(&agglayer_prover_types::Proof::SP1(result.proof))
                         .map_err(|_| {
-                            tonic::Status::internal("Unable to serialize generated proof")
+                            ErrorCode::Internal.status("Unable to serialize generated proof")
                         })?
                         .into(),
+                    sp1_proof_mode: sp1_proof_mode as i32,
                 };
 
                 PROVING_REQUEST_SUCCEEDED.add(1, metrics_attrs);
@@ -79,14 +98,14 @@ impl PessimisticProofService for ProverRPC {
                     let response: Status =
                         ErrorWrapper::try_into_status(error).unwrap_or_else(|inner_error| {
                             warn!("Unable to serialize the prover error: {}", inner_error);
-                            tonic::Status::invalid_argument(error.to_string())
+                            ErrorCode::InvalidArgument.status(error.to_string())
                         });
 
                     return Err(response);
                 } else {
                     error!("Failed to generate proof: {:?}", error);
 
-                    return Err(tonic::Status::internal("Failed to generate proof"));
+                    return Err(ErrorCode::ProvingFailed.status("Failed to generate proof"));
                 }
             }
         }
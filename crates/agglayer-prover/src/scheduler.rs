@@ -0,0 +1,787 @@
+//! Two-lane queue of the proof requests in front of the executor, the
+//! settlement critical requests being dispatched before the background ones,
+//! e.g. the reproofs and the benchmarks.
+//!
+//! A request is picked whenever the executor has capacity, from the high
+//! priority lane first. To protect the low priority lane from starvation, a
+//! low priority request is picked after `max_consecutive_high` high priority
+//! requests were dispatched in a row while it was waiting.
+//!
+//! Within a lane, the networks waiting take turns in a weighted round-robin,
+//! such that a network flooding the prover doesn't delay the others: each
+//! turn dispatches up to the weight of the network, 1 unless configured.
+//!
+//! Alternatively, the requests are dispatched earliest deadline first: by the
+//! latest time their proving can start to meet their settlement deadline,
+//! i.e. their deadline minus the moving average of the proving time of their
+//! network. The requests without a deadline come last, by priority.
+//!
+//! The number of requests waiting is exported by lane and by network, for the
+//! clients to decide whether to wait or to turn to another prover.
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    future::Future,
+    num::{NonZeroU32, NonZeroUsize},
+    pin::Pin,
+    sync::{Arc, LazyLock, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use agglayer_prover_config::{scheduler::SchedulingMode, SchedulerConfig};
+use opentelemetry::{global, metrics::UpDownCounter, KeyValue};
+use tokio::sync::{mpsc, oneshot};
+use tower::{BoxError, Service, ServiceExt as _};
+use tracing::{debug, error};
+
+const METER_NAME: &str = "agglayer_prover";
+
+/// Weight of the last proving time of a network in its moving average.
+const PROVING_TIME_WEIGHT: f64 = 0.25;
+
+static QUEUE_DEPTH: LazyLock<UpDownCounter<i64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .i64_up_down_counter("scheduler_queue_depth")
+        .with_description("Proof requests waiting to be dispatched, by lane and network")
+        .build()
+});
+
+type ResponseFuture<Response> = Pin<Box<dyn Future<Output = Result<Response, BoxError>> + Send>>;
+
+/// Priority of a proof request in the queue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Priority {
+    High,
+    Low,
+}
+
+impl Priority {
+    /// Name of the lane of the priority, used as metric label.
+    fn lane(self) -> &'static str {
+        match self {
+            Priority::High => "high",
+            Priority::Low => "low",
+        }
+    }
+}
+
+fn queue_labels(priority: Priority, network_id: u32) -> [KeyValue; 2] {
+    [
+        KeyValue::new("lane", priority.lane()),
+        KeyValue::new("network_id", i64::from(network_id)),
+    ]
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("The proof request scheduler is closed")]
+pub struct Closed;
+
+struct Message<Request, Response> {
+    network_id: u32,
+    deadline: Option<SystemTime>,
+    request: Request,
+    respond: oneshot::Sender<ResponseFuture<Response>>,
+}
+
+/// Handle queueing the requests into the lane of their priority, the
+/// requests being dispatched to the service by a background worker.
+pub struct PriorityScheduler<Request, Response> {
+    high: mpsc::Sender<Message<Request, Response>>,
+    low: mpsc::Sender<Message<Request, Response>>,
+}
+
+impl<Request, Response> Clone for PriorityScheduler<Request, Response> {
+    fn clone(&self) -> Self {
+        Self {
+            high: self.high.clone(),
+            low: self.low.clone(),
+        }
+    }
+}
+
+impl<Request, Response> PriorityScheduler<Request, Response>
+where
+    Request: Send + 'static,
+    Response: Send + 'static,
+{
+    /// Spawns the worker dispatching the requests to the service, each lane
+    /// buffering up to `capacity` requests.
+    pub fn new<S>(service: S, capacity: usize, config: &SchedulerConfig) -> Self
+    where
+        S: Service<Request, Response = Response> + Send + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send + 'static,
+    {
+        let (high, high_lane) = mpsc::channel(capacity);
+        let (low, low_lane) = mpsc::channel(capacity);
+        let high_lane = Lane::new(high_lane, capacity, config.network_weights.clone());
+        let low_lane = Lane::new(low_lane, capacity, config.network_weights.clone());
+        match config.mode {
+            SchedulingMode::PriorityLanes => tokio::spawn(run(
+                service,
+                high_lane,
+                low_lane,
+                config.max_consecutive_high_priority,
+            )),
+            SchedulingMode::EarliestDeadlineFirst => tokio::spawn(run_by_deadline(
+                service,
+                high_lane,
+                low_lane,
+                DeadlineQueue::new(2 * capacity, config.default_proving_time),
+            )),
+        };
+
+        Self { high, low }
+    }
+}
+
+impl<Request, Response> Service<(Priority, u32, Option<SystemTime>, Request)>
+    for PriorityScheduler<Request, Response>
+where
+    Request: Send + 'static,
+    Response: Send + 'static,
+{
+    type Response = Response;
+    type Error = BoxError;
+    type Future = ResponseFuture<Response>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // The requests wait for room in their lane once called.
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(
+        &mut self,
+        (priority, network_id, deadline, request): (Priority, u32, Option<SystemTime>, Request),
+    ) -> Self::Future {
+        let lane = match priority {
+            Priority::High => self.high.clone(),
+            Priority::Low => self.low.clone(),
+        };
+
+        Box::pin(async move {
+            let (respond, response) = oneshot::channel();
+            // Counted once sure to be queued, the wait for room in the lane
+            // being cancellable.
+            let permit = lane.reserve().await.map_err(|_| Closed)?;
+            QUEUE_DEPTH.add(1, &queue_labels(priority, network_id));
+            permit.send(Message {
+                network_id,
+                deadline,
+                request,
+                respond,
+            });
+            let response = response.await.map_err(|_| Closed)?;
+
+            response.await
+        })
+    }
+}
+
+async fn run<S, Request, Response>(
+    mut service: S,
+    mut high_lane: Lane<Request, Response>,
+    mut low_lane: Lane<Request, Response>,
+    max_consecutive_high: NonZeroUsize,
+) where
+    S: Service<Request, Response = Response>,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    // High priority requests dispatched in a row while the low priority lane
+    // was not empty.
+    let mut consecutive_high = 0;
+
+    loop {
+        // The request is picked once the service has capacity, such that a
+        // high priority request received meanwhile is picked first.
+        if let Err(error) = service.ready().await {
+            error!(error = %error.into(), "Proof executor failed, closing the scheduler");
+            return;
+        }
+
+        let starving = consecutive_high >= max_consecutive_high.get();
+        let picked = next(&mut high_lane, &mut low_lane, |high_lane, low_lane| {
+            pick_by_priority(high_lane, low_lane, starving)
+        });
+        let Some((priority, message)) = picked.await else {
+            debug!("All the handles to the scheduler are dropped, stopping");
+            return;
+        };
+        match priority {
+            Priority::High if !low_lane.is_empty() => consecutive_high += 1,
+            Priority::High => consecutive_high = 0,
+            Priority::Low => {
+                if starving {
+                    debug!("Dispatching a low priority request to prevent its starvation");
+                }
+                consecutive_high = 0;
+            }
+        }
+
+        let response = service.call(message.request);
+        // Dropped if the request is cancelled meanwhile.
+        _ = message
+            .respond
+            .send(Box::pin(async move { response.await.map_err(Into::into) }));
+    }
+}
+
+async fn run_by_deadline<S, Request, Response>(
+    mut service: S,
+    mut high_lane: Lane<Request, Response>,
+    mut low_lane: Lane<Request, Response>,
+    mut queue: DeadlineQueue<Request, Response>,
+) where
+    S: Service<Request, Response = Response>,
+    S::Error: Into<BoxError>,
+    S::Future: Send + 'static,
+{
+    loop {
+        if let Err(error) = service.ready().await {
+            error!(error = %error.into(), "Proof executor failed, closing the scheduler");
+            return;
+        }
+
+        let picked = next(&mut high_lane, &mut low_lane, |high_lane, low_lane| {
+            queue.pop(high_lane, low_lane)
+        });
+        let Some((_, message)) = picked.await else {
+            debug!("All the handles to the scheduler are dropped, stopping");
+            return;
+        };
+
+        // Timed up to the proof, such that the next requests of the network
+        // are dispatched in time.
+        let (network_id, proving_times) = (message.network_id, queue.proving_times.clone());
+        let started = Instant::now();
+        let response = service.call(message.request);
+        _ = message.respond.send(Box::pin(async move {
+            let response = response.await.map_err(Into::into);
+            if response.is_ok() {
+                proving_times
+                    .lock()
+                    .expect("proving times lock poisoned")
+                    .record(network_id, started.elapsed());
+            }
+
+            response
+        }));
+    }
+}
+
+/// Picks the next queued request, from the low priority lane first if it is
+/// starving.
+fn pick_by_priority<Request, Response>(
+    high_lane: &mut Lane<Request, Response>,
+    low_lane: &mut Lane<Request, Response>,
+    starving: bool,
+) -> Option<(Priority, Message<Request, Response>)> {
+    match starving.then(|| low_lane.pop()).flatten() {
+        Some(message) => Some((Priority::Low, message)),
+        None => high_lane
+            .pop()
+            .map(|message| (Priority::High, message))
+            .or_else(|| low_lane.pop().map(|message| (Priority::Low, message))),
+    }
+}
+
+/// Picks the next request still awaited, among the queued ones first.
+async fn next<Request, Response>(
+    high_lane: &mut Lane<Request, Response>,
+    low_lane: &mut Lane<Request, Response>,
+    mut pick: impl FnMut(
+        &mut Lane<Request, Response>,
+        &mut Lane<Request, Response>,
+    ) -> Option<(Priority, Message<Request, Response>)>,
+) -> Option<(Priority, Message<Request, Response>)> {
+    loop {
+        let queued = pick(high_lane, low_lane);
+        // Both lanes being empty, the first request received is the only one
+        // waiting.
+        let next = match queued {
+            Some(next) => Some(next),
+            None => tokio::select! {
+                biased;
+                Some(message) = high_lane.receiver.recv() => Some((Priority::High, message)),
+                Some(message) = low_lane.receiver.recv() => Some((Priority::Low, message)),
+                else => None,
+            },
+        };
+
+        let (priority, message) = next?;
+        QUEUE_DEPTH.add(-1, &queue_labels(priority, message.network_id));
+        if !message.respond.is_closed() {
+            return Some((priority, message));
+        }
+    }
+}
+
+/// Lane of one priority, the received requests being queued by network.
+struct Lane<Request, Response> {
+    receiver: mpsc::Receiver<Message<Request, Response>>,
+    queue: FairQueue<Message<Request, Response>>,
+    capacity: usize,
+}
+
+impl<Request, Response> Lane<Request, Response> {
+    fn new(
+        receiver: mpsc::Receiver<Message<Request, Response>>,
+        capacity: usize,
+        network_weights: BTreeMap<u32, NonZeroU32>,
+    ) -> Self {
+        Self {
+            receiver,
+            queue: FairQueue::new(network_weights),
+            capacity,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.queue.is_empty() && self.receiver.is_empty()
+    }
+
+    /// Pops the next queued request, after queueing the ones received
+    /// meanwhile.
+    fn pop(&mut self) -> Option<Message<Request, Response>> {
+        while self.queue.len() < self.capacity {
+            let Ok(message) = self.receiver.try_recv() else {
+                break;
+            };
+            self.queue.push(message.network_id, message);
+        }
+
+        self.queue.pop()
+    }
+}
+
+/// Requests of both lanes, by the latest start of their proving to meet their
+/// deadline.
+struct DeadlineQueue<Request, Response> {
+    queue: BTreeMap<Urgency, Message<Request, Response>>,
+    capacity: usize,
+    proving_times: Arc<Mutex<ProvingTimes>>,
+    /// Number of requests queued so far, ordering the requests otherwise
+    /// equally urgent by arrival.
+    received: u64,
+}
+
+/// Order of the requests in the [`DeadlineQueue`]: those without a deadline
+/// last, then by latest start, priority and arrival.
+type Urgency = (bool, Option<SystemTime>, Priority, u64);
+
+impl<Request, Response> DeadlineQueue<Request, Response> {
+    fn new(capacity: usize, default_proving_time: Duration) -> Self {
+        Self {
+            queue: BTreeMap::new(),
+            capacity,
+            proving_times: Arc::new(Mutex::new(ProvingTimes::new(default_proving_time))),
+            received: 0,
+        }
+    }
+
+    /// Pops the most urgent request, after queueing the ones received
+    /// meanwhile.
+    fn pop(
+        &mut self,
+        high_lane: &mut Lane<Request, Response>,
+        low_lane: &mut Lane<Request, Response>,
+    ) -> Option<(Priority, Message<Request, Response>)> {
+        for (priority, lane) in [(Priority::High, high_lane), (Priority::Low, low_lane)] {
+            while self.queue.len() < self.capacity {
+                let Ok(message) = lane.receiver.try_recv() else {
+                    break;
+                };
+                self.push(priority, message);
+            }
+        }
+
+        self.queue
+            .pop_first()
+            .map(|((_, _, priority, _), message)| (priority, message))
+    }
+
+    fn push(&mut self, priority: Priority, message: Message<Request, Response>) {
+        let latest_start = message.deadline.map(|deadline| {
+            let proving_time = self
+                .proving_times
+                .lock()
+                .expect("proving times lock poisoned")
+                .get(message.network_id);
+            deadline.checked_sub(proving_time).unwrap_or(UNIX_EPOCH)
+        });
+        self.received += 1;
+
+        let urgency = (
+            latest_start.is_none(),
+            latest_start,
+            priority,
+            self.received,
+        );
+        self.queue.insert(urgency, message);
+    }
+}
+
+/// Moving averages of the proving time of the requests of the networks.
+struct ProvingTimes {
+    default: Duration,
+    averages: HashMap<u32, Duration>,
+}
+
+impl ProvingTimes {
+    fn new(default: Duration) -> Self {
+        Self {
+            default,
+            averages: HashMap::new(),
+        }
+    }
+
+    fn get(&self, network_id: u32) -> Duration {
+        self.averages
+            .get(&network_id)
+            .copied()
+            .unwrap_or(self.default)
+    }
+
+    fn record(&mut self, network_id: u32, proving_time: Duration) {
+        let average = self.averages.entry(network_id).or_insert(proving_time);
+        *average =
+            average.mul_f64(1.0 - PROVING_TIME_WEIGHT) + proving_time.mul_f64(PROVING_TIME_WEIGHT);
+    }
+}
+
+/// Queue taking its items from the networks in a weighted round-robin.
+struct FairQueue<T> {
+    queues: HashMap<u32, VecDeque<T>>,
+    /// The networks having queued items, in the order of their turn.
+    turns: VecDeque<u32>,
+    /// The items left to take from the network whose turn it is.
+    credit: u32,
+    weights: BTreeMap<u32, NonZeroU32>,
+    len: usize,
+}
+
+impl<T> FairQueue<T> {
+    fn new(weights: BTreeMap<u32, NonZeroU32>) -> Self {
+        Self {
+            queues: HashMap::new(),
+            turns: VecDeque::new(),
+            credit: 0,
+            weights,
+            len: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn push(&mut self, network_id: u32, item: T) {
+        let queue = self.queues.entry(network_id).or_default();
+        if queue.is_empty() {
+            self.turns.push_back(network_id);
+        }
+        queue.push_back(item);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        let network_id = *self.turns.front()?;
+        if self.credit == 0 {
+            self.credit = self
+                .weights
+                .get(&network_id)
+                .map_or(1, |weight| weight.get());
+        }
+
+        let queue = self.queues.get_mut(&network_id)?;
+        let item = queue.pop_front()?;
+        self.len -= 1;
+        self.credit -= 1;
+
+        if queue.is_empty() {
+            self.queues.remove(&network_id);
+            self.turns.pop_front();
+            self.credit = 0;
+        } else if self.credit == 0 {
+            self.turns.rotate_left(1);
+        }
+
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::Semaphore;
+
+    use super::*;
+
+    fn priority_lanes(
+        max_consecutive_high: usize,
+        network_weights: BTreeMap<u32, NonZeroU32>,
+    ) -> SchedulerConfig {
+        SchedulerConfig {
+            max_consecutive_high_priority: NonZeroUsize::new(max_consecutive_high).unwrap(),
+            network_weights,
+            ..Default::default()
+        }
+    }
+
+    /// Scheduler over a service proving one request at a time, each request
+    /// waiting for a permit of the returned semaphore to complete, and
+    /// recording the order of the dispatched requests.
+    fn scheduler(
+        config: SchedulerConfig,
+    ) -> (
+        PriorityScheduler<u32, u32>,
+        Arc<Semaphore>,
+        Arc<Mutex<Vec<u32>>>,
+    ) {
+        let gate = Arc::new(Semaphore::new(0));
+        let dispatched = Arc::new(Mutex::new(Vec::new()));
+
+        let service = tower::ServiceBuilder::new()
+            .concurrency_limit(1)
+            .service_fn({
+                let gate = gate.clone();
+                let dispatched = dispatched.clone();
+                move |request: u32| {
+                    dispatched
+                        .lock()
+                        .expect("dispatched requests lock poisoned")
+                        .push(request);
+                    let gate = gate.clone();
+                    async move {
+                        gate.acquire().await.unwrap().forget();
+                        Ok::<_, BoxError>(request)
+                    }
+                }
+            });
+        let scheduler = PriorityScheduler::new(service, 10, &config);
+
+        (scheduler, gate, dispatched)
+    }
+
+    async fn wait_for_dispatched(dispatched: &Mutex<Vec<u32>>, count: usize) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while dispatched
+                .lock()
+                .expect("dispatched requests lock poisoned")
+                .len()
+                < count
+            {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("the requests are dispatched");
+    }
+
+    /// Sends the requests of a same network while the service is busy with a
+    /// first one, then returns the order in which they are dispatched.
+    async fn dispatch_order(max_consecutive_high: usize, requests: &[(Priority, u32)]) -> Vec<u32> {
+        let requests: Vec<_> = requests
+            .iter()
+            .map(|&(priority, request)| (priority, 0, request))
+            .collect();
+
+        networks_dispatch_order(max_consecutive_high, BTreeMap::new(), &requests).await
+    }
+
+    /// Sends the requests of their network while the service is busy with a
+    /// first one, then returns the order in which they are dispatched.
+    async fn networks_dispatch_order(
+        max_consecutive_high: usize,
+        network_weights: BTreeMap<u32, NonZeroU32>,
+        requests: &[(Priority, u32, u32)],
+    ) -> Vec<u32> {
+        let requests: Vec<_> = requests
+            .iter()
+            .map(|&(priority, network_id, request)| (priority, network_id, None, request))
+            .collect();
+
+        scheduled_order(
+            priority_lanes(max_consecutive_high, network_weights),
+            &requests,
+        )
+        .await
+    }
+
+    /// Sends the requests while the service is busy with a first one, then
+    /// returns the order in which they are dispatched.
+    async fn scheduled_order(
+        config: SchedulerConfig,
+        requests: &[(Priority, u32, Option<SystemTime>, u32)],
+    ) -> Vec<u32> {
+        let (scheduler, gate, dispatched) = scheduler(config);
+
+        let busy = tokio::spawn(scheduler.clone().oneshot((Priority::Low, 0, None, 0)));
+        wait_for_dispatched(&dispatched, 1).await;
+        let mut handles = vec![busy];
+        for &request in requests {
+            handles.push(tokio::spawn(scheduler.clone().oneshot(request)));
+            // Lets the request enter its lane before the next one.
+            tokio::task::yield_now().await;
+        }
+
+        for count in 2..=requests.len() + 1 {
+            gate.add_permits(1);
+            wait_for_dispatched(&dispatched, count).await;
+        }
+        gate.add_permits(1);
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        let order = dispatched
+            .lock()
+            .expect("dispatched requests lock poisoned")
+            .clone();
+        order
+    }
+
+    #[tokio::test]
+    async fn high_priority_requests_are_dispatched_first() {
+        let order = dispatch_order(
+            10,
+            &[
+                (Priority::Low, 1),
+                (Priority::Low, 2),
+                (Priority::High, 3),
+                (Priority::High, 4),
+            ],
+        )
+        .await;
+
+        assert_eq!(order, [0, 3, 4, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn low_priority_requests_are_not_starved() {
+        let order = dispatch_order(
+            2,
+            &[
+                (Priority::Low, 1),
+                (Priority::Low, 2),
+                (Priority::High, 3),
+                (Priority::High, 4),
+                (Priority::High, 5),
+                (Priority::High, 6),
+                (Priority::High, 7),
+            ],
+        )
+        .await;
+
+        assert_eq!(order, [0, 3, 4, 1, 5, 6, 2, 7]);
+    }
+
+    #[tokio::test]
+    async fn networks_take_weighted_turns() {
+        let order = networks_dispatch_order(
+            10,
+            BTreeMap::from([(1, NonZeroU32::new(2).unwrap())]),
+            &[
+                (Priority::Low, 1, 1),
+                (Priority::Low, 1, 2),
+                (Priority::Low, 1, 3),
+                (Priority::Low, 1, 4),
+                (Priority::Low, 2, 5),
+                (Priority::Low, 2, 6),
+                (Priority::Low, 3, 7),
+                (Priority::High, 2, 8),
+            ],
+        )
+        .await;
+
+        assert_eq!(order, [0, 8, 1, 2, 5, 7, 3, 4, 6]);
+    }
+
+    #[tokio::test]
+    async fn cancelled_requests_are_skipped() {
+        let (scheduler, gate, dispatched) = scheduler(priority_lanes(10, BTreeMap::new()));
+
+        let busy = tokio::spawn(scheduler.clone().oneshot((Priority::High, 0, None, 0)));
+        wait_for_dispatched(&dispatched, 1).await;
+        let cancelled = tokio::spawn(scheduler.clone().oneshot((Priority::High, 0, None, 1)));
+        tokio::task::yield_now().await;
+        cancelled.abort();
+        let awaited = tokio::spawn(scheduler.clone().oneshot((Priority::Low, 0, None, 2)));
+        tokio::task::yield_now().await;
+
+        gate.add_permits(2);
+        assert_eq!(awaited.await.unwrap().unwrap(), 2);
+        busy.await.unwrap().unwrap();
+        assert_eq!(
+            *dispatched
+                .lock()
+                .expect("dispatched requests lock poisoned"),
+            [0, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn earliest_deadlines_are_dispatched_first() {
+        let config = SchedulerConfig {
+            mode: SchedulingMode::EarliestDeadlineFirst,
+            ..Default::default()
+        };
+        let now = SystemTime::now();
+        let in_minutes = |minutes| Some(now + Duration::from_secs(60 * minutes));
+
+        let order = scheduled_order(
+            config,
+            &[
+                (Priority::High, 1, None, 1),
+                (Priority::Low, 1, in_minutes(30), 2),
+                (Priority::Low, 2, in_minutes(10), 3),
+                (Priority::Low, 1, None, 4),
+                (Priority::High, 1, in_minutes(20), 5),
+            ],
+        )
+        .await;
+
+        assert_eq!(order, [0, 3, 5, 2, 1, 4]);
+    }
+
+    #[test]
+    fn deadlines_account_for_the_proving_time() {
+        let (high, high_lane) = mpsc::channel(10);
+        let (low, low_lane) = mpsc::channel(10);
+        let mut high_lane = Lane::new(high_lane, 10, BTreeMap::new());
+        let mut low_lane = Lane::new(low_lane, 10, BTreeMap::new());
+        let mut queue = DeadlineQueue::<u32, u32>::new(20, Duration::from_secs(60));
+        queue
+            .proving_times
+            .lock()
+            .expect("proving times lock poisoned")
+            .record(1, Duration::from_secs(20 * 60));
+
+        let now = SystemTime::now();
+        for (lane, network_id, minutes, request) in
+            [(&low, 1, 30, 1), (&high, 2, 15, 2), (&low, 1, 10, 3)]
+        {
+            let sent = lane.try_send(Message {
+                network_id,
+                deadline: Some(now + Duration::from_secs(60 * minutes)),
+                request,
+                respond: oneshot::channel().0,
+            });
+            assert!(sent.is_ok());
+        }
+
+        // The requests of the slow network have to start before the one of the
+        // other network, despite the later deadline of one of them.
+        let order: Vec<_> = std::iter::from_fn(|| queue.pop(&mut high_lane, &mut low_lane))
+            .map(|(priority, message)| (priority, message.request))
+            .collect();
+        assert_eq!(
+            order,
+            [(Priority::Low, 3), (Priority::Low, 1), (Priority::High, 2)]
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use std::{str::FromStr, time::Duration};
 
-use prover_utils::from_env_or_default;
+use prover_utils::{from_env_or_default, secret::Secret};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr, DurationSeconds};
 use url::Url;
@@ -14,12 +15,13 @@ const DEFAULT_PROPOSER_SERVICE_ENDPOINT: &str = "http://proposer-mock-rpc:3000";
 const DEFAULT_SP1_CLUSTER_ENDPOINT: &str = "https://rpc.production.succinct.xyz/";
 
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ProposerClientConfig {
     /// The proposer service http endpoint.
     #[serde(default = "default_proposer_service_endpoint")]
     #[serde_as(as = "DisplayFromStr")]
+    #[schemars(with = "String")]
     pub proposer_endpoint: GrpcUri,
 
     /// The sp1 proving cluster endpoint.
@@ -29,12 +31,20 @@ pub struct ProposerClientConfig {
     /// Proposer request timeout in seconds.
     #[serde(default = "default_request_timeout")]
     #[serde_as(as = "DurationSeconds<u64>")]
+    #[schemars(with = "u64")]
     pub request_timeout: Duration,
 
     /// Proving timeout in seconds.
     #[serde(default = "default_proving_timeout")]
     #[serde_as(as = "DurationSeconds<u64>")]
+    #[schemars(with = "u64")]
     pub proving_timeout: Duration,
+
+    /// Private key of the proving cluster account, e.g.
+    /// `file:/run/secrets/network-key`. Read from `NETWORK_PRIVATE_KEY` when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_private_key: Option<Secret>,
 }
 
 impl Default for ProposerClientConfig {
@@ -44,6 +54,7 @@ impl Default for ProposerClientConfig {
             sp1_cluster_endpoint: default_sp1_cluster_endpoint(),
             request_timeout: default_request_timeout(),
             proving_timeout: default_proving_timeout(),
+            network_private_key: None,
         }
     }
 }
@@ -18,6 +18,21 @@ pub enum Error {
     Connect(#[source] tonic::transport::Error),
 }
 
+impl Error {
+    /// Whether the proposer rejected the requested range as out of range,
+    /// e.g. as too large to be aggregated in one proof. The other rejections,
+    /// e.g. of invalid arguments, don't depend on the range.
+    pub fn is_range_rejected(&self) -> bool {
+        match self {
+            Error::Requesting(error) => match error.as_ref() {
+                ProofRequestError::Grpc(status) => status.code() == tonic::Code::OutOfRange,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ProofRequestError {
     #[error("Cannot parse grpc response")]
@@ -2,6 +2,7 @@ use std::time::Duration;
 
 use alloy_primitives::B256;
 use anyhow::Context;
+use prover_utils::secret::Secret;
 use sp1_sdk::{
     NetworkProver, Prover, SP1ProofWithPublicValues, SP1ProvingKey, SP1VerificationError,
     SP1VerifyingKey,
@@ -32,12 +33,22 @@ impl AggregationProver for NetworkProver {
     }
 }
 
-pub fn new_network_prover<T: AsRef<str>>(endpoint: T) -> anyhow::Result<NetworkProver> {
+/// Network prover authenticated with `private_key`, or with the
+/// `NETWORK_PRIVATE_KEY` environment variable when unset.
+pub fn new_network_prover<T: AsRef<str>>(
+    endpoint: T,
+    private_key: Option<&Secret>,
+) -> anyhow::Result<NetworkProver> {
+    let private_key = match private_key {
+        Some(private_key) => private_key.expose().to_string(),
+        None => std::env::var("NETWORK_PRIVATE_KEY").context(
+            "Failed to get NETWORK_PRIVATE_KEY, when building NetworkProver for proposer-client",
+        )?,
+    };
+
     Ok(sp1_sdk::ProverClient::builder()
         .network()
         .rpc_url(endpoint.as_ref())
-        .private_key(&std::env::var("NETWORK_PRIVATE_KEY").context(
-            "Failed to get NETWORK_PRIVATE_KEY, when building NetworkProver for proposer-client",
-        )?)
+        .private_key(&private_key)
         .build())
 }
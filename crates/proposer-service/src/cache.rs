@@ -0,0 +1,486 @@
+//! Cache of the aggregation proofs acquired from the proposer, keyed by the
+//! requested range, so that a retried aggchain proof request reuses the
+//! aggregation proof already paid for.
+//!
+//! The proofs are addressed by the blake3 hash of their encoding, the ranges
+//! served with the same proof, e.g. overlapping ranges shrunk by the proposer
+//! or replays, sharing a single copy of it in memory and on disk. The disk
+//! directory holds:
+//!
+//! - `blobs/<hash>.proof`, the proofs, compressed then encrypted when
+//!   configured;
+//! - `refs/<range>.ref`, the hash of the proof of each requested range.
+//!
+//! The references beyond the retention, then the proofs referenced by no
+//! range, are removed by [`collect_garbage`], run in the background by the
+//! prover or with `aggkit-prover gc-proof-cache`.
+use std::{
+    collections::{hash_map, HashMap, HashSet, VecDeque},
+    ffi::OsStr,
+    fs::{DirEntry, Metadata},
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use aggkit_prover_types::{codec::Codec, compression};
+use alloy_primitives::B256;
+use proposer_client::FepProposerRequest;
+use prover_utils::{
+    encryption::{self, Cipher},
+    retention::RetentionPolicy,
+};
+use serde::{Deserialize, Serialize};
+use sp1_sdk::SP1ProofWithPublicValues;
+use tracing::warn;
+
+use crate::config::ProofCacheConfig;
+
+/// Directory of the proofs, by hash.
+const BLOBS_DIR: &str = "blobs";
+
+/// Directory of the references of the ranges to their proof.
+const REFS_DIR: &str = "refs";
+
+const PROOF_EXTENSION: &str = "proof";
+const REF_EXTENSION: &str = "ref";
+
+/// Age under which the unreferenced proofs are kept by the garbage
+/// collection, their reference being possibly about to be written.
+const GC_GRACE_PERIOD: Duration = Duration::from_secs(10 * 60);
+
+/// Range of an aggregation proof request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SpanProofKey {
+    pub last_proven_block: u64,
+    pub requested_end_block: u64,
+    pub l1_block_hash: B256,
+}
+
+impl From<&FepProposerRequest> for SpanProofKey {
+    fn from(request: &FepProposerRequest) -> Self {
+        Self {
+            last_proven_block: request.last_proven_block,
+            requested_end_block: request.requested_end_block,
+            l1_block_hash: request.l1_block_hash,
+        }
+    }
+}
+
+impl SpanProofKey {
+    fn file_stem(&self) -> String {
+        format!(
+            "{}-{}-{}",
+            self.last_proven_block, self.requested_end_block, self.l1_block_hash
+        )
+    }
+
+    fn from_file_stem(stem: &str) -> Option<Self> {
+        let mut parts = stem.splitn(3, '-');
+
+        Some(Self {
+            last_proven_block: parts.next()?.parse().ok()?,
+            requested_end_block: parts.next()?.parse().ok()?,
+            l1_block_hash: parts.next()?.parse().ok()?,
+        })
+    }
+}
+
+/// Aggregation proof acquired from the proposer, once verified.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CachedSpanProof {
+    pub proof: SP1ProofWithPublicValues,
+
+    /// Range actually proven, the proposer possibly ending it before the
+    /// requested end block.
+    pub last_proven_block: u64,
+    pub end_block: u64,
+}
+
+/// Proof of a range, as exported to the snapshots of the prover.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RangeProof {
+    pub range: SpanProofKey,
+    pub proof: CachedSpanProof,
+}
+
+#[derive(Default)]
+struct Entries {
+    /// Hash of the proof of each range.
+    keys: HashMap<SpanProofKey, blake3::Hash>,
+    /// Proofs by hash, along with the number of ranges referencing them.
+    proofs: HashMap<blake3::Hash, (Arc<CachedSpanProof>, usize)>,
+    /// Keys by insertion order, the oldest first.
+    order: VecDeque<SpanProofKey>,
+}
+
+impl Entries {
+    fn get(&self, key: &SpanProofKey) -> Option<Arc<CachedSpanProof>> {
+        let hash = self.keys.get(key)?;
+
+        self.proofs.get(hash).map(|(proof, _)| proof.clone())
+    }
+
+    /// Drops a reference to the proof, removing it once unreferenced.
+    fn release(&mut self, hash: blake3::Hash) {
+        if let hash_map::Entry::Occupied(mut entry) = self.proofs.entry(hash) {
+            entry.get_mut().1 -= 1;
+            if entry.get().1 == 0 {
+                entry.remove();
+            }
+        }
+    }
+}
+
+pub struct SpanProofCache {
+    capacity: usize,
+    disk_dir: Option<PathBuf>,
+    codec: Codec,
+    compression_level: Option<i32>,
+    cipher: Option<Cipher>,
+    entries: Mutex<Entries>,
+}
+
+impl SpanProofCache {
+    pub fn new(config: &ProofCacheConfig) -> Self {
+        Self {
+            capacity: config.capacity,
+            disk_dir: config.disk_dir.clone(),
+            codec: config.codec,
+            compression_level: config.compression_level,
+            cipher: config.encryption.as_ref().map(Cipher::new),
+            entries: Default::default(),
+        }
+    }
+
+    /// Returns the proof of the range, looked up in memory then on disk.
+    pub async fn get(&self, key: &SpanProofKey) -> Option<Arc<CachedSpanProof>> {
+        if let Some(proof) = self
+            .entries
+            .lock()
+            .expect("proof cache lock poisoned")
+            .get(key)
+        {
+            return Some(proof);
+        }
+
+        let dir = self.disk_dir.clone()?;
+        let (codec, cipher, range) = (self.codec, self.cipher.clone(), *key);
+        let read = move || read(codec, cipher.as_ref(), &dir, &range);
+        let (hash, proof) = match tokio::task::spawn_blocking(read)
+            .await
+            .unwrap_or_else(|error| Err(error.into()))
+        {
+            Ok(Some(found)) => found,
+            Ok(None) => return None,
+            Err(error) => {
+                warn!(?key, ?error, "Unable to read the cached aggregation proof");
+                return None;
+            }
+        };
+        let proof = Arc::new(proof);
+        self.remember(*key, hash, proof.clone());
+
+        Some(proof)
+    }
+
+    /// Keeps the proof of the range, in memory and on disk when configured.
+    pub async fn insert(&self, key: SpanProofKey, proof: CachedSpanProof) {
+        let proof = Arc::new(proof);
+        let (codec, level, dir) = (self.codec, self.compression_level, self.disk_dir.clone());
+        let cipher = self.cipher.clone();
+        let encoded = proof.clone();
+        let hash = tokio::task::spawn_blocking(move || {
+            let bytes = codec.encode(&*encoded)?;
+            let hash = blake3::hash(&bytes);
+            if let Some(dir) = dir {
+                if let Err(error) = write(level, cipher.as_ref(), &dir, &key, hash, bytes) {
+                    warn!(?key, ?error, "Unable to write the cached aggregation proof");
+                }
+            }
+
+            anyhow::Ok(hash)
+        })
+        .await
+        .unwrap_or_else(|error| Err(error.into()));
+
+        match hash {
+            Ok(hash) => self.remember(key, hash, proof),
+            Err(error) => warn!(
+                ?key,
+                ?error,
+                "Unable to encode the cached aggregation proof"
+            ),
+        }
+    }
+
+    /// Returns the proofs of all the ranges, in memory and on disk, the
+    /// unreadable ones being skipped.
+    pub async fn export(&self) -> io::Result<Vec<RangeProof>> {
+        let mut proofs: Vec<RangeProof> = {
+            let entries = self.entries.lock().expect("proof cache lock poisoned");
+            entries
+                .order
+                .iter()
+                .filter_map(|range| {
+                    Some(RangeProof {
+                        range: *range,
+                        proof: CachedSpanProof::clone(&entries.get(range)?),
+                    })
+                })
+                .collect()
+        };
+
+        let Some(dir) = self.disk_dir.clone() else {
+            return Ok(proofs);
+        };
+        let (codec, cipher) = (self.codec, self.cipher.clone());
+        let in_memory: HashSet<SpanProofKey> = proofs.iter().map(|proof| proof.range).collect();
+        let stored = tokio::task::spawn_blocking(move || {
+            let mut stored = Vec::new();
+            for range in stored_ranges(&dir)? {
+                if in_memory.contains(&range) {
+                    continue;
+                }
+                match read(codec, cipher.as_ref(), &dir, &range) {
+                    Ok(Some((_, proof))) => stored.push(RangeProof { range, proof }),
+                    Ok(None) => {}
+                    Err(error) => {
+                        warn!(
+                            ?range,
+                            ?error,
+                            "Unable to read the cached aggregation proof"
+                        )
+                    }
+                }
+            }
+
+            io::Result::Ok(stored)
+        })
+        .await
+        .map_err(io::Error::other)??;
+        proofs.extend(stored);
+
+        Ok(proofs)
+    }
+
+    fn remember(&self, key: SpanProofKey, hash: blake3::Hash, proof: Arc<CachedSpanProof>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().expect("proof cache lock poisoned");
+        match entries.keys.insert(key, hash) {
+            Some(previous) => entries.release(previous),
+            None => entries.order.push_back(key),
+        }
+        entries.proofs.entry(hash).or_insert((proof, 0)).1 += 1;
+        while entries.order.len() > self.capacity {
+            if let Some(oldest) = entries.order.pop_front() {
+                if let Some(hash) = entries.keys.remove(&oldest) {
+                    entries.release(hash);
+                }
+            }
+        }
+    }
+}
+
+/// Outcome of a garbage collection of the cache directory.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GarbageCollection {
+    /// References kept.
+    pub refs: usize,
+    /// References removed, beyond the retention.
+    pub expired_refs: usize,
+    /// Proofs kept, referenced or written too recently to tell.
+    pub kept_proofs: usize,
+    /// Proofs removed, referenced by no range.
+    pub removed_proofs: usize,
+    /// Size of the removed proofs.
+    pub freed_bytes: u64,
+}
+
+/// Removes the references beyond the retention, the most recently written
+/// ones being ranked first, then the proofs no longer referenced. Nothing is
+/// removed on a dry run, the outcome being reported as if.
+pub fn collect_garbage(
+    dir: &Path,
+    retention: &RetentionPolicy,
+    dry_run: bool,
+) -> io::Result<GarbageCollection> {
+    let now = SystemTime::now();
+    let remove = |path: &Path| {
+        if dry_run {
+            return Ok(());
+        }
+        match std::fs::remove_file(path) {
+            // Possibly collected by another process sharing the directory.
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    };
+    let mut collection = GarbageCollection::default();
+    let mut ref_counts: HashMap<blake3::Hash, usize> = HashMap::new();
+
+    let mut refs = Vec::new();
+    for entry in dir_entries(&dir.join(REFS_DIR))? {
+        let (path, metadata) = (entry.path(), entry.metadata()?);
+        if path.extension() == Some(OsStr::new(REF_EXTENSION)) {
+            refs.push((path, age(now, &metadata)));
+        } else if age(now, &metadata) > GC_GRACE_PERIOD {
+            // Left over by an interrupted write.
+            remove(&path)?;
+        }
+    }
+    refs.sort_by_key(|(_, age)| *age);
+
+    for (rank, (path, age)) in refs.into_iter().enumerate() {
+        if retention.expires(age, rank) {
+            remove(&path)?;
+            collection.expired_refs += 1;
+            continue;
+        }
+
+        match std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|hex| blake3::Hash::from_hex(hex.trim()).ok())
+        {
+            Some(hash) => *ref_counts.entry(hash).or_default() += 1,
+            None => warn!(path = %path.display(), "Ignoring the unreadable proof reference"),
+        }
+        collection.refs += 1;
+    }
+
+    for entry in dir_entries(&dir.join(BLOBS_DIR))? {
+        let (path, metadata) = (entry.path(), entry.metadata()?);
+        let referenced = path
+            .file_stem()
+            .and_then(OsStr::to_str)
+            .and_then(|hex| blake3::Hash::from_hex(hex).ok())
+            .is_some_and(|hash| ref_counts.contains_key(&hash));
+        if referenced || age(now, &metadata) <= GC_GRACE_PERIOD {
+            collection.kept_proofs += 1;
+            continue;
+        }
+
+        remove(&path)?;
+        collection.removed_proofs += 1;
+        collection.freed_bytes += metadata.len();
+    }
+
+    Ok(collection)
+}
+
+fn blob_path(dir: &Path, hash: &blake3::Hash) -> PathBuf {
+    dir.join(BLOBS_DIR)
+        .join(format!("{}.{PROOF_EXTENSION}", hash.to_hex()))
+}
+
+fn ref_path(dir: &Path, key: &SpanProofKey) -> PathBuf {
+    dir.join(REFS_DIR)
+        .join(format!("{}.{REF_EXTENSION}", key.file_stem()))
+}
+
+/// Ranges referenced on disk.
+fn stored_ranges(dir: &Path) -> io::Result<Vec<SpanProofKey>> {
+    Ok(dir_entries(&dir.join(REFS_DIR))?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension() == Some(OsStr::new(REF_EXTENSION)))
+        .filter_map(|path| {
+            path.file_stem()
+                .and_then(OsStr::to_str)
+                .and_then(SpanProofKey::from_file_stem)
+        })
+        .collect())
+}
+
+fn read(
+    codec: Codec,
+    cipher: Option<&Cipher>,
+    dir: &Path,
+    key: &SpanProofKey,
+) -> anyhow::Result<Option<(blake3::Hash, CachedSpanProof)>> {
+    let Some(hex) = read_file(&ref_path(dir, key))? else {
+        return Ok(None);
+    };
+    let hash = blake3::Hash::from_hex(String::from_utf8_lossy(&hex).trim())?;
+    // Possibly removed by the garbage collection since.
+    let Some(bytes) = read_file(&blob_path(dir, &hash))? else {
+        return Ok(None);
+    };
+
+    let bytes = encryption::decrypt(&bytes, cipher)?;
+    let bytes = compression::decompress(&bytes)?;
+    anyhow::ensure!(
+        blake3::hash(&bytes) == hash,
+        "The cached aggregation proof {hash} is corrupted"
+    );
+
+    Ok(Some((hash, codec.decode(&bytes)?)))
+}
+
+fn write(
+    compression_level: Option<i32>,
+    cipher: Option<&Cipher>,
+    dir: &Path,
+    key: &SpanProofKey,
+    hash: blake3::Hash,
+    bytes: Vec<u8>,
+) -> anyhow::Result<()> {
+    let blob = blob_path(dir, &hash);
+    match std::fs::File::options().write(true).open(&blob) {
+        // Already written for another range, touched for the garbage
+        // collection not to remove it before referenced.
+        Ok(file) => file.set_modified(SystemTime::now())?,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            let bytes = compression::compress(bytes, compression_level)?;
+            write_atomically(&blob, &encryption::encrypt(bytes, cipher)?)?;
+        }
+        Err(error) => return Err(error.into()),
+    }
+
+    write_atomically(&ref_path(dir, key), hash.to_hex().as_bytes())?;
+
+    Ok(())
+}
+
+fn read_file(path: &Path) -> io::Result<Option<Vec<u8>>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Writes the file aside then moves it, for a partially written file not to
+/// be read.
+fn write_atomically(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, bytes)?;
+    std::fs::rename(partial, path)
+}
+
+fn dir_entries(dir: &Path) -> io::Result<Vec<DirEntry>> {
+    match std::fs::read_dir(dir) {
+        Ok(entries) => entries.collect(),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Time since the last modification, zero when unknown.
+fn age(now: SystemTime, metadata: &Metadata) -> Duration {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|modified| now.duration_since(modified).ok())
+        .unwrap_or_default()
+}
@@ -1,8 +1,13 @@
+use std::path::PathBuf;
+
+use aggkit_prover_types::codec::Codec;
 use proposer_client::config::ProposerClientConfig;
 use prover_alloy::L1RpcEndpoint;
+use prover_utils::{encryption::EncryptionConfig, retention::RetentionPolicy};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Default, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct ProposerServiceConfig {
     #[serde(default)] // bool::default() is false
@@ -12,4 +17,92 @@ pub struct ProposerServiceConfig {
 
     /// JSON-RPC endpoint of the l1 node.
     pub l1_rpc_endpoint: L1RpcEndpoint,
+
+    /// Cache of the aggregation proofs acquired from the proposer, disabled
+    /// when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_cache: Option<ProofCacheConfig>,
+
+    /// Negotiation of the range with the proposer when rejected.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub range_negotiation: RangeNegotiationConfig,
+}
+
+/// Negotiation of the range of the aggregation proofs, the range rejected by
+/// the proposer being halved until accepted.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct RangeNegotiationConfig {
+    /// Number of times a rejected range is halved before giving up, the
+    /// rejection being returned at once when zero.
+    #[serde(default = "default_max_splits")]
+    pub max_splits: u32,
+}
+
+impl Default for RangeNegotiationConfig {
+    fn default() -> Self {
+        Self {
+            max_splits: default_max_splits(),
+        }
+    }
+}
+
+/// Cache of the aggregation proofs by requested range, so that a retried
+/// request reuses the proof already paid for.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProofCacheConfig {
+    /// Number of proofs kept in memory, the oldest being evicted first.
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+
+    /// Directory where the proofs are also written, to be reused across
+    /// restarts, each distinct proof once. Kept in memory only when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disk_dir: Option<PathBuf>,
+
+    /// Encoding of the proofs written to disk.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub codec: Codec,
+
+    /// Zstd level the proofs written to disk are compressed with,
+    /// uncompressed when unset. The compressed and uncompressed proofs are
+    /// both read, whatever the level.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression_level: Option<i32>,
+
+    /// Keys the proofs written to disk are encrypted with, once compressed.
+    /// Plain when unset, the plain proofs being read whatever the keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<EncryptionConfig>,
+
+    /// Ranges whose proof is kept on disk, by age and by count, the proofs
+    /// of the other ones being removed by the garbage collection.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub retention: RetentionPolicy,
+}
+
+impl Default for ProofCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: default_capacity(),
+            disk_dir: None,
+            codec: Codec::default(),
+            compression_level: None,
+            encryption: None,
+            retention: RetentionPolicy::default(),
+        }
+    }
+}
+
+const fn default_capacity() -> usize {
+    16
+}
+
+const fn default_max_splits() -> u32 {
+    4
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
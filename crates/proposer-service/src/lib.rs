@@ -14,13 +14,17 @@ use proposer_client::{
     mock_grpc_prover::MockGrpcProver,
     network_prover::new_network_prover,
     rpc::{AggregationProofProposerRequest, ProposerRpcClient},
-    FepProposerRequest,
+    FepProposerRequest, RequestId,
 };
 use sp1_prover::SP1VerifyingKey;
-use sp1_sdk::NetworkProver;
-use tracing::{debug, info};
+use sp1_sdk::{NetworkProver, SP1ProofWithPublicValues};
+use tracing::{debug, info, warn};
 
-use crate::config::ProposerServiceConfig;
+use crate::{
+    cache::{CachedSpanProof, SpanProofCache, SpanProofKey},
+    config::ProposerServiceConfig,
+    pending::{PendingProposerRequest, PendingRequests},
+};
 
 type AggregationProof = Box<sp1_core_executor::SP1ReduceProof<sp1_prover::InnerSC>>;
 
@@ -28,12 +32,17 @@ type AggregationProof = Box<sp1_core_executor::SP1ReduceProof<sp1_prover::InnerS
 pub struct ProposerResponse {
     pub aggregation_proof: AggregationProof,
     pub last_proven_block: u64,
+    /// End block of the aggregation proof, before the requested one when the
+    /// proposer covered only part of the range.
     pub end_block: u64,
+    pub requested_end_block: u64,
     pub public_values: AggregationProofPublicValues,
 }
 
+pub mod cache;
 pub mod config;
 pub mod error;
+pub mod pending;
 
 #[cfg(test)]
 mod tests;
@@ -49,6 +58,15 @@ pub struct ProposerService<L1Rpc, ProposerClient> {
 
     /// Aggregated span proof verification key.
     aggregation_vkey: SP1VerifyingKey,
+
+    /// Aggregation proofs already acquired, when cached.
+    proof_cache: Option<Arc<SpanProofCache>>,
+
+    /// Aggregation proofs requested and not acquired yet.
+    pending_requests: Arc<PendingRequests>,
+
+    /// Number of times a range rejected by the proposer is halved.
+    max_range_splits: u32,
 }
 
 impl<L1Rpc, Prover>
@@ -79,6 +97,12 @@ where
                 Some(config.client.proving_timeout),
             )?),
             aggregation_vkey,
+            proof_cache: config
+                .proof_cache
+                .as_ref()
+                .map(|proof_cache| Arc::new(SpanProofCache::new(proof_cache))),
+            pending_requests: Default::default(),
+            max_range_splits: config.range_negotiation.max_splits,
         })
     }
 
@@ -88,6 +112,18 @@ where
     }
 }
 
+impl<L1Rpc, ProposerClient> ProposerService<L1Rpc, ProposerClient> {
+    /// Aggregation proofs already acquired, when cached.
+    pub fn proof_cache(&self) -> Option<&Arc<SpanProofCache>> {
+        self.proof_cache.as_ref()
+    }
+
+    /// Aggregation proofs requested and not acquired yet.
+    pub fn pending_requests(&self) -> &Arc<PendingRequests> {
+        &self.pending_requests
+    }
+}
+
 impl<L1Rpc>
     ProposerService<L1Rpc, proposer_client::client::Client<ProposerRpcClient, NetworkProver>>
 {
@@ -100,8 +136,11 @@ impl<L1Rpc>
             "Building a network proposer service with a mock config"
         );
         Self::new(
-            new_network_prover(&config.client.sp1_cluster_endpoint)
-                .map_err(Error::UnableToCreateProver)?,
+            new_network_prover(
+                &config.client.sp1_cluster_endpoint,
+                config.client.network_private_key.as_ref(),
+            )
+            .map_err(Error::UnableToCreateProver)?,
             config,
             l1_rpc,
         )
@@ -151,50 +190,62 @@ where
         Poll::Ready(Ok(()))
     }
 
-    fn call(
-        &mut self,
-        FepProposerRequest {
-            last_proven_block,
-            requested_end_block,
-            l1_block_hash,
-        }: FepProposerRequest,
-    ) -> Self::Future {
+    fn call(&mut self, request: FepProposerRequest) -> Self::Future {
         let client = self.client.clone();
         let l1_rpc = self.l1_rpc.clone();
         let aggregation_vkey = self.aggregation_vkey.clone();
+        let proof_cache = self.proof_cache.clone();
+        let pending_requests = self.pending_requests.clone();
+        let max_range_splits = self.max_range_splits;
+        let key = SpanProofKey::from(&request);
+        let FepProposerRequest {
+            last_proven_block,
+            requested_end_block,
+            l1_block_hash: _,
+        } = request;
 
         async move {
-            info!(%last_proven_block, %requested_end_block, "Requesting fep aggregation proof");
-            let l1_block_number = l1_rpc
-                .get_block_number(l1_block_hash.into())
-                .await
-                .map_err(|e| {
-                    Error::AlloyProviderError(
-                        e.into()
-                            .context(format!("Getting the block number for hash {l1_block_hash}")),
+            if let Some(proof_cache) = &proof_cache {
+                if let Some(cached) = proof_cache.get(&key).await {
+                    info!(%last_proven_block, %requested_end_block, end_block = %cached.end_block, "Aggregation proof reused from the cache");
+                    return to_response(
+                        &cached.proof,
+                        cached.last_proven_block,
+                        cached.end_block,
+                        requested_end_block,
+                    );
+                }
+            }
+
+            let pending = match pending_requests.get(&key) {
+                Some(pending) => {
+                    info!(%last_proven_block, %requested_end_block, request_id = %RequestId(pending.request_id), "Waiting for the aggregation proof already requested");
+                    pending
+                }
+                None => {
+                    let pending = request_agg_proof(
+                        client.as_ref(),
+                        l1_rpc.as_ref(),
+                        key,
+                        max_range_splits,
                     )
-                })?;
-
-            // Request the AggregationProof generation from the proposer.
-            let response = client
-                .request_agg_proof(AggregationProofProposerRequest {
-                    last_proven_block,
-                    requested_end_block,
-                    l1_block_number,
-                    l1_block_hash,
-                })
-                .await?;
-            let request_id = response.request_id;
-            let end_block = response.end_block;
-            let last_proven_block = response.last_proven_block;
-            debug!(%last_proven_block, %end_block, %request_id, "Aggregation proof request submitted");
-
-            // Wait for the prover to finish aggregating span proofs
-            let proof_with_pv = client.wait_for_proof(request_id.clone()).await?;
-
-            let public_values =
-                AggregationProofPublicValues::abi_decode(proof_with_pv.public_values.as_slice())
-                    .map_err(Error::FepPublicValuesDeserializeFailure)?;
+                    .await?;
+                    pending_requests.insert(pending.clone());
+                    pending
+                }
+            };
+            let request_id = RequestId(pending.request_id);
+            let PendingProposerRequest {
+                last_proven_block,
+                end_block,
+                ..
+            } = pending;
+
+            // Wait for the prover to finish aggregating span proofs, the
+            // request being made again on failure.
+            let proof_with_pv = client.wait_for_proof(request_id.clone()).await;
+            pending_requests.remove(&key);
+            let proof_with_pv = proof_with_pv?;
 
             debug!(%last_proven_block, %end_block, %request_id, "Aggregation proof received from the proposer");
 
@@ -203,22 +254,126 @@ where
 
             debug!(%last_proven_block, %end_block, %request_id, "Aggregation proof verified successfully");
 
-            let proof_mode: sp1_sdk::SP1ProofMode = (&proof_with_pv.proof).into();
-            let aggregation_proof = proof_with_pv
-                .proof
-                .clone()
-                .try_as_compressed()
-                .ok_or_else(|| Error::UnsupportedAggregationProofMode(proof_mode))?;
+            let proposer_response = to_response(
+                &proof_with_pv,
+                last_proven_block,
+                end_block,
+                requested_end_block,
+            )?;
 
+            if end_block < requested_end_block {
+                warn!(%last_proven_block, %end_block, %requested_end_block, %request_id, "Aggregation proof covering only part of the requested range");
+            }
             info!(%last_proven_block, %end_block, %request_id, "Aggregation proof successfully acquired");
 
-            Ok(ProposerResponse {
-                aggregation_proof,
-                last_proven_block: response.last_proven_block,
-                end_block: response.end_block,
-                public_values,
-            })
+            if let Some(proof_cache) = &proof_cache {
+                proof_cache
+                    .insert(
+                        key,
+                        CachedSpanProof {
+                            proof: proof_with_pv,
+                            last_proven_block,
+                            end_block,
+                        },
+                    )
+                    .await;
+            }
+
+            Ok(proposer_response)
         }
         .boxed()
     }
 }
+
+/// Requests the aggregation proof of the range from the proposer, halving the
+/// range while rejected.
+async fn request_agg_proof<L1Rpc, ProposerClient>(
+    client: &ProposerClient,
+    l1_rpc: &L1Rpc,
+    key: SpanProofKey,
+    max_range_splits: u32,
+) -> Result<PendingProposerRequest, Error>
+where
+    L1Rpc: GetBlockNumber<Error: Into<anyhow::Error>>,
+    ProposerClient: proposer_client::ProposerClient,
+{
+    let SpanProofKey {
+        last_proven_block,
+        requested_end_block,
+        l1_block_hash,
+    } = key;
+    info!(%last_proven_block, %requested_end_block, "Requesting fep aggregation proof");
+    let l1_block_number = l1_rpc
+        .get_block_number(l1_block_hash.into())
+        .await
+        .map_err(|e| {
+            Error::AlloyProviderError(
+                e.into()
+                    .context(format!("Getting the block number for hash {l1_block_hash}")),
+            )
+        })?;
+
+    // Request the AggregationProof generation from the proposer, halving
+    // the range while rejected.
+    let mut range_end_block = requested_end_block;
+    let mut splits = 0;
+    let response = loop {
+        match client
+            .request_agg_proof(AggregationProofProposerRequest {
+                last_proven_block,
+                requested_end_block: range_end_block,
+                l1_block_number,
+                l1_block_hash,
+            })
+            .await
+        {
+            Ok(response) => break response,
+            Err(error)
+                if error.is_range_rejected()
+                    && splits < max_range_splits
+                    && range_end_block.saturating_sub(last_proven_block) > 1 =>
+            {
+                let half = last_proven_block + (range_end_block - last_proven_block) / 2;
+                warn!(%last_proven_block, %range_end_block, %half, ?error, "Range rejected by the proposer, requesting half of it");
+                range_end_block = half;
+                splits += 1;
+            }
+            Err(error) => return Err(error.into()),
+        }
+    };
+    debug!(%last_proven_block, end_block = %response.end_block, request_id = %response.request_id, "Aggregation proof request submitted");
+
+    Ok(PendingProposerRequest {
+        range: key,
+        request_id: response.request_id.0,
+        last_proven_block: response.last_proven_block,
+        end_block: response.end_block,
+    })
+}
+
+/// Extracts the compressed aggregation proof and its public values.
+fn to_response(
+    proof_with_pv: &SP1ProofWithPublicValues,
+    last_proven_block: u64,
+    end_block: u64,
+    requested_end_block: u64,
+) -> Result<ProposerResponse, Error> {
+    let public_values =
+        AggregationProofPublicValues::abi_decode(proof_with_pv.public_values.as_slice())
+            .map_err(Error::FepPublicValuesDeserializeFailure)?;
+
+    let proof_mode: sp1_sdk::SP1ProofMode = (&proof_with_pv.proof).into();
+    let aggregation_proof = proof_with_pv
+        .proof
+        .clone()
+        .try_as_compressed()
+        .ok_or_else(|| Error::UnsupportedAggregationProofMode(proof_mode))?;
+
+    Ok(ProposerResponse {
+        aggregation_proof,
+        last_proven_block,
+        end_block,
+        requested_end_block,
+        public_values,
+    })
+}
@@ -0,0 +1,85 @@
+//! Aggregation proof requests submitted to the proposer and not proven yet,
+//! keyed by the requested range, so that a retried aggchain proof request, or
+//! one restored from a snapshot of the prover, waits for the proof already
+//! requested instead of requesting it again.
+use std::{collections::HashMap, sync::Mutex};
+
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+
+use crate::cache::SpanProofKey;
+
+/// Aggregation proof request being proven by the proposer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PendingProposerRequest {
+    pub range: SpanProofKey,
+
+    /// Id of the request on the proposer, the proof being fetched with it.
+    pub request_id: B256,
+
+    /// Range being proven, the proposer possibly ending it before the
+    /// requested end block.
+    pub last_proven_block: u64,
+    pub end_block: u64,
+}
+
+#[derive(Default)]
+pub struct PendingRequests {
+    requests: Mutex<HashMap<SpanProofKey, PendingProposerRequest>>,
+}
+
+impl PendingRequests {
+    pub fn get(&self, range: &SpanProofKey) -> Option<PendingProposerRequest> {
+        self.requests
+            .lock()
+            .expect("pending proposer requests lock poisoned")
+            .get(range)
+            .cloned()
+    }
+
+    pub(crate) fn insert(&self, request: PendingProposerRequest) {
+        self.requests
+            .lock()
+            .expect("pending proposer requests lock poisoned")
+            .insert(request.range, request);
+    }
+
+    pub(crate) fn remove(&self, range: &SpanProofKey) {
+        self.requests
+            .lock()
+            .expect("pending proposer requests lock poisoned")
+            .remove(range);
+    }
+
+    /// Requests being proven, by range.
+    pub fn list(&self) -> Vec<PendingProposerRequest> {
+        let mut requests: Vec<_> = self
+            .requests
+            .lock()
+            .expect("pending proposer requests lock poisoned")
+            .values()
+            .cloned()
+            .collect();
+        requests.sort_by_key(|request| {
+            (
+                request.range.last_proven_block,
+                request.range.requested_end_block,
+            )
+        });
+
+        requests
+    }
+
+    /// Waits for the proofs of the given requests, as if requested by this
+    /// process.
+    pub fn restore(&self, requests: Vec<PendingProposerRequest>) {
+        let mut pending = self
+            .requests
+            .lock()
+            .expect("pending proposer requests lock poisoned");
+        for request in requests {
+            pending.insert(request.range, request);
+        }
+    }
+}
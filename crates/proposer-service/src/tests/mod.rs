@@ -1,14 +1,29 @@
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 
+use aggkit_prover_types::codec::Codec;
 use agglayer_evm_client::MockRpc;
 use alloy_primitives::FixedBytes;
 use proposer_client::{
-    rpc::AggregationProofProposerRequest, FepProposerRequest, MockProposerClient, RequestId,
+    error::ProofRequestError, rpc::AggregationProofProposerRequest, FepProposerRequest,
+    MockProposerClient, RequestId,
+};
+use prover_utils::{
+    encryption::{EncryptionConfig, EncryptionKey, ENCRYPTION_MAGIC},
+    retention::RetentionPolicy,
 };
 use sp1_sdk::{Prover as _, SP1PublicValues, SP1_CIRCUIT_VERSION};
 use tower::Service as _;
 
-use crate::{Error, ProposerService};
+use crate::{
+    cache::{collect_garbage, CachedSpanProof, SpanProofCache, SpanProofKey},
+    config::ProofCacheConfig,
+    pending::PendingProposerRequest,
+    Error, ProposerService,
+};
 
 const ELF: &[u8] = include_bytes!("../../../prover-dummy-program/elf/riscv32im-succinct-zkvm-elf");
 
@@ -90,6 +105,9 @@ async fn test_proposer_service() {
         client,
         l1_rpc,
         aggregation_vkey: vkey,
+        proof_cache: None,
+        pending_requests: Default::default(),
+        max_range_splits: 0,
     };
 
     let request = FepProposerRequest {
@@ -120,6 +138,9 @@ async fn unable_to_fetch_block_hash() {
         client,
         l1_rpc,
         aggregation_vkey: vkey,
+        proof_cache: None,
+        pending_requests: Default::default(),
+        max_range_splits: 0,
     };
 
     let request = FepProposerRequest {
@@ -136,6 +157,409 @@ async fn unable_to_fetch_block_hash() {
     ));
 }
 
+#[tokio::test]
+async fn cached_proof_is_reused() {
+    let mut l1_rpc = MockRpc::new();
+    l1_rpc
+        .expect_get_block_number()
+        .once()
+        .returning(|_| Ok(10));
+
+    let mut client = MockProposerClient::new();
+    client.expect_request_agg_proof().once().returning(
+        |request: AggregationProofProposerRequest| {
+            Box::pin(async move {
+                Ok(proposer_client::rpc::AggregationProofProposerResponse {
+                    request_id: RequestId(FixedBytes::new([0; 32])),
+                    last_proven_block: request.last_proven_block,
+                    end_block: request.requested_end_block - 2,
+                })
+            })
+        },
+    );
+
+    let (pkey, vkey, public_values) = generate_keys();
+    let mock_proof = sp1_sdk::SP1ProofWithPublicValues::create_mock_proof(
+        &pkey,
+        public_values,
+        sp1_sdk::SP1ProofMode::Compressed,
+        SP1_CIRCUIT_VERSION,
+    );
+    client
+        .expect_wait_for_proof()
+        .once()
+        .return_once(move |_| Box::pin(async move { Ok(mock_proof) }));
+    client
+        .expect_verify_agg_proof()
+        .once()
+        .return_once(move |_, _, _| Ok(()));
+
+    let dir = std::env::temp_dir().join(format!("proposer-proof-cache-{}", std::process::id()));
+    let config = ProofCacheConfig {
+        capacity: 1,
+        disk_dir: Some(dir.clone()),
+        codec: Codec::Cbor,
+        compression_level: Some(3),
+        encryption: None,
+        retention: Default::default(),
+    };
+    let mut proposer_service = ProposerService {
+        client: Arc::new(client),
+        l1_rpc: Arc::new(l1_rpc),
+        aggregation_vkey: vkey,
+        proof_cache: Some(Arc::new(SpanProofCache::new(&config))),
+        pending_requests: Default::default(),
+        max_range_splits: 0,
+    };
+
+    let request = FepProposerRequest {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+
+    // The proposer is requested once, the retry being served from the cache.
+    let response = proposer_service.call(request.clone()).await.unwrap();
+    assert_eq!(response.end_block, 8);
+    let response = proposer_service.call(request.clone()).await.unwrap();
+    assert_eq!(response.end_block, 8);
+
+    // The proof is kept on disk across restarts, compressed.
+    let restarted = SpanProofCache::new(&config);
+    let key = SpanProofKey::from(&request);
+    let written = std::fs::read_dir(dir.join("blobs"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert!(std::fs::read(written.path())
+        .unwrap()
+        .starts_with(&aggkit_prover_types::compression::ZSTD_MAGIC));
+    assert_eq!(restarted.get(&key).await.unwrap().end_block, 8);
+    let other = SpanProofKey {
+        requested_end_block: 20,
+        ..key
+    };
+    assert!(restarted.get(&other).await.is_none());
+
+    // Exported once, whether in memory or on disk only.
+    let exported = restarted.export().await.unwrap();
+    assert_eq!(exported.len(), 1);
+    assert_eq!((exported[0].range, exported[0].proof.end_block), (key, 8));
+    let exported = SpanProofCache::new(&config).export().await.unwrap();
+    assert_eq!(exported.len(), 1);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn cached_proofs_are_stored_once_and_collected() {
+    let (pkey, _, public_values) = generate_keys();
+    let proof = CachedSpanProof {
+        proof: sp1_sdk::SP1ProofWithPublicValues::create_mock_proof(
+            &pkey,
+            public_values,
+            sp1_sdk::SP1ProofMode::Compressed,
+            SP1_CIRCUIT_VERSION,
+        ),
+        last_proven_block: 0,
+        end_block: 8,
+    };
+
+    let dir = std::env::temp_dir().join(format!("proposer-proof-gc-{}", std::process::id()));
+    let config = ProofCacheConfig {
+        disk_dir: Some(dir.clone()),
+        ..Default::default()
+    };
+    let cache = SpanProofCache::new(&config);
+
+    // Both ranges were shrunk by the proposer to the same proof.
+    let first = SpanProofKey {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+    let second = SpanProofKey {
+        requested_end_block: 12,
+        ..first
+    };
+    cache.insert(first, proof.clone()).await;
+    cache.insert(second, proof).await;
+    let count = |subdir: &str| std::fs::read_dir(dir.join(subdir)).unwrap().count();
+    assert_eq!((count("refs"), count("blobs")), (2, 1));
+
+    let collection = collect_garbage(&dir, &Default::default(), false).unwrap();
+    assert_eq!((collection.refs, collection.kept_proofs), (2, 1));
+    let newest_only = RetentionPolicy {
+        max_age: None,
+        max_count: Some(1),
+    };
+    let collection = collect_garbage(&dir, &newest_only, true).unwrap();
+    assert_eq!((collection.refs, collection.expired_refs), (1, 1));
+    assert_eq!(collection.kept_proofs, 1);
+
+    // Aged past the retention, then past the grace period of the proofs.
+    let age = |path: PathBuf| {
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(2 * 24 * 60 * 60))
+            .unwrap()
+    };
+    let files = |subdir: &str| {
+        std::fs::read_dir(dir.join(subdir))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>()
+    };
+    let refs = files("refs");
+    age(refs[0].clone());
+    files("blobs").into_iter().for_each(age);
+
+    let retention = RetentionPolicy {
+        max_age: Some(Duration::from_secs(24 * 60 * 60)),
+        max_count: None,
+    };
+    let collection = collect_garbage(&dir, &retention, false).unwrap();
+    assert_eq!(collection.expired_refs, 1);
+    assert_eq!((collection.kept_proofs, collection.removed_proofs), (1, 0));
+
+    age(refs[1].clone());
+    let collection = collect_garbage(&dir, &retention, true).unwrap();
+    assert_eq!((collection.expired_refs, collection.removed_proofs), (1, 1));
+    assert!(collection.freed_bytes > 0);
+    assert_eq!((count("refs"), count("blobs")), (1, 1));
+
+    collect_garbage(&dir, &retention, false).unwrap();
+    assert_eq!((count("refs"), count("blobs")), (0, 0));
+    let restarted = SpanProofCache::new(&config);
+    assert!(restarted.get(&first).await.is_none());
+    assert!(restarted.get(&second).await.is_none());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn cached_proofs_are_encrypted_on_disk() {
+    let (pkey, _, public_values) = generate_keys();
+    let proof = CachedSpanProof {
+        proof: sp1_sdk::SP1ProofWithPublicValues::create_mock_proof(
+            &pkey,
+            public_values,
+            sp1_sdk::SP1ProofMode::Compressed,
+            SP1_CIRCUIT_VERSION,
+        ),
+        last_proven_block: 0,
+        end_block: 8,
+    };
+    let key = |byte: u8| EncryptionKey::resolve(&format!("{byte:02x}").repeat(32)).unwrap();
+
+    let dir = std::env::temp_dir().join(format!("proposer-proof-enc-{}", std::process::id()));
+    let config = ProofCacheConfig {
+        disk_dir: Some(dir.clone()),
+        compression_level: Some(3),
+        encryption: Some(EncryptionConfig {
+            key: key(1),
+            previous_keys: vec![],
+        }),
+        ..Default::default()
+    };
+    let range = SpanProofKey {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+    SpanProofCache::new(&config).insert(range, proof).await;
+
+    let written = std::fs::read_dir(dir.join("blobs"))
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert!(std::fs::read(written.path())
+        .unwrap()
+        .starts_with(&ENCRYPTION_MAGIC));
+
+    // Read after a rotation of the key, but not without it.
+    let rotated = ProofCacheConfig {
+        encryption: Some(EncryptionConfig {
+            key: key(2),
+            previous_keys: vec![key(1)],
+        }),
+        ..config.clone()
+    };
+    assert_eq!(
+        SpanProofCache::new(&rotated)
+            .get(&range)
+            .await
+            .unwrap()
+            .end_block,
+        8
+    );
+    let plain = ProofCacheConfig {
+        encryption: None,
+        ..config
+    };
+    assert!(SpanProofCache::new(&plain).get(&range).await.is_none());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn rejected_range_is_halved() {
+    let mut l1_rpc = MockRpc::new();
+    l1_rpc
+        .expect_get_block_number()
+        .once()
+        .returning(|_| Ok(10));
+
+    let mut client = MockProposerClient::new();
+    client.expect_request_agg_proof().times(3).returning(
+        |request: AggregationProofProposerRequest| {
+            Box::pin(async move {
+                if request.requested_end_block > 3 {
+                    return Err(proposer_client::error::Error::Requesting(Box::new(
+                        ProofRequestError::Grpc(tonic::Status::out_of_range("range too large")),
+                    )));
+                }
+
+                Ok(proposer_client::rpc::AggregationProofProposerResponse {
+                    request_id: RequestId(FixedBytes::new([0; 32])),
+                    last_proven_block: request.last_proven_block,
+                    end_block: request.requested_end_block,
+                })
+            })
+        },
+    );
+
+    let (pkey, vkey, public_values) = generate_keys();
+    let mock_proof = sp1_sdk::SP1ProofWithPublicValues::create_mock_proof(
+        &pkey,
+        public_values,
+        sp1_sdk::SP1ProofMode::Compressed,
+        SP1_CIRCUIT_VERSION,
+    );
+    client
+        .expect_wait_for_proof()
+        .once()
+        .return_once(move |_| Box::pin(async move { Ok(mock_proof) }));
+    client
+        .expect_verify_agg_proof()
+        .once()
+        .return_once(move |_, _, _| Ok(()));
+
+    let mut proposer_service = ProposerService {
+        client: Arc::new(client),
+        l1_rpc: Arc::new(l1_rpc),
+        aggregation_vkey: vkey,
+        proof_cache: None,
+        pending_requests: Default::default(),
+        max_range_splits: 2,
+    };
+
+    let request = FepProposerRequest {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+
+    let response = proposer_service.call(request).await.unwrap();
+    assert_eq!(response.end_block, 2);
+    assert_eq!(response.requested_end_block, 10);
+}
+
+#[tokio::test]
+async fn invalid_request_is_not_halved() {
+    let mut l1_rpc = MockRpc::new();
+    l1_rpc
+        .expect_get_block_number()
+        .once()
+        .returning(|_| Ok(10));
+
+    let mut client = MockProposerClient::new();
+    client
+        .expect_request_agg_proof()
+        .once()
+        .returning(|_: AggregationProofProposerRequest| {
+            Box::pin(async move {
+                Err(proposer_client::error::Error::Requesting(Box::new(
+                    ProofRequestError::Grpc(tonic::Status::invalid_argument("invalid l1 block")),
+                )))
+            })
+        });
+
+    let (_pkey, vkey, _public_values) = generate_keys();
+    let mut proposer_service = ProposerService {
+        client: Arc::new(client),
+        l1_rpc: Arc::new(l1_rpc),
+        aggregation_vkey: vkey,
+        proof_cache: None,
+        pending_requests: Default::default(),
+        max_range_splits: 2,
+    };
+
+    let request = FepProposerRequest {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+
+    assert!(proposer_service.call(request).await.is_err());
+}
+
+#[tokio::test]
+async fn restored_pending_request_is_awaited() {
+    // Neither l1 nor the proposer are requested, the proof being already
+    // requested before the snapshot.
+    let l1_rpc = MockRpc::new();
+    let mut client = MockProposerClient::new();
+
+    let (pkey, vkey, public_values) = generate_keys();
+    let mock_proof = sp1_sdk::SP1ProofWithPublicValues::create_mock_proof(
+        &pkey,
+        public_values,
+        sp1_sdk::SP1ProofMode::Compressed,
+        SP1_CIRCUIT_VERSION,
+    );
+    client
+        .expect_wait_for_proof()
+        .withf(|request_id| *request_id == RequestId(FixedBytes::new([7; 32])))
+        .once()
+        .return_once(move |_| Box::pin(async move { Ok(mock_proof) }));
+    client
+        .expect_verify_agg_proof()
+        .once()
+        .return_once(move |_, _, _| Ok(()));
+
+    let mut proposer_service = ProposerService {
+        client: Arc::new(client),
+        l1_rpc: Arc::new(l1_rpc),
+        aggregation_vkey: vkey,
+        proof_cache: None,
+        pending_requests: Default::default(),
+        max_range_splits: 0,
+    };
+    let request = FepProposerRequest {
+        last_proven_block: 0,
+        requested_end_block: 10,
+        l1_block_hash: Default::default(),
+    };
+    let range = SpanProofKey::from(&request);
+    proposer_service
+        .pending_requests()
+        .restore(vec![PendingProposerRequest {
+            range,
+            request_id: FixedBytes::new([7; 32]),
+            last_proven_block: 0,
+            end_block: 6,
+        }]);
+
+    let response = proposer_service.call(request).await.unwrap();
+    assert_eq!(response.end_block, 6);
+    assert!(proposer_service.pending_requests().get(&range).is_none());
+}
+
 #[test]
 #[ignore = "to be implemented"]
 fn test_invalid_proof_vkey_verificatinon_fails() {}
@@ -71,8 +71,11 @@ pub async fn main() -> anyhow::Result<()> {
             sp1_cluster_endpoint: cli.sp1_cluster_endpoint,
             request_timeout: proposer_client::config::default_request_timeout(),
             proving_timeout: proposer_client::config::default_proving_timeout(),
+            network_private_key: None,
         },
         l1_rpc_endpoint: cli.l1_rpc_endpoint,
+        proof_cache: None,
+        range_negotiation: Default::default(),
     };
     let mut proposer_service = if cli.mock {
         tower::ServiceBuilder::new()
@@ -0,0 +1,213 @@
+//! Batched reads from the RPC nodes.
+//!
+//! Fetching the block headers and storage slots one by one costs a round trip
+//! each. The helpers below send them as JSON-RPC batches, and the contract
+//! reads as `Multicall3` aggregates, to fetch them in a handful of round trips.
+use alloy::{
+    eips::{BlockId, BlockNumberOrTag},
+    primitives::{address, Address, Bytes, U256},
+    providers::Provider,
+    rpc::types::{Block, Header},
+    sol,
+    sol_types::SolCall,
+};
+
+/// Maximum number of requests sent in one JSON-RPC batch, most RPC nodes
+/// rejecting larger batches.
+pub const DEFAULT_RPC_BATCH_SIZE: usize = 100;
+
+/// Address of the `Multicall3` contract, identical on every chain it is
+/// deployed on.
+pub const MULTICALL3_ADDRESS: Address = address!("0xcA11bde05977b3631167028862bE2a173976CA11");
+
+sol!(
+    #[allow(missing_docs)]
+    #[sol(rpc)]
+    interface IMulticall3 {
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Call3Result {
+            bool success;
+            bytes returnData;
+        }
+
+        function aggregate3(Call3[] calldata calls)
+            external
+            payable
+            returns (Call3Result[] memory returnData);
+    }
+);
+
+/// Fetches the headers of the given blocks, `batch_size` blocks per round
+/// trip. The headers are returned in the order of `block_numbers`.
+pub async fn get_block_headers<P: Provider>(
+    provider: &P,
+    block_numbers: &[u64],
+    batch_size: usize,
+) -> Result<Vec<Header>, anyhow::Error> {
+    let mut headers = Vec::with_capacity(block_numbers.len());
+
+    for chunk in block_numbers.chunks(batch_size.max(1)) {
+        let mut batch = provider.client().new_batch();
+        let waiters = chunk
+            .iter()
+            .map(|&block_number| {
+                batch.add_call::<_, Option<Block>>(
+                    "eth_getBlockByNumber",
+                    &(BlockNumberOrTag::Number(block_number), false),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        batch.send().await?;
+
+        for (waiter, block_number) in waiters.into_iter().zip(chunk) {
+            let block = waiter
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Block {block_number} not found"))?;
+            headers.push(block.header);
+        }
+    }
+
+    Ok(headers)
+}
+
+/// Fetches the given storage slots at `block`, `batch_size` slots per round
+/// trip. The values are returned in the order of `slots`.
+pub async fn get_storage_slots<P: Provider>(
+    provider: &P,
+    slots: &[(Address, U256)],
+    block: BlockId,
+    batch_size: usize,
+) -> Result<Vec<U256>, anyhow::Error> {
+    let mut values = Vec::with_capacity(slots.len());
+
+    for chunk in slots.chunks(batch_size.max(1)) {
+        let mut batch = provider.client().new_batch();
+        let waiters = chunk
+            .iter()
+            .map(|(address, slot)| {
+                batch.add_call::<_, U256>("eth_getStorageAt", &(address, slot, block))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        batch.send().await?;
+
+        for waiter in waiters {
+            values.push(waiter.await?);
+        }
+    }
+
+    Ok(values)
+}
+
+/// Contract reads aggregated into a single `Multicall3` call.
+#[derive(Debug, Clone, Default)]
+pub struct Multicall {
+    calls: Vec<IMulticall3::Call3>,
+}
+
+/// Outcome of one of the aggregated reads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MulticallResult {
+    Success(Bytes),
+    /// The read reverted, with the given revert data.
+    Reverted(Bytes),
+}
+
+impl Multicall {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a read of `target`, returning its index in the results.
+    pub fn add<C: SolCall>(&mut self, target: Address, call: &C) -> usize {
+        self.calls.push(IMulticall3::Call3 {
+            target,
+            allowFailure: true,
+            callData: call.abi_encode().into(),
+        });
+
+        self.calls.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Runs the aggregated reads at `block`, in one round trip. A reverting
+    /// read doesn't fail the others.
+    pub async fn call<P: Provider>(
+        self,
+        provider: &P,
+        block: BlockId,
+    ) -> Result<Vec<MulticallResult>, anyhow::Error> {
+        if self.calls.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let results = IMulticall3::new(MULTICALL3_ADDRESS, provider)
+            .aggregate3(self.calls)
+            .block(block)
+            .call()
+            .await?;
+
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                if result.success {
+                    MulticallResult::Success(result.returnData)
+                } else {
+                    MulticallResult::Reverted(result.returnData)
+                }
+            })
+            .collect())
+    }
+}
+
+impl MulticallResult {
+    /// Decodes the return value of a successful read of `C`.
+    pub fn decode<C: SolCall>(&self) -> Result<C::Return, anyhow::Error> {
+        match self {
+            Self::Success(data) => Ok(C::abi_decode_returns(data)?),
+            Self::Reverted(data) => Err(anyhow::anyhow!("{} reverted with {data}", C::SIGNATURE)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::sol_types::SolValue as _;
+
+    use super::*;
+
+    sol! {
+        function balanceOf(address owner) external view returns (uint256);
+    }
+
+    #[test]
+    fn multicall_encoding() {
+        let token = Address::repeat_byte(1);
+        let call = balanceOfCall {
+            owner: Address::repeat_byte(2),
+        };
+
+        let mut multicall = Multicall::new();
+        assert_eq!(multicall.add(token, &call), 0);
+        assert_eq!(multicall.add(token, &call), 1);
+        assert_eq!(multicall.len(), 2);
+        assert_eq!(multicall.calls[1].callData, Bytes::from(call.abi_encode()));
+
+        let result = MulticallResult::Success(U256::from(42).abi_encode().into());
+        assert_eq!(result.decode::<balanceOfCall>().unwrap(), U256::from(42));
+        assert!(MulticallResult::Reverted(Bytes::new())
+            .decode::<balanceOfCall>()
+            .is_err());
+    }
+}
@@ -0,0 +1,350 @@
+//! Transport spreading the requests over several RPC nodes serving the same
+//! chain, routing each request to the healthiest node.
+//!
+//! Nodes get demoted when they fail or answer slowly, and the idempotent
+//! requests failing on one node are retried on the next one, so that a single
+//! flaky node doesn't fail whole proof requests.
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use alloy::{
+    providers::{IpcConnect, ProviderBuilder, WsConnect},
+    pubsub::PubSubConnect as _,
+    rpc::{
+        client::ClientBuilder,
+        json_rpc::{RequestPacket, ResponsePacket},
+    },
+    transports::{
+        http::{reqwest, Http},
+        BoxTransport, TransportError, TransportErrorKind, TransportFut,
+    },
+};
+use tower::Service;
+use tracing::warn;
+use url::Url;
+
+use crate::{
+    connect_alloy_fill_provider, metrics::RpcMetricsLayer, retry::retry_layer, AlloyFillProvider,
+    RpcTransport,
+};
+
+/// Weight of the last request in the latency moving average.
+const LATENCY_SMOOTHING: f64 = 0.2;
+/// Latency penalty applied per consecutive error of a node.
+const ERROR_PENALTY: Duration = Duration::from_secs(1);
+/// Demotion period after the first error, doubled for each consecutive error.
+const BASE_DEMOTION: Duration = Duration::from_secs(1);
+const MAX_DEMOTION: Duration = Duration::from_secs(60);
+
+/// Methods which must not be sent twice, as they have side effects.
+const NON_IDEMPOTENT_METHODS: &[&str] = &[
+    "eth_sendRawTransaction",
+    "eth_sendTransaction",
+    "eth_subscribe",
+    "eth_unsubscribe",
+];
+
+/// Health of one RPC node, as observed from its past requests.
+#[derive(Debug, Clone, Default)]
+struct Health {
+    /// Moving average of the request latency, in milliseconds.
+    latency_ms: f64,
+    consecutive_errors: u32,
+    demoted_until: Option<Instant>,
+}
+
+impl Health {
+    fn record_success(&mut self, latency: Duration) {
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+        self.latency_ms = if self.latency_ms == 0.0 {
+            latency_ms
+        } else {
+            LATENCY_SMOOTHING * latency_ms + (1.0 - LATENCY_SMOOTHING) * self.latency_ms
+        };
+        self.consecutive_errors = 0;
+        self.demoted_until = None;
+    }
+
+    fn record_error(&mut self, now: Instant) {
+        self.consecutive_errors = self.consecutive_errors.saturating_add(1);
+        let demotion = BASE_DEMOTION
+            .saturating_mul(1 << self.consecutive_errors.min(16).saturating_sub(1))
+            .min(MAX_DEMOTION);
+        self.demoted_until = Some(now + demotion);
+    }
+
+    fn is_demoted(&self, now: Instant) -> bool {
+        self.demoted_until.is_some_and(|until| until > now)
+    }
+
+    /// Lower is healthier.
+    fn score(&self) -> f64 {
+        self.latency_ms + f64::from(self.consecutive_errors) * ERROR_PENALTY.as_millis() as f64
+    }
+}
+
+struct Endpoint {
+    url: Url,
+    transport: BoxTransport,
+    health: Mutex<Health>,
+}
+
+/// Transport routing the requests to the healthiest of several RPC nodes.
+#[derive(Clone)]
+pub struct FallbackTransport {
+    endpoints: Arc<Vec<Endpoint>>,
+}
+
+impl FallbackTransport {
+    pub fn new(transports: Vec<(Url, BoxTransport)>) -> Self {
+        Self {
+            endpoints: Arc::new(
+                transports
+                    .into_iter()
+                    .map(|(url, transport)| Endpoint {
+                        url,
+                        transport,
+                        health: Mutex::default(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Indices of the endpoints, healthiest first. The demoted endpoints come
+    /// last, but are still tried when every other endpoint fails.
+    fn ranking(&self, now: Instant) -> Vec<usize> {
+        let mut ranking: Vec<(bool, f64, usize)> = self
+            .endpoints
+            .iter()
+            .enumerate()
+            .map(|(i, endpoint)| {
+                let health = endpoint.health.lock().expect("health lock poisoned");
+                (health.is_demoted(now), health.score(), i)
+            })
+            .collect();
+        ranking.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+        ranking.into_iter().map(|(_, _, i)| i).collect()
+    }
+
+    async fn dispatch(self, request: RequestPacket) -> Result<ResponsePacket, TransportError> {
+        let idempotent = is_idempotent(&request);
+        let mut last_error = None;
+
+        for i in self.ranking(Instant::now()) {
+            let endpoint = &self.endpoints[i];
+            let mut transport = endpoint.transport.clone();
+
+            let start = Instant::now();
+            let result = transport.call(request.clone()).await;
+
+            let mut health = endpoint.health.lock().expect("health lock poisoned");
+            match result {
+                Ok(response) => {
+                    health.record_success(start.elapsed());
+                    return Ok(response);
+                }
+                Err(error) => {
+                    health.record_error(Instant::now());
+                    warn!(url = %endpoint.url, %error, "RPC node request failed");
+
+                    if !idempotent {
+                        return Err(error);
+                    }
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| TransportErrorKind::custom_str("No RPC node configured")))
+    }
+}
+
+impl Service<RequestPacket> for FallbackTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        Box::pin(self.clone().dispatch(request))
+    }
+}
+
+fn is_idempotent(request: &RequestPacket) -> bool {
+    let is_idempotent = |method: &str| !NON_IDEMPOTENT_METHODS.contains(&method);
+
+    match request {
+        RequestPacket::Single(request) => is_idempotent(request.method()),
+        RequestPacket::Batch(requests) => requests
+            .iter()
+            .all(|request| is_idempotent(request.method())),
+    }
+}
+
+async fn connect_transport(rpc_url: &Url) -> Result<BoxTransport, anyhow::Error> {
+    Ok(match RpcTransport::from_url(rpc_url)? {
+        RpcTransport::Http => {
+            let reqwest_client = reqwest::ClientBuilder::new()
+                .pool_max_idle_per_host(crate::HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST)
+                .pool_idle_timeout(Duration::from_secs(
+                    crate::HTTP_CLIENT_CONNECTION_POOL_IDLE_TIMEOUT,
+                ))
+                .build()?;
+
+            BoxTransport::new(Http::with_client(reqwest_client, rpc_url.clone()))
+        }
+        RpcTransport::Ws => {
+            BoxTransport::new(WsConnect::new(rpc_url.as_str()).into_service().await?)
+        }
+        RpcTransport::Ipc => BoxTransport::new(
+            IpcConnect::new(rpc_url.path().to_string())
+                .into_service()
+                .await?,
+        ),
+    })
+}
+
+/// Connects to several RPC nodes serving the same chain, the requests being
+/// routed to the healthiest one. Falls back to a plain provider when only one
+/// URL is given.
+pub async fn connect_fallback_alloy_fill_provider(
+    rpc_urls: &[Url],
+    backoff: u64,
+    max_retries: u32,
+) -> Result<AlloyFillProvider, anyhow::Error> {
+    match rpc_urls {
+        [] => anyhow::bail!("No RPC URL configured"),
+        [rpc_url] => connect_alloy_fill_provider(rpc_url, backoff, max_retries).await,
+        rpc_urls => {
+            let mut transports = Vec::with_capacity(rpc_urls.len());
+            for rpc_url in rpc_urls {
+                transports.push((rpc_url.clone(), connect_transport(rpc_url).await?));
+            }
+
+            let client = ClientBuilder::default()
+                .layer(retry_layer(backoff, max_retries))
+                .layer(RpcMetricsLayer)
+                .transport(FallbackTransport::new(transports), false);
+
+            Ok(ProviderBuilder::new().on_client(client))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use alloy::rpc::json_rpc::{Id, Request, Response, ResponsePayload};
+
+    use super::*;
+
+    /// Transport answering `null`, or failing, counting the requests.
+    #[derive(Clone)]
+    struct FakeTransport {
+        fail: bool,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Service<RequestPacket> for FakeTransport {
+        type Response = ResponsePacket;
+        type Error = TransportError;
+        type Future = TransportFut<'static>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _request: RequestPacket) -> Self::Future {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let fail = self.fail;
+
+            Box::pin(async move {
+                if fail {
+                    return Err(TransportErrorKind::custom_str("node down"));
+                }
+
+                Ok(ResponsePacket::Single(Response {
+                    id: Id::Number(1),
+                    payload: ResponsePayload::Success(
+                        serde_json::value::to_raw_value(&()).unwrap(),
+                    ),
+                }))
+            })
+        }
+    }
+
+    fn fallback(fails: &[bool]) -> (FallbackTransport, Vec<Arc<AtomicUsize>>) {
+        let calls: Vec<_> = fails.iter().map(|_| Arc::default()).collect();
+        let transports = fails
+            .iter()
+            .zip(&calls)
+            .enumerate()
+            .map(|(i, (&fail, calls))| {
+                (
+                    Url::parse(&format!("http://node-{i}")).unwrap(),
+                    BoxTransport::new(FakeTransport {
+                        fail,
+                        calls: Arc::clone(calls),
+                    }),
+                )
+            })
+            .collect();
+
+        (FallbackTransport::new(transports), calls)
+    }
+
+    fn request(method: &'static str) -> RequestPacket {
+        RequestPacket::Single(Request::new(method, Id::Number(1), ()).serialize().unwrap())
+    }
+
+    #[tokio::test]
+    async fn idempotent_requests_fail_over() {
+        let (mut transport, calls) = fallback(&[true, false]);
+
+        transport.call(request("eth_blockNumber")).await.unwrap();
+        assert_eq!(calls[0].load(Ordering::SeqCst), 1);
+        assert_eq!(calls[1].load(Ordering::SeqCst), 1);
+
+        // The failing node is demoted, the next request goes to the healthy one.
+        transport.call(request("eth_blockNumber")).await.unwrap();
+        assert_eq!(calls[0].load(Ordering::SeqCst), 1);
+        assert_eq!(calls[1].load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn non_idempotent_requests_are_sent_once() {
+        let (mut transport, calls) = fallback(&[true, false]);
+
+        assert!(transport
+            .call(request("eth_sendRawTransaction"))
+            .await
+            .is_err());
+        assert_eq!(calls[1].load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn health_ranking() {
+        let now = Instant::now();
+        let mut health = Health::default();
+        health.record_success(Duration::from_millis(100));
+        assert_eq!(health.score(), 100.0);
+
+        health.record_error(now);
+        assert!(health.is_demoted(now));
+        assert!(!health.is_demoted(now + MAX_DEMOTION));
+        assert_eq!(health.score(), 1100.0);
+
+        health.record_success(Duration::from_millis(200));
+        assert!(!health.is_demoted(now));
+        assert_eq!(health.score(), 120.0);
+    }
+}
@@ -0,0 +1,131 @@
+//! Finality level of the blocks the data is fetched at.
+//!
+//! Data read at `latest` can be reorged away, hence the witnesses of the
+//! proofs should be built from blocks which reached the configured finality.
+use std::time::Duration;
+
+use alloy::{
+    eips::{BlockId, BlockNumberOrTag},
+    providers::Provider,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+/// Default interval between two polls of the finalized block.
+pub const DEFAULT_FINALITY_POLL_INTERVAL: Duration = Duration::from_secs(12);
+
+/// Block tag at which the data is fetched.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum BlockFinality {
+    /// Block which can no longer be reorged.
+    #[default]
+    Finalized,
+    /// Block unlikely to be reorged.
+    Safe,
+    /// Head of the chain.
+    Latest,
+}
+
+impl BlockFinality {
+    /// Finality to use for one call, `overridden` taking precedence over the
+    /// configured finality.
+    pub fn or_override(self, overridden: Option<BlockFinality>) -> BlockFinality {
+        overridden.unwrap_or(self)
+    }
+}
+
+impl From<BlockFinality> for BlockNumberOrTag {
+    fn from(finality: BlockFinality) -> Self {
+        match finality {
+            BlockFinality::Finalized => BlockNumberOrTag::Finalized,
+            BlockFinality::Safe => BlockNumberOrTag::Safe,
+            BlockFinality::Latest => BlockNumberOrTag::Latest,
+        }
+    }
+}
+
+impl From<BlockFinality> for BlockId {
+    fn from(finality: BlockFinality) -> Self {
+        BlockId::Number(finality.into())
+    }
+}
+
+/// Number of the last block which reached the given finality.
+pub async fn get_block_number_at<P: Provider>(
+    provider: &P,
+    finality: BlockFinality,
+) -> Result<u64, anyhow::Error> {
+    let block = provider
+        .get_block_by_number(finality.into())
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No {finality:?} block available"))?;
+
+    Ok(block.header.number)
+}
+
+/// Waits until the block `block_number` reached the given finality, polling
+/// the node every `poll_interval`. Returns the number of the last block which
+/// reached it.
+pub async fn wait_until_block_reaches<P: Provider>(
+    provider: &P,
+    block_number: u64,
+    finality: BlockFinality,
+    poll_interval: Duration,
+) -> Result<u64, anyhow::Error> {
+    loop {
+        let current = get_block_number_at(provider, finality).await?;
+        if current >= block_number {
+            return Ok(current);
+        }
+
+        debug!(
+            block_number,
+            current,
+            ?finality,
+            "Waiting for the block finality"
+        );
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Waits until the block `block_number` is finalized.
+pub async fn wait_until_finalized<P: Provider>(
+    provider: &P,
+    block_number: u64,
+) -> Result<u64, anyhow::Error> {
+    wait_until_block_reaches(
+        provider,
+        block_number,
+        BlockFinality::Finalized,
+        DEFAULT_FINALITY_POLL_INTERVAL,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        finality: BlockFinality,
+    }
+
+    #[test]
+    fn finality_tags() {
+        let config: Config = toml::from_str(r#"finality = "safe""#).unwrap();
+        assert_eq!(config.finality, BlockFinality::Safe);
+
+        assert_eq!(
+            BlockNumberOrTag::from(BlockFinality::default()),
+            BlockNumberOrTag::Finalized
+        );
+        assert_eq!(
+            BlockFinality::Finalized.or_override(Some(BlockFinality::Latest)),
+            BlockFinality::Latest
+        );
+        assert_eq!(BlockFinality::Safe.or_override(None), BlockFinality::Safe);
+    }
+}
@@ -5,17 +5,43 @@ use alloy::{
     network::Ethereum,
     providers::{
         fillers::{BlobGasFiller, ChainIdFiller, FillProvider, GasFiller, JoinFill, NonceFiller},
-        Identity, ProviderBuilder, RootProvider,
+        Identity, Provider as _, ProviderBuilder, RootProvider,
     },
     rpc::client::ClientBuilder,
-    transports::{http::reqwest, layers::RetryBackoffLayer},
+    transports::http::reqwest,
 };
 pub use async_trait::async_trait;
 use derive_more::{From, FromStr};
 use educe::Educe;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+use crate::retry::retry_layer;
+
+mod batch;
+mod fallback;
+mod finality;
+mod metrics;
+mod retry;
+mod transport;
+
+pub use batch::{
+    get_block_headers, get_storage_slots, Multicall, MulticallResult, DEFAULT_RPC_BATCH_SIZE,
+    MULTICALL3_ADDRESS,
+};
+pub use fallback::{connect_fallback_alloy_fill_provider, FallbackTransport};
+pub use finality::{
+    get_block_number_at, wait_until_block_reaches, wait_until_finalized, BlockFinality,
+    DEFAULT_FINALITY_POLL_INTERVAL,
+};
+pub use metrics::RpcMetricsLayer;
+pub use retry::{
+    BudgetedRetryPolicy, RetryBudget, DEFAULT_RETRY_BUDGET_CAPACITY,
+    DEFAULT_RETRY_BUDGET_REFILL_PER_SECOND, GLOBAL_RETRY_BUDGET,
+};
+pub use transport::{connect_alloy_fill_provider, RpcTransport};
+
 const HTTP_CLIENT_CONNECTION_POOL_IDLE_TIMEOUT: u64 = 90;
 const HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST: usize = 64;
 pub const DEFAULT_HTTP_RPC_NODE_INITIAL_BACKOFF_MS: u64 = 5000;
@@ -35,7 +61,7 @@ pub fn build_alloy_fill_provider(
     backoff: u64,
     max_retries: u32,
 ) -> Result<AlloyFillProvider, anyhow::Error> {
-    let retry_policy = RetryBackoffLayer::new(max_retries, backoff, 5);
+    let retry_policy = retry_layer(backoff, max_retries);
     let reqwest_client = reqwest::ClientBuilder::new()
         .pool_max_idle_per_host(HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST)
         .pool_idle_timeout(Duration::from_secs(
@@ -47,6 +73,7 @@ pub fn build_alloy_fill_provider(
     let is_local = http.guess_local();
     let client = ClientBuilder::default()
         .layer(retry_policy)
+        .layer(RpcMetricsLayer)
         .transport(http, is_local);
 
     Ok(ProviderBuilder::new().on_client(client))
@@ -57,6 +84,7 @@ pub fn build_alloy_fill_provider(
 /// allows direct use of the provider if necessary.
 pub struct AlloyProvider {
     client: AlloyFillProvider,
+    transport: RpcTransport,
 }
 
 impl AlloyProvider {
@@ -65,7 +93,7 @@ impl AlloyProvider {
         backoff: u64,
         max_retries: u32,
     ) -> Result<AlloyProvider, anyhow::Error> {
-        let retry_policy = RetryBackoffLayer::new(max_retries, backoff, 5);
+        let retry_policy = retry_layer(backoff, max_retries);
         let reqwest_client = reqwest::ClientBuilder::new()
             .pool_max_idle_per_host(HTTP_CLIENT_MAX_IDLE_CONNECTIONS_PER_HOST)
             .pool_idle_timeout(Duration::from_secs(
@@ -77,12 +105,51 @@ impl AlloyProvider {
         let is_local = http.guess_local();
         let client = ClientBuilder::default()
             .layer(retry_policy)
+            .layer(RpcMetricsLayer)
             .transport(http, is_local);
 
         Ok(AlloyProvider {
             client: ProviderBuilder::new().on_client(client),
+            transport: RpcTransport::Http,
         })
     }
+
+    /// Connects to the RPC node over HTTP, WS or IPC depending on the URL
+    /// scheme.
+    pub async fn connect(
+        rpc_url: &url::Url,
+        backoff: u64,
+        max_retries: u32,
+    ) -> Result<AlloyProvider, anyhow::Error> {
+        Ok(AlloyProvider {
+            client: connect_alloy_fill_provider(rpc_url, backoff, max_retries).await?,
+            transport: RpcTransport::from_url(rpc_url)?,
+        })
+    }
+
+    /// Subscribes to the new block headers. Only supported by the WS and IPC
+    /// transports; HTTP clients have to poll.
+    pub async fn subscribe_new_heads(
+        &self,
+    ) -> Result<alloy::pubsub::Subscription<alloy::rpc::types::Header>, anyhow::Error> {
+        if !self.transport.supports_pubsub() {
+            anyhow::bail!(
+                "Subscriptions are not supported by the {:?} transport",
+                self.transport
+            );
+        }
+
+        Ok(self.client.subscribe_blocks().await?)
+    }
+
+    /// Fetches the headers of the given blocks in batches of
+    /// [`DEFAULT_RPC_BATCH_SIZE`].
+    pub async fn get_block_headers(
+        &self,
+        block_numbers: &[u64],
+    ) -> Result<Vec<alloy::rpc::types::Header>, anyhow::Error> {
+        get_block_headers(&self.client, block_numbers, DEFAULT_RPC_BATCH_SIZE).await
+    }
 }
 
 impl AlloyRpc for AlloyProvider {
@@ -91,7 +158,7 @@ impl AlloyRpc for AlloyProvider {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, From, FromStr, Educe)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone, From, FromStr, Educe)]
 #[serde(transparent)]
 #[educe(Default)]
 pub struct L1RpcEndpoint {
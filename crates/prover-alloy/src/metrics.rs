@@ -0,0 +1,173 @@
+//! Metrics of the calls made to the RPC nodes.
+//!
+//! The [`RpcMetricsLayer`] sits below the retry layer, hence every attempt of
+//! a call is accounted for, and the retries are counted separately by the
+//! retry policy.
+use std::{
+    sync::LazyLock,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use alloy::{
+    rpc::json_rpc::{RequestPacket, Response, ResponsePacket},
+    transports::{RpcError, TransportError, TransportErrorKind, TransportFut},
+};
+use opentelemetry::{
+    global,
+    metrics::{Counter, Histogram},
+    KeyValue,
+};
+use tower::{Layer, Service};
+
+const METER_NAME: &str = "prover_alloy";
+
+pub(crate) static RPC_CALLS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("rpc_calls")
+        .with_description("Number of calls made to the RPC nodes, by method")
+        .build()
+});
+
+pub(crate) static RPC_ERRORS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("rpc_errors")
+        .with_description("Number of failed calls to the RPC nodes, by method and error class")
+        .build()
+});
+
+pub(crate) static RPC_RETRIES: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("rpc_retries")
+        .with_description("Number of retried calls to the RPC nodes, by error class")
+        .build()
+});
+
+pub(crate) static RPC_RETRY_BUDGET_EXHAUSTED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("rpc_retry_budget_exhausted")
+        .with_description("Number of retries denied because the retry budget was exhausted")
+        .build()
+});
+
+pub(crate) static RPC_CALL_DURATION: LazyLock<Histogram<f64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .f64_histogram("rpc_call_duration")
+        .with_description("Duration of the RPC round trips, in seconds")
+        .with_unit("s")
+        .build()
+});
+
+/// Class of a failed RPC call, used as metric label.
+pub(crate) fn error_class(error: &TransportError) -> &'static str {
+    match error {
+        RpcError::ErrorResp(payload) if payload.code == 429 => "rate-limited",
+        RpcError::ErrorResp(_) => "rpc-error",
+        RpcError::NullResp => "null-response",
+        RpcError::SerError(_) | RpcError::DeserError { .. } => "serialization",
+        RpcError::Transport(TransportErrorKind::HttpError(error)) if error.is_rate_limit_err() => {
+            "rate-limited"
+        }
+        RpcError::Transport(TransportErrorKind::HttpError(_)) => "http",
+        RpcError::Transport(TransportErrorKind::BackendGone) => "backend-gone",
+        RpcError::Transport(_) => "transport",
+        _ => "other",
+    }
+}
+
+/// Layer recording the [`RPC_CALLS`], [`RPC_ERRORS`] and [`RPC_CALL_DURATION`]
+/// metrics of the wrapped transport.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RpcMetricsLayer;
+
+impl<S> Layer<S> for RpcMetricsLayer {
+    type Service = RpcMetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMetricsService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RpcMetricsService<S> {
+    inner: S,
+}
+
+impl<S> Service<RequestPacket> for RpcMetricsService<S>
+where
+    S: Service<
+            RequestPacket,
+            Response = ResponsePacket,
+            Error = TransportError,
+            Future = TransportFut<'static>,
+        > + Send
+        + 'static,
+{
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: RequestPacket) -> Self::Future {
+        let methods: Vec<String> = match &request {
+            RequestPacket::Single(request) => vec![request.method().to_string()],
+            RequestPacket::Batch(requests) => requests
+                .iter()
+                .map(|request| request.method().to_string())
+                .collect(),
+        };
+        for method in &methods {
+            RPC_CALLS.add(1, &[KeyValue::new("method", method.clone())]);
+        }
+
+        let start = Instant::now();
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let result = future.await;
+            RPC_CALL_DURATION.record(start.elapsed().as_secs_f64(), &[]);
+
+            match &result {
+                Ok(response) => record_error_responses(&methods, response),
+                Err(error) => {
+                    let class = error_class(error);
+                    for method in &methods {
+                        RPC_ERRORS.add(
+                            1,
+                            &[
+                                KeyValue::new("method", method.clone()),
+                                KeyValue::new("class", class),
+                            ],
+                        );
+                    }
+                }
+            }
+
+            result
+        })
+    }
+}
+
+/// Records the calls answered with an error payload. The responses of a batch
+/// are matched with the requests by position, as a best effort.
+fn record_error_responses(methods: &[String], response: &ResponsePacket) {
+    let responses: Vec<&Response> = match response {
+        ResponsePacket::Single(response) => vec![response],
+        ResponsePacket::Batch(responses) => responses.iter().collect(),
+    };
+
+    for (response, method) in responses.into_iter().zip(methods) {
+        if response.is_error() {
+            RPC_ERRORS.add(
+                1,
+                &[
+                    KeyValue::new("method", method.clone()),
+                    KeyValue::new("class", "rpc-error"),
+                ],
+            );
+        }
+    }
+}
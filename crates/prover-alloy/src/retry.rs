@@ -0,0 +1,167 @@
+//! Retry policy of the calls to the RPC nodes, bounded by a retry budget.
+//!
+//! During an outage of the RPC provider every in-flight call would retry on
+//! its own, multiplying the load on the provider when it comes back. The
+//! retries are hence drawn from a budget shared by all the providers of the
+//! process, which refills over time.
+use std::{
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use alloy::transports::{
+    layers::{RateLimitRetryPolicy, RetryBackoffLayer, RetryPolicy},
+    TransportError,
+};
+use opentelemetry::KeyValue;
+use tracing::warn;
+
+use crate::metrics::{error_class, RPC_RETRIES, RPC_RETRY_BUDGET_EXHAUSTED};
+
+/// Maximum number of retries which can be spent in a burst.
+pub const DEFAULT_RETRY_BUDGET_CAPACITY: u32 = 200;
+/// Number of retries the budget regains per second.
+pub const DEFAULT_RETRY_BUDGET_REFILL_PER_SECOND: u32 = 10;
+
+/// Compute units per second assumed by the retry backoff, as in alloy.
+const COMPUTE_UNITS_PER_SECOND: u64 = 5;
+
+/// Budget shared by all the providers built by this crate.
+pub static GLOBAL_RETRY_BUDGET: LazyLock<Arc<RetryBudget>> = LazyLock::new(|| {
+    Arc::new(RetryBudget::new(
+        DEFAULT_RETRY_BUDGET_CAPACITY,
+        DEFAULT_RETRY_BUDGET_REFILL_PER_SECOND,
+    ))
+});
+
+/// Token bucket bounding the rate of the retries.
+#[derive(Debug)]
+pub struct RetryBudget {
+    capacity: f64,
+    refill_per_second: f64,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Debug)]
+struct BudgetState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    pub fn new(capacity: u32, refill_per_second: u32) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_second: f64::from(refill_per_second),
+            state: Mutex::new(BudgetState {
+                tokens: f64::from(capacity),
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Withdraws one retry from the budget, returning whether it was
+    /// available.
+    pub fn try_withdraw(&self) -> bool {
+        self.try_withdraw_at(Instant::now())
+    }
+
+    fn try_withdraw_at(&self, now: Instant) -> bool {
+        let mut state = self.state.lock().expect("retry budget lock poisoned");
+
+        let elapsed = now.saturating_duration_since(state.refilled_at);
+        state.tokens =
+            (state.tokens + elapsed.as_secs_f64() * self.refill_per_second).min(self.capacity);
+        state.refilled_at = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Retries the rate limited and transient errors, as long as the retry budget
+/// allows it.
+#[derive(Debug, Clone)]
+pub struct BudgetedRetryPolicy {
+    inner: RateLimitRetryPolicy,
+    budget: Arc<RetryBudget>,
+}
+
+impl BudgetedRetryPolicy {
+    pub fn new(budget: Arc<RetryBudget>) -> Self {
+        Self {
+            inner: RateLimitRetryPolicy,
+            budget,
+        }
+    }
+}
+
+impl Default for BudgetedRetryPolicy {
+    fn default() -> Self {
+        Self::new(Arc::clone(&GLOBAL_RETRY_BUDGET))
+    }
+}
+
+impl RetryPolicy for BudgetedRetryPolicy {
+    fn should_retry(&self, error: &TransportError) -> bool {
+        if !self.inner.should_retry(error) {
+            return false;
+        }
+
+        let class = error_class(error);
+        if !self.budget.try_withdraw() {
+            warn!(%error, "RPC retry budget exhausted, not retrying");
+            RPC_RETRY_BUDGET_EXHAUSTED.add(1, &[KeyValue::new("class", class)]);
+            return false;
+        }
+
+        RPC_RETRIES.add(1, &[KeyValue::new("class", class)]);
+        true
+    }
+
+    fn backoff_hint(&self, error: &TransportError) -> Option<Duration> {
+        self.inner.backoff_hint(error)
+    }
+}
+
+/// Retry layer of the providers built by this crate, drawing from the
+/// [`GLOBAL_RETRY_BUDGET`].
+pub(crate) fn retry_layer(
+    backoff: u64,
+    max_retries: u32,
+) -> RetryBackoffLayer<BudgetedRetryPolicy> {
+    RetryBackoffLayer::new_with_policy(
+        max_retries,
+        backoff,
+        COMPUTE_UNITS_PER_SECOND,
+        BudgetedRetryPolicy::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_refills_over_time() {
+        let budget = RetryBudget::new(2, 1);
+        let start = Instant::now();
+
+        assert!(budget.try_withdraw_at(start));
+        assert!(budget.try_withdraw_at(start));
+        assert!(!budget.try_withdraw_at(start));
+
+        assert!(budget.try_withdraw_at(start + Duration::from_secs(1)));
+        assert!(!budget.try_withdraw_at(start + Duration::from_secs(1)));
+
+        // The budget never exceeds its capacity.
+        let later = start + Duration::from_secs(60);
+        assert!(budget.try_withdraw_at(later));
+        assert!(budget.try_withdraw_at(later));
+        assert!(!budget.try_withdraw_at(later));
+    }
+}
@@ -0,0 +1,103 @@
+//! Transports to the RPC nodes, selected by the scheme of the RPC URL.
+use std::time::Duration;
+
+use alloy::{
+    providers::{IpcConnect, ProviderBuilder, WsConnect},
+    rpc::client::ClientBuilder,
+};
+use url::Url;
+
+use crate::{
+    build_alloy_fill_provider, metrics::RpcMetricsLayer, retry::retry_layer, AlloyFillProvider,
+};
+
+/// Interval between two attempts to reconnect a dropped pubsub connection.
+const PUBSUB_RECONNECT_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Transport used to reach one RPC node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcTransport {
+    /// `http://` and `https://` URLs.
+    Http,
+    /// `ws://` and `wss://` URLs.
+    Ws,
+    /// `ipc://` and `file://` URLs, pointing at the path of the IPC socket.
+    Ipc,
+}
+
+impl RpcTransport {
+    pub fn from_url(url: &Url) -> Result<Self, anyhow::Error> {
+        match url.scheme() {
+            "http" | "https" => Ok(Self::Http),
+            "ws" | "wss" => Ok(Self::Ws),
+            "ipc" | "file" => Ok(Self::Ipc),
+            scheme => Err(anyhow::anyhow!("Unsupported RPC URL scheme: {scheme}")),
+        }
+    }
+
+    /// Whether the transport supports subscriptions, e.g. to the new heads.
+    pub fn supports_pubsub(&self) -> bool {
+        matches!(self, Self::Ws | Self::Ipc)
+    }
+}
+
+/// Connects to the RPC node with the transport matching the URL scheme.
+///
+/// The pubsub transports reconnect on their own when the connection drops,
+/// and the active subscriptions are re-established on the new connection.
+pub async fn connect_alloy_fill_provider(
+    rpc_url: &Url,
+    backoff: u64,
+    max_retries: u32,
+) -> Result<AlloyFillProvider, anyhow::Error> {
+    let retry_policy = retry_layer(backoff, max_retries);
+
+    let client = match RpcTransport::from_url(rpc_url)? {
+        RpcTransport::Http => return build_alloy_fill_provider(rpc_url, backoff, max_retries),
+        RpcTransport::Ws => {
+            let ws = WsConnect::new(rpc_url.as_str())
+                .with_max_retries(max_retries)
+                .with_retry_interval(PUBSUB_RECONNECT_INTERVAL);
+
+            ClientBuilder::default()
+                .layer(retry_policy)
+                .layer(RpcMetricsLayer)
+                .ws(ws)
+                .await?
+        }
+        RpcTransport::Ipc => {
+            let ipc = IpcConnect::new(rpc_url.path().to_string());
+
+            ClientBuilder::default()
+                .layer(retry_policy)
+                .layer(RpcMetricsLayer)
+                .ipc(ipc)
+                .await?
+        }
+    };
+
+    Ok(ProviderBuilder::new().on_client(client))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn transport_from_url_scheme() {
+        let transport = |url: &str| RpcTransport::from_url(&Url::from_str(url).unwrap());
+
+        assert_eq!(
+            transport("https://rpc.example").unwrap(),
+            RpcTransport::Http
+        );
+        assert_eq!(transport("wss://rpc.example").unwrap(), RpcTransport::Ws);
+        assert_eq!(transport("ipc:///tmp/geth.ipc").unwrap(), RpcTransport::Ipc);
+        assert!(transport("ftp://rpc.example").is_err());
+
+        assert!(!RpcTransport::Http.supports_pubsub());
+        assert!(RpcTransport::Ws.supports_pubsub());
+    }
+}
@@ -1,6 +1,7 @@
-use std::{str::FromStr, time::Duration};
+use std::{collections::BTreeMap, path::PathBuf, str::FromStr, time::Duration};
 
-use prover_utils::{from_env_or_default, with};
+use prover_utils::{encryption::EncryptionConfig, from_env_or_default, secret::Secret, with};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use url::Url;
@@ -9,12 +10,15 @@ use url::Url;
 const DEFAULT_SP1_CLUSTER_ENDPOINT: &str = "https://rpc.production.succinct.xyz/";
 
 /// Type of the prover to be used for generation of the pessimistic proof
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub enum ProverType {
     NetworkProver(NetworkProverConfig),
     CpuProver(CpuProverConfig),
     MockProver(MockProverConfig),
+    /// Proving service speaking the generic remote prover gRPC protocol,
+    /// instead of the Succinct proving network.
+    GrpcProver(GrpcProverConfig),
 }
 
 impl Default for ProverType {
@@ -23,19 +27,43 @@ impl Default for ProverType {
     }
 }
 
+/// SP1 proof mode requested from the provers.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProofType {
+    /// Proof of every shard of the execution, the fastest to generate.
+    Core,
+    /// Constant size STARK proof, verifiable within SP1 for aggregation.
+    Compressed,
+    /// Groth16 SNARK proof, the cheapest to verify on-chain.
+    Groth16,
+    /// Plonk SNARK proof, verifiable on-chain without a trusted setup.
+    Plonk,
+}
+
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct CpuProverConfig {
     #[serde(default = "default_max_concurrency_limit")]
     pub max_concurrency_limit: usize,
 
     #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    #[schemars(with = "Option<crate::with::HumanDuration>")]
     pub proving_request_timeout: Option<Duration>,
 
     #[serde(default = "default_local_proving_timeout")]
     #[serde(with = "crate::with::HumanDuration")]
     pub proving_timeout: Duration,
+
+    /// Memory ceiling of the proofs, unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_limit: Option<MemoryLimitConfig>,
+
+    /// SP1 circuit artifacts installed at startup, downloaded by the first
+    /// SNARK proof otherwise.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub circuit_artifacts: Option<CircuitArtifactsConfig>,
 }
 
 impl CpuProverConfig {
@@ -54,15 +82,108 @@ impl Default for CpuProverConfig {
             max_concurrency_limit: default_max_concurrency_limit(),
             proving_request_timeout: None,
             proving_timeout: default_local_proving_timeout(),
+            memory_limit: None,
+            circuit_artifacts: None,
+        }
+    }
+}
+
+/// SNARK circuit wrapping the compressed proofs, whose artifacts are
+/// downloaded from Succinct.
+#[derive(
+    Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum SnarkCircuit {
+    Groth16,
+    Plonk,
+}
+
+impl SnarkCircuit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SnarkCircuit::Groth16 => "groth16",
+            SnarkCircuit::Plonk => "plonk",
+        }
+    }
+}
+
+impl std::fmt::Display for SnarkCircuit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Local installation of the SP1 circuit artifacts, the proving keys of the
+/// SNARK circuits being gigabytes large.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct CircuitArtifactsConfig {
+    /// Directory where the artifacts are installed, one subdirectory per
+    /// circuit and SP1 circuit version.
+    pub dir: PathBuf,
+
+    /// Circuits installed at startup.
+    #[serde(default = "default_installed_circuits")]
+    pub circuits: Vec<SnarkCircuit>,
+
+    /// Expected SHA-256 digest of the artifacts of the circuits, as logged
+    /// when installed. The artifacts are otherwise checked against the
+    /// digest recorded at installation.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub checksums: BTreeMap<SnarkCircuit, String>,
+}
+
+fn default_installed_circuits() -> Vec<SnarkCircuit> {
+    vec![SnarkCircuit::Groth16]
+}
+
+/// Memory ceiling of the proofs generated on the host, the requests which
+/// would exceed it being rejected before proving rather than the process
+/// being OOM-killed along with the proofs in flight.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct MemoryLimitConfig {
+    /// Memory the proofs running at once may use, in bytes. Defaults to the
+    /// memory limit of the cgroup of the process, or to the memory of the
+    /// host.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ceiling: Option<u64>,
+
+    /// Memory used by a proof whatever its inputs, in bytes.
+    #[serde(default = "default_base_memory")]
+    pub base_memory: u64,
+
+    /// Memory used per byte of the inputs of a proof, the witness being
+    /// expanded into the execution trace.
+    #[serde(default = "default_memory_per_input_byte")]
+    pub memory_per_input_byte: u64,
+}
+
+impl Default for MemoryLimitConfig {
+    fn default() -> Self {
+        Self {
+            ceiling: None,
+            base_memory: default_base_memory(),
+            memory_per_input_byte: default_memory_per_input_byte(),
         }
     }
 }
 
+const fn default_base_memory() -> u64 {
+    8 << 30
+}
+
+const fn default_memory_per_input_byte() -> u64 {
+    256
+}
+
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct NetworkProverConfig {
     #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    #[schemars(with = "Option<crate::with::HumanDuration>")]
     pub proving_request_timeout: Option<Duration>,
 
     #[serde(default = "default_network_proving_timeout")]
@@ -72,6 +193,12 @@ pub struct NetworkProverConfig {
     /// The sp1 proving cluster endpoint.
     #[serde(default = "default_sp1_cluster_endpoint")]
     pub sp1_cluster_endpoint: url::Url,
+
+    /// Private key of the proving cluster account, e.g.
+    /// `file:/run/secrets/network-key`. Read from `NETWORK_PRIVATE_KEY` when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<Secret>,
 }
 
 impl NetworkProverConfig {
@@ -90,18 +217,51 @@ impl Default for NetworkProverConfig {
             proving_request_timeout: None,
             proving_timeout: default_network_proving_timeout(),
             sp1_cluster_endpoint: default_sp1_cluster_endpoint(),
+            private_key: None,
         }
     }
 }
 
 #[serde_as]
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct GrpcProverConfig {
+    /// Endpoint of the proving service, e.g. `https://prover.example.com:443`.
+    pub endpoint: Url,
+
+    /// API key sent to the proving service in the `x-api-key` metadata, e.g.
+    /// `file:/run/secrets/prover-api-key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<Secret>,
+
+    #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    #[schemars(with = "Option<crate::with::HumanDuration>")]
+    pub proving_request_timeout: Option<Duration>,
+
+    #[serde(default = "default_network_proving_timeout")]
+    #[serde(with = "crate::with::HumanDuration")]
+    pub proving_timeout: Duration,
+}
+
+impl GrpcProverConfig {
+    // This constant represents the number of second added to the proving_timeout
+    pub const DEFAULT_PROVING_TIMEOUT_PADDING: Duration = Duration::from_secs(1);
+
+    pub fn get_proving_request_timeout(&self) -> Duration {
+        self.proving_request_timeout
+            .unwrap_or_else(|| self.proving_timeout + Self::DEFAULT_PROVING_TIMEOUT_PADDING)
+    }
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 #[serde(rename_all = "kebab-case")]
 pub struct MockProverConfig {
     #[serde(default = "default_max_concurrency_limit")]
     pub max_concurrency_limit: usize,
 
     #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    #[schemars(with = "Option<crate::with::HumanDuration>")]
     pub proving_request_timeout: Option<Duration>,
 
     #[serde(default = "default_local_proving_timeout")]
@@ -129,6 +289,80 @@ impl Default for MockProverConfig {
     }
 }
 
+/// Expected identity of the program served by the prover, checked at startup
+/// so that a vkey drift is caught before any proof gets generated.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProgramCheckConfig {
+    /// Expected vkey of the program, as a `0x` prefixed bytes32 hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_vkey: Option<String>,
+
+    /// SP1 circuit version run by the proving cluster, e.g. `v5.0.0`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_sp1_version: Option<String>,
+}
+
+/// Capture of the proven requests as golden vectors, replayed against the
+/// upcoming versions of the program.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct GoldenCaptureConfig {
+    /// Directory where the golden vectors are written.
+    pub output_dir: PathBuf,
+
+    /// Keys the golden vectors are encrypted with, as they carry the
+    /// witnesses of the requests. Plain when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<EncryptionConfig>,
+}
+
+/// Accounting of the cost of the proofs, in cycles and in proving network
+/// credits, counted by executing the program before proving it.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct CostAccountingConfig {
+    /// Credits charged by the proving network per million cycles, used to
+    /// estimate the credits spent on the proofs.
+    #[serde(default)]
+    pub credits_per_million_cycles: u64,
+
+    /// Cycles which may be proven for a network per calendar month (UTC),
+    /// the proof requests being rejected once exhausted. Unlimited when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_cycle_budget: Option<u64>,
+}
+
+/// Pool of workers executing the program without proving it, used for the
+/// mock proofs and the cycle-count dry runs.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+#[serde(rename_all = "kebab-case")]
+pub struct ExecutorPoolConfig {
+    /// Number of workers, each one keeping its own warm SP1 executor.
+    #[serde(default = "default_executor_pool_size")]
+    pub pool_size: usize,
+
+    /// Directory where the cycle profile of every execution is exported, as
+    /// JSON and as flamegraph folded stacks. Profiling is disabled when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_dir: Option<PathBuf>,
+}
+
+impl Default for ExecutorPoolConfig {
+    fn default() -> Self {
+        Self {
+            pool_size: default_executor_pool_size(),
+            profile_dir: None,
+        }
+    }
+}
+
+fn default_executor_pool_size() -> usize {
+    std::thread::available_parallelism().map_or(1, |parallelism| parallelism.get().min(4))
+}
+
 pub const fn default_max_concurrency_limit() -> usize {
     100
 }
@@ -1,5 +1,9 @@
 use pretty_assertions::assert_eq;
-use prover_config::{CpuProverConfig, MockProverConfig, NetworkProverConfig, ProverType};
+use prover_config::{
+    CircuitArtifactsConfig, CpuProverConfig, GrpcProverConfig, MemoryLimitConfig, MockProverConfig,
+    NetworkProverConfig, ProverType, SnarkCircuit,
+};
+use prover_utils::secret::Secret;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -20,6 +24,7 @@ fn network_prover() {
             proving_request_timeout: Some(std::time::Duration::from_secs(300)),
             proving_timeout: std::time::Duration::from_secs(600),
             sp1_cluster_endpoint: url::Url::parse("https://rpc.production.succinct.xyz/").unwrap(),
+            private_key: None,
         })
     );
 }
@@ -35,6 +40,15 @@ fn cpu_prover() {
             max_concurrency_limit: 10,
             proving_request_timeout: Some(std::time::Duration::from_secs(300)),
             proving_timeout: std::time::Duration::from_secs(600),
+            memory_limit: Some(MemoryLimitConfig {
+                ceiling: Some(64 << 30),
+                ..Default::default()
+            }),
+            circuit_artifacts: Some(CircuitArtifactsConfig {
+                dir: "/var/lib/sp1/circuits".into(),
+                circuits: vec![SnarkCircuit::Groth16, SnarkCircuit::Plonk],
+                checksums: [(SnarkCircuit::Plonk, "ab".repeat(32))].into(),
+            }),
         })
     );
 }
@@ -50,6 +64,7 @@ fn network_and_cpu_prover() {
             proving_request_timeout: Some(std::time::Duration::from_secs(300)),
             proving_timeout: std::time::Duration::from_secs(600),
             sp1_cluster_endpoint: url::Url::parse("https://rpc.production.succinct.xyz/").unwrap(),
+            private_key: None,
         })
     );
 
@@ -59,10 +74,35 @@ fn network_and_cpu_prover() {
             max_concurrency_limit: 10,
             proving_request_timeout: Some(std::time::Duration::from_secs(300)),
             proving_timeout: std::time::Duration::from_secs(600),
+            memory_limit: None,
+            circuit_artifacts: None,
         }))
     );
 }
 
+#[test]
+fn grpc_prover() {
+    let input = "./tests/fixtures/validate_config/prover_config_grpc_prover.toml";
+    let config: TestConfig = toml::from_str(&std::fs::read_to_string(input).unwrap()).unwrap();
+
+    assert_eq!(
+        config.primary_prover,
+        ProverType::GrpcProver(GrpcProverConfig {
+            endpoint: url::Url::parse("https://prover.example.com:443").unwrap(),
+            api_key: Some(Secret::resolve("prover-api-key").unwrap()),
+            proving_request_timeout: None,
+            proving_timeout: std::time::Duration::from_secs(600),
+        })
+    );
+    let ProverType::GrpcProver(config) = config.primary_prover else {
+        unreachable!()
+    };
+    assert_eq!(
+        config.get_proving_request_timeout(),
+        std::time::Duration::from_secs(601)
+    );
+}
+
 #[test]
 fn mock_prover() {
     let input = "./tests/fixtures/validate_config/prover_config_mock_prover.toml";
@@ -2,7 +2,7 @@ use std::{convert::Infallible, future::IntoFuture, net::SocketAddr, time::Durati
 
 use agglayer_telemetry::ServerBuilder as MetricsBuilder;
 use http::{Request, Response};
-use tokio::{net::TcpListener, runtime::Runtime};
+use tokio::{net::TcpListener, runtime::Runtime, sync::watch};
 use tokio_util::sync::CancellationToken;
 use tonic::{
     body::{boxed, BoxBody},
@@ -15,10 +15,12 @@ pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
 
 pub struct ProverEngine {
     rpc_server: axum::Router,
+    admin_server: Option<(SocketAddr, axum::Router)>,
     rpc_runtime: Option<Runtime>,
     metrics_runtime: Option<Runtime>,
     reflection: Vec<&'static [u8]>,
     healthy_service: Vec<&'static str>,
+    serving: Option<watch::Receiver<bool>>,
     cancellation_token: Option<CancellationToken>,
     metric_socket_addr: SocketAddr,
     rpc_socket_addr: SocketAddr,
@@ -33,8 +35,10 @@ impl ProverEngine {
     ) -> Self {
         Self {
             rpc_server: axum::Router::new(),
+            admin_server: None,
             reflection: vec![tonic_health::pb::FILE_DESCRIPTOR_SET],
             healthy_service: vec![],
+            serving: None,
             rpc_runtime: None,
             metrics_runtime: None,
             cancellation_token: None,
@@ -56,6 +60,22 @@ impl ProverEngine {
         self
     }
 
+    /// Serves the given HTTP routes on a dedicated address, kept apart from
+    /// the RPC server as they aren't meant to be exposed to the clients.
+    pub fn set_admin_server(mut self, admin_socket_addr: SocketAddr, router: axum::Router) -> Self {
+        self.admin_server = Some((admin_socket_addr, router));
+
+        self
+    }
+
+    /// Reports the RPC services as serving to the health checks only while
+    /// the watched value is true, e.g. while the instance is the leader.
+    pub fn set_serving(mut self, serving: watch::Receiver<bool>) -> Self {
+        self.serving = Some(serving);
+
+        self
+    }
+
     pub fn set_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
         self.cancellation_token = Some(cancellation_token);
         self
@@ -158,13 +178,35 @@ impl ProverEngine {
         })?;
 
         debug!("Setting the health status of the services to healthy");
-        prover_runtime.block_on(async {
-            for service_name in self.healthy_service.iter() {
-                health_reporter
-                    .set_service_status(service_name, tonic_health::ServingStatus::Serving)
-                    .await;
+        match self.serving.take() {
+            None => prover_runtime.block_on(async {
+                for service_name in self.healthy_service.iter() {
+                    health_reporter
+                        .set_service_status(service_name, tonic_health::ServingStatus::Serving)
+                        .await;
+                }
+            }),
+            Some(mut serving) => {
+                let services = self.healthy_service.clone();
+                prover_runtime.spawn(async move {
+                    loop {
+                        let status = if *serving.borrow_and_update() {
+                            tonic_health::ServingStatus::Serving
+                        } else {
+                            tonic_health::ServingStatus::NotServing
+                        };
+                        for service_name in services.iter() {
+                            health_reporter
+                                .set_service_status(service_name, status)
+                                .await;
+                        }
+                        if serving.changed().await.is_err() {
+                            break;
+                        }
+                    }
+                });
             }
-        });
+        }
 
         debug!("Adding the reflection and health services to the RPC server");
         // Adding the reflection and health services to the RPC server
@@ -179,6 +221,23 @@ impl ProverEngine {
                 .into_future(),
         );
 
+        let admin_handle = match self.admin_server.take() {
+            Some((admin_socket_addr, router)) => {
+                let tcp_listener = prover_runtime.block_on(TcpListener::bind(admin_socket_addr))?;
+                let token = cancellation_token.clone();
+                info!("Admin server started on {admin_socket_addr}");
+
+                Some(
+                    prover_runtime.spawn(
+                        axum::serve(tcp_listener, router)
+                            .with_graceful_shutdown(async move { token.cancelled().await })
+                            .into_future(),
+                    ),
+                )
+            }
+            None => None,
+        };
+
         info!("Metrics server started on {}", self.metric_socket_addr);
         info!("RPC server started on {}", self.rpc_socket_addr);
         let terminate_signal = async {
@@ -199,6 +258,10 @@ impl ProverEngine {
                         cancellation_token.cancel();
                         // Wait for the prover to shutdown.
                         _ = prover_handle.await;
+                        // Wait for the admin server to shutdown.
+                        if let Some(admin_handle) = admin_handle {
+                            _ = admin_handle.await;
+                        }
                         // Wait for the metrics server to shutdown.
                         _ = metrics_handle.await;
                     }
@@ -208,6 +271,10 @@ impl ProverEngine {
                         cancellation_token.cancel();
                         // Wait for the prover to shutdown.
                         _ = prover_handle.await;
+                        // Wait for the admin server to shutdown.
+                        if let Some(admin_handle) = admin_handle {
+                            _ = admin_handle.await;
+                        }
                         // Wait for the metrics server to shutdown.
                         _ = metrics_handle.await;
                     }
@@ -0,0 +1,328 @@
+//! Stable error codes shared by the agglayer and the aggkit provers, so that
+//! their clients tell the retriable errors from the fatal ones alike.
+//!
+//! Every error reported on the gRPC APIs carries its code and its category in
+//! the [`ERROR_CODE_METADATA`] and [`ERROR_CATEGORY_METADATA`] metadata. The
+//! statuses whose details are `google.rpc` ones also carry them in an error
+//! info, whose reason is the name of the code. The codes are grouped by
+//! category, by thousand:
+//!
+//! - 1xxx, validation: the request is invalid, and retrying it fails alike;
+//! - 2xxx, upstream: a dependency of the prover failed, e.g. l1 or the
+//!   proposer;
+//! - 3xxx, capacity: the prover can't serve the request now, e.g. rate limited
+//!   or standing by;
+//! - 4xxx, internal: the prover failed.
+//!
+//! The codes are never reused nor renumbered, the new ones being appended to
+//! their category.
+use std::{collections::HashMap, fmt};
+
+use tonic::{metadata::MetadataValue, Code, Status};
+use tonic_types::{ErrorDetails, StatusExt};
+
+/// Metadata of the statuses holding the error code, e.g. `3000`.
+pub const ERROR_CODE_METADATA: &str = "error-code";
+
+/// Metadata of the statuses holding the error category, e.g. `capacity`.
+pub const ERROR_CATEGORY_METADATA: &str = "error-category";
+
+/// Domain of the error info of the statuses.
+pub const ERROR_DOMAIN: &str = "agglayer-provers";
+
+/// Category of an error, telling whether a retry may succeed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    Validation,
+    Upstream,
+    Capacity,
+    Internal,
+}
+
+impl ErrorCategory {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Validation => "validation",
+            Self::Upstream => "upstream",
+            Self::Capacity => "capacity",
+            Self::Internal => "internal",
+        }
+    }
+
+    /// Whether a retry of the request may succeed, once the dependency is
+    /// back or the capacity freed.
+    pub const fn is_retriable(self) -> bool {
+        matches!(self, Self::Upstream | Self::Capacity)
+    }
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown error code {0}")]
+pub struct UnknownErrorCode(pub u16);
+
+macro_rules! error_codes {
+    ($(
+        $(#[doc = $doc:literal])*
+        $name:ident = $code:literal, $reason:literal, $category:ident, $grpc_code:ident;
+    )*) => {
+        /// Stable code of an error reported by a prover.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        #[repr(u16)]
+        pub enum ErrorCode {
+            $($(#[doc = $doc])* $name = $code,)*
+        }
+
+        impl ErrorCode {
+            /// Every code, in the order of their number.
+            pub const ALL: &[Self] = &[$(Self::$name),*];
+
+            pub const fn category(self) -> ErrorCategory {
+                match self {
+                    $(Self::$name => ErrorCategory::$category,)*
+                }
+            }
+
+            /// Name of the code, the reason of the error info of the statuses.
+            pub const fn reason(self) -> &'static str {
+                match self {
+                    $(Self::$name => $reason,)*
+                }
+            }
+
+            /// gRPC code the error is reported with.
+            pub const fn grpc_code(self) -> Code {
+                match self {
+                    $(Self::$name => Code::$grpc_code,)*
+                }
+            }
+        }
+
+        impl TryFrom<u16> for ErrorCode {
+            type Error = UnknownErrorCode;
+
+            fn try_from(code: u16) -> Result<Self, Self::Error> {
+                match code {
+                    $($code => Ok(Self::$name),)*
+                    _ => Err(UnknownErrorCode(code)),
+                }
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// The request is malformed, e.g. a missing or out of range field.
+    InvalidArgument = 1000, "INVALID_ARGUMENT", Validation, InvalidArgument;
+    /// The API key of the request is unknown.
+    Unauthenticated = 1001, "UNAUTHENTICATED", Validation, Unauthenticated;
+    /// The version of the client is no longer served.
+    UnsupportedClientVersion = 1002, "UNSUPPORTED_CLIENT_VERSION", Validation, FailedPrecondition;
+    /// The request id is unknown to the prover.
+    UnknownRequest = 1003, "UNKNOWN_REQUEST", Validation, NotFound;
+    /// A request with the same id is already in flight.
+    DuplicateRequest = 1004, "DUPLICATE_REQUEST", Validation, AlreadyExists;
+    /// The requested program is not served.
+    UnknownProgram = 1005, "UNKNOWN_PROGRAM", Validation, NotFound;
+    /// The request refers to a stale state or replays a settled range.
+    StaleRequest = 1006, "STALE_REQUEST", Validation, FailedPrecondition;
+    /// The request would cost more than allowed, a shorter range being
+    /// accepted.
+    RequestTooCostly = 1007, "REQUEST_TOO_COSTLY", Validation, OutOfRange;
+    /// The request cites a GER unknown to l1.
+    InvalidGer = 1008, "INVALID_GER", Validation, InvalidArgument;
+    /// The request cites an l1 info tree differing from the one of l1.
+    L1InfoTreeMismatch = 1009, "L1_INFO_TREE_MISMATCH", Validation, InvalidArgument;
+    /// The request is rejected by the policy of the prover, e.g. of the
+    /// optimistic mode.
+    RequestRejected = 1010, "REQUEST_REJECTED", Validation, PermissionDenied;
+    /// The program fails on the witness of the request.
+    ExecutionFailed = 1011, "EXECUTION_FAILED", Validation, InvalidArgument;
+    /// The proof generated for the request does not verify.
+    ProofVerificationFailed = 1012, "PROOF_VERIFICATION_FAILED", Validation, InvalidArgument;
+
+    /// l1 can't be read, e.g. to check the GERs of the request.
+    L1Unavailable = 2000, "L1_UNAVAILABLE", Upstream, Unavailable;
+    /// The proposer failed to serve the aggregation proof.
+    ProposerFailed = 2001, "PROPOSER_FAILED", Upstream, Unavailable;
+    /// The proving network failed to prove the request.
+    ProverFailed = 2002, "PROVER_FAILED", Upstream, Internal;
+
+    /// The client exceeded its rate limit.
+    RateLimited = 3000, "RATE_LIMITED", Capacity, ResourceExhausted;
+    /// The monthly cycle budget of the network is exhausted.
+    CycleBudgetExhausted = 3001, "CYCLE_BUDGET_EXHAUSTED", Capacity, ResourceExhausted;
+    /// The tenant of the client has as many requests in flight as allowed.
+    TenantConcurrencyExceeded = 3002, "TENANT_CONCURRENCY_EXCEEDED", Capacity, ResourceExhausted;
+    /// The monthly cycle budget of the tenant of the client is exhausted.
+    TenantBudgetExhausted = 3003, "TENANT_BUDGET_EXHAUSTED", Capacity, ResourceExhausted;
+    /// The proof requests of the network are disabled by the operator.
+    NetworkDisabled = 3004, "NETWORK_DISABLED", Capacity, Unavailable;
+    /// The prover instance is on standby, the requests being served by the
+    /// leader.
+    Standby = 3005, "STANDBY", Capacity, Unavailable;
+
+    /// The prover failed.
+    Internal = 4000, "INTERNAL", Internal, Internal;
+    /// The proof generation failed.
+    ProvingFailed = 4001, "PROVING_FAILED", Internal, Internal;
+    /// The prover can't be run, e.g. failed to initialize.
+    ProverUnavailable = 4002, "PROVER_UNAVAILABLE", Internal, Internal;
+}
+
+impl ErrorCode {
+    pub const fn code(self) -> u16 {
+        self as u16
+    }
+
+    /// Status of the error, along with its error info.
+    pub fn status(self, message: impl Into<String>) -> Status {
+        self.status_with_details(message, ErrorDetails::new())
+    }
+
+    /// Status of the error, the error info being added to the details.
+    pub fn status_with_details(self, message: impl Into<String>, details: ErrorDetails) -> Status {
+        self.status_with_metadata(message, details, HashMap::new())
+    }
+
+    /// Status of the error, the error info, holding the given metadata, e.g.
+    /// the tenant, being added to the details.
+    pub fn status_with_metadata(
+        self,
+        message: impl Into<String>,
+        mut details: ErrorDetails,
+        mut metadata: HashMap<String, String>,
+    ) -> Status {
+        metadata.insert("code".to_string(), self.code().to_string());
+        metadata.insert("category".to_string(), self.category().to_string());
+        details.set_error_info(self.reason(), ERROR_DOMAIN, metadata);
+
+        let mut status = Status::with_error_details(self.grpc_code(), message, details);
+        self.annotate(&mut status);
+        status
+    }
+
+    /// Adds the code and the category of the error to the metadata of the
+    /// status, e.g. of the statuses whose details are not `google.rpc` ones.
+    pub fn annotate(self, status: &mut Status) {
+        let metadata = status.metadata_mut();
+        metadata.insert(ERROR_CODE_METADATA, self.code().into());
+        metadata.insert(
+            ERROR_CATEGORY_METADATA,
+            MetadataValue::from_static(self.category().as_str()),
+        );
+    }
+
+    /// Code of the status, if reported by a prover aware of the codes.
+    pub fn of(status: &Status) -> Option<Self> {
+        status
+            .metadata()
+            .get(ERROR_CODE_METADATA)?
+            .to_str()
+            .ok()?
+            .parse::<u16>()
+            .ok()?
+            .try_into()
+            .ok()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.reason(), self.code())
+    }
+}
+
+/// Whether a retry of the request may succeed, from the category of its
+/// error, or from the gRPC code of the statuses without any, e.g. of the
+/// provers predating the codes.
+pub fn is_retriable(status: &Status) -> bool {
+    match ErrorCode::of(status) {
+        Some(code) => code.category().is_retriable(),
+        None => matches!(
+            status.code(),
+            Code::Unavailable | Code::ResourceExhausted | Code::Aborted
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codes_are_grouped_by_category() {
+        for code in ErrorCode::ALL {
+            let category = match code.code() / 1000 {
+                1 => ErrorCategory::Validation,
+                2 => ErrorCategory::Upstream,
+                3 => ErrorCategory::Capacity,
+                4 => ErrorCategory::Internal,
+                _ => panic!("{code} is out of the ranges of the categories"),
+            };
+            assert_eq!(code.category(), category, "{code}");
+            assert_eq!(ErrorCode::try_from(code.code()).unwrap(), *code);
+        }
+
+        let mut reasons = ErrorCode::ALL
+            .iter()
+            .map(|code| code.reason())
+            .collect::<Vec<_>>();
+        reasons.sort();
+        reasons.dedup();
+        assert_eq!(reasons.len(), ErrorCode::ALL.len());
+        assert!(ErrorCode::try_from(999).is_err());
+    }
+
+    #[test]
+    fn statuses_carry_their_code() {
+        let mut details = ErrorDetails::new();
+        details.add_bad_request_violation("requested_end_block", "at most 100");
+        let status = ErrorCode::RequestTooCostly.status_with_metadata(
+            "too costly",
+            details,
+            HashMap::from([("network-id".to_string(), "1".to_string())]),
+        );
+
+        assert_eq!(status.code(), Code::OutOfRange);
+        assert_eq!(ErrorCode::of(&status), Some(ErrorCode::RequestTooCostly));
+        let category = status.metadata().get(ERROR_CATEGORY_METADATA).unwrap();
+        assert_eq!(category.to_str().unwrap(), "validation");
+        let error_info = status.get_details_error_info().unwrap();
+        assert_eq!(error_info.reason, "REQUEST_TOO_COSTLY");
+        assert_eq!(error_info.metadata["code"], "1007");
+        assert_eq!(error_info.metadata["network-id"], "1");
+        assert_eq!(
+            status
+                .get_details_bad_request()
+                .unwrap()
+                .field_violations
+                .len(),
+            1
+        );
+        assert!(!is_retriable(&status));
+    }
+
+    #[test]
+    fn retries_follow_the_category() {
+        assert!(is_retriable(&ErrorCode::Standby.status("standby")));
+        assert!(is_retriable(&ErrorCode::ProverFailed.status("failed")));
+        assert!(!is_retriable(&ErrorCode::Internal.status("failed")));
+
+        // Annotated statuses keep their own details and code.
+        let mut status = Status::with_details(Code::Internal, "failed", vec![1, 2, 3].into());
+        ErrorCode::ProverFailed.annotate(&mut status);
+        assert!(is_retriable(&status));
+        assert_eq!(status.details(), &[1, 2, 3]);
+
+        // Statuses without any code are told apart by their gRPC code.
+        assert!(is_retriable(&Status::unavailable("down")));
+        assert!(!is_retriable(&Status::internal("failed")));
+    }
+}
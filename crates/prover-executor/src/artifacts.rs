@@ -0,0 +1,288 @@
+//! Installation of the SP1 circuit artifacts before serving.
+//!
+//! The cpu prover downloads the artifacts of the Groth16 and Plonk circuits,
+//! gigabytes large, the first time it wraps a proof into a SNARK, blowing the
+//! deadline of that request. They are instead installed at startup into the
+//! configured directory, checked against their digest, and SP1 is pointed at
+//! them through the `SP1_GROTH16_CIRCUIT_PATH` and `SP1_PLONK_CIRCUIT_PATH`
+//! variables.
+//!
+//! The digest of the artifacts is the SHA-256 of their files, in the order of
+//! their relative paths, each one prefixed with its path and its length. It is
+//! recorded next to the artifacts once installed, and the artifacts which
+//! don't match it anymore are installed again.
+use std::{
+    fs,
+    io::{self, Read as _},
+    path::{Path, PathBuf},
+};
+
+use prover_config::{CircuitArtifactsConfig, SnarkCircuit};
+use sha2::{Digest as _, Sha256};
+use sp1_sdk::SP1_CIRCUIT_VERSION;
+use tracing::{info, warn};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArtifactsError {
+    #[error("Circuit artifacts directory {dir:?} is not writable")]
+    Unwritable {
+        dir: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("Unable to install the {circuit} circuit artifacts into {dir:?}")]
+    Io {
+        circuit: SnarkCircuit,
+        dir: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("Unable to download the {circuit} circuit artifacts")]
+    Download { circuit: SnarkCircuit },
+    #[error(
+        "The {circuit} circuit artifacts were installed into {installed:?} instead of {dir:?}"
+    )]
+    Misplaced {
+        circuit: SnarkCircuit,
+        dir: PathBuf,
+        installed: PathBuf,
+    },
+    #[error(
+        "Checksum mismatch of the {circuit} circuit artifacts: expected {expected}, got {actual}"
+    )]
+    ChecksumMismatch {
+        circuit: SnarkCircuit,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Installs the configured circuits, downloading the missing ones, and points
+/// SP1 at them.
+///
+/// Meant to be called at startup: the SP1 variables are set for the whole
+/// process.
+pub fn install(config: &CircuitArtifactsConfig) -> Result<(), ArtifactsError> {
+    check_writable(&config.dir)?;
+
+    for circuit in &config.circuits {
+        let dir = install_circuit(config, *circuit)?;
+        std::env::set_var(circuit_path_var(*circuit), &dir);
+    }
+
+    Ok(())
+}
+
+fn install_circuit(
+    config: &CircuitArtifactsConfig,
+    circuit: SnarkCircuit,
+) -> Result<PathBuf, ArtifactsError> {
+    let parent = config.dir.join(circuit.as_str());
+    let dir = parent.join(SP1_CIRCUIT_VERSION);
+    let manifest = parent.join(format!("{SP1_CIRCUIT_VERSION}.sha256"));
+    let io_error = |source| ArtifactsError::Io {
+        circuit,
+        dir: dir.clone(),
+        source,
+    };
+
+    if dir.exists() {
+        let recorded = fs::read_to_string(&manifest).ok();
+        let actual = digest(&dir).map_err(io_error)?;
+        if recorded.as_deref().map(str::trim) == Some(actual.as_str()) {
+            check_expected(config, circuit, &actual)?;
+            info!(%circuit, digest = actual, ?dir, "Circuit artifacts checked");
+
+            return Ok(dir);
+        }
+
+        warn!(%circuit, ?dir, "Circuit artifacts don't match their digest, installing them again");
+        fs::remove_dir_all(&dir).map_err(io_error)?;
+    }
+
+    // Downloaded aside, so that an interrupted download is never mistaken for
+    // installed artifacts.
+    let partial = parent.join(format!("{SP1_CIRCUIT_VERSION}.partial"));
+    if partial.exists() {
+        fs::remove_dir_all(&partial).map_err(io_error)?;
+    }
+    fs::create_dir_all(&parent).map_err(io_error)?;
+
+    info!(%circuit, ?dir, "Downloading the circuit artifacts");
+    download(circuit, &partial)?;
+
+    let actual = digest(&partial).map_err(io_error)?;
+    if let Err(error) = check_expected(config, circuit, &actual) {
+        let _ = fs::remove_dir_all(&partial);
+
+        return Err(error);
+    }
+
+    fs::rename(&partial, &dir).map_err(io_error)?;
+    fs::write(&manifest, format!("{actual}\n")).map_err(io_error)?;
+    info!(%circuit, digest = actual, ?dir, "Circuit artifacts installed");
+
+    Ok(dir)
+}
+
+/// Downloads the artifacts with the installer of SP1, on its own thread as it
+/// runs its own async runtime and panics on failure.
+fn download(circuit: SnarkCircuit, dir: &Path) -> Result<(), ArtifactsError> {
+    std::env::set_var(circuit_path_var(circuit), dir);
+
+    let installed = std::thread::scope(|scope| {
+        scope
+            .spawn(|| sp1_sdk::install::try_install_circuit_artifacts(circuit.as_str()))
+            .join()
+    })
+    .map_err(|_| ArtifactsError::Download { circuit })?;
+
+    if installed != dir {
+        return Err(ArtifactsError::Misplaced {
+            circuit,
+            dir: dir.to_path_buf(),
+            installed,
+        });
+    }
+
+    Ok(())
+}
+
+fn check_expected(
+    config: &CircuitArtifactsConfig,
+    circuit: SnarkCircuit,
+    actual: &str,
+) -> Result<(), ArtifactsError> {
+    match config.checksums.get(&circuit) {
+        Some(expected) if !expected.eq_ignore_ascii_case(actual) => {
+            Err(ArtifactsError::ChecksumMismatch {
+                circuit,
+                expected: expected.clone(),
+                actual: actual.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Fails unless a file can be written into the directory, created if
+/// missing, rather than once the artifacts are downloaded.
+fn check_writable(dir: &Path) -> Result<(), ArtifactsError> {
+    let unwritable = |source| ArtifactsError::Unwritable {
+        dir: dir.to_path_buf(),
+        source,
+    };
+    fs::create_dir_all(dir).map_err(unwritable)?;
+
+    let probe = dir.join(format!(".write-probe-{}", std::process::id()));
+    fs::write(&probe, b"").map_err(unwritable)?;
+    fs::remove_file(&probe).map_err(unwritable)
+}
+
+fn circuit_path_var(circuit: SnarkCircuit) -> &'static str {
+    match circuit {
+        SnarkCircuit::Groth16 => "SP1_GROTH16_CIRCUIT_PATH",
+        SnarkCircuit::Plonk => "SP1_PLONK_CIRCUIT_PATH",
+    }
+}
+
+/// Digest of the files of the directory, as a hex string.
+pub fn digest(dir: &Path) -> io::Result<String> {
+    let mut files = Vec::new();
+    list_files(dir, dir, &mut files)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 1 << 20];
+    for relative in files {
+        let mut file = fs::File::open(dir.join(&relative))?;
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(file.metadata()?.len().to_le_bytes());
+        loop {
+            let read = file.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+        }
+    }
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn list_files(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.push(relative.to_path_buf());
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn installed_artifacts_are_checked() {
+        let root = std::env::temp_dir().join(format!(
+            "prover-executor-circuit-artifacts-{}",
+            std::process::id()
+        ));
+        let parent = root.join("groth16");
+        let dir = parent.join(SP1_CIRCUIT_VERSION);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("groth16_pk.bin"), b"proving key").unwrap();
+        fs::write(dir.join("nested").join("vk.bin"), b"verifying key").unwrap();
+
+        let recorded = digest(&dir).unwrap();
+        fs::write(
+            parent.join(format!("{SP1_CIRCUIT_VERSION}.sha256")),
+            format!("{recorded}\n"),
+        )
+        .unwrap();
+
+        let mut config = CircuitArtifactsConfig {
+            dir: root.clone(),
+            circuits: vec![SnarkCircuit::Groth16],
+            checksums: BTreeMap::from([(SnarkCircuit::Groth16, recorded.to_uppercase())]),
+        };
+        install(&config).unwrap();
+        assert_eq!(
+            std::env::var_os("SP1_GROTH16_CIRCUIT_PATH"),
+            Some(dir.clone().into_os_string())
+        );
+
+        config
+            .checksums
+            .insert(SnarkCircuit::Groth16, "00".repeat(32));
+        assert!(matches!(
+            install(&config),
+            Err(ArtifactsError::ChecksumMismatch {
+                circuit: SnarkCircuit::Groth16,
+                ..
+            })
+        ));
+
+        // The digest covers the names of the files as well as their content.
+        fs::rename(dir.join("groth16_pk.bin"), dir.join("plonk_pk.bin")).unwrap();
+        assert_ne!(digest(&dir).unwrap(), recorded);
+
+        let file = root.join("file");
+        fs::write(&file, b"").unwrap();
+        config.dir = file;
+        assert!(matches!(
+            install(&config),
+            Err(ArtifactsError::Unwritable { .. })
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
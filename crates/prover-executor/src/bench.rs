@@ -0,0 +1,165 @@
+//! Local benchmark of a program on one witness, tracking the regressions of
+//! the cycles, the wall time and the memory across releases.
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use sp1_sdk::{CpuProver, Prover as _, ProverClient, SP1ProvingKey, SP1Stdin};
+
+use crate::{sp1_proof_mode, Error, ProgramIdentity, ProofType};
+
+/// Benchmark of one program, set up once for all the runs.
+pub struct Bench {
+    prover: CpuProver,
+    proving_key: SP1ProvingKey,
+    identity: ProgramIdentity,
+    setup: Duration,
+}
+
+/// Outcome of a benchmark, serialized as the JSON report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct BenchReport {
+    #[serde(flatten)]
+    pub identity: ProgramIdentity,
+    pub iterations: usize,
+    /// Number of RISC-V instructions executed by one run.
+    pub cycles: u64,
+    pub setup_ms: f64,
+    pub execution: PhaseReport,
+    /// Local proving, when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proving: Option<PhaseReport>,
+}
+
+/// Measures of one phase, execution or proving, over all the runs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PhaseReport {
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub max_ms: f64,
+    /// Peak resident memory of the process at the end of the phase, only
+    /// known on Linux.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_rss_bytes: Option<u64>,
+}
+
+impl PhaseReport {
+    fn new(durations: &[Duration]) -> Self {
+        let total: Duration = durations.iter().sum();
+
+        Self {
+            min_ms: durations.iter().min().map_or(0.0, millis),
+            mean_ms: millis(&total) / durations.len().max(1) as f64,
+            max_ms: durations.iter().max().map_or(0.0, millis),
+            peak_rss_bytes: peak_rss_bytes(),
+        }
+    }
+}
+
+impl Bench {
+    pub fn new(program: &[u8]) -> Self {
+        let prover = ProverClient::builder().cpu().build();
+
+        let start = Instant::now();
+        let (proving_key, vkey) = prover.setup(program);
+        let setup = start.elapsed();
+
+        Self {
+            prover,
+            proving_key,
+            identity: ProgramIdentity::from_vkey(&vkey),
+            setup,
+        }
+    }
+
+    /// Executes the program on `stdin` `iterations` times, then proves it as
+    /// many times with `proof_type` if set.
+    pub fn run(
+        &self,
+        stdin: &SP1Stdin,
+        iterations: usize,
+        proof_type: Option<ProofType>,
+    ) -> Result<BenchReport, Error> {
+        let iterations = iterations.max(1);
+
+        let mut cycles = 0;
+        let mut executions = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let (_, report) = self
+                .prover
+                .execute(&self.proving_key.elf, stdin)
+                .run()
+                .map_err(|error| Error::ProverFailed(error.to_string()))?;
+            executions.push(start.elapsed());
+            cycles = report.total_instruction_count();
+        }
+        let execution = PhaseReport::new(&executions);
+
+        let proving = match proof_type {
+            Some(proof_type) => {
+                let mut provings = Vec::with_capacity(iterations);
+                for _ in 0..iterations {
+                    let start = Instant::now();
+                    self.prover
+                        .prove(&self.proving_key, stdin)
+                        .mode(sp1_proof_mode(proof_type))
+                        .run()
+                        .map_err(|error| Error::ProverFailed(error.to_string()))?;
+                    provings.push(start.elapsed());
+                }
+                Some(PhaseReport::new(&provings))
+            }
+            None => None,
+        };
+
+        Ok(BenchReport {
+            identity: self.identity.clone(),
+            iterations,
+            cycles,
+            setup_ms: millis(&self.setup),
+            execution,
+            proving,
+        })
+    }
+}
+
+fn millis(duration: &Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+/// Peak resident memory of the process, from `/proc/self/status`.
+fn peak_rss_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_report() {
+        let report = PhaseReport::new(&[
+            Duration::from_millis(30),
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+        ]);
+
+        assert_eq!(report.min_ms, 10.0);
+        assert_eq!(report.mean_ms, 20.0);
+        assert_eq!(report.max_ms, 30.0);
+        #[cfg(target_os = "linux")]
+        assert!(report.peak_rss_bytes.is_some_and(|bytes| bytes > 0));
+    }
+}
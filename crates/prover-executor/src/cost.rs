@@ -0,0 +1,518 @@
+//! Accounting of the proving cost, in cycles and in proving network credits,
+//! per network and per calendar month.
+//!
+//! The cycles of a request are counted by executing the program alongside
+//! the proving, hence the estimate is known long before the proof. The
+//! actual cost only accounts for the delivered proofs.
+use std::{
+    collections::BTreeMap,
+    fmt,
+    pin::Pin,
+    str::FromStr,
+    sync::{Arc, LazyLock, Mutex},
+    task::{Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::Future;
+use opentelemetry::{global, metrics::Counter, KeyValue};
+use prover_config::{CostAccountingConfig, ExecutorPoolConfig};
+use serde::{Deserialize, Serialize};
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+use crate::{ExecutorPool, Request, Response};
+
+const METER_NAME: &str = "prover_executor";
+
+static PROVING_CYCLES: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("proving_cycles")
+        .with_description("Cycles of the proof requests, by network and estimated or actual cost")
+        .build()
+});
+
+static PROVING_CREDITS: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter(METER_NAME)
+        .u64_counter("proving_credits")
+        .with_description(
+            "Proving network credits of the proof requests, by network and estimated or actual \
+             cost",
+        )
+        .build()
+});
+
+/// Calendar month (UTC), displayed as `2026-10`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Month {
+    pub year: i64,
+    pub month: u32,
+}
+
+impl Month {
+    pub fn now() -> Self {
+        Self::at(SystemTime::now())
+    }
+
+    pub fn at(time: SystemTime) -> Self {
+        let days = time
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs()
+            / 86_400;
+
+        // Civil date of the days since the epoch, after the `civil_from_days`
+        // algorithm of Howard Hinnant.
+        let days = days as i64 + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+
+        Self {
+            year: year_of_era + era * 400 + i64::from(month <= 2),
+            month: month as u32,
+        }
+    }
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+impl FromStr for Month {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid month {value:?}, expected e.g. 2026-10");
+        let (year, month) = value.split_once('-').ok_or_else(invalid)?;
+        let month = Self {
+            year: year.parse().map_err(|_| invalid())?,
+            month: month.parse().map_err(|_| invalid())?,
+        };
+        if !(1..=12).contains(&month.month) {
+            return Err(invalid());
+        }
+
+        Ok(month)
+    }
+}
+
+impl From<Month> for String {
+    fn from(month: Month) -> Self {
+        month.to_string()
+    }
+}
+
+impl TryFrom<String> for Month {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CostTotals {
+    pub requests: u64,
+    pub cycles: u64,
+    pub credits: u64,
+}
+
+/// Cost of the proofs of a network over the current month.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworkCost {
+    pub network_id: u32,
+    pub month: Month,
+    /// Cost of the requests executed, whether proven or not.
+    pub estimated: CostTotals,
+    /// Cost of the delivered proofs.
+    pub actual: CostTotals,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_cycle_budget: Option<u64>,
+}
+
+/// Rejection of a proof request of a network which proved its monthly
+/// budget of cycles.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[error(
+    "Monthly cycle budget of the network {network_id} exhausted, {used} of {budget} cycles proven \
+     in {month}"
+)]
+pub struct BudgetExhausted {
+    pub network_id: u32,
+    pub month: Month,
+    pub budget: u64,
+    pub used: u64,
+}
+
+/// Running totals of the proving cost of every network.
+pub struct CostLedger {
+    credits_per_million_cycles: u64,
+    monthly_cycle_budget: Option<u64>,
+    networks: Mutex<BTreeMap<u32, NetworkCost>>,
+}
+
+impl CostLedger {
+    pub fn new(config: &CostAccountingConfig) -> Self {
+        Self {
+            credits_per_million_cycles: config.credits_per_million_cycles,
+            monthly_cycle_budget: config.monthly_cycle_budget,
+            networks: Default::default(),
+        }
+    }
+
+    /// Checks that the network didn't prove its budget of cycles this month.
+    ///
+    /// The requests proven concurrently are accounted for once proven, hence
+    /// may exceed the budget.
+    pub fn check_budget(&self, network_id: u32) -> Result<(), BudgetExhausted> {
+        self.check_budget_at(network_id, Month::now())
+    }
+
+    /// Records the cost of a request executed.
+    pub fn record_estimate(&self, network_id: u32, cycles: u64) {
+        self.record_at(network_id, cycles, Month::now(), "estimated", |cost| {
+            &mut cost.estimated
+        });
+    }
+
+    /// Records the cost of a proof delivered.
+    pub fn record_actual(&self, network_id: u32, cycles: u64) {
+        self.record_at(network_id, cycles, Month::now(), "actual", |cost| {
+            &mut cost.actual
+        });
+    }
+
+    /// Returns the cost of every network over the current month.
+    pub fn costs(&self) -> Vec<NetworkCost> {
+        self.costs_at(Month::now())
+    }
+
+    /// Replaces the running totals of the networks by the given ones, e.g.
+    /// of a snapshot of another node. The totals of a past month are dropped,
+    /// and the configured budget applies.
+    pub fn restore(&self, costs: impl IntoIterator<Item = NetworkCost>) {
+        self.restore_at(costs, Month::now());
+    }
+
+    fn credits(&self, cycles: u64) -> u64 {
+        (u128::from(cycles) * u128::from(self.credits_per_million_cycles) / 1_000_000)
+            .try_into()
+            .unwrap_or(u64::MAX)
+    }
+
+    fn check_budget_at(&self, network_id: u32, month: Month) -> Result<(), BudgetExhausted> {
+        let Some(budget) = self.monthly_cycle_budget else {
+            return Ok(());
+        };
+        let used = self.with_network(network_id, month, |cost| cost.actual.cycles);
+
+        if used >= budget {
+            Err(BudgetExhausted {
+                network_id,
+                month,
+                budget,
+                used,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn record_at(
+        &self,
+        network_id: u32,
+        cycles: u64,
+        month: Month,
+        kind: &'static str,
+        totals: impl FnOnce(&mut NetworkCost) -> &mut CostTotals,
+    ) {
+        let credits = self.credits(cycles);
+        self.with_network(network_id, month, |cost| {
+            let totals = totals(cost);
+            totals.requests += 1;
+            totals.cycles = totals.cycles.saturating_add(cycles);
+            totals.credits = totals.credits.saturating_add(credits);
+        });
+
+        let attributes = [
+            KeyValue::new("network_id", i64::from(network_id)),
+            KeyValue::new("kind", kind),
+        ];
+        PROVING_CYCLES.add(cycles, &attributes);
+        PROVING_CREDITS.add(credits, &attributes);
+    }
+
+    fn restore_at(&self, costs: impl IntoIterator<Item = NetworkCost>, month: Month) {
+        let mut networks = self.networks.lock().expect("cost ledger lock poisoned");
+
+        for mut cost in costs {
+            cost.monthly_cycle_budget = self.monthly_cycle_budget;
+            self.roll_over(&mut cost, month);
+            networks.insert(cost.network_id, cost);
+        }
+    }
+
+    fn costs_at(&self, month: Month) -> Vec<NetworkCost> {
+        let mut networks = self.networks.lock().expect("cost ledger lock poisoned");
+
+        networks
+            .values_mut()
+            .map(|cost| {
+                self.roll_over(cost, month);
+                cost.clone()
+            })
+            .collect()
+    }
+
+    /// Gives the cost of the network over the month, starting it over when
+    /// the month changed.
+    fn with_network<T>(
+        &self,
+        network_id: u32,
+        month: Month,
+        f: impl FnOnce(&mut NetworkCost) -> T,
+    ) -> T {
+        let mut networks = self.networks.lock().expect("cost ledger lock poisoned");
+        let cost = networks.entry(network_id).or_insert_with(|| NetworkCost {
+            network_id,
+            month,
+            estimated: CostTotals::default(),
+            actual: CostTotals::default(),
+            monthly_cycle_budget: self.monthly_cycle_budget,
+        });
+        self.roll_over(cost, month);
+
+        f(cost)
+    }
+
+    fn roll_over(&self, cost: &mut NetworkCost, month: Month) {
+        if cost.month < month {
+            info!(
+                network_id = cost.network_id,
+                month = %cost.month,
+                estimated = ?cost.estimated,
+                actual = ?cost.actual,
+                "Proving cost of the month"
+            );
+            cost.month = month;
+            cost.estimated = CostTotals::default();
+            cost.actual = CostTotals::default();
+        }
+    }
+}
+
+/// Layer accounting the cost of the proof requests of a network into a
+/// [`CostLedger`].
+#[derive(Clone)]
+pub struct CostAccountingLayer {
+    accounting: Arc<CostAccounting>,
+}
+
+struct CostAccounting {
+    ledger: Arc<CostLedger>,
+    network_id: u32,
+    pool: ExecutorPool,
+}
+
+impl CostAccountingLayer {
+    pub fn new(ledger: Arc<CostLedger>, network_id: u32, elf: &[u8]) -> Self {
+        Self {
+            accounting: Arc::new(CostAccounting {
+                ledger,
+                network_id,
+                pool: ExecutorPool::new(&ExecutorPoolConfig::default(), elf),
+            }),
+        }
+    }
+}
+
+impl<S> Layer<S> for CostAccountingLayer {
+    type Service = CostAccountingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CostAccountingService {
+            inner,
+            accounting: self.accounting.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct CostAccountingService<S> {
+    inner: S,
+    accounting: Arc<CostAccounting>,
+}
+
+impl<S> Service<Request> for CostAccountingService<S>
+where
+    S: Service<Request, Response = Response>,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let accounting = self.accounting.clone();
+        let execution = {
+            let accounting = accounting.clone();
+            let stdin = request.stdin.clone();
+            async move {
+                // Accounting never fails the request.
+                match accounting.pool.execute(stdin).await {
+                    Ok(execution) => {
+                        accounting
+                            .ledger
+                            .record_estimate(accounting.network_id, execution.cycles);
+                        Some(execution.cycles)
+                    }
+                    Err(error) => {
+                        warn!(?error, "Unable to count the cycles of the proof request");
+                        None
+                    }
+                }
+            }
+        };
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let (cycles, response) = futures::future::join(execution, future).await;
+            let response = response?;
+
+            if let Some(cycles) = cycles {
+                accounting
+                    .ledger
+                    .record_actual(accounting.network_id, cycles);
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn months_of_the_calendar() {
+        let month = |secs| Month::at(UNIX_EPOCH + Duration::from_secs(secs)).to_string();
+
+        assert_eq!(month(0), "1970-01");
+        assert_eq!(month(951_782_400), "2000-02");
+        assert_eq!(month(1_790_000_000), "2026-09");
+        assert_eq!(month(4_107_542_400), "2100-03");
+        assert_eq!(
+            "2026-10".parse(),
+            Ok(Month {
+                year: 2026,
+                month: 10
+            })
+        );
+        assert!("2026-13".parse::<Month>().is_err());
+    }
+
+    #[test]
+    fn budget_is_enforced_over_the_month() {
+        let ledger = CostLedger::new(&CostAccountingConfig {
+            credits_per_million_cycles: 2_500,
+            monthly_cycle_budget: Some(10_000_000),
+        });
+        let september = Month {
+            year: 2026,
+            month: 9,
+        };
+        let october = Month {
+            year: 2026,
+            month: 10,
+        };
+
+        ledger.record_at(1, 6_000_000, september, "estimated", |cost| {
+            &mut cost.estimated
+        });
+        ledger.record_at(1, 6_000_000, september, "actual", |cost| &mut cost.actual);
+        assert_eq!(ledger.check_budget_at(1, september), Ok(()));
+        assert_eq!(ledger.check_budget_at(2, september), Ok(()));
+
+        ledger.record_at(1, 4_000_000, september, "actual", |cost| &mut cost.actual);
+        assert_eq!(
+            ledger.check_budget_at(1, september),
+            Err(BudgetExhausted {
+                network_id: 1,
+                month: september,
+                budget: 10_000_000,
+                used: 10_000_000,
+            })
+        );
+        assert_eq!(
+            ledger.costs_at(september)[0].actual,
+            CostTotals {
+                requests: 2,
+                cycles: 10_000_000,
+                credits: 25_000,
+            }
+        );
+
+        assert_eq!(ledger.check_budget_at(1, october), Ok(()));
+        let costs = ledger.costs_at(october);
+        assert_eq!(costs[0].month, october);
+        assert_eq!(costs[0].estimated, CostTotals::default());
+    }
+
+    #[test]
+    fn restored_costs_of_the_month_are_kept() {
+        let ledger = CostLedger::new(&CostAccountingConfig {
+            credits_per_million_cycles: 1_000,
+            monthly_cycle_budget: Some(5_000_000),
+        });
+        let september = Month {
+            year: 2026,
+            month: 9,
+        };
+        let october = Month {
+            year: 2026,
+            month: 10,
+        };
+        let cost = |network_id, month| NetworkCost {
+            network_id,
+            month,
+            estimated: CostTotals::default(),
+            actual: CostTotals {
+                requests: 1,
+                cycles: 6_000_000,
+                credits: 6_000,
+            },
+            monthly_cycle_budget: None,
+        };
+
+        ledger.restore_at([cost(1, september), cost(2, october)], october);
+
+        let costs = ledger.costs_at(october);
+        assert_eq!(costs[0].actual, CostTotals::default());
+        assert_eq!(costs[1].actual.cycles, 6_000_000);
+        assert_eq!(costs[1].monthly_cycle_budget, Some(5_000_000));
+        assert_eq!(ledger.check_budget_at(1, october), Ok(()));
+        assert!(ledger.check_budget_at(2, october).is_err());
+    }
+}
@@ -15,6 +15,25 @@ pub enum Error {
     UnableToInitializePrimaryProver,
     #[error("Unable to initialize the fallback prover")]
     UnableToInitializeFallbackProver,
+    #[error(
+        "Proof rejected, its estimated {estimated} bytes of memory exceed the {available} bytes \
+         left of the {ceiling} bytes ceiling"
+    )]
+    MemoryLimitExceeded {
+        estimated: u64,
+        available: u64,
+        ceiling: u64,
+    },
+}
+
+/// Mismatch between the served program and the expected one, detected at
+/// startup.
+#[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CompatibilityError {
+    #[error("Program vkey mismatch: expected {expected}, computed {computed} from the ELF")]
+    VKeyMismatch { expected: String, computed: String },
+    #[error("SP1 version mismatch: expected {expected}, the prover runs {running}")]
+    Sp1VersionMismatch { expected: String, running: String },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, thiserror::Error, PartialEq, Eq)]
@@ -0,0 +1,300 @@
+//! Golden vectors: proof requests captured along with the public values they
+//! produced, replayed against the current program to detect any drift of the
+//! public values, e.g. before a circuit upgrade.
+//!
+//! The vectors carry the witnesses of the requests, and are encrypted when
+//! keys are configured. Either is read, given the keys.
+use std::{
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::Future;
+use prover_config::{ExecutorPoolConfig, GoldenCaptureConfig};
+use prover_utils::encryption::{self, Cipher, EncryptionError};
+use serde::{Deserialize, Serialize};
+use sp1_sdk::{HashableKey as _, SP1Stdin, SP1VerifyingKey, SP1_CIRCUIT_VERSION};
+use tower::{Layer, Service};
+use tracing::{debug, warn};
+
+use crate::{ExecutorPool, ProofType, Request, Response};
+
+/// Version of the golden vector file format.
+pub const GOLDEN_VECTOR_VERSION: u32 = 1;
+
+/// Extension of the golden vector files.
+pub const GOLDEN_VECTOR_EXTENSION: &str = "golden";
+
+#[derive(Debug, thiserror::Error)]
+pub enum GoldenVectorError {
+    #[error("Unable to access the golden vector")]
+    Io(#[from] std::io::Error),
+    #[error("Unable to encode or decode the golden vector")]
+    Encoding(#[from] bincode::Error),
+    #[error("Unable to encrypt or decrypt the golden vector")]
+    Encryption(#[from] EncryptionError),
+    #[error("Unsupported golden vector version {found}, expected {GOLDEN_VECTOR_VERSION}")]
+    UnsupportedVersion { found: u32 },
+    #[error("Unable to execute the golden vector")]
+    Execution(#[source] crate::Error),
+}
+
+/// Proof request captured along with the public values it produced.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GoldenVector {
+    /// Version of the file format, always serialized first.
+    pub version: u32,
+    /// Name of the program, e.g. `pessimistic` or `aggchain`.
+    pub program: String,
+    /// Vkey of the program which served the request.
+    pub vkey: String,
+    pub sp1_version: String,
+    pub proof_type: ProofType,
+    pub stdin: SP1Stdin,
+    pub public_values: Vec<u8>,
+}
+
+impl GoldenVector {
+    pub fn new(
+        program: impl Into<String>,
+        vkey: &SP1VerifyingKey,
+        request: Request,
+        public_values: Vec<u8>,
+    ) -> Self {
+        Self {
+            version: GOLDEN_VECTOR_VERSION,
+            program: program.into(),
+            vkey: vkey.bytes32(),
+            sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+            proof_type: request.proof_type,
+            stdin: request.stdin,
+            public_values,
+        }
+    }
+
+    /// Reads the vector, decrypted with the cipher if encrypted.
+    pub fn load(path: &Path, cipher: Option<&Cipher>) -> Result<Self, GoldenVectorError> {
+        let bytes = std::fs::read(path)?;
+        let bytes = encryption::decrypt(&bytes, cipher)?;
+
+        // The version is checked first, the rest of the format may differ.
+        let version: u32 = bincode::deserialize(&bytes)?;
+        if version != GOLDEN_VECTOR_VERSION {
+            return Err(GoldenVectorError::UnsupportedVersion { found: version });
+        }
+
+        Ok(bincode::deserialize(&bytes)?)
+    }
+
+    /// Writes the vector, encrypted with the cipher when set.
+    pub fn save(&self, path: &Path, cipher: Option<&Cipher>) -> Result<(), GoldenVectorError> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        // Written aside then renamed, so that a vector is never read partially.
+        let partial = path.with_extension("partial");
+        let bytes = encryption::encrypt(bincode::serialize(self)?, cipher)?;
+        std::fs::write(&partial, bytes)?;
+        std::fs::rename(partial, path)?;
+
+        Ok(())
+    }
+}
+
+/// Layer capturing every successfully proven request as a golden vector in
+/// the configured directory.
+#[derive(Clone)]
+pub struct GoldenCaptureLayer {
+    capture: Arc<GoldenCapture>,
+}
+
+struct GoldenCapture {
+    program: String,
+    vkey: Arc<SP1VerifyingKey>,
+    output_dir: PathBuf,
+    cipher: Option<Cipher>,
+    sequence: AtomicU64,
+}
+
+impl GoldenCaptureLayer {
+    pub fn new(
+        program: impl Into<String>,
+        vkey: Arc<SP1VerifyingKey>,
+        config: &GoldenCaptureConfig,
+    ) -> Self {
+        Self {
+            capture: Arc::new(GoldenCapture {
+                program: program.into(),
+                vkey,
+                output_dir: config.output_dir.clone(),
+                cipher: config.encryption.as_ref().map(Cipher::new),
+                sequence: AtomicU64::new(0),
+            }),
+        }
+    }
+}
+
+impl<S> Layer<S> for GoldenCaptureLayer {
+    type Service = GoldenCaptureService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GoldenCaptureService {
+            inner,
+            capture: self.capture.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GoldenCaptureService<S> {
+    inner: S,
+    capture: Arc<GoldenCapture>,
+}
+
+impl<S> Service<Request> for GoldenCaptureService<S>
+where
+    S: Service<Request, Response = Response>,
+    S::Future: Send + 'static,
+    S::Error: Send + 'static,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        let capture = self.capture.clone();
+        let captured_request = request.clone();
+        let future = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = future.await?;
+
+            let vector = GoldenVector::new(
+                capture.program.clone(),
+                &capture.vkey,
+                captured_request,
+                response.proof.public_values.to_vec(),
+            );
+            // Capturing never fails the request.
+            tokio::task::spawn_blocking(move || capture.save(&vector));
+
+            Ok(response)
+        })
+    }
+}
+
+impl GoldenCapture {
+    fn save(&self, vector: &GoldenVector) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let path = self.output_dir.join(format!(
+            "{}-{timestamp}-{}.{GOLDEN_VECTOR_EXTENSION}",
+            self.program,
+            self.sequence.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        match vector.save(&path, self.cipher.as_ref()) {
+            Ok(()) => debug!(path = %path.display(), "Golden vector captured"),
+            Err(error) => warn!(?error, "Unable to capture the golden vector"),
+        }
+    }
+}
+
+/// Outcome of the replay of a golden vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayOutcome {
+    pub path: PathBuf,
+    /// Whether the vector was captured with another version of the program.
+    pub vkey_changed: bool,
+    /// Public values produced by the current program, when they differ from
+    /// the captured ones.
+    pub drift: Option<Vec<u8>>,
+}
+
+impl ReplayOutcome {
+    pub fn has_drifted(&self) -> bool {
+        self.drift.is_some()
+    }
+}
+
+/// Re-executes golden vectors against the current version of a program.
+pub struct ReplayRunner {
+    program: String,
+    pool: ExecutorPool,
+    cipher: Option<Cipher>,
+}
+
+impl ReplayRunner {
+    pub fn new(program: impl Into<String>, elf: &[u8]) -> Self {
+        Self {
+            program: program.into(),
+            pool: ExecutorPool::new(&ExecutorPoolConfig::default(), elf),
+            cipher: None,
+        }
+    }
+
+    /// Decrypts the encrypted vectors with the cipher.
+    pub fn with_cipher(mut self, cipher: Cipher) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    pub async fn replay(&self, path: &Path) -> Result<ReplayOutcome, GoldenVectorError> {
+        let vector = GoldenVector::load(path, self.cipher.as_ref())?;
+
+        let execution = self
+            .pool
+            .execute(vector.stdin)
+            .await
+            .map_err(GoldenVectorError::Execution)?;
+        let public_values = execution.public_values.to_vec();
+
+        Ok(ReplayOutcome {
+            path: path.to_path_buf(),
+            vkey_changed: !vector
+                .vkey
+                .eq_ignore_ascii_case(&self.pool.get_vkey().bytes32()),
+            drift: (public_values != vector.public_values).then_some(public_values),
+        })
+    }
+
+    /// Replays every golden vector of the program found in `dir`, in the order
+    /// of their file names.
+    pub async fn replay_dir(
+        &self,
+        dir: &Path,
+    ) -> Result<Vec<Result<ReplayOutcome, GoldenVectorError>>, GoldenVectorError> {
+        let prefix = format!("{}-", self.program);
+        let mut paths = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.retain(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == GOLDEN_VECTOR_EXTENSION)
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+        });
+        paths.sort();
+
+        let mut outcomes = Vec::with_capacity(paths.len());
+        for path in paths {
+            outcomes.push(self.replay(&path).await);
+        }
+
+        Ok(outcomes)
+    }
+}
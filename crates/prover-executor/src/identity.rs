@@ -0,0 +1,68 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use sp1_sdk::{HashableKey as _, SP1VerifyingKey, SP1_CIRCUIT_VERSION};
+
+use crate::Executor;
+
+/// Identity of a program, as checked by the verifiers of its proofs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ProgramIdentity {
+    /// Vkey as a bytes32 hex string, as registered on L1.
+    pub vkey: String,
+    /// Vkey hash as committed in the recursive proofs, e.g.
+    /// `AGGREGATION_VKEY_HASH`.
+    pub vkey_hash_u32: [u32; 8],
+    pub sp1_version: String,
+}
+
+impl ProgramIdentity {
+    pub fn from_vkey(vkey: &SP1VerifyingKey) -> Self {
+        Self {
+            vkey: vkey.bytes32(),
+            vkey_hash_u32: vkey.hash_u32(),
+            sp1_version: SP1_CIRCUIT_VERSION.to_string(),
+        }
+    }
+
+    /// Computes the identity of the program, which requires its setup.
+    pub fn compute(program: &[u8]) -> Self {
+        Self::from_vkey(&Executor::compute_program_vkey(program))
+    }
+}
+
+impl fmt::Display for ProgramIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "vkey:          {}", self.vkey)?;
+        writeln!(f, "vkey hash u32: {:?}", self.vkey_hash_u32)?;
+        write!(f, "sp1 version:   {}", self.sp1_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_output() {
+        let identity = ProgramIdentity {
+            vkey: "0x00aa".to_string(),
+            vkey_hash_u32: [1, 2, 3, 4, 5, 6, 7, 8],
+            sp1_version: "v5.0.0".to_string(),
+        };
+
+        assert_eq!(
+            identity.to_string(),
+            "vkey:          0x00aa\nvkey hash u32: [1, 2, 3, 4, 5, 6, 7, 8]\nsp1 version:   v5.0.0"
+        );
+        assert_eq!(
+            serde_json::to_value(&identity).unwrap(),
+            serde_json::json!({
+                "vkey": "0x00aa",
+                "vkey-hash-u32": [1, 2, 3, 4, 5, 6, 7, 8],
+                "sp1-version": "v5.0.0",
+            })
+        );
+    }
+}
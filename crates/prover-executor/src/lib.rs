@@ -2,28 +2,45 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-pub use error::Error;
+pub use error::{CompatibilityError, Error};
 use futures::{Future, TryFutureExt};
-use prover_config::{CpuProverConfig, ProverType};
+pub use prover_config::ProofType;
+use prover_config::{CpuProverConfig, ProgramCheckConfig, ProverType};
 use sp1_sdk::{
-    network::{prover::NetworkProver, FulfillmentStrategy},
-    CpuProver, Prover, ProverClient, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin,
-    SP1VerifyingKey,
+    CpuProver, HashableKey as _, Prover, ProverClient, SP1ProofMode, SP1ProofWithPublicValues,
+    SP1ProvingKey, SP1Stdin, SP1VerifyingKey, SP1_CIRCUIT_VERSION,
 };
 use tokio::task::spawn_blocking;
 use tower::{
     limit::ConcurrencyLimitLayer, timeout::TimeoutLayer, util::BoxCloneService, Service,
     ServiceBuilder, ServiceExt,
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, info_span, warn, Span};
 
 #[cfg(test)]
 mod tests;
 
+pub mod artifacts;
+pub mod bench;
+pub mod cost;
 mod error;
+pub mod golden;
+mod identity;
+pub mod memory;
+mod pool;
+mod profile;
+pub mod remote;
+mod stdin;
+
+pub use identity::ProgramIdentity;
+use memory::MemoryBudget;
+pub use pool::{ExecutionResponse, ExecutorPool};
+pub use profile::{CycleProfile, ProfileExportError, RegionProfile};
+pub use remote::RemoteProver;
+pub use stdin::{StdinBuilder, StdinDump, StdinEntry, StdinEntryKind};
 
 #[derive(Clone)]
 pub struct Executor {
@@ -97,26 +114,36 @@ impl Executor {
         match prover_type {
             ProverType::NetworkProver(network_prover_config) => {
                 debug!("Creating network prover executor...");
-                let network_prover = ProverClient::builder()
+                let mut builder = ProverClient::builder()
                     .network()
-                    .rpc_url(network_prover_config.sp1_cluster_endpoint.as_str())
-                    .build();
-                let (proving_key, verification_key) = network_prover.setup(program);
-                (
-                    verification_key.clone(),
-                    Self::build_network_service(
-                        network_prover_config.get_proving_request_timeout(),
-                        NetworkExecutor {
-                            prover: Arc::new(network_prover),
-                            proving_key,
-                            verification_key,
-                            timeout: network_prover_config.proving_timeout,
-                        },
-                    ),
+                    .rpc_url(network_prover_config.sp1_cluster_endpoint.as_str());
+                if let Some(private_key) = &network_prover_config.private_key {
+                    builder = builder.private_key(private_key.expose());
+                }
+                Self::create_remote_prover(
+                    Arc::new(builder.build()),
+                    network_prover_config.proving_timeout,
+                    network_prover_config.get_proving_request_timeout(),
+                    program,
+                )
+            }
+            ProverType::GrpcProver(grpc_prover_config) => {
+                debug!("Creating gRPC remote prover executor...");
+                let prover = remote::GrpcRemoteProver::new(grpc_prover_config)
+                    .expect("the endpoint of the gRPC prover is valid");
+                Self::create_remote_prover(
+                    Arc::new(prover),
+                    grpc_prover_config.proving_timeout,
+                    grpc_prover_config.get_proving_request_timeout(),
+                    program,
                 )
             }
             ProverType::CpuProver(cpu_prover_config) => {
                 debug!("Creating CPU prover executor...");
+                if let Some(circuit_artifacts) = &cpu_prover_config.circuit_artifacts {
+                    artifacts::install(circuit_artifacts)
+                        .expect("the SP1 circuit artifacts are installed");
+                }
                 let prover = CpuProver::new();
                 let (proving_key, verification_key) = prover.setup(program);
 
@@ -130,6 +157,10 @@ impl Executor {
                             is_mock: false,
                             proving_key,
                             verification_key,
+                            memory: cpu_prover_config
+                                .memory_limit
+                                .as_ref()
+                                .and_then(MemoryBudget::new),
                         },
                     ),
                 )
@@ -149,6 +180,7 @@ impl Executor {
                             is_mock: true,
                             proving_key,
                             verification_key,
+                            memory: None,
                         },
                     ),
                 )
@@ -156,6 +188,28 @@ impl Executor {
         }
     }
 
+    /// Creates the executor of a prover proving the program off the host.
+    pub fn create_remote_prover(
+        prover: Arc<dyn RemoteProver>,
+        proving_timeout: Duration,
+        proving_request_timeout: Duration,
+        program: &[u8],
+    ) -> (SP1VerifyingKey, BoxCloneService<Request, Response, Error>) {
+        let (proving_key, verification_key) = prover.setup(program);
+        (
+            verification_key.clone(),
+            Self::build_network_service(
+                proving_request_timeout,
+                RemoteExecutor {
+                    prover,
+                    proving_key,
+                    verification_key,
+                    timeout: proving_timeout,
+                },
+            ),
+        )
+    }
+
     pub fn new(primary: &ProverType, fallback: &Option<ProverType>, program: &[u8]) -> Self {
         let (vkey, primary) = Self::create_prover(primary, program);
         let fallback = fallback
@@ -168,6 +222,41 @@ impl Executor {
         }
     }
 
+    /// Checks that the served program and the SP1 version match the expected
+    /// ones, so that the prover refuses to serve proofs which would fail to
+    /// settle.
+    pub fn check_compatibility(
+        &self,
+        config: &ProgramCheckConfig,
+    ) -> Result<(), CompatibilityError> {
+        if let Some(expected) = &config.expected_vkey {
+            let computed = self.vkey.bytes32();
+            if !expected.eq_ignore_ascii_case(&computed) {
+                return Err(CompatibilityError::VKeyMismatch {
+                    expected: expected.clone(),
+                    computed,
+                });
+            }
+        }
+
+        if let Some(expected) = &config.expected_sp1_version {
+            if expected != SP1_CIRCUIT_VERSION {
+                return Err(CompatibilityError::Sp1VersionMismatch {
+                    expected: expected.clone(),
+                    running: SP1_CIRCUIT_VERSION.to_string(),
+                });
+            }
+        }
+
+        info!(
+            vkey = self.vkey.bytes32(),
+            sp1_version = SP1_CIRCUIT_VERSION,
+            "Program compatibility checked"
+        );
+
+        Ok(())
+    }
+
     pub fn compute_program_vkey(program: &[u8]) -> SP1VerifyingKey {
         let executor = Executor::new(
             &ProverType::CpuProver(CpuProverConfig::default()),
@@ -178,10 +267,14 @@ impl Executor {
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum ProofType {
-    Stark,
-    Plonk,
+/// SP1 proof mode matching the requested [`ProofType`].
+pub fn sp1_proof_mode(proof_type: ProofType) -> SP1ProofMode {
+    match proof_type {
+        ProofType::Core => SP1ProofMode::Core,
+        ProofType::Compressed => SP1ProofMode::Compressed,
+        ProofType::Groth16 => SP1ProofMode::Groth16,
+        ProofType::Plonk => SP1ProofMode::Plonk,
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -193,6 +286,22 @@ pub struct Request {
 #[derive(Debug, Clone)]
 pub struct Response {
     pub proof: SP1ProofWithPublicValues,
+
+    /// Time spent verifying the proof, unless not verified by the executor.
+    pub verification: Option<Duration>,
+}
+
+impl Response {
+    /// Mode of the produced proof, which may differ from the requested one if
+    /// the prover didn't honor it.
+    pub fn proof_type(&self) -> ProofType {
+        match SP1ProofMode::from(&self.proof.proof) {
+            SP1ProofMode::Core => ProofType::Core,
+            SP1ProofMode::Compressed => ProofType::Compressed,
+            SP1ProofMode::Groth16 => ProofType::Groth16,
+            SP1ProofMode::Plonk => ProofType::Plonk,
+        }
+    }
 }
 
 impl Service<Request> for Executor {
@@ -246,6 +355,8 @@ struct LocalExecutor {
     verification_key: SP1VerifyingKey,
     is_mock: bool,
     prover: Arc<CpuProver>,
+    /// Memory budget of the proofs, unlimited when unset.
+    memory: Option<Arc<MemoryBudget>>,
 }
 
 impl Service<Request> for LocalExecutor {
@@ -267,16 +378,25 @@ impl Service<Request> for LocalExecutor {
         let proving_key = self.proving_key.clone();
         let verification_key = self.verification_key.clone();
 
+        // Held until the proof completes.
+        let reservation = match self.memory.as_ref().map(|memory| memory.reserve(&stdin)) {
+            Some(Err(error)) => {
+                warn!(%error, "Rejected the proof request");
+                return Box::pin(futures::future::ready(Err(error)));
+            }
+            Some(Ok(reservation)) => Some(reservation),
+            None => None,
+        };
+
         debug!("Proving with CPU prover");
+        let span = Span::current();
         Box::pin(
             spawn_blocking(move || {
+                let _reservation = reservation;
                 debug!("Starting the proving of the requested MultiBatchHeader");
                 let mut proof_request = prover.prove(&proving_key, &stdin);
 
-                proof_request = match req.proof_type {
-                    ProofType::Plonk => proof_request.plonk(),
-                    ProofType::Stark => proof_request.compressed(),
-                };
+                proof_request = proof_request.mode(sp1_proof_mode(req.proof_type));
 
                 if is_mock {
                     proof_request = proof_request.deferred_proof_verification(false);
@@ -287,13 +407,19 @@ impl Service<Request> for LocalExecutor {
                     .map_err(|error| Error::ProverFailed(error.to_string()))?;
 
                 debug!("Proving completed. Verifying the proof...");
-                prover
-                    .verify(&proof, &verification_key)
-                    .map_err(|error| Error::ProofVerificationFailed(error.into()))?;
+                let started = Instant::now();
+                info_span!(parent: &span, "verify").in_scope(|| {
+                    prover
+                        .verify(&proof, &verification_key)
+                        .map_err(|error| Error::ProofVerificationFailed(error.into()))
+                })?;
 
                 debug!("Proof verification completed successfully");
 
-                Ok(Response { proof })
+                Ok(Response {
+                    proof,
+                    verification: Some(started.elapsed()),
+                })
             })
             .map_err(|_| Error::UnableToExecuteProver)
             .and_then(|res| async { res }),
@@ -302,14 +428,14 @@ impl Service<Request> for LocalExecutor {
 }
 
 #[derive(Clone)]
-struct NetworkExecutor {
-    prover: Arc<NetworkProver>,
+struct RemoteExecutor {
+    prover: Arc<dyn RemoteProver>,
     proving_key: SP1ProvingKey,
     verification_key: SP1VerifyingKey,
     timeout: Duration,
 }
 
-impl Service<Request> for NetworkExecutor {
+impl Service<Request> for RemoteExecutor {
     type Response = Response;
 
     type Error = Error;
@@ -328,30 +454,22 @@ impl Service<Request> for NetworkExecutor {
         let proving_key = self.proving_key.clone();
         let timeout = self.timeout;
 
-        debug!("Proving with network prover with timeout: {:?}", timeout);
+        debug!("Proving with remote prover with timeout: {:?}", timeout);
         let fut = async move {
             debug!("Starting the proving of the requested MultiBatchHeader");
-            let proof_request = prover.prove(&proving_key, &stdin);
-
-            let proof_request = match req.proof_type {
-                ProofType::Plonk => proof_request.plonk(),
-                ProofType::Stark => proof_request.compressed(),
-            };
-
-            let proof = proof_request
-                .timeout(timeout)
-                .strategy(FulfillmentStrategy::Reserved)
-                .run_async()
-                .await
-                .map_err(|error| Error::ProverFailed(error.to_string()))?;
+            let proof = prover
+                .prove(&proving_key, &stdin, req.proof_type, timeout)
+                .await?;
 
             debug!("Proving completed. Verifying the proof...");
-            prover
-                .verify(&proof, &verification_key)
-                .map_err(|error| Error::ProofVerificationFailed(error.into()))?;
+            let started = Instant::now();
+            info_span!("verify").in_scope(|| prover.verify(&proof, &verification_key))?;
 
             debug!("Proof verification completed successfully");
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: Some(started.elapsed()),
+            })
         };
 
         Box::pin(fut)
@@ -0,0 +1,185 @@
+//! Memory budget of the proofs generated on the host.
+//!
+//! The memory of a proof is estimated from the size of its inputs before
+//! proving it, and reserved out of the ceiling until the proof completes. The
+//! proofs which don't fit in what the proofs in flight leave are rejected,
+//! instead of the process being OOM-killed mid-way through all of them.
+use std::{
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use prover_config::MemoryLimitConfig;
+use sp1_sdk::SP1Stdin;
+use tracing::{debug, warn};
+
+use crate::Error;
+
+/// Memory limit of the cgroup v2 of the process.
+const CGROUP_V2_MEMORY_MAX: &str = "/sys/fs/cgroup/memory.max";
+
+/// Memory limit of the cgroup v1 of the process.
+const CGROUP_V1_MEMORY_LIMIT: &str = "/sys/fs/cgroup/memory/memory.limit_in_bytes";
+
+/// Limits above this are the "unlimited" placeholder of the cgroup v1.
+const CGROUP_V1_UNLIMITED: u64 = 1 << 62;
+
+#[derive(Debug)]
+pub struct MemoryBudget {
+    ceiling: u64,
+    base_memory: u64,
+    memory_per_input_byte: u64,
+    reserved: Mutex<u64>,
+}
+
+impl MemoryBudget {
+    /// Budget with the configured ceiling, or the one of the cgroup or of the
+    /// host when unset. Unlimited when none can be found.
+    pub fn new(config: &MemoryLimitConfig) -> Option<Arc<Self>> {
+        let ceiling = config
+            .ceiling
+            .or_else(cgroup_memory_limit)
+            .or_else(host_memory);
+        let Some(ceiling) = ceiling else {
+            warn!("Unable to find the memory available to the proofs, leaving them unlimited");
+            return None;
+        };
+        debug!(ceiling, "Memory budget of the proofs set");
+
+        Some(Arc::new(Self {
+            ceiling,
+            base_memory: config.base_memory,
+            memory_per_input_byte: config.memory_per_input_byte,
+            reserved: Mutex::new(0),
+        }))
+    }
+
+    /// Estimated memory of the proof of the given inputs, in bytes.
+    pub fn estimate(&self, stdin: &SP1Stdin) -> u64 {
+        let input_bytes = bincode::serialized_size(stdin).unwrap_or(u64::MAX);
+
+        self.base_memory
+            .saturating_add(input_bytes.saturating_mul(self.memory_per_input_byte))
+    }
+
+    /// Reserves the memory of the proof of the given inputs, until the
+    /// reservation is dropped.
+    pub fn reserve(self: &Arc<Self>, stdin: &SP1Stdin) -> Result<MemoryReservation, Error> {
+        let estimated = self.estimate(stdin);
+        let mut reserved = self.reserved.lock().expect("memory budget lock poisoned");
+
+        let available = self.ceiling.saturating_sub(*reserved);
+        if estimated > available {
+            return Err(Error::MemoryLimitExceeded {
+                estimated,
+                available,
+                ceiling: self.ceiling,
+            });
+        }
+        *reserved += estimated;
+
+        Ok(MemoryReservation {
+            budget: self.clone(),
+            bytes: estimated,
+        })
+    }
+
+    /// Memory reserved by the proofs in flight, in bytes.
+    pub fn reserved(&self) -> u64 {
+        *self.reserved.lock().expect("memory budget lock poisoned")
+    }
+}
+
+/// Memory reserved by a proof in flight, released once dropped.
+#[derive(Debug)]
+pub struct MemoryReservation {
+    budget: Arc<MemoryBudget>,
+    bytes: u64,
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        let mut reserved = self
+            .budget
+            .reserved
+            .lock()
+            .expect("memory budget lock poisoned");
+        *reserved = reserved.saturating_sub(self.bytes);
+    }
+}
+
+/// Memory limit of the cgroup of the process, if limited.
+pub fn cgroup_memory_limit() -> Option<u64> {
+    read_limit(Path::new(CGROUP_V2_MEMORY_MAX))
+        .or_else(|| read_limit(Path::new(CGROUP_V1_MEMORY_LIMIT)))
+}
+
+fn read_limit(path: &Path) -> Option<u64> {
+    parse_limit(&std::fs::read_to_string(path).ok()?)
+}
+
+/// Parses a cgroup memory limit, `max` standing for no limit.
+fn parse_limit(limit: &str) -> Option<u64> {
+    limit
+        .trim()
+        .parse()
+        .ok()
+        .filter(|limit| *limit < CGROUP_V1_UNLIMITED)
+}
+
+/// Total memory of the host.
+fn host_memory() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let kib: u64 = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemTotal:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(ceiling: u64) -> Arc<MemoryBudget> {
+        MemoryBudget::new(&MemoryLimitConfig {
+            ceiling: Some(ceiling),
+            base_memory: 1_000,
+            memory_per_input_byte: 10,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn proofs_are_rejected_beyond_the_ceiling() {
+        let mut stdin = SP1Stdin::new();
+        stdin.write_slice(&[0; 100]);
+
+        let budget = budget(3_000);
+        let estimated = budget.estimate(&stdin);
+        assert!(estimated > 2_000 && estimated < 3_000, "{estimated}");
+
+        let reservation = budget.reserve(&stdin).unwrap();
+        assert_eq!(budget.reserved(), estimated);
+        assert!(matches!(
+            budget.reserve(&stdin),
+            Err(Error::MemoryLimitExceeded { ceiling: 3_000, .. })
+        ));
+
+        drop(reservation);
+        assert_eq!(budget.reserved(), 0);
+        assert!(budget.reserve(&stdin).is_ok());
+    }
+
+    #[test]
+    fn cgroup_limits() {
+        assert_eq!(parse_limit("max\n"), None);
+        assert_eq!(parse_limit("9223372036854771712\n"), None);
+        assert_eq!(parse_limit("68719476736\n"), Some(64 << 30));
+    }
+}
@@ -0,0 +1,232 @@
+use std::{
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    task::{Context, Poll},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use futures::Future;
+use prover_config::ExecutorPoolConfig;
+use sp1_sdk::{
+    CpuProver, Prover, SP1ProofWithPublicValues, SP1ProvingKey, SP1PublicValues, SP1Stdin,
+    SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+};
+use tokio::sync::oneshot;
+use tower::Service;
+use tracing::{debug, warn};
+
+use crate::{sp1_proof_mode, CycleProfile, Error, Request, Response};
+
+/// Outcome of the execution of the program, without proving it.
+#[derive(Debug, Clone)]
+pub struct ExecutionResponse {
+    pub public_values: SP1PublicValues,
+    /// Number of RISC-V instructions executed.
+    pub cycles: u64,
+    /// Cycles spent in the tracked regions of the program.
+    pub profile: CycleProfile,
+}
+
+enum Job {
+    Execute {
+        stdin: SP1Stdin,
+        reply: oneshot::Sender<Result<ExecutionResponse, Error>>,
+    },
+    MockProve {
+        request: Request,
+        reply: oneshot::Sender<Result<Response, Error>>,
+    },
+}
+
+/// Pool of workers executing the program without proving it.
+///
+/// Every worker owns its SP1 executor, set up once for the program when the
+/// pool starts, so that the requests don't pay the setup cost. The workers
+/// stop once every handle to the pool is dropped.
+#[derive(Clone)]
+pub struct ExecutorPool {
+    jobs: mpsc::Sender<Job>,
+    vkey: Arc<SP1VerifyingKey>,
+}
+
+impl ExecutorPool {
+    pub fn new(config: &ExecutorPoolConfig, program: &[u8]) -> Self {
+        let (proving_key, verification_key) = CpuProver::mock().setup(program);
+        let proving_key = Arc::new(proving_key);
+
+        let (jobs, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let profiles = Arc::new(ProfileExport {
+            dir: config.profile_dir.clone(),
+            sequence: AtomicU64::new(0),
+        });
+
+        let pool_size = config.pool_size.max(1);
+        debug!("Starting {pool_size} executor workers...");
+        for index in 0..pool_size {
+            let worker = Worker {
+                index,
+                prover: CpuProver::mock(),
+                proving_key: proving_key.clone(),
+                profiles: profiles.clone(),
+            };
+            let receiver = receiver.clone();
+
+            thread::Builder::new()
+                .name(format!("sp1-executor-{index}"))
+                .spawn(move || worker.run(&receiver))
+                .expect("Unable to spawn an executor worker");
+        }
+
+        Self {
+            jobs,
+            vkey: Arc::new(verification_key),
+        }
+    }
+
+    pub fn get_vkey(&self) -> &Arc<SP1VerifyingKey> {
+        &self.vkey
+    }
+
+    /// Executes the program on `stdin`, returning its public values and its
+    /// cycle profile.
+    pub async fn execute(&self, stdin: SP1Stdin) -> Result<ExecutionResponse, Error> {
+        let (reply, response) = oneshot::channel();
+        self.jobs
+            .send(Job::Execute { stdin, reply })
+            .map_err(|_| Error::UnableToExecuteProver)?;
+
+        response.await.map_err(|_| Error::UnableToExecuteProver)?
+    }
+
+    /// Executes the program and wraps its public values in a mock proof.
+    pub async fn mock_prove(&self, request: Request) -> Result<Response, Error> {
+        let (reply, response) = oneshot::channel();
+        self.jobs
+            .send(Job::MockProve { request, reply })
+            .map_err(|_| Error::UnableToExecuteProver)?;
+
+        response.await.map_err(|_| Error::UnableToExecuteProver)?
+    }
+}
+
+impl Service<Request> for ExecutorPool {
+    type Response = Response;
+    type Error = Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let pool = self.clone();
+
+        Box::pin(async move { pool.mock_prove(req).await })
+    }
+}
+
+struct Worker {
+    index: usize,
+    prover: CpuProver,
+    proving_key: Arc<SP1ProvingKey>,
+    profiles: Arc<ProfileExport>,
+}
+
+/// Export of the cycle profiles, shared by the workers.
+struct ProfileExport {
+    dir: Option<PathBuf>,
+    sequence: AtomicU64,
+}
+
+impl ProfileExport {
+    fn export(&self, profile: &CycleProfile) {
+        let Some(dir) = &self.dir else {
+            return;
+        };
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let name = format!(
+            "execution-{timestamp}-{}",
+            self.sequence.fetch_add(1, Ordering::Relaxed)
+        );
+
+        // Profiling is a diagnostic, it never fails the execution.
+        match profile.export(dir, &name) {
+            Ok(path) => debug!(path = %path.display(), "Cycle profile exported"),
+            Err(error) => warn!(?error, "Unable to export the cycle profile"),
+        }
+    }
+}
+
+impl Worker {
+    fn run(self, jobs: &Mutex<mpsc::Receiver<Job>>) {
+        loop {
+            // The lock is only held while waiting for the next job.
+            let job = match jobs.lock() {
+                Ok(jobs) => jobs.recv(),
+                Err(_) => {
+                    warn!(worker = self.index, "Executor job queue poisoned");
+                    return;
+                }
+            };
+            let Ok(job) = job else {
+                debug!(
+                    worker = self.index,
+                    "Executor pool closed, stopping the worker"
+                );
+                return;
+            };
+
+            // The requester may have given up on the result, e.g. on timeout.
+            match job {
+                Job::Execute { stdin, reply } => {
+                    _ = reply.send(self.execute(&stdin));
+                }
+                Job::MockProve { request, reply } => {
+                    _ = reply.send(self.mock_prove(request));
+                }
+            }
+        }
+    }
+
+    fn execute(&self, stdin: &SP1Stdin) -> Result<ExecutionResponse, Error> {
+        let (public_values, report) = self
+            .prover
+            .execute(&self.proving_key.elf, stdin)
+            .run()
+            .map_err(|error| Error::ProverFailed(error.to_string()))?;
+
+        let profile = CycleProfile::from_report(&report);
+        self.profiles.export(&profile);
+
+        Ok(ExecutionResponse {
+            public_values,
+            cycles: profile.total_cycles,
+            profile,
+        })
+    }
+
+    fn mock_prove(&self, request: Request) -> Result<Response, Error> {
+        let ExecutionResponse { public_values, .. } = self.execute(&request.stdin)?;
+
+        Ok(Response {
+            proof: SP1ProofWithPublicValues::create_mock_proof(
+                &self.proving_key,
+                public_values,
+                sp1_proof_mode(request.proof_type),
+                SP1_CIRCUIT_VERSION,
+            ),
+            verification: None,
+        })
+    }
+}
@@ -0,0 +1,105 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use sp1_sdk::ExecutionReport;
+
+/// Root frame of the folded stacks.
+const ROOT_FRAME: &str = "program";
+
+/// Cycle profile of one execution of the program.
+///
+/// The regions are the ones delimited in the guest program with the
+/// `cycle-tracker-report-start` / `cycle-tracker-report-end` markers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CycleProfile {
+    pub total_cycles: u64,
+    pub total_syscalls: u64,
+    /// Gas of the execution, when computed by the executor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<u64>,
+    pub regions: BTreeMap<String, RegionProfile>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RegionProfile {
+    /// Cycles spent in the region, over all its invocations.
+    pub cycles: u64,
+    pub invocations: u64,
+}
+
+impl CycleProfile {
+    pub fn from_report(report: &ExecutionReport) -> Self {
+        let regions = report
+            .cycle_tracker
+            .iter()
+            .map(|(label, cycles)| {
+                (
+                    label.clone(),
+                    RegionProfile {
+                        cycles: *cycles,
+                        invocations: report.invocation_tracker.get(label).copied().unwrap_or(1),
+                    },
+                )
+            })
+            .collect();
+
+        Self {
+            total_cycles: report.total_instruction_count(),
+            total_syscalls: report.total_syscall_count(),
+            gas: report.gas,
+            regions,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Folded stacks, as consumed by `inferno-flamegraph` or `flamegraph.pl`.
+    ///
+    /// The regions are rendered as children of the whole program. Nested
+    /// regions are not known to the executor, hence they show up side by side.
+    pub fn to_folded(&self) -> String {
+        let in_regions: u64 = self.regions.values().map(|region| region.cycles).sum();
+
+        let mut folded = format!(
+            "{ROOT_FRAME} {}\n",
+            self.total_cycles.saturating_sub(in_regions)
+        );
+        for (label, region) in &self.regions {
+            folded.push_str(&format!(
+                "{ROOT_FRAME};{} {}\n",
+                label.replace([';', ' '], "_"),
+                region.cycles
+            ));
+        }
+
+        folded
+    }
+
+    /// Writes the JSON profile and the folded stacks in `dir`, as
+    /// `<name>.json` and `<name>.folded`. Returns the path of the JSON
+    /// profile.
+    pub fn export(&self, dir: &Path, name: &str) -> Result<PathBuf, ProfileExportError> {
+        std::fs::create_dir_all(dir)?;
+
+        let json = dir.join(format!("{name}.json"));
+        std::fs::write(&json, self.to_json()?)?;
+        std::fs::write(dir.join(format!("{name}.folded")), self.to_folded())?;
+
+        Ok(json)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProfileExportError {
+    #[error("Unable to write the cycle profile")]
+    Io(#[from] std::io::Error),
+    #[error("Unable to serialize the cycle profile")]
+    Json(#[from] serde_json::Error),
+}
@@ -0,0 +1,76 @@
+//! Proving services proving the programs off the host, behind the
+//! [`RemoteProver`] trait so that the proofs can be routed to another
+//! provider than the Succinct proving network.
+use std::time::Duration;
+
+use async_trait::async_trait;
+use prover_config::ProofType;
+use sp1_sdk::{
+    network::{prover::NetworkProver, FulfillmentStrategy},
+    SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin, SP1VerifyingKey,
+};
+
+use crate::{sp1_proof_mode, Error};
+
+mod grpc;
+
+pub use grpc::GrpcRemoteProver;
+
+/// Provider of the proofs generated off the host.
+///
+/// The keys are generated and the proofs verified locally, the provider only
+/// being trusted to prove.
+#[async_trait]
+pub trait RemoteProver: Send + Sync {
+    /// Generates the proving and verification keys of the program.
+    fn setup(&self, program: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey);
+
+    /// Proves the execution of the program with the given stdin, the
+    /// provider giving up after the timeout.
+    async fn prove(
+        &self,
+        proving_key: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        proof_type: ProofType,
+        timeout: Duration,
+    ) -> Result<SP1ProofWithPublicValues, Error>;
+
+    /// Verifies a proof received from the provider.
+    fn verify(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        verification_key: &SP1VerifyingKey,
+    ) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl RemoteProver for NetworkProver {
+    fn setup(&self, program: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey) {
+        sp1_sdk::Prover::setup(self, program)
+    }
+
+    async fn prove(
+        &self,
+        proving_key: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        proof_type: ProofType,
+        timeout: Duration,
+    ) -> Result<SP1ProofWithPublicValues, Error> {
+        NetworkProver::prove(self, proving_key, stdin)
+            .mode(sp1_proof_mode(proof_type))
+            .timeout(timeout)
+            .strategy(FulfillmentStrategy::Reserved)
+            .run_async()
+            .await
+            .map_err(|error| Error::ProverFailed(error.to_string()))
+    }
+
+    fn verify(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        verification_key: &SP1VerifyingKey,
+    ) -> Result<(), Error> {
+        sp1_sdk::Prover::verify(self, proof, verification_key)
+            .map_err(|error| Error::ProofVerificationFailed(error.into()))
+    }
+}
@@ -0,0 +1,221 @@
+//! Client of the generic remote prover gRPC protocol, letting any proving
+//! service implement it to prove the programs of the prover:
+//!
+//! ```proto
+//! syntax = "proto3";
+//!
+//! package sp1.remote.prover.v1;
+//!
+//! service RemoteProverService {
+//!   // Proves the execution of the program, within the gRPC deadline.
+//!   rpc Prove(ProveRequest) returns (ProveResponse);
+//! }
+//!
+//! enum ProofMode {
+//!   PROOF_MODE_UNSPECIFIED = 0;
+//!   PROOF_MODE_CORE = 1;
+//!   PROOF_MODE_COMPRESSED = 2;
+//!   PROOF_MODE_GROTH16 = 3;
+//!   PROOF_MODE_PLONK = 4;
+//! }
+//!
+//! message ProveRequest {
+//!   // ELF of the program.
+//!   bytes program = 1;
+//!   // Vkey of the program as a `0x` prefixed bytes32 hash, letting the
+//!   // service cache the setup of the program.
+//!   string program_vkey = 2;
+//!   // `SP1Stdin` serialized with bincode.
+//!   bytes stdin = 3;
+//!   ProofMode mode = 4;
+//! }
+//!
+//! message ProveResponse {
+//!   // `SP1ProofWithPublicValues` serialized with bincode.
+//!   bytes proof = 1;
+//! }
+//! ```
+use std::{str::FromStr as _, time::Duration};
+
+use async_trait::async_trait;
+use prover_config::{GrpcProverConfig, ProofType};
+use sp1_sdk::{
+    CpuProver, HashableKey as _, Prover as _, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin,
+    SP1VerifyingKey,
+};
+use tonic::{
+    codec::ProstCodec,
+    codegen::http::uri::PathAndQuery,
+    metadata::{AsciiMetadataValue, MetadataValue},
+    transport::{Channel, ClientTlsConfig, Endpoint},
+};
+use tracing::debug;
+
+use super::RemoteProver;
+use crate::Error;
+
+const PROVE_PATH: &str = "/sp1.remote.prover.v1.RemoteProverService/Prove";
+
+/// Metadata holding the API key of the prover.
+const API_KEY_METADATA: &str = "x-api-key";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+enum ProofMode {
+    Unspecified = 0,
+    Core = 1,
+    Compressed = 2,
+    Groth16 = 3,
+    Plonk = 4,
+}
+
+impl From<ProofType> for ProofMode {
+    fn from(proof_type: ProofType) -> Self {
+        match proof_type {
+            ProofType::Core => ProofMode::Core,
+            ProofType::Compressed => ProofMode::Compressed,
+            ProofType::Groth16 => ProofMode::Groth16,
+            ProofType::Plonk => ProofMode::Plonk,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ProveRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    program: Vec<u8>,
+    #[prost(string, tag = "2")]
+    program_vkey: String,
+    #[prost(bytes = "vec", tag = "3")]
+    stdin: Vec<u8>,
+    #[prost(enumeration = "ProofMode", tag = "4")]
+    mode: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ProveResponse {
+    #[prost(bytes = "vec", tag = "1")]
+    proof: Vec<u8>,
+}
+
+/// Prover delegating the proving to a service implementing the generic
+/// remote prover protocol.
+pub struct GrpcRemoteProver {
+    channel: Channel,
+    api_key: Option<AsciiMetadataValue>,
+    /// Local prover generating the keys and verifying the proofs.
+    verifier: CpuProver,
+}
+
+impl GrpcRemoteProver {
+    /// Connects lazily, on the first proof requested.
+    pub fn new(config: &GrpcProverConfig) -> Result<Self, Error> {
+        let mut endpoint = Endpoint::from_str(config.endpoint.as_str())
+            .map_err(|error| Error::ProverFailed(format!("Invalid prover endpoint: {error}")))?;
+        if config.endpoint.scheme() == "https" {
+            endpoint = endpoint
+                .tls_config(ClientTlsConfig::new().with_native_roots())
+                .map_err(|error| Error::ProverFailed(format!("Invalid TLS config: {error}")))?;
+        }
+        let api_key = config
+            .api_key
+            .as_ref()
+            .map(|api_key| MetadataValue::try_from(api_key.expose()))
+            .transpose()
+            .map_err(|_| Error::ProverFailed("Invalid prover API key".to_string()))?;
+
+        Ok(Self {
+            channel: endpoint.connect_lazy(),
+            api_key,
+            verifier: CpuProver::new(),
+        })
+    }
+}
+
+#[async_trait]
+impl RemoteProver for GrpcRemoteProver {
+    fn setup(&self, program: &[u8]) -> (SP1ProvingKey, SP1VerifyingKey) {
+        self.verifier.setup(program)
+    }
+
+    async fn prove(
+        &self,
+        proving_key: &SP1ProvingKey,
+        stdin: &SP1Stdin,
+        proof_type: ProofType,
+        timeout: Duration,
+    ) -> Result<SP1ProofWithPublicValues, Error> {
+        let stdin = bincode::serialize(stdin)
+            .map_err(|error| Error::ProverFailed(format!("Unable to encode the stdin: {error}")))?;
+        let mut request = tonic::Request::new(ProveRequest {
+            program: proving_key.elf.clone(),
+            program_vkey: proving_key.vk.bytes32(),
+            stdin,
+            mode: ProofMode::from(proof_type).into(),
+        });
+        request.set_timeout(timeout);
+        if let Some(api_key) = &self.api_key {
+            request
+                .metadata_mut()
+                .insert(API_KEY_METADATA, api_key.clone());
+        }
+
+        debug!("Requesting the proof to the remote prover");
+        let mut client = tonic::client::Grpc::new(self.channel.clone());
+        client
+            .ready()
+            .await
+            .map_err(|error| Error::ProverFailed(format!("Remote prover unavailable: {error}")))?;
+        let response: tonic::Response<ProveResponse> = client
+            .unary(
+                request,
+                PathAndQuery::from_static(PROVE_PATH),
+                ProstCodec::default(),
+            )
+            .await
+            .map_err(|status| Error::ProverFailed(format!("Remote prover failed: {status}")))?;
+
+        bincode::deserialize(&response.into_inner().proof)
+            .map_err(|error| Error::ProverFailed(format!("Unable to decode the proof: {error}")))
+    }
+
+    fn verify(
+        &self,
+        proof: &SP1ProofWithPublicValues,
+        verification_key: &SP1VerifyingKey,
+    ) -> Result<(), Error> {
+        self.verifier
+            .verify(proof, verification_key)
+            .map_err(|error| Error::ProofVerificationFailed(error.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost::Message as _;
+
+    use super::*;
+
+    #[test]
+    fn prove_request_wire_format() {
+        let request = ProveRequest {
+            program: vec![0x7f, b'E', b'L', b'F'],
+            program_vkey: "0x01".to_string(),
+            stdin: vec![1, 2, 3],
+            mode: ProofMode::from(ProofType::Plonk).into(),
+        };
+
+        let bytes = request.encode_to_vec();
+        assert_eq!(
+            bytes,
+            [
+                [0x0a, 4, 0x7f, b'E', b'L', b'F'].as_slice(),
+                &[0x12, 4, b'0', b'x', b'0', b'1'],
+                &[0x1a, 3, 1, 2, 3],
+                &[0x20, 4],
+            ]
+            .concat()
+        );
+        assert_eq!(ProveRequest::decode(bytes.as_slice()).unwrap(), request);
+    }
+}
@@ -0,0 +1,129 @@
+use std::fmt::Debug;
+
+use serde::{Deserialize, Serialize};
+use sp1_core_executor::SP1ReduceProof;
+use sp1_prover::InnerSC;
+use sp1_sdk::{HashableKey as _, SP1Stdin, SP1VerifyingKey};
+
+/// Kind of an input written to the stdin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StdinEntryKind {
+    /// Value serialized with bincode, read with `sp1_zkvm::io::read`.
+    Value,
+    /// Raw bytes, read with `sp1_zkvm::io::read_vec`.
+    Slice,
+    /// Proof verified within the program.
+    Proof,
+}
+
+/// Description of one input written to the stdin.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct StdinEntry {
+    pub label: String,
+    pub kind: StdinEntryKind,
+    /// Debug representation of the input, only recorded by the builders
+    /// created with [`StdinBuilder::with_debug`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debug: Option<String>,
+}
+
+/// Stdin along with the description of every input written to it, which is
+/// enough to reproduce the execution of the program.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StdinDump {
+    pub entries: Vec<StdinEntry>,
+    pub stdin: SP1Stdin,
+}
+
+/// Typed builder of the SP1 stdin, recording every input written to it.
+///
+/// The inputs are read by the program in the order they are written.
+#[derive(Clone, Default)]
+pub struct StdinBuilder {
+    stdin: SP1Stdin,
+    entries: Vec<StdinEntry>,
+    record_debug: bool,
+}
+
+impl StdinBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder also recording the debug representation of the inputs. This
+    /// can be costly for large witnesses, hence is meant for witness dumps.
+    pub fn with_debug() -> Self {
+        Self {
+            record_debug: true,
+            ..Self::default()
+        }
+    }
+
+    /// Writes a value, such as the witness or public values struct of the
+    /// program.
+    pub fn write<T: Serialize + Debug>(mut self, label: impl Into<String>, value: &T) -> Self {
+        self.stdin.write(value);
+        self.record(label, StdinEntryKind::Value, || format!("{value:#?}"));
+
+        self
+    }
+
+    /// Writes raw bytes.
+    pub fn write_slice(mut self, label: impl Into<String>, bytes: &[u8]) -> Self {
+        self.stdin.write_slice(bytes);
+        self.record(label, StdinEntryKind::Slice, || {
+            format!("0x{}", hex::encode(bytes))
+        });
+
+        self
+    }
+
+    /// Writes a compressed proof, along with the vkey of the program which
+    /// produced it, to be verified within the program.
+    pub fn write_proof(
+        mut self,
+        label: impl Into<String>,
+        proof: SP1ReduceProof<InnerSC>,
+        vkey: &SP1VerifyingKey,
+    ) -> Self {
+        self.stdin.write_proof(proof, vkey.vk.clone());
+        self.record(label, StdinEntryKind::Proof, || {
+            format!("compressed proof of the program {}", vkey.bytes32())
+        });
+
+        self
+    }
+
+    pub fn entries(&self) -> &[StdinEntry] {
+        &self.entries
+    }
+
+    pub fn build(self) -> SP1Stdin {
+        self.stdin
+    }
+
+    /// Builds the stdin along with its dump.
+    pub fn build_with_dump(self) -> (SP1Stdin, StdinDump) {
+        let dump = StdinDump {
+            entries: self.entries,
+            stdin: self.stdin.clone(),
+        };
+
+        (self.stdin, dump)
+    }
+
+    fn record(
+        &mut self,
+        label: impl Into<String>,
+        kind: StdinEntryKind,
+        debug: impl FnOnce() -> String,
+    ) {
+        self.entries.push(StdinEntry {
+            label: label.into(),
+            kind,
+            debug: self.record_debug.then(debug),
+        });
+    }
+}
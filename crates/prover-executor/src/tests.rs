@@ -3,14 +3,23 @@ use std::{
     time::Duration,
 };
 
-use prover_config::MockProverConfig;
+use prover_config::{
+    ExecutorPoolConfig, GoldenCaptureConfig, MemoryLimitConfig, MockProverConfig,
+    ProgramCheckConfig,
+};
+use prover_utils::encryption::{Cipher, EncryptionConfig, EncryptionKey, ENCRYPTION_MAGIC};
 use sp1_sdk::{
-    CpuProver, Prover, SP1ProofMode, SP1ProofWithPublicValues, SP1ProvingKey, SP1Stdin,
-    SP1VerifyingKey, SP1_CIRCUIT_VERSION,
+    CpuProver, HashableKey, Prover, SP1ProofMode, SP1ProofWithPublicValues, SP1ProvingKey,
+    SP1Stdin, SP1VerifyingKey, SP1_CIRCUIT_VERSION,
 };
-use tower::{service_fn, timeout::TimeoutLayer, Service, ServiceBuilder, ServiceExt};
+use tower::{service_fn, timeout::TimeoutLayer, Layer, Service, ServiceBuilder, ServiceExt};
 
-use crate::{Executor, LocalExecutor, ProofType, Request, Response};
+use crate::{
+    golden::{GoldenCaptureLayer, GoldenVector, ReplayRunner},
+    memory::MemoryBudget,
+    CompatibilityError, CycleProfile, Executor, ExecutorPool, LocalExecutor, ProofType,
+    RegionProfile, Request, Response,
+};
 const ELF: &[u8] = include_bytes!("../../prover-dummy-program/elf/riscv32im-succinct-zkvm-elf");
 
 fn cpu_prover() -> &'static CpuProver {
@@ -54,7 +63,10 @@ async fn executor_normal_behavior() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -84,7 +96,10 @@ async fn executor_normal_behavior_only_network() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -114,7 +129,10 @@ async fn executor_fallback_behavior_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_local".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -139,7 +157,10 @@ async fn executor_fallback_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -150,7 +171,10 @@ async fn executor_fallback_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_local".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -176,7 +200,10 @@ async fn executor_fails_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -188,7 +215,10 @@ async fn executor_fails_because_of_timeout_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_local".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -219,7 +249,10 @@ async fn executor_fails_because_of_concurrency_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_network".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -231,7 +264,10 @@ async fn executor_fails_because_of_concurrency_cpu() {
             let mut proof = mock_proof(r.stdin);
             proof.sp1_version = "from_local".to_string();
 
-            Ok(Response { proof })
+            Ok(Response {
+                proof,
+                verification: None,
+            })
         }),
     );
 
@@ -283,6 +319,7 @@ async fn executor_normal_behavior_mock_prover() {
             is_mock: true,
             proving_key,
             verification_key: verification_key.clone(),
+            memory: None,
         },
     );
     let executor = executor.ready().await.expect("valid executor");
@@ -299,3 +336,295 @@ async fn executor_normal_behavior_mock_prover() {
         .verify(&result.unwrap().proof, &verification_key)
         .is_ok());
 }
+
+#[tokio::test]
+async fn executor_rejects_proofs_beyond_the_memory_ceiling() {
+    let prover = Arc::new(CpuProver::mock());
+    let (proving_key, verification_key) = prover.setup(ELF);
+
+    let mock_prover_config = MockProverConfig::default();
+    let mut executor = Executor::build_local_service(
+        mock_prover_config.proving_timeout,
+        mock_prover_config.max_concurrency_limit,
+        LocalExecutor {
+            prover,
+            is_mock: true,
+            proving_key,
+            verification_key,
+            memory: MemoryBudget::new(&MemoryLimitConfig {
+                ceiling: Some(1 << 30),
+                ..Default::default()
+            }),
+        },
+    );
+    let executor = executor.ready().await.expect("valid executor");
+
+    let result = executor
+        .call(Request {
+            stdin: SP1Stdin::new(),
+            proof_type: ProofType::Plonk,
+        })
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(crate::Error::MemoryLimitExceeded {
+            ceiling: 1_073_741_824,
+            ..
+        })
+    ));
+}
+
+#[tokio::test]
+async fn executor_pool_reuses_warm_workers() {
+    let pool = ExecutorPool::new(
+        &ExecutorPoolConfig {
+            pool_size: 2,
+            profile_dir: None,
+        },
+        ELF,
+    );
+    assert_eq!(pool.get_vkey().bytes32(), vkey().bytes32());
+
+    let executions = futures::future::try_join_all((0..4).map(|_| pool.execute(SP1Stdin::new())))
+        .await
+        .unwrap();
+    assert!(executions[0].cycles > 0);
+    assert!(executions
+        .iter()
+        .all(|execution| execution.cycles == executions[0].cycles));
+
+    let mut service = pool.clone();
+    let response = service
+        .ready()
+        .await
+        .unwrap()
+        .call(Request {
+            stdin: SP1Stdin::new(),
+            proof_type: ProofType::Plonk,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        response.proof.public_values.as_slice(),
+        executions[0].public_values.as_slice()
+    );
+}
+
+#[test]
+fn response_surfaces_the_produced_proof_type() {
+    let response = Response {
+        proof: mock_proof(SP1Stdin::new()),
+        verification: None,
+    };
+    assert_eq!(response.proof_type(), ProofType::Plonk);
+    assert_eq!(
+        crate::sp1_proof_mode(ProofType::Groth16),
+        SP1ProofMode::Groth16
+    );
+}
+
+#[test]
+fn compatibility_check() {
+    let executor = Executor::new(
+        &prover_config::ProverType::MockProver(MockProverConfig::default()),
+        &None,
+        ELF,
+    );
+    let vkey = vkey().bytes32();
+
+    executor
+        .check_compatibility(&ProgramCheckConfig::default())
+        .unwrap();
+    executor
+        .check_compatibility(&ProgramCheckConfig {
+            expected_vkey: Some(vkey.to_uppercase().replacen("0X", "0x", 1)),
+            expected_sp1_version: Some(SP1_CIRCUIT_VERSION.to_string()),
+        })
+        .unwrap();
+
+    assert!(matches!(
+        executor.check_compatibility(&ProgramCheckConfig {
+            expected_vkey: Some(format!("0x{}", "00".repeat(32))),
+            expected_sp1_version: None,
+        }),
+        Err(CompatibilityError::VKeyMismatch { .. })
+    ));
+    assert!(matches!(
+        executor.check_compatibility(&ProgramCheckConfig {
+            expected_vkey: None,
+            expected_sp1_version: Some("v0.0.0".to_string()),
+        }),
+        Err(CompatibilityError::Sp1VersionMismatch { .. })
+    ));
+}
+
+#[test]
+fn stdin_builder_records_the_inputs() {
+    let (stdin, dump) = StdinBuilder::with_debug()
+        .write("block_number", &42u64)
+        .write_slice("raw", &[0xab, 0xcd])
+        .build_with_dump();
+
+    assert_eq!(stdin.buffer.len(), 2);
+    assert_eq!(
+        dump.entries,
+        [
+            StdinEntry {
+                label: "block_number".to_string(),
+                kind: StdinEntryKind::Value,
+                debug: Some("42".to_string()),
+            },
+            StdinEntry {
+                label: "raw".to_string(),
+                kind: StdinEntryKind::Slice,
+                debug: Some("0xabcd".to_string()),
+            },
+        ]
+    );
+
+    let builder = StdinBuilder::new().write("block_number", &42u64);
+    assert_eq!(builder.entries()[0].debug, None);
+}
+
+#[test]
+fn cycle_profile_renders_folded_stacks() {
+    let profile = CycleProfile {
+        total_cycles: 1_000,
+        total_syscalls: 10,
+        gas: None,
+        regions: [
+            (
+                "verify aggregation".to_string(),
+                RegionProfile {
+                    cycles: 600,
+                    invocations: 1,
+                },
+            ),
+            (
+                "hash_leaves".to_string(),
+                RegionProfile {
+                    cycles: 300,
+                    invocations: 3,
+                },
+            ),
+        ]
+        .into_iter()
+        .collect(),
+    };
+
+    assert_eq!(
+        profile.to_folded(),
+        "program 100\nprogram;hash_leaves 300\nprogram;verify_aggregation 600\n"
+    );
+
+    let json = profile.to_json().unwrap();
+    assert_eq!(
+        serde_json::from_str::<CycleProfile>(&json).unwrap(),
+        profile
+    );
+}
+
+#[tokio::test]
+async fn executor_pool_exports_cycle_profiles() {
+    let profile_dir =
+        std::env::temp_dir().join(format!("prover-executor-profiles-{}", std::process::id()));
+    let pool = ExecutorPool::new(
+        &ExecutorPoolConfig {
+            pool_size: 1,
+            profile_dir: Some(profile_dir.clone()),
+        },
+        ELF,
+    );
+
+    let execution = pool.execute(SP1Stdin::new()).await.unwrap();
+    assert_eq!(execution.profile.total_cycles, execution.cycles);
+
+    let mut exported: Vec<_> = std::fs::read_dir(&profile_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    exported.sort();
+    assert_eq!(exported.len(), 2);
+    assert_eq!(
+        exported[1]
+            .extension()
+            .and_then(|extension| extension.to_str()),
+        Some("json")
+    );
+
+    let profile: CycleProfile =
+        serde_json::from_slice(&std::fs::read(&exported[1]).unwrap()).unwrap();
+    assert_eq!(profile, execution.profile);
+
+    std::fs::remove_dir_all(profile_dir).unwrap();
+}
+
+#[tokio::test]
+async fn golden_vectors_are_captured_and_replayed() {
+    let output_dir =
+        std::env::temp_dir().join(format!("prover-executor-golden-{}", std::process::id()));
+    let encryption = EncryptionConfig {
+        key: EncryptionKey::resolve(&hex::encode([1; 32])).unwrap(),
+        previous_keys: vec![],
+    };
+    let cipher = Cipher::new(&encryption);
+    let mut service = GoldenCaptureLayer::new(
+        "dummy",
+        vkey().clone(),
+        &GoldenCaptureConfig {
+            output_dir: output_dir.clone(),
+            encryption: Some(encryption),
+        },
+    )
+    .layer(service_fn(|request: Request| async move {
+        Ok::<_, crate::Error>(Response {
+            proof: mock_proof(request.stdin),
+            verification: None,
+        })
+    }));
+
+    service
+        .ready()
+        .await
+        .unwrap()
+        .call(Request {
+            stdin: SP1Stdin::new(),
+            proof_type: ProofType::Plonk,
+        })
+        .await
+        .unwrap();
+
+    // The vector is written in the background.
+    let path = loop {
+        let paths: Vec<_> = std::fs::read_dir(&output_dir)
+            .into_iter()
+            .flatten()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "golden"))
+            .collect();
+        if let [path] = paths.as_slice() {
+            break path.clone();
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+
+    // The vector carries the witness, hence is encrypted.
+    assert!(std::fs::read(&path).unwrap().starts_with(&ENCRYPTION_MAGIC));
+    assert!(ReplayRunner::new("dummy", ELF).replay(&path).await.is_err());
+
+    let runner = ReplayRunner::new("dummy", ELF).with_cipher(cipher.clone());
+    let outcomes = runner.replay_dir(&output_dir).await.unwrap();
+    assert_eq!(outcomes.len(), 1);
+    let outcome = outcomes[0].as_ref().unwrap();
+    assert!(!outcome.has_drifted());
+    assert!(!outcome.vkey_changed);
+
+    // A drift of the public values is flagged.
+    let mut vector = GoldenVector::load(&path, Some(&cipher)).unwrap();
+    vector.public_values.push(0);
+    vector.save(&path, Some(&cipher)).unwrap();
+    assert!(runner.replay(&path).await.unwrap().has_drifted());
+
+    std::fs::remove_dir_all(output_dir).unwrap();
+}
@@ -0,0 +1,330 @@
+//! Soak test of an agglayer prover, backed by a real prover or by the fake
+//! one: the golden vectors are replayed as proof requests at a steady rate,
+//! every proof is verified, and the latencies are reported, e.g. to validate
+//! the capacity of a release before rolling it out.
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use agglayer_prover_client::{AgglayerProverClient, Error as ClientError, Priority};
+use prover_executor::golden::GoldenVector;
+use serde::{Deserialize, Serialize};
+use sp1_sdk::{CpuProver, Prover as _, ProverClient, SP1ProofWithPublicValues, SP1VerifyingKey};
+use tokio::{sync::Semaphore, task::JoinSet, time::MissedTickBehavior};
+use tracing::{debug, warn};
+
+/// Load sent to the prover.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadConfig {
+    /// Requests sent per second.
+    pub rate: f64,
+    pub requests: usize,
+    /// Requests awaiting their proof at most, the next ones being delayed
+    /// until one of them completes.
+    pub max_in_flight: usize,
+    pub network_id: u32,
+    pub priority: Priority,
+}
+
+/// Verification of the proofs against the program of the golden vectors.
+pub struct Verifier {
+    prover: CpuProver,
+    vkey: SP1VerifyingKey,
+}
+
+impl Verifier {
+    /// Verifies the proofs of `program`, the mock ones of the fake prover
+    /// when `mock` is set.
+    pub fn new(program: &[u8], mock: bool) -> Self {
+        let prover = if mock {
+            ProverClient::builder().mock().build()
+        } else {
+            ProverClient::builder().cpu().build()
+        };
+        let (_proving_key, vkey) = prover.setup(program);
+
+        Self { prover, vkey }
+    }
+
+    /// Whether the proof verifies and commits to the public values of the
+    /// golden vector.
+    fn verify(&self, proof: &SP1ProofWithPublicValues, fixture: &GoldenVector) -> bool {
+        if proof.public_values.as_slice() != fixture.public_values.as_slice() {
+            debug!("Proof committing to unexpected public values");
+            return false;
+        }
+
+        self.prover
+            .verify(proof, &self.vkey)
+            .inspect_err(|error| debug!(%error, "Proof not verified"))
+            .is_ok()
+    }
+}
+
+/// Outcome of a load test, serialized as the JSON report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LoadReport {
+    pub requests: usize,
+    pub verified: usize,
+    /// Responses whose proof doesn't verify, or which couldn't be decoded.
+    pub unverified: usize,
+    /// Requests failed by the prover, by gRPC status code.
+    pub failures: BTreeMap<String, usize>,
+    pub elapsed_ms: f64,
+    /// Verified proofs per second over the whole test.
+    pub throughput: f64,
+    /// Latency of the verified proofs.
+    pub latency: LatencyReport,
+}
+
+impl LoadReport {
+    /// Share of the requests not answered with a verified proof.
+    pub fn failure_ratio(&self) -> f64 {
+        (self.requests - self.verified) as f64 / self.requests.max(1) as f64
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct LatencyReport {
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+}
+
+impl LatencyReport {
+    fn new(mut latencies: Vec<Duration>) -> Self {
+        if latencies.is_empty() {
+            return Self::default();
+        }
+        latencies.sort();
+        let total: Duration = latencies.iter().sum();
+
+        Self {
+            p50_ms: millis(percentile(&latencies, 50.0)),
+            p90_ms: millis(percentile(&latencies, 90.0)),
+            p99_ms: millis(percentile(&latencies, 99.0)),
+            max_ms: millis(latencies[latencies.len() - 1]),
+            mean_ms: millis(total) / latencies.len() as f64,
+        }
+    }
+}
+
+/// Nearest-rank percentile of the sorted, non-empty, latencies.
+fn percentile(sorted: &[Duration], percentile: f64) -> Duration {
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+enum Outcome {
+    Verified(Duration),
+    Unverified,
+    Failed(tonic::Code),
+}
+
+/// Sends `config.requests` requests, cycling through the fixtures, then
+/// waits for all of them to complete.
+pub async fn run(
+    client: AgglayerProverClient,
+    fixtures: Vec<GoldenVector>,
+    verifier: Verifier,
+    config: &LoadConfig,
+) -> LoadReport {
+    assert!(!fixtures.is_empty(), "No fixture to replay");
+    let fixtures = Arc::new(fixtures);
+    let verifier = Arc::new(verifier);
+    let in_flight = Arc::new(Semaphore::new(config.max_in_flight.max(1)));
+
+    let mut ticks = tokio::time::interval(Duration::from_secs_f64(1.0 / config.rate));
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let start = Instant::now();
+    let mut requests = JoinSet::new();
+    for index in 0..config.requests {
+        ticks.tick().await;
+        let permit = in_flight
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("the semaphore is never closed");
+
+        let client = client.clone();
+        let fixtures = fixtures.clone();
+        let verifier = verifier.clone();
+        let (network_id, priority) = (config.network_id, config.priority);
+        requests.spawn(async move {
+            let fixture = index % fixtures.len();
+            let sent = Instant::now();
+            let response = client
+                .generate_pessimistic_proof(network_id, &fixtures[fixture].stdin, priority, None)
+                .await;
+            let latency = sent.elapsed();
+            drop(permit);
+
+            match response {
+                Ok(proof) => {
+                    let verified = tokio::task::spawn_blocking(move || {
+                        verifier.verify(&proof, &fixtures[fixture])
+                    })
+                    .await
+                    .unwrap_or(false);
+                    if verified {
+                        Outcome::Verified(latency)
+                    } else {
+                        Outcome::Unverified
+                    }
+                }
+                Err(ClientError::Status(status)) => {
+                    warn!(index, code = ?status.code(), "Request failed");
+                    Outcome::Failed(status.code())
+                }
+                Err(error) => {
+                    warn!(index, %error, "Unable to read the response");
+                    Outcome::Unverified
+                }
+            }
+        });
+    }
+
+    let mut latencies = Vec::with_capacity(config.requests);
+    let mut unverified = 0;
+    let mut failures = BTreeMap::new();
+    while let Some(outcome) = requests.join_next().await {
+        match outcome {
+            Ok(Outcome::Verified(latency)) => latencies.push(latency),
+            Ok(Outcome::Unverified) | Err(_) => unverified += 1,
+            Ok(Outcome::Failed(code)) => {
+                *failures.entry(format!("{code:?}")).or_default() += 1;
+            }
+        }
+    }
+    let elapsed = start.elapsed();
+
+    LoadReport {
+        requests: config.requests,
+        verified: latencies.len(),
+        unverified,
+        failures,
+        elapsed_ms: millis(elapsed),
+        throughput: latencies.len() as f64 / elapsed.as_secs_f64(),
+        latency: LatencyReport::new(latencies),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use agglayer_prover::fake::{FakeProver, FakeProverConfig};
+    use prover_executor::{ProofType, Request};
+    use sp1_sdk::SP1Stdin;
+    use tokio_util::sync::CancellationToken;
+
+    use super::*;
+
+    const ELF: &[u8] = include_bytes!("../../prover-dummy-program/elf/riscv32im-succinct-zkvm-elf");
+
+    fn fixture() -> GoldenVector {
+        let prover = ProverClient::builder().mock().build();
+        let (_proving_key, vkey) = prover.setup(ELF);
+        let stdin = SP1Stdin::new();
+        let (public_values, _report) = prover.execute(ELF, &stdin).run().unwrap();
+
+        GoldenVector::new(
+            "dummy",
+            &vkey,
+            Request {
+                stdin,
+                proof_type: ProofType::Plonk,
+            },
+            public_values.to_vec(),
+        )
+    }
+
+    async fn load_test(fake_prover: FakeProverConfig) -> LoadReport {
+        let cancellation_token = CancellationToken::new();
+        let (addr, _handle) = FakeProver::spawn(
+            FakeProver::with_config(ELF, fake_prover),
+            cancellation_token.clone(),
+        )
+        .await
+        .unwrap();
+        let client = AgglayerProverClient::connect(
+            &format!("http://{addr}"),
+            agglayer_prover_client::ClientConfig {
+                max_retries: 0,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        let config = LoadConfig {
+            rate: 100.0,
+            requests: 4,
+            max_in_flight: 2,
+            network_id: 1,
+            priority: Priority::Low,
+        };
+        let report = run(client, vec![fixture()], Verifier::new(ELF, true), &config).await;
+        cancellation_token.cancel();
+
+        report
+    }
+
+    #[tokio::test]
+    async fn every_proof_is_verified() {
+        let report = load_test(FakeProverConfig::default()).await;
+        assert_eq!(report.verified, 4);
+        assert_eq!(report.failure_ratio(), 0.0);
+        assert!(report.latency.p50_ms <= report.latency.max_ms);
+
+        let report = load_test(FakeProverConfig {
+            invalid_proof_probability: 1.0,
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(report.unverified, 4);
+        assert_eq!(report.latency, LatencyReport::default());
+    }
+
+    #[tokio::test]
+    async fn failures_are_counted_by_code() {
+        let report = load_test(FakeProverConfig {
+            failure_probability: 1.0,
+            failure_code: tonic::Code::ResourceExhausted,
+            ..Default::default()
+        })
+        .await;
+
+        assert_eq!(report.verified, 0);
+        assert_eq!(
+            report.failures,
+            BTreeMap::from([("ResourceExhausted".to_string(), 4)])
+        );
+        assert_eq!(report.failure_ratio(), 1.0);
+    }
+
+    #[test]
+    fn percentiles_are_nearest_rank() {
+        let latencies = (1..=100).map(Duration::from_millis).collect::<Vec<_>>();
+        let report = LatencyReport::new(latencies.into_iter().rev().collect());
+
+        assert_eq!(report.p50_ms, 50.0);
+        assert_eq!(report.p90_ms, 90.0);
+        assert_eq!(report.p99_ms, 99.0);
+        assert_eq!(report.max_ms, 100.0);
+        assert_eq!(report.mean_ms, 50.5);
+        assert_eq!(
+            percentile(&[Duration::from_millis(7)], 99.0),
+            Duration::from_millis(7)
+        );
+    }
+}
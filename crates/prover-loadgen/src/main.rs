@@ -0,0 +1,189 @@
+use std::{path::PathBuf, time::Duration};
+
+use agglayer_prover_client::{AgglayerProverClient, ClientConfig, Priority};
+use anyhow::Context as _;
+use clap::{Parser, ValueHint};
+use prover_executor::golden::{GoldenVector, GOLDEN_VECTOR_EXTENSION};
+use prover_loadgen::{LoadConfig, LoadReport, Verifier};
+use prover_logger::log::Log;
+use prover_utils::encryption::{Cipher, EncryptionConfig, EncryptionKey};
+
+/// Replay golden vectors against an agglayer prover at a steady rate,
+/// verifying every proof and reporting the latency percentiles.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// The gRPC endpoint of the agglayer prover.
+    #[arg(long, default_value = "http://127.0.0.1:8081")]
+    endpoint: String,
+
+    /// The directory holding the golden vectors replayed, in the order of
+    /// their file names.
+    #[arg(long, value_hint = ValueHint::DirPath)]
+    fixtures: PathBuf,
+
+    /// The path to the ELF of the program proven, verifying the proofs.
+    #[arg(long, value_hint = ValueHint::FilePath)]
+    elf: PathBuf,
+
+    /// The proofs are the mock ones of the fake prover.
+    #[arg(long)]
+    mock_proofs: bool,
+
+    /// The number of requests sent per second.
+    #[arg(long, default_value_t = 1.0)]
+    rate: f64,
+
+    /// The time spent sending the requests, e.g. `30m`.
+    #[arg(long, default_value = "1m", value_parser = humantime::parse_duration)]
+    duration: Duration,
+
+    /// The number of requests awaiting their proof at most, the next ones
+    /// being delayed until one of them completes.
+    #[arg(long, default_value_t = 64)]
+    max_in_flight: usize,
+
+    /// The time given to a request before it fails.
+    #[arg(long, default_value = "1h", value_parser = humantime::parse_duration)]
+    timeout: Duration,
+
+    /// The network on behalf of which the proofs are requested.
+    #[arg(long, default_value_t = 1)]
+    network_id: u32,
+
+    /// Request the proofs with the low priority, as the non settling ones.
+    #[arg(long)]
+    low_priority: bool,
+
+    /// The share of the requests allowed not to be answered with a verified
+    /// proof, above which the test fails.
+    #[arg(long, default_value_t = 0.0)]
+    max_failure_ratio: f64,
+
+    /// Print the report as JSON.
+    #[arg(long)]
+    json: bool,
+
+    /// The keys the golden vectors are encrypted with, as secret
+    /// references, e.g. `env:GOLDEN_KEY`. Repeated across a rotation.
+    #[arg(long, value_name = "secret")]
+    encryption_key: Vec<String>,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    prover_logger::tracing(&Log::default());
+
+    anyhow::ensure!(cli.rate > 0.0, "The rate must be positive");
+    let fixtures = load_fixtures(&cli)?;
+    let elf = std::fs::read(&cli.elf)
+        .with_context(|| format!("Failed to read the ELF {}", cli.elf.display()))?;
+    let verifier = Verifier::new(&elf, cli.mock_proofs);
+
+    let client = AgglayerProverClient::connect(
+        &cli.endpoint,
+        ClientConfig {
+            deadline: Some(cli.timeout),
+            // The failures are reported, not hidden by the retries.
+            max_retries: 0,
+            ..Default::default()
+        },
+    )
+    .await
+    .with_context(|| format!("Failed to connect to {}", cli.endpoint))?;
+
+    let config = LoadConfig {
+        rate: cli.rate,
+        requests: (cli.rate * cli.duration.as_secs_f64()).ceil() as usize,
+        max_in_flight: cli.max_in_flight,
+        network_id: cli.network_id,
+        priority: if cli.low_priority {
+            Priority::Low
+        } else {
+            Priority::High
+        },
+    };
+    let report = prover_loadgen::run(client, fixtures, verifier, &config).await;
+
+    if cli.json {
+        let report = serde_json::to_string_pretty(&report)
+            .context("Failed to serialize the load report to JSON")?;
+        println!("{report}");
+    } else {
+        println!("{}", format_report(&report));
+    }
+
+    anyhow::ensure!(
+        report.failure_ratio() <= cli.max_failure_ratio,
+        "{:.2}% of the requests were not answered with a verified proof",
+        report.failure_ratio() * 100.0
+    );
+
+    Ok(())
+}
+
+fn load_fixtures(cli: &Cli) -> anyhow::Result<Vec<GoldenVector>> {
+    let cipher = cipher(cli.encryption_key.iter().cloned())?;
+
+    let mut paths = std::fs::read_dir(&cli.fixtures)
+        .with_context(|| format!("Failed to read {}", cli.fixtures.display()))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    paths.retain(|path| {
+        path.extension()
+            .is_some_and(|extension| extension == GOLDEN_VECTOR_EXTENSION)
+    });
+    paths.sort();
+    anyhow::ensure!(
+        !paths.is_empty(),
+        "No golden vector in {}",
+        cli.fixtures.display()
+    );
+
+    paths
+        .iter()
+        .map(|path| {
+            GoldenVector::load(path, cipher.as_ref())
+                .with_context(|| format!("Failed to load the fixture {}", path.display()))
+        })
+        .collect()
+}
+
+fn cipher(keys: impl IntoIterator<Item = String>) -> anyhow::Result<Option<Cipher>> {
+    let mut keys = keys
+        .into_iter()
+        .map(|key| EncryptionKey::resolve(&key).context("Invalid encryption key"))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    if keys.is_empty() {
+        return Ok(None);
+    }
+
+    let key = keys.remove(0);
+    Ok(Some(Cipher::new(&EncryptionConfig {
+        key,
+        previous_keys: keys,
+    })))
+}
+
+fn format_report(report: &LoadReport) -> String {
+    let mut output = format!(
+        "requests:      {}\nverified:      {}\nunverified:    {}\nelapsed:       {:.1} s\n\
+         throughput:    {:.2} proofs/s\nlatency:       p50 {:.1} ms, p90 {:.1} ms, \
+         p99 {:.1} ms, max {:.1} ms, mean {:.1} ms",
+        report.requests,
+        report.verified,
+        report.unverified,
+        report.elapsed_ms / 1000.0,
+        report.throughput,
+        report.latency.p50_ms,
+        report.latency.p90_ms,
+        report.latency.p99_ms,
+        report.latency.max_ms,
+        report.latency.mean_ms,
+    );
+    for (code, count) in &report.failures {
+        output.push_str(&format!("\nfailed:        {count} ({code})"));
+    }
+    output
+}
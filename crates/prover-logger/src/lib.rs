@@ -1,11 +1,12 @@
 use log::Log;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use tracing_subscriber::{prelude::*, EnvFilter};
+use tracing_subscriber::{prelude::*, EnvFilter, Layer, Registry};
 
 pub mod log;
 
 /// The log format.
-#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum LogFormat {
     #[default]
@@ -13,6 +14,12 @@ pub enum LogFormat {
     Json,
 }
 pub fn tracing(config: &Log) {
+    tracing_with_layers(config, Vec::new())
+}
+
+/// Installs the logger along with other layers of the spans, e.g. one
+/// following the requests in flight.
+pub fn tracing_with_layers(config: &Log, mut layers: Vec<Box<dyn Layer<Registry> + Send + Sync>>) {
     // TODO: Support multiple outputs.
     let writer = config.outputs.first().cloned().unwrap_or_default();
 
@@ -30,5 +37,6 @@ pub fn tracing(config: &Log) {
             .boxed(),
     };
 
-    tracing_subscriber::Registry::default().with(layer).init();
+    layers.push(layer);
+    tracing_subscriber::Registry::default().with(layers).init();
 }
@@ -1,12 +1,13 @@
-use std::{fmt::Display, path::PathBuf};
+use std::{borrow::Cow, fmt::Display, path::PathBuf};
 
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde::{Deserialize, Deserializer, Serialize};
 use tracing_subscriber::{fmt::writer::BoxMakeWriter, EnvFilter};
 
 use crate::LogFormat;
 
 /// The log configuration.
-#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Default, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct Log {
     /// The `RUST_LOG` environment variable will take precedence over the
@@ -20,7 +21,7 @@ pub struct Log {
 }
 
 /// The log level.
-#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum LogLevel {
     Trace,
@@ -87,6 +88,19 @@ impl<'de> Deserialize<'de> for LogOutput {
     }
 }
 
+impl JsonSchema for LogOutput {
+    fn schema_name() -> Cow<'static, str> {
+        "LogOutput".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "\"stdout\", \"stderr\" or the path to a file.",
+            "type": "string"
+        })
+    }
+}
+
 impl LogOutput {
     /// Get a [`BoxMakeWriter`] for the log output.
     ///
@@ -0,0 +1,36 @@
+//! Serves the fake agglayer prover and the mock proposer, injecting the
+//! configured failures, see [`prover_testutils::fakes`].
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use clap::{Parser, ValueHint};
+use prover_testutils::fakes::{self, FakeServicesConfig};
+use tokio_util::sync::CancellationToken;
+
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    /// The path to the configuration file of the fake services, in TOML.
+    #[arg(long, short, value_hint = ValueHint::FilePath, default_value = "prover-fakes.toml", env = "CONFIG_PATH")]
+    config_path: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    let config = FakeServicesConfig::load(&cli.config_path)?;
+    prover_logger::tracing(&config.log);
+
+    let cancellation_token = CancellationToken::new();
+    fakes::spawn(config, cancellation_token.clone())
+        .await
+        .context("Unable to start the fake services")?;
+
+    tokio::signal::ctrl_c()
+        .await
+        .context("Unable to listen for the shutdown signal")?;
+    cancellation_token.cancel();
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+//! The fake agglayer prover and the mock proposer served on their own, e.g. in
+//! a staging environment exercising the error handling of the aggkit prover
+//! continuously, with the failures injected as configured:
+//!
+//! ```toml
+//! [agglayer-prover]
+//! grpc-endpoint = "0.0.0.0:8081"
+//! program = "pessimistic-program.elf"
+//! latency = { uniform = { min = "1s", max = "10s" } }
+//! failure-probability = 0.05
+//! failure-code = 14
+//! invalid-proof-probability = 0.01
+//!
+//! [proposer]
+//! grpc-endpoint = "0.0.0.0:50051"
+//! failure-probability = 0.05
+//! mode-mismatch-probability = 0.01
+//! ```
+//!
+//! The services left out of the configuration are not served.
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+};
+
+use agglayer_prover::fake::{FakeProver, FakeProverConfig};
+use prover_logger::log::Log;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::{Error, MockProposer, MockProposerConfig};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FakeServicesConfigError {
+    #[error("Unable to read the fake services configuration {path:?}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Unable to parse the fake services configuration {path:?}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct FakeServicesConfig {
+    #[serde(default)]
+    pub log: Log,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agglayer_prover: Option<FakeAgglayerProverConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proposer: Option<FakeProposerConfig>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct FakeAgglayerProverConfig {
+    pub grpc_endpoint: SocketAddr,
+    /// ELF of the program proven by the fake prover.
+    pub program: PathBuf,
+    #[serde(flatten)]
+    pub behavior: FakeProverConfig,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct FakeProposerConfig {
+    pub grpc_endpoint: SocketAddr,
+    #[serde(flatten)]
+    pub behavior: MockProposerConfig,
+}
+
+impl FakeServicesConfig {
+    pub fn load(path: &Path) -> Result<Self, FakeServicesConfigError> {
+        let content =
+            std::fs::read_to_string(path).map_err(|source| FakeServicesConfigError::Read {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+        toml::from_str(&content).map_err(|source| FakeServicesConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+}
+
+/// Spawns the configured services, served until `cancellation_token` is
+/// cancelled.
+pub async fn spawn(
+    config: FakeServicesConfig,
+    cancellation_token: CancellationToken,
+) -> Result<(), Error> {
+    if let Some(agglayer_prover) = config.agglayer_prover {
+        let program = std::fs::read(&agglayer_prover.program).map_err(|source| Error::Program {
+            path: agglayer_prover.program.clone(),
+            source,
+        })?;
+        let fake_prover = FakeProver::with_config(&program, agglayer_prover.behavior);
+        FakeProver::spawn_at(
+            fake_prover,
+            agglayer_prover.grpc_endpoint,
+            cancellation_token.clone(),
+        )
+        .await
+        .map_err(|()| Error::FakeProver)?;
+    }
+
+    if let Some(proposer) = config.proposer {
+        let addr = MockProposer::with_config(proposer.behavior)
+            .spawn_at(proposer.grpc_endpoint, cancellation_token)
+            .await?;
+        info!("Mock proposer listening on {addr}");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use agglayer_prover::fake::FakeLatency;
+
+    use super::*;
+
+    #[test]
+    fn failures_are_configured() {
+        let config: FakeServicesConfig = toml::from_str(
+            r#"
+            [agglayer-prover]
+            grpc-endpoint = "127.0.0.1:8081"
+            program = "program.elf"
+            latency = { uniform = { min = "1s", max = "10s" } }
+            failure-probability = 0.05
+            failure-code = 8
+
+            [proposer]
+            grpc-endpoint = "127.0.0.1:50051"
+            seed = 42
+            mode-mismatch-probability = 0.5
+            "#,
+        )
+        .unwrap();
+
+        let agglayer_prover = config.agglayer_prover.unwrap();
+        assert_eq!(
+            agglayer_prover.behavior,
+            FakeProverConfig {
+                latency: FakeLatency::Uniform {
+                    min: Duration::from_secs(1),
+                    max: Duration::from_secs(10),
+                },
+                failure_probability: 0.05,
+                failure_code: tonic::Code::ResourceExhausted,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            config.proposer.unwrap().behavior,
+            MockProposerConfig {
+                seed: 42,
+                mode_mismatch_probability: 0.5,
+                ..Default::default()
+            }
+        );
+
+        assert!(toml::from_str::<FakeProverConfig>("failure-code = 17").is_err());
+    }
+}
@@ -0,0 +1,283 @@
+//! In-process harness for the end-to-end tests of the provers.
+//!
+//! [`TestNetwork::start`] spins up the anvil L1 and L2 nodes, the
+//! [`MockProposer`] and the fake agglayer prover, each one on an ephemeral
+//! local port. The aggkit prover reads the rollup contracts on startup, hence
+//! it is started separately with [`TestNetwork::start_aggkit_prover`], once the
+//! test has deployed them.
+//!
+//! Everything is stopped when the [`TestNetwork`] is dropped.
+use std::net::{Ipv4Addr, SocketAddr};
+
+use aggchain_proof_builder::config::AggchainProofBuilderConfig;
+use aggchain_proof_contracts::config::AggchainProofContractsConfig;
+use aggchain_proof_service::config::AggchainProofServiceConfig;
+use aggkit_prover::rpc::GrpcService;
+use aggkit_prover_types::{
+    v1::aggchain_proof_service_client::AggchainProofServiceClient,
+    v1::aggchain_proof_service_server::AggchainProofServiceServer,
+    v2::aggchain_proof_service_client::AggchainProofServiceClient as AggchainProofServiceClientV2,
+    v2::aggchain_proof_service_server::AggchainProofServiceServer as AggchainProofServiceServerV2,
+};
+use agglayer_prover::fake::{FakeProver, FakeProverConfig};
+use agglayer_prover_client::{AgglayerProverClient, ClientConfig};
+use alloy::{
+    node_bindings::{Anvil, AnvilInstance, NodeError},
+    providers::{DynProvider, Provider as _, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+use proposer_client::{config::ProposerClientConfig, GrpcUri};
+use proposer_service::config::ProposerServiceConfig;
+use prover_alloy::L1RpcEndpoint;
+use prover_config::{MockProverConfig, ProverType};
+use tokio::net::TcpListener;
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tonic::transport::{server::TcpIncoming, Channel};
+use tracing::{debug, error};
+use url::Url;
+
+use crate::proposer::grpc_uri;
+pub use crate::proposer::{MockProposer, MockProposerConfig};
+
+pub mod fakes;
+mod proposer;
+pub mod simulation;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Unable to spawn anvil")]
+    Anvil(#[from] NodeError),
+
+    #[error("Unable to bind a local port")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unable to listen on a local port: {0}")]
+    Listener(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("Unable to spawn the fake agglayer prover")]
+    FakeProver,
+
+    #[error("Unable to read the program {path:?}")]
+    Program {
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unable to start the aggkit prover")]
+    AggkitProver(#[source] aggchain_proof_service::Error),
+
+    #[error("Unable to connect to the service")]
+    Connect(#[from] tonic::transport::Error),
+}
+
+/// Configuration of the [`TestNetwork`].
+pub struct TestNetworkConfig {
+    pub l1_chain_id: u64,
+    pub l2_chain_id: u64,
+    /// Program proven by the fake agglayer prover.
+    pub agglayer_program: &'static [u8],
+    pub fake_prover: FakeProverConfig,
+    pub proposer: MockProposer,
+}
+
+impl TestNetworkConfig {
+    pub fn new(agglayer_program: &'static [u8]) -> Self {
+        Self {
+            l1_chain_id: 1,
+            l2_chain_id: 2,
+            agglayer_program,
+            fake_prover: FakeProverConfig::default(),
+            proposer: MockProposer::new(),
+        }
+    }
+}
+
+/// The nodes and services surrounding the aggkit prover.
+pub struct TestNetwork {
+    l1: AnvilInstance,
+    l2: AnvilInstance,
+    proposer: MockProposer,
+    proposer_addr: SocketAddr,
+    agglayer_prover_addr: SocketAddr,
+    cancellation_token: CancellationToken,
+    _drop_guard: DropGuard,
+}
+
+impl TestNetwork {
+    pub async fn start(config: TestNetworkConfig) -> Result<Self, Error> {
+        let l1 = Anvil::new().chain_id(config.l1_chain_id).try_spawn()?;
+        let l2 = Anvil::new().chain_id(config.l2_chain_id).try_spawn()?;
+        debug!(l1 = %l1.endpoint_url(), l2 = %l2.endpoint_url(), "Anvil nodes started");
+
+        let cancellation_token = CancellationToken::new();
+
+        let proposer_addr = config
+            .proposer
+            .clone()
+            .spawn(cancellation_token.clone())
+            .await?;
+
+        let fake_prover = FakeProver::with_config(config.agglayer_program, config.fake_prover);
+        let (agglayer_prover_addr, _handle) =
+            FakeProver::spawn(fake_prover, cancellation_token.clone())
+                .await
+                .map_err(|()| Error::FakeProver)?;
+
+        Ok(Self {
+            l1,
+            l2,
+            proposer: config.proposer,
+            proposer_addr,
+            agglayer_prover_addr,
+            _drop_guard: cancellation_token.clone().drop_guard(),
+            cancellation_token,
+        })
+    }
+
+    pub fn l1_url(&self) -> Url {
+        self.l1.endpoint_url()
+    }
+
+    pub fn l2_url(&self) -> Url {
+        self.l2.endpoint_url()
+    }
+
+    /// Provider of the L1 node, signing with its first prefunded account.
+    pub fn l1_provider(&self) -> DynProvider {
+        funded_provider(&self.l1)
+    }
+
+    /// Provider of the L2 node, signing with its first prefunded account.
+    pub fn l2_provider(&self) -> DynProvider {
+        funded_provider(&self.l2)
+    }
+
+    pub fn proposer(&self) -> &MockProposer {
+        &self.proposer
+    }
+
+    pub fn proposer_uri(&self) -> GrpcUri {
+        grpc_uri(self.proposer_addr)
+    }
+
+    pub fn agglayer_prover_uri(&self) -> GrpcUri {
+        grpc_uri(self.agglayer_prover_addr)
+    }
+
+    pub async fn agglayer_prover_client(&self) -> Result<AgglayerProverClient, Error> {
+        Ok(AgglayerProverClient::new(
+            Channel::builder(self.agglayer_prover_uri())
+                .connect()
+                .await?,
+            ClientConfig::default(),
+        ))
+    }
+
+    /// Configuration of an aggkit prover for the rollup `network_id`, using
+    /// the nodes and the proposer of this network along with a mock prover.
+    /// The public values of its proofs are checked against the native
+    /// execution of the aggchain proof program.
+    ///
+    /// The L2 consensus layer RPC points to the L2 anvil node, which doesn't
+    /// serve the rollup node API.
+    pub fn aggkit_prover_config(&self, network_id: u32) -> AggchainProofServiceConfig {
+        let l1_rpc_endpoint = L1RpcEndpoint { url: self.l1_url() };
+
+        AggchainProofServiceConfig {
+            aggchain_proof_builder: AggchainProofBuilderConfig {
+                network_id,
+                primary_prover: ProverType::MockProver(MockProverConfig::default()),
+                differential_verification: true,
+                contracts: AggchainProofContractsConfig {
+                    l1_rpc_endpoint: l1_rpc_endpoint.clone(),
+                    l2_execution_layer_rpc_endpoint: self.l2_url(),
+                    l2_consensus_layer_rpc_endpoint: self.l2_url(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            proposer_service: ProposerServiceConfig {
+                mock: true,
+                client: ProposerClientConfig {
+                    proposer_endpoint: self.proposer_uri(),
+                    ..Default::default()
+                },
+                l1_rpc_endpoint,
+                proof_cache: None,
+                range_negotiation: Default::default(),
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Starts the aggkit prover gRPC service, serving both the v1 and v2 APIs,
+    /// on an ephemeral local port.
+    pub async fn start_aggkit_prover(
+        &self,
+        config: &AggchainProofServiceConfig,
+    ) -> Result<AggkitProver, Error> {
+        let service = GrpcService::new(config)
+            .await
+            .map_err(Error::AggkitProver)?;
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let addr = listener.local_addr()?;
+        let incoming = TcpIncoming::from_listener(listener, true, None).map_err(Error::Listener)?;
+
+        let cancellation_token = self.cancellation_token.child_token();
+        tokio::spawn({
+            let cancellation_token = cancellation_token.clone();
+            async move {
+                if let Err(error) = tonic::transport::Server::builder()
+                    .add_service(AggchainProofServiceServerV2::new(service.v2()))
+                    .add_service(AggchainProofServiceServer::new(service))
+                    .serve_with_incoming_shutdown(incoming, cancellation_token.cancelled())
+                    .await
+                {
+                    error!(%error, "Aggkit prover stopped");
+                }
+            }
+        });
+        debug!("Aggkit prover listening on {addr}");
+
+        Ok(AggkitProver {
+            uri: grpc_uri(addr),
+            _drop_guard: cancellation_token.drop_guard(),
+        })
+    }
+}
+
+/// Handle to a running aggkit prover, stopped when dropped.
+pub struct AggkitProver {
+    uri: GrpcUri,
+    _drop_guard: DropGuard,
+}
+
+impl AggkitProver {
+    pub fn uri(&self) -> &GrpcUri {
+        &self.uri
+    }
+
+    pub async fn v1_client(&self) -> Result<AggchainProofServiceClient<Channel>, Error> {
+        Ok(AggchainProofServiceClient::new(
+            Channel::builder(self.uri.clone()).connect().await?,
+        ))
+    }
+
+    pub async fn v2_client(&self) -> Result<AggchainProofServiceClientV2<Channel>, Error> {
+        Ok(AggchainProofServiceClientV2::new(
+            Channel::builder(self.uri.clone()).connect().await?,
+        ))
+    }
+}
+
+fn funded_provider(anvil: &AnvilInstance) -> DynProvider {
+    let signer = PrivateKeySigner::from_slice(&anvil.keys()[0].to_bytes())
+        .expect("anvil keys are valid private keys");
+
+    ProviderBuilder::new()
+        .wallet(signer)
+        .on_http(anvil.endpoint_url())
+        .erased()
+}
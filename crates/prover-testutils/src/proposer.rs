@@ -0,0 +1,297 @@
+//! Mock of the op-succinct proposer, answering every aggregation proof request
+//! with a mock proof, unless configured to inject failures.
+use std::{
+    collections::HashMap,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+};
+
+use aggchain_proof_core::full_execution_proof::AggregationProofPublicValues;
+use agglayer_prover::fake::grpc_code;
+use alloy_primitives::{Address, B256};
+use alloy_sol_types::SolType as _;
+use proposer_client::{
+    rpc::grpc::{
+        self,
+        proofs_server::{Proofs, ProofsServer},
+        GetMockProofRequest, GetMockProofResponse,
+    },
+    GrpcUri,
+};
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+use serde::{Deserialize, Serialize};
+use sp1_sdk::{
+    CpuProver, Prover as _, SP1ProofMode, SP1ProofWithPublicValues, SP1ProvingKey, SP1PublicValues,
+    SP1_CIRCUIT_VERSION,
+};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+use tonic::{transport::server::TcpIncoming, Request, Response, Status};
+use tracing::{debug, error};
+
+use crate::Error;
+
+type PublicValuesFn =
+    dyn Fn(&grpc::AggProofRequest) -> AggregationProofPublicValues + Send + Sync + 'static;
+
+/// Mock proposer, serving the op-succinct `Proofs` gRPC service.
+///
+/// The public values of the aggregation proofs are computed from the requests
+/// with [`MockProposer::with_public_values`], and default to the requested l1
+/// head and end block.
+#[derive(Clone)]
+pub struct MockProposer {
+    proving_key: Arc<SP1ProvingKey>,
+    public_values: Arc<PublicValuesFn>,
+    config: MockProposerConfig,
+    rng: Arc<Mutex<StdRng>>,
+    state: Arc<Mutex<ProposerState>>,
+}
+
+/// Failures injected by the mock proposer.
+///
+/// The outcome of every request is drawn from a RNG seeded with `seed`, hence
+/// a given sequence of requests always gets the same sequence of outcomes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MockProposerConfig {
+    pub seed: u64,
+    /// Probability for a request to fail with `failure_code`.
+    pub failure_probability: f64,
+    /// gRPC status code of the injected failures, given by its number in the
+    /// configuration, e.g. 14 for `UNAVAILABLE`.
+    #[serde(with = "grpc_code")]
+    pub failure_code: tonic::Code,
+    /// Probability for a request to be answered with a core proof, instead of
+    /// the compressed one expected by the aggkit prover.
+    pub mode_mismatch_probability: f64,
+}
+
+impl Default for MockProposerConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            failure_probability: 0.0,
+            failure_code: tonic::Code::Unavailable,
+            mode_mismatch_probability: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProposerOutcome {
+    Proof,
+    ModeMismatch,
+    Failure,
+}
+
+#[derive(Default)]
+struct ProposerState {
+    requests: Vec<grpc::AggProofRequest>,
+    proofs: HashMap<i64, Vec<u8>>,
+}
+
+impl MockProposer {
+    pub fn new() -> Self {
+        Self::with_config(MockProposerConfig::default())
+    }
+
+    pub fn with_config(config: MockProposerConfig) -> Self {
+        let (proving_key, _verifying_key) =
+            CpuProver::mock().setup(proposer_service::AGGREGATION_ELF);
+
+        Self {
+            proving_key: Arc::new(proving_key),
+            public_values: Arc::new(default_public_values),
+            rng: Arc::new(Mutex::new(StdRng::seed_from_u64(config.seed))),
+            config,
+            state: Default::default(),
+        }
+    }
+
+    pub fn with_public_values(
+        mut self,
+        public_values: impl Fn(&grpc::AggProofRequest) -> AggregationProofPublicValues
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        self.public_values = Arc::new(public_values);
+        self
+    }
+
+    /// Aggregation proof requests received so far.
+    pub fn requests(&self) -> Vec<grpc::AggProofRequest> {
+        self.state
+            .lock()
+            .expect("mock proposer lock poisoned")
+            .requests
+            .clone()
+    }
+
+    /// Serves the mock proposer on an ephemeral local port until
+    /// `cancellation_token` is cancelled.
+    pub(crate) async fn spawn(
+        self,
+        cancellation_token: CancellationToken,
+    ) -> Result<SocketAddr, Error> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+
+        self.spawn_with_listener(listener, cancellation_token)
+    }
+
+    /// Serves the mock proposer on `endpoint` until `cancellation_token` is
+    /// cancelled.
+    pub async fn spawn_at(
+        self,
+        endpoint: SocketAddr,
+        cancellation_token: CancellationToken,
+    ) -> Result<SocketAddr, Error> {
+        let listener = TcpListener::bind(endpoint).await?;
+
+        self.spawn_with_listener(listener, cancellation_token)
+    }
+
+    fn spawn_with_listener(
+        self,
+        listener: TcpListener,
+        cancellation_token: CancellationToken,
+    ) -> Result<SocketAddr, Error> {
+        let local_addr = listener.local_addr()?;
+        let incoming = TcpIncoming::from_listener(listener, true, None).map_err(Error::Listener)?;
+
+        tokio::spawn(async move {
+            if let Err(error) = tonic::transport::Server::builder()
+                .add_service(ProofsServer::new(self))
+                .serve_with_incoming_shutdown(incoming, cancellation_token.cancelled())
+                .await
+            {
+                error!(%error, "Mock proposer stopped");
+            }
+        });
+        debug!("Mock proposer listening on {local_addr}");
+
+        Ok(local_addr)
+    }
+}
+
+impl MockProposer {
+    fn draw_outcome(&self) -> ProposerOutcome {
+        let mut rng = self.rng.lock().expect("mock proposer rng lock poisoned");
+
+        // Both draws are always made, so that the sequence of outcomes
+        // doesn't depend on the outcomes themselves.
+        let failure = rng.gen_bool(self.config.failure_probability.clamp(0.0, 1.0));
+        let mismatch = rng.gen_bool(self.config.mode_mismatch_probability.clamp(0.0, 1.0));
+        if failure {
+            ProposerOutcome::Failure
+        } else if mismatch {
+            ProposerOutcome::ModeMismatch
+        } else {
+            ProposerOutcome::Proof
+        }
+    }
+}
+
+impl Default for MockProposer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn default_public_values(request: &grpc::AggProofRequest) -> AggregationProofPublicValues {
+    AggregationProofPublicValues {
+        l1_head: request.l1_block_hash.parse().unwrap_or_default(),
+        l2_pre_root: B256::ZERO,
+        l2_post_root: B256::ZERO,
+        l2_block_number: request.requested_end_block,
+        rollup_config_hash: B256::ZERO,
+        multi_block_vkey: B256::ZERO,
+        prover_address: Address::ZERO,
+    }
+}
+
+/// Request id of a mock proof, as expected by the mock aggregation prover.
+fn mock_request_id(proof_id: i64) -> B256 {
+    let mut request_id = B256::ZERO;
+    request_id[24..].copy_from_slice(&proof_id.to_be_bytes());
+    request_id
+}
+
+/// URI of a gRPC service listening on `addr`.
+pub(crate) fn grpc_uri(addr: SocketAddr) -> GrpcUri {
+    format!("http://{addr}")
+        .parse()
+        .expect("socket addresses are valid URIs")
+}
+
+#[tonic::async_trait]
+impl Proofs for MockProposer {
+    async fn request_agg_proof(
+        &self,
+        request: Request<grpc::AggProofRequest>,
+    ) -> Result<Response<grpc::AggProofResponse>, Status> {
+        let request = request.into_inner();
+
+        let proof_mode = match self.draw_outcome() {
+            ProposerOutcome::Proof => SP1ProofMode::Compressed,
+            ProposerOutcome::ModeMismatch => {
+                debug!("Answering with a core aggregation proof");
+                SP1ProofMode::Core
+            }
+            ProposerOutcome::Failure => {
+                debug!("Injecting a failure of the mock proposer");
+                self.state
+                    .lock()
+                    .expect("mock proposer lock poisoned")
+                    .requests
+                    .push(request);
+                return Err(Status::new(
+                    self.config.failure_code,
+                    "Injected mock proposer failure",
+                ));
+            }
+        };
+
+        let public_values = (self.public_values)(&request);
+        let proof = SP1ProofWithPublicValues::create_mock_proof(
+            &self.proving_key,
+            SP1PublicValues::from(&AggregationProofPublicValues::abi_encode(&public_values)),
+            proof_mode,
+            SP1_CIRCUIT_VERSION,
+        );
+        let proof = agglayer_interop_types::bincode::default()
+            .serialize(&proof)
+            .map_err(|error| Status::internal(error.to_string()))?;
+
+        let mut state = self.state.lock().expect("mock proposer lock poisoned");
+        let proof_id = state.proofs.len() as i64 + 1;
+        state.proofs.insert(proof_id, proof);
+        state.requests.push(request.clone());
+
+        Ok(Response::new(grpc::AggProofResponse {
+            last_proven_block: request.last_proven_block,
+            end_block: request.requested_end_block,
+            proof_request_id: mock_request_id(proof_id).to_vec(),
+        }))
+    }
+
+    async fn get_mock_proof(
+        &self,
+        request: Request<GetMockProofRequest>,
+    ) -> Result<Response<GetMockProofResponse>, Status> {
+        let proof_id = request.into_inner().proof_id;
+
+        self.state
+            .lock()
+            .expect("mock proposer lock poisoned")
+            .proofs
+            .get(&proof_id)
+            .map(|proof| {
+                Response::new(GetMockProofResponse {
+                    proof: proof.clone(),
+                })
+            })
+            .ok_or_else(|| Status::not_found(format!("Unknown mock proof {proof_id}")))
+    }
+}
@@ -0,0 +1,573 @@
+//! Simulation of the aggchain pipeline on synthetic chains, generated from a
+//! seed with the number of bridge exits, inserted GERs and imported bridge
+//! exits of their blocks drawn at random.
+//!
+//! The [`SyntheticChain`] serves both the l1 and the l2 contracts to the
+//! checks run by the aggkit prover before proving: the l1 info tree mirror,
+//! the liveness of the GERs, the cost model of the requests, and the local exit
+//! root recomputed from the bridge events along with the witness of the bridge
+//! exits of the request. [`simulate`] requests the chain
+//! range after range, as the aggsender does, and reports the outcome of the
+//! checks of every range, e.g. to reproduce an indexing bug from the seed of a
+//! failing run.
+//!
+//! The proving itself is not simulated: the aggchain program reads the l2
+//! bridge contracts through static calls, which the synthetic chains have no
+//! state for. It is covered by the end-to-end tests on a [`crate::TestNetwork`].
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use aggchain_proof_contracts::{
+    config::LocalExitRootCheckConfig,
+    contracts::{
+        IndexedBridgeExit, IndexedL1InfoTreeLeaf, L1GlobalExitRootFetcher, L1InfoTreeLeavesFetcher,
+        L2BridgeExitsFetcher, L2LocalExitRootFetcher,
+    },
+    local_exit_root::LocalExitRootCheck,
+};
+use aggchain_proof_service::{
+    ger_liveness::{GerLivenessCheck, GerLivenessConfig},
+    l1_info_tree::{L1InfoTreeMirror, L1InfoTreeMirrorConfig},
+    request_cost::RequestCostModel,
+};
+use aggchain_proof_types::{
+    imported_bridge_exit::{BridgeExitHash, ImportedBridgeExitWithBlockNumber},
+    inserted_ger::{InsertedGer, InsertedGerWithBlockNumber},
+    AggchainProofInputs,
+};
+use agglayer_interop::types::{Digest, L1InfoTreeLeaf, L1InfoTreeLeafInner, MerkleProof};
+use agglayer_primitives::U256;
+use alloy_primitives::Address;
+use rand::{rngs::StdRng, Rng as _, SeedableRng as _};
+use serde::{Deserialize, Serialize};
+use unified_bridge::{BridgeExit, GlobalIndex, LeafType, LocalExitTree, NetworkId, TokenInfo};
+
+/// Parameters of a synthetic chain.
+///
+/// The counts are the maximum per block, the actual ones being drawn
+/// uniformly from a RNG seeded with `seed`, hence a given configuration always
+/// generates the same chain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct SyntheticChainConfig {
+    pub seed: u64,
+    pub network_id: u32,
+    /// Number of l2 blocks, the l1 blocks being numbered alike.
+    pub blocks: u64,
+    pub bridge_exits_per_block: u32,
+    pub inserted_gers_per_block: u32,
+    pub imported_bridge_exits_per_block: u32,
+    /// Probability for an inserted GER not to be known by the global exit
+    /// root manager of the l1, as if the l2 inserted a forged one.
+    pub unknown_ger_probability: f64,
+}
+
+impl Default for SyntheticChainConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            network_id: 1,
+            blocks: 100,
+            bridge_exits_per_block: 2,
+            inserted_gers_per_block: 1,
+            imported_bridge_exits_per_block: 1,
+            unknown_ger_probability: 0.0,
+        }
+    }
+}
+
+/// L1 and l2 generated from a [`SyntheticChainConfig`].
+///
+/// Every GER inserted on the l2 comes with a leaf of the l1 info tree added in
+/// the l1 block of the same number, the tree starting with a leaf at block
+/// zero such that every range has a l1 info tree leaf to settle against.
+#[derive(Debug, Clone)]
+pub struct SyntheticChain {
+    config: SyntheticChainConfig,
+    bridge_exits: Vec<IndexedBridgeExit>,
+    /// Local exit root of the l2 bridge after each block.
+    local_exit_roots: Vec<Digest>,
+    l1_info_tree_leaves: Vec<IndexedL1InfoTreeLeaf>,
+    /// Timestamps of the GERs known by the l1.
+    l1_gers: HashMap<Digest, u64>,
+    inserted_gers: Vec<InsertedGerWithBlockNumber>,
+    imported_bridge_exits: Vec<ImportedBridgeExitWithBlockNumber>,
+}
+
+impl SyntheticChain {
+    pub fn generate(config: SyntheticChainConfig) -> Self {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut chain = Self {
+            config,
+            bridge_exits: Vec::new(),
+            local_exit_roots: Vec::new(),
+            l1_info_tree_leaves: Vec::new(),
+            l1_gers: HashMap::new(),
+            inserted_gers: Vec::new(),
+            imported_bridge_exits: Vec::new(),
+        };
+        let mut local_exit_tree = LocalExitTree::new();
+        let mut l1_info_tree = LocalExitTree::new();
+        chain.add_l1_info_tree_leaf(&mut rng, &mut l1_info_tree, 0);
+        chain.local_exit_roots.push(local_exit_tree.get_root());
+
+        for block_number in 1..=chain.config.blocks {
+            for block_index in 0..rng.gen_range(0..=chain.config.inserted_gers_per_block) {
+                let leaf = chain.add_l1_info_tree_leaf(&mut rng, &mut l1_info_tree, block_number);
+                if rng.gen_bool(chain.config.unknown_ger_probability.clamp(0.0, 1.0)) {
+                    chain.l1_gers.remove(&leaf.inner.global_exit_root);
+                }
+                chain.inserted_gers.push(InsertedGerWithBlockNumber {
+                    block_number,
+                    block_index: u64::from(block_index),
+                    inserted_ger: InsertedGer {
+                        proof_ger_l1root: placeholder_proof(),
+                        l1_leaf: leaf,
+                    },
+                });
+            }
+
+            for _ in 0..rng.gen_range(0..=chain.config.bridge_exits_per_block) {
+                let bridge_exit = bridge_exit(&mut rng, chain.config.network_id);
+                let deposit_count = local_exit_tree.leaf_count;
+                local_exit_tree
+                    .add_leaf(bridge_exit.hash())
+                    .expect("the local exit tree is never full");
+                chain.bridge_exits.push(IndexedBridgeExit {
+                    block_number,
+                    deposit_count,
+                    bridge_exit,
+                });
+            }
+
+            for _ in 0..rng.gen_range(0..=chain.config.imported_bridge_exits_per_block) {
+                let leaf_index = chain.imported_bridge_exits.len() as u32;
+                chain
+                    .imported_bridge_exits
+                    .push(ImportedBridgeExitWithBlockNumber {
+                        block_number,
+                        bridge_exit_hash: BridgeExitHash(Digest(rng.gen())),
+                        global_index: GlobalIndex::new(NetworkId::new(0), leaf_index),
+                    });
+            }
+
+            chain.local_exit_roots.push(local_exit_tree.get_root());
+        }
+
+        chain
+    }
+
+    pub fn config(&self) -> &SyntheticChainConfig {
+        &self.config
+    }
+
+    /// Adds a leaf of a new GER to the l1 info tree, at the l1 block.
+    fn add_l1_info_tree_leaf(
+        &mut self,
+        rng: &mut StdRng,
+        l1_info_tree: &mut LocalExitTree,
+        block_number: u64,
+    ) -> L1InfoTreeLeaf {
+        let leaf = L1InfoTreeLeaf {
+            l1_info_tree_index: l1_info_tree.leaf_count,
+            rer: Digest(rng.gen()),
+            mer: Digest(rng.gen()),
+            inner: L1InfoTreeLeafInner {
+                global_exit_root: Digest(rng.gen()),
+                block_hash: Digest(rng.gen()),
+                timestamp: 1_000 + block_number * 12,
+            },
+        };
+        l1_info_tree
+            .add_leaf(leaf.hash())
+            .expect("the l1 info tree is never full");
+        self.l1_gers
+            .insert(leaf.inner.global_exit_root, leaf.inner.timestamp);
+        self.l1_info_tree_leaves.push(IndexedL1InfoTreeLeaf {
+            block_number,
+            leaf: leaf.clone(),
+            root: l1_info_tree.get_root(),
+        });
+
+        leaf
+    }
+
+    /// Inputs of the aggsender requesting the proof of the blocks after
+    /// `last_proven_block` up to `end_block`, settled against the last leaf of
+    /// the l1 info tree added at `end_block`.
+    ///
+    /// The merkle proofs are placeholders, the checks before proving not
+    /// verifying them.
+    pub fn inputs(&self, last_proven_block: u64, end_block: u64) -> AggchainProofInputs {
+        let in_range =
+            |block_number: u64| (last_proven_block + 1..=end_block).contains(&block_number);
+        let settled = self
+            .l1_info_tree_leaves
+            .iter()
+            .take_while(|indexed| indexed.block_number <= end_block)
+            .last()
+            .expect("the l1 info tree starts with a leaf at block zero");
+
+        AggchainProofInputs {
+            last_proven_block,
+            requested_end_block: end_block,
+            l1_info_tree_root_hash: settled.root,
+            l1_info_tree_leaf: settled.leaf.clone(),
+            l1_info_tree_merkle_proof: placeholder_proof(),
+            ger_leaves: self
+                .inserted_gers
+                .iter()
+                .filter(|inserted_ger| in_range(inserted_ger.block_number))
+                .map(|inserted_ger| {
+                    let ger = inserted_ger.inserted_ger.l1_leaf.inner.global_exit_root;
+                    (ger.to_string(), inserted_ger.clone())
+                })
+                .collect(),
+            imported_bridge_exits: self
+                .imported_bridge_exits
+                .iter()
+                .filter(|imported| in_range(imported.block_number))
+                .cloned()
+                .collect(),
+            requested_vkey_hash: None,
+        }
+    }
+}
+
+fn placeholder_proof() -> MerkleProof {
+    MerkleProof::new(Digest::default(), [Digest::default(); 32])
+}
+
+fn bridge_exit(rng: &mut StdRng, network_id: u32) -> BridgeExit {
+    BridgeExit {
+        leaf_type: LeafType::Transfer,
+        token_info: TokenInfo {
+            origin_network: NetworkId::new(0),
+            origin_token_address: Address::ZERO.into(),
+        },
+        dest_network: NetworkId::new(if network_id == 0 { 1 } else { 0 }),
+        dest_address: Address::from(rng.gen::<[u8; 20]>()).into(),
+        amount: U256::from(rng.gen_range(1..=1_000_000_000u64)),
+        metadata: None,
+    }
+}
+
+#[async_trait::async_trait]
+impl L2LocalExitRootFetcher for SyntheticChain {
+    async fn get_l2_local_exit_root(
+        &self,
+        block_number: u64,
+    ) -> Result<Digest, aggchain_proof_contracts::Error> {
+        Ok(self
+            .local_exit_roots
+            .get(block_number as usize)
+            .or(self.local_exit_roots.last())
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+#[async_trait::async_trait]
+impl L2BridgeExitsFetcher for SyntheticChain {
+    async fn get_l2_bridge_exits(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedBridgeExit>, aggchain_proof_contracts::Error> {
+        Ok(self
+            .bridge_exits
+            .iter()
+            .filter(|indexed| (from_block..=to_block).contains(&indexed.block_number))
+            .cloned()
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl L1GlobalExitRootFetcher for SyntheticChain {
+    async fn get_l1_global_exit_root_timestamp(
+        &self,
+        ger: Digest,
+    ) -> Result<u64, aggchain_proof_contracts::Error> {
+        Ok(self.l1_gers.get(&ger).copied().unwrap_or_default())
+    }
+}
+
+#[async_trait::async_trait]
+impl L1InfoTreeLeavesFetcher for SyntheticChain {
+    async fn get_l1_head(&self) -> Result<u64, aggchain_proof_contracts::Error> {
+        Ok(self.config.blocks)
+    }
+
+    async fn get_l1_info_tree_leaves(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<IndexedL1InfoTreeLeaf>, aggchain_proof_contracts::Error> {
+        Ok(self
+            .l1_info_tree_leaves
+            .iter()
+            .filter(|indexed| (from_block..=to_block).contains(&indexed.block_number))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Requests of a simulation, over the whole synthetic chain.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct SimulationConfig {
+    pub chain: SyntheticChainConfig,
+    /// Blocks proven per request, the last request being shorter.
+    pub blocks_per_request: u64,
+    pub cost_model: RequestCostModel,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            chain: SyntheticChainConfig::default(),
+            blocks_per_request: 10,
+            cost_model: RequestCostModel::default(),
+        }
+    }
+}
+
+/// Check of the aggkit prover rejecting a request.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum Stage {
+    L1InfoTree,
+    GerLiveness,
+    RequestCost,
+    LocalExitRoot,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Rejection {
+    pub stage: Stage,
+    pub reason: String,
+}
+
+/// Outcome of the checks of one request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RangeReport {
+    pub last_proven_block: u64,
+    pub end_block: u64,
+    pub bridge_exits: usize,
+    pub inserted_gers: usize,
+    pub imported_bridge_exits: usize,
+    pub estimated_cycles: u64,
+    pub checks_ms: f64,
+    /// First check failed by the request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rejection: Option<Rejection>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SimulationReport {
+    pub ranges: Vec<RangeReport>,
+}
+
+impl SimulationReport {
+    /// Number of rejected requests, by check.
+    pub fn rejections(&self) -> BTreeMap<Stage, usize> {
+        let mut rejections = BTreeMap::new();
+        for rejection in self
+            .ranges
+            .iter()
+            .filter_map(|range| range.rejection.as_ref())
+        {
+            *rejections.entry(rejection.stage).or_default() += 1;
+        }
+        rejections
+    }
+}
+
+/// Number of polls of the l1 info tree mirror awaited for the leaves of a
+/// request, before rejecting it.
+const MIRROR_POLLS: u32 = 100;
+const MIRROR_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Generates the chain of `config` and runs the checks of the aggkit prover
+/// on its consecutive ranges, the state of the checks being kept across the
+/// requests as in the service.
+pub async fn simulate(config: &SimulationConfig) -> SimulationReport {
+    let chain = Arc::new(SyntheticChain::generate(config.chain.clone()));
+    let mirror = L1InfoTreeMirror::spawn(
+        chain.clone(),
+        L1InfoTreeMirrorConfig {
+            from_block: 0,
+            max_block_range: 10_000,
+            poll_interval: MIRROR_POLL_INTERVAL,
+        },
+    );
+    let ger_liveness = GerLivenessCheck::new(chain.clone(), &GerLivenessConfig::default());
+    let local_exit_root = LocalExitRootCheck::new(LocalExitRootCheckConfig::default())
+        .expect("no local exit tree checkpoint to read");
+
+    let blocks_per_request = config.blocks_per_request.max(1);
+    let mut ranges = Vec::new();
+    let mut last_proven_block = 0;
+    while last_proven_block < chain.config.blocks {
+        let end_block = chain
+            .config
+            .blocks
+            .min(last_proven_block + blocks_per_request);
+        let inputs = chain.inputs(last_proven_block, end_block);
+
+        let start = Instant::now();
+        let rejection = async {
+            check_mirrored(&mirror, &inputs).await?;
+            ger_liveness
+                .check(&inputs)
+                .await
+                .map_err(|error| rejected(Stage::GerLiveness, error))?;
+            config
+                .cost_model
+                .check(&inputs)
+                .map_err(|error| rejected(Stage::RequestCost, error))?;
+            let on_chain = chain
+                .get_l2_local_exit_root(end_block)
+                .await
+                .map_err(|error| rejected(Stage::LocalExitRoot, error))?;
+            local_exit_root
+                .check(chain.as_ref(), end_block, on_chain)
+                .await
+                .map_err(|error| rejected(Stage::LocalExitRoot, error))?;
+            let prev_on_chain = chain
+                .get_l2_local_exit_root(last_proven_block)
+                .await
+                .map_err(|error| rejected(Stage::LocalExitRoot, error))?;
+            local_exit_root
+                .bridge_exits_witness(chain.as_ref(), last_proven_block, prev_on_chain, end_block)
+                .await
+                .map_err(|error| rejected(Stage::LocalExitRoot, error))?
+                .verify(prev_on_chain, on_chain)
+                .map_err(|error| rejected(Stage::LocalExitRoot, error))
+        }
+        .await
+        .err();
+
+        ranges.push(RangeReport {
+            last_proven_block,
+            end_block,
+            bridge_exits: chain
+                .bridge_exits
+                .iter()
+                .filter(|indexed| {
+                    (last_proven_block + 1..=end_block).contains(&indexed.block_number)
+                })
+                .count(),
+            inserted_gers: inputs.ger_leaves.len(),
+            imported_bridge_exits: inputs.imported_bridge_exits.len(),
+            estimated_cycles: config.cost_model.estimate(&inputs),
+            checks_ms: start.elapsed().as_secs_f64() * 1000.0,
+            rejection,
+        });
+        last_proven_block = end_block;
+    }
+
+    SimulationReport { ranges }
+}
+
+/// Checks the request against the mirror, once its leaves are mirrored.
+async fn check_mirrored(
+    mirror: &L1InfoTreeMirror<SyntheticChain>,
+    inputs: &AggchainProofInputs,
+) -> Result<(), Rejection> {
+    for _ in 0..MIRROR_POLLS {
+        match mirror.check(inputs) {
+            Ok(true) => return Ok(()),
+            Ok(false) => tokio::time::sleep(MIRROR_POLL_INTERVAL).await,
+            Err(error) => return Err(rejected(Stage::L1InfoTree, error)),
+        }
+    }
+
+    Err(Rejection {
+        stage: Stage::L1InfoTree,
+        reason: "The l1 info tree leaves of the request are not mirrored".to_string(),
+    })
+}
+
+fn rejected<E>(stage: Stage, error: E) -> Rejection
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    Rejection {
+        stage,
+        reason: format!("{:#}", anyhow::Error::from(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn synthetic_chains_pass_the_checks() {
+        let config = SimulationConfig {
+            chain: SyntheticChainConfig {
+                seed: 7,
+                blocks: 50,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let report = simulate(&config).await;
+
+        assert_eq!(report.ranges.len(), 5);
+        assert_eq!(report.ranges.last().unwrap().end_block, 50);
+        assert!(report.rejections().is_empty(), "{report:#?}");
+        assert!(report.ranges.iter().any(|range| range.bridge_exits > 0));
+
+        // Generated alike from the same seed.
+        assert_eq!(simulate(&config).await.ranges.len(), 5);
+        let chain = SyntheticChain::generate(config.chain.clone());
+        assert_eq!(
+            chain.local_exit_roots,
+            SyntheticChain::generate(config.chain).local_exit_roots
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_gers_and_costly_requests_are_rejected() {
+        let report = simulate(&SimulationConfig {
+            chain: SyntheticChainConfig {
+                blocks: 20,
+                inserted_gers_per_block: 3,
+                unknown_ger_probability: 1.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(
+            report.rejections(),
+            BTreeMap::from([(Stage::GerLiveness, 2)])
+        );
+
+        let report = simulate(&SimulationConfig {
+            chain: SyntheticChainConfig {
+                blocks: 20,
+                ..Default::default()
+            },
+            cost_model: RequestCostModel {
+                max_cycles: Some(1),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+        assert_eq!(
+            report.rejections(),
+            BTreeMap::from([(Stage::RequestCost, 2)])
+        );
+    }
+}
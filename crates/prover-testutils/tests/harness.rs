@@ -0,0 +1,52 @@
+use agglayer_prover_client::Priority;
+use proposer_client::{
+    rpc::{
+        AggregationProofProposer as _, AggregationProofProposerRequest, MockProofProposerRequest,
+        ProposerRpcClient,
+    },
+    MockProofId,
+};
+use prover_testutils::{TestNetwork, TestNetworkConfig};
+use sp1_sdk::SP1Stdin;
+
+const ELF: &[u8] = include_bytes!("../../prover-dummy-program/elf/riscv32im-succinct-zkvm-elf");
+
+#[tokio::test]
+#[ignore = "requires the anvil binary"]
+async fn network_serves_the_agglayer_prover_and_the_proposer() {
+    let network = TestNetwork::start(TestNetworkConfig::new(ELF))
+        .await
+        .expect("start the test network");
+
+    network
+        .agglayer_prover_client()
+        .await
+        .unwrap()
+        .generate_pessimistic_proof(1, &SP1Stdin::new(), Priority::High, None)
+        .await
+        .unwrap();
+
+    let proposer =
+        ProposerRpcClient::new(network.proposer_uri(), std::time::Duration::from_secs(10))
+            .await
+            .unwrap();
+    let response = proposer
+        .request_agg_proof(AggregationProofProposerRequest {
+            last_proven_block: 10,
+            requested_end_block: 20,
+            l1_block_number: 1,
+            l1_block_hash: Default::default(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.end_block, 20);
+    assert_eq!(network.proposer().requests().len(), 1);
+
+    let proof = proposer
+        .get_mock_proof(MockProofProposerRequest {
+            proof_id: MockProofId(1),
+        })
+        .await
+        .unwrap();
+    assert!(!proof.proof.is_empty());
+}
@@ -0,0 +1,318 @@
+//! AES-256-GCM encryption of the payloads kept at rest, e.g. the witnesses of
+//! the proof requests, which can carry chain data not published yet.
+//!
+//! An encrypted payload is [`ENCRYPTION_MAGIC`], the fingerprint of its key,
+//! a random nonce, then the ciphertext, the magic number and the fingerprint
+//! being authenticated along with it. The encrypted payloads are told apart
+//! by the magic number, which neither the codecs nor zstd start a payload
+//! with, so that the plain payloads are still read once encryption is
+//! switched on.
+//!
+//! The keys are rotated by configuring the new one as `key`, the former one
+//! being moved to `previous-keys` until the payloads encrypted with it are
+//! gone. The payloads are always encrypted with `key`, and decrypted with
+//! the key of their fingerprint.
+use std::{borrow::Cow, fmt};
+
+use aes_gcm::{
+    aead::{Aead, AeadCore, KeyInit, OsRng, Payload},
+    Aes256Gcm, Nonce,
+};
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest as _, Sha256};
+
+use crate::secret::{Secret, SecretError};
+
+/// First bytes of an encrypted payload.
+pub const ENCRYPTION_MAGIC: [u8; 4] = [0xff, b'e', b'n', b'c'];
+
+const KEY_LEN: usize = 32;
+const FINGERPRINT_LEN: usize = 4;
+const NONCE_LEN: usize = 12;
+
+/// Magic number and key fingerprint, authenticated along with the payload.
+const HEADER_LEN: usize = ENCRYPTION_MAGIC.len() + FINGERPRINT_LEN;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionKeyError {
+    #[error(transparent)]
+    Secret(#[from] SecretError),
+
+    #[error("The encryption key is not {KEY_LEN} hex encoded bytes")]
+    Invalid,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EncryptionError {
+    #[error("The payload is encrypted, but no encryption key is configured")]
+    MissingKey,
+
+    #[error("The payload is encrypted with the unknown key {fingerprint}")]
+    UnknownKey { fingerprint: String },
+
+    #[error("The encrypted payload is truncated")]
+    Truncated,
+
+    #[error("Unable to encrypt the payload")]
+    Encryption,
+
+    #[error("Unable to decrypt the payload, which is corrupted")]
+    Decryption,
+}
+
+/// Keys of the payloads kept at rest.
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct EncryptionConfig {
+    /// Key encrypting the payloads written.
+    pub key: EncryptionKey,
+
+    /// Former keys, still decrypting the payloads written with them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub previous_keys: Vec<EncryptionKey>,
+}
+
+/// AES-256 key, given as a [`Secret`] resolving to its hex encoded bytes.
+#[derive(Clone, PartialEq, Eq)]
+pub struct EncryptionKey {
+    secret: Secret,
+    bytes: [u8; KEY_LEN],
+}
+
+impl EncryptionKey {
+    pub fn resolve(reference: &str) -> Result<Self, EncryptionKeyError> {
+        Self::try_from(Secret::resolve(reference)?)
+    }
+
+    /// First bytes of the SHA-256 of the key, identifying it in the payloads
+    /// without disclosing it.
+    fn fingerprint(&self) -> [u8; FINGERPRINT_LEN] {
+        let digest = Sha256::digest(self.bytes);
+
+        let mut fingerprint = [0; FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(&digest[..FINGERPRINT_LEN]);
+        fingerprint
+    }
+}
+
+impl TryFrom<Secret> for EncryptionKey {
+    type Error = EncryptionKeyError;
+
+    fn try_from(secret: Secret) -> Result<Self, Self::Error> {
+        let hex = secret.expose();
+        let bytes = hex::decode(hex.strip_prefix("0x").unwrap_or(hex))
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(EncryptionKeyError::Invalid)?;
+
+        Ok(Self { secret, bytes })
+    }
+}
+
+impl fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("EncryptionKey").field(&self.secret).finish()
+    }
+}
+
+impl Serialize for EncryptionKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.secret.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptionKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(Secret::deserialize(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+impl JsonSchema for EncryptionKey {
+    fn schema_name() -> Cow<'static, str> {
+        "EncryptionKey".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "AES-256 key, 32 hex encoded bytes, as a secret: \"file:<path>\", \"env:<variable>\", \"<scheme>:<reference>\" of a registered resolver, or inlined.",
+            "type": "string"
+        })
+    }
+}
+
+/// Encryption of the payloads with the configured keys.
+#[derive(Clone)]
+pub struct Cipher {
+    /// Keys by fingerprint, the first one encrypting the payloads.
+    keys: Vec<([u8; FINGERPRINT_LEN], Aes256Gcm)>,
+}
+
+impl Cipher {
+    pub fn new(config: &EncryptionConfig) -> Self {
+        Self {
+            keys: std::iter::once(&config.key)
+                .chain(&config.previous_keys)
+                .map(|key| (key.fingerprint(), Aes256Gcm::new(&key.bytes.into())))
+                .collect(),
+        }
+    }
+
+    pub fn encrypt(&self, bytes: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        let (fingerprint, key) = &self.keys[0];
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+        let mut encrypted = Vec::with_capacity(HEADER_LEN + NONCE_LEN + bytes.len() + 16);
+        encrypted.extend_from_slice(&ENCRYPTION_MAGIC);
+        encrypted.extend_from_slice(fingerprint);
+        let ciphertext = key
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: bytes,
+                    aad: &encrypted,
+                },
+            )
+            .map_err(|_| EncryptionError::Encryption)?;
+        encrypted.extend_from_slice(&nonce);
+        encrypted.extend_from_slice(&ciphertext);
+
+        Ok(encrypted)
+    }
+
+    pub fn decrypt(&self, bytes: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        if bytes.len() < HEADER_LEN + NONCE_LEN {
+            return Err(EncryptionError::Truncated);
+        }
+        let (header, rest) = bytes.split_at(HEADER_LEN);
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+        let fingerprint = &header[ENCRYPTION_MAGIC.len()..];
+
+        let (_, key) = self
+            .keys
+            .iter()
+            .find(|(key_fingerprint, _)| key_fingerprint == fingerprint)
+            .ok_or_else(|| EncryptionError::UnknownKey {
+                fingerprint: hex::encode(fingerprint),
+            })?;
+
+        key.decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| EncryptionError::Decryption)
+    }
+}
+
+/// Encrypts the encoded payload with the cipher, left as is when unset.
+pub fn encrypt(bytes: Vec<u8>, cipher: Option<&Cipher>) -> Result<Vec<u8>, EncryptionError> {
+    match cipher {
+        Some(cipher) => cipher.encrypt(&bytes),
+        None => Ok(bytes),
+    }
+}
+
+/// Decrypts the payload if encrypted.
+pub fn decrypt<'a>(
+    bytes: &'a [u8],
+    cipher: Option<&Cipher>,
+) -> Result<Cow<'a, [u8]>, EncryptionError> {
+    if !bytes.starts_with(&ENCRYPTION_MAGIC) {
+        return Ok(Cow::Borrowed(bytes));
+    }
+
+    let cipher = cipher.ok_or(EncryptionError::MissingKey)?;
+    cipher.decrypt(bytes).map(Cow::Owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> EncryptionKey {
+        EncryptionKey::resolve(&hex::encode([byte; KEY_LEN])).unwrap()
+    }
+
+    fn cipher(key: EncryptionKey, previous_keys: Vec<EncryptionKey>) -> Cipher {
+        Cipher::new(&EncryptionConfig { key, previous_keys })
+    }
+
+    #[test]
+    fn encrypted_and_plain_payloads_are_read_alike() {
+        let cipher = cipher(key(1), vec![]);
+        let payload = b"witness".repeat(10);
+
+        let encrypted = encrypt(payload.clone(), Some(&cipher)).unwrap();
+        assert!(encrypted.starts_with(&ENCRYPTION_MAGIC));
+        assert!(!encrypted
+            .windows(payload.len())
+            .any(|window| window == payload));
+        // Each payload has its own nonce.
+        assert_ne!(encrypted, encrypt(payload.clone(), Some(&cipher)).unwrap());
+
+        let plain = encrypt(payload.clone(), None).unwrap();
+        for bytes in [&encrypted, &plain] {
+            assert_eq!(decrypt(bytes, Some(&cipher)).unwrap(), payload.as_slice());
+        }
+        assert!(matches!(
+            decrypt(&encrypted, None),
+            Err(EncryptionError::MissingKey)
+        ));
+    }
+
+    #[test]
+    fn previous_keys_still_decrypt() {
+        let payload = b"witness".to_vec();
+        let former = encrypt(payload.clone(), Some(&cipher(key(1), vec![]))).unwrap();
+
+        let rotated = cipher(key(2), vec![key(1)]);
+        assert_eq!(
+            decrypt(&former, Some(&rotated)).unwrap(),
+            payload.as_slice()
+        );
+        let current = encrypt(payload.clone(), Some(&rotated)).unwrap();
+        assert_eq!(
+            decrypt(&current, Some(&cipher(key(2), vec![]))).unwrap(),
+            payload.as_slice()
+        );
+
+        // Once dropped, the former key no longer decrypts.
+        assert!(matches!(
+            decrypt(&former, Some(&cipher(key(2), vec![]))),
+            Err(EncryptionError::UnknownKey { .. })
+        ));
+    }
+
+    #[test]
+    fn corrupted_payloads_are_rejected() {
+        let cipher = cipher(key(1), vec![]);
+        let mut encrypted = encrypt(b"witness".to_vec(), Some(&cipher)).unwrap();
+
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(matches!(
+            decrypt(&encrypted, Some(&cipher)),
+            Err(EncryptionError::Decryption)
+        ));
+        assert!(matches!(
+            decrypt(&encrypted[..10], Some(&cipher)),
+            Err(EncryptionError::Truncated)
+        ));
+    }
+
+    #[test]
+    fn keys_are_checked_and_never_leak() {
+        assert!(matches!(
+            EncryptionKey::resolve("0xaabbcc"),
+            Err(EncryptionKeyError::Invalid)
+        ));
+        assert!(EncryptionKey::resolve(&format!("0x{}", hex::encode([1; KEY_LEN]))).is_ok());
+
+        let key = key(1);
+        assert_eq!(format!("{key:?}"), r#"EncryptionKey(Secret("<redacted>"))"#);
+        assert_eq!(serde_json::to_string(&key).unwrap(), r#""<redacted>""#);
+    }
+}
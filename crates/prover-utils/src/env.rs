@@ -0,0 +1,229 @@
+//! Overrides of the configuration fields from the environment.
+//!
+//! The variable `<PREFIX>__<SECTION>__<FIELD>` overrides the field `field` of
+//! the table `[section]`, e.g. `AGGKIT_PROVER__TELEMETRY__PROMETHEUS_ADDR`
+//! overrides `prometheus-addr` in `[telemetry]`. The names are matched case
+//! insensitively, with the underscores standing for the dashes of the keys.
+//!
+//! The values are parsed after the type of the field they override, taken from
+//! the configuration file or else from the defaults, such that string fields
+//! like `0x10`, `8081` or `1979-05-27` stay strings. The values of the other
+//! fields are parsed as TOML values, falling back to strings, such that
+//! `8081`, `true` or `["stdout"]` keep their type while `127.0.0.1:8081` or
+//! `5m` don't need any quoting.
+use toml::{Table, Value};
+
+/// Separator of the sections in the names of the variables.
+const SEPARATOR: &str = "__";
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EnvOverrideError {
+    #[error("{variable} overrides a field of {key:?}, which is not a section")]
+    NotASection { variable: String, key: String },
+}
+
+/// Applies the overrides of the process environment to `config`, the fields
+/// missing from `config` taking their type from `defaults`.
+pub fn apply_env_overrides(
+    config: &mut Table,
+    defaults: &Table,
+    prefix: &str,
+) -> Result<(), EnvOverrideError> {
+    apply_overrides(config, defaults, prefix, std::env::vars())
+}
+
+/// Applies the overrides found in `variables` to `config`, the variables not
+/// starting with `prefix` being ignored.
+pub fn apply_overrides(
+    config: &mut Table,
+    defaults: &Table,
+    prefix: &str,
+    variables: impl IntoIterator<Item = (String, String)>,
+) -> Result<(), EnvOverrideError> {
+    let prefix = format!("{prefix}{SEPARATOR}");
+
+    for (variable, value) in variables {
+        let Some(path) = variable.strip_prefix(&prefix) else {
+            continue;
+        };
+        let keys: Vec<String> = path
+            .split(SEPARATOR)
+            .map(|key| key.to_lowercase().replace('_', "-"))
+            .collect();
+        let Some((field, sections)) = keys.split_last() else {
+            continue;
+        };
+
+        let mut table = &mut *config;
+        let mut default = Some(defaults);
+        for key in sections {
+            default = default
+                .and_then(|default| default.get(key))
+                .and_then(Value::as_table);
+            table = match table
+                .entry(key.clone())
+                .or_insert_with(|| Value::Table(Table::new()))
+            {
+                Value::Table(table) => table,
+                _ => {
+                    return Err(EnvOverrideError::NotASection {
+                        variable,
+                        key: key.clone(),
+                    })
+                }
+            };
+        }
+        let current = table
+            .get(field)
+            .or_else(|| default.and_then(|default| default.get(field)));
+        let value = parse_value(&value, current);
+        table.insert(field.clone(), value);
+    }
+
+    Ok(())
+}
+
+/// Parses `raw` as a value of the type of `current`, if any.
+fn parse_value(raw: &str, current: Option<&Value>) -> Value {
+    if let Some(Value::String(_)) = current {
+        return Value::String(raw.to_string());
+    }
+
+    format!("value = {raw}")
+        .parse::<Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables(variables: &[(&str, &str)]) -> Vec<(String, String)> {
+        variables
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn overrides_fields() {
+        let mut config: Table = r#"
+            grpc-endpoint = "127.0.0.1:8081"
+
+            [telemetry]
+            prometheus-addr = "0.0.0.0:3001"
+        "#
+        .parse()
+        .unwrap();
+
+        apply_overrides(
+            &mut config,
+            &Table::new(),
+            "AGGKIT_PROVER",
+            variables(&[
+                ("AGGKIT_PROVER__GRPC_ENDPOINT", "0.0.0.0:8081"),
+                ("AGGKIT_PROVER__TELEMETRY__PROMETHEUS_ADDR", "0.0.0.0:9090"),
+                ("AGGKIT_PROVER__SHUTDOWN__RUNTIME_TIMEOUT", "1m"),
+                ("AGGKIT_PROVER__GRPC__MAX_DECODING_MESSAGE_SIZE", "8388608"),
+                ("AGGKIT_PROVER__LOG__OUTPUTS", r#"["stderr"]"#),
+                ("AGGLAYER_PROVER__GRPC_ENDPOINT", "ignored"),
+            ]),
+        )
+        .unwrap();
+
+        let expected: Table = r#"
+            grpc-endpoint = "0.0.0.0:8081"
+
+            [telemetry]
+            prometheus-addr = "0.0.0.0:9090"
+
+            [shutdown]
+            runtime-timeout = "1m"
+
+            [grpc]
+            max-decoding-message-size = 8388608
+
+            [log]
+            outputs = ["stderr"]
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(config, expected);
+    }
+
+    #[test]
+    fn string_fields_stay_strings() {
+        let mut config: Table = r#"
+            network-id = "1"
+
+            [contracts]
+            l1-bridge = "0x01"
+        "#
+        .parse()
+        .unwrap();
+        let defaults: Table = r#"
+            genesis = "1970-01-01"
+
+            [contracts]
+            chain-id = "1"
+
+            [grpc]
+            max-decoding-message-size = 4194304
+        "#
+        .parse()
+        .unwrap();
+
+        apply_overrides(
+            &mut config,
+            &defaults,
+            "AGGKIT_PROVER",
+            variables(&[
+                ("AGGKIT_PROVER__NETWORK_ID", "8081"),
+                ("AGGKIT_PROVER__GENESIS", "1979-05-27"),
+                ("AGGKIT_PROVER__CONTRACTS__L1_BRIDGE", "0x10"),
+                ("AGGKIT_PROVER__CONTRACTS__CHAIN_ID", "0x10"),
+                ("AGGKIT_PROVER__GRPC__MAX_DECODING_MESSAGE_SIZE", "0x10"),
+                ("AGGKIT_PROVER__SHUTDOWN__GRACE_PERIOD", "10"),
+            ]),
+        )
+        .unwrap();
+
+        let expected: Table = r#"
+            network-id = "8081"
+            genesis = "1979-05-27"
+
+            [contracts]
+            l1-bridge = "0x10"
+            chain-id = "0x10"
+
+            [grpc]
+            max-decoding-message-size = 16
+
+            [shutdown]
+            grace-period = 10
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(config, expected);
+    }
+
+    #[test]
+    fn rejects_fields_of_values() {
+        let mut config: Table = r#"grpc-endpoint = "127.0.0.1:8081""#.parse().unwrap();
+
+        assert_eq!(
+            apply_overrides(
+                &mut config,
+                &Table::new(),
+                "AGGKIT_PROVER",
+                variables(&[("AGGKIT_PROVER__GRPC_ENDPOINT__PORT", "1")]),
+            ),
+            Err(EnvOverrideError::NotASection {
+                variable: "AGGKIT_PROVER__GRPC_ENDPOINT__PORT".to_string(),
+                key: "grpc-endpoint".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! Formats of the configuration files, which share the same schema.
+use std::{fmt, path::Path, str::FromStr};
+
+use toml::Table;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConfigFormat {
+    #[default]
+    Toml,
+    Yaml,
+    Json,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigParseError {
+    #[error("Invalid TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid YAML: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("Invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl ConfigFormat {
+    /// Format of the file, from its extension. Defaults to TOML.
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| extension.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Parses the content of a configuration file, as a TOML table whatever
+    /// the format. The null values of YAML and JSON are not supported.
+    pub fn parse(self, content: &str) -> Result<Table, ConfigParseError> {
+        Ok(match self {
+            Self::Toml => content.parse()?,
+            Self::Yaml => serde_yaml::from_str(content)?,
+            Self::Json => serde_json::from_str(content)?,
+        })
+    }
+}
+
+impl FromStr for ConfigFormat {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, Self::Err> {
+        match format.to_lowercase().as_str() {
+            "toml" => Ok(Self::Toml),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "json" => Ok(Self::Json),
+            _ => Err(format!(
+                "unknown configuration format {format:?}, expected toml, yaml or json"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Toml => "toml",
+            Self::Yaml => "yaml",
+            Self::Json => "json",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_share_the_schema() {
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("prover.yml")),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("prover.json")),
+            ConfigFormat::Json
+        );
+        assert_eq!(
+            ConfigFormat::from_path(Path::new("prover.conf")),
+            ConfigFormat::Toml
+        );
+
+        let toml = ConfigFormat::Toml
+            .parse(
+                r#"
+                grpc-endpoint = "127.0.0.1:8081"
+
+                [shutdown]
+                runtime-timeout = "30s"
+
+                [log]
+                outputs = ["stdout"]
+                "#,
+            )
+            .unwrap();
+        let yaml = ConfigFormat::Yaml
+            .parse(
+                r#"
+                grpc-endpoint: 127.0.0.1:8081
+                shutdown:
+                  runtime-timeout: 30s
+                log:
+                  outputs: [stdout]
+                "#,
+            )
+            .unwrap();
+        let json = ConfigFormat::Json
+            .parse(
+                r#"{
+                    "grpc-endpoint": "127.0.0.1:8081",
+                    "shutdown": { "runtime-timeout": "30s" },
+                    "log": { "outputs": ["stdout"] }
+                }"#,
+            )
+            .unwrap();
+
+        assert_eq!(yaml, toml);
+        assert_eq!(json, toml);
+    }
+}
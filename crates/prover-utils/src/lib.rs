@@ -1,5 +1,10 @@
 use std::str::FromStr;
 
+pub mod encryption;
+pub mod env;
+pub mod format;
+pub mod retention;
+pub mod secret;
 pub mod with;
 
 /// Get an environment variable or a default value if it is not set.
@@ -0,0 +1,62 @@
+//! Retention of the state persisted by the provers, e.g. the cached proofs or
+//! the audit log files, by age and by count.
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Items kept, all of them when neither limit is set.
+#[serde_as]
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RetentionPolicy {
+    /// Age from which the items are removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<crate::with::HumanDuration>")]
+    #[schemars(with = "Option<crate::with::HumanDuration>")]
+    pub max_age: Option<Duration>,
+
+    /// Number of the most recent items kept, the older ones being removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_count: Option<usize>,
+}
+
+impl RetentionPolicy {
+    /// Whether all the items are kept.
+    pub fn keeps_all(&self) -> bool {
+        self.max_age.is_none() && self.max_count.is_none()
+    }
+
+    /// Whether the item of the given age is removed, `rank` being its
+    /// position from the most recent item, zero for the most recent one.
+    pub fn expires(&self, age: Duration, rank: usize) -> bool {
+        self.max_age.is_some_and(|max_age| age > max_age)
+            || self.max_count.is_some_and(|max_count| rank >= max_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn items_expire_by_age_or_by_count() {
+        let policy: RetentionPolicy = toml::from_str(
+            r#"
+            max-age = "1d"
+            max-count = 2
+            "#,
+        )
+        .unwrap();
+        let day = Duration::from_secs(24 * 60 * 60);
+
+        assert!(!policy.expires(Duration::ZERO, 0));
+        assert!(!policy.expires(day, 1));
+        assert!(policy.expires(day + Duration::from_secs(1), 0));
+        assert!(policy.expires(Duration::ZERO, 2));
+
+        assert!(RetentionPolicy::default().keeps_all());
+        assert!(!RetentionPolicy::default().expires(100 * day, 1_000));
+    }
+}
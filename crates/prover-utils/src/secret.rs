@@ -0,0 +1,220 @@
+//! Sensitive configuration values, given as references resolved when the
+//! configuration is loaded rather than inlined in the configuration files.
+//!
+//! - `file:/run/secrets/network-key` reads the file, without its trailing
+//!   newline;
+//! - `env:NETWORK_PRIVATE_KEY` reads the environment variable;
+//! - `<scheme>:<reference>` is handed to the [`SecretResolver`] registered for
+//!   the scheme with [`register_resolver`], e.g. a Vault or KMS integration;
+//! - any other value is the secret itself.
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    path::PathBuf,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Placeholder of the inlined secrets, which are never serialized back.
+const REDACTED: &str = "<redacted>";
+
+#[derive(Debug, thiserror::Error)]
+pub enum SecretError {
+    #[error("Unable to read the secret file {path:?}")]
+    File {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Unable to read the secret from the environment variable {variable}")]
+    Env {
+        variable: String,
+        #[source]
+        source: std::env::VarError,
+    },
+
+    #[error("Unable to resolve the secret {reference:?} with the {scheme} resolver")]
+    Resolver {
+        scheme: String,
+        reference: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Resolver of the secrets referenced with a given scheme.
+pub trait SecretResolver: Send + Sync {
+    /// Resolves the reference, stripped of its `<scheme>:` prefix.
+    fn resolve(&self, reference: &str) -> Result<String, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+type Resolvers = RwLock<HashMap<String, Arc<dyn SecretResolver>>>;
+
+fn resolvers() -> &'static Resolvers {
+    static RESOLVERS: OnceLock<Resolvers> = OnceLock::new();
+    RESOLVERS.get_or_init(Default::default)
+}
+
+/// Registers the resolver of the secrets referenced as `<scheme>:...`. To be
+/// called before loading the configuration.
+pub fn register_resolver(scheme: impl Into<String>, resolver: Arc<dyn SecretResolver>) {
+    resolvers()
+        .write()
+        .expect("secret resolvers lock poisoned")
+        .insert(scheme.into(), resolver);
+}
+
+/// Secret resolved from its reference.
+///
+/// The secret never shows up in the debug output nor in the serialized
+/// configuration, which keeps its reference instead.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret {
+    /// Reference of the secret, `None` for an inlined secret.
+    reference: Option<String>,
+    value: String,
+}
+
+impl Secret {
+    pub fn resolve(reference: &str) -> Result<Self, SecretError> {
+        let Some((scheme, path)) = reference.split_once(':') else {
+            return Ok(Self::inline(reference));
+        };
+
+        let value = match scheme {
+            "file" => std::fs::read_to_string(path)
+                .map(|value| value.trim_end_matches(['\n', '\r']).to_string())
+                .map_err(|source| SecretError::File {
+                    path: path.into(),
+                    source,
+                })?,
+            "env" => std::env::var(path).map_err(|source| SecretError::Env {
+                variable: path.to_string(),
+                source,
+            })?,
+            _ => {
+                let resolver = resolvers()
+                    .read()
+                    .expect("secret resolvers lock poisoned")
+                    .get(scheme)
+                    .cloned();
+                let Some(resolver) = resolver else {
+                    return Ok(Self::inline(reference));
+                };
+
+                resolver
+                    .resolve(path)
+                    .map_err(|source| SecretError::Resolver {
+                        scheme: scheme.to_string(),
+                        reference: path.to_string(),
+                        source,
+                    })?
+            }
+        };
+
+        Ok(Self {
+            reference: Some(reference.to_string()),
+            value,
+        })
+    }
+
+    fn inline(value: &str) -> Self {
+        Self {
+            reference: None,
+            value: value.to_string(),
+        }
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Secret")
+            .field(&self.reference.as_deref().unwrap_or(REDACTED))
+            .finish()
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.reference.as_deref().unwrap_or(REDACTED))
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let reference = String::deserialize(deserializer)?;
+
+        Self::resolve(&reference).map_err(serde::de::Error::custom)
+    }
+}
+
+impl JsonSchema for Secret {
+    fn schema_name() -> Cow<'static, str> {
+        "Secret".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Secret as \"file:<path>\", \"env:<variable>\", \"<scheme>:<reference>\" of a registered resolver, or inlined.",
+            "type": "string"
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Upper;
+
+    impl SecretResolver for Upper {
+        fn resolve(
+            &self,
+            reference: &str,
+        ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(reference.to_uppercase())
+        }
+    }
+
+    #[test]
+    fn resolves_references() {
+        let path = std::env::temp_dir().join(format!("prover-utils-secret-{}", std::process::id()));
+        std::fs::write(&path, "from-file\n").unwrap();
+        let secret = Secret::resolve(&format!("file:{}", path.display())).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(secret.expose(), "from-file");
+
+        let secret = Secret::resolve("env:CARGO_PKG_NAME").unwrap();
+        assert_eq!(secret.expose(), env!("CARGO_PKG_NAME"));
+
+        register_resolver("upper", Arc::new(Upper));
+        let secret = Secret::resolve("upper:vault-key").unwrap();
+        assert_eq!(secret.expose(), "VAULT-KEY");
+
+        let secret = Secret::resolve("0xaabbcc").unwrap();
+        assert_eq!(secret.expose(), "0xaabbcc");
+
+        assert!(matches!(
+            Secret::resolve("env:PROVER_UTILS_UNSET_SECRET"),
+            Err(SecretError::Env { .. })
+        ));
+    }
+
+    #[test]
+    fn never_leaks() {
+        let inline = Secret::resolve("0xaabbcc").unwrap();
+        assert_eq!(format!("{inline:?}"), r#"Secret("<redacted>")"#);
+        assert_eq!(serde_json::to_string(&inline).unwrap(), r#""<redacted>""#);
+
+        let reference = Secret::resolve("env:CARGO_PKG_NAME").unwrap();
+        assert_eq!(format!("{reference:?}"), r#"Secret("env:CARGO_PKG_NAME")"#);
+    }
+}
@@ -0,0 +1,84 @@
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Bytes encoded as a `0x`-prefixed hex string in the human readable formats,
+/// as a sequence of bytes in the others.
+pub struct HexBytes;
+
+impl HexBytes {
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&format!("0x{}", hex::encode(bytes)))
+        } else {
+            // Same encoding as a `Vec<u8>`, hence as the bytes without `with`.
+            serializer.collect_seq(bytes)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Vec::deserialize(deserializer);
+        }
+
+        match HexBytesImpl::deserialize(deserializer)? {
+            HexBytesImpl::Hex(hex) => hex::decode(hex.strip_prefix("0x").unwrap_or(&hex))
+                .map_err(serde::de::Error::custom),
+            HexBytesImpl::Seq(bytes) => Ok(bytes),
+        }
+    }
+}
+
+/// Human readable bytes, the sequences written before the hex encoding being
+/// read as well.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HexBytesImpl {
+    Hex(String),
+    Seq(Vec<u8>),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq)]
+    struct Payload {
+        #[serde(with = "super::HexBytes")]
+        bytes: Vec<u8>,
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_value(Payload {
+                bytes: vec![0xab, 0x01]
+            })
+            .unwrap(),
+            json!({ "bytes": "0xab01" }),
+        );
+        assert_eq!(
+            serde_json::to_value(Payload { bytes: vec![] }).unwrap(),
+            json!({ "bytes": "0x" }),
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        let payload = Payload {
+            bytes: vec![0xab, 0x01],
+        };
+
+        assert_eq!(
+            serde_json::from_value::<Payload>(json!({ "bytes": "0xab01" })).unwrap(),
+            payload
+        );
+        assert_eq!(
+            serde_json::from_value::<Payload>(json!({ "bytes": "ab01" })).unwrap(),
+            payload
+        );
+        assert_eq!(
+            serde_json::from_value::<Payload>(json!({ "bytes": [171, 1] })).unwrap(),
+            payload
+        );
+        assert!(serde_json::from_value::<Payload>(json!({ "bytes": "0xab0" })).is_err());
+    }
+}
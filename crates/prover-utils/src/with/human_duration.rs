@@ -1,9 +1,24 @@
-use std::time::Duration;
+use std::{borrow::Cow, time::Duration};
 
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
 use serde_with::serde_conv;
 
 serde_conv!(pub HumanDuration, Duration, HumanDurationImpl::new, HumanDurationImpl::get);
 
+impl JsonSchema for HumanDuration {
+    fn schema_name() -> Cow<'static, str> {
+        "HumanDuration".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": "Number of seconds, or human readable duration such as \"5m 30s\".",
+            "type": ["integer", "string"],
+            "minimum": 0
+        })
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy)]
 #[serde(untagged)]
 enum HumanDurationImpl {
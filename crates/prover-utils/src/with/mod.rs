@@ -1,5 +1,6 @@
 //! Helper (de)serializers to be used with `#[serde(with)]` and `#[serde_as]`.
 
+mod hex_bytes;
 mod human_duration;
 
 /// A config-friendly [std::time::Duration].
@@ -7,3 +8,9 @@ mod human_duration;
 /// Can be specified as either human-readable string, such as `"1h"` or
 /// `"15min"`, or as an integer interpreted as the number of seconds.
 pub use human_duration::HumanDuration;
+
+/// Bytes readable in the JSON payloads.
+///
+/// Encoded as a `0x`-prefixed hex string in the human readable formats, as
+/// before otherwise, so that the binary encodings are unchanged.
+pub use hex_bytes::HexBytes;